@@ -0,0 +1,135 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A minimal command-line client for the out-of-process widget inspector.
+//!
+//! Start any Masonry app with the `inspector` feature and the `MASONRY_INSPECTOR`
+//! environment variable set to a TCP port, then connect to it:
+//!
+//! ```sh
+//! MASONRY_INSPECTOR=9339 cargo run --example hello_masonry --features inspector
+//! cargo run --example inspector_client --features inspector -- 9339
+//! ```
+//!
+//! The app only accepts connections from the same machine.
+//!
+//! Commands are read from stdin, one per line:
+//!
+//! - `tree`
+//! - `timings`
+//! - `highlight <id>` / `highlight`
+//! - `disable <id>` / `enable <id>`
+//! - `set <id> <property> <json value>`, e.g. `set 12 Background "#ff0000"`
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, TcpStream};
+
+use masonry::app::inspector::{InspectorRequest, InspectorResponse, WidgetNode};
+
+fn parse_command(line: &str) -> Result<InspectorRequest, String> {
+    let mut words = line.split_whitespace();
+    let command = words.next().unwrap_or_default();
+    let mut widget = || -> Result<u64, String> {
+        let word = words.next().ok_or("missing widget id")?;
+        word.trim_start_matches('#')
+            .parse()
+            .map_err(|_| format!("invalid widget id '{word}'"))
+    };
+
+    match command {
+        "tree" => Ok(InspectorRequest::GetTree),
+        "timings" => Ok(InspectorRequest::GetTimings),
+        "highlight" => Ok(InspectorRequest::Highlight {
+            widget: widget().ok(),
+        }),
+        "disable" | "enable" => Ok(InspectorRequest::SetDisabled {
+            widget: widget()?,
+            disabled: command == "disable",
+        }),
+        "set" => {
+            let widget = widget()?;
+            let mut rest = line.splitn(4, char::is_whitespace).skip(2);
+            let property = rest.next().ok_or("missing property name")?.to_string();
+            let value = rest.next().ok_or("missing property value")?;
+            let value = serde_json::from_str(value).map_err(|err| err.to_string())?;
+            Ok(InspectorRequest::SetProperty {
+                widget,
+                property,
+                value,
+            })
+        }
+        _ => Err(format!("unknown command '{command}'")),
+    }
+}
+
+fn print_tree(node: &WidgetNode, depth: usize) {
+    let [x0, y0, x1, y1] = node.border_box;
+    let mut flags = String::new();
+    if node.disabled {
+        flags.push_str(" disabled");
+    }
+    if node.stashed {
+        flags.push_str(" stashed");
+    }
+    println!(
+        "{:indent$}{} #{} {:.0}x{:.0} at ({:.0}, {:.0}){flags}{}",
+        "",
+        node.name,
+        node.id,
+        x1 - x0,
+        y1 - y0,
+        x0,
+        y0,
+        node.debug_text
+            .as_ref()
+            .map(|text| format!(" {text:?}"))
+            .unwrap_or_default(),
+        indent = depth * 2,
+    );
+    for child in &node.children {
+        print_tree(child, depth + 1);
+    }
+}
+
+fn main() {
+    let port: u16 = std::env::args()
+        .nth(1)
+        .map_or(9339, |port| port.parse().expect("expected a port number"));
+    let mut stream = TcpStream::connect((Ipv4Addr::LOCALHOST, port))
+        .expect("failed to connect to the inspected app");
+    let mut responses = BufReader::new(stream.try_clone().unwrap()).lines();
+
+    for line in std::io::stdin().lines() {
+        let line = line.unwrap();
+        if line.trim().is_empty() {
+            continue;
+        }
+        let request = match parse_command(&line) {
+            Ok(request) => request,
+            Err(err) => {
+                eprintln!("{err}");
+                continue;
+            }
+        };
+
+        let mut message = serde_json::to_string(&request).unwrap();
+        message.push('\n');
+        stream.write_all(message.as_bytes()).unwrap();
+        let Some(Ok(response)) = responses.next() else {
+            eprintln!("connection closed");
+            return;
+        };
+
+        match serde_json::from_str(&response) {
+            Ok(InspectorResponse::Tree { root }) => print_tree(&root, 0),
+            Ok(InspectorResponse::Timings { passes }) => {
+                for pass in passes {
+                    println!("{:>20}: {:>8.1} us", pass.name, pass.micros);
+                }
+            }
+            Ok(InspectorResponse::Done) => println!("ok"),
+            Ok(InspectorResponse::Error { message }) => eprintln!("error: {message}"),
+            Err(err) => eprintln!("invalid response: {err}"),
+        }
+    }
+}
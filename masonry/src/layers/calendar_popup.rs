@@ -0,0 +1,172 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ActionCtx, ChildrenIds, ErasedAction, EventCtx, Layer, LayoutCtx, MeasureCtx,
+    NewWidget, NoAction, PaintCtx, PointerButtonEvent, PointerEvent, PropertiesMut, PropertiesRef,
+    RegisterCtx, Widget, WidgetId, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::{LayoutSize, LenReq, Length};
+use crate::widgets::{Calendar, CalendarAction, DatePicker};
+
+/// A [`Layer`] showing the [`Calendar`] of a [`DatePicker`].
+///
+/// The picker keeps the focus while the popup is open and handles the keyboard
+/// on behalf of the calendar. The popup tells the picker which date was picked,
+/// or when it was dismissed by clicking outside of it.
+pub struct CalendarPopup {
+    creator: WidgetId,
+    calendar: WidgetPod<Calendar>,
+    /// The widget to give the focus back to when a date is picked.
+    restore_focus: Option<WidgetId>,
+}
+
+// --- MARK: BUILDERS
+impl CalendarPopup {
+    /// Creates a popup showing `calendar`, for the date picker `creator`.
+    pub(crate) fn new(creator: WidgetId, calendar: NewWidget<Calendar>) -> Self {
+        Self {
+            creator,
+            calendar: calendar.to_pod(),
+            restore_focus: None,
+        }
+    }
+
+    /// Builder-style method to set the widget given the focus when a date is picked.
+    pub(crate) fn with_restore_focus(mut self, id: Option<WidgetId>) -> Self {
+        self.restore_focus = id;
+        self
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for CalendarPopup {
+    type Action = NoAction;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if let PointerEvent::Down(..) = event {
+            // Clicking outside of the focused picker would clear the focus, which closes the popup.
+            ctx.set_focus(self.creator);
+        }
+    }
+
+    fn on_action(
+        &mut self,
+        ctx: &mut ActionCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        action: &ErasedAction,
+        source: WidgetId,
+    ) {
+        if source != self.calendar.id() {
+            return;
+        }
+        let Some(&CalendarAction::DateSelected(date)) = action.downcast_ref::<CalendarAction>()
+        else {
+            return;
+        };
+        if let Some(id) = self.restore_focus {
+            ctx.set_focus(id);
+        }
+        let popup = ctx.widget_id();
+        ctx.mutate_later(self.creator, move |mut picker| {
+            DatePicker::pick_from_popup(&mut picker.downcast(), popup, date);
+        });
+        ctx.set_handled();
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.calendar);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        ctx.compute_length(
+            &mut self.calendar,
+            len_req.into(),
+            context_size,
+            axis,
+            cross_length,
+        )
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.calendar, size);
+        ctx.place_child(&mut self.calendar, Point::ORIGIN);
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Dialog
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.calendar.id()])
+    }
+
+    fn as_layer(&mut self) -> Option<&mut dyn Layer> {
+        Some(self)
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("CalendarPopup", id = id.trace())
+    }
+}
+
+// --- MARK: IMPL LAYER
+impl Layer for CalendarPopup {
+    fn capture_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        let dismiss = match event {
+            PointerEvent::Down(PointerButtonEvent { state, .. }) => {
+                let local_pos = ctx.local_position(state.position);
+                !ctx.border_box().contains(local_pos)
+            }
+            PointerEvent::Cancel(..) => true,
+            _ => false,
+        };
+
+        if dismiss {
+            // The picker removes the layer, unless it already closed it.
+            let popup = ctx.widget_id();
+            ctx.mutate_later(self.creator, move |mut picker| {
+                DatePicker::dismiss_popup(&mut picker.downcast(), popup);
+            });
+        }
+    }
+}
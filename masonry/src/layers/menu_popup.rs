@@ -0,0 +1,338 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ChildrenIds, EventCtx, Layer, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
+    PointerButton, PointerButtonEvent, PointerEvent, PointerUpdate, PropertiesMut, PropertiesRef,
+    RegisterCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Line, Point, Rect, Size, Stroke};
+use crate::layout::{LayoutSize, LenDef, LenReq, Length, SizeDef};
+use crate::properties::ContentColor;
+use crate::theme;
+use crate::widgets::{Label, MenuBar, MenuItem, parse_access_key};
+
+/// The horizontal padding around the text of an item.
+const ITEM_PADDING_H: f64 = 12.;
+/// The vertical padding around the text of an item.
+const ITEM_PADDING_V: f64 = 4.;
+/// The minimum space between the label and the shortcut of an item.
+const SHORTCUT_GAP: f64 = 24.;
+/// The height of a separator.
+const SEPARATOR_HEIGHT: f64 = 9.;
+
+/// A [`Layer`] showing the items of a menu opened from a [`MenuBar`].
+///
+/// The popup only displays the menu: the bar handles keyboard navigation, and is told
+/// which item is hovered, clicked, or when the popup is dismissed.
+pub struct MenuPopup {
+    creator: WidgetId,
+    rows: Vec<Row>,
+    highlighted: Option<usize>,
+    /// The widget to give the focus back to when the popup closes.
+    restore_focus: Option<WidgetId>,
+}
+
+/// An item of a [`MenuPopup`].
+struct Row {
+    /// The label and shortcut of a command, or `None` for a separator.
+    texts: Option<(WidgetPod<Label>, Option<WidgetPod<Label>>)>,
+    enabled: bool,
+    /// The area of the row, in content-box coordinates.
+    rect: Rect,
+}
+
+// --- MARK: BUILDERS
+impl MenuPopup {
+    /// Creates a popup showing `items`, for the menu bar `creator`.
+    pub(crate) fn new(creator: WidgetId, items: &[MenuItem], show_access_keys: bool) -> Self {
+        let rows = items
+            .iter()
+            .map(|item| match item {
+                MenuItem::Command(command) => {
+                    let (text, access_key) = parse_access_key(&command.label);
+                    let underline = access_key
+                        .filter(|_| show_access_keys)
+                        .map(|access_key| access_key.range);
+                    let label = NewWidget::new(Label::new(text).with_underline(underline))
+                        .disabled(!command.enabled)
+                        .to_pod();
+                    let shortcut = command.shortcut.as_ref().map(|shortcut| {
+                        NewWidget::new(Label::new(shortcut.clone()))
+                            .with_props(ContentColor::new(theme::DISABLED_TEXT_COLOR))
+                            .to_pod()
+                    });
+                    Row {
+                        texts: Some((label, shortcut)),
+                        enabled: command.enabled,
+                        rect: Rect::ZERO,
+                    }
+                }
+                MenuItem::Separator => Row {
+                    texts: None,
+                    enabled: false,
+                    rect: Rect::ZERO,
+                },
+            })
+            .collect();
+        Self {
+            creator,
+            rows,
+            highlighted: None,
+            restore_focus: None,
+        }
+    }
+
+    /// Builder-style method to highlight the item at `index`.
+    pub(crate) fn with_highlighted(mut self, index: Option<usize>) -> Self {
+        self.highlighted = index;
+        self
+    }
+
+    /// Builder-style method to set the widget given the focus back when an item is
+    /// clicked or the popup is dismissed.
+    pub(crate) fn with_restore_focus(mut self, id: Option<WidgetId>) -> Self {
+        self.restore_focus = id;
+        self
+    }
+
+    /// Returns the index of the highlighted item, if any.
+    pub fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    fn item_at(&self, pos: Point) -> Option<usize> {
+        self.rows
+            .iter()
+            .position(|row| row.rect.contains(pos))
+            .filter(|&index| self.rows[index].enabled)
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl MenuPopup {
+    /// Highlights the item at `index`.
+    pub(crate) fn set_highlighted(this: &mut WidgetMut<'_, Self>, index: Option<usize>) {
+        if this.widget.highlighted != index {
+            this.widget.highlighted = index;
+            this.ctx.request_paint_only();
+        }
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for MenuPopup {
+    type Action = NoAction;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        let popup = ctx.widget_id();
+        match event {
+            PointerEvent::Move(PointerUpdate { current, .. }) => {
+                let hovered = self.item_at(ctx.local_position(current.position));
+                if hovered != self.highlighted {
+                    self.highlighted = hovered;
+                    ctx.request_paint_only();
+                    ctx.mutate_later(self.creator, move |mut bar| {
+                        MenuBar::set_hovered_item(&mut bar.downcast(), popup, hovered);
+                    });
+                }
+            }
+            PointerEvent::Down(..) => {
+                // Clicking outside of the focused bar would clear the focus, which closes the menu.
+                ctx.set_focus(self.creator);
+            }
+            PointerEvent::Up(PointerButtonEvent {
+                button: None | Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                let Some(index) = self.item_at(ctx.local_position(state.position)) else {
+                    return;
+                };
+                if let Some(id) = self.restore_focus {
+                    ctx.set_focus(id);
+                }
+                ctx.mutate_later(self.creator, move |mut bar| {
+                    MenuBar::activate_from_popup(&mut bar.downcast(), popup, index);
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for (label, shortcut) in self.rows.iter_mut().filter_map(|row| row.texts.as_mut()) {
+            ctx.register_child(label);
+            if let Some(shortcut) = shortcut {
+                ctx.register_child(shortcut);
+            }
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        _cross_length: Option<Length>,
+    ) -> Length {
+        let context_size = LayoutSize::NONE;
+        let mut label_length = Length::ZERO;
+        let mut shortcut_length = Length::ZERO;
+        let mut total = Length::ZERO;
+        for row in &mut self.rows {
+            let Some((label, shortcut)) = &mut row.texts else {
+                total = total.saturating_add(Length::px(SEPARATOR_HEIGHT));
+                continue;
+            };
+            let length = ctx.compute_length(label, LenDef::MaxContent, context_size, axis, None);
+            let shortcut = shortcut.as_mut().map(|shortcut| {
+                ctx.compute_length(shortcut, LenDef::MaxContent, context_size, axis, None)
+            });
+            match axis {
+                Axis::Horizontal => {
+                    label_length = label_length.max(length);
+                    if let Some(shortcut) = shortcut {
+                        shortcut_length =
+                            shortcut_length.max(shortcut.saturating_add(Length::px(SHORTCUT_GAP)));
+                    }
+                }
+                Axis::Vertical => {
+                    let height = shortcut.map_or(length, |shortcut| length.max(shortcut));
+                    total = total
+                        .saturating_add(height.saturating_add(Length::px(2. * ITEM_PADDING_V)));
+                }
+            }
+        }
+        match axis {
+            Axis::Horizontal => label_length
+                .saturating_add(shortcut_length)
+                .saturating_add(Length::px(2. * ITEM_PADDING_H)),
+            Axis::Vertical => total,
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let mut y = 0.;
+        for row in &mut self.rows {
+            let Some((label, shortcut)) = &mut row.texts else {
+                row.rect = Rect::new(0., y, size.width, y + SEPARATOR_HEIGHT);
+                y += SEPARATOR_HEIGHT;
+                continue;
+            };
+            let label_size = ctx.compute_size(label, SizeDef::MAX, size.into());
+            ctx.run_layout(label, label_size);
+            let shortcut_size = shortcut.as_mut().map(|shortcut| {
+                let shortcut_size = ctx.compute_size(shortcut, SizeDef::MAX, size.into());
+                ctx.run_layout(shortcut, shortcut_size);
+                shortcut_size
+            });
+
+            let height = shortcut_size.map_or(label_size.height, |shortcut_size| {
+                label_size.height.max(shortcut_size.height)
+            }) + 2. * ITEM_PADDING_V;
+            ctx.place_child(
+                label,
+                Point::new(ITEM_PADDING_H, y + (height - label_size.height) * 0.5),
+            );
+            if let (Some(shortcut), Some(shortcut_size)) = (shortcut, shortcut_size) {
+                let x = size.width - ITEM_PADDING_H - shortcut_size.width;
+                ctx.place_child(
+                    shortcut,
+                    Point::new(x, y + (height - shortcut_size.height) * 0.5),
+                );
+            }
+            row.rect = Rect::new(0., y, size.width, y + height);
+            y += height;
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        painter: &mut Painter<'_>,
+    ) {
+        for (index, row) in self.rows.iter().enumerate() {
+            if row.texts.is_none() {
+                let y = row.rect.center().y;
+                let line = Line::new((ITEM_PADDING_H, y), (row.rect.x1 - ITEM_PADDING_H, y));
+                painter
+                    .stroke(line, &Stroke::new(1.), theme::ZYNC_700)
+                    .draw();
+            } else if self.highlighted == Some(index) {
+                painter.fill(row.rect, theme::ACCENT_COLOR).draw();
+            }
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Menu
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.rows
+            .iter()
+            .filter_map(|row| row.texts.as_ref())
+            .flat_map(|(label, shortcut)| [Some(label.id()), shortcut.as_ref().map(WidgetPod::id)])
+            .flatten()
+            .collect()
+    }
+
+    fn as_layer(&mut self) -> Option<&mut dyn Layer> {
+        Some(self)
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("MenuPopup", id = id.trace())
+    }
+}
+
+// --- MARK: IMPL LAYER
+impl Layer for MenuPopup {
+    fn capture_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        let dismiss = match event {
+            PointerEvent::Down(PointerButtonEvent { state, .. }) => {
+                let local_pos = ctx.local_position(state.position);
+                !ctx.border_box().contains(local_pos)
+            }
+            PointerEvent::Cancel(..) => true,
+            _ => false,
+        };
+
+        if dismiss {
+            // If the click opens another menu of the bar, the bar takes the focus again.
+            if let Some(id) = self.restore_focus {
+                ctx.set_focus(id);
+            }
+            // The bar removes the layer, unless it already replaced it with another menu.
+            let popup = ctx.widget_id();
+            ctx.mutate_later(self.creator, move |mut bar| {
+                MenuBar::dismiss_popup(&mut bar.downcast(), popup);
+            });
+        }
+    }
+}
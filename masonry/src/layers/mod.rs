@@ -8,8 +8,14 @@
     reason = "Widgets are not expected to implement Debug"
 )]
 
+mod calendar_popup;
+mod menu_popup;
 mod selector_menu;
+mod toast;
 mod tooltip;
 
+pub use calendar_popup::*;
+pub use menu_popup::*;
 pub use selector_menu::*;
+pub use toast::*;
 pub use tooltip::*;
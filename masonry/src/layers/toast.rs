@@ -0,0 +1,778 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use accesskit::{Live, Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::app::RenderRoot;
+use crate::core::{
+    AccessCtx, ActionCtx, ArcStr, ChildrenIds, ErasedAction, EventCtx, Layer, LayoutCtx,
+    MeasureCtx, NewWidget, PaintCtx, PointerEvent, PropertiesMut, PropertiesRef, RegisterCtx,
+    Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Affine, Axis, Point, Size};
+use crate::layout::{LayoutSize, LenDef, LenReq, Length, SizeDef};
+use crate::widgets::{Button, ButtonPress, Label};
+
+/// How long toasts are shown by default.
+const DEFAULT_DURATION: Duration = Duration::from_secs(4);
+/// The length of the entry and exit animations, in seconds.
+const ANIMATION_SECONDS: f64 = 0.2;
+/// The space between the message of a toast and its action button.
+const BUTTON_GAP: f64 = 12.;
+/// The space between stacked toasts.
+const TOAST_GAP: f64 = 8.;
+/// The space between toasts and the edges of the window.
+const WINDOW_MARGIN: f64 = 16.;
+/// The widest a toast can be.
+const MAX_TOAST_WIDTH: f64 = 360.;
+/// The most toasts shown at once. Older toasts are dismissed to make room for new ones.
+const MAX_TOASTS: usize = 5;
+
+/// The corner of the window in which a [`ToastLayer`] stacks its toasts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToastCorner {
+    /// The top left corner.
+    TopLeft,
+    /// The top right corner.
+    TopRight,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The bottom right corner.
+    #[default]
+    BottomRight,
+}
+
+impl ToastCorner {
+    fn is_top(self) -> bool {
+        matches!(self, Self::TopLeft | Self::TopRight)
+    }
+
+    fn is_left(self) -> bool {
+        matches!(self, Self::TopLeft | Self::BottomLeft)
+    }
+}
+
+/// A transient notification, with a message and an optional action button.
+///
+/// Toasts are shown in a [`ToastLayer`], which removes them once they're dismissed.
+/// A toast slides in when it's added, and is dismissed after its duration,
+/// which doesn't run while the pointer is over it.
+/// Pressing its action button also dismisses it.
+///
+/// Toasts are announced by assistive technology as live regions,
+/// [politely](Live::Polite) by default.
+///
+/// Emits [`ToastAction`], which is handled by the [`ToastLayer`].
+pub struct Toast {
+    message: WidgetPod<Label>,
+    action: Option<WidgetPod<Button>>,
+    duration: Option<Duration>,
+    live: Live,
+    /// How long the toast was shown for, in seconds, not counting the time it was hovered.
+    shown_for: f64,
+    /// The progress of the entry animation, from 0 to 1, going back to 0 when dismissed.
+    appearance: f64,
+    dismissed: bool,
+    /// Whether the toast slides in from the left of the window.
+    from_left: bool,
+}
+
+/// An action emitted by a [`Toast`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToastAction {
+    /// The action button of the toast was pressed.
+    Activated,
+    /// The toast was dismissed, and its exit animation is done.
+    Closed,
+}
+
+// --- MARK: BUILDERS
+impl Toast {
+    /// Creates a toast showing `message`.
+    pub fn new(message: impl Into<ArcStr>) -> Self {
+        Self {
+            message: Label::new(message).prepare().to_pod(),
+            action: None,
+            duration: Some(DEFAULT_DURATION),
+            live: Live::Polite,
+            shown_for: 0.,
+            appearance: 0.,
+            dismissed: false,
+            from_left: false,
+        }
+    }
+
+    /// Builder-style method to add an action button with the given text.
+    pub fn with_action(mut self, text: impl Into<ArcStr>) -> Self {
+        self.action = Some(Button::with_text(text).prepare().to_pod());
+        self
+    }
+
+    /// Builder-style method to set how long the toast is shown.
+    ///
+    /// Toasts without a duration stay until they're [dismissed](Self::dismiss)
+    /// or their action is pressed. Toasts are shown for four seconds by default.
+    pub fn with_duration(mut self, duration: Option<Duration>) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Builder-style method to set how assistive technology announces the toast.
+    ///
+    /// [`Live::Assertive`] toasts interrupt the user, and are exposed as alerts.
+    pub fn with_live(mut self, live: Live) -> Self {
+        self.live = live;
+        self
+    }
+
+    /// Returns whether the toast was dismissed, and is being removed.
+    pub fn is_dismissed(&self) -> bool {
+        self.dismissed
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Toast {
+    /// Sets the message of the toast.
+    pub fn set_message(this: &mut WidgetMut<'_, Self>, message: impl Into<ArcStr>) {
+        Label::set_text(&mut this.ctx.get_mut(&mut this.widget.message), message);
+    }
+
+    /// Dismisses the toast, which is removed after its exit animation.
+    pub fn dismiss(this: &mut WidgetMut<'_, Self>) {
+        if !this.widget.dismissed {
+            this.widget.dismissed = true;
+            this.ctx.request_anim_frame();
+        }
+    }
+
+    fn set_from_left(this: &mut WidgetMut<'_, Self>, from_left: bool) {
+        this.widget.from_left = from_left;
+        let transform = this.widget.transform(this.ctx.border_box().width());
+        this.ctx.set_transform(transform);
+    }
+}
+
+// --- MARK: INTERNALS
+impl Toast {
+    /// Returns the transform sliding the toast out of the window, as it appears.
+    fn transform(&self, width: f64) -> Affine {
+        // Ease out: the toast slows down as it reaches its place.
+        let eased = 1. - (1. - self.appearance).powi(3);
+        let distance = (1. - eased) * (width + WINDOW_MARGIN);
+        let side = if self.from_left { -1. } else { 1. };
+        Affine::translate((side * distance, 0.))
+    }
+
+    fn button_length(&mut self, ctx: &mut MeasureCtx<'_>, axis: Axis) -> Length {
+        match &mut self.action {
+            Some(button) => {
+                ctx.compute_length(button, LenDef::MaxContent, LayoutSize::NONE, axis, None)
+            }
+            None => Length::ZERO,
+        }
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Toast {
+    type Action = ToastAction;
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        let seconds = interval as f64 * 1e-9;
+        if self.dismissed {
+            self.appearance = (self.appearance - seconds / ANIMATION_SECONDS).max(0.);
+            if self.appearance == 0. {
+                ctx.submit_action::<ToastAction>(ToastAction::Closed);
+            } else {
+                ctx.request_anim_frame();
+            }
+        } else if self.appearance < 1. {
+            self.appearance = (self.appearance + seconds / ANIMATION_SECONDS).min(1.);
+            ctx.request_anim_frame();
+        } else if let Some(duration) = self.duration {
+            // The duration runs while the entry animation is done and the toast isn't hovered.
+            if !ctx.has_hovered() {
+                self.shown_for += seconds;
+                if self.shown_for >= duration.as_secs_f64() {
+                    self.dismissed = true;
+                }
+                ctx.request_anim_frame();
+            }
+        }
+        ctx.set_transform(self.transform(ctx.border_box().width()));
+    }
+
+    fn on_action(
+        &mut self,
+        ctx: &mut ActionCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        action: &ErasedAction,
+        source: WidgetId,
+    ) {
+        if self.action.as_ref().map(WidgetPod::id) != Some(source) || !action.is::<ButtonPress>() {
+            return;
+        }
+        ctx.set_handled();
+        if !self.dismissed {
+            ctx.submit_action::<ToastAction>(ToastAction::Activated);
+            self.dismissed = true;
+            ctx.request_anim_frame();
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::WidgetAdded => {
+                ctx.set_transform(self.transform(0.));
+                ctx.request_anim_frame();
+            }
+            // Resumes the duration once the pointer leaves the toast.
+            Update::HoveredChanged(false) | Update::ChildHoveredChanged(false) => {
+                ctx.request_anim_frame();
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.message);
+        if let Some(button) = &mut self.action {
+            ctx.register_child(button);
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        let button_width = self.button_length(ctx, Axis::Horizontal);
+        let other_width = if self.action.is_some() {
+            button_width.saturating_add(Length::const_px(BUTTON_GAP))
+        } else {
+            Length::ZERO
+        };
+        match axis {
+            Axis::Horizontal => {
+                let message_width = ctx.compute_length(
+                    &mut self.message,
+                    len_req.reduce(other_width).into(),
+                    LayoutSize::maybe(Axis::Vertical, cross_length),
+                    axis,
+                    cross_length,
+                );
+                message_width.saturating_add(other_width)
+            }
+            Axis::Vertical => {
+                let message_width = cross_length.map(|width| width.saturating_sub(other_width));
+                let message_height = ctx.compute_length(
+                    &mut self.message,
+                    len_req.into(),
+                    LayoutSize::maybe(Axis::Horizontal, message_width),
+                    axis,
+                    message_width,
+                );
+                message_height.max(self.button_length(ctx, Axis::Vertical))
+            }
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let mut message_space = size;
+        if let Some(button) = &mut self.action {
+            let button_size = ctx.compute_size(button, SizeDef::MAX, LayoutSize::NONE);
+            ctx.run_layout(button, button_size);
+            let origin = Point::new(
+                size.width - button_size.width,
+                (size.height - button_size.height) / 2.,
+            );
+            ctx.place_child(button, origin);
+            message_space.width = (size.width - button_size.width - BUTTON_GAP).max(0.);
+        }
+
+        let message_size = ctx.compute_size(
+            &mut self.message,
+            SizeDef::fit(message_space),
+            message_space.into(),
+        );
+        ctx.run_layout(&mut self.message, message_size);
+        let origin = Point::new(0., (size.height - message_size.height) / 2.);
+        ctx.place_child(&mut self.message, origin);
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        match self.live {
+            Live::Assertive => Role::Alert,
+            _ => Role::Status,
+        }
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_live(self.live);
+        node.set_live_atomic();
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        let mut ids = ChildrenIds::from_slice(&[self.message.id()]);
+        ids.extend(self.action.as_ref().map(WidgetPod::id));
+        ids
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Toast", id = id.trace())
+    }
+}
+
+/// A [`Layer`] covering the window, which stacks [`Toast`]s in one of its corners.
+///
+/// The newest toast is the closest to the corner. Toasts are removed from the layer
+/// once they're dismissed, and the oldest toasts are dismissed when more than five are shown.
+/// The layer doesn't take pointer events outside of its toasts.
+///
+/// Use [`ToastLayer::show`] to show a toast in the window of a [`RenderRoot`].
+///
+/// Emits [`ToastActivated`] when the action button of one of its toasts is pressed.
+#[derive(Default)]
+pub struct ToastLayer {
+    corner: ToastCorner,
+    toasts: Vec<WidgetPod<Toast>>,
+}
+
+/// The action button of a [`Toast`] in a [`ToastLayer`] was pressed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ToastActivated {
+    /// The id of the toast.
+    pub toast: WidgetId,
+}
+
+// --- MARK: BUILDERS
+impl ToastLayer {
+    /// Creates an empty layer stacking toasts in `corner`.
+    pub fn new(corner: ToastCorner) -> Self {
+        Self {
+            corner,
+            toasts: Vec::new(),
+        }
+    }
+
+    /// Builder-style method to add a toast to the layer.
+    pub fn with_toast(mut self, mut toast: NewWidget<Toast>) -> Self {
+        toast.widget.from_left = self.corner.is_left();
+        self.toasts.push(toast.to_pod());
+        self
+    }
+
+    /// Shows `toast` in the window of `render_root`, and returns its id.
+    ///
+    /// The toast is added to the first `ToastLayer` of the window.
+    /// If there is none, a layer stacking toasts in the [default corner](ToastCorner::default)
+    /// is added. To use another corner, add the layer with [`RenderRoot::add_layer`]
+    /// before showing toasts.
+    pub fn show(render_root: &mut RenderRoot, toast: NewWidget<Toast>) -> WidgetId {
+        let id = toast.id();
+        match Self::find(render_root) {
+            Some(layer) => render_root.edit_widget(layer, |mut layer| {
+                Self::push(&mut layer.downcast(), toast);
+            }),
+            None => {
+                let layer = Self::default().with_toast(toast);
+                render_root.add_layer(layer.prepare(), Point::ORIGIN);
+            }
+        }
+        id
+    }
+
+    /// Returns the id of the first `ToastLayer` in the window of `render_root`, if any.
+    pub fn find(render_root: &RenderRoot) -> Option<WidgetId> {
+        render_root.find_layer::<Self>().map(|layer| layer.id())
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl ToastLayer {
+    /// Adds a toast to the layer.
+    pub fn push(this: &mut WidgetMut<'_, Self>, mut toast: NewWidget<Toast>) {
+        toast.widget.from_left = this.widget.corner.is_left();
+        this.widget.toasts.push(toast.to_pod());
+        this.ctx.children_changed();
+
+        let shown = this.widget.toasts.len();
+        for toast in this
+            .widget
+            .toasts
+            .iter_mut()
+            .take(shown.saturating_sub(MAX_TOASTS))
+        {
+            Toast::dismiss(&mut this.ctx.get_mut(toast));
+        }
+    }
+
+    /// Sets the corner in which toasts are stacked.
+    pub fn set_corner(this: &mut WidgetMut<'_, Self>, corner: ToastCorner) {
+        if this.widget.corner == corner {
+            return;
+        }
+        this.widget.corner = corner;
+        for toast in &mut this.widget.toasts {
+            Toast::set_from_left(&mut this.ctx.get_mut(toast), corner.is_left());
+        }
+        this.ctx.request_layout();
+    }
+
+    /// Dismisses all the toasts of the layer.
+    pub fn dismiss_all(this: &mut WidgetMut<'_, Self>) {
+        for toast in &mut this.widget.toasts {
+            Toast::dismiss(&mut this.ctx.get_mut(toast));
+        }
+    }
+
+    /// Returns the ids of the toasts in the layer, from the oldest to the newest.
+    pub fn toast_ids(&self) -> impl Iterator<Item = WidgetId> + '_ {
+        self.toasts.iter().map(WidgetPod::id)
+    }
+
+    /// Returns the number of toasts in the layer, including those being dismissed.
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// Returns `true` if the layer has no toasts.
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for ToastLayer {
+    type Action = ToastActivated;
+
+    fn on_action(
+        &mut self,
+        ctx: &mut ActionCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        action: &ErasedAction,
+        source: WidgetId,
+    ) {
+        let Some(action) = action.downcast_ref::<ToastAction>() else {
+            return;
+        };
+        let Some(idx) = self.toasts.iter().position(|toast| toast.id() == source) else {
+            return;
+        };
+        match action {
+            ToastAction::Activated => {
+                ctx.submit_action::<ToastActivated>(ToastActivated { toast: source });
+            }
+            ToastAction::Closed => {
+                let toast = self.toasts.remove(idx);
+                ctx.remove_child(toast);
+                ctx.request_layout();
+            }
+        }
+        ctx.set_handled();
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for toast in &mut self.toasts {
+            ctx.register_child(toast);
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        _cross_length: Option<Length>,
+    ) -> Length {
+        // The layer covers the window, whose size is given as the context size.
+        match (ctx.context_size().length(axis), len_req) {
+            (Some(length), _) | (None, LenReq::FitContent(length)) => length,
+            (None, _) => Length::ZERO,
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let max_width = MAX_TOAST_WIDTH.min(size.width - WINDOW_MARGIN * 2.).max(0.);
+        let space = Size::new(max_width, size.height);
+        let mut y = WINDOW_MARGIN;
+        // The newest toast is the closest to the corner.
+        for toast in self.toasts.iter_mut().rev() {
+            let toast_size = ctx.compute_size(toast, SizeDef::fit(space), space.into());
+            ctx.run_layout(toast, toast_size);
+            let x = if self.corner.is_left() {
+                WINDOW_MARGIN
+            } else {
+                size.width - WINDOW_MARGIN - toast_size.width
+            };
+            let top = if self.corner.is_top() {
+                y
+            } else {
+                size.height - y - toast_size.height
+            };
+            ctx.place_child(toast, Point::new(x, top));
+            y += toast_size.height + TOAST_GAP;
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.toasts.iter().map(WidgetPod::id).collect()
+    }
+
+    fn as_layer(&mut self) -> Option<&mut dyn Layer> {
+        Some(self)
+    }
+
+    fn accepts_pointer_interaction(&self) -> bool {
+        false
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("ToastLayer", id = id.trace())
+    }
+}
+
+// --- MARK: IMPL LAYER
+impl Layer for ToastLayer {
+    fn capture_pointer_event(
+        &mut self,
+        _ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+
+    fn toast_ids(harness: &TestHarness<ToastLayer>) -> Vec<WidgetId> {
+        harness
+            .root_widget()
+            .inner()
+            .toasts
+            .iter()
+            .map(WidgetPod::id)
+            .collect()
+    }
+
+    fn toast_rect(harness: &TestHarness<ToastLayer>, id: WidgetId) -> crate::kurbo::Rect {
+        let toast = harness.get_widget_with_id(id);
+        toast
+            .ctx()
+            .window_transform()
+            .transform_rect_bbox(toast.ctx().border_box())
+    }
+
+    #[test]
+    fn stacked_toasts() {
+        let layer = ToastLayer::new(ToastCorner::BottomRight)
+            .with_toast(NewWidget::new(Toast::new("File saved")))
+            .with_toast(NewWidget::new(
+                Toast::new("Connection lost").with_action("Retry"),
+            ));
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(layer), (400, 300));
+        harness.animate_ms(500);
+
+        assert_render_snapshot!(harness, "toast_layer_stacked_toasts");
+    }
+
+    #[test]
+    fn toasts_stack_in_corner() {
+        let layer = ToastLayer::new(ToastCorner::BottomRight)
+            .with_toast(NewWidget::new(Toast::new("First")))
+            .with_toast(NewWidget::new(Toast::new("Second")));
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(layer), (400, 300));
+        harness.animate_ms(500);
+        let [first, second] = toast_ids(&harness)[..] else {
+            panic!("expected two toasts");
+        };
+
+        let first = toast_rect(&harness, first);
+        let second = toast_rect(&harness, second);
+        // The newest toast is the closest to the corner.
+        assert_eq!(second.x1, 400. - WINDOW_MARGIN);
+        assert_eq!(second.y1, 300. - WINDOW_MARGIN);
+        assert_eq!(first.y1, second.y0 - TOAST_GAP);
+        assert!(first.width() <= MAX_TOAST_WIDTH);
+    }
+
+    #[test]
+    fn toasts_slide_in() {
+        let layer =
+            ToastLayer::new(ToastCorner::TopLeft).with_toast(NewWidget::new(Toast::new("Hello")));
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(layer), (400, 300));
+        let id = toast_ids(&harness)[0];
+
+        assert!(toast_rect(&harness, id).x1 <= 0.);
+        harness.animate_ms(100);
+        let halfway = toast_rect(&harness, id).x0;
+        assert!(halfway < WINDOW_MARGIN);
+        harness.animate_ms(100);
+        assert_eq!(
+            toast_rect(&harness, id).origin(),
+            (WINDOW_MARGIN, WINDOW_MARGIN).into()
+        );
+    }
+
+    #[test]
+    fn toasts_dismiss_after_duration() {
+        let toast = Toast::new("Saved").with_duration(Some(Duration::from_secs(1)));
+        let layer = ToastLayer::default().with_toast(NewWidget::new(toast));
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(layer), (400, 300));
+
+        // Entry animation, then most of the duration.
+        harness.animate_ms(200);
+        harness.animate_ms(900);
+        assert_eq!(harness.root_widget().inner().len(), 1);
+        // End of the duration, then exit animation.
+        harness.animate_ms(100);
+        harness.animate_ms(200);
+        assert!(harness.root_widget().inner().is_empty());
+    }
+
+    #[test]
+    fn hover_pauses_dismissal() {
+        let toast = Toast::new("Saved").with_duration(Some(Duration::from_secs(1)));
+        let layer = ToastLayer::default().with_toast(NewWidget::new(toast));
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(layer), (400, 300));
+        let id = toast_ids(&harness)[0];
+        harness.animate_ms(200);
+
+        harness.mouse_move_to(id);
+        for _ in 0..10 {
+            harness.animate_ms(500);
+        }
+        assert_eq!(harness.root_widget().inner().len(), 1);
+
+        harness.mouse_move((0., 0.));
+        harness.animate_ms(1000);
+        harness.animate_ms(200);
+        assert!(harness.root_widget().inner().is_empty());
+    }
+
+    #[test]
+    fn action_button_activates_toast() {
+        let toast = Toast::new("Deleted")
+            .with_action("Undo")
+            .with_duration(None);
+        let layer = ToastLayer::default().with_toast(NewWidget::new(toast));
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(layer), (400, 300));
+        harness.animate_ms(200);
+        let id = toast_ids(&harness)[0];
+        let button = harness
+            .get_widget_with_id(id)
+            .downcast::<Toast>()
+            .unwrap()
+            .inner()
+            .action
+            .as_ref()
+            .map(WidgetPod::id)
+            .unwrap();
+
+        harness.mouse_click_on(button, None);
+        assert_eq!(
+            harness.pop_action::<ToastActivated>(),
+            Some((ToastActivated { toast: id }, harness.root_id()))
+        );
+        assert!(harness.pop_action_erased().is_none());
+        harness.animate_ms(200);
+        assert!(harness.root_widget().inner().is_empty());
+    }
+
+    #[test]
+    fn oldest_toasts_are_dismissed() {
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            NewWidget::new(ToastLayer::default()),
+            (400, 300),
+        );
+        for i in 0..MAX_TOASTS + 2 {
+            harness.edit_root_widget(|mut layer| {
+                ToastLayer::push(&mut layer, NewWidget::new(Toast::new(format!("Toast {i}"))));
+            });
+        }
+        harness.animate_ms(100);
+        harness.animate_ms(100);
+        assert_eq!(harness.root_widget().inner().len(), MAX_TOASTS);
+    }
+
+    #[test]
+    fn toasts_are_live_regions() {
+        let layer = ToastLayer::default()
+            .with_toast(NewWidget::new(Toast::new("Saved")))
+            .with_toast(NewWidget::new(
+                Toast::new("Failed").with_live(Live::Assertive),
+            ));
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(layer), (400, 300));
+        harness.redraw();
+        let [polite, assertive] = toast_ids(&harness)[..] else {
+            panic!("expected two toasts");
+        };
+
+        let node = harness.access_node(polite).unwrap();
+        assert_eq!(node.role(), Role::Status);
+        assert_eq!(node.live(), Live::Polite);
+        let node = harness.access_node(assertive).unwrap();
+        assert_eq!(node.role(), Role::Alert);
+        assert_eq!(node.live(), Live::Assertive);
+    }
+}
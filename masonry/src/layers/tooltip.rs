@@ -16,8 +16,12 @@ use crate::kurbo::{Axis, Size};
 use crate::layout::{LayoutSize, LenReq, Length, SizeDef};
 
 /// A [`Layer`] representing a simple tooltip showing some content until the mouse moves.
+///
+/// Tooltips which [aren't dismissed by the pointer](Self::with_dismiss_on_pointer)
+/// stay until they're removed by the widget which created them.
 pub struct Tooltip {
     child: WidgetPod<dyn Widget>,
+    dismiss_on_pointer: bool,
 }
 
 // --- MARK: BUILDERS
@@ -26,8 +30,18 @@ impl Tooltip {
     pub fn new(child: NewWidget<impl Widget + ?Sized>) -> Self {
         Self {
             child: child.erased().to_pod(),
+            dismiss_on_pointer: true,
         }
     }
+
+    /// Builder-style method to set whether the tooltip is removed when the pointer
+    /// moves, is pressed or released, or leaves the window.
+    ///
+    /// This is true by default.
+    pub fn with_dismiss_on_pointer(mut self, dismiss: bool) -> Self {
+        self.dismiss_on_pointer = dismiss;
+        self
+    }
 }
 
 // --- MARK: WIDGETMUT
@@ -163,13 +177,14 @@ impl Layer for Tooltip {
         _props: &mut PropertiesMut<'_>,
         event: &PointerEvent,
     ) {
-        let remove_tooltip = matches!(
-            event,
-            PointerEvent::Down(_)
-                | PointerEvent::Up(_)
-                | PointerEvent::Move(_)
-                | PointerEvent::Leave(_)
-        );
+        let remove_tooltip = self.dismiss_on_pointer
+            && matches!(
+                event,
+                PointerEvent::Down(_)
+                    | PointerEvent::Up(_)
+                    | PointerEvent::Move(_)
+                    | PointerEvent::Leave(_)
+            );
 
         if remove_tooltip {
             ctx.remove_layer(ctx.widget_id());
@@ -157,6 +157,11 @@
 //! - `tracy`: Enables creating output for the [Tracy](https://github.com/wolfpld/tracy) profiler using [`tracing-tracy`][tracing_tracy].
 //!   This can be used by installing Tracy and connecting to a Masonry with this feature enabled.
 //! - `testing`: Re-exports the test harness from [Masonry Testing][masonry_testing].
+//! - `software`: Renders on the CPU and presents frames with `softbuffer` when no compatible GPU
+//!   is found, or when the `MASONRY_RENDERER` environment variable is set to `software`.
+//! - `interaction_script`: Records input events to a replayable script when the `MASONRY_RECORD_SCRIPT`
+//!   environment variable is set to a file path, with the F9 key marking snapshot points.
+//!   Scripts can be replayed in tests with `TestHarness::replay_script`.
 //!
 //! # Debugging features
 //!
@@ -164,6 +169,8 @@
 //!
 //! - A rudimentary widget inspector - toggled by the F11 key.
 //! - A debug mode painting widget layout rectangles - toggled by the F12 key.
+//! - A layout debugger overlay showing box models, baselines, clips and the properties of the hovered widget - toggled by Shift+F12.
+//! - An out-of-process inspector protocol, to inspect and edit the widget tree from another process - enabled by the `inspector` feature and the `MASONRY_INSPECTOR` environment variable.
 //! - Optional automatic registration of a [tracing] subscriber, which outputs to the console and to a file in the dev profile.
 //!
 //! If you want to use your own subscriber, simply set it before starting masonry - in this case masonry will not set a subscriber.
@@ -224,3 +231,4 @@ pub use masonry_core::{app, core, dpi, kurbo, layout, parley, peniko, ui_events,
 #[cfg(any(feature = "testing", test))]
 pub use masonry_testing as testing;
 pub use parley::{Alignment as TextAlign, AlignmentOptions as TextAlignOptions};
+pub use time;
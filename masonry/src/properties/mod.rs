@@ -7,7 +7,6 @@
 
 mod animation;
 mod checkmark;
-mod content_color;
 mod gap;
 mod line_breaking;
 mod object_fit;
@@ -24,7 +23,6 @@ pub mod types;
 
 pub use animation::*;
 pub use checkmark::*;
-pub use content_color::*;
 pub use gap::*;
 pub use line_breaking::*;
 pub use object_fit::*;
@@ -33,6 +33,41 @@ pub enum MainAxisAlignment {
     SpaceAround,
 }
 
+/// Arrangement of lines on a wrapping container's cross axis.
+///
+/// If there is surplus space on the cross axis after laying out all the lines,
+/// this enum represents how those lines are positioned in this space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineAlignment {
+    /// Lines are packed flush to each other at the start edge of the container's cross axis.
+    Start,
+    /// Lines are packed flush to each other at the center of the container's cross axis.
+    Center,
+    /// Lines are packed flush to each other at the end edge of the container's cross axis.
+    End,
+    /// Lines are evenly distributed on the container's cross axis.
+    ///
+    /// The space between each pair of lines is the same.
+    /// The first line is flush with the start edge,
+    /// and the last line is flush with the end edge.
+    SpaceBetween,
+    /// Lines are evenly distributed on the container's cross axis.
+    ///
+    /// The space between each pair of lines, the start edge and the first line,
+    /// and the end edge and the last line, are all exactly the same.
+    SpaceEvenly,
+    /// Lines are evenly distributed on the container's cross axis.
+    ///
+    /// The space between each pair of lines is the same.
+    /// The space before the first and after the last line
+    /// equals half of the space between each pair of lines.
+    /// If there is only one line, it will be centered.
+    SpaceAround,
+    /// Lines are packed flush to each other and the surplus space is divided equally
+    /// among them, growing every line's cross length.
+    Stretch,
+}
+
 /// Alignment of widgets on a container's cross axis.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CrossAxisAlignment {
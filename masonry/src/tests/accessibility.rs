@@ -1,13 +1,19 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use accesskit::{Action, Role};
 use accesskit_consumer::NodeId;
 use assert_matches::assert_matches;
-use masonry_testing::{ModularWidget, Record, TestHarness, TestWidgetExt, assert_any, assert_none};
-
-use crate::core::{NewWidget, Widget, WidgetTag};
+use masonry_testing::{
+    AccessibilityIssue, ModularWidget, Record, TestHarness, TestWidgetExt, assert_any, assert_none,
+};
+
+use crate::core::{NewWidget, PropertySet, Widget, WidgetTag};
+use crate::palette::css::{DIM_GRAY, GOLD};
+use crate::peniko::{ImageAlphaType, ImageData, ImageFormat};
+use crate::properties::ContentColor;
 use crate::theme::test_property_set;
-use crate::widgets::SizedBox;
+use crate::widgets::{Button, ChildAlignment, Flex, Image, Label, SizedBox, ZStack};
 
 #[test]
 fn request_accessibility() {
@@ -89,3 +95,131 @@ fn node_local_id_to_u64(node_id: NodeId) -> u64 {
     let node_id: u128 = node_id.into();
     (node_id >> 64) as u64
 }
+
+fn red_image() -> ImageData {
+    ImageData {
+        data: [255, 0, 0, 255].repeat(4).into(),
+        format: ImageFormat::Rgba8,
+        alpha_type: ImageAlphaType::Alpha,
+        width: 2,
+        height: 2,
+    }
+}
+
+#[test]
+fn audit_accepts_labelled_widgets() {
+    let widget = Flex::column()
+        .with_fixed(NewWidget::new(Label::new("Name")))
+        .with_fixed(NewWidget::new(Button::with_text("OK")))
+        .with_fixed(NewWidget::new(Button::with_text("Cancel")))
+        .with_fixed(NewWidget::new(
+            Image::new(red_image()).with_alt_text("A red square"),
+        ))
+        .with_fixed(NewWidget::new(Image::new(red_image()).decorative(true)));
+
+    let mut harness = TestHarness::create(test_property_set(), NewWidget::new(widget));
+    harness.assert_accessible();
+}
+
+#[test]
+fn audit_finds_missing_labels_and_alt_text() {
+    let button_tag = WidgetTag::named("button");
+    let image_tag = WidgetTag::named("image");
+    let widget = Flex::column()
+        .with_fixed(
+            NewWidget::new(Button::new(NewWidget::new(SizedBox::empty()))).with_tag(button_tag),
+        )
+        .with_fixed(NewWidget::new(Image::new(red_image())).with_tag(image_tag));
+
+    let mut harness = TestHarness::create(test_property_set(), NewWidget::new(widget));
+    let button_id = harness.get_widget(button_tag).id();
+    let image_id = harness.get_widget(image_tag).id();
+    assert_eq!(
+        harness.accessibility_audit(),
+        [
+            AccessibilityIssue::MissingLabel { widget: button_id },
+            AccessibilityIssue::MissingAltText { widget: image_id },
+        ]
+    );
+}
+
+#[test]
+fn audit_finds_widgets_unreachable_by_tab() {
+    let unreachable_tag = WidgetTag::named("unreachable");
+    let button_tag = WidgetTag::named("button");
+    // This widget tells assistive technologies it can be focused, but doesn't accept focus.
+    let unreachable =
+        ModularWidget::new(())
+            .role_fn(|_| Role::Button)
+            .access_fn(|_, _, _, node| {
+                node.set_label("Unreachable");
+                node.add_action(Action::Focus);
+            });
+    let widget = Flex::column()
+        .with_fixed(NewWidget::new(unreachable).with_tag(unreachable_tag))
+        .with_fixed(NewWidget::new(Button::with_text("OK")).with_tag(button_tag));
+
+    let mut harness = TestHarness::create(test_property_set(), NewWidget::new(widget));
+    let unreachable_id = harness.get_widget(unreachable_tag).id();
+    let button_id = harness.get_widget(button_tag).id();
+    harness.focus_on(Some(button_id));
+    assert_eq!(
+        harness.accessibility_audit(),
+        [AccessibilityIssue::NotReachableByTab {
+            widget: unreachable_id
+        }]
+    );
+    // The audit doesn't move the focus.
+    assert_eq!(harness.focused_widget_id(), Some(button_id));
+}
+
+#[test]
+fn audit_finds_low_contrast_text() {
+    let dim_tag = WidgetTag::named("dim");
+    let widget = Flex::column()
+        .with_fixed(
+            NewWidget::new(Label::new("Hard to read"))
+                .with_props(PropertySet::new().with(ContentColor::new(DIM_GRAY)))
+                .with_tag(dim_tag),
+        )
+        .with_fixed(NewWidget::new(Label::new("Easy to read")));
+
+    let mut harness = TestHarness::create(test_property_set(), NewWidget::new(widget));
+    let dim_id = harness.get_widget(dim_tag).id();
+    let issues = harness.accessibility_audit();
+    assert_matches!(
+        issues[..],
+        [AccessibilityIssue::LowContrast { widget, ratio }] if widget == dim_id && ratio < 4.5
+    );
+
+    harness.edit_widget(dim_tag, |mut label| {
+        label.insert_prop(ContentColor::new(GOLD));
+    });
+    harness.assert_accessible();
+}
+
+#[test]
+fn audit_finds_overlapping_hit_targets() {
+    let first_tag = WidgetTag::named("first");
+    let second_tag = WidgetTag::named("second");
+    let widget = ZStack::new()
+        .with(
+            NewWidget::new(Button::with_text("First")).with_tag(first_tag),
+            ChildAlignment::ParentAligned,
+        )
+        .with(
+            NewWidget::new(Button::with_text("Second")).with_tag(second_tag),
+            ChildAlignment::ParentAligned,
+        );
+
+    let mut harness = TestHarness::create(test_property_set(), NewWidget::new(widget));
+    let first_id = harness.get_widget(first_tag).id();
+    let second_id = harness.get_widget(second_tag).id();
+    assert_eq!(
+        harness.accessibility_audit(),
+        [AccessibilityIssue::OverlappingHitTargets {
+            widget: first_id,
+            other: second_id,
+        }]
+    );
+}
@@ -0,0 +1,96 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use crate::app::RenderRoot;
+use crate::core::{NewWidget, WidgetId};
+use crate::tests::paint::create_render_root;
+use crate::widgets::{Flex, Label};
+
+fn create_profiled_root() -> (RenderRoot, [WidgetId; 3]) {
+    let first = NewWidget::new(Label::new("A"));
+    let second = NewWidget::new(Label::new("B"));
+    let ids = [first.id(), second.id()];
+    let flex = NewWidget::new(Flex::row().with_fixed(first).with_fixed(second));
+    let flex_id = flex.id();
+
+    let mut root = create_render_root(flex);
+    root.set_frame_profiling(true);
+    let _ = root.redraw();
+    (root, [flex_id, ids[0], ids[1]])
+}
+
+#[test]
+fn timing_is_disabled_by_default() {
+    let mut root = create_render_root(NewWidget::new(Label::new("A")));
+    let _ = root.redraw();
+    assert!(root.last_frame_timings().is_empty());
+
+    root.set_frame_timing(true);
+    let _ = root.redraw();
+    let paint = root.last_frame_timings().iter().find(|t| t.name == "paint");
+    assert!(paint.is_some());
+}
+
+#[test]
+fn profiling_is_disabled_by_default() {
+    let mut root = create_render_root(NewWidget::new(Label::new("A")));
+    root.set_frame_timing(true);
+    let _ = root.redraw();
+
+    assert!(root.last_frame_profile().is_none());
+    let paint = root.last_frame_timings().iter().find(|t| t.name == "paint");
+    assert_eq!(paint.unwrap().widgets_visited, 0);
+}
+
+#[test]
+fn profile_counts_widget_visits() {
+    let (root, ids) = create_profiled_root();
+    let profile = root.last_frame_profile().unwrap();
+
+    assert_eq!(profile.passes, root.last_frame_timings());
+    assert!(profile.pass("paint").unwrap().widgets_visited >= ids.len());
+    for id in ids {
+        let paint = profile
+            .widgets
+            .iter()
+            .find(|widget| widget.id == id && widget.pass == "paint");
+        assert_eq!(paint.unwrap().visits, 1);
+    }
+
+    let top = profile.most_expensive_widgets(2);
+    assert_eq!(top.len(), 2);
+    assert!(top[0].duration >= top[1].duration);
+    assert_eq!(
+        profile.most_expensive_widgets(1000).len(),
+        profile.widgets.len()
+    );
+}
+
+#[test]
+fn profile_exports_chrome_trace() {
+    let (root, ids) = create_profiled_root();
+    let trace = root.last_frame_profile().unwrap().to_chrome_trace();
+
+    let json: serde_json::Value = serde_json::from_str(&trace).unwrap();
+    let events = json["traceEvents"].as_array().unwrap();
+    assert!(events.iter().any(|event| event["name"] == "paint"));
+    let label = events
+        .iter()
+        .find(|event| event["args"]["widget_id"] == ids[1].to_raw())
+        .unwrap();
+    assert_eq!(label["name"], "Label");
+    assert_eq!(label["ph"], "X");
+}
+
+#[test]
+fn profiling_can_be_disabled() {
+    let (mut root, _) = create_profiled_root();
+    // A frame over budget only logs a warning.
+    root.set_frame_budget(Some(Duration::ZERO));
+    root.set_frame_profiling(false);
+    let _ = root.redraw();
+
+    assert!(root.last_frame_profile().is_none());
+}
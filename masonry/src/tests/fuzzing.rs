@@ -0,0 +1,143 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::core::{CollectionWidget, NewWidget, PointerEvent};
+use crate::kurbo::Axis;
+use crate::layout::AsUnit;
+use crate::testing::{ModularWidget, WidgetFuzzer};
+use crate::theme::test_property_set;
+use crate::widgets::{
+    Button, Checkbox, ChildAlignment, Divider, Flex, Label, Portal, ProgressBar, SizedBox, Slider,
+    Spinner, Switch, TextArea, TextInput, ZStack,
+};
+
+const TEXTS: &[&str] = &["", "Hello", "A much longer text which will need to wrap"];
+
+/// A fuzzer covering the most common widgets of Masonry.
+fn widget_fuzzer() -> WidgetFuzzer {
+    WidgetFuzzer::new()
+        .with_leaf("Label", |input| {
+            NewWidget::new(Label::new(*input.pick(TEXTS)))
+        })
+        .with_leaf("Button", |input| {
+            NewWidget::new(Button::with_text(*input.pick(TEXTS)))
+        })
+        .with_leaf("Checkbox", |input| {
+            NewWidget::new(Checkbox::new(input.bool(), *input.pick(TEXTS)))
+        })
+        .with_leaf("Switch", |input| NewWidget::new(Switch::new(input.bool())))
+        .with_leaf("Slider", |input| {
+            NewWidget::new(Slider::new(0., 10., input.f64_between(0., 10.)))
+        })
+        .with_leaf("ProgressBar", |input| {
+            let progress = input.bool().then(|| input.f64_between(0., 1.));
+            NewWidget::new(ProgressBar::new(progress))
+        })
+        .with_leaf("Spinner", |_| NewWidget::new(Spinner::new()))
+        .with_leaf("Divider", |input| {
+            NewWidget::new(Divider::new(
+                *input.pick(&[Axis::Horizontal, Axis::Vertical]),
+            ))
+        })
+        .with_leaf("TextArea", |input| {
+            let text = *input.pick(TEXTS);
+            NewWidget::new(TextArea::new_editable(text))
+        })
+        .with_leaf("TextInput", |input| {
+            let text = *input.pick(TEXTS);
+            NewWidget::new(TextInput::new(text))
+        })
+        .with_container("Flex", 4, |input, children| {
+            let axis = *input.pick(&[Axis::Horizontal, Axis::Vertical]);
+            let mut flex = Flex::for_axis(axis);
+            for child in children {
+                flex = if input.bool() {
+                    flex.with(child, 1.)
+                } else {
+                    flex.with_fixed(child)
+                };
+            }
+            NewWidget::new(flex)
+        })
+        .with_container("ZStack", 3, |_, children| {
+            let zstack = children.into_iter().fold(ZStack::new(), |zstack, child| {
+                zstack.with(child, ChildAlignment::ParentAligned)
+            });
+            NewWidget::new(zstack)
+        })
+        .with_container("SizedBox", 1, |input, children| {
+            let sized_box = match children.into_iter().next() {
+                Some(child) => SizedBox::new(child),
+                None => SizedBox::empty(),
+            };
+            let width = input.f64_between(0., 300.);
+            NewWidget::new(sized_box.width(width.px()))
+        })
+        .with_container("Portal", 1, |_, children| {
+            let child = children
+                .into_iter()
+                .next()
+                .unwrap_or_else(|| NewWidget::new(Label::new("Empty")).erased());
+            NewWidget::new(Portal::new(child))
+        })
+        .with_edit::<Label>("set text", |input, mut label| {
+            Label::set_text(&mut label, *input.pick(TEXTS));
+        })
+        .with_edit::<Checkbox>("toggle", |input, mut checkbox| {
+            Checkbox::set_checked(&mut checkbox, input.bool());
+        })
+        .with_edit::<Slider>("set range", |input, mut slider| {
+            let max = input.f64_between(1., 100.);
+            Slider::set_range(&mut slider, 0., max);
+        })
+        .with_edit::<TextArea<true>>("reset text", |input, mut text_area| {
+            let text = *input.pick(TEXTS);
+            TextArea::reset_text(&mut text_area, text);
+        })
+        .with_edit::<SizedBox>("set height", |input, mut sized_box| {
+            SizedBox::set_height(&mut sized_box, input.f64_between(0., 300.).px());
+        })
+        .with_edit::<SizedBox>("remove child", |_, mut sized_box| {
+            SizedBox::remove_child(&mut sized_box);
+        })
+        .with_edit::<Flex>("add child", |input, mut flex| {
+            let label = NewWidget::new(Label::new(*input.pick(TEXTS)));
+            Flex::add_fixed(&mut flex, label);
+        })
+        .with_edit::<Flex>("remove child", |input, mut flex| {
+            if !flex.widget.is_empty() {
+                let idx = input.below(flex.widget.len());
+                Flex::remove(&mut flex, idx);
+            }
+        })
+        .with_edit::<Flex>("swap children", |input, mut flex| {
+            let len = flex.widget.len();
+            if len > 1 {
+                let (a, b) = (input.below(len), input.below(len));
+                Flex::swap(&mut flex, a, b);
+            }
+        })
+}
+
+#[test]
+fn fuzz_widget_trees() {
+    widget_fuzzer().run(test_property_set);
+}
+
+#[test]
+#[should_panic(expected = "Shrunk case")]
+fn fuzzer_finds_failing_widgets() {
+    WidgetFuzzer::new()
+        .with_leaf("Fragile", |_| {
+            let widget = ModularWidget::new(())
+                .measure_fn(|_, _, _, _, _, _| 50.px())
+                .pointer_event_fn(|_, _, _, event| {
+                    assert!(
+                        !matches!(event, PointerEvent::Down(..)),
+                        "Fragile widget was clicked"
+                    );
+                });
+            NewWidget::new(widget)
+        })
+        .run(test_property_set);
+}
@@ -0,0 +1,204 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+
+use assert_matches::assert_matches;
+
+use crate::app::inspector::{InspectorRequest, InspectorResponse, InspectorServer, WidgetNode};
+use crate::core::{NewWidget, Widget as _, WidgetTag};
+use crate::layout::Length;
+use crate::palette::css::RED;
+use crate::properties::{Background, Padding};
+use crate::testing::TestHarness;
+use crate::theme::test_property_set;
+use crate::util::{Duration, Instant};
+use crate::widgets::{Button, Flex, Label};
+
+fn find_node(node: &WidgetNode, id: u64) -> Option<&WidgetNode> {
+    if node.id == id {
+        return Some(node);
+    }
+    node.children.iter().find_map(|child| find_node(child, id))
+}
+
+fn get_tree(harness: &mut TestHarness<Flex>) -> WidgetNode {
+    match harness.inspector_request(InspectorRequest::GetTree) {
+        InspectorResponse::Tree { root } => root,
+        response => panic!("unexpected response {response:?}"),
+    }
+}
+
+#[test]
+fn inspector_tree_describes_widgets() {
+    let label_tag = WidgetTag::named("label");
+    let button_tag = WidgetTag::named("button");
+    let widget = Flex::column()
+        .with_fixed(
+            NewWidget::new(Label::new("Hello"))
+                .with_tag(label_tag)
+                .with_class("greeting"),
+        )
+        .with_fixed(NewWidget::new(Button::with_text("Click")).with_tag(button_tag));
+
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), widget.prepare(), (200., 200.));
+    let root = get_tree(&mut harness);
+
+    let label = harness.get_widget(label_tag);
+    let label_id = label.id().to_raw();
+    let label_rect = label
+        .ctx()
+        .window_transform()
+        .transform_rect_bbox(label.ctx().border_box());
+    let node = find_node(&root, label_id).unwrap();
+    assert_eq!(node.name, "Label");
+    assert_eq!(
+        node.border_box,
+        [label_rect.x0, label_rect.y0, label_rect.x1, label_rect.y1]
+    );
+    assert_eq!(node.classes, ["greeting"]);
+    let access = node.accessibility.as_ref().unwrap();
+    assert_eq!(access.role, "Label");
+    assert!(access.states.is_empty());
+    assert_eq!(node.debug_text.as_deref(), Some("Hello"));
+    assert!(node.properties.iter().any(|(name, _)| name == "Padding"));
+    assert!(!node.disabled);
+
+    let button_id = harness.get_widget(button_tag).id().to_raw();
+    let node = find_node(&root, button_id).unwrap();
+    assert_eq!(node.name, "Button");
+    assert_eq!(node.children.len(), 1);
+    let access = node.accessibility.as_ref().unwrap();
+    assert_eq!(access.role, "Button");
+    assert!(access.states.iter().any(|state| state == "focusable"));
+}
+
+#[test]
+fn inspector_edits_widgets() {
+    let label_tag = WidgetTag::named("label");
+    let widget = Flex::column().with_fixed(NewWidget::new(Label::new("Hello")).with_tag(label_tag));
+
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), widget.prepare(), (200., 200.));
+    let label_id = harness.get_widget(label_tag).id().to_raw();
+
+    let response = harness.inspector_request(InspectorRequest::SetDisabled {
+        widget: label_id,
+        disabled: true,
+    });
+    assert_eq!(response, InspectorResponse::Done);
+    assert!(harness.get_widget(label_tag).ctx().is_disabled());
+    let root = get_tree(&mut harness);
+    let node = find_node(&root, label_id).unwrap();
+    assert!(node.disabled);
+    let access = node.accessibility.as_ref().unwrap();
+    assert!(access.states.iter().any(|state| state == "disabled"));
+
+    let response = harness.inspector_request(InspectorRequest::SetProperty {
+        widget: label_id,
+        property: "Padding".into(),
+        value: serde_json::json!({ "left": 4.0, "top": 2.0 }),
+    });
+    assert_eq!(response, InspectorResponse::Done);
+    let padding = *harness.get_widget(label_tag).get_prop::<Padding>();
+    assert_eq!(padding.left, Length::px(4.));
+    assert_eq!(padding.top, Length::px(2.));
+    assert_eq!(padding.right, Length::ZERO);
+
+    let response = harness.inspector_request(InspectorRequest::SetProperty {
+        widget: label_id,
+        property: "Background".into(),
+        value: "red".into(),
+    });
+    assert_eq!(response, InspectorResponse::Done);
+    assert_eq!(
+        harness.get_widget(label_tag).get_prop::<Background>(),
+        &Background::Color(RED)
+    );
+
+    let response = harness.inspector_request(InspectorRequest::Highlight {
+        widget: Some(label_id),
+    });
+    assert_eq!(response, InspectorResponse::Done);
+    let _ = harness.render();
+}
+
+#[test]
+fn inspector_reports_errors() {
+    let widget = Flex::column().with_fixed(Label::new("Hello").prepare());
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), widget.prepare(), (200., 200.));
+    let root_id = get_tree(&mut harness).id;
+
+    let response = harness.inspector_request(InspectorRequest::SetDisabled {
+        widget: u64::MAX,
+        disabled: true,
+    });
+    assert_matches!(response, InspectorResponse::Error { .. });
+
+    let response = harness.inspector_request(InspectorRequest::SetProperty {
+        widget: root_id,
+        property: "Padding".into(),
+        value: (-3.0).into(),
+    });
+    assert_matches!(response, InspectorResponse::Error { .. });
+
+    let response = harness.inspector_request(InspectorRequest::SetProperty {
+        widget: root_id,
+        property: "Opacity".into(),
+        value: 1.0.into(),
+    });
+    assert_matches!(response, InspectorResponse::Error { .. });
+}
+
+#[test]
+fn inspector_reports_pass_timings() {
+    let widget = Flex::column().with_fixed(Label::new("Hello").prepare());
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), widget.prepare(), (200., 200.));
+    // Passes are only timed once an inspector is connected.
+    let InspectorResponse::Timings { passes } =
+        harness.inspector_request(InspectorRequest::GetTimings)
+    else {
+        panic!("expected timings");
+    };
+    assert!(passes.is_empty());
+    let _ = harness.render();
+
+    let InspectorResponse::Timings { passes } =
+        harness.inspector_request(InspectorRequest::GetTimings)
+    else {
+        panic!("expected timings");
+    };
+    let names: Vec<_> = passes.iter().map(|pass| pass.name.as_str()).collect();
+    assert!(names.contains(&"layout"));
+    assert!(names.contains(&"paint"));
+}
+
+#[test]
+fn inspector_server_answers_over_tcp() {
+    let widget = Flex::column().with_fixed(Label::new("Hello").prepare());
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), widget.prepare(), (200., 200.));
+
+    let server = InspectorServer::bind_tcp(0, || {}).unwrap();
+    assert!(server.local_addr().unwrap().ip().is_loopback());
+    let mut client = TcpStream::connect(server.local_addr().unwrap()).unwrap();
+    client.write_all(b"{\"command\":\"get_tree\"}\n").unwrap();
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !harness.poll_inspector_server(&server) {
+        assert!(Instant::now() < deadline, "inspector request never arrived");
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    let mut line = String::new();
+    BufReader::new(client).read_line(&mut line).unwrap();
+    let response: InspectorResponse = serde_json::from_str(&line).unwrap();
+    let InspectorResponse::Tree { root } = response else {
+        panic!("expected a tree, got {response:?}");
+    };
+    assert_eq!(root.name, "LayerStack");
+}
@@ -0,0 +1,115 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use assert_matches::assert_matches;
+
+use crate::app::interaction_script::{
+    InteractionScript, ScriptButton, ScriptEvent, ScriptModifier, ScriptRecorder, ScrollUnit,
+};
+use crate::core::keyboard::{Key, KeyState, Modifiers, NamedKey};
+use crate::core::{
+    Ime, KeyboardEvent, NewWidget, PointerEvent, PointerScrollEvent, PointerState, ScrollDelta,
+    TextEvent, Widget as _, WidgetTag,
+};
+use crate::testing::{PRIMARY_MOUSE, TestHarness};
+use crate::theme::test_property_set;
+use crate::widgets::{Button, ButtonPress, Flex, TextArea};
+
+#[test]
+fn recorded_scripts_round_trip_through_json() {
+    let mut recorder = ScriptRecorder::new();
+    recorder.record_pointer_event(&PointerEvent::Scroll(PointerScrollEvent {
+        pointer: PRIMARY_MOUSE,
+        delta: ScrollDelta::LineDelta(0., -3.),
+        state: PointerState::default(),
+    }));
+    recorder.record_text_event(&TextEvent::Keyboard(KeyboardEvent {
+        state: KeyState::Down,
+        key: Key::Named(NamedKey::Enter),
+        modifiers: Modifiers::SHIFT,
+        ..KeyboardEvent::default()
+    }));
+    recorder.record_text_event(&TextEvent::Ime(Ime::Commit("hello".into())));
+    // Preedit states aren't recorded.
+    recorder.record_text_event(&TextEvent::Ime(Ime::Preedit("h".into(), None)));
+    recorder.mark_snapshot();
+    let script = recorder.finish();
+
+    let events: Vec<_> = script.steps.iter().map(|step| &step.event).collect();
+    assert_eq!(
+        events,
+        [
+            &ScriptEvent::Scroll {
+                x: 0.,
+                y: -3.,
+                unit: ScrollUnit::Lines,
+            },
+            &ScriptEvent::KeyDown {
+                key: "Enter".into(),
+                modifiers: vec![ScriptModifier::Shift],
+            },
+            &ScriptEvent::Text {
+                text: "hello".into(),
+            },
+            &ScriptEvent::Snapshot {
+                name: "snapshot_1".into(),
+            },
+        ]
+    );
+    assert_eq!(
+        InteractionScript::from_json(&script.to_json()).unwrap(),
+        script
+    );
+}
+
+#[test]
+fn replayed_scripts_drive_the_widget_tree() {
+    let button_tag = WidgetTag::named("button");
+    let text_tag = WidgetTag::named("text");
+    let widget = Flex::column()
+        .with_fixed(NewWidget::new(Button::with_text("Click")).with_tag(button_tag))
+        .with_fixed(NewWidget::new(TextArea::new_editable("")).with_tag(text_tag));
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), widget.prepare(), (200., 200.));
+
+    let center = |harness: &TestHarness<Flex>, id| {
+        let widget = harness.get_widget_with_id(id);
+        widget.ctx().window_transform() * widget.ctx().border_box().center()
+    };
+    let button = center(&harness, harness.get_widget(button_tag).id());
+    let text = center(&harness, harness.get_widget(text_tag).id());
+
+    // Scripts are meant to be stored as files, so we use the JSON format here.
+    let script = InteractionScript::from_json(&format!(
+        r#"{{"steps":[
+            {{"time_ms":0,"event":"pointer_move","x":{},"y":{}}},
+            {{"time_ms":50,"event":"pointer_down","button":"primary"}},
+            {{"time_ms":100,"event":"pointer_up","button":"primary"}},
+            {{"time_ms":300,"event":"pointer_move","x":{},"y":{}}},
+            {{"time_ms":350,"event":"pointer_down","button":"primary"}},
+            {{"time_ms":400,"event":"pointer_up","button":"primary"}},
+            {{"time_ms":600,"event":"text","text":"hi"}},
+            {{"time_ms":700,"event":"snapshot","name":"typed"}},
+            {{"time_ms":800,"event":"key_down","key":"Backspace"}},
+            {{"time_ms":850,"event":"key_up","key":"Backspace"}}
+        ]}}"#,
+        button.x, button.y, text.x, text.y
+    ))
+    .unwrap();
+    assert_eq!(
+        script.steps[1].event,
+        ScriptEvent::PointerDown {
+            button: Some(ScriptButton::Primary)
+        }
+    );
+
+    let mut snapshots = Vec::new();
+    harness.replay_script(&script, |harness, name| {
+        let text = harness.get_widget(text_tag).text().to_string();
+        snapshots.push((name.to_string(), text));
+    });
+
+    assert_eq!(snapshots, [("typed".to_string(), "hi".to_string())]);
+    assert_eq!(harness.get_widget(text_tag).text().to_string(), "h");
+    assert_matches!(harness.pop_action::<ButtonPress>(), Some(_));
+}
@@ -12,6 +12,10 @@ mod action;
 mod anim;
 mod compose;
 mod event;
+mod frame_profile;
+mod fuzzing;
+mod inspector;
+mod interaction_script;
 mod layout;
 mod mutate;
 mod paint;
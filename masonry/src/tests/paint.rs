@@ -5,21 +5,27 @@ use std::sync::Arc;
 
 use assert_matches::assert_matches;
 
-use crate::app::{RenderRoot, RenderRootOptions, WindowSizePolicy};
-use crate::core::{NewWidget, PaintLayerMode, PropertySet, Widget, WidgetTag};
+use crate::app::{RenderRoot, RenderRootOptions, VisualLayerKind, WindowSizePolicy};
+use crate::core::keyboard::{Key, KeyState, NamedKey};
+use crate::core::{
+    CollectionWidget, KeyboardEvent, Modifiers, NewWidget, PaintLayerMode, PropertySet, TextEvent,
+    Widget, WidgetId, WidgetTag,
+};
 use crate::dpi::PhysicalSize;
-use crate::kurbo::{Circle, Dashes, Point, Stroke, Vec2};
+use crate::kurbo::{Circle, Dashes, Point, Rect, Stroke, Vec2};
 use crate::layout::{AsUnit, Length, SizeDef, UnitPoint};
 use crate::palette::css::{BLUE, GREEN, RED};
 use crate::peniko::color::{AlphaColor, Srgb};
 use crate::peniko::{Blob, Color};
 use crate::properties::types::MainAxisAlignment;
-use crate::properties::{Background, Dimensions, Gap, Padding};
+use crate::properties::{Background, BorderColor, BorderWidth, Dimensions, Gap, Padding};
 use crate::testing::{
     ModularWidget, ROBOTO, Record, TestHarness, TestWidgetExt, assert_render_snapshot,
 };
 use crate::theme::test_property_set;
-use crate::widgets::{Align, ChildAlignment, Flex, Grid, GridParams, Label, SizedBox, ZStack};
+use crate::widgets::{
+    Align, ChildAlignment, Flex, Grid, GridParams, Label, ProgressBar, SizedBox, TextArea, ZStack,
+};
 
 #[test]
 fn request_paint() {
@@ -156,6 +162,9 @@ fn paint_clipping() {
     assert_render_snapshot!(harness, "paint_clipping");
 }
 
+const LEADING_TAG: WidgetTag<ModularWidget<()>> = WidgetTag::named("leading");
+const TRAILING_TAG: WidgetTag<ModularWidget<bool>> = WidgetTag::named("trailing");
+
 fn make_layer_split_tree(isolate_trailing_box: bool) -> NewWidget<impl Widget> {
     let leading = NewWidget::new(
         ModularWidget::new(())
@@ -163,7 +172,8 @@ fn make_layer_split_tree(isolate_trailing_box: bool) -> NewWidget<impl Widget> {
             .paint_fn(|_, ctx, _, scene| {
                 scene.fill(ctx.content_box(), RED).draw();
             }),
-    );
+    )
+    .with_tag(LEADING_TAG);
     let trailing = NewWidget::new(
         ModularWidget::new(isolate_trailing_box)
             .measure_fn(|_, _, _, _, _, _| 20.px())
@@ -173,7 +183,8 @@ fn make_layer_split_tree(isolate_trailing_box: bool) -> NewWidget<impl Widget> {
                 }
                 scene.fill(ctx.content_box(), BLUE).draw();
             }),
-    );
+    )
+    .with_tag(TRAILING_TAG);
 
     Flex::row()
         .with_fixed(leading)
@@ -181,6 +192,8 @@ fn make_layer_split_tree(isolate_trailing_box: bool) -> NewWidget<impl Widget> {
         .prepare()
 }
 
+const PLACEHOLDER_TAG: WidgetTag<ModularWidget<()>> = WidgetTag::named("placeholder");
+
 fn make_external_placeholder_tree() -> NewWidget<impl Widget> {
     let leading = NewWidget::new(
         ModularWidget::new(())
@@ -198,7 +211,8 @@ fn make_external_placeholder_tree() -> NewWidget<impl Widget> {
             .paint_fn(|_, ctx, _, _scene| {
                 ctx.set_paint_layer_mode(PaintLayerMode::External);
             }),
-    );
+    )
+    .with_tag(PLACEHOLDER_TAG);
     let trailing = NewWidget::new(
         ModularWidget::new(())
             .measure_fn(|_, _, _, _, _, _| 20.px())
@@ -214,7 +228,7 @@ fn make_external_placeholder_tree() -> NewWidget<impl Widget> {
         .prepare()
 }
 
-fn create_render_root(root_widget: NewWidget<impl Widget>) -> RenderRoot {
+pub(super) fn create_render_root(root_widget: NewWidget<impl Widget>) -> RenderRoot {
     let test_font = Blob::new(Arc::new(ROBOTO));
     RenderRoot::new(
         root_widget,
@@ -226,6 +240,7 @@ fn create_render_root(root_widget: NewWidget<impl Widget>) -> RenderRoot {
             size: PhysicalSize::new(40, 20),
             scale_factor: 1.0,
             test_font: Some(test_font),
+            layout_debugger: false,
         },
     )
 }
@@ -256,18 +271,321 @@ fn external_placeholders_appear_in_painter_order() {
 
     assert!(matches!(
         visual_layers.layers[0].kind,
-        crate::app::VisualLayerKind::Scene(_)
+        VisualLayerKind::Scene(_)
     ));
     assert!(matches!(
         visual_layers.layers[1].kind,
-        crate::app::VisualLayerKind::External { .. }
+        VisualLayerKind::External { .. }
     ));
     assert!(matches!(
         visual_layers.layers[2].kind,
-        crate::app::VisualLayerKind::Scene(_)
+        VisualLayerKind::Scene(_)
     ));
 }
 
+#[test]
+fn composited_isolated_layers_are_cached() {
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), make_layer_split_tree(true), (40, 20));
+
+    let (image, stats) = harness.render_composited();
+    assert_eq!(stats.rendered_layers, 1);
+    assert_eq!(stats.reused_layers, 0);
+    assert_eq!(image, harness.render());
+
+    let (second_image, stats) = harness.render_composited();
+    assert_eq!(stats.rendered_layers, 0);
+    assert_eq!(stats.reused_layers, 1);
+    assert_eq!(second_image, image);
+
+    // Repainting a widget of another layer doesn't invalidate the isolated layer.
+    harness.edit_widget(LEADING_TAG, |mut widget| {
+        widget.ctx.request_paint_only();
+    });
+    let (_, stats) = harness.render_composited();
+    assert_eq!(stats.rendered_layers, 0);
+    assert_eq!(stats.reused_layers, 1);
+
+    // Repainting the isolated widget does.
+    harness.edit_widget(TRAILING_TAG, |mut widget| {
+        widget.ctx.request_paint_only();
+    });
+    let (image, stats) = harness.render_composited();
+    assert_eq!(stats.rendered_layers, 1);
+    assert_eq!(stats.reused_layers, 0);
+    assert_eq!(image, harness.render());
+}
+
+#[test]
+fn isolated_layer_generation_tracks_repaints() {
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), make_layer_split_tree(true), (40, 20));
+    let trailing = harness.get_widget(TRAILING_TAG);
+    let trailing_id = trailing.id();
+    let trailing_rect = trailing
+        .ctx()
+        .window_transform()
+        .transform_rect_bbox(trailing.ctx().border_box());
+    let generation_of = |harness: &mut TestHarness<_>| {
+        let (plan, _) = harness.redraw();
+        let layer = plan
+            .layers
+            .iter()
+            .find(|layer| layer.widget_id == trailing_id)
+            .unwrap();
+        (layer.generation, layer.bounds)
+    };
+
+    let (generation, bounds) = generation_of(&mut harness);
+    assert_eq!(bounds, trailing_rect);
+    // Redrawing without any change, even when other layers change, keeps the generation.
+    assert_eq!(generation_of(&mut harness).0, generation);
+    harness.edit_widget(LEADING_TAG, |mut widget| {
+        widget.ctx.request_paint_only();
+    });
+    assert_eq!(generation_of(&mut harness).0, generation);
+
+    harness.edit_widget(TRAILING_TAG, |mut widget| {
+        widget.ctx.request_paint_only();
+    });
+    assert_ne!(generation_of(&mut harness).0, generation);
+}
+
+#[test]
+fn composited_external_layers_show_external_images() {
+    let mut harness = TestHarness::create_with_size(
+        test_property_set(),
+        make_external_placeholder_tree(),
+        (80, 20),
+    );
+    let placeholder = harness.get_widget(PLACEHOLDER_TAG);
+    let placeholder_id = placeholder.id();
+    let rect = placeholder
+        .ctx()
+        .window_transform()
+        .transform_rect_bbox(placeholder.ctx().border_box());
+    let green = image::Rgba(GREEN.to_rgba8().to_u8_array());
+
+    // Without an external image, the placeholder is left empty.
+    let (image, _) = harness.render_composited();
+    assert_eq!(image, harness.render());
+
+    harness.set_external_image(placeholder_id, image::RgbaImage::from_pixel(2, 2, green));
+    let (image, _) = harness.render_composited();
+    let (x0, y0) = (rect.x0 as u32, rect.y0 as u32);
+    let (x1, y1) = (rect.x1 as u32 - 1, rect.y1 as u32 - 1);
+    for (x, y) in [(x0, y0), (x1, y1), ((x0 + x1) / 2, (y0 + y1) / 2)] {
+        assert_eq!(*image.get_pixel(x, y), green);
+    }
+    assert_ne!(*image.get_pixel(x0 - 1, y0), green);
+    assert_ne!(*image.get_pixel(x1 + 1, y1), green);
+}
+
+const CACHE_TARGET_TAG: WidgetTag<SizedBox> = WidgetTag::named("cache_target");
+const CACHE_LABEL_TAG: WidgetTag<Label> = WidgetTag::named("cache_label");
+const CACHE_ROOT_TAG: WidgetTag<Flex> = WidgetTag::named("cache_root");
+
+fn make_paint_cache_tree(text: &str) -> NewWidget<impl Widget> {
+    let static_column = Flex::column()
+        .with_fixed(NewWidget::new(Label::new(text)).with_tag(CACHE_LABEL_TAG))
+        .with_fixed(Label::new("Static").prepare());
+    let target = NewWidget::new(SizedBox::empty().width(10.px()).height(10.px()))
+        .with_props(Background::Color(RED))
+        .with_tag(CACHE_TARGET_TAG);
+
+    NewWidget::new(
+        Flex::row()
+            .with_fixed(static_column.prepare())
+            .with_fixed(target),
+    )
+    .with_tag(CACHE_ROOT_TAG)
+}
+
+#[test]
+fn unchanged_subtrees_reuse_cached_paint() {
+    let mut root = create_render_root(make_paint_cache_tree("Hello"));
+    let _ = root.redraw();
+
+    let request_target_paint = |root: &mut RenderRoot| {
+        root.edit_widget_with_tag(CACHE_TARGET_TAG, |mut widget| {
+            widget.ctx.request_paint_only();
+        });
+    };
+
+    // The static column is recorded the first time it's painted without changes...
+    request_target_paint(&mut root);
+    let _ = root.redraw();
+    assert_eq!(root.last_paint_cache_stats().hits, 0);
+    assert_eq!(root.last_paint_cache_stats().misses, 1);
+
+    // ...and reused afterwards.
+    request_target_paint(&mut root);
+    let _ = root.redraw();
+    assert_eq!(root.last_paint_cache_stats().hits, 1);
+    assert_eq!(root.last_paint_cache_stats().misses, 0);
+
+    // Changing the column invalidates its cached scene.
+    root.edit_widget_with_tag(CACHE_LABEL_TAG, |mut label| {
+        Label::set_text(&mut label, "World");
+    });
+    let _ = root.redraw();
+    assert_eq!(root.last_paint_cache_stats().hits, 0);
+}
+
+#[test]
+fn cached_paint_matches_fresh_paint() {
+    let mut harness = TestHarness::create_with_size(
+        test_property_set(),
+        make_paint_cache_tree("Hello"),
+        (100, 50),
+    );
+    let _ = harness.render();
+    for _ in 0..2 {
+        harness.edit_widget(CACHE_TARGET_TAG, |mut widget| {
+            widget.ctx.request_paint_only();
+        });
+        let _ = harness.render();
+    }
+
+    harness.edit_widget(CACHE_LABEL_TAG, |mut label| {
+        Label::set_text(&mut label, "World");
+    });
+    let mut fresh_harness = TestHarness::create_with_size(
+        test_property_set(),
+        make_paint_cache_tree("World"),
+        (100, 50),
+    );
+    assert_eq!(harness.render(), fresh_harness.render());
+
+    harness.edit_widget(CACHE_TARGET_TAG, |mut widget| {
+        widget.ctx.request_paint_only();
+    });
+    assert_eq!(harness.render(), fresh_harness.render());
+}
+
+#[test]
+fn static_charts_are_served_from_the_paint_cache() {
+    const CHART_COUNT: usize = 100;
+    let counter_tag = WidgetTag::named("counter");
+
+    let charts = (0..CHART_COUNT).fold(Flex::row(), |row, idx| {
+        let chart = Flex::column()
+            .with_fixed(Label::new(format!("Chart {idx}")).prepare())
+            .with_fixed(ProgressBar::new(Some(idx as f64 / CHART_COUNT as f64)).prepare());
+        row.with_fixed(chart.prepare())
+    });
+    let dashboard = Flex::column()
+        .with_fixed(NewWidget::new(Label::new("0 ticks")).with_tag(counter_tag))
+        .with_fixed(charts.prepare());
+    let mut root = create_render_root(dashboard.prepare());
+    let _ = root.redraw();
+
+    // Each tick only changes the counter, so the charts are recorded once, then reused.
+    for ticks in 1..=3 {
+        root.edit_widget_with_tag(counter_tag, |mut label| {
+            Label::set_text(&mut label, format!("{ticks} ticks"));
+        });
+        let _ = root.redraw();
+        let stats = root.last_paint_cache_stats();
+        if ticks == 1 {
+            assert_eq!(stats.hits, 0);
+        } else {
+            assert!(stats.hits > 0);
+            assert_eq!(stats.misses, 0);
+        }
+    }
+}
+
+fn window_paint_rect(harness: &TestHarness<impl Widget>, id: WidgetId) -> Rect {
+    let widget = harness.get_widget_with_id(id);
+    widget
+        .ctx()
+        .window_transform()
+        .transform_rect_bbox(widget.ctx().paint_box())
+}
+
+#[test]
+fn redraw_reports_damaged_area() {
+    let mut harness = TestHarness::create_with_size(
+        test_property_set(),
+        make_paint_cache_tree("Hello"),
+        (100, 50),
+    );
+    let (plan, _) = harness.redraw();
+    assert_eq!(plan.damage.map(|damage| damage.area()), Some(0.));
+
+    harness.edit_widget(CACHE_TARGET_TAG, |mut widget| {
+        widget.ctx.request_paint_only();
+    });
+    let target_id = harness.get_widget(CACHE_TARGET_TAG).id();
+    let (plan, _) = harness.redraw();
+    assert_eq!(plan.damage, Some(window_paint_rect(&harness, target_id)));
+
+    // Removed widgets damage the area they were painted in.
+    let target_rect = window_paint_rect(&harness, target_id);
+    harness.edit_widget(CACHE_ROOT_TAG, |mut flex| {
+        Flex::remove(&mut flex, 1);
+    });
+    let (plan, _) = harness.redraw();
+    assert!(plan.damage.unwrap().contains_rect(target_rect));
+}
+
+#[test]
+fn cursor_blink_only_damages_the_text_area() {
+    let text_area_tag = WidgetTag::named("text_area");
+    let widget = Flex::column()
+        .with_fixed(Label::new("Static").prepare())
+        .with_fixed(NewWidget::new(TextArea::new_editable("Text")).with_tag(text_area_tag));
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), widget.prepare(), (200, 200));
+    let text_area_id = harness.get_widget(text_area_tag).id();
+    harness.focus_on(Some(text_area_id));
+    // Typing restarts the blink animation.
+    harness.keyboard_type_chars("a");
+    let _ = harness.redraw();
+
+    // Half a blink cycle later, the cursor is hidden.
+    harness.animate_ms(600);
+    let (plan, _) = harness.redraw();
+    assert_eq!(plan.damage, Some(window_paint_rect(&harness, text_area_id)));
+}
+
+#[test]
+fn widgets_render_to_images_at_any_scale() {
+    let mut harness = TestHarness::create_with_size(
+        test_property_set(),
+        make_paint_cache_tree("Hello"),
+        (100, 50),
+    );
+    let target_id = harness.get_widget(CACHE_TARGET_TAG).id();
+
+    // The 10x10 red box is rendered on its own, at three times its size.
+    let image = harness.render_widget(target_id, 3.);
+    assert_eq!(image.dimensions(), (30, 30));
+    let red = image::Rgba(RED.to_rgba8().to_u8_array());
+    assert!(
+        image.pixels().all(|pixel| *pixel == red),
+        "the widget's background should fill the image"
+    );
+
+    // Ancestors are rendered with their descendants, on a transparent background.
+    let root_id = harness.get_widget(CACHE_ROOT_TAG).id();
+    let root_size = harness.get_widget(CACHE_ROOT_TAG).ctx().border_box().size();
+    let image = harness.render_widget(root_id, 2.);
+    assert_eq!(
+        image.dimensions(),
+        (
+            (root_size.width * 2.).ceil() as u32,
+            (root_size.height * 2.).ceil() as u32
+        )
+    );
+    let target_center = window_paint_rect(&harness, target_id).center()
+        - window_paint_rect(&harness, root_id).origin();
+    let pixel = |x: f64, y: f64| *image.get_pixel((x * 2.) as u32, (y * 2.) as u32);
+    assert_eq!(pixel(target_center.x, target_center.y), red);
+    assert_eq!(pixel(root_size.width - 1., root_size.height - 1.)[3], 0);
+}
+
 // Layered slightly misaligned grid layer painting:
 //
 // Color background
@@ -345,3 +663,77 @@ fn paint_transparency() {
 
     assert_render_snapshot!(harness, "paint_transparency");
 }
+
+#[test]
+fn layout_debugger_adds_overlay_layer() {
+    let mut root = create_render_root(make_layer_split_tree(false));
+    let (visual_layers, _) = root.redraw();
+    assert_eq!(visual_layers.layers.len(), 1);
+
+    // Shift+F12 toggles the overlay.
+    root.handle_text_event(TextEvent::Keyboard(KeyboardEvent {
+        state: KeyState::Down,
+        key: Key::Named(NamedKey::F12),
+        modifiers: Modifiers::SHIFT,
+        ..KeyboardEvent::default()
+    }));
+    assert!(root.layout_debugger_enabled());
+
+    let (visual_layers, _) = root.redraw();
+    assert_eq!(visual_layers.layers.len(), 2);
+    assert_matches!(visual_layers.layers[1].kind, VisualLayerKind::Scene(_));
+
+    root.set_layout_debugger(false);
+    let (visual_layers, _) = root.redraw();
+    assert_eq!(visual_layers.layers.len(), 1);
+}
+
+#[test]
+fn layout_debugger_describes_hovered_widget() {
+    let target_tag = WidgetTag::named("target");
+    let label = Label::new("Inspect me")
+        .prepare()
+        .with_tag(target_tag)
+        .with_props((
+            Padding::all(6.px()),
+            BorderWidth::all(2.px()),
+            BorderColor::new(BLUE),
+        ));
+    let root = Flex::column()
+        .with_fixed(label)
+        .prepare()
+        .with_props(Padding::all(10.px()));
+
+    let mut harness = TestHarness::create_with_size(test_property_set(), root, (400, 160));
+    // Labels don't accept pointer interaction, but the layout debugger still describes them.
+    harness.mouse_move((200., 20.));
+    assert_eq!(harness.layout_debugger_target(), None);
+
+    harness.set_layout_debugger(true);
+    harness.mouse_move((200., 21.));
+    let label_id = harness.get_widget(target_tag).id();
+    assert_eq!(harness.layout_debugger_target(), Some(label_id));
+
+    // Painting the panel for the hovered widget shouldn't panic.
+    let _ = harness.render();
+}
+
+#[test]
+fn snapshots_use_the_harness_renderer_reference() {
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), Label::new("Hello").prepare(), (60, 20));
+    let manifest_dir =
+        std::env::temp_dir().join(format!("masonry-renderer-reference-{}", std::process::id()));
+    let screenshots = manifest_dir.join("screenshots");
+    std::fs::create_dir_all(&screenshots).unwrap();
+
+    // The shared reference doesn't match, but the reference for the renderer the harness uses does.
+    let image = harness.render();
+    image::RgbaImage::new(image.width(), image.height())
+        .save(screenshots.join("label.png"))
+        .unwrap();
+    image.save(screenshots.join("label.vello_cpu.png")).unwrap();
+    harness.check_render_snapshot(manifest_dir.to_str().unwrap(), "label", false);
+
+    let _ = std::fs::remove_dir_all(&manifest_dir);
+}
@@ -1,13 +1,15 @@
 // Copyright 2026 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::core::Widget as _;
+use masonry_testing::TestHarness;
+
+use crate::core::{DefaultProperties, Widget as _};
 use crate::kurbo::Rect;
 use crate::layout::AsUnit;
 use crate::palette::css::BLUE;
-use crate::properties::{ContentColor, Dimensions, Gap, ObjectFit};
+use crate::properties::{ContentColor, Dimensions, Gap, ObjectFit, Padding};
 use crate::tests::assert_rect_approx_eq;
-use crate::widgets::Button;
+use crate::widgets::{Button, Label, SizedBox};
 
 #[test]
 fn widget_new_properties() {
@@ -33,6 +35,35 @@ fn widget_new_properties() {
     assert_eq!(props.get::<ContentColor>(), Some(&ContentColor::new(BLUE)));
 }
 
+#[test]
+fn child_gets_own_default_properties() {
+    let mut default_props = DefaultProperties::new();
+    default_props.insert::<SizedBox, _>(Padding::all(3.px()));
+    default_props.insert::<Label, _>(Padding::all(7.px()));
+
+    let widget = SizedBox::new(Label::new("Hello").prepare()).prepare();
+    let mut harness = TestHarness::create(default_props, widget);
+
+    // Through a parent's `WidgetRef`.
+    let root = harness.root_widget();
+    assert_eq!(root.get_prop::<Padding>(), &Padding::all(3.px()));
+    let child_id = root.children()[0].id();
+    assert_eq!(
+        root.children()[0].get_prop::<Padding>(),
+        &Padding::all(7.px())
+    );
+
+    // Through the parent's `QueryCtx`.
+    let child = root.ctx().get(child_id);
+    assert_eq!(child.get_prop::<Padding>(), &Padding::all(7.px()));
+
+    // Through the parent's `MutateCtx`.
+    harness.edit_root_widget(|mut root| {
+        let mut child = SizedBox::child_mut(&mut root).unwrap();
+        assert_eq!(child.get_prop::<Padding>(), &Padding::all(7.px()));
+    });
+}
+
 #[test]
 fn object_fit_affine_stretch_maps_rect_to_rect() {
     let container = Rect::new(10., -20., 110., 30.);
@@ -329,6 +329,7 @@ fn focus_order() {
             focusable_widgets.push(id);
         }
     });
+    assert_eq!(harness.focus_chain(), focusable_widgets);
     let mut next_focusable_widgets = focusable_widgets.clone();
     next_focusable_widgets.remove(0);
     next_focusable_widgets.push(*focusable_widgets.first().unwrap());
@@ -397,6 +398,7 @@ fn disable_focusable() {
 
     harness.focus_on(Some(button2_id));
     harness.set_disabled(button2_tag, true);
+    assert_eq!(harness.focus_chain(), [button1_id, button3_id]);
 
     // We skip button2 and jump from button1 to button3.
     harness.focus_on(Some(button1_id));
@@ -8,6 +8,7 @@
 use crate::core::{
     DefaultProperties, PropertySet, PropertyStack, Selector, StyleProperty, StyleSet,
 };
+use crate::layers::{CalendarPopup, MenuPopup, Toast};
 use crate::layout::{AsUnit, Length};
 use crate::palette::css::DIM_GRAY;
 use crate::parley::{GenericFamily, LineHeight};
@@ -38,6 +39,7 @@ pub const DISABLED_TEXT_COLOR: Color = Color::from_rgb8(0xa0, 0xa0, 0x9a);
 pub const PLACEHOLDER_COLOR: Color = Color::from_rgba8(0xFF, 0xFF, 0xFF, 0x8F);
 pub const TEXT_BACKGROUND_COLOR: Color = Color::from_rgb8(0x16, 0x16, 0x16);
 pub const FOCUS_COLOR: Color = Color::from_rgb8(0xff, 0xff, 0xff);
+pub const ERROR_COLOR: Color = Color::from_rgb8(0xf8, 0x71, 0x71);
 
 // TODO: The following constants are not being used in properties
 pub const TEXT_SIZE_NORMAL: f32 = 15.0;
@@ -54,6 +56,7 @@ pub const DEFAULT_GAP: Length = Length::const_px(10.0);
 pub const DEFAULT_SPACER_LEN: Length = Length::const_px(10.0);
 pub const WIDGET_CONTROL_COMPONENT_PADDING: Length = Length::const_px(4.0);
 pub const SELECTOR_MIN_WIDTH: f64 = 100.0;
+pub const FORM_ERROR_GAP: Length = Length::const_px(4.0);
 
 pub fn default_property_set() -> DefaultProperties {
     let mut properties = DefaultProperties::new();
@@ -133,6 +136,10 @@ pub fn default_property_set() -> DefaultProperties {
                     color: DISABLED_TEXT_COLOR,
                 }),
         );
+        stack.push(
+            Selector::new().with_invalid(true),
+            BorderColor { color: ERROR_COLOR },
+        );
         properties.insert_stack::<Checkbox>(stack);
     }
 
@@ -182,6 +189,40 @@ pub fn default_property_set() -> DefaultProperties {
         properties.insert_stack::<Switch>(stack);
     }
 
+    // TabBar
+    properties.insert::<TabBar, _>(Gap::new(2.px()));
+
+    // TabHeader
+    properties.insert::<TabHeader, _>(Padding::from_vh(6.px(), 10.px()));
+    properties.insert::<TabHeader, _>(CornerRadius { radius: 4.px() });
+    properties.insert::<TabHeader, _>(BorderWidth {
+        width: BORDER_WIDTH,
+    });
+    properties.insert::<TabHeader, _>(BorderColor {
+        color: Color::TRANSPARENT,
+    });
+    properties.insert::<TabHeader, _>(ContentColor::new(TEXT_COLOR));
+    {
+        let mut stack = PropertyStack::new();
+        stack.push(
+            Selector::new().with_hovered(true),
+            Background::Color(ZYNC_800),
+        );
+        stack.push(
+            Selector::classes(&["#selected"]),
+            Background::Color(ZYNC_700),
+        );
+        stack.push(
+            Selector::classes(&["#focused"]),
+            BorderColor { color: FOCUS_COLOR },
+        );
+        stack.push(
+            Selector::new().with_disabled(true),
+            ContentColor::new(DISABLED_TEXT_COLOR),
+        );
+        properties.insert_stack::<TabHeader>(stack);
+    }
+
     // FIXME
     use crate::widgets::Selector as SelectorButton;
 
@@ -212,6 +253,10 @@ pub fn default_property_set() -> DefaultProperties {
             Selector::new().with_focused(true),
             BorderColor { color: FOCUS_COLOR },
         );
+        stack.push(
+            Selector::new().with_invalid(true),
+            BorderColor { color: ERROR_COLOR },
+        );
         properties.insert_stack::<SelectorButton>(stack);
     }
 
@@ -231,6 +276,123 @@ pub fn default_property_set() -> DefaultProperties {
         properties.insert_stack::<SelectorItem>(stack);
     }
 
+    // MenuPopup
+    properties.insert::<MenuPopup, _>(Padding::from_vh(4.px(), 0.px()));
+    properties.insert::<MenuPopup, _>(CornerRadius { radius: 4.px() });
+    properties.insert::<MenuPopup, _>(BorderWidth {
+        width: BORDER_WIDTH,
+    });
+    properties.insert::<MenuPopup, _>(BorderColor { color: ZYNC_700 });
+    properties.insert::<MenuPopup, _>(Background::Color(ZYNC_900));
+
+    // CalendarDay
+    properties.insert::<CalendarDay, _>(CornerRadius { radius: 4.px() });
+    properties.insert::<CalendarDay, _>(BorderWidth {
+        width: BORDER_WIDTH,
+    });
+    properties.insert::<CalendarDay, _>(BorderColor {
+        color: Color::TRANSPARENT,
+    });
+    {
+        let mut stack = PropertyStack::new();
+        stack.push(
+            Selector::new().with_hovered(true),
+            Background::Color(ZYNC_800),
+        );
+        stack.push(
+            Selector::classes(&["#in-range"]),
+            Background::Color(ZYNC_700),
+        );
+        stack.push(
+            Selector::classes(&["#today"]),
+            BorderColor { color: ZYNC_500 },
+        );
+        stack.push(
+            Selector::classes(&["#selected"]),
+            Background::Color(ACCENT_COLOR),
+        );
+        stack.push(
+            Selector::classes(&["#focused"]),
+            BorderColor { color: FOCUS_COLOR },
+        );
+        properties.insert_stack::<CalendarDay>(stack);
+    }
+
+    // CalendarPopup
+    properties.insert::<CalendarPopup, _>(Padding::all(8.px()));
+    properties.insert::<CalendarPopup, _>(CornerRadius { radius: 4.px() });
+    properties.insert::<CalendarPopup, _>(BorderWidth {
+        width: BORDER_WIDTH,
+    });
+    properties.insert::<CalendarPopup, _>(BorderColor { color: ZYNC_700 });
+    properties.insert::<CalendarPopup, _>(Background::Color(ZYNC_900));
+
+    // Toast
+    properties.insert::<Toast, _>(Padding::from_vh(10.px(), 14.px()));
+    properties.insert::<Toast, _>(CornerRadius { radius: 6.px() });
+    properties.insert::<Toast, _>(BorderWidth {
+        width: BORDER_WIDTH,
+    });
+    properties.insert::<Toast, _>(BorderColor { color: ZYNC_700 });
+    properties.insert::<Toast, _>(Background::Color(ZYNC_900));
+
+    // ColorArea
+    properties.insert::<ColorArea, _>(CornerRadius { radius: 4.px() });
+    properties.insert::<ColorArea, _>(BorderWidth {
+        width: BORDER_WIDTH,
+    });
+    properties.insert::<ColorArea, _>(BorderColor { color: ZYNC_700 });
+    {
+        let mut stack = PropertyStack::new();
+        stack.push(
+            Selector::new().with_hovered(true),
+            BorderColor { color: ZYNC_500 },
+        );
+        stack.push(
+            Selector::new().with_focused(true),
+            BorderColor { color: FOCUS_COLOR },
+        );
+        properties.insert_stack::<ColorArea>(stack);
+    }
+
+    // ColorSlider
+    properties.insert::<ColorSlider, _>(CornerRadius { radius: 4.px() });
+    properties.insert::<ColorSlider, _>(BorderWidth {
+        width: BORDER_WIDTH,
+    });
+    properties.insert::<ColorSlider, _>(BorderColor { color: ZYNC_700 });
+    {
+        let mut stack = PropertyStack::new();
+        stack.push(
+            Selector::new().with_hovered(true),
+            BorderColor { color: ZYNC_500 },
+        );
+        stack.push(
+            Selector::new().with_focused(true),
+            BorderColor { color: FOCUS_COLOR },
+        );
+        properties.insert_stack::<ColorSlider>(stack);
+    }
+
+    // ColorSwatch
+    properties.insert::<ColorSwatch, _>(CornerRadius { radius: 4.px() });
+    properties.insert::<ColorSwatch, _>(BorderWidth {
+        width: BORDER_WIDTH,
+    });
+    properties.insert::<ColorSwatch, _>(BorderColor { color: ZYNC_700 });
+    {
+        let mut stack = PropertyStack::new();
+        stack.push(
+            Selector::new().with_hovered(true),
+            BorderColor { color: ZYNC_500 },
+        );
+        stack.push(
+            Selector::new().with_focused(true),
+            BorderColor { color: FOCUS_COLOR },
+        );
+        properties.insert_stack::<ColorSwatch>(stack);
+    }
+
     // Flex
     properties.insert::<Flex, _>(Gap::new(DEFAULT_GAP));
 
@@ -266,6 +428,10 @@ pub fn default_property_set() -> DefaultProperties {
             Selector::new().with_disabled(true),
             Background::Color(TEXT_BACKGROUND_COLOR),
         );
+        stack.push(
+            Selector::new().with_invalid(true),
+            BorderColor { color: ERROR_COLOR },
+        );
         properties.insert_stack::<TextInput>(stack);
     }
 
@@ -305,6 +471,14 @@ pub fn default_property_set() -> DefaultProperties {
             Selector::new().with_disabled(true),
             ContentColor::new(DISABLED_TEXT_COLOR),
         );
+        stack.push(
+            Selector::classes(&[FORM_ERROR_CLASS]),
+            ContentColor::new(ERROR_COLOR),
+        );
+        stack.push(
+            Selector::classes(&[CALENDAR_OUTSIDE_DAY_CLASS]),
+            ContentColor::new(DISABLED_TEXT_COLOR),
+        );
         properties.insert_stack::<Label>(stack);
     }
 
@@ -350,6 +524,13 @@ pub fn default_property_set() -> DefaultProperties {
         properties.insert_stack::<RadioButton>(stack);
     }
 
+    // RangeSlider
+    properties.insert::<RangeSlider, _>(TrackThickness(4.px()));
+    properties.insert::<RangeSlider, _>(TrackColor {
+        active: ACCENT_COLOR,
+        inactive: ZYNC_800,
+    });
+
     // Slider
     properties.insert::<Slider, _>(TrackThickness(4.px()));
     properties.insert::<Slider, _>(TrackColor {
@@ -371,6 +552,25 @@ pub fn default_property_set() -> DefaultProperties {
         properties.insert_stack::<Slider>(stack);
     }
 
+    // SliderThumb
+    properties.insert::<SliderThumb, _>(TrackColor {
+        active: ACCENT_COLOR,
+        inactive: ZYNC_800,
+    });
+    properties.insert::<SliderThumb, _>(ThumbColor(TEXT_COLOR));
+    properties.insert::<SliderThumb, _>(ThumbRadius(7.px()));
+    properties.insert::<SliderThumb, _>(BorderColor {
+        color: Color::TRANSPARENT,
+    });
+    {
+        let mut stack = PropertyStack::new();
+        stack.push(
+            Selector::new().with_focused(true),
+            BorderColor { color: FOCUS_COLOR },
+        );
+        properties.insert_stack::<SliderThumb>(stack);
+    }
+
     // Spinner
     properties.insert::<Spinner, _>(TrackColor {
         active: TEXT_COLOR,
@@ -430,6 +630,10 @@ fn default_step_input_style<T: Steppable>(properties: &mut DefaultProperties) {
             Selector::new().with_focused(true),
             BorderColor { color: FOCUS_COLOR },
         );
+        stack.push(
+            Selector::new().with_invalid(true),
+            BorderColor { color: ERROR_COLOR },
+        );
 
         properties.insert_stack::<StepInput<T>>(stack);
     }
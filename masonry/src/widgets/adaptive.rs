@@ -0,0 +1,435 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ChildrenIds, CollectionWidget, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
+    PropertiesRef, RegisterCtx, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::{AsUnit, LayoutSize, LenReq, Length, SizeDef};
+
+/// A container that displays one of its children, chosen by the space available to it.
+///
+/// Every child has a breakpoint, which is the minimum length on the container's axis
+/// that the child needs to be chosen. During layout the container displays the child
+/// with the largest breakpoint that fits into its length, or the child with the smallest
+/// breakpoint if none of them fit.
+///
+/// Because the choice is made during the layout pass, the container switches between
+/// children in the same frame as the available space changes, without a round-trip
+/// through app state. The children that aren't displayed are [stashed], so they keep
+/// their state and can be displayed again later.
+///
+/// On its axis the container fills all of the space that it is offered, so that the child
+/// chosen during measurement is the same one that gets chosen during layout.
+/// Its min-content length is that of the child with the smallest breakpoint,
+/// and its max-content length is that of the child with the largest breakpoint,
+/// but at least that breakpoint.
+///
+/// [stashed]: crate::doc::masonry_concepts#stashed
+pub struct Adaptive {
+    axis: Axis,
+    children: Vec<Child>,
+    /// The child displayed by the most recent layout pass.
+    active_child: Option<usize>,
+}
+
+struct Child {
+    widget: WidgetPod<dyn Widget>,
+    breakpoint: Length,
+}
+
+// --- MARK: BUILDERS
+impl Adaptive {
+    /// Creates a new container with no children, which chooses a child based on
+    /// the space available on the given `axis`.
+    pub fn new(axis: Axis) -> Self {
+        Self {
+            axis,
+            children: Vec::new(),
+            active_child: None,
+        }
+    }
+
+    /// Builder-style method to add a child widget with the given `breakpoint`.
+    pub fn with(mut self, child: NewWidget<impl Widget + ?Sized>, breakpoint: Length) -> Self {
+        self.children.push(Child {
+            widget: child.erased().to_pod(),
+            breakpoint,
+        });
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl Adaptive {
+    /// Returns the axis whose available length decides which child is displayed.
+    pub fn axis(&self) -> Axis {
+        self.axis
+    }
+
+    /// Returns the index of the child displayed by the most recent layout pass.
+    ///
+    /// Returns `None` if there are no children or if layout hasn't run since they changed.
+    pub fn active_child(&self) -> Option<usize> {
+        self.active_child
+    }
+
+    /// Returns the index of the child to display given the available `length`.
+    fn select(&self, length: Length) -> Option<usize> {
+        // Iterate in reverse so that max_by returns the first of equal breakpoints
+        let fitting = self
+            .children
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, child)| child.breakpoint.get() <= length.get())
+            .max_by(|(_, a), (_, b)| a.breakpoint.get().total_cmp(&b.breakpoint.get()));
+        fitting.or_else(|| self.smallest()).map(|(idx, _)| idx)
+    }
+
+    /// Returns the child with the smallest breakpoint.
+    fn smallest(&self) -> Option<(usize, &Child)> {
+        self.children
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.breakpoint.get().total_cmp(&b.breakpoint.get()))
+    }
+
+    /// Returns the index of the child with the largest breakpoint.
+    fn largest(&self) -> Option<usize> {
+        self.children
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by(|(_, a), (_, b)| a.breakpoint.get().total_cmp(&b.breakpoint.get()))
+            .map(|(idx, _)| idx)
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Adaptive {
+    /// Sets the axis whose available length decides which child is displayed.
+    pub fn set_axis(this: &mut WidgetMut<'_, Self>, axis: Axis) {
+        this.widget.axis = axis;
+        this.ctx.request_layout();
+    }
+}
+
+// --- MARK: COLLECTIONWIDGET
+impl CollectionWidget<Length> for Adaptive {
+    /// Returns the number of children.
+    fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Returns `true` if there are no children.
+    fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Returns a mutable reference to the child widget at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn get_mut<'t>(this: &'t mut WidgetMut<'_, Self>, idx: usize) -> WidgetMut<'t, dyn Widget> {
+        let child = &mut this.widget.children[idx].widget;
+        this.ctx.get_mut(child)
+    }
+
+    /// Appends a child widget with the given breakpoint to the collection.
+    fn add(
+        this: &mut WidgetMut<'_, Self>,
+        child: NewWidget<impl Widget + ?Sized>,
+        breakpoint: impl Into<Length>,
+    ) {
+        this.widget.children.push(Child {
+            widget: child.erased().to_pod(),
+            breakpoint: breakpoint.into(),
+        });
+        this.widget.active_child = None;
+        this.ctx.children_changed();
+    }
+
+    /// Inserts a child widget with the given breakpoint at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the number of children.
+    fn insert(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        child: NewWidget<impl Widget + ?Sized>,
+        breakpoint: impl Into<Length>,
+    ) {
+        let child = Child {
+            widget: child.erased().to_pod(),
+            breakpoint: breakpoint.into(),
+        };
+        this.widget.children.insert(idx, child);
+        this.widget.active_child = None;
+        this.ctx.children_changed();
+    }
+
+    /// Replaces the child widget at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn set(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        child: NewWidget<impl Widget + ?Sized>,
+        breakpoint: impl Into<Length>,
+    ) {
+        let child = Child {
+            widget: child.erased().to_pod(),
+            breakpoint: breakpoint.into(),
+        };
+        let old_child = std::mem::replace(&mut this.widget.children[idx], child);
+        this.ctx.remove_child(old_child.widget);
+        this.widget.active_child = None;
+        this.ctx.children_changed();
+    }
+
+    /// Sets the breakpoint of the child at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn set_params(this: &mut WidgetMut<'_, Self>, idx: usize, breakpoint: impl Into<Length>) {
+        this.widget.children[idx].breakpoint = breakpoint.into();
+        this.ctx.request_layout();
+    }
+
+    /// Swaps the index of two children.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    fn swap(this: &mut WidgetMut<'_, Self>, a: usize, b: usize) {
+        this.widget.children.swap(a, b);
+        this.widget.active_child = None;
+        this.ctx.children_changed();
+    }
+
+    /// Removes the child at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn remove(this: &mut WidgetMut<'_, Self>, idx: usize) {
+        let child = this.widget.children.remove(idx);
+        this.ctx.remove_child(child.widget);
+        this.widget.active_child = None;
+    }
+
+    /// Removes all children.
+    fn clear(this: &mut WidgetMut<'_, Self>) {
+        for child in this.widget.children.drain(..) {
+            this.ctx.remove_child(child.widget);
+        }
+        this.widget.active_child = None;
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Adaptive {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for child in &mut self.children {
+            ctx.register_child(&mut child.widget);
+        }
+    }
+
+    fn property_changed(&mut self, _ctx: &mut UpdateCtx<'_>, _property_type: TypeId) {}
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+
+        if axis == self.axis {
+            let (idx, min_length) = match len_req {
+                // We fill all the offered space, so that layout chooses the measured child.
+                LenReq::FitContent(space) => return space,
+                LenReq::MinContent => match self.smallest() {
+                    Some((idx, _)) => (idx, Length::ZERO),
+                    None => return Length::ZERO,
+                },
+                LenReq::MaxContent => match self.largest() {
+                    Some(idx) => (idx, self.children[idx].breakpoint),
+                    None => return Length::ZERO,
+                },
+            };
+            let length = ctx.compute_length(
+                &mut self.children[idx].widget,
+                len_req.into(),
+                context_size,
+                axis,
+                cross_length,
+            );
+            min_length.max(length)
+        } else {
+            // Without a known length we expect to be at our max-content length.
+            let idx = match cross_length {
+                Some(cross_length) => self.select(cross_length),
+                None => self.largest(),
+            };
+            let Some(idx) = idx else {
+                return Length::ZERO;
+            };
+            ctx.compute_length(
+                &mut self.children[idx].widget,
+                len_req.into(),
+                context_size,
+                axis,
+                cross_length,
+            )
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        self.active_child = self.select(size.get_coord(self.axis).px());
+
+        // TODO: move set_stashed to a different layout pass when possible
+        for (idx, child) in self.children.iter_mut().enumerate() {
+            ctx.set_stashed(&mut child.widget, Some(idx) != self.active_child);
+        }
+
+        let Some(active_child) = self.active_child else {
+            return;
+        };
+        let child = &mut self.children[active_child].widget;
+        let child_size = ctx.compute_size(child, SizeDef::fit(size), size.into());
+        ctx.run_layout(child, child_size);
+        ctx.place_child(child, Point::ORIGIN);
+        ctx.derive_baselines(child);
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.children
+            .iter()
+            .map(|child| child.widget.id())
+            .collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Adaptive", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{WidgetTag, WindowEvent};
+    use crate::dpi::PhysicalSize;
+    use crate::properties::Dimensions;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::{Flex, SizedBox};
+
+    #[test]
+    fn chooses_child_by_available_length() {
+        let narrow = WidgetTag::unique();
+        let medium = WidgetTag::unique();
+        let wide = WidgetTag::unique();
+
+        let child = |tag| {
+            SizedBox::empty()
+                .prepare()
+                .with_tag(tag)
+                .with_props(Dimensions::STRETCH)
+        };
+        // The children are intentionally out of order.
+        let widget = Adaptive::new(Axis::Horizontal)
+            .with(child(wide), 600.px())
+            .with(child(narrow), 0.px())
+            .with(child(medium), 300.px())
+            .prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (400, 100));
+        assert_eq!(harness.root_widget().inner().active_child(), Some(2));
+        assert!(harness.get_widget(narrow).ctx().is_stashed());
+        assert!(!harness.get_widget(medium).ctx().is_stashed());
+        assert!(harness.get_widget(wide).ctx().is_stashed());
+
+        harness.process_window_event(WindowEvent::Resize(PhysicalSize::new(800, 100)));
+        assert_eq!(harness.root_widget().inner().active_child(), Some(0));
+        assert!(!harness.get_widget(wide).ctx().is_stashed());
+        assert!(harness.get_widget(medium).ctx().is_stashed());
+
+        harness.process_window_event(WindowEvent::Resize(PhysicalSize::new(200, 100)));
+        assert_eq!(harness.root_widget().inner().active_child(), Some(1));
+        assert!(!harness.get_widget(narrow).ctx().is_stashed());
+        assert_eq!(
+            harness.get_widget(narrow).ctx().border_box().size(),
+            Size::new(200., 100.)
+        );
+    }
+
+    #[test]
+    fn falls_back_to_smallest_breakpoint() {
+        let widget = Adaptive::new(Axis::Horizontal)
+            .with(SizedBox::empty().prepare(), 300.px())
+            .with(SizedBox::empty().prepare(), 200.px())
+            .prepare();
+
+        let harness = TestHarness::create_with_size(test_property_set(), widget, (100, 100));
+        assert_eq!(harness.root_widget().inner().active_child(), Some(1));
+    }
+
+    #[test]
+    fn measures_chosen_child() {
+        let adaptive_tag = WidgetTag::unique();
+
+        let widget = Adaptive::new(Axis::Horizontal)
+            .with(SizedBox::empty().height(50.px()).prepare(), 0.px())
+            .with(SizedBox::empty().height(20.px()).prepare(), 300.px())
+            .prepare()
+            .with_tag(adaptive_tag);
+        let root = Flex::column().with_fixed(widget).prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), root, (400, 400));
+        let size = harness.get_widget(adaptive_tag).ctx().border_box().size();
+        assert_eq!(size, Size::new(400., 20.));
+
+        harness.process_window_event(WindowEvent::Resize(PhysicalSize::new(200, 400)));
+        let size = harness.get_widget(adaptive_tag).ctx().border_box().size();
+        assert_eq!(size, Size::new(200., 50.));
+    }
+}
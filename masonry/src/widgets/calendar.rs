@@ -0,0 +1,994 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, NodeId, Role};
+use time::{Date, Duration, Month, Weekday};
+use tracing::{Span, trace_span};
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::{
+    AccessCtx, AccessEvent, ActionCtx, ChildrenIds, ErasedAction, EventCtx, LayoutCtx, MeasureCtx,
+    PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PointerUpdate, PropertiesMut,
+    PropertiesRef, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
+    WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::{LayoutSize, LenDef, LenReq, Length, SizeDef};
+use crate::widgets::{Button, ButtonPress, CalendarDay, DayState, Label};
+
+/// The side of the square of a day in the grid.
+const CELL_SIZE: f64 = 32.;
+/// The height of the row of weekday names.
+const WEEKDAY_HEIGHT: f64 = 24.;
+/// The space between the header and the grid.
+const HEADER_GAP: f64 = 4.;
+/// The number of weeks shown, which is enough for any month.
+const WEEKS: usize = 6;
+
+/// A month grid of days, in which the user picks a date or a range of dates.
+///
+/// The header shows the displayed month with buttons to show the previous and next months.
+/// Weeks start on the [first day of the week](Self::with_first_day_of_week), Monday by default.
+///
+/// When focused, the calendar is used with the keyboard:
+/// * The arrow keys move the cursor by a day or a week.
+/// * `PageUp` and `PageDown` move it by a month, or by a year while `Shift` is held.
+/// * `Home` and `End` move it to the start or end of the week.
+/// * `Enter` and `Space` pick the date under the cursor.
+///
+/// In [range mode](CalendarMode::Range), the first picked date starts a range which is
+/// completed by the second one, and `Escape` cancels a started range.
+///
+/// There will be a [`CalendarAction`] when the user picks a date or completes a range.
+///
+/// Assistive technologies see the calendar as a grid of [`CalendarDay`] cells.
+pub struct Calendar {
+    /// The first day of the displayed month.
+    month: Date,
+    /// The day moved with the keyboard, always in the displayed month.
+    cursor: Date,
+    selection: Option<(Date, Date)>,
+    /// The first date of a range which isn't completed yet.
+    anchor: Option<Date>,
+    mode: CalendarMode,
+    first_day_of_week: Weekday,
+    today: Option<Date>,
+    focused: bool,
+    /// Whether the cursor is shown while the calendar doesn't have focus.
+    cursor_shown: bool,
+    /// The index of the day on which the pointer was pressed.
+    pressed: Option<usize>,
+
+    prev: WidgetPod<Button>,
+    title: WidgetPod<Label>,
+    next: WidgetPod<Button>,
+    weekdays: Vec<WidgetPod<Label>>,
+    days: Vec<WidgetPod<CalendarDay>>,
+    /// The top-left corner of the first day, in content-box coordinates.
+    grid_origin: Point,
+}
+
+/// Whether a [`Calendar`] picks a single date or a range of dates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CalendarMode {
+    /// A single date is picked.
+    #[default]
+    Single,
+    /// Two dates are picked, which are the ends of a range.
+    Range,
+}
+
+/// A date or range was picked in a [`Calendar`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CalendarAction {
+    /// A date was picked in [single mode](CalendarMode::Single).
+    DateSelected(Date),
+    /// A range was completed in [range mode](CalendarMode::Range).
+    RangeSelected {
+        /// The first day of the range.
+        start: Date,
+        /// The last day of the range, which may be the same as `start`.
+        end: Date,
+    },
+}
+
+/// What a key press did to a [`Calendar`].
+enum KeyResult {
+    Ignored,
+    Handled,
+    Picked(CalendarAction),
+}
+
+// --- MARK: BUILDERS
+impl Calendar {
+    /// Creates a calendar showing the month of `date`, with the cursor on it.
+    pub fn new(date: Date) -> Self {
+        let first_day_of_week = Weekday::Monday;
+        Self {
+            month: first_of_month(date),
+            cursor: date,
+            selection: None,
+            anchor: None,
+            mode: CalendarMode::Single,
+            first_day_of_week,
+            today: None,
+            focused: false,
+            cursor_shown: false,
+            pressed: None,
+            prev: WidgetPod::new(Button::with_text("‹")),
+            title: WidgetPod::new(Label::new(month_title(date))),
+            next: WidgetPod::new(Button::with_text("›")),
+            weekdays: weekday_labels(first_day_of_week),
+            days: (0..7 * WEEKS)
+                .map(|index| WidgetPod::new(CalendarDay::new(date, index / 7, index % 7)))
+                .collect(),
+            grid_origin: Point::ORIGIN,
+        }
+    }
+
+    /// Builder-style method to set the day on which weeks start.
+    pub fn with_first_day_of_week(mut self, weekday: Weekday) -> Self {
+        self.first_day_of_week = weekday;
+        self.weekdays = weekday_labels(weekday);
+        self
+    }
+
+    /// Builder-style method to pick single dates or ranges.
+    pub fn with_mode(mut self, mode: CalendarMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Builder-style method to set the selected date.
+    pub fn with_selected_date(mut self, date: Option<Date>) -> Self {
+        self.selection = date.map(|date| (date, date));
+        self
+    }
+
+    /// Builder-style method to set the selected range, from its first to its last day.
+    pub fn with_selected_range(mut self, range: Option<(Date, Date)>) -> Self {
+        self.selection = range.map(|(start, end)| ordered(start, end));
+        self
+    }
+
+    /// Builder-style method to set the current date, whose day has the `#today` class.
+    pub fn with_today(mut self, today: Option<Date>) -> Self {
+        self.today = today;
+        self
+    }
+
+    /// Builder-style method to show the cursor even when the calendar doesn't have focus,
+    /// for when another widget handles the keyboard on its behalf.
+    pub(crate) fn with_cursor_shown(mut self) -> Self {
+        self.cursor_shown = true;
+        self
+    }
+
+    /// Returns the year and month being displayed.
+    pub fn displayed_month(&self) -> (i32, Month) {
+        (self.month.year(), self.month.month())
+    }
+
+    /// Returns the date under the cursor.
+    pub fn cursor(&self) -> Date {
+        self.cursor
+    }
+
+    /// Returns the first and last days of the selection, which are the same in single mode.
+    pub fn selection(&self) -> Option<(Date, Date)> {
+        self.selection
+    }
+
+    /// Returns the day on which weeks start.
+    pub fn first_day_of_week(&self) -> Weekday {
+        self.first_day_of_week
+    }
+
+    /// Returns whether single dates or ranges are picked.
+    pub fn mode(&self) -> CalendarMode {
+        self.mode
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Calendar {
+    /// Shows the month of `date`, with the cursor on it.
+    pub fn show_date(this: &mut WidgetMut<'_, Self>, date: Date) {
+        this.widget.move_cursor(date);
+        Self::sync(this);
+    }
+
+    /// Sets the day on which weeks start.
+    pub fn set_first_day_of_week(this: &mut WidgetMut<'_, Self>, weekday: Weekday) {
+        if this.widget.first_day_of_week == weekday {
+            return;
+        }
+        this.widget.first_day_of_week = weekday;
+        for (index, weekday) in weekday_names(weekday).enumerate() {
+            Label::set_text(
+                &mut this.ctx.get_mut(&mut this.widget.weekdays[index]),
+                weekday,
+            );
+        }
+        Self::sync(this);
+    }
+
+    /// Sets whether single dates or ranges are picked, which cancels a started range.
+    pub fn set_mode(this: &mut WidgetMut<'_, Self>, mode: CalendarMode) {
+        this.widget.mode = mode;
+        this.widget.anchor = None;
+        this.ctx.request_accessibility_update();
+        Self::sync(this);
+    }
+
+    /// Sets the selected date.
+    pub fn set_selected_date(this: &mut WidgetMut<'_, Self>, date: Option<Date>) {
+        this.widget.selection = date.map(|date| (date, date));
+        this.widget.anchor = None;
+        Self::sync(this);
+    }
+
+    /// Sets the selected range, from its first to its last day.
+    pub fn set_selected_range(this: &mut WidgetMut<'_, Self>, range: Option<(Date, Date)>) {
+        this.widget.selection = range.map(|(start, end)| ordered(start, end));
+        this.widget.anchor = None;
+        Self::sync(this);
+    }
+
+    /// Sets the current date, whose day has the `#today` class.
+    pub fn set_today(this: &mut WidgetMut<'_, Self>, today: Option<Date>) {
+        this.widget.today = today;
+        Self::sync(this);
+    }
+
+    /// Handles a key pressed while another widget has focus on behalf of the calendar.
+    pub(crate) fn press_key(this: &mut WidgetMut<'_, Self>, key: &Key, shift: bool) {
+        match this.widget.on_key(key, shift) {
+            KeyResult::Ignored => return,
+            KeyResult::Handled => {}
+            KeyResult::Picked(action) => this.ctx.submit_action::<CalendarAction>(action),
+        }
+        Self::sync(this);
+    }
+
+    /// Updates the title and the days to the state of the calendar.
+    fn sync(this: &mut WidgetMut<'_, Self>) {
+        let title = month_title(this.widget.month);
+        {
+            let mut label = this.ctx.get_mut(&mut this.widget.title);
+            if label.widget.text().as_ref() != title {
+                Label::set_text(&mut label, title);
+            }
+        }
+
+        let start = this.widget.grid_start();
+        let range = this.widget.shown_range();
+        let show_cursor = this.widget.focused || this.widget.cursor_shown;
+        for (offset, day) in (0..).zip(&mut this.widget.days) {
+            let date = add_days(start, offset).unwrap_or(Date::MAX);
+            let state = DayState {
+                outside: date.month() != this.widget.month.month(),
+                today: this.widget.today == Some(date),
+                selected: range.is_some_and(|(start, end)| date == start || date == end),
+                in_range: range.is_some_and(|(start, end)| start < date && date < end),
+                focused: show_cursor && date == this.widget.cursor,
+            };
+            CalendarDay::set_day(&mut this.ctx.get_mut(day), date, state);
+        }
+        this.ctx.request_accessibility_update();
+    }
+}
+
+// --- MARK: INTERNALS
+impl Calendar {
+    /// Returns the first day of the grid, which may be in the previous month.
+    fn grid_start(&self) -> Date {
+        let offset = days_into_week(self.month, self.first_day_of_week);
+        add_days(self.month, -offset).unwrap_or(self.month)
+    }
+
+    /// Returns the range to highlight, which follows the cursor while a range is started.
+    fn shown_range(&self) -> Option<(Date, Date)> {
+        match self.anchor {
+            Some(anchor) => Some(ordered(anchor, self.cursor)),
+            None => self.selection,
+        }
+    }
+
+    fn move_cursor(&mut self, date: Date) {
+        self.cursor = date;
+        self.month = first_of_month(date);
+    }
+
+    /// Returns the index of the day at `pos`, in content-box coordinates.
+    fn day_at(&self, pos: Point) -> Option<usize> {
+        let x = (pos.x - self.grid_origin.x) / CELL_SIZE;
+        let y = (pos.y - self.grid_origin.y - WEEKDAY_HEIGHT) / CELL_SIZE;
+        if !(0. ..7.).contains(&x) || !(0. ..WEEKS as f64).contains(&y) {
+            return None;
+        }
+        Some(y as usize * 7 + x as usize)
+    }
+
+    fn date_at(&self, index: usize) -> Option<Date> {
+        add_days(self.grid_start(), i64::try_from(index).ok()?)
+    }
+
+    /// Picks `date`, returning the action to submit if a date or range was completed.
+    fn pick(&mut self, date: Date) -> Option<CalendarAction> {
+        self.move_cursor(date);
+        match self.mode {
+            CalendarMode::Single => {
+                self.selection = Some((date, date));
+                Some(CalendarAction::DateSelected(date))
+            }
+            CalendarMode::Range => match self.anchor.take() {
+                Some(anchor) => {
+                    let (start, end) = ordered(anchor, date);
+                    self.selection = Some((start, end));
+                    Some(CalendarAction::RangeSelected { start, end })
+                }
+                None => {
+                    self.anchor = Some(date);
+                    None
+                }
+            },
+        }
+    }
+
+    fn on_key(&mut self, key: &Key, shift: bool) -> KeyResult {
+        let months = if shift { 12 } else { 1 };
+        let week_day = days_into_week(self.cursor, self.first_day_of_week);
+        let target = match key {
+            Key::Named(NamedKey::ArrowLeft) => add_days(self.cursor, -1),
+            Key::Named(NamedKey::ArrowRight) => add_days(self.cursor, 1),
+            Key::Named(NamedKey::ArrowUp) => add_days(self.cursor, -7),
+            Key::Named(NamedKey::ArrowDown) => add_days(self.cursor, 7),
+            Key::Named(NamedKey::PageUp) => add_months(self.cursor, -months),
+            Key::Named(NamedKey::PageDown) => add_months(self.cursor, months),
+            Key::Named(NamedKey::Home) => add_days(self.cursor, -week_day),
+            Key::Named(NamedKey::End) => add_days(self.cursor, 6 - week_day),
+            Key::Named(NamedKey::Enter) => {
+                return self
+                    .pick(self.cursor)
+                    .map_or(KeyResult::Handled, KeyResult::Picked);
+            }
+            Key::Character(c) if c == " " => {
+                return self
+                    .pick(self.cursor)
+                    .map_or(KeyResult::Handled, KeyResult::Picked);
+            }
+            Key::Named(NamedKey::Escape) if self.anchor.is_some() => {
+                self.anchor = None;
+                return KeyResult::Handled;
+            }
+            _ => return KeyResult::Ignored,
+        };
+        if let Some(target) = target {
+            self.move_cursor(target);
+        }
+        KeyResult::Handled
+    }
+
+    fn request_sync(ctx: &mut EventCtx<'_>) {
+        ctx.mutate_self_later(|mut calendar| Self::sync(&mut calendar.downcast()));
+    }
+}
+
+/// Returns the first day of the month of `date`.
+fn first_of_month(date: Date) -> Date {
+    date.replace_day(1).unwrap()
+}
+
+/// Returns how many days `date` is after the start of its week.
+fn days_into_week(date: Date, first_day_of_week: Weekday) -> i64 {
+    let day = date.weekday().number_days_from_monday();
+    let first = first_day_of_week.number_days_from_monday();
+    i64::from((day + 7 - first) % 7)
+}
+
+fn add_days(date: Date, days: i64) -> Option<Date> {
+    date.checked_add(Duration::days(days))
+}
+
+/// Adds `months` to `date`, moving it to the last day of the month if that day doesn't exist.
+fn add_months(date: Date, months: i32) -> Option<Date> {
+    let index = date.year().checked_mul(12)? + i32::from(u8::from(date.month())) - 1 + months;
+    let year = index.div_euclid(12);
+    let month = Month::try_from(index.rem_euclid(12) as u8 + 1).ok()?;
+    let day = date.day().min(month.length(year));
+    Date::from_calendar_date(year, month, day).ok()
+}
+
+fn ordered(a: Date, b: Date) -> (Date, Date) {
+    if a <= b { (a, b) } else { (b, a) }
+}
+
+fn month_title(date: Date) -> String {
+    format!("{} {}", date.month(), date.year())
+}
+
+/// Returns the two-letter names of the days of the week, starting with `first_day_of_week`.
+fn weekday_names(first_day_of_week: Weekday) -> impl Iterator<Item = String> {
+    (0..7).map(move |n| {
+        let weekday = first_day_of_week.nth_next(n).to_string();
+        weekday[..2].to_string()
+    })
+}
+
+fn weekday_labels(first_day_of_week: Weekday) -> Vec<WidgetPod<Label>> {
+    weekday_names(first_day_of_week)
+        .map(|name| WidgetPod::new(Label::new(name)))
+        .collect()
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Calendar {
+    type Action = CalendarAction;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: None | Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                let Some(index) = self.day_at(ctx.local_position(state.position)) else {
+                    return;
+                };
+                self.pressed = Some(index);
+                ctx.capture_pointer();
+                ctx.request_focus();
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) if self.anchor.is_some() => {
+                // The started range follows the pointer.
+                let date = self
+                    .day_at(ctx.local_position(current.position))
+                    .and_then(|index| self.date_at(index))
+                    .filter(|date| date.month() == self.month.month());
+                if let Some(date) = date
+                    && date != self.cursor
+                {
+                    self.cursor = date;
+                    Self::request_sync(ctx);
+                }
+            }
+            PointerEvent::Up(PointerButtonEvent { state, .. }) => {
+                let Some(pressed) = self.pressed.take() else {
+                    return;
+                };
+                if self.day_at(ctx.local_position(state.position)) != Some(pressed) {
+                    return;
+                }
+                let Some(date) = self.date_at(pressed) else {
+                    return;
+                };
+                if let Some(action) = self.pick(date) {
+                    ctx.submit_action::<Self::Action>(action);
+                }
+                Self::request_sync(ctx);
+            }
+            PointerEvent::Cancel(..) => {
+                self.pressed = None;
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let TextEvent::Keyboard(event) = event else {
+            return;
+        };
+        if !event.state.is_down() {
+            return;
+        }
+        match self.on_key(&event.key, event.modifiers.shift()) {
+            KeyResult::Ignored => return,
+            KeyResult::Handled => {}
+            KeyResult::Picked(action) => ctx.submit_action::<Self::Action>(action),
+        }
+        Self::request_sync(ctx);
+        ctx.set_handled();
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if event.action != accesskit::Action::Click {
+            return;
+        }
+        let target = ctx.target();
+        let Some(index) = self.days.iter().position(|day| day.id() == target) else {
+            return;
+        };
+        let Some(date) = self.date_at(index) else {
+            return;
+        };
+        if let Some(action) = self.pick(date) {
+            ctx.submit_action::<Self::Action>(action);
+        }
+        Self::request_sync(ctx);
+        ctx.set_handled();
+    }
+
+    fn on_action(
+        &mut self,
+        ctx: &mut ActionCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        action: &ErasedAction,
+        source: WidgetId,
+    ) {
+        if !action.is::<ButtonPress>() {
+            return;
+        }
+        let months = if source == self.prev.id() {
+            -1
+        } else if source == self.next.id() {
+            1
+        } else {
+            return;
+        };
+        if let Some(date) = add_months(self.cursor, months) {
+            self.move_cursor(date);
+            ctx.mutate_self_later(|mut calendar| Self::sync(&mut calendar.downcast()));
+        }
+        ctx.set_handled();
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::WidgetAdded => {
+                ctx.mutate_self_later(|mut calendar| Self::sync(&mut calendar.downcast()));
+            }
+            Update::FocusChanged(focused) => {
+                self.focused = *focused;
+                ctx.mutate_self_later(|mut calendar| Self::sync(&mut calendar.downcast()));
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.prev);
+        ctx.register_child(&mut self.title);
+        ctx.register_child(&mut self.next);
+        for weekday in &mut self.weekdays {
+            ctx.register_child(weekday);
+        }
+        for day in &mut self.days {
+            ctx.register_child(day);
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        _cross_length: Option<Length>,
+    ) -> Length {
+        let context_size = LayoutSize::NONE;
+        let prev = ctx.compute_length(&mut self.prev, LenDef::MaxContent, context_size, axis, None);
+        let title = ctx.compute_length(
+            &mut self.title,
+            LenDef::MaxContent,
+            context_size,
+            axis,
+            None,
+        );
+        let next = ctx.compute_length(&mut self.next, LenDef::MaxContent, context_size, axis, None);
+        match axis {
+            Axis::Horizontal => prev
+                .saturating_add(title)
+                .saturating_add(next)
+                .max(Length::px(7. * CELL_SIZE)),
+            Axis::Vertical => prev.max(title).max(next).saturating_add(Length::px(
+                HEADER_GAP + WEEKDAY_HEIGHT + WEEKS as f64 * CELL_SIZE,
+            )),
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let prev_size = ctx.compute_size(&mut self.prev, SizeDef::MAX, size.into());
+        ctx.run_layout(&mut self.prev, prev_size);
+        let title_size = ctx.compute_size(&mut self.title, SizeDef::MAX, size.into());
+        ctx.run_layout(&mut self.title, title_size);
+        let next_size = ctx.compute_size(&mut self.next, SizeDef::MAX, size.into());
+        ctx.run_layout(&mut self.next, next_size);
+
+        let header_height = prev_size
+            .height
+            .max(title_size.height)
+            .max(next_size.height);
+        ctx.place_child(
+            &mut self.prev,
+            Point::new(0., (header_height - prev_size.height) * 0.5),
+        );
+        ctx.place_child(
+            &mut self.title,
+            Point::new(
+                (size.width - title_size.width) * 0.5,
+                (header_height - title_size.height) * 0.5,
+            ),
+        );
+        ctx.place_child(
+            &mut self.next,
+            Point::new(
+                size.width - next_size.width,
+                (header_height - next_size.height) * 0.5,
+            ),
+        );
+
+        self.grid_origin = Point::new(
+            (size.width - 7. * CELL_SIZE) * 0.5,
+            header_height + HEADER_GAP,
+        );
+        let Point { x: x0, y: y0 } = self.grid_origin;
+        for (column, weekday) in self.weekdays.iter_mut().enumerate() {
+            let weekday_size = ctx.compute_size(weekday, SizeDef::MAX, size.into());
+            ctx.run_layout(weekday, weekday_size);
+            let x = x0 + column as f64 * CELL_SIZE + (CELL_SIZE - weekday_size.width) * 0.5;
+            let y = y0 + (WEEKDAY_HEIGHT - weekday_size.height) * 0.5;
+            ctx.place_child(weekday, Point::new(x, y));
+        }
+        for (index, day) in self.days.iter_mut().enumerate() {
+            ctx.run_layout(day, Size::new(CELL_SIZE, CELL_SIZE));
+            let x = x0 + (index % 7) as f64 * CELL_SIZE;
+            let y = y0 + WEEKDAY_HEIGHT + (index / 7) as f64 * CELL_SIZE;
+            ctx.place_child(day, Point::new(x, y));
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Grid
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_label(month_title(self.month));
+        node.set_row_count(WEEKS);
+        node.set_column_count(7);
+        if self.mode == CalendarMode::Range {
+            node.set_multiselectable();
+        }
+        let cursor = self
+            .days
+            .iter()
+            .enumerate()
+            .find(|(index, _)| self.date_at(*index) == Some(self.cursor));
+        if let Some((_, day)) = cursor {
+            node.set_active_descendant(NodeId::from(day.id()));
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        [self.prev.id(), self.title.id(), self.next.id()]
+            .into_iter()
+            .chain(self.weekdays.iter().map(WidgetPod::id))
+            .chain(self.days.iter().map(WidgetPod::id))
+            .collect()
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Calendar", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::NewWidget;
+    use crate::core::keyboard::{KeyState, KeyboardEvent, Modifiers};
+    use crate::properties::ContentColor;
+    use crate::testing::{TestHarness, TestHarnessParams, assert_render_snapshot};
+    use crate::theme::{self, test_property_set};
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    fn press(harness: &mut TestHarness<Calendar>, key: Key, shift: bool) {
+        let modifiers = if shift {
+            Modifiers::SHIFT
+        } else {
+            Modifiers::empty()
+        };
+        for state in [KeyState::Down, KeyState::Up] {
+            harness.process_text_event(TextEvent::Keyboard(KeyboardEvent {
+                state,
+                key: key.clone(),
+                modifiers,
+                ..KeyboardEvent::default()
+            }));
+        }
+    }
+
+    fn press_named(harness: &mut TestHarness<Calendar>, named: NamedKey) {
+        press(harness, Key::Named(named), false);
+    }
+
+    /// Clicks the day of the grid showing `date`.
+    fn click_day(harness: &mut TestHarness<Calendar>, date: Date) {
+        let ids = harness
+            .root_widget()
+            .inner()
+            .days
+            .iter()
+            .map(WidgetPod::id)
+            .collect::<Vec<_>>();
+        let center = ids
+            .into_iter()
+            .map(|id| harness.get_widget_with_id(id))
+            .find(|day| day.downcast::<CalendarDay>().unwrap().inner().date() == date)
+            .map(|day| {
+                day.ctx()
+                    .window_transform()
+                    .transform_rect_bbox(day.ctx().border_box())
+                    .center()
+            })
+            .unwrap();
+        harness.mouse_move(center);
+        harness.mouse_button_press(None);
+        harness.mouse_button_release(None);
+    }
+
+    fn day_state(harness: &TestHarness<Calendar>, index: usize) -> DayState {
+        let id = harness.root_widget().inner().days[index].id();
+        harness
+            .get_widget_with_id(id)
+            .downcast::<CalendarDay>()
+            .unwrap()
+            .inner()
+            .state
+    }
+
+    #[test]
+    fn month_with_selected_date() {
+        let calendar = Calendar::new(date(2025, Month::March, 1))
+            .with_selected_date(Some(date(2025, Month::March, 14)))
+            .with_today(Some(date(2025, Month::March, 3)));
+        // A whole month of text doesn't fit the default size limit.
+        let params = TestHarnessParams::DEFAULT
+            .with_size((280, 280))
+            .with_max_screenshot_size(16 * TestHarnessParams::KIBIBYTE);
+        let mut harness =
+            TestHarness::create_with(test_property_set(), NewWidget::new(calendar), params);
+
+        assert_render_snapshot!(harness, "calendar_month_with_selected_date");
+    }
+
+    #[test]
+    fn outside_days_are_dimmed() {
+        // March 2025 starts on a Saturday, so the grid starts with February 24.
+        let calendar = Calendar::new(date(2025, Month::March, 1));
+        let harness = TestHarness::create(test_property_set(), NewWidget::new(calendar));
+        let day_color = |index: usize| {
+            let day = harness.root_widget().inner().days[index].id();
+            let label = harness.get_widget_with_id(day).children()[0].id();
+            let label = harness.get_widget_with_id(label);
+            label.get_prop::<ContentColor>().color
+        };
+
+        assert_eq!(day_color(0), theme::DISABLED_TEXT_COLOR);
+        assert_eq!(day_color(5), theme::TEXT_COLOR);
+    }
+
+    #[test]
+    fn month_arithmetic() {
+        assert_eq!(
+            add_months(date(2026, Month::January, 31), 1),
+            Some(date(2026, Month::February, 28))
+        );
+        assert_eq!(
+            add_months(date(2026, Month::January, 15), -1),
+            Some(date(2025, Month::December, 15))
+        );
+        assert_eq!(
+            add_months(date(2024, Month::February, 29), 12),
+            Some(date(2025, Month::February, 28))
+        );
+        assert_eq!(
+            days_into_week(date(2026, Month::January, 4), Weekday::Monday),
+            6
+        );
+        assert_eq!(
+            days_into_week(date(2026, Month::January, 4), Weekday::Sunday),
+            0
+        );
+    }
+
+    #[test]
+    fn first_day_of_week() {
+        let january = date(2026, Month::January, 15);
+        let calendar = Calendar::new(january);
+        assert_eq!(calendar.grid_start(), date(2025, Month::December, 29));
+        let calendar = Calendar::new(january).with_first_day_of_week(Weekday::Sunday);
+        assert_eq!(calendar.grid_start(), date(2025, Month::December, 28));
+
+        let calendar = NewWidget::new(Calendar::new(january));
+        let calendar_id = calendar.id();
+        let mut harness = TestHarness::create(test_property_set(), calendar);
+        harness.focus_on(Some(calendar_id));
+        harness.edit_root_widget(|mut calendar| {
+            Calendar::set_first_day_of_week(&mut calendar, Weekday::Sunday);
+        });
+        let calendar = harness.root_widget();
+        let first = calendar.inner().weekdays[0].id();
+        let first = harness.get_widget_with_id(first);
+        assert_eq!(
+            first.downcast::<Label>().unwrap().inner().text().as_ref(),
+            "Su"
+        );
+        // The days outside of the displayed month are marked.
+        assert!(day_state(&harness, 3).outside);
+        assert!(!day_state(&harness, 4).outside);
+    }
+
+    #[test]
+    fn keyboard_navigation() {
+        let calendar = NewWidget::new(Calendar::new(date(2026, Month::January, 15)));
+        let calendar_id = calendar.id();
+        let mut harness = TestHarness::create(test_property_set(), calendar);
+        harness.focus_on(Some(calendar_id));
+
+        press_named(&mut harness, NamedKey::ArrowRight);
+        assert_eq!(
+            harness.root_widget().inner().cursor(),
+            date(2026, Month::January, 16)
+        );
+        press_named(&mut harness, NamedKey::ArrowDown);
+        assert_eq!(
+            harness.root_widget().inner().cursor(),
+            date(2026, Month::January, 23)
+        );
+        press_named(&mut harness, NamedKey::Home);
+        assert_eq!(
+            harness.root_widget().inner().cursor(),
+            date(2026, Month::January, 19)
+        );
+        press_named(&mut harness, NamedKey::End);
+        assert_eq!(
+            harness.root_widget().inner().cursor(),
+            date(2026, Month::January, 25)
+        );
+
+        // Moving the cursor out of the month shows the next one.
+        press_named(&mut harness, NamedKey::ArrowDown);
+        assert_eq!(
+            harness.root_widget().inner().cursor(),
+            date(2026, Month::February, 1)
+        );
+        assert_eq!(
+            harness.root_widget().inner().displayed_month(),
+            (2026, Month::February)
+        );
+        press_named(&mut harness, NamedKey::PageUp);
+        assert_eq!(
+            harness.root_widget().inner().cursor(),
+            date(2026, Month::January, 1)
+        );
+        press(&mut harness, Key::Named(NamedKey::PageDown), true);
+        assert_eq!(
+            harness.root_widget().inner().cursor(),
+            date(2027, Month::January, 1)
+        );
+
+        press_named(&mut harness, NamedKey::Enter);
+        let (action, _) = harness.pop_action::<CalendarAction>().unwrap();
+        assert_eq!(
+            action,
+            CalendarAction::DateSelected(date(2027, Month::January, 1))
+        );
+        let selected = date(2027, Month::January, 1);
+        assert_eq!(
+            harness.root_widget().inner().selection(),
+            Some((selected, selected))
+        );
+        // The grid starts on Monday, December 28.
+        let state = day_state(&harness, 4);
+        assert!(state.selected && state.focused);
+    }
+
+    #[test]
+    fn header_buttons_change_month() {
+        let calendar = NewWidget::new(Calendar::new(date(2026, Month::January, 31)));
+        let calendar_id = calendar.id();
+        let mut harness = TestHarness::create(test_property_set(), calendar);
+        harness.focus_on(Some(calendar_id));
+        let next = harness.root_widget().inner().next.id();
+        let next = harness.get_widget_with_id(next);
+        let center = next
+            .ctx()
+            .window_transform()
+            .transform_rect_bbox(next.ctx().border_box())
+            .center();
+        harness.mouse_move(center);
+        harness.mouse_button_press(None);
+        harness.mouse_button_release(None);
+        assert_eq!(
+            harness.root_widget().inner().displayed_month(),
+            (2026, Month::February)
+        );
+        assert_eq!(
+            harness.root_widget().inner().cursor(),
+            date(2026, Month::February, 28)
+        );
+        assert_eq!(harness.pop_action::<CalendarAction>(), None);
+    }
+
+    #[test]
+    fn range_selection() {
+        let calendar = NewWidget::new(
+            Calendar::new(date(2026, Month::January, 15)).with_mode(CalendarMode::Range),
+        );
+        let calendar_id = calendar.id();
+        let mut harness = TestHarness::create(test_property_set(), calendar);
+        harness.focus_on(Some(calendar_id));
+
+        // The end can be picked before the start.
+        click_day(&mut harness, date(2026, Month::January, 20));
+        assert_eq!(harness.pop_action::<CalendarAction>(), None);
+        click_day(&mut harness, date(2026, Month::January, 12));
+        let (action, _) = harness.pop_action::<CalendarAction>().unwrap();
+        assert_eq!(
+            action,
+            CalendarAction::RangeSelected {
+                start: date(2026, Month::January, 12),
+                end: date(2026, Month::January, 20),
+            }
+        );
+        // The grid starts on Monday, December 29.
+        assert!(day_state(&harness, 14).selected);
+        assert!(day_state(&harness, 16).in_range);
+        assert!(day_state(&harness, 22).selected);
+
+        // Escape cancels a started range.
+        press(&mut harness, Key::Character(" ".into()), false);
+        press_named(&mut harness, NamedKey::ArrowRight);
+        press_named(&mut harness, NamedKey::Escape);
+        press_named(&mut harness, NamedKey::Enter);
+        assert_eq!(harness.pop_action::<CalendarAction>(), None);
+        press_named(&mut harness, NamedKey::ArrowRight);
+        press_named(&mut harness, NamedKey::Enter);
+        let (action, _) = harness.pop_action::<CalendarAction>().unwrap();
+        assert_eq!(
+            action,
+            CalendarAction::RangeSelected {
+                start: date(2026, Month::January, 13),
+                end: date(2026, Month::January, 14),
+            }
+        );
+    }
+}
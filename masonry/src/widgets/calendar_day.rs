@@ -0,0 +1,187 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use time::Date;
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ChildrenIds, LayoutCtx, MeasureCtx, NoAction, PaintCtx, PropertiesRef, RegisterCtx,
+    Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Size};
+use crate::layout::{LayoutSize, LenReq, Length, SizeDef};
+use crate::widgets::Label;
+
+/// The class of the label of a [`CalendarDay`] outside of the displayed month.
+///
+/// The default theme uses it to show the day in [`DISABLED_TEXT_COLOR`](crate::theme::DISABLED_TEXT_COLOR).
+pub const CALENDAR_OUTSIDE_DAY_CLASS: &str = "#calendar_outside_day";
+
+/// A day in the grid of a [`Calendar`](super::Calendar).
+///
+/// Days have the following [classes]:
+/// * `#outside` for the days of the previous and next months.
+/// * `#today` for the [current date](super::Calendar::with_today).
+/// * `#selected` for the selected date, or the first and last days of the selected range.
+/// * `#in-range` for the days between the first and last days of the selected range.
+/// * `#focused` for the day under the cursor, while the calendar has focus.
+///
+/// [classes]: masonry_core::doc::masonry_concepts#classes
+pub struct CalendarDay {
+    date: Date,
+    label: WidgetPod<Label>,
+    row: usize,
+    column: usize,
+    pub(crate) state: DayState,
+}
+
+/// The classes of a [`CalendarDay`].
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct DayState {
+    pub(crate) outside: bool,
+    pub(crate) today: bool,
+    pub(crate) selected: bool,
+    pub(crate) in_range: bool,
+    pub(crate) focused: bool,
+}
+
+impl DayState {
+    fn classes(self) -> [(&'static str, bool); 5] {
+        [
+            ("#outside", self.outside),
+            ("#today", self.today),
+            ("#selected", self.selected),
+            ("#in-range", self.in_range),
+            ("#focused", self.focused),
+        ]
+    }
+}
+
+// --- MARK: BUILDERS
+impl CalendarDay {
+    pub(crate) fn new(date: Date, row: usize, column: usize) -> Self {
+        Self {
+            date,
+            label: WidgetPod::new(Label::new(date.day().to_string())),
+            row,
+            column,
+            state: DayState::default(),
+        }
+    }
+
+    /// Returns the date of the day.
+    pub fn date(&self) -> Date {
+        self.date
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl CalendarDay {
+    /// Shows `date`, with the classes of `state`.
+    pub(crate) fn set_day(this: &mut WidgetMut<'_, Self>, date: Date, state: DayState) {
+        if this.widget.date != date {
+            if this.widget.date.day() != date.day() {
+                let mut label = this.ctx.get_mut(&mut this.widget.label);
+                Label::set_text(&mut label, date.day().to_string());
+            }
+            this.widget.date = date;
+            this.ctx.request_accessibility_update();
+        }
+        if this.widget.state == state {
+            return;
+        }
+        if this.widget.state.outside != state.outside {
+            let mut label = this.ctx.get_mut(&mut this.widget.label);
+            if state.outside {
+                label.ctx.add_class(CALENDAR_OUTSIDE_DAY_CLASS);
+            } else {
+                label.ctx.remove_class(CALENDAR_OUTSIDE_DAY_CLASS);
+            }
+        }
+        for ((class, old), (_, new)) in this.widget.state.classes().into_iter().zip(state.classes())
+        {
+            if new && !old {
+                this.ctx.add_class(class);
+            } else if old && !new {
+                this.ctx.remove_class(class);
+            }
+        }
+        this.widget.state = state;
+        this.ctx.request_accessibility_update();
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for CalendarDay {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.label);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        ctx.compute_length(
+            &mut self.label,
+            len_req.into(),
+            context_size,
+            axis,
+            cross_length,
+        )
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let label_size = ctx.compute_size(&mut self.label, SizeDef::fit(size), size.into());
+        ctx.run_layout(&mut self.label, label_size);
+        let origin = ((size - label_size).to_vec2() * 0.5).to_point();
+        ctx.place_child(&mut self.label, origin);
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::GridCell
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_label(format!(
+            "{}, {} {}, {}",
+            self.date.weekday(),
+            self.date.month(),
+            self.date.day(),
+            self.date.year()
+        ));
+        node.set_row_index(self.row);
+        node.set_column_index(self.column);
+        node.set_selected(self.state.selected || self.state.in_range);
+        node.add_action(accesskit::Action::Click);
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.label.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("CalendarDay", id = id.trace())
+    }
+}
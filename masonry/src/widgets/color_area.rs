@@ -0,0 +1,302 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::pointer::PointerButton;
+use crate::core::{
+    AccessCtx, ChildrenIds, EventCtx, LayoutCtx, MeasureCtx, PaintCtx, PointerButtonEvent,
+    PointerEvent, PointerUpdate, PropertiesMut, PropertiesRef, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Circle, Point, Size, Stroke};
+use crate::layout::{LenReq, Length};
+use crate::peniko::Color;
+use crate::properties::types::Gradient;
+use crate::properties::{BorderWidth, CornerRadius};
+use crate::widgets::hue_color;
+
+/// The size of the area, when not constrained by its parent.
+const AREA_WIDTH: f64 = 200.;
+const AREA_HEIGHT: f64 = 150.;
+const MARKER_RADIUS: f64 = 6.;
+
+/// A square in which the saturation and value of a color are picked, for a given hue.
+///
+/// The saturation increases from left to right, and the value from bottom to top.
+/// When focused, the arrow keys change the saturation and value by 1%,
+/// or 10% while `Shift` is held.
+///
+/// There will be a [`ColorAreaMoved`] action when the user picks another point.
+pub struct ColorArea {
+    hue: f32,
+    saturation: f32,
+    value: f32,
+}
+
+/// The saturation and value of a [`ColorArea`] were changed by the user.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorAreaMoved {
+    /// The saturation, from 0 to 1.
+    pub saturation: f32,
+    /// The value, from 0 to 1.
+    pub value: f32,
+}
+
+// --- MARK: BUILDERS
+impl ColorArea {
+    /// Creates an area for the `hue` in degrees, with the given saturation and value from 0 to 1.
+    pub fn new(hue: f32, saturation: f32, value: f32) -> Self {
+        Self {
+            hue,
+            saturation: saturation.clamp(0., 1.),
+            value: value.clamp(0., 1.),
+        }
+    }
+
+    /// Returns the hue of the area, in degrees.
+    pub fn hue(&self) -> f32 {
+        self.hue
+    }
+
+    /// Returns the picked saturation, from 0 to 1.
+    pub fn saturation(&self) -> f32 {
+        self.saturation
+    }
+
+    /// Returns the picked value, from 0 to 1.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl ColorArea {
+    /// Sets the hue of the area, in degrees.
+    pub fn set_hue(this: &mut WidgetMut<'_, Self>, hue: f32) {
+        if this.widget.hue != hue {
+            this.widget.hue = hue;
+            this.ctx.request_render();
+        }
+    }
+
+    /// Sets the picked saturation and value, from 0 to 1.
+    pub fn set_saturation_value(this: &mut WidgetMut<'_, Self>, saturation: f32, value: f32) {
+        let (saturation, value) = (saturation.clamp(0., 1.), value.clamp(0., 1.));
+        if this.widget.saturation != saturation || this.widget.value != value {
+            this.widget.saturation = saturation;
+            this.widget.value = value;
+            this.ctx.request_render();
+        }
+    }
+}
+
+// --- MARK: INTERNALS
+impl ColorArea {
+    /// Moves the marker to `pos`, in content-box coordinates.
+    fn pick_position(&mut self, ctx: &mut EventCtx<'_>, pos: Point) {
+        let size = ctx.content_box().size();
+        #[expect(clippy::cast_possible_truncation, reason = "color components are f32")]
+        let (saturation, value) = (
+            (pos.x / size.width).clamp(0., 1.) as f32,
+            (1. - pos.y / size.height).clamp(0., 1.) as f32,
+        );
+        self.pick(ctx, saturation, value);
+    }
+
+    fn pick(&mut self, ctx: &mut EventCtx<'_>, saturation: f32, value: f32) {
+        let (saturation, value) = (saturation.clamp(0., 1.), value.clamp(0., 1.));
+        if saturation == self.saturation && value == self.value {
+            return;
+        }
+        self.saturation = saturation;
+        self.value = value;
+        ctx.submit_action::<ColorAreaMoved>(ColorAreaMoved { saturation, value });
+        ctx.request_render();
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for ColorArea {
+    type Action = ColorAreaMoved;
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if ctx.is_disabled() {
+            return;
+        }
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: Some(PointerButton::Primary) | None,
+                state,
+                ..
+            }) => {
+                ctx.request_focus();
+                ctx.capture_pointer();
+                let pos = ctx.local_position(state.position);
+                self.pick_position(ctx, pos);
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) if ctx.is_active() => {
+                let pos = ctx.local_position(current.position);
+                self.pick_position(ctx, pos);
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if ctx.is_disabled() || !ctx.is_focus_target() {
+            return;
+        }
+        let TextEvent::Keyboard(event) = event else {
+            return;
+        };
+        if event.state.is_up() {
+            return;
+        }
+        let step = if event.modifiers.shift() { 0.1 } else { 0.01 };
+        let (saturation, value) = match &event.key {
+            Key::Named(NamedKey::ArrowLeft) => (self.saturation - step, self.value),
+            Key::Named(NamedKey::ArrowRight) => (self.saturation + step, self.value),
+            Key::Named(NamedKey::ArrowDown) => (self.saturation, self.value - step),
+            Key::Named(NamedKey::ArrowUp) => (self.saturation, self.value + step),
+            _ => return,
+        };
+        self.pick(ctx, saturation, value);
+        ctx.set_handled();
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::FocusChanged(_) = event {
+            ctx.request_render();
+        }
+    }
+
+    fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
+
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        _cross_length: Option<Length>,
+    ) -> Length {
+        match (axis, len_req) {
+            (Axis::Horizontal, LenReq::FitContent(space)) => space,
+            (Axis::Horizontal, _) => Length::const_px(AREA_WIDTH),
+            (Axis::Vertical, _) => Length::const_px(AREA_HEIGHT),
+        }
+    }
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, _size: Size) {}
+
+    fn paint(
+        &mut self,
+        ctx: &mut PaintCtx<'_>,
+        props: &PropertiesRef<'_>,
+        painter: &mut Painter<'_>,
+    ) {
+        let cache = ctx.property_cache();
+        let border_width = props.get::<BorderWidth>(cache);
+        let corner_radius = props.get::<CornerRadius>(cache);
+        let shape = border_width.bg_rect(ctx.border_box(), corner_radius);
+        let rect = shape.rect();
+
+        // The saturation goes from white to the pure hue, and the value darkens it to black.
+        let saturation = Gradient::new_linear(FRAC_PI_2)
+            .with_stops([(0., Color::WHITE), (1., hue_color(self.hue))]);
+        painter
+            .fill(shape, &saturation.get_peniko_gradient_for_rect(rect))
+            .draw();
+        let value = Gradient::new_linear(PI)
+            .with_stops([(0., Color::BLACK.with_alpha(0.)), (1., Color::BLACK)]);
+        painter
+            .fill(shape, &value.get_peniko_gradient_for_rect(rect))
+            .draw();
+
+        let size = ctx.content_box().size();
+        let center = Point::new(
+            f64::from(self.saturation) * size.width,
+            f64::from(1. - self.value) * size.height,
+        );
+        let marker = Circle::new(center, MARKER_RADIUS);
+        painter
+            .stroke(marker, &Stroke::new(3.), Color::BLACK)
+            .draw();
+        painter
+            .stroke(marker, &Stroke::new(1.5), Color::WHITE)
+            .draw();
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Slider
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_label("Saturation and brightness");
+        node.set_value(format!(
+            "Saturation {}%, brightness {}%",
+            (self.saturation * 100.).round(),
+            (self.value * 100.).round()
+        ));
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::new()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("ColorArea", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::NewWidget;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+
+    #[test]
+    fn pick_with_pointer_and_keys() {
+        let area = NewWidget::new(ColorArea::new(120., 0., 0.));
+        let mut harness = TestHarness::create(test_property_set(), area);
+        let id = harness.root_id();
+
+        harness.mouse_click_on(id, None);
+        let (moved, _) = harness.pop_action::<ColorAreaMoved>().unwrap();
+        assert!((moved.saturation - 0.5).abs() < 0.01);
+        assert!((moved.value - 0.5).abs() < 0.01);
+
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowUp)));
+        let (moved, _) = harness.pop_action::<ColorAreaMoved>().unwrap();
+        assert_eq!(moved.saturation, harness.root_widget().inner().saturation());
+        assert_eq!(moved.value, harness.root_widget().inner().value());
+        assert_eq!(harness.root_widget().inner().hue(), 120.);
+    }
+}
@@ -0,0 +1,785 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ActionCtx, ChildrenIds, ErasedAction, LayoutCtx, MeasureCtx, PaintCtx,
+    PropertiesMut, PropertiesRef, RegisterCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Rect, Size};
+use crate::layout::{LayoutSize, LenDef, LenReq, Length, SizeDef};
+use crate::peniko::Color;
+use crate::peniko::color::{AlphaColor, Hsl, Hwb, Oklch, Srgb, parse_color as parse_css_color};
+use crate::widgets::{
+    Button, ButtonPress, ColorArea, ColorAreaMoved, ColorChannel, ColorSlider, ColorSliderMoved,
+    ColorSwatch, Label, TextAction, TextArea, TextInput, paint_color,
+};
+
+/// The space between the parts of the picker.
+const GAP: f64 = 8.;
+/// The space between the swatches of the palette.
+const SWATCH_GAP: f64 = 4.;
+
+/// A widget to pick a color, with its hue, saturation, value and opacity, or as text.
+///
+/// The picker is made of:
+/// * A [`ColorArea`] for the saturation and value.
+/// * A [`ColorSlider`] for the hue, and another for the opacity.
+/// * A preview of the color next to a text field, which shows the color in the
+///   [format](ColorFormat) selected with the button next to it.
+///   Any CSS color can be typed, such as `#ff8000`, `rgb(255 128 0)`, `hsl(30 100% 50%)`
+///   or `oklch(0.73 0.19 50)`. While the text isn't a valid color, the picker is [invalid].
+/// * A palette of [swatches](Self::with_swatches), if any, which pick their color when pressed.
+///
+/// The hue is kept while picking grays, so that moving back to a saturated color
+/// doesn't lose it.
+///
+/// There will be a [`ColorChanged`] action when the user changes the color.
+///
+/// [invalid]: masonry_core::doc::masonry_concepts#invalid
+pub struct ColorPicker {
+    color: Color,
+    hsva: Hsva,
+    format: ColorFormat,
+    area: WidgetPod<ColorArea>,
+    hue: WidgetPod<ColorSlider>,
+    alpha: WidgetPod<ColorSlider>,
+    input: WidgetPod<TextInput>,
+    format_button: WidgetPod<Button>,
+    swatch_colors: Vec<Color>,
+    swatches: Vec<WidgetPod<ColorSwatch>>,
+    preview_rect: Rect,
+}
+
+/// The text format in which a [`ColorPicker`] shows its color.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorFormat {
+    /// Hexadecimal sRGB, such as `#ff8000`.
+    #[default]
+    Hex,
+    /// Functional sRGB, such as `rgb(255 128 0)`.
+    Rgb,
+    /// Hue, saturation and lightness, such as `hsl(30 100% 50%)`.
+    Hsl,
+    /// Oklab lightness, chroma and hue, such as `oklch(0.732 0.186 53)`.
+    Oklch,
+}
+
+impl ColorFormat {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Hex => "HEX",
+            Self::Rgb => "RGB",
+            Self::Hsl => "HSL",
+            Self::Oklch => "OKLCH",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            Self::Hex => Self::Rgb,
+            Self::Rgb => Self::Hsl,
+            Self::Hsl => Self::Oklch,
+            Self::Oklch => Self::Hex,
+        }
+    }
+}
+
+/// The color of a [`ColorPicker`] was changed by the user.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorChanged(pub Color);
+
+/// A color as its hue, saturation, value and alpha.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Hsva {
+    /// The hue in degrees, which is kept for grays.
+    pub(crate) hue: f32,
+    pub(crate) saturation: f32,
+    pub(crate) value: f32,
+    pub(crate) alpha: f32,
+}
+
+impl Hsva {
+    /// Converts `color`, keeping `hue` if the color is a gray.
+    pub(crate) fn from_color(color: Color, hue: f32) -> Self {
+        // HWB has the same hue as HSV, and its whiteness and blackness map to saturation and value.
+        let [color_hue, whiteness, blackness, alpha] = color.convert::<Hwb>().components;
+        let value = (1. - blackness / 100.).clamp(0., 1.);
+        let saturation = if value > 0. {
+            (1. - whiteness / 100. / value).clamp(0., 1.)
+        } else {
+            0.
+        };
+        Self {
+            // Conversions leave a tiny saturation in some grays.
+            hue: if saturation > 1e-4 { color_hue } else { hue },
+            saturation,
+            value,
+            alpha: alpha.clamp(0., 1.),
+        }
+    }
+
+    pub(crate) fn to_color(self) -> Color {
+        let whiteness = (1. - self.saturation) * self.value * 100.;
+        let blackness = (1. - self.value) * 100.;
+        AlphaColor::<Hwb>::new([self.hue, whiteness, blackness, self.alpha]).convert()
+    }
+}
+
+/// Returns the fully saturated and bright color of `hue`, in degrees.
+pub(crate) fn hue_color(hue: f32) -> Color {
+    Hsva {
+        hue,
+        saturation: 1.,
+        value: 1.,
+        alpha: 1.,
+    }
+    .to_color()
+}
+
+// --- MARK: BUILDERS
+impl ColorPicker {
+    /// Creates a picker showing `color`.
+    pub fn new(color: Color) -> Self {
+        let hsva = Hsva::from_color(color, 0.);
+        let format = ColorFormat::default();
+        Self {
+            color,
+            hsva,
+            format,
+            area: WidgetPod::new(ColorArea::new(hsva.hue, hsva.saturation, hsva.value)),
+            hue: WidgetPod::new(ColorSlider::new(ColorChannel::Hue, hsva.hue)),
+            alpha: WidgetPod::new(
+                ColorSlider::new(ColorChannel::Alpha, hsva.alpha).with_color(color),
+            ),
+            input: WidgetPod::new(TextInput::new(&format_color(color, format))),
+            format_button: WidgetPod::new(Button::with_text(format.label())),
+            swatch_colors: Vec::new(),
+            swatches: Vec::new(),
+            preview_rect: Rect::ZERO,
+        }
+    }
+
+    /// Builder-style method to set the format of the text field.
+    pub fn with_format(mut self, format: ColorFormat) -> Self {
+        self.format = format;
+        self.input = WidgetPod::new(TextInput::new(&format_color(self.color, format)));
+        self.format_button = WidgetPod::new(Button::with_text(format.label()));
+        self
+    }
+
+    /// Builder-style method to set the colors of the palette.
+    pub fn with_swatches(mut self, colors: Vec<Color>) -> Self {
+        self.swatches = swatch_pods(&colors);
+        self.swatch_colors = colors;
+        self
+    }
+
+    /// Returns the color of the picker.
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Returns the format of the text field.
+    pub fn format(&self) -> ColorFormat {
+        self.format
+    }
+
+    /// Returns the colors of the palette.
+    pub fn swatches(&self) -> &[Color] {
+        &self.swatch_colors
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl ColorPicker {
+    /// Sets the color.
+    ///
+    /// Does nothing if the picker already has this color, so that the text being typed
+    /// isn't replaced.
+    pub fn set_color(this: &mut WidgetMut<'_, Self>, color: Color) {
+        if this.widget.color == color {
+            return;
+        }
+        this.widget.color = color;
+        this.widget.hsva = Hsva::from_color(color, this.widget.hsva.hue);
+        Self::sync(this, true);
+    }
+
+    /// Sets the format of the text field.
+    pub fn set_format(this: &mut WidgetMut<'_, Self>, format: ColorFormat) {
+        if this.widget.format == format {
+            return;
+        }
+        this.widget.format = format;
+        {
+            let mut button = this.ctx.get_mut(&mut this.widget.format_button);
+            Label::set_text(
+                &mut Button::child_mut(&mut button).downcast(),
+                format.label(),
+            );
+        }
+        Self::show_text(this);
+    }
+
+    /// Sets the colors of the palette.
+    pub fn set_swatches(this: &mut WidgetMut<'_, Self>, colors: Vec<Color>) {
+        if this.widget.swatch_colors == colors {
+            return;
+        }
+        for swatch in this.widget.swatches.drain(..) {
+            this.ctx.remove_child(swatch);
+        }
+        this.widget.swatches = swatch_pods(&colors);
+        this.widget.swatch_colors = colors;
+        this.ctx.children_changed();
+    }
+
+    /// Returns a mutable reference to the text input.
+    pub fn input_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, TextInput> {
+        this.ctx.get_mut(&mut this.widget.input)
+    }
+
+    /// Updates the children to the color, and the text unless it's being typed.
+    fn sync(this: &mut WidgetMut<'_, Self>, update_text: bool) {
+        let Hsva {
+            hue,
+            saturation,
+            value,
+            alpha,
+        } = this.widget.hsva;
+        {
+            let mut area = this.ctx.get_mut(&mut this.widget.area);
+            ColorArea::set_hue(&mut area, hue);
+            ColorArea::set_saturation_value(&mut area, saturation, value);
+        }
+        ColorSlider::set_value(&mut this.ctx.get_mut(&mut this.widget.hue), hue);
+        {
+            let mut slider = this.ctx.get_mut(&mut this.widget.alpha);
+            ColorSlider::set_value(&mut slider, alpha);
+            ColorSlider::set_color(&mut slider, this.widget.color);
+        }
+        if update_text {
+            Self::show_text(this);
+        }
+        this.ctx.request_render();
+    }
+
+    /// Replaces the text of the field with the color.
+    fn show_text(this: &mut WidgetMut<'_, Self>) {
+        let text = format_color(this.widget.color, this.widget.format);
+        TextArea::reset_text(&mut TextInput::text_mut(&mut Self::input_mut(this)), &text);
+        this.ctx.set_invalid(false);
+    }
+}
+
+// --- MARK: INTERNALS
+impl ColorPicker {
+    /// Picks the color of the current hue, saturation, value and alpha.
+    fn pick_hsva(&mut self, ctx: &mut ActionCtx<'_>) {
+        self.color = self.hsva.to_color();
+        self.changed(ctx, true);
+    }
+
+    fn pick_color(&mut self, ctx: &mut ActionCtx<'_>, color: Color, update_text: bool) {
+        self.color = color;
+        self.hsva = Hsva::from_color(color, self.hsva.hue);
+        self.changed(ctx, update_text);
+    }
+
+    fn changed(&mut self, ctx: &mut ActionCtx<'_>, update_text: bool) {
+        ctx.submit_action::<ColorChanged>(ColorChanged(self.color));
+        ctx.mutate_self_later(move |mut picker| {
+            Self::sync(&mut picker.downcast(), update_text);
+        });
+        ctx.request_accessibility_update();
+    }
+}
+
+/// Measures the max-content length of `child` on `axis`.
+fn max_content(
+    ctx: &mut MeasureCtx<'_>,
+    child: &mut WidgetPod<impl Widget + ?Sized>,
+    axis: Axis,
+    cross_length: Option<Length>,
+) -> Length {
+    let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+    ctx.compute_length(child, LenDef::MaxContent, context_size, axis, cross_length)
+}
+
+fn swatch_pods(colors: &[Color]) -> Vec<WidgetPod<ColorSwatch>> {
+    colors
+        .iter()
+        .map(|color| WidgetPod::new(ColorSwatch::new(*color)))
+        .collect()
+}
+
+/// Returns the positions of `count` swatches of side `side`, wrapped in rows of `width`.
+fn swatch_origins(count: usize, side: f64, width: f64) -> impl Iterator<Item = Point> {
+    let mut next = Point::ORIGIN;
+    (0..count).map(move |_| {
+        if next.x > 0. && next.x + side > width {
+            next = Point::new(0., next.y + side + SWATCH_GAP);
+        }
+        let origin = next;
+        next.x += side + SWATCH_GAP;
+        origin
+    })
+}
+
+/// Formats `value` with at most `decimals` decimals.
+fn format_number(value: f32, decimals: usize) -> String {
+    // Adding zero turns negative zero into zero, which isn't shown with a sign.
+    let text = format!("{:.decimals$}", value + 0.);
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        text
+    }
+}
+
+/// Formats `color` as CSS text in the given format.
+pub(crate) fn format_color(color: Color, format: ColorFormat) -> String {
+    let alpha = color.components[3];
+    let alpha_suffix = if alpha < 1. {
+        format!(" / {}", format_number(alpha, 2))
+    } else {
+        String::new()
+    };
+    match format {
+        ColorFormat::Hex => {
+            let rgba = color.to_rgba8();
+            if rgba.a == 255 {
+                format!("#{:02x}{:02x}{:02x}", rgba.r, rgba.g, rgba.b)
+            } else {
+                format!("#{:02x}{:02x}{:02x}{:02x}", rgba.r, rgba.g, rgba.b, rgba.a)
+            }
+        }
+        ColorFormat::Rgb => {
+            let rgba = color.to_rgba8();
+            format!("rgb({} {} {}{alpha_suffix})", rgba.r, rgba.g, rgba.b)
+        }
+        ColorFormat::Hsl => {
+            let [hue, saturation, lightness, _] = color.convert::<Hsl>().components;
+            format!(
+                "hsl({} {}% {}%{alpha_suffix})",
+                format_number(hue, 0),
+                format_number(saturation, 0),
+                format_number(lightness, 0)
+            )
+        }
+        ColorFormat::Oklch => {
+            let [lightness, chroma, hue, _] = color.convert::<Oklch>().components;
+            format!(
+                "oklch({} {} {}{alpha_suffix})",
+                format_number(lightness, 3),
+                format_number(chroma, 3),
+                format_number(if hue.is_nan() { 0. } else { hue }, 1)
+            )
+        }
+    }
+}
+
+/// Parses a CSS color, or hexadecimal digits without a leading `#`.
+pub(crate) fn parse_color(text: &str) -> Option<Color> {
+    let text = text.trim();
+    let is_bare_hex = matches!(text.len(), 3 | 4 | 6 | 8)
+        && text.chars().all(|c| c.is_ascii_hexdigit())
+        && parse_css_color(text).is_err();
+    let color = if is_bare_hex {
+        parse_css_color(&format!("#{text}"))
+    } else {
+        parse_css_color(text)
+    };
+    color.ok().map(|color| color.to_alpha_color::<Srgb>())
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for ColorPicker {
+    type Action = ColorChanged;
+
+    fn on_action(
+        &mut self,
+        ctx: &mut ActionCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        action: &ErasedAction,
+        source: WidgetId,
+    ) {
+        if let Some(moved) = action.downcast_ref::<ColorAreaMoved>() {
+            self.hsva.saturation = moved.saturation;
+            self.hsva.value = moved.value;
+            self.pick_hsva(ctx);
+        } else if let Some(moved) = action.downcast_ref::<ColorSliderMoved>() {
+            if source == self.hue.id() {
+                self.hsva.hue = moved.value;
+            } else if source == self.alpha.id() {
+                self.hsva.alpha = moved.value;
+            } else {
+                return;
+            }
+            self.pick_hsva(ctx);
+        } else if let Some(action) = action.downcast_ref::<TextAction>() {
+            match action {
+                TextAction::Changed(text) => match parse_color(text) {
+                    Some(color) => {
+                        ctx.set_invalid(false);
+                        if color != self.color {
+                            self.pick_color(ctx, color, false);
+                        }
+                    }
+                    None => ctx.set_invalid(true),
+                },
+                // Show the color in the selected format again.
+                TextAction::Entered(_) | TextAction::Cancelled => {
+                    ctx.mutate_self_later(|mut picker| Self::show_text(&mut picker.downcast()));
+                }
+            }
+        } else if action.is::<ButtonPress>() {
+            if source == self.format_button.id() {
+                let format = self.format.next();
+                ctx.mutate_self_later(move |mut picker| {
+                    Self::set_format(&mut picker.downcast(), format);
+                });
+            } else if let Some(index) = self.swatches.iter().position(|s| s.id() == source) {
+                self.pick_color(ctx, self.swatch_colors[index], true);
+            } else {
+                return;
+            }
+        } else {
+            return;
+        }
+        ctx.set_handled();
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.area);
+        ctx.register_child(&mut self.hue);
+        ctx.register_child(&mut self.alpha);
+        ctx.register_child(&mut self.input);
+        ctx.register_child(&mut self.format_button);
+        for swatch in &mut self.swatches {
+            ctx.register_child(swatch);
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        if axis == Axis::Horizontal {
+            return match len_req {
+                LenReq::FitContent(space) => space,
+                _ => max_content(ctx, &mut self.area, axis, None),
+            };
+        }
+        let area = max_content(ctx, &mut self.area, axis, cross_length).get();
+        let strip = max_content(ctx, &mut self.hue, axis, cross_length).get();
+        let input = max_content(ctx, &mut self.input, axis, None).get();
+        let button = max_content(ctx, &mut self.format_button, axis, None).get();
+        let mut height = area + 2. * (GAP + strip) + GAP + input.max(button);
+        if let Some(swatch) = self.swatches.first_mut() {
+            let side = max_content(ctx, swatch, axis, None).get();
+            let width = cross_length.map_or(f64::INFINITY, Length::get);
+            let last = swatch_origins(self.swatches.len(), side, width).last();
+            height += GAP + last.map_or(0., |origin| origin.y) + side;
+        }
+        Length::px(height)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let area_height = ctx
+            .compute_size(&mut self.area, SizeDef::fit(size), size.into())
+            .height;
+        ctx.run_layout(&mut self.area, Size::new(size.width, area_height));
+        ctx.place_child(&mut self.area, Point::ORIGIN);
+        let mut y = area_height + GAP;
+        for strip in [&mut self.hue, &mut self.alpha] {
+            let strip_height = ctx
+                .compute_size(strip, SizeDef::fit(size), size.into())
+                .height;
+            ctx.run_layout(strip, Size::new(size.width, strip_height));
+            ctx.place_child(strip, Point::new(0., y));
+            y += strip_height + GAP;
+        }
+
+        let button_size = ctx.compute_size(&mut self.format_button, SizeDef::MAX, size.into());
+        let input_size = ctx.compute_size(&mut self.input, SizeDef::MAX, size.into());
+        let row_height = input_size.height.max(button_size.height);
+        self.preview_rect = Rect::new(0., y, row_height, y + row_height);
+        let button_size = Size::new(button_size.width, row_height);
+        ctx.run_layout(&mut self.format_button, button_size);
+        ctx.place_child(
+            &mut self.format_button,
+            Point::new(size.width - button_size.width, y),
+        );
+        let input_width = (size.width - row_height - button_size.width - 2. * GAP).max(0.);
+        ctx.run_layout(&mut self.input, Size::new(input_width, row_height));
+        ctx.place_child(&mut self.input, Point::new(row_height + GAP, y));
+        y += row_height + GAP;
+
+        let Some(first) = self.swatches.first_mut() else {
+            return;
+        };
+        let side = ctx.compute_size(first, SizeDef::MAX, size.into()).height;
+        let origins = swatch_origins(self.swatches.len(), side, size.width);
+        for (swatch, origin) in self.swatches.iter_mut().zip(origins) {
+            ctx.run_layout(swatch, Size::new(side, side));
+            ctx.place_child(swatch, origin + (0., y));
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        painter: &mut Painter<'_>,
+    ) {
+        paint_color(painter, self.preview_rect.to_rounded_rect(4.), self.color);
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::ColorWell
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_value(format_color(self.color, self.format));
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        [
+            self.area.id(),
+            self.hue.id(),
+            self.alpha.id(),
+            self.input.id(),
+            self.format_button.id(),
+        ]
+        .into_iter()
+        .chain(self.swatches.iter().map(WidgetPod::id))
+        .collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("ColorPicker", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::keyboard::{Key, Modifiers, NamedKey};
+    use crate::core::{NewWidget, TextEvent};
+    use crate::palette;
+    use crate::testing::{TestHarness, TestHarnessParams, assert_render_snapshot};
+    use crate::theme::test_property_set;
+
+    const ORANGE: Color = Color::from_rgb8(0xff, 0x80, 0x00);
+
+    fn text_area_id(harness: &TestHarness<ColorPicker>) -> WidgetId {
+        let input_id = harness.root_widget().inner().input.id();
+        harness
+            .get_widget_with_id(input_id)
+            .downcast::<TextInput>()
+            .unwrap()
+            .inner()
+            .area_pod()
+            .id()
+    }
+
+    fn text(harness: &TestHarness<ColorPicker>) -> String {
+        harness
+            .get_widget_with_id(text_area_id(harness))
+            .downcast::<TextArea<true>>()
+            .unwrap()
+            .inner()
+            .text()
+            .to_string()
+    }
+
+    fn press(harness: &mut TestHarness<ColorPicker>, key: NamedKey, modifiers: Modifiers) {
+        let mut event = TextEvent::key_down(Key::Named(key));
+        if let TextEvent::Keyboard(event) = &mut event {
+            event.modifiers = modifiers;
+        }
+        harness.process_text_event(event);
+        harness.process_text_event(TextEvent::key_up(Key::Named(key)));
+    }
+
+    fn changed_color(harness: &mut TestHarness<ColorPicker>) -> Color {
+        harness.pop_action::<ColorChanged>().unwrap().0.0
+    }
+
+    #[test]
+    fn picker_with_swatches() {
+        let picker = ColorPicker::new(ORANGE).with_swatches(vec![
+            palette::css::RED,
+            palette::css::GREEN,
+            palette::css::BLUE,
+        ]);
+        // The smooth gradients of the plane and the sliders don't fit the default size limit.
+        let params = TestHarnessParams::DEFAULT
+            .with_size((240, 280))
+            .with_max_screenshot_size(16 * TestHarnessParams::KIBIBYTE);
+        let mut harness =
+            TestHarness::create_with(test_property_set(), NewWidget::new(picker), params);
+
+        assert_render_snapshot!(harness, "color_picker_with_swatches");
+    }
+
+    #[test]
+    fn format_and_parse() {
+        assert_eq!(format_color(ORANGE, ColorFormat::Hex), "#ff8000");
+        assert_eq!(format_color(ORANGE, ColorFormat::Rgb), "rgb(255 128 0)");
+        assert_eq!(format_color(ORANGE, ColorFormat::Hsl), "hsl(30 100% 50%)");
+        assert_eq!(
+            format_color(ORANGE, ColorFormat::Oklch),
+            "oklch(0.732 0.186 53)"
+        );
+        let translucent = ORANGE.with_alpha(0.5);
+        assert_eq!(format_color(translucent, ColorFormat::Hex), "#ff800080");
+        assert_eq!(
+            format_color(translucent, ColorFormat::Rgb),
+            "rgb(255 128 0 / 0.5)"
+        );
+
+        for format in [
+            ColorFormat::Hex,
+            ColorFormat::Rgb,
+            ColorFormat::Hsl,
+            ColorFormat::Oklch,
+        ] {
+            let parsed = parse_color(&format_color(ORANGE, format)).unwrap();
+            assert_eq!(parsed.to_rgba8(), ORANGE.to_rgba8(), "{format:?}");
+        }
+        assert_eq!(
+            parse_color(" ff8000 ").unwrap().to_rgba8(),
+            ORANGE.to_rgba8()
+        );
+        assert_eq!(parse_color("orange").unwrap().to_rgba8().g, 0xa5);
+        assert_eq!(parse_color("#ff80x"), None);
+        assert_eq!(parse_color("not a color"), None);
+    }
+
+    #[test]
+    fn hsva_keeps_hue_of_grays() {
+        let hsva = Hsva::from_color(ORANGE, 0.);
+        assert!((hsva.hue - 30.).abs() < 1.);
+        assert!((hsva.saturation - 1.).abs() < 1e-3);
+        assert!((hsva.value - 1.).abs() < 1e-3);
+        assert_eq!(hsva.to_color().to_rgba8(), ORANGE.to_rgba8());
+
+        let gray = Hsva::from_color(Color::from_rgb8(0x80, 0x80, 0x80), 200.);
+        assert_eq!(gray.hue, 200.);
+        assert!(gray.saturation < 1e-4);
+        let black = Hsva::from_color(Color::BLACK, 120.);
+        assert_eq!(black.hue, 120.);
+        assert_eq!(black.value, 0.);
+    }
+
+    #[test]
+    fn keyboard_channels() {
+        let mut harness = TestHarness::create(
+            test_property_set(),
+            NewWidget::new(ColorPicker::new(ORANGE)),
+        );
+        let (area_id, hue_id, alpha_id) = {
+            let picker = harness.root_widget().inner();
+            (picker.area.id(), picker.hue.id(), picker.alpha.id())
+        };
+
+        harness.focus_on(Some(area_id));
+        press(&mut harness, NamedKey::ArrowDown, Modifiers::SHIFT);
+        let color = changed_color(&mut harness);
+        let hsva = Hsva::from_color(color, 0.);
+        assert!((hsva.value - 0.9).abs() < 1e-3);
+        assert_eq!(text(&harness), format_color(color, ColorFormat::Hex));
+
+        harness.focus_on(Some(hue_id));
+        press(&mut harness, NamedKey::ArrowRight, Modifiers::SHIFT);
+        let hue = Hsva::from_color(changed_color(&mut harness), 0.).hue;
+        assert!((hue - 40.).abs() < 1.);
+
+        harness.focus_on(Some(alpha_id));
+        press(&mut harness, NamedKey::Home, Modifiers::empty());
+        assert_eq!(changed_color(&mut harness).components[3], 0.);
+        let slider = harness.get_widget_with_id(alpha_id);
+        assert_eq!(
+            slider.downcast::<ColorSlider>().unwrap().inner().value(),
+            0.
+        );
+    }
+
+    #[test]
+    fn typed_colors() {
+        let mut harness = TestHarness::create(
+            test_property_set(),
+            NewWidget::new(ColorPicker::new(ORANGE)),
+        );
+        let area_id = text_area_id(&harness);
+        harness.edit_widget_with_id(area_id, |mut area| {
+            TextArea::reset_text(&mut area.downcast::<TextArea<true>>(), "");
+        });
+        harness.focus_on(Some(area_id));
+
+        harness.keyboard_type_chars("#0");
+        assert!(harness.root_widget().ctx().is_invalid());
+        assert_eq!(harness.pop_action::<ColorChanged>(), None);
+
+        harness.keyboard_type_chars("0f");
+        assert!(!harness.root_widget().ctx().is_invalid());
+        assert_eq!(changed_color(&mut harness), Color::from_rgb8(0, 0, 0xff));
+        // The text isn't replaced while it's being typed.
+        assert_eq!(text(&harness), "#00f");
+
+        press(&mut harness, NamedKey::Enter, Modifiers::empty());
+        assert_eq!(text(&harness), "#0000ff");
+        let alpha_id = harness.root_widget().inner().alpha.id();
+        let slider = harness.get_widget_with_id(alpha_id);
+        assert_eq!(
+            slider.downcast::<ColorSlider>().unwrap().inner().value(),
+            1.
+        );
+    }
+
+    #[test]
+    fn swatches_and_formats() {
+        let green = Color::from_rgb8(0, 0xff, 0);
+        let mut harness = TestHarness::create(
+            test_property_set(),
+            NewWidget::new(ColorPicker::new(ORANGE).with_swatches(vec![Color::WHITE, green])),
+        );
+        let (swatch_id, format_id) = {
+            let picker = harness.root_widget().inner();
+            (picker.swatches[1].id(), picker.format_button.id())
+        };
+
+        harness.mouse_click_on(swatch_id, None);
+        assert_eq!(changed_color(&mut harness), green);
+        assert_eq!(harness.root_widget().inner().color(), green);
+        assert_eq!(text(&harness), "#00ff00");
+
+        harness.mouse_click_on(format_id, None);
+        assert_eq!(harness.root_widget().inner().format(), ColorFormat::Rgb);
+        assert_eq!(text(&harness), "rgb(0 255 0)");
+        assert_eq!(harness.pop_action::<ColorChanged>(), None);
+
+        harness.edit_root_widget(|mut picker| {
+            ColorPicker::set_color(&mut picker, ORANGE);
+            ColorPicker::set_swatches(&mut picker, vec![green]);
+        });
+        assert_eq!(text(&harness), "rgb(255 128 0)");
+        assert_eq!(harness.root_widget().inner().swatches(), &[green]);
+    }
+}
@@ -0,0 +1,359 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::f64::consts::FRAC_PI_2;
+
+use accesskit::{ActionData, Node, Orientation, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::pointer::PointerButton;
+use crate::core::{
+    AccessCtx, AccessEvent, ChildrenIds, EventCtx, LayoutCtx, MeasureCtx, PaintCtx,
+    PointerButtonEvent, PointerEvent, PointerUpdate, PropertiesMut, PropertiesRef, RegisterCtx,
+    TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Rect, Size, Stroke};
+use crate::layout::{LenReq, Length};
+use crate::peniko::Color;
+use crate::properties::types::Gradient;
+use crate::properties::{BorderWidth, CornerRadius};
+use crate::widgets::{hue_color, paint_checkerboard};
+
+/// The size of the strip, when not constrained by its parent.
+const STRIP_WIDTH: f64 = 200.;
+const STRIP_HEIGHT: f64 = 16.;
+const THUMB_WIDTH: f64 = 6.;
+
+/// The channel of a color picked with a [`ColorSlider`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorChannel {
+    /// The hue, from 0 to 360 degrees.
+    Hue,
+    /// The opacity, from 0 to 1.
+    Alpha,
+}
+
+impl ColorChannel {
+    fn max(self) -> f32 {
+        match self {
+            Self::Hue => 360.,
+            Self::Alpha => 1.,
+        }
+    }
+
+    /// The change made by the arrow keys.
+    fn step(self) -> f32 {
+        match self {
+            Self::Hue => 1.,
+            Self::Alpha => 0.01,
+        }
+    }
+}
+
+/// A horizontal strip in which one channel of a color is picked.
+///
+/// The strip shows a gradient of the channel: the rainbow of hues,
+/// or the [base color](Self::with_color) from transparent to opaque.
+/// When focused, the arrow keys change the value by a degree or 1%,
+/// ten times as much while `Shift` is held.
+///
+/// There will be a [`ColorSliderMoved`] action when the user picks another value.
+pub struct ColorSlider {
+    channel: ColorChannel,
+    value: f32,
+    color: Color,
+}
+
+/// The value of a [`ColorSlider`] was changed by the user.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorSliderMoved {
+    /// The new value of the channel.
+    pub value: f32,
+}
+
+// --- MARK: BUILDERS
+impl ColorSlider {
+    /// Creates a slider for `channel`, with the given value.
+    pub fn new(channel: ColorChannel, value: f32) -> Self {
+        Self {
+            channel,
+            value: value.clamp(0., channel.max()),
+            color: Color::WHITE,
+        }
+    }
+
+    /// Builder-style method to set the color whose opacity is shown by an alpha slider.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Returns the channel of the slider.
+    pub fn channel(&self) -> ColorChannel {
+        self.channel
+    }
+
+    /// Returns the value of the slider.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl ColorSlider {
+    /// Sets the value of the slider.
+    pub fn set_value(this: &mut WidgetMut<'_, Self>, value: f32) {
+        let value = value.clamp(0., this.widget.channel.max());
+        if this.widget.value != value {
+            this.widget.value = value;
+            this.ctx.request_render();
+        }
+    }
+
+    /// Sets the color whose opacity is shown by an alpha slider.
+    pub fn set_color(this: &mut WidgetMut<'_, Self>, color: Color) {
+        if this.widget.color != color {
+            this.widget.color = color;
+            this.ctx.request_paint_only();
+        }
+    }
+}
+
+// --- MARK: INTERNALS
+impl ColorSlider {
+    fn pick(&mut self, ctx: &mut EventCtx<'_>, value: f32) {
+        let value = value.clamp(0., self.channel.max());
+        if value == self.value {
+            return;
+        }
+        self.value = value;
+        ctx.submit_action::<ColorSliderMoved>(ColorSliderMoved { value });
+        ctx.request_render();
+    }
+
+    fn pick_position(&mut self, ctx: &mut EventCtx<'_>, x: f64) {
+        let width = ctx.content_box().size().width;
+        #[expect(clippy::cast_possible_truncation, reason = "color components are f32")]
+        let progress = (x / width).clamp(0., 1.) as f32;
+        self.pick(ctx, progress * self.channel.max());
+    }
+
+    fn gradient(&self) -> Gradient {
+        let gradient = Gradient::new_linear(FRAC_PI_2);
+        match self.channel {
+            ColorChannel::Hue => gradient.with_stops(
+                [0., 60., 120., 180., 240., 300., 360.].map(|hue| (hue / 360., hue_color(hue))),
+            ),
+            ColorChannel::Alpha => gradient.with_stops([
+                (0., self.color.with_alpha(0.)),
+                (1., self.color.with_alpha(1.)),
+            ]),
+        }
+    }
+
+    fn value_text(&self) -> String {
+        match self.channel {
+            ColorChannel::Hue => format!("{} degrees", self.value.round()),
+            ColorChannel::Alpha => format!("{}%", (self.value * 100.).round()),
+        }
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for ColorSlider {
+    type Action = ColorSliderMoved;
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if ctx.is_disabled() {
+            return;
+        }
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: Some(PointerButton::Primary) | None,
+                state,
+                ..
+            }) => {
+                ctx.request_focus();
+                ctx.capture_pointer();
+                let x = ctx.local_position(state.position).x;
+                self.pick_position(ctx, x);
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) if ctx.is_active() => {
+                let x = ctx.local_position(current.position).x;
+                self.pick_position(ctx, x);
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if ctx.is_disabled() || !ctx.is_focus_target() {
+            return;
+        }
+        let TextEvent::Keyboard(event) = event else {
+            return;
+        };
+        if event.state.is_up() {
+            return;
+        }
+        let step = self.channel.step() * if event.modifiers.shift() { 10. } else { 1. };
+        let value = match &event.key {
+            Key::Named(NamedKey::ArrowLeft | NamedKey::ArrowDown) => self.value - step,
+            Key::Named(NamedKey::ArrowRight | NamedKey::ArrowUp) => self.value + step,
+            Key::Named(NamedKey::Home) => 0.,
+            Key::Named(NamedKey::End) => self.channel.max(),
+            _ => return,
+        };
+        self.pick(ctx, value);
+        ctx.set_handled();
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if ctx.is_disabled() {
+            return;
+        }
+        #[expect(clippy::cast_possible_truncation, reason = "color components are f32")]
+        let value = match (event.action, &event.data) {
+            (accesskit::Action::Increment, _) => self.value + self.channel.step(),
+            (accesskit::Action::Decrement, _) => self.value - self.channel.step(),
+            (accesskit::Action::SetValue, Some(ActionData::NumericValue(value))) => *value as f32,
+            _ => return,
+        };
+        self.pick(ctx, value);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::FocusChanged(_) = event {
+            ctx.request_render();
+        }
+    }
+
+    fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
+
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        _cross_length: Option<Length>,
+    ) -> Length {
+        match (axis, len_req) {
+            (Axis::Horizontal, LenReq::FitContent(space)) => space,
+            (Axis::Horizontal, _) => Length::const_px(STRIP_WIDTH),
+            (Axis::Vertical, _) => Length::const_px(STRIP_HEIGHT),
+        }
+    }
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, _size: Size) {}
+
+    fn paint(
+        &mut self,
+        ctx: &mut PaintCtx<'_>,
+        props: &PropertiesRef<'_>,
+        painter: &mut Painter<'_>,
+    ) {
+        let cache = ctx.property_cache();
+        let border_width = props.get::<BorderWidth>(cache);
+        let corner_radius = props.get::<CornerRadius>(cache);
+        let shape = border_width.bg_rect(ctx.border_box(), corner_radius);
+        let rect = shape.rect();
+
+        if self.channel == ColorChannel::Alpha {
+            paint_checkerboard(painter, shape);
+        }
+        let gradient = self.gradient().get_peniko_gradient_for_rect(rect);
+        painter.fill(shape, &gradient).draw();
+
+        let size = ctx.content_box().size();
+        let progress = f64::from(self.value / self.channel.max());
+        let x = progress * (size.width - THUMB_WIDTH);
+        let thumb = Rect::new(x, 0., x + THUMB_WIDTH, size.height).to_rounded_rect(2.);
+        painter.stroke(thumb, &Stroke::new(3.), Color::BLACK).draw();
+        painter
+            .stroke(thumb, &Stroke::new(1.5), Color::WHITE)
+            .draw();
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Slider
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_label(match self.channel {
+            ColorChannel::Hue => "Hue",
+            ColorChannel::Alpha => "Opacity",
+        });
+        node.set_orientation(Orientation::Horizontal);
+        node.set_value(self.value_text());
+        node.set_numeric_value(f64::from(self.value));
+        node.set_min_numeric_value(0.);
+        node.set_max_numeric_value(f64::from(self.channel.max()));
+        node.set_numeric_value_step(f64::from(self.channel.step()));
+        node.add_action(accesskit::Action::SetValue);
+        node.add_action(accesskit::Action::Increment);
+        node.add_action(accesskit::Action::Decrement);
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::new()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("ColorSlider", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::NewWidget;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+
+    #[test]
+    fn keys_clamp_to_channel() {
+        let slider = NewWidget::new(ColorSlider::new(ColorChannel::Hue, 350.));
+        let mut harness = TestHarness::create(test_property_set(), slider);
+        harness.focus_on(Some(harness.root_id()));
+
+        let key = |key| TextEvent::key_down(Key::Named(key));
+        harness.process_text_event(key(NamedKey::ArrowLeft));
+        let (moved, _) = harness.pop_action::<ColorSliderMoved>().unwrap();
+        assert_eq!(moved.value, 349.);
+
+        harness.process_text_event(key(NamedKey::End));
+        assert_eq!(harness.root_widget().inner().value(), 360.);
+        harness.pop_action::<ColorSliderMoved>().unwrap();
+        harness.process_text_event(key(NamedKey::ArrowRight));
+        assert_eq!(harness.pop_action::<ColorSliderMoved>(), None);
+        assert_eq!(harness.root_widget().inner().value(), 360.);
+    }
+}
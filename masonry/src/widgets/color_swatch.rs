@@ -0,0 +1,206 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::{
+    AccessCtx, AccessEvent, ChildrenIds, EventCtx, LayoutCtx, MeasureCtx, PaintCtx,
+    PointerButtonEvent, PointerEvent, PropertiesMut, PropertiesRef, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Rect, RoundedRect, Size};
+use crate::layout::{LenReq, Length};
+use crate::peniko::Color;
+use crate::properties::{BorderWidth, CornerRadius};
+use crate::widgets::{ButtonPress, ColorFormat, format_color};
+
+/// The side of a swatch, when not constrained by its parent.
+const SWATCH_SIZE: f64 = 20.;
+/// The side of the squares of the checkerboard shown under translucent colors.
+const CHECKER_SIZE: f64 = 4.;
+const CHECKER_LIGHT: Color = Color::from_rgb8(0xcc, 0xcc, 0xcc);
+const CHECKER_DARK: Color = Color::from_rgb8(0x88, 0x88, 0x88);
+
+/// A square of color which can be pressed, as found in the palette of a [`ColorPicker`].
+///
+/// Translucent colors are shown over a checkerboard.
+///
+/// There will be a [`ButtonPress`] action when the swatch is clicked,
+/// or when `Enter` or `Space` is pressed while it has focus.
+///
+/// [`ColorPicker`]: super::ColorPicker
+pub struct ColorSwatch {
+    color: Color,
+}
+
+// --- MARK: BUILDERS
+impl ColorSwatch {
+    /// Creates a swatch showing `color`.
+    pub fn new(color: Color) -> Self {
+        Self { color }
+    }
+
+    /// Returns the color of the swatch.
+    pub fn color(&self) -> Color {
+        self.color
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl ColorSwatch {
+    /// Sets the color of the swatch.
+    pub fn set_color(this: &mut WidgetMut<'_, Self>, color: Color) {
+        if this.widget.color != color {
+            this.widget.color = color;
+            this.ctx.request_render();
+        }
+    }
+}
+
+/// Fills `shape` with `color`, over a checkerboard if the color is translucent.
+pub(crate) fn paint_color(painter: &mut Painter<'_>, shape: RoundedRect, color: Color) {
+    if color.components[3] < 1. {
+        paint_checkerboard(painter, shape);
+    }
+    painter.fill(shape, color).draw();
+}
+
+/// Fills `shape` with a checkerboard, to show the transparency of colors painted over it.
+pub(crate) fn paint_checkerboard(painter: &mut Painter<'_>, shape: RoundedRect) {
+    let rect = shape.rect();
+    painter.push_fill_clip(shape);
+    painter.fill(rect, CHECKER_LIGHT).draw();
+    let mut y = rect.y0;
+    let mut dark_row = false;
+    while y < rect.y1 {
+        let mut x = rect.x0 + if dark_row { 0. } else { CHECKER_SIZE };
+        while x < rect.x1 {
+            let square = Rect::new(x, y, x + CHECKER_SIZE, y + CHECKER_SIZE);
+            painter.fill(square, CHECKER_DARK).draw();
+            x += 2. * CHECKER_SIZE;
+        }
+        y += CHECKER_SIZE;
+        dark_row = !dark_row;
+    }
+    painter.pop_clip();
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for ColorSwatch {
+    type Action = ButtonPress;
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Down(..) => {
+                ctx.request_focus();
+                ctx.capture_pointer();
+            }
+            PointerEvent::Up(PointerButtonEvent { button, .. })
+                if ctx.is_active() && ctx.is_hovered() =>
+            {
+                ctx.submit_action::<Self::Action>(ButtonPress { button: *button });
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if let TextEvent::Keyboard(event) = event
+            && event.state.is_up()
+            && (matches!(&event.key, Key::Character(c) if c == " ")
+                || event.key == Key::Named(NamedKey::Enter))
+        {
+            ctx.submit_action::<Self::Action>(ButtonPress { button: None });
+        }
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if ctx.is_disabled() {
+            return;
+        }
+        if event.action == accesskit::Action::Click {
+            ctx.submit_action::<Self::Action>(ButtonPress { button: None });
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::HoveredChanged(_) | Update::FocusChanged(_) | Update::ActiveChanged(_) => {
+                ctx.request_paint_only();
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
+
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _axis: Axis,
+        _len_req: LenReq,
+        _cross_length: Option<Length>,
+    ) -> Length {
+        Length::const_px(SWATCH_SIZE)
+    }
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, _size: Size) {}
+
+    fn paint(
+        &mut self,
+        ctx: &mut PaintCtx<'_>,
+        props: &PropertiesRef<'_>,
+        painter: &mut Painter<'_>,
+    ) {
+        let cache = ctx.property_cache();
+        let border_width = props.get::<BorderWidth>(cache);
+        let corner_radius = props.get::<CornerRadius>(cache);
+        let shape = border_width.bg_rect(ctx.border_box(), corner_radius);
+        paint_color(painter, shape, self.color);
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Button
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_label(format_color(self.color, ColorFormat::Hex));
+        node.add_action(accesskit::Action::Click);
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::new()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("ColorSwatch", id = id.trace())
+    }
+}
@@ -0,0 +1,562 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use time::{Date, Month, Weekday};
+use tracing::{Span, trace_span};
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::{
+    AccessCtx, ActionCtx, ChildrenIds, ErasedAction, EventCtx, LayerType, LayoutCtx, MeasureCtx,
+    NewWidget, PaintCtx, PointerEvent, PropertiesMut, PropertiesRef, RegisterCtx, TextEvent,
+    Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Size};
+use crate::layers::CalendarPopup;
+use crate::layout::{Anchor, LayoutSize, LenReq, Length, SizeDef};
+use crate::widgets::{Button, ButtonPress, Calendar, TextAction, TextArea, TextInput};
+
+/// The month shown by the calendar when the picker has neither a date nor a current date.
+const FALLBACK_DATE: Date = match Date::from_calendar_date(2000, Month::January, 1) {
+    Ok(date) => date,
+    Err(_) => unreachable!(),
+};
+
+/// A text field for a date, with a button showing a [`Calendar`] in a popup.
+///
+/// Dates are typed in the `YYYY-MM-DD` format of ISO 8601.
+/// While the text isn't a valid date, the picker is [invalid] and keeps its previous date.
+///
+/// While the popup is open, the picker has focus and handles the keyboard for the calendar:
+/// see [`Calendar`] for the keys. `Escape` closes the popup.
+/// The calendar opens on the month of the date, or of the [current date](Self::with_today)
+/// if the field is empty.
+///
+/// There will be a [`DateChanged`] action when the user types a valid date,
+/// clears the field, or picks a date in the calendar.
+///
+/// [invalid]: masonry_core::doc::masonry_concepts#invalid
+pub struct DatePicker {
+    date: Option<Date>,
+    first_day_of_week: Weekday,
+    today: Option<Date>,
+    input: WidgetPod<TextInput>,
+    /// The text area of the input, which gets the focus back when a date is picked.
+    area_id: WidgetId,
+    toggle: WidgetPod<Button>,
+    /// The open popup, and the calendar inside it.
+    popup: Option<(WidgetId, WidgetId)>,
+    /// Whether the next press of the button is ignored, because it closed the popup.
+    ignore_press: bool,
+}
+
+/// The date of a [`DatePicker`] was changed by the user.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DateChanged(pub Option<Date>);
+
+// --- MARK: BUILDERS
+impl DatePicker {
+    /// Creates a picker showing `date`, or an empty field.
+    pub fn new(date: Option<Date>) -> Self {
+        let input = TextInput::new(&format_date(date)).with_placeholder("YYYY-MM-DD");
+        Self {
+            date,
+            first_day_of_week: Weekday::Monday,
+            today: None,
+            area_id: input.area_pod().id(),
+            input: WidgetPod::new(input),
+            toggle: WidgetPod::new(Button::with_text("▾")),
+            popup: None,
+            ignore_press: false,
+        }
+    }
+
+    /// Builder-style method to set the day on which the weeks of the calendar start.
+    pub fn with_first_day_of_week(mut self, weekday: Weekday) -> Self {
+        self.first_day_of_week = weekday;
+        self
+    }
+
+    /// Builder-style method to set the current date, which is highlighted in the calendar.
+    pub fn with_today(mut self, today: Option<Date>) -> Self {
+        self.today = today;
+        self
+    }
+
+    /// Returns the date of the picker.
+    pub fn date(&self) -> Option<Date> {
+        self.date
+    }
+
+    /// Returns whether the calendar popup is open.
+    pub fn is_open(&self) -> bool {
+        self.popup.is_some()
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl DatePicker {
+    /// Sets the date, replacing the text of the field.
+    ///
+    /// Does nothing if the picker already has this date, so that the text being typed
+    /// isn't replaced.
+    pub fn set_date(this: &mut WidgetMut<'_, Self>, date: Option<Date>) {
+        if this.widget.date == date {
+            return;
+        }
+        this.widget.date = date;
+        Self::show_date(this);
+    }
+
+    /// Sets the day on which the weeks of the calendar start.
+    pub fn set_first_day_of_week(this: &mut WidgetMut<'_, Self>, weekday: Weekday) {
+        this.widget.first_day_of_week = weekday;
+    }
+
+    /// Sets the current date, which is highlighted in the calendar.
+    pub fn set_today(this: &mut WidgetMut<'_, Self>, today: Option<Date>) {
+        this.widget.today = today;
+    }
+
+    /// Returns a mutable reference to the text input.
+    pub fn input_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, TextInput> {
+        this.ctx.get_mut(&mut this.widget.input)
+    }
+
+    /// Closes the popup after a date was picked in it, and reports the date.
+    pub(crate) fn pick_from_popup(this: &mut WidgetMut<'_, Self>, popup: WidgetId, date: Date) {
+        // The popup gives the focus back to the text field before calling this,
+        // so the picker may already have closed it.
+        Self::dismiss_popup(this, popup);
+        this.widget.date = Some(date);
+        Self::show_date(this);
+        this.ctx
+            .submit_action::<DateChanged>(DateChanged(Some(date)));
+    }
+
+    /// Closes the popup after it was dismissed by clicking outside of it.
+    pub(crate) fn dismiss_popup(this: &mut WidgetMut<'_, Self>, popup: WidgetId) {
+        if this.widget.popup.map(|(id, _)| id) == Some(popup) {
+            this.widget.popup = None;
+            this.ctx.remove_layer(popup);
+        }
+    }
+
+    /// Replaces the text of the field with the date.
+    fn show_date(this: &mut WidgetMut<'_, Self>) {
+        let text = format_date(this.widget.date);
+        TextArea::reset_text(&mut TextInput::text_mut(&mut Self::input_mut(this)), &text);
+        this.ctx.set_invalid(false);
+    }
+}
+
+// --- MARK: INTERNALS
+impl DatePicker {
+    fn open_popup(&mut self, ctx: &mut ActionCtx<'_>) {
+        let shown = self.date.or(self.today).unwrap_or(FALLBACK_DATE);
+        let calendar = Calendar::new(shown)
+            .with_first_day_of_week(self.first_day_of_week)
+            .with_selected_date(self.date)
+            .with_today(self.today)
+            .with_cursor_shown();
+        let calendar = NewWidget::new(calendar);
+        let calendar_id = calendar.id();
+        let popup =
+            CalendarPopup::new(ctx.widget_id(), calendar).with_restore_focus(Some(self.area_id));
+        let popup = NewWidget::new(popup);
+        self.popup = Some((popup.id(), calendar_id));
+        ctx.create_anchored_layer(LayerType::Other, popup, Anchor::below(ctx.widget_id()));
+        // The picker handles the keyboard on behalf of the calendar.
+        ctx.request_focus();
+    }
+
+    fn close_popup(&mut self, ctx: &mut EventCtx<'_>) {
+        if let Some((popup, _)) = self.popup.take() {
+            ctx.remove_layer(popup);
+        }
+    }
+}
+
+/// Formats `date` as `YYYY-MM-DD`, or returns an empty string.
+fn format_date(date: Option<Date>) -> String {
+    match date {
+        Some(date) => format!(
+            "{:04}-{:02}-{:02}",
+            date.year(),
+            u8::from(date.month()),
+            date.day()
+        ),
+        None => String::new(),
+    }
+}
+
+/// Parses a `YYYY-MM-DD` date, where an empty text is no date.
+///
+/// Returns `None` if the text isn't a valid date.
+fn parse_date(text: &str) -> Option<Option<Date>> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Some(None);
+    }
+    let mut parts = text.split('-');
+    let (Some(year), Some(month), Some(day), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return None;
+    };
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return None;
+    }
+    let month = Month::try_from(month.parse::<u8>().ok()?).ok()?;
+    let date = Date::from_calendar_date(year.parse().ok()?, month, day.parse().ok()?).ok()?;
+    Some(Some(date))
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for DatePicker {
+    type Action = DateChanged;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if let PointerEvent::Down(..) = event {
+            // Clicking the picker while the popup is open closes it,
+            // and clicking the button shouldn't open it again.
+            self.ignore_press = self.popup.is_some() && ctx.target() == self.toggle.id();
+            self.close_popup(ctx);
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let TextEvent::Keyboard(event) = event else {
+            return;
+        };
+        let Some((_, calendar)) = self.popup else {
+            return;
+        };
+        if !event.state.is_down() {
+            return;
+        }
+        match &event.key {
+            Key::Named(NamedKey::Escape) => {
+                self.close_popup(ctx);
+                ctx.set_focus(self.area_id);
+            }
+            Key::Named(
+                NamedKey::ArrowLeft
+                | NamedKey::ArrowRight
+                | NamedKey::ArrowUp
+                | NamedKey::ArrowDown
+                | NamedKey::PageUp
+                | NamedKey::PageDown
+                | NamedKey::Home
+                | NamedKey::End
+                | NamedKey::Enter,
+            ) => {
+                let key = event.key.clone();
+                let shift = event.modifiers.shift();
+                ctx.mutate_later(calendar, move |mut calendar| {
+                    Calendar::press_key(&mut calendar.downcast(), &key, shift);
+                });
+            }
+            Key::Character(c) if c == " " => {
+                let key = event.key.clone();
+                ctx.mutate_later(calendar, move |mut calendar| {
+                    Calendar::press_key(&mut calendar.downcast(), &key, false);
+                });
+            }
+            _ => return,
+        }
+        ctx.set_handled();
+    }
+
+    fn on_action(
+        &mut self,
+        ctx: &mut ActionCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        action: &ErasedAction,
+        source: WidgetId,
+    ) {
+        if action.is::<ButtonPress>() && source == self.toggle.id() {
+            if !std::mem::take(&mut self.ignore_press) {
+                self.open_popup(ctx);
+            }
+            ctx.set_handled();
+        } else if let Some(action) = action.downcast_ref::<TextAction>() {
+            if let TextAction::Changed(text) = action {
+                match parse_date(text) {
+                    Some(date) => {
+                        ctx.set_invalid(false);
+                        if date != self.date {
+                            self.date = date;
+                            ctx.submit_action::<Self::Action>(DateChanged(date));
+                        }
+                    }
+                    None => ctx.set_invalid(true),
+                }
+            }
+            ctx.set_handled();
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            // The focus moved elsewhere while the popup was open.
+            Update::FocusChanged(false) => {
+                if let Some((popup, _)) = self.popup.take() {
+                    ctx.remove_layer(popup);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.input);
+        ctx.register_child(&mut self.toggle);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        let auto_length = len_req.into();
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        let input_length = ctx.compute_length(
+            &mut self.input,
+            auto_length,
+            context_size,
+            axis,
+            cross_length,
+        );
+        let toggle_length = ctx.compute_length(
+            &mut self.toggle,
+            auto_length,
+            context_size,
+            axis,
+            cross_length,
+        );
+        match axis {
+            Axis::Horizontal => match len_req {
+                LenReq::FitContent(space) => space,
+                _ => input_length.saturating_add(toggle_length),
+            },
+            Axis::Vertical => input_length.max(toggle_length),
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let toggle_size = ctx.compute_size(&mut self.toggle, SizeDef::fit(size), size.into());
+        let toggle_size = Size::new(toggle_size.width, size.height);
+        ctx.run_layout(&mut self.toggle, toggle_size);
+        ctx.place_child(
+            &mut self.toggle,
+            Point::new(size.width - toggle_size.width, 0.),
+        );
+
+        let input_size = Size::new((size.width - toggle_size.width).max(0.), size.height);
+        ctx.run_layout(&mut self.input, input_size);
+        ctx.place_child(&mut self.input, Point::ORIGIN);
+        ctx.derive_baselines(&self.input);
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::DateInput
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_expanded(self.popup.is_some());
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.input.id(), self.toggle.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("DatePicker", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::WidgetTag;
+    use crate::layout::{AsUnit, UnitPoint};
+    use crate::properties::Dimensions;
+    use crate::testing::{TestHarness, TestHarnessParams, assert_render_snapshot};
+    use crate::theme::test_property_set;
+    use crate::widgets::Align;
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    fn click_toggle(harness: &mut TestHarness<DatePicker>) {
+        let toggle = harness.root_widget().inner().toggle.id();
+        let toggle = harness.get_widget_with_id(toggle);
+        let center = toggle
+            .ctx()
+            .window_transform()
+            .transform_rect_bbox(toggle.ctx().border_box())
+            .center();
+        harness.mouse_move(center);
+        harness.mouse_button_press(None);
+        harness.mouse_button_release(None);
+    }
+
+    fn press(harness: &mut TestHarness<DatePicker>, key: NamedKey) {
+        harness.process_text_event(TextEvent::key_down(Key::Named(key)));
+        harness.process_text_event(TextEvent::key_up(Key::Named(key)));
+    }
+
+    fn text(harness: &TestHarness<DatePicker>) -> String {
+        let area_id = harness.root_widget().inner().area_id;
+        harness
+            .get_widget_with_id(area_id)
+            .downcast::<TextArea<true>>()
+            .unwrap()
+            .inner()
+            .text()
+            .to_string()
+    }
+
+    #[test]
+    fn open_popup() {
+        let tag = WidgetTag::unique();
+        let today = date(2025, Month::March, 3);
+        let picker = DatePicker::new(Some(date(2025, Month::March, 14))).with_today(Some(today));
+        let picker = NewWidget::new(picker)
+            .with_tag(tag)
+            .with_props(Dimensions::fixed(180.px(), 36.px()));
+        let root = Align::new(UnitPoint::TOP_LEFT, picker);
+        // The popup holds a whole month of text, which doesn't fit the default size limit.
+        let params = TestHarnessParams::DEFAULT
+            .with_size((250, 310))
+            .with_max_screenshot_size(16 * TestHarnessParams::KIBIBYTE);
+        let mut harness =
+            TestHarness::create_with(test_property_set(), NewWidget::new(root), params);
+        let toggle = harness.get_widget(tag).inner().toggle.id();
+        harness.mouse_click_on(toggle, None);
+
+        assert_render_snapshot!(harness, "date_picker_open_popup");
+    }
+
+    #[test]
+    fn parse_and_format() {
+        let day = date(2026, Month::March, 5);
+        assert_eq!(format_date(Some(day)), "2026-03-05");
+        assert_eq!(format_date(None), "");
+        assert_eq!(parse_date("2026-03-05"), Some(Some(day)));
+        assert_eq!(parse_date(" 2026-03-05 "), Some(Some(day)));
+        assert_eq!(parse_date(""), Some(None));
+        assert_eq!(parse_date("2026-3-5"), None);
+        assert_eq!(parse_date("2026-02-30"), None);
+        assert_eq!(parse_date("2026-03-05-01"), None);
+        assert_eq!(parse_date("march"), None);
+    }
+
+    #[test]
+    fn typed_dates() {
+        let mut harness =
+            TestHarness::create(test_property_set(), NewWidget::new(DatePicker::new(None)));
+        let area_id = harness.root_widget().inner().area_id;
+        harness.focus_on(Some(area_id));
+
+        harness.keyboard_type_chars("2026-01-1");
+        assert!(harness.root_widget().ctx().is_invalid());
+        assert_eq!(harness.pop_action::<DateChanged>(), None);
+
+        harness.keyboard_type_chars("5");
+        assert!(!harness.root_widget().ctx().is_invalid());
+        let (action, _) = harness.pop_action::<DateChanged>().unwrap();
+        assert_eq!(action, DateChanged(Some(date(2026, Month::January, 15))));
+        assert_eq!(
+            harness.root_widget().inner().date(),
+            Some(date(2026, Month::January, 15))
+        );
+
+        // Setting the date replaces the text.
+        harness.edit_root_widget(|mut picker| {
+            DatePicker::set_date(&mut picker, Some(date(2027, Month::July, 4)));
+        });
+        assert_eq!(text(&harness), "2027-07-04");
+        assert_eq!(harness.pop_action::<DateChanged>(), None);
+    }
+
+    #[test]
+    fn pick_from_calendar() {
+        let picker = DatePicker::new(Some(date(2026, Month::January, 15)));
+        let mut harness = TestHarness::create(test_property_set(), NewWidget::new(picker));
+        let area_id = harness.root_widget().inner().area_id;
+        harness.focus_on(Some(area_id));
+        let picker_id = harness.root_widget().id();
+
+        click_toggle(&mut harness);
+        assert!(harness.root_widget().inner().is_open());
+        assert_eq!(harness.focused_widget_id(), Some(picker_id));
+
+        press(&mut harness, NamedKey::ArrowDown);
+        press(&mut harness, NamedKey::ArrowRight);
+        press(&mut harness, NamedKey::Enter);
+        let (action, _) = harness.pop_action::<DateChanged>().unwrap();
+        assert_eq!(action, DateChanged(Some(date(2026, Month::January, 23))));
+        assert!(!harness.root_widget().inner().is_open());
+        assert_eq!(text(&harness), "2026-01-23");
+        let area_id = harness.root_widget().inner().area_id;
+        assert_eq!(harness.focused_widget_id(), Some(area_id));
+    }
+
+    #[test]
+    fn dismiss_popup() {
+        let mut harness =
+            TestHarness::create(test_property_set(), NewWidget::new(DatePicker::new(None)));
+        let area_id = harness.root_widget().inner().area_id;
+        harness.focus_on(Some(area_id));
+        let area_id = harness.root_widget().inner().area_id;
+
+        click_toggle(&mut harness);
+        assert!(harness.root_widget().inner().is_open());
+        press(&mut harness, NamedKey::Escape);
+        assert!(!harness.root_widget().inner().is_open());
+        assert_eq!(harness.focused_widget_id(), Some(area_id));
+
+        // Clicking the button again closes the popup instead of reopening it.
+        click_toggle(&mut harness);
+        assert!(harness.root_widget().inner().is_open());
+        click_toggle(&mut harness);
+        assert!(!harness.root_widget().inner().is_open());
+        click_toggle(&mut harness);
+        assert!(harness.root_widget().inner().is_open());
+
+        harness.focus_on(Some(area_id));
+        assert!(!harness.root_widget().inner().is_open());
+        assert_eq!(harness.pop_action::<DateChanged>(), None);
+    }
+}
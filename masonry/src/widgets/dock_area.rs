@@ -0,0 +1,1134 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use super::dock_layout::{DropTarget, Edge, GroupLoc};
+use super::tab_bar::SyncCtx;
+use crate::core::pointer::PointerButton;
+use crate::core::{
+    AccessCtx, AccessEvent, ActionCtx, ChildrenIds, CursorIcon, ErasedAction, EventCtx, LayoutCtx,
+    MeasureCtx, NewWidget, PaintCtx, PointerButtonEvent, PointerEvent, PointerUpdate,
+    PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, Update, UpdateCtx, Widget, WidgetId,
+    WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, BezPath, Cap, Point, Rect, Size, Stroke, Vec2};
+use crate::layout::{LayoutSize, LenDef, LenReq, Length, SizeDef};
+use crate::theme;
+use crate::widgets::{DockLayout, DockNode, DockSide, TabCloseRequested, TabHeader};
+
+/// The thickness of the bars between groups, like the default bar of a [`Split`](super::Split).
+const BAR_THICKNESS: f64 = 6.;
+/// The thickness of a collapsed side dock.
+const COLLAPSED_THICKNESS: f64 = 24.;
+/// How far the pointer must move before a pressed tab starts being dragged.
+const DRAG_THRESHOLD: f64 = 4.;
+/// The fraction of a group, from each of its edges, where dropping a panel splits the group.
+const SPLIT_ZONE: f64 = 0.25;
+/// The band along the edges of the area where dropping a panel docks it to an empty side dock.
+const SIDE_ZONE: f64 = 32.;
+
+/// An IDE-style area of panels, arranged by a [`DockLayout`].
+///
+/// The center of the area is a tree of groups of tabbed panels, with draggable bars
+/// between them, and each side can have one more group, which can be collapsed by
+/// the button at the end of its tabs.
+///
+/// Dragging a tab moves its panel: among the tabs of a group, next to a group to split it,
+/// or to a side dock, with a preview of where it will land.
+/// Every change made by the user is reported with a [`DockAction::LayoutChanged`],
+/// whose layout can be saved and restored with [`with_layout`](Self::with_layout).
+///
+/// Panels are identified by their key.
+/// Panels missing from the layout are added to the first group of the center,
+/// and keys without a panel are dropped from the layout.
+/// The content of the panels which aren't selected in their group is [stashed],
+/// so it keeps its state.
+///
+/// [stashed]: masonry_core::doc::masonry_concepts#stashed
+pub struct DockArea {
+    layout: DockLayout,
+    panels: Vec<Panel>,
+    /// The geometry computed in the last layout pass.
+    size: Size,
+    groups: Vec<GroupArea>,
+    bars: Vec<BarArea>,
+    /// The strips of the collapsed side docks, and the buttons collapsing the others.
+    toggles: Vec<(DockSide, Rect)>,
+    drag: Option<Drag>,
+}
+
+/// Something the user did with a [`DockArea`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum DockAction {
+    /// The user changed the layout, which is now this one.
+    LayoutChanged(DockLayout),
+    /// The user clicked the close button of the panel with this key.
+    CloseRequested(String),
+}
+
+struct Panel {
+    key: String,
+    header: WidgetPod<TabHeader>,
+    content: WidgetPod<dyn Widget>,
+    /// Whether the header is in the selected state, kept in sync with the layout.
+    selected: bool,
+}
+
+/// The geometry of a shown group.
+struct GroupArea {
+    loc: GroupLoc,
+    rect: Rect,
+    /// The space for the tabs.
+    strip: Rect,
+    content: Rect,
+    /// The index in `panels` and the rect of each tab.
+    tabs: Vec<(usize, Rect)>,
+}
+
+/// The geometry of a bar between two groups, or between a side dock and the rest.
+struct BarArea {
+    target: BarTarget,
+    rect: Rect,
+    axis: Axis,
+    /// The extent, along `axis`, of the space divided by the bar.
+    span: (f64, f64),
+}
+
+enum BarTarget {
+    Split(Vec<bool>),
+    Side(DockSide),
+}
+
+enum Drag {
+    Panel {
+        key: String,
+        start: Point,
+        dragging: bool,
+        target: Option<DropTarget>,
+    },
+    Bar {
+        index: usize,
+        /// The distance from the bar center to where it was pressed.
+        offset: f64,
+    },
+}
+
+// --- MARK: BUILDERS
+impl DockArea {
+    /// Creates a dock area with no panels.
+    pub fn new() -> Self {
+        Self {
+            layout: DockLayout::default(),
+            panels: Vec::new(),
+            size: Size::ZERO,
+            groups: Vec::new(),
+            bars: Vec::new(),
+            toggles: Vec::new(),
+            drag: None,
+        }
+    }
+
+    /// Builder-style method to set the layout.
+    pub fn with_layout(mut self, layout: DockLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Builder-style method to add a panel, whose tab is `header`.
+    ///
+    /// If there's already a panel with this key, it's replaced.
+    pub fn with_panel(
+        mut self,
+        key: impl Into<String>,
+        header: NewWidget<TabHeader>,
+        content: NewWidget<impl Widget + ?Sized>,
+    ) -> Self {
+        let key = key.into();
+        self.panels.retain(|panel| panel.key != key);
+        self.panels.push(Panel {
+            key,
+            header: header.to_pod(),
+            content: content.erased().to_pod(),
+            selected: false,
+        });
+        self
+    }
+
+    /// Returns the layout.
+    pub fn layout(&self) -> &DockLayout {
+        &self.layout
+    }
+
+    /// Returns the number of panels.
+    pub fn len(&self) -> usize {
+        self.panels.len()
+    }
+
+    /// Returns `true` if there are no panels.
+    pub fn is_empty(&self) -> bool {
+        self.panels.is_empty()
+    }
+}
+
+impl Default for DockArea {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl DockArea {
+    /// Sets the layout.
+    ///
+    /// This doesn't submit a [`DockAction::LayoutChanged`].
+    pub fn set_layout(this: &mut WidgetMut<'_, Self>, layout: DockLayout) {
+        this.widget.layout = layout;
+        this.widget.reconcile();
+        this.widget.sync_headers(&mut this.ctx);
+    }
+
+    /// Adds a panel, whose tab is `header`.
+    ///
+    /// If there's already a panel with this key, it's replaced and keeps its place.
+    /// Otherwise, unless the layout already has it, the panel is added to the first group
+    /// of the center.
+    pub fn add_panel(
+        this: &mut WidgetMut<'_, Self>,
+        key: impl Into<String>,
+        mut header: NewWidget<TabHeader>,
+        content: NewWidget<impl Widget + ?Sized>,
+    ) {
+        let key = key.into();
+        Self::remove_widgets(this, &key);
+        let keys = this.widget.panels.iter().map(|panel| panel.key.as_str());
+        reconcile(&mut this.widget.layout, keys.chain([key.as_str()]));
+        let selected = this.widget.layout.is_selected(&key);
+        TabHeader::init_selected(&mut header, selected, false);
+        this.widget.panels.push(Panel {
+            key,
+            header: header.to_pod(),
+            content: content.erased().to_pod(),
+            selected,
+        });
+        this.ctx.children_changed();
+        this.widget.sync_headers(&mut this.ctx);
+    }
+
+    /// Removes the panel with this key, if any.
+    pub fn remove_panel(this: &mut WidgetMut<'_, Self>, key: &str) {
+        if Self::remove_widgets(this, key) {
+            this.widget.reconcile();
+            this.widget.sync_headers(&mut this.ctx);
+        }
+    }
+
+    /// Sets whether the dock at `side` is collapsed.
+    ///
+    /// This doesn't submit a [`DockAction::LayoutChanged`].
+    pub fn set_collapsed(this: &mut WidgetMut<'_, Self>, side: DockSide, collapsed: bool) {
+        this.widget.layout.side_mut(side).collapsed = collapsed;
+        this.ctx.request_layout();
+    }
+
+    /// Returns a mutable reference to the tab of the panel with this key, if any.
+    pub fn header_mut<'t>(
+        this: &'t mut WidgetMut<'_, Self>,
+        key: &str,
+    ) -> Option<WidgetMut<'t, TabHeader>> {
+        let index = this.widget.panel_index(key)?;
+        Some(this.ctx.get_mut(&mut this.widget.panels[index].header))
+    }
+
+    /// Returns a mutable reference to the content of the panel with this key, if any.
+    pub fn content_mut<'t>(
+        this: &'t mut WidgetMut<'_, Self>,
+        key: &str,
+    ) -> Option<WidgetMut<'t, dyn Widget>> {
+        let index = this.widget.panel_index(key)?;
+        Some(this.ctx.get_mut(&mut this.widget.panels[index].content))
+    }
+
+    /// Removes the widgets of the panel, but not its place in the layout.
+    fn remove_widgets(this: &mut WidgetMut<'_, Self>, key: &str) -> bool {
+        let Some(index) = this.widget.panel_index(key) else {
+            return false;
+        };
+        let panel = this.widget.panels.remove(index);
+        this.ctx.remove_child(panel.header);
+        this.ctx.remove_child(panel.content);
+        true
+    }
+}
+
+// --- MARK: INTERNALS
+impl DockArea {
+    fn panel_index(&self, key: &str) -> Option<usize> {
+        self.panels.iter().position(|panel| panel.key == key)
+    }
+
+    fn reconcile(&mut self) {
+        let keys = self.panels.iter().map(|panel| panel.key.as_str());
+        reconcile(&mut self.layout, keys);
+    }
+
+    /// Applies the selection state of the layout to the tabs.
+    fn sync_headers(&mut self, ctx: &mut impl SyncCtx) {
+        for panel in &mut self.panels {
+            let selected = self.layout.is_selected(&panel.key);
+            if panel.selected != selected {
+                panel.selected = selected;
+                ctx.mutate_child_later(&mut panel.header, move |mut header| {
+                    TabHeader::set_selected(&mut header, selected, false);
+                });
+            }
+        }
+        ctx.request_layout();
+    }
+
+    /// Reports a change of the layout made by the user.
+    fn layout_changed(&mut self, ctx: &mut EventCtx<'_>) {
+        self.sync_headers(ctx);
+        ctx.submit_action::<<Self as Widget>::Action>(DockAction::LayoutChanged(
+            self.layout.clone(),
+        ));
+    }
+
+    /// Returns the index of the panel whose tab is at `pos`.
+    fn tab_at(&self, pos: Point) -> Option<usize> {
+        self.groups
+            .iter()
+            .flat_map(|area| &area.tabs)
+            .find(|(_, rect)| rect.contains(pos))
+            .map(|&(index, _)| index)
+    }
+
+    /// Returns where a panel dragged to `pos` would be dropped.
+    fn drop_target(&self, pos: Point) -> Option<DropTarget> {
+        let area = self.size.to_rect();
+        for side in DockSide::ALL {
+            if !self.layout.side(side).group.panels.is_empty() {
+                continue;
+            }
+            let distance = match side {
+                DockSide::Left => pos.x - area.x0,
+                DockSide::Right => area.x1 - pos.x,
+                DockSide::Bottom => area.y1 - pos.y,
+            };
+            if distance < SIDE_ZONE {
+                return Some(DropTarget::Side(side));
+            }
+        }
+        if let Some(&(side, _)) = self.toggles.iter().find(|(_, rect)| rect.contains(pos)) {
+            return Some(DropTarget::Side(side));
+        }
+
+        let group = self.groups.iter().find(|group| group.rect.contains(pos))?;
+        if group.strip.contains(pos) {
+            let index = group
+                .tabs
+                .iter()
+                .filter(|(_, rect)| rect.center().x < pos.x)
+                .count();
+            return Some(DropTarget::Tab(group.loc.clone(), index));
+        }
+        let content = group.content;
+        if let GroupLoc::Center(path) = &group.loc
+            && content.width() > 0.
+            && content.height() > 0.
+        {
+            let x = (pos.x - content.x0) / content.width();
+            let y = (pos.y - content.y0) / content.height();
+            let (distance, edge) = [
+                (x, Edge::Left),
+                (1. - x, Edge::Right),
+                (y, Edge::Top),
+                (1. - y, Edge::Bottom),
+            ]
+            .into_iter()
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .unwrap();
+            if distance < SPLIT_ZONE {
+                return Some(DropTarget::Split(path.clone(), edge));
+            }
+        }
+        Some(DropTarget::Tab(group.loc.clone(), group.tabs.len()))
+    }
+
+    /// Returns the area a panel dropped on `target` would take.
+    fn preview_rect(&self, target: &DropTarget) -> Option<Rect> {
+        match target {
+            DropTarget::Tab(loc, _) => self
+                .groups
+                .iter()
+                .find(|group| group.loc == *loc)
+                .map(|group| group.rect),
+            DropTarget::Split(path, edge) => {
+                let group = self
+                    .groups
+                    .iter()
+                    .find(|group| matches!(&group.loc, GroupLoc::Center(p) if p == path))?;
+                let content = group.content;
+                let center = content.center();
+                Some(match edge {
+                    Edge::Left => content.with_size((content.width() * 0.5, content.height())),
+                    Edge::Right => Rect::new(center.x, content.y0, content.x1, content.y1),
+                    Edge::Top => content.with_size((content.width(), content.height() * 0.5)),
+                    Edge::Bottom => Rect::new(content.x0, center.y, content.x1, content.y1),
+                })
+            }
+            DropTarget::Side(side) => {
+                let area = self.size.to_rect();
+                let extent = area.size().get_coord(side_axis(*side));
+                let length = self.layout.side(*side).size.min(extent * 0.25);
+                Some(cut(area, *side, length).0)
+            }
+        }
+    }
+
+    /// Moves the dragged bar so that its center is at `pos`, along its axis.
+    fn drag_bar(&mut self, index: usize, pos: f64) {
+        let Some(bar) = self.bars.get(index) else {
+            return;
+        };
+        let (start, end) = bar.span;
+        let space = (end - start - BAR_THICKNESS).max(0.);
+        let edge = pos - BAR_THICKNESS * 0.5;
+        match &bar.target {
+            BarTarget::Split(path) => {
+                if let Some(DockNode::Split { fraction, .. }) = self.layout.center.node_mut(path)
+                    && space > 0.
+                {
+                    *fraction = ((edge - start) / space).clamp(0., 1.);
+                }
+            }
+            BarTarget::Side(side) => {
+                let size = match side {
+                    DockSide::Left => edge - start,
+                    DockSide::Right | DockSide::Bottom => end - edge - BAR_THICKNESS,
+                };
+                self.layout.side_mut(*side).size = size.clamp(0., space);
+            }
+        }
+    }
+}
+
+/// Drops the keys of `layout` which aren't in `keys`, and adds the missing ones.
+fn reconcile<'a>(layout: &mut DockLayout, keys: impl Iterator<Item = &'a str> + Clone) {
+    layout.retain_panels(|key| keys.clone().any(|k| k == key));
+    for key in keys {
+        if !layout.contains(key) {
+            layout.insert_panel(key.into());
+        }
+    }
+}
+
+/// Returns the axis along which the dock at `side` is resized.
+fn side_axis(side: DockSide) -> Axis {
+    match side {
+        DockSide::Left | DockSide::Right => Axis::Horizontal,
+        DockSide::Bottom => Axis::Vertical,
+    }
+}
+
+/// Splits `rect` into a part of `length` along the `side` edge, and the rest.
+fn cut(rect: Rect, side: DockSide, length: f64) -> (Rect, Rect) {
+    let axis = side_axis(side);
+    let (start, end) = rect.get_coords(axis);
+    let length = length.clamp(0., end - start);
+    let at = match side {
+        DockSide::Left => start + length,
+        DockSide::Right | DockSide::Bottom => end - length,
+    };
+    let (mut before, mut after) = (rect, rect);
+    before.set_coords(axis, start, at);
+    after.set_coords(axis, at, end);
+    match side {
+        DockSide::Left => (before, after),
+        DockSide::Right | DockSide::Bottom => (after, before),
+    }
+}
+
+impl GroupArea {
+    /// Creates the area of a group, whose tabs leave `reserved` space at the end of the strip.
+    fn new(loc: GroupLoc, rect: Rect, strip_height: f64, reserved: f64) -> Self {
+        let strip_bottom = (rect.y0 + strip_height).min(rect.y1);
+        Self {
+            loc,
+            rect,
+            strip: Rect::new(
+                rect.x0,
+                rect.y0,
+                (rect.x1 - reserved).max(rect.x0),
+                strip_bottom,
+            ),
+            content: Rect::new(rect.x0, strip_bottom, rect.x1, rect.y1),
+            tabs: Vec::new(),
+        }
+    }
+}
+
+/// Computes the areas of the groups and bars of `node`, which takes `rect`.
+fn layout_node(
+    node: &DockNode,
+    path: &mut Vec<bool>,
+    rect: Rect,
+    strip_height: f64,
+    groups: &mut Vec<GroupArea>,
+    bars: &mut Vec<BarArea>,
+) {
+    match node {
+        DockNode::Group(_) => {
+            groups.push(GroupArea::new(
+                GroupLoc::Center(path.clone()),
+                rect,
+                strip_height,
+                0.,
+            ));
+        }
+        DockNode::Split {
+            axis,
+            fraction,
+            first,
+            second,
+        } => {
+            let (start, end) = rect.get_coords(*axis);
+            let space = (end - start - BAR_THICKNESS).max(0.);
+            let edge = start + space * fraction;
+            let bar_end = (edge + BAR_THICKNESS).min(end);
+            let (mut first_rect, mut bar_rect, mut second_rect) = (rect, rect, rect);
+            first_rect.set_coords(*axis, start, edge);
+            bar_rect.set_coords(*axis, edge, bar_end);
+            second_rect.set_coords(*axis, bar_end, end);
+            bars.push(BarArea {
+                target: BarTarget::Split(path.clone()),
+                rect: bar_rect,
+                axis: *axis,
+                span: (start, end),
+            });
+            for (is_second, child, child_rect) in
+                [(false, first, first_rect), (true, second, second_rect)]
+            {
+                path.push(is_second);
+                layout_node(child, path, child_rect, strip_height, groups, bars);
+                path.pop();
+            }
+        }
+    }
+}
+
+/// Returns a chevron in `rect`, pointing where the dock at `side` moves when toggled.
+fn toggle_chevron(rect: Rect, side: DockSide, collapsed: bool) -> BezPath {
+    let direction = match (side, collapsed) {
+        (DockSide::Left, false) | (DockSide::Right, true) => Vec2::new(-1., 0.),
+        (DockSide::Left, true) | (DockSide::Right, false) => Vec2::new(1., 0.),
+        (DockSide::Bottom, false) => Vec2::new(0., 1.),
+        (DockSide::Bottom, true) => Vec2::new(0., -1.),
+    };
+    let center = rect.center();
+    let arm = 4.;
+    let side_arm = Vec2::new(-direction.y, direction.x) * arm;
+    let mut path = BezPath::new();
+    path.move_to(center - direction * arm * 0.5 + side_arm);
+    path.line_to(center + direction * arm * 0.5);
+    path.line_to(center - direction * arm * 0.5 - side_arm);
+    path
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for DockArea {
+    type Action = DockAction;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if ctx.is_disabled() {
+            return;
+        }
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: Some(PointerButton::Primary) | None,
+                state,
+                ..
+            }) => {
+                let pos = ctx.local_position(state.position);
+                if let Some(index) = self.bars.iter().position(|bar| bar.rect.contains(pos)) {
+                    let bar = &self.bars[index];
+                    let center = bar.rect.center().get_coord(bar.axis);
+                    let offset = pos.get_coord(bar.axis) - center;
+                    self.drag = Some(Drag::Bar { index, offset });
+                    ctx.capture_pointer();
+                    ctx.request_paint_only();
+                } else if let Some(&(side, _)) =
+                    self.toggles.iter().find(|(_, rect)| rect.contains(pos))
+                {
+                    let dock = self.layout.side_mut(side);
+                    dock.collapsed = !dock.collapsed;
+                    self.layout_changed(ctx);
+                } else if let Some(index) = self.tab_at(pos) {
+                    let key = self.panels[index].key.clone();
+                    if self.layout.select(&key) {
+                        self.layout_changed(ctx);
+                    }
+                    self.drag = Some(Drag::Panel {
+                        key,
+                        start: pos,
+                        dragging: false,
+                        target: None,
+                    });
+                    ctx.capture_pointer();
+                } else {
+                    return;
+                }
+                ctx.set_handled();
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) => {
+                let pos = ctx.local_position(current.position);
+                match self.drag {
+                    Some(Drag::Bar { index, offset }) => {
+                        if let Some(bar) = self.bars.get(index) {
+                            let center = pos.get_coord(bar.axis) - offset;
+                            self.drag_bar(index, center);
+                            ctx.request_layout();
+                        }
+                    }
+                    Some(Drag::Panel {
+                        start, dragging, ..
+                    }) if dragging || (pos - start).hypot() > DRAG_THRESHOLD => {
+                        let new_target = self.drop_target(pos);
+                        if let Some(Drag::Panel {
+                            dragging, target, ..
+                        }) = &mut self.drag
+                        {
+                            *dragging = true;
+                            *target = new_target;
+                        }
+                        ctx.request_paint_only();
+                    }
+                    Some(Drag::Panel { .. }) | None => {}
+                }
+            }
+            PointerEvent::Up(..) | PointerEvent::Cancel(..) => {
+                let dropped = matches!(event, PointerEvent::Up(..));
+                match self.drag.take() {
+                    Some(Drag::Bar { .. }) => {
+                        self.layout_changed(ctx);
+                    }
+                    Some(Drag::Panel {
+                        key,
+                        dragging: true,
+                        target: Some(target),
+                        ..
+                    }) if dropped => {
+                        let mut layout = self.layout.clone();
+                        layout.move_panel(&key, target);
+                        if layout != self.layout {
+                            self.layout = layout;
+                            self.layout_changed(ctx);
+                        }
+                    }
+                    Some(Drag::Panel { .. }) | None => {}
+                }
+                ctx.request_paint_only();
+            }
+            _ => {}
+        }
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if event.action != accesskit::Action::Click {
+            return;
+        }
+        let target = ctx.target();
+        let Some(panel) = self.panels.iter().find(|panel| panel.header.id() == target) else {
+            return;
+        };
+        let key = panel.key.clone();
+        if self.layout.select(&key) {
+            self.layout_changed(ctx);
+        }
+        ctx.set_handled();
+    }
+
+    fn on_action(
+        &mut self,
+        ctx: &mut ActionCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        action: &ErasedAction,
+        source: WidgetId,
+    ) {
+        if action.is::<TabCloseRequested>()
+            && let Some(panel) = self.panels.iter().find(|panel| panel.header.id() == source)
+        {
+            ctx.submit_action::<Self::Action>(DockAction::CloseRequested(panel.key.clone()));
+            ctx.set_handled();
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::WidgetAdded = event {
+            self.reconcile();
+            self.sync_headers(ctx);
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for panel in &mut self.panels {
+            ctx.register_child(&mut panel.header);
+            ctx.register_child(&mut panel.content);
+        }
+    }
+
+    fn property_changed(&mut self, _ctx: &mut UpdateCtx<'_>, _property_type: TypeId) {}
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        if let LenReq::FitContent(space) = len_req {
+            // Like a split, we always use all the offered space.
+            return space;
+        }
+        let auto_length = len_req.into();
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+
+        let mut content_length = Length::ZERO;
+        let mut header_length = Length::ZERO;
+        for panel in &mut self.panels {
+            let (header_shown, content_shown) = self.layout.panel_visibility(&panel.key);
+            if content_shown {
+                let length = ctx.compute_length(
+                    &mut panel.content,
+                    auto_length,
+                    context_size,
+                    axis,
+                    cross_length,
+                );
+                content_length = content_length.max(length);
+            }
+            if header_shown && axis == Axis::Vertical {
+                let length = ctx.compute_length(
+                    &mut panel.header,
+                    auto_length,
+                    context_size,
+                    axis,
+                    cross_length,
+                );
+                header_length = header_length.max(length);
+            }
+        }
+        content_length.saturating_add(header_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        self.size = size;
+
+        // TODO: move set_stashed to a different layout pass when possible
+        let mut strip_height: f64 = 0.;
+        let mut widths = vec![0.; self.panels.len()];
+        for (panel, width) in self.panels.iter_mut().zip(&mut widths) {
+            let (header_shown, content_shown) = self.layout.panel_visibility(&panel.key);
+            ctx.set_stashed(&mut panel.header, !header_shown);
+            ctx.set_stashed(&mut panel.content, !content_shown);
+            if header_shown {
+                let auto_size = SizeDef::new(LenDef::MaxContent, LenDef::MaxContent);
+                let header_size = ctx.compute_size(&mut panel.header, auto_size, size.into());
+                strip_height = strip_height.max(header_size.height);
+                *width = header_size.width;
+            }
+        }
+
+        self.groups.clear();
+        self.bars.clear();
+        self.toggles.clear();
+        let mut rest = size.to_rect();
+        for side in DockSide::ALL {
+            let dock = self.layout.side(side);
+            if dock.group.panels.is_empty() {
+                continue;
+            }
+            let axis = side_axis(side);
+            let span = rest.get_coords(axis);
+            if dock.collapsed {
+                let (strip, remaining) = cut(rest, side, COLLAPSED_THICKNESS);
+                self.toggles.push((side, strip));
+                rest = remaining;
+                continue;
+            }
+            let length = dock.size.min(span.1 - span.0 - BAR_THICKNESS);
+            let (dock_rect, remaining) = cut(rest, side, length);
+            let (bar_rect, remaining) = cut(remaining, side, BAR_THICKNESS);
+            self.bars.push(BarArea {
+                target: BarTarget::Side(side),
+                rect: bar_rect,
+                axis,
+                span,
+            });
+            let toggle = Rect::new(
+                (dock_rect.x1 - strip_height).max(dock_rect.x0),
+                dock_rect.y0,
+                dock_rect.x1,
+                (dock_rect.y0 + strip_height).min(dock_rect.y1),
+            );
+            self.toggles.push((side, toggle));
+            self.groups.push(GroupArea::new(
+                GroupLoc::Side(side),
+                dock_rect,
+                strip_height,
+                toggle.width(),
+            ));
+            rest = remaining;
+        }
+        layout_node(
+            &self.layout.center,
+            &mut Vec::new(),
+            rest,
+            strip_height,
+            &mut self.groups,
+            &mut self.bars,
+        );
+
+        for area in &mut self.groups {
+            let Some(group) = self.layout.group(&area.loc) else {
+                continue;
+            };
+            let indices: Vec<usize> = group
+                .panels
+                .iter()
+                .filter_map(|key| self.panels.iter().position(|panel| panel.key == *key))
+                .collect();
+            let max_width = area.strip.width() / indices.len().max(1) as f64;
+            let mut x = area.strip.x0;
+            for (tab, &index) in indices.iter().enumerate() {
+                let panel = &mut self.panels[index];
+                let width = widths[index].min(max_width);
+                let rect = Rect::new(x, area.strip.y0, x + width, area.strip.y1);
+                ctx.run_layout(&mut panel.header, rect.size());
+                ctx.place_child(&mut panel.header, rect.origin());
+                area.tabs.push((index, rect));
+                x += width;
+
+                if tab == group.selected {
+                    ctx.run_layout(&mut panel.content, area.content.size());
+                    ctx.place_child(&mut panel.content, area.content.origin());
+                }
+            }
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        painter: &mut Painter<'_>,
+    ) {
+        for area in &self.groups {
+            let strip = Rect::new(area.rect.x0, area.rect.y0, area.rect.x1, area.strip.y1);
+            painter.fill(strip, theme::ZYNC_900).draw();
+        }
+        for (index, bar) in self.bars.iter().enumerate() {
+            let active =
+                matches!(self.drag, Some(Drag::Bar { index: dragged, .. }) if dragged == index);
+            let color = if active {
+                theme::ZYNC_600
+            } else {
+                theme::ZYNC_800
+            };
+            painter.fill(bar.rect, color).draw();
+        }
+        let stroke = Stroke::new(1.5).with_caps(Cap::Round);
+        for &(side, rect) in &self.toggles {
+            let collapsed = self.layout.side(side).collapsed;
+            if collapsed {
+                painter.fill(rect, theme::ZYNC_900).draw();
+            }
+            let chevron = toggle_chevron(rect, side, collapsed);
+            painter.stroke(chevron, &stroke, theme::TEXT_COLOR).draw();
+        }
+    }
+
+    fn post_paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        painter: &mut Painter<'_>,
+    ) {
+        let Some(Drag::Panel {
+            dragging: true,
+            target: Some(target),
+            ..
+        }) = &self.drag
+        else {
+            return;
+        };
+        let Some(rect) = self.preview_rect(target) else {
+            return;
+        };
+        painter
+            .fill(rect, theme::ACCENT_COLOR.with_alpha(0.3))
+            .draw();
+        painter
+            .stroke(rect.inset(-0.5), &Stroke::new(1.), theme::ACCENT_COLOR)
+            .draw();
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.panels
+            .iter()
+            .flat_map(|panel| [panel.header.id(), panel.content.id()])
+            .collect()
+    }
+
+    fn get_cursor(&self, ctx: &QueryCtx<'_>, pos: Point) -> CursorIcon {
+        let pos = ctx.to_local(pos);
+        let bar = match &self.drag {
+            Some(Drag::Bar { index, .. }) => self.bars.get(*index),
+            _ => self.bars.iter().find(|bar| bar.rect.contains(pos)),
+        };
+        match bar.map(|bar| bar.axis) {
+            Some(Axis::Horizontal) => CursorIcon::EwResize,
+            Some(Axis::Vertical) => CursorIcon::NsResize,
+            None => CursorIcon::Default,
+        }
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("DockArea", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+    use crate::widgets::{DockGroup, Label};
+
+    /// Creates an area with a split center, the `"a"` and `"b"` panels on the left of `"c"`,
+    /// and the `"d"` panel docked at the bottom.
+    fn dock_area() -> (DockArea, Vec<WidgetId>) {
+        let layout = DockLayout::new(DockNode::split(
+            Axis::Horizontal,
+            0.5,
+            DockNode::group(["a", "b"]),
+            DockNode::group(["c"]),
+        ))
+        .with_side(DockSide::Bottom, ["d"], 100.);
+        let mut area = DockArea::new().with_layout(layout);
+        let mut headers = Vec::new();
+        for key in ["a", "b", "c", "d"] {
+            let header = NewWidget::new(TabHeader::new(key));
+            headers.push(header.id());
+            let content = NewWidget::new(Label::new(format!("Content of {key}")));
+            area = area.with_panel(key, header, content);
+        }
+        (area, headers)
+    }
+
+    fn content_id(harness: &TestHarness<DockArea>, key: &str) -> WidgetId {
+        let area = harness.root_widget();
+        let index = area.inner().panel_index(key).unwrap();
+        area.inner().panels[index].content.id()
+    }
+
+    fn is_stashed(harness: &TestHarness<DockArea>, key: &str) -> bool {
+        let id = content_id(harness, key);
+        harness.get_widget_with_id(id).ctx().is_stashed()
+    }
+
+    fn window_rect(harness: &TestHarness<DockArea>, id: WidgetId) -> Rect {
+        let widget = harness.get_widget_with_id(id);
+        widget
+            .ctx()
+            .window_transform()
+            .transform_rect_bbox(widget.ctx().border_box())
+    }
+
+    fn layout_changed(harness: &mut TestHarness<DockArea>) -> Option<DockLayout> {
+        match harness.pop_action::<DockAction>() {
+            Some((DockAction::LayoutChanged(layout), _)) => Some(layout),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn split_center_with_bottom_side() {
+        let (area, _) = dock_area();
+        let mut harness = TestHarness::create(test_property_set(), NewWidget::new(area));
+
+        assert_render_snapshot!(harness, "dock_area_split_center_with_bottom_side");
+    }
+
+    #[test]
+    fn only_selected_panels_are_shown() {
+        let (area, headers) = dock_area();
+        let mut harness = TestHarness::create(test_property_set(), NewWidget::new(area));
+        assert!(!is_stashed(&harness, "a"));
+        assert!(is_stashed(&harness, "b"));
+        assert!(!is_stashed(&harness, "c"));
+        assert!(!is_stashed(&harness, "d"));
+
+        harness.mouse_click_on(headers[1], None);
+        let layout = layout_changed(&mut harness).unwrap();
+        assert_eq!(
+            layout.center.node(&[false]),
+            Some(&DockNode::Group(DockGroup {
+                panels: vec!["a".into(), "b".into()],
+                selected: 1,
+            }))
+        );
+        assert!(is_stashed(&harness, "a"));
+        assert!(!is_stashed(&harness, "b"));
+        let is_selected = |harness: &TestHarness<DockArea>, id| {
+            let header = harness.get_widget_with_id(id);
+            header
+                .downcast::<TabHeader>()
+                .unwrap()
+                .inner()
+                .is_selected()
+        };
+        assert!(is_selected(&harness, headers[1]));
+        assert!(!is_selected(&harness, headers[0]));
+
+        // Panels missing from the layout go to the first group.
+        harness.edit_root_widget(|mut area| {
+            let header = NewWidget::new(TabHeader::new("e"));
+            DockArea::add_panel(&mut area, "e", header, NewWidget::new(Label::new("E")));
+            DockArea::remove_panel(&mut area, "c");
+        });
+        assert_eq!(
+            harness.root_widget().inner().layout().center,
+            DockNode::Group(DockGroup {
+                panels: vec!["a".into(), "b".into(), "e".into()],
+                selected: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn drag_tab_to_split_group() {
+        let (area, headers) = dock_area();
+        let mut harness = TestHarness::create(test_property_set(), NewWidget::new(area));
+        let start = window_rect(&harness, headers[1]).center();
+        let c_rect = window_rect(&harness, content_id(&harness, "c"));
+        // Closer to the edge, it would be docked to the right side.
+        let end = Point::new(c_rect.x1 - 40., c_rect.center().y);
+
+        harness.mouse_move(start);
+        harness.mouse_button_press(None);
+        let _ = layout_changed(&mut harness);
+        harness.mouse_move(end);
+        assert_eq!(
+            harness
+                .root_widget()
+                .inner()
+                .drag
+                .as_ref()
+                .and_then(|drag| match drag {
+                    Drag::Panel { target, .. } => target.clone(),
+                    Drag::Bar { .. } => None,
+                }),
+            Some(DropTarget::Split(vec![true], Edge::Right))
+        );
+        harness.mouse_button_release(None);
+
+        let expected = DockNode::split(
+            Axis::Horizontal,
+            0.5,
+            DockNode::group(["a"]),
+            DockNode::split(
+                Axis::Horizontal,
+                0.5,
+                DockNode::group(["c"]),
+                DockNode::group(["b"]),
+            ),
+        );
+        assert_eq!(layout_changed(&mut harness).unwrap().center, expected);
+        assert_eq!(harness.root_widget().inner().layout().center, expected);
+        assert!(!is_stashed(&harness, "a"));
+        assert!(!is_stashed(&harness, "b"));
+    }
+
+    #[test]
+    fn side_dock_collapses() {
+        let (area, headers) = dock_area();
+        let mut harness = TestHarness::create(test_property_set(), NewWidget::new(area));
+        let toggle = |harness: &TestHarness<DockArea>| {
+            let area = harness.root_widget();
+            let &(side, rect) = area.inner().toggles.last().unwrap();
+            assert_eq!(side, DockSide::Bottom);
+            area.ctx().to_window(rect.center())
+        };
+
+        harness.mouse_move(toggle(&harness));
+        harness.mouse_button_press(None);
+        harness.mouse_button_release(None);
+        assert!(layout_changed(&mut harness).unwrap().bottom.collapsed);
+        assert!(is_stashed(&harness, "d"));
+        assert!(harness.get_widget_with_id(headers[3]).ctx().is_stashed());
+
+        // The strip of the collapsed dock expands it.
+        harness.mouse_move(toggle(&harness));
+        harness.mouse_button_press(None);
+        harness.mouse_button_release(None);
+        assert!(!layout_changed(&mut harness).unwrap().bottom.collapsed);
+        assert!(!is_stashed(&harness, "d"));
+    }
+
+    #[test]
+    fn drag_bar_resizes_groups() {
+        let (area, _) = dock_area();
+        let mut harness = TestHarness::create(test_property_set(), NewWidget::new(area));
+        let area = harness.root_widget();
+        let bar = area
+            .inner()
+            .bars
+            .iter()
+            .find(|bar| matches!(bar.target, BarTarget::Split(_)))
+            .unwrap();
+        let start = area.ctx().to_window(bar.rect.center());
+        let a_width = window_rect(&harness, content_id(&harness, "a")).width();
+
+        harness.mouse_move(start);
+        harness.mouse_button_press(None);
+        harness.mouse_move(start + Vec2::new(40., 0.));
+        harness.mouse_button_release(None);
+
+        let layout = layout_changed(&mut harness).unwrap();
+        let DockNode::Split { fraction, .. } = layout.center else {
+            panic!("the center should still be split");
+        };
+        assert!(fraction > 0.5);
+        let new_width = window_rect(&harness, content_id(&harness, "a")).width();
+        assert!((new_width - a_width - 40.).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,610 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use crate::kurbo::Axis;
+
+/// One of the docks at the edges of a [`DockArea`](super::DockArea).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DockSide {
+    /// The dock on the left, spanning the whole height of the area.
+    Left,
+    /// The dock on the right, spanning the whole height of the area.
+    Right,
+    /// The dock at the bottom, between the left and right docks.
+    Bottom,
+}
+
+impl DockSide {
+    /// All sides, in the order their docks are laid out.
+    pub(crate) const ALL: [Self; 3] = [Self::Left, Self::Right, Self::Bottom];
+}
+
+/// The arrangement of the panels of a [`DockArea`](super::DockArea).
+///
+/// The center of the area is a tree of [splits](DockNode::Split) whose leaves are
+/// [groups](DockGroup) of tabbed panels.
+/// Each [side](DockSide) has one more group, which can be collapsed.
+/// Panels are identified by their key.
+///
+/// With the `serde` feature, layouts can be serialized,
+/// e.g. to restore the arrangement of the panels on the next launch.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DockLayout {
+    /// The panels in the center.
+    pub center: DockNode,
+    /// The dock on the left.
+    pub left: SideDock,
+    /// The dock on the right.
+    pub right: SideDock,
+    /// The dock at the bottom.
+    pub bottom: SideDock,
+}
+
+/// A node of the tree of panels in the center of a [`DockLayout`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DockNode {
+    /// A group of tabbed panels.
+    Group(DockGroup),
+    /// Two nodes divided by a draggable bar, like in a [`Split`](super::Split).
+    Split {
+        /// The split axis: with [`Axis::Horizontal`], `first` is on the left of `second`.
+        #[cfg_attr(feature = "serde", serde(with = "AxisDef"))]
+        axis: Axis,
+        /// The fraction of the space given to `first`, between `0.0` and `1.0`.
+        fraction: f64,
+        /// The node on the left or at the top.
+        first: Box<Self>,
+        /// The node on the right or at the bottom.
+        second: Box<Self>,
+    },
+}
+
+/// Panels shown as tabs, of which only the selected one is visible.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DockGroup {
+    /// The keys of the panels, in tab order.
+    pub panels: Vec<String>,
+    /// The index of the selected panel.
+    pub selected: usize,
+}
+
+/// The group of panels docked at one side of a [`DockLayout`].
+///
+/// The dock is hidden while it has no panels.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SideDock {
+    /// The panels of the dock.
+    pub group: DockGroup,
+    /// The width of the left and right docks, or the height of the bottom dock, in logical pixels.
+    pub size: f64,
+    /// Whether only a thin strip is shown, which expands the dock when clicked.
+    pub collapsed: bool,
+}
+
+/// Mirrors [`Axis`], which doesn't implement the serde traits.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "Axis", rename_all = "snake_case")]
+enum AxisDef {
+    Horizontal,
+    Vertical,
+}
+
+/// Where a group is in a [`DockLayout`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum GroupLoc {
+    /// The path to the group in the center tree, `true` meaning the second child of a split.
+    Center(Vec<bool>),
+    Side(DockSide),
+}
+
+/// An edge of a group, next to which a panel can be dropped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Edge {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+/// Where a dragged panel can be dropped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum DropTarget {
+    /// Among the tabs of a group, at the given index.
+    Tab(GroupLoc, usize),
+    /// Next to a group of the center, splitting its space.
+    Split(Vec<bool>, Edge),
+    /// At the end of a side dock.
+    Side(DockSide),
+}
+
+// --- MARK: BUILDERS
+impl DockLayout {
+    /// Creates a layout with the given center and empty side docks.
+    pub fn new(center: DockNode) -> Self {
+        Self {
+            center,
+            ..Default::default()
+        }
+    }
+
+    /// Builder-style method to put `panels` in the dock at `side`.
+    ///
+    /// `size` is the width of the left and right docks, or the height of the bottom dock.
+    pub fn with_side(
+        mut self,
+        side: DockSide,
+        panels: impl IntoIterator<Item = impl Into<String>>,
+        size: f64,
+    ) -> Self {
+        let dock = self.side_mut(side);
+        dock.group = DockGroup::new(panels);
+        dock.size = size;
+        self
+    }
+
+    /// Returns the dock at `side`.
+    pub fn side(&self, side: DockSide) -> &SideDock {
+        match side {
+            DockSide::Left => &self.left,
+            DockSide::Right => &self.right,
+            DockSide::Bottom => &self.bottom,
+        }
+    }
+
+    /// Returns a mutable reference to the dock at `side`.
+    pub fn side_mut(&mut self, side: DockSide) -> &mut SideDock {
+        match side {
+            DockSide::Left => &mut self.left,
+            DockSide::Right => &mut self.right,
+            DockSide::Bottom => &mut self.bottom,
+        }
+    }
+
+    /// Returns `true` if the panel with this key is in the layout.
+    pub fn contains(&self, key: &str) -> bool {
+        self.locate(key).is_some()
+    }
+
+    /// Returns `true` if the panel with this key is the selected panel of its group.
+    pub fn is_selected(&self, key: &str) -> bool {
+        self.locate(key)
+            .and_then(|(loc, index)| self.group(&loc).map(|group| group.selected == index))
+            .unwrap_or(false)
+    }
+
+    /// Returns the keys of all the panels in the layout.
+    pub fn panels(&self) -> impl Iterator<Item = &str> {
+        let mut groups = Vec::new();
+        self.center.collect_groups(&mut groups);
+        groups.extend(DockSide::ALL.map(|side| &self.side(side).group));
+        groups
+            .into_iter()
+            .flat_map(|group| group.panels.iter().map(String::as_str))
+    }
+}
+
+impl DockNode {
+    /// Creates a group of tabbed panels, with the first one selected.
+    pub fn group(panels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self::Group(DockGroup::new(panels))
+    }
+
+    /// Creates a split between `first` and `second`, giving `fraction` of the space to `first`.
+    ///
+    /// The fraction is clamped to `0.0..=1.0`.
+    pub fn split(axis: Axis, fraction: f64, first: Self, second: Self) -> Self {
+        Self::Split {
+            axis,
+            fraction: fraction.clamp(0., 1.),
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+}
+
+impl Default for DockNode {
+    fn default() -> Self {
+        Self::Group(DockGroup::default())
+    }
+}
+
+impl DockGroup {
+    /// Creates a group of panels, with the first one selected.
+    pub fn new(panels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            panels: panels.into_iter().map(Into::into).collect(),
+            selected: 0,
+        }
+    }
+
+    /// Returns the key of the selected panel, if the group isn't empty.
+    pub fn selected_panel(&self) -> Option<&str> {
+        self.panels.get(self.selected).map(String::as_str)
+    }
+}
+
+impl Default for SideDock {
+    fn default() -> Self {
+        Self {
+            group: DockGroup::default(),
+            size: 240.,
+            collapsed: false,
+        }
+    }
+}
+
+// --- MARK: INTERNALS
+impl DockLayout {
+    pub(crate) fn group(&self, loc: &GroupLoc) -> Option<&DockGroup> {
+        match loc {
+            GroupLoc::Center(path) => match self.center.node(path)? {
+                DockNode::Group(group) => Some(group),
+                DockNode::Split { .. } => None,
+            },
+            GroupLoc::Side(side) => Some(&self.side(*side).group),
+        }
+    }
+
+    pub(crate) fn group_mut(&mut self, loc: &GroupLoc) -> Option<&mut DockGroup> {
+        match loc {
+            GroupLoc::Center(path) => match self.center.node_mut(path)? {
+                DockNode::Group(group) => Some(group),
+                DockNode::Split { .. } => None,
+            },
+            GroupLoc::Side(side) => Some(&mut self.side_mut(*side).group),
+        }
+    }
+
+    /// Returns the group holding the panel with this key, and the index of the panel in it.
+    pub(crate) fn locate(&self, key: &str) -> Option<(GroupLoc, usize)> {
+        let mut path = Vec::new();
+        if let Some(index) = self.center.locate(key, &mut path) {
+            return Some((GroupLoc::Center(path), index));
+        }
+        DockSide::ALL.into_iter().find_map(|side| {
+            let index = self.side(side).group.position(key)?;
+            Some((GroupLoc::Side(side), index))
+        })
+    }
+
+    /// Returns whether the group is shown: side docks are hidden while empty or collapsed.
+    pub(crate) fn is_shown(&self, loc: &GroupLoc) -> bool {
+        match loc {
+            GroupLoc::Center(_) => true,
+            GroupLoc::Side(side) => {
+                let dock = self.side(*side);
+                !dock.collapsed && !dock.group.panels.is_empty()
+            }
+        }
+    }
+
+    /// Returns whether the tab and the content of the panel with this key are shown.
+    pub(crate) fn panel_visibility(&self, key: &str) -> (bool, bool) {
+        let Some((loc, index)) = self.locate(key) else {
+            return (false, false);
+        };
+        let shown = self.is_shown(&loc);
+        let selected = self
+            .group(&loc)
+            .is_some_and(|group| group.selected == index);
+        (shown, shown && selected)
+    }
+
+    /// Selects the panel with this key in its group, and returns whether the selection changed.
+    pub(crate) fn select(&mut self, key: &str) -> bool {
+        let Some((loc, index)) = self.locate(key) else {
+            return false;
+        };
+        let group = self.group_mut(&loc).unwrap();
+        let changed = group.selected != index;
+        group.selected = index;
+        changed
+    }
+
+    /// Moves the panel with this key to `target`, and selects it.
+    ///
+    /// Does nothing if the panel or the target doesn't exist.
+    pub(crate) fn move_panel(&mut self, key: &str, target: DropTarget) {
+        let Some((from_loc, from)) = self.locate(key) else {
+            return;
+        };
+        let target_exists = match &target {
+            DropTarget::Tab(loc, _) => self.group(loc).is_some(),
+            DropTarget::Split(path, _) => self.center.node(path).is_some(),
+            DropTarget::Side(_) => true,
+        };
+        if !target_exists {
+            return;
+        }
+        // Groups are only pruned once the panel is inserted, so `target` stays valid.
+        self.group_mut(&from_loc).unwrap().remove(from);
+        match target {
+            DropTarget::Tab(loc, index) => {
+                let index = if loc == from_loc && index > from {
+                    index - 1
+                } else {
+                    index
+                };
+                let group = self.group_mut(&loc).unwrap();
+                let index = index.min(group.panels.len());
+                group.panels.insert(index, key.into());
+                group.selected = index;
+            }
+            DropTarget::Split(path, edge) => {
+                let node = self.center.node_mut(&path).unwrap();
+                let old = std::mem::take(node);
+                let new = DockNode::group([key]);
+                let (axis, first, second) = match edge {
+                    Edge::Left => (Axis::Horizontal, new, old),
+                    Edge::Right => (Axis::Horizontal, old, new),
+                    Edge::Top => (Axis::Vertical, new, old),
+                    Edge::Bottom => (Axis::Vertical, old, new),
+                };
+                *node = DockNode::split(axis, 0.5, first, second);
+            }
+            DropTarget::Side(side) => {
+                let dock = self.side_mut(side);
+                dock.group.panels.push(key.into());
+                dock.group.selected = dock.group.panels.len() - 1;
+                dock.collapsed = false;
+            }
+        }
+        self.normalize();
+    }
+
+    /// Removes the panels for which `keep` returns `false`, and duplicate panels.
+    pub(crate) fn retain_panels(&mut self, keep: impl Fn(&str) -> bool) {
+        let mut seen = HashSet::new();
+        let mut groups = Vec::new();
+        let Self {
+            center,
+            left,
+            right,
+            bottom,
+        } = self;
+        center.collect_groups_mut(&mut groups);
+        groups.extend([&mut left.group, &mut right.group, &mut bottom.group]);
+        for group in groups {
+            let selected = group.selected_panel().map(str::to_string);
+            group
+                .panels
+                .retain(|key| keep(key) && seen.insert(key.clone()));
+            group.selected = selected
+                .and_then(|key| group.position(&key))
+                .unwrap_or(group.selected);
+        }
+        self.normalize();
+    }
+
+    /// Adds a panel to the first group of the center.
+    pub(crate) fn insert_panel(&mut self, key: String) {
+        self.center.first_group_mut().panels.push(key);
+    }
+
+    /// Removes empty groups from the center and fixes out of range values.
+    fn normalize(&mut self) {
+        self.center = std::mem::take(&mut self.center).pruned();
+        let mut groups = Vec::new();
+        self.center.collect_groups_mut(&mut groups);
+        for group in groups {
+            group.clamp_selected();
+        }
+        for side in DockSide::ALL {
+            let dock = self.side_mut(side);
+            dock.group.clamp_selected();
+            if !dock.size.is_finite() || dock.size < 0. {
+                dock.size = Self::default().side(side).size;
+            }
+        }
+    }
+}
+
+impl DockNode {
+    pub(crate) fn node(&self, path: &[bool]) -> Option<&Self> {
+        let Some((&is_second, rest)) = path.split_first() else {
+            return Some(self);
+        };
+        match self {
+            Self::Split { first, second, .. } => if is_second { second } else { first }.node(rest),
+            Self::Group(_) => None,
+        }
+    }
+
+    pub(crate) fn node_mut(&mut self, path: &[bool]) -> Option<&mut Self> {
+        let Some((&is_second, rest)) = path.split_first() else {
+            return Some(self);
+        };
+        match self {
+            Self::Split { first, second, .. } => {
+                if is_second { second } else { first }.node_mut(rest)
+            }
+            Self::Group(_) => None,
+        }
+    }
+
+    fn locate(&self, key: &str, path: &mut Vec<bool>) -> Option<usize> {
+        match self {
+            Self::Group(group) => group.position(key),
+            Self::Split { first, second, .. } => {
+                for (is_second, child) in [(false, first), (true, second)] {
+                    path.push(is_second);
+                    if let Some(index) = child.locate(key, path) {
+                        return Some(index);
+                    }
+                    path.pop();
+                }
+                None
+            }
+        }
+    }
+
+    fn collect_groups<'a>(&'a self, groups: &mut Vec<&'a DockGroup>) {
+        match self {
+            Self::Group(group) => groups.push(group),
+            Self::Split { first, second, .. } => {
+                first.collect_groups(groups);
+                second.collect_groups(groups);
+            }
+        }
+    }
+
+    fn collect_groups_mut<'a>(&'a mut self, groups: &mut Vec<&'a mut DockGroup>) {
+        match self {
+            Self::Group(group) => groups.push(group),
+            Self::Split { first, second, .. } => {
+                first.collect_groups_mut(groups);
+                second.collect_groups_mut(groups);
+            }
+        }
+    }
+
+    fn first_group_mut(&mut self) -> &mut DockGroup {
+        match self {
+            Self::Group(group) => group,
+            Self::Split { first, .. } => first.first_group_mut(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self, Self::Group(group) if group.panels.is_empty())
+    }
+
+    /// Returns the node without its empty groups, replacing splits with a remaining child.
+    fn pruned(self) -> Self {
+        match self {
+            Self::Group(_) => self,
+            Self::Split {
+                axis,
+                fraction,
+                first,
+                second,
+            } => {
+                let first = first.pruned();
+                let second = second.pruned();
+                if first.is_empty() {
+                    second
+                } else if second.is_empty() {
+                    first
+                } else {
+                    let fraction = if fraction.is_finite() { fraction } else { 0.5 };
+                    Self::split(axis, fraction, first, second)
+                }
+            }
+        }
+    }
+}
+
+impl DockGroup {
+    fn position(&self, key: &str) -> Option<usize> {
+        self.panels.iter().position(|panel| panel == key)
+    }
+
+    /// Removes the panel at `index`.
+    ///
+    /// If it was selected, the next panel is selected instead, or the previous one if it was the last.
+    fn remove(&mut self, index: usize) {
+        self.panels.remove(index);
+        if index < self.selected || self.selected >= self.panels.len() {
+            self.selected = self.selected.saturating_sub(1);
+        }
+    }
+
+    fn clamp_selected(&mut self) {
+        self.selected = self.selected.min(self.panels.len().saturating_sub(1));
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn editor_layout() -> DockLayout {
+        DockLayout::new(DockNode::split(
+            Axis::Horizontal,
+            0.7,
+            DockNode::group(["main.rs", "lib.rs"]),
+            DockNode::group(["outline"]),
+        ))
+        .with_side(DockSide::Bottom, ["terminal"], 200.)
+    }
+
+    #[test]
+    fn moving_last_panel_prunes_group() {
+        let mut layout = editor_layout();
+        layout.move_panel("outline", DropTarget::Tab(GroupLoc::Center(vec![false]), 1));
+        assert_eq!(
+            layout.center,
+            DockNode::Group(DockGroup {
+                panels: vec!["main.rs".into(), "outline".into(), "lib.rs".into()],
+                selected: 1,
+            })
+        );
+
+        layout.move_panel("lib.rs", DropTarget::Split(vec![], Edge::Bottom));
+        assert_eq!(
+            layout.center,
+            DockNode::split(
+                Axis::Vertical,
+                0.5,
+                DockNode::group(["main.rs", "outline"]).with_selected(1),
+                DockNode::group(["lib.rs"]),
+            )
+        );
+
+        layout.move_panel("terminal", DropTarget::Tab(GroupLoc::Center(vec![true]), 0));
+        assert!(layout.bottom.group.panels.is_empty());
+        assert!(layout.is_selected("terminal"));
+        assert!(!layout.is_selected("lib.rs"));
+    }
+
+    #[test]
+    fn moving_to_missing_target_keeps_panel() {
+        let mut layout = editor_layout();
+        layout.select("lib.rs");
+        let expected = layout.clone();
+        layout.move_panel(
+            "lib.rs",
+            DropTarget::Tab(GroupLoc::Center(vec![true, false]), 0),
+        );
+        assert_eq!(layout, expected);
+        layout.move_panel("lib.rs", DropTarget::Split(vec![false, true], Edge::Left));
+        assert_eq!(layout, expected);
+    }
+
+    #[test]
+    fn retain_panels_keeps_selection() {
+        let mut layout = editor_layout();
+        layout.select("lib.rs");
+        layout.retain_panels(|key| key != "main.rs" && key != "outline");
+        assert_eq!(layout.center, DockNode::group(["lib.rs"]));
+        assert_eq!(layout.panels().collect::<Vec<_>>(), ["lib.rs", "terminal"]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let layout = editor_layout();
+        let json = serde_json::to_string(&layout).unwrap();
+        assert_eq!(serde_json::from_str::<DockLayout>(&json).unwrap(), layout);
+    }
+
+    impl DockNode {
+        fn with_selected(mut self, selected: usize) -> Self {
+            if let Self::Group(group) = &mut self {
+                group.selected = selected;
+            }
+            self
+        }
+    }
+}
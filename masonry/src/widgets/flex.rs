@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::any::TypeId;
+use std::ops::Range;
 
 use accesskit::{Node, Role};
 use include_doc_path::include_doc_path;
@@ -15,7 +16,7 @@ use crate::imaging::Painter;
 use crate::kurbo::{Axis, Size};
 use crate::layout::{AsUnit, LayoutSize, LenDef, LenReq, Length};
 use crate::properties::Gap;
-use crate::properties::types::{CrossAxisAlignment, MainAxisAlignment};
+use crate::properties::types::{CrossAxisAlignment, LineAlignment, MainAxisAlignment};
 use crate::util::Sanitize;
 
 /// A container with either horizontal or vertical layout.
@@ -42,6 +43,11 @@ use crate::util::Sanitize;
 /// There is currently no fine-grained support for flex grow or flex shrink.
 /// Instead every child gets their size decided in one shot, as described above.
 ///
+/// By default all children are laid out on a single line. With [`FlexWrap::Wrap`]
+/// the children get broken into multiple lines instead, and the above process
+/// happens separately for every line. How the lines themselves get distributed
+/// on the cross axis is controlled by [`LineAlignment`].
+///
 #[doc = concat!(
     "![Flex column with multiple labels](",
     include_doc_path!("screenshots/flex_col_main_axis_spaceAround.png"),
@@ -51,9 +57,32 @@ pub struct Flex {
     direction: Axis,
     cross_alignment: CrossAxisAlignment,
     main_alignment: MainAxisAlignment,
+    wrap: FlexWrap,
+    line_alignment: LineAlignment,
     children: Vec<Child>,
 }
 
+/// Whether [`Flex`] children are laid out on a single line or broken into multiple lines.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum FlexWrap {
+    /// All children are laid out on a single line, which may overflow the container.
+    #[default]
+    NoWrap,
+    /// Children which don't fit on the current line are moved to a new line.
+    ///
+    /// Lines are stacked from the start edge of the container's cross axis
+    /// towards the end edge.
+    ///
+    /// Line breaking uses the basis of every child, except for flexible children
+    /// with a [`FlexBasis::Zero`] basis which use their minimum preferred length instead.
+    /// Children are never shrunk, so a child that is longer than the container will
+    /// get a line all to itself and overflow the container.
+    Wrap,
+    /// Same as [`Wrap`](Self::Wrap) but lines are stacked from the end edge
+    /// of the container's cross axis towards the start edge.
+    WrapReverse,
+}
+
 /// The initial size of a [`Flex`] child before extra space distribution.
 ///
 /// Children are ensured this initial size and if there is any extra space left,
@@ -143,6 +172,8 @@ impl Flex {
             children: Vec::new(),
             cross_alignment: CrossAxisAlignment::Center,
             main_alignment: MainAxisAlignment::Start,
+            wrap: FlexWrap::NoWrap,
+            line_alignment: LineAlignment::Start,
         }
     }
 
@@ -173,6 +204,20 @@ impl Flex {
         self
     }
 
+    /// Builder-style method for specifying whether children wrap onto multiple lines.
+    pub fn with_wrap(mut self, wrap: FlexWrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Builder-style method for specifying the [`LineAlignment`] of wrapped lines.
+    ///
+    /// This has no effect unless wrapping has been enabled with [`with_wrap`](Self::with_wrap).
+    pub fn line_alignment(mut self, alignment: LineAlignment) -> Self {
+        self.line_alignment = alignment;
+        self
+    }
+
     /// Builder-style variant of [`Flex::add_fixed`].
     ///
     /// Convenient for assembling a group of widgets in a single expression.
@@ -248,6 +293,18 @@ impl Flex {
         this.ctx.request_layout();
     }
 
+    /// Sets whether children wrap onto multiple lines (see [`FlexWrap`]).
+    pub fn set_wrap(this: &mut WidgetMut<'_, Self>, wrap: FlexWrap) {
+        this.widget.wrap = wrap;
+        this.ctx.request_layout();
+    }
+
+    /// Sets the [`LineAlignment`] of wrapped lines.
+    pub fn set_line_alignment(this: &mut WidgetMut<'_, Self>, alignment: LineAlignment) {
+        this.widget.line_alignment = alignment;
+        this.ctx.request_layout();
+    }
+
     /// Adds a non-flex child widget.
     ///
     /// See also [`with_fixed`].
@@ -609,6 +666,12 @@ impl Child {
         matches!(self, Self::Widget { .. })
     }
 
+    fn flex(&self) -> f64 {
+        match self {
+            Self::Widget { flex, .. } | Self::Spacer { flex, .. } => *flex,
+        }
+    }
+
     fn widget_mut(&mut self) -> Option<&mut WidgetPod<dyn Widget>> {
         match self {
             Self::Widget { widget, .. } => Some(widget),
@@ -676,8 +739,366 @@ fn get_spacing(alignment: MainAxisAlignment, extra: f64, child_count: usize) ->
     (space_before, space_between)
 }
 
+/// Calculates `(space_before, space_between, line_growth)` from the `extra` cross space
+/// given the `line_count` of a wrapping [`Flex`].
+fn get_line_spacing(alignment: LineAlignment, extra: f64, line_count: usize) -> (f64, f64, f64) {
+    let alignment = match alignment {
+        LineAlignment::Start => MainAxisAlignment::Start,
+        LineAlignment::Center => MainAxisAlignment::Center,
+        LineAlignment::End => MainAxisAlignment::End,
+        LineAlignment::SpaceBetween => MainAxisAlignment::SpaceBetween,
+        LineAlignment::SpaceEvenly => MainAxisAlignment::SpaceEvenly,
+        LineAlignment::SpaceAround => MainAxisAlignment::SpaceAround,
+        LineAlignment::Stretch => {
+            let line_growth = if line_count > 0 {
+                extra / line_count as f64
+            } else {
+                0.
+            };
+            return (0., 0., line_growth);
+        }
+    };
+    let (space_before, space_between) = get_spacing(alignment, extra, line_count);
+    (space_before, space_between, 0.)
+}
+
+/// A line of children in a wrapping [`Flex`].
+struct FlexLine {
+    /// The indices of the children that belong to this line.
+    children: Range<usize>,
+    /// Free main axis space that flexible children didn't use up.
+    main_free: f64,
+    /// The cross axis length of the line.
+    cross_length: f64,
+}
+
+/// Returns the main axis length of every child that is used for line breaking.
+///
+/// This is the child's basis, except for flexible children with a zero basis,
+/// which use their minimum preferred length instead.
+fn hypothetical_lengths(
+    children: &mut [Child],
+    main: Axis,
+    mut compute_length: impl FnMut(&mut WidgetPod<dyn Widget>, LenDef, Axis, Option<Length>) -> Length,
+) -> Vec<f64> {
+    children
+        .iter_mut()
+        .map(|child| match child {
+            Child::Widget {
+                widget,
+                flex,
+                basis,
+                ..
+            } => {
+                let main_auto = match effective_basis(*basis, *flex) {
+                    FlexBasis::Auto => LenDef::MaxContent,
+                    FlexBasis::Zero => LenDef::MinContent,
+                };
+                compute_length(widget, main_auto, main, None).get()
+            }
+            Child::Spacer { basis, .. } => basis.get(),
+        })
+        .collect()
+}
+
+/// Breaks the children of a wrapping [`Flex`] into lines that fit into `main_space`,
+/// and distributes the free space of each line among its flexible children.
+///
+/// Returns the lines and the main axis length of every child.
+/// The cross length of each line is the largest child cross length resolved with `cross_auto`.
+fn resolve_lines(
+    children: &mut [Child],
+    main: Axis,
+    main_space: f64,
+    gap_length: f64,
+    cross_auto: LenDef,
+    mut compute_length: impl FnMut(&mut WidgetPod<dyn Widget>, LenDef, Axis, Option<Length>) -> Length,
+) -> (Vec<FlexLine>, Vec<f64>) {
+    let mut main_lengths = hypothetical_lengths(children, main, &mut compute_length);
+
+    // Greedily fill every line, always putting at least one child on each line.
+    let mut ranges = Vec::new();
+    let mut line_start = 0;
+    let mut line_length = 0.;
+    for (idx, length) in main_lengths.iter().enumerate() {
+        if idx > line_start {
+            if line_length + gap_length + length > main_space {
+                ranges.push(line_start..idx);
+                line_start = idx;
+                line_length = 0.;
+            } else {
+                line_length += gap_length;
+            }
+        }
+        line_length += length;
+    }
+    if line_start < children.len() {
+        ranges.push(line_start..children.len());
+    }
+
+    let cross = main.cross();
+    let mut lines = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        let gap_lengths = range.len().saturating_sub(1) as f64 * gap_length;
+        let used = main_lengths[range.clone()].iter().sum::<f64>() + gap_lengths;
+        let mut main_free = (main_space - used).max(0.);
+
+        // Offer the free space of this line to its flexible children
+        let flex_sum = children[range.clone()].iter().map(Child::flex).sum::<f64>();
+        if flex_sum > 0. {
+            let flex_fraction = main_free / flex_sum;
+            for idx in range.clone() {
+                main_lengths[idx] += children[idx].flex() * flex_fraction;
+            }
+            main_free = 0.;
+        }
+
+        let mut cross_length: f64 = 0.;
+        for idx in range.clone() {
+            if let Child::Widget { widget, .. } = &mut children[idx] {
+                let child_cross_length =
+                    compute_length(widget, cross_auto, cross, Some(main_lengths[idx].px()));
+                cross_length = cross_length.max(child_cross_length.get());
+            }
+        }
+
+        lines.push(FlexLine {
+            children: range,
+            main_free,
+            cross_length,
+        });
+    }
+
+    (lines, main_lengths)
+}
+
 impl UsesProperty<Gap> for Flex {}
 
+// --- MARK: WRAPPED LAYOUT
+impl Flex {
+    /// Lays out the children on multiple lines, used when wrapping is enabled.
+    fn layout_wrapped(&mut self, ctx: &mut LayoutCtx<'_>, size: Size, gap_length: f64) {
+        let main = self.direction;
+        let cross = main.cross();
+        let main_space = size.get_coord(main);
+        let cross_space = size.get_coord(cross);
+
+        let (mut lines, main_lengths) = resolve_lines(
+            &mut self.children,
+            main,
+            main_space,
+            gap_length,
+            LenDef::FitContent(cross_space.px()),
+            |widget, auto, axis, cross| ctx.compute_length(widget, auto, size.into(), axis, cross),
+        );
+
+        // Lay out all the children that don't depend on the final line cross length,
+        // and grow the lines to fit their baseline alignment groups.
+        // As we currently only support the horizontal-tb writing mode, we do it only for rows.
+        let mut line_baselines = Vec::with_capacity(lines.len());
+        for line in &mut lines {
+            for idx in line.children.clone() {
+                let Child::Widget {
+                    widget, alignment, ..
+                } = &mut self.children[idx]
+                else {
+                    continue;
+                };
+                if alignment.unwrap_or(self.cross_alignment) == CrossAxisAlignment::Stretch {
+                    continue;
+                }
+                let child_main_length = main_lengths[idx].px();
+                let child_cross_length = ctx.compute_length(
+                    widget,
+                    LenDef::FitContent(cross_space.px()),
+                    size.into(),
+                    cross,
+                    Some(child_main_length),
+                );
+                let child_size = main.pack_size(child_main_length.get(), child_cross_length.get());
+                ctx.run_layout(widget, child_size);
+            }
+
+            let mut alignment_ascent: Option<f64> = None;
+            let mut alignment_descent: Option<f64> = None;
+            if main == Axis::Horizontal {
+                for child in &self.children[line.children.clone()] {
+                    let Child::Widget {
+                        widget, alignment, ..
+                    } = child
+                    else {
+                        continue;
+                    };
+                    match alignment.unwrap_or(self.cross_alignment) {
+                        CrossAxisAlignment::FirstBaseline => {
+                            let (first_baseline, _) = ctx.child_layout_baselines(widget);
+                            alignment_ascent = Some(
+                                alignment_ascent
+                                    .unwrap_or(first_baseline)
+                                    .max(first_baseline),
+                            );
+                        }
+                        CrossAxisAlignment::LastBaseline => {
+                            let (_, last_baseline) = ctx.child_layout_baselines(widget);
+                            let child_size = ctx.child_size(widget);
+                            let descent = child_size.get_coord(cross) - last_baseline;
+                            alignment_descent =
+                                Some(alignment_descent.unwrap_or(descent).max(descent));
+                        }
+                        _ => (),
+                    }
+                }
+                for child in &self.children[line.children.clone()] {
+                    let Child::Widget {
+                        widget, alignment, ..
+                    } = child
+                    else {
+                        continue;
+                    };
+                    let required = match alignment.unwrap_or(self.cross_alignment) {
+                        CrossAxisAlignment::FirstBaseline => {
+                            let (first_baseline, _) = ctx.child_layout_baselines(widget);
+                            let child_cross_length = ctx.child_size(widget).get_coord(cross);
+                            alignment_ascent.unwrap() - first_baseline + child_cross_length
+                        }
+                        CrossAxisAlignment::LastBaseline => {
+                            let (_, last_baseline) = ctx.child_layout_baselines(widget);
+                            alignment_descent.unwrap() + last_baseline
+                        }
+                        _ => continue,
+                    };
+                    line.cross_length = line.cross_length.max(required);
+                }
+            }
+            line_baselines.push((alignment_ascent, alignment_descent));
+        }
+
+        // Distribute the free cross space among the lines
+        let line_gaps = lines.len().saturating_sub(1) as f64 * gap_length;
+        let lines_length = lines.iter().map(|line| line.cross_length).sum::<f64>() + line_gaps;
+        let (space_before, space_between, line_growth) = get_line_spacing(
+            self.line_alignment,
+            (cross_space - lines_length).max(0.),
+            lines.len(),
+        );
+
+        let mut cross_offset = space_before;
+        for (line, (alignment_ascent, alignment_descent)) in lines.iter_mut().zip(line_baselines) {
+            line.cross_length += line_growth;
+            let line_origin_cross = match self.wrap {
+                FlexWrap::WrapReverse => cross_space - cross_offset - line.cross_length,
+                _ => cross_offset,
+            };
+            cross_offset += line.cross_length + gap_length + space_between;
+
+            // Stretched children can only be laid out once the line cross length is final
+            for idx in line.children.clone() {
+                let Child::Widget {
+                    widget, alignment, ..
+                } = &mut self.children[idx]
+                else {
+                    continue;
+                };
+                if alignment.unwrap_or(self.cross_alignment) != CrossAxisAlignment::Stretch {
+                    continue;
+                }
+                let child_main_length = main_lengths[idx].px();
+                let child_cross_length = ctx.compute_length(
+                    widget,
+                    LenDef::Fixed(line.cross_length.px()),
+                    size.into(),
+                    cross,
+                    Some(child_main_length),
+                );
+                let child_size = main.pack_size(child_main_length.get(), child_cross_length.get());
+                ctx.run_layout(widget, child_size);
+            }
+
+            // We only distribute free space around widgets, not spacers.
+            let widget_count = self.children[line.children.clone()]
+                .iter()
+                .filter(|child| child.is_widget())
+                .count();
+            let (space_before, space_between) =
+                get_spacing(self.main_alignment, line.main_free, widget_count);
+
+            let mut main_offset = space_before;
+            let mut previous_was_widget = false;
+            for idx in line.children.clone() {
+                match &mut self.children[idx] {
+                    Child::Widget {
+                        widget, alignment, ..
+                    } => {
+                        if previous_was_widget {
+                            main_offset += space_between;
+                        }
+
+                        let child_size = ctx.child_size(widget);
+                        let alignment = alignment.unwrap_or(self.cross_alignment);
+                        let child_origin_cross = match alignment {
+                            CrossAxisAlignment::FirstBaseline if main == Axis::Horizontal => {
+                                let (first_baseline, _) = ctx.child_layout_baselines(widget);
+                                alignment_ascent.unwrap() - first_baseline
+                            }
+                            CrossAxisAlignment::LastBaseline if main == Axis::Horizontal => {
+                                let (_, last_baseline) = ctx.child_layout_baselines(widget);
+                                let descent = child_size.get_coord(cross) - last_baseline;
+                                let end_gap = alignment_descent.unwrap() - descent;
+                                let cross_unused = line.cross_length - child_size.get_coord(cross);
+                                cross_unused - end_gap
+                            }
+                            _ => {
+                                let cross_unused = line.cross_length - child_size.get_coord(cross);
+                                alignment.offset(cross_unused)
+                            }
+                        };
+
+                        let child_origin =
+                            main.pack_point(main_offset, line_origin_cross + child_origin_cross);
+                        ctx.place_child(widget, child_origin);
+
+                        main_offset += child_size.get_coord(main);
+                        main_offset += gap_length;
+                        previous_was_widget = true;
+                    }
+                    Child::Spacer { .. } => {
+                        main_offset += main_lengths[idx];
+                        main_offset += gap_length;
+                        previous_was_widget = false;
+                    }
+                }
+            }
+        }
+
+        // Derive the container's own baselines from the visually first and last lines.
+        let (first_line, last_line) = match self.wrap {
+            FlexWrap::WrapReverse => (lines.last(), lines.first()),
+            _ => (lines.first(), lines.last()),
+        };
+        let first_child = first_line.and_then(|line| {
+            self.children[line.children.clone()]
+                .iter()
+                .find_map(Child::widget)
+        });
+        let last_child = last_line.and_then(|line| {
+            self.children[line.children.clone()]
+                .iter()
+                .rev()
+                .find_map(Child::widget)
+        });
+        if let (Some(first_child), Some(last_child)) = (first_child, last_child) {
+            let (first_baseline, _) = ctx.child_aligned_baselines(first_child);
+            let first_baseline = ctx.child_origin(first_child).y + first_baseline;
+            let (_, last_baseline) = ctx.child_aligned_baselines(last_child);
+            let last_baseline = ctx.child_origin(last_child).y + last_baseline;
+            ctx.set_baselines(first_baseline, last_baseline);
+        } else {
+            // No child widgets, so fall back to default baselines.
+            ctx.clear_baselines();
+        }
+    }
+}
+
 // --- MARK: IMPL WIDGET
 impl Widget for Flex {
     type Action = NoAction;
@@ -728,6 +1149,36 @@ impl Widget for Flex {
             LenReq::FitContent(space) => (LenReq::MinContent, space),
         };
 
+        if self.wrap != FlexWrap::NoWrap {
+            let compute_length = |widget: &mut WidgetPod<dyn Widget>, auto, axis, cross| {
+                ctx.compute_length(widget, auto, context_size, axis, cross)
+            };
+            if measure_axis == main && len_req == LenReq::MinContent {
+                // Every child can get a line all to itself,
+                // so the minimum length is that of the longest child.
+                let length = hypothetical_lengths(&mut self.children, main, compute_length)
+                    .into_iter()
+                    .fold(0., f64::max);
+                return min_result.max(length.px());
+            }
+            if measure_axis == cross
+                && let Some(main_space) = main_space
+            {
+                let (lines, _) = resolve_lines(
+                    &mut self.children,
+                    main,
+                    main_space.get(),
+                    gap_length,
+                    len_req.into(),
+                    compute_length,
+                );
+                let line_gaps = lines.len().saturating_sub(1) as f64 * gap_length;
+                let length = lines.iter().map(|line| line.cross_length).sum::<f64>() + line_gaps;
+                return min_result.max(length.px());
+            }
+            // Otherwise all the children fit on a single line, same as without wrapping.
+        }
+
         // We can skip resolving bases if we don't know the main space when measuring cross.
         // That is because in that code path we don't ever read the resolved basis.
         let skip_resolving_bases = measure_axis == cross && main_space.is_none();
@@ -925,6 +1376,11 @@ impl Widget for Flex {
         let gap_length = gap.gap.get();
         let gap_count = self.children.len().saturating_sub(1);
 
+        if self.wrap != FlexWrap::NoWrap {
+            self.layout_wrapped(ctx, size, gap_length);
+            return;
+        }
+
         let main = self.direction;
         let cross = main.cross();
         let cross_space = size.get_coord(cross).px();
@@ -1515,6 +1971,111 @@ mod tests {
         assert_eq!(rect, Rect::new(0., 2., 10., 8.));
     }
 
+    #[test]
+    fn wrap_breaks_lines() {
+        let tags: [WidgetTag<SizedBox>; 5] = std::array::from_fn(|_| WidgetTag::unique());
+
+        let mut widget = Flex::row()
+            .with_wrap(FlexWrap::Wrap)
+            .cross_axis_alignment(CrossAxisAlignment::Start);
+        for (idx, tag) in tags.iter().enumerate() {
+            let height = if idx == 1 { 20.px() } else { 10.px() };
+            widget = widget.with_fixed(
+                SizedBox::empty()
+                    .size(30.px(), height)
+                    .prepare()
+                    .with_tag(*tag),
+            );
+        }
+        let widget = widget.prepare().with_props(Gap::new(5.px()));
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (100, 60));
+
+        let rect = |harness: &TestHarness<Flex>, tag: WidgetTag<SizedBox>| {
+            let widget = harness.get_widget(tag);
+            let ctx = widget.ctx();
+            ctx.window_transform().transform_rect_bbox(ctx.border_box())
+        };
+
+        assert_eq!(rect(&harness, tags[0]), Rect::new(0., 0., 30., 10.));
+        assert_eq!(rect(&harness, tags[1]), Rect::new(35., 0., 65., 20.));
+        assert_eq!(rect(&harness, tags[2]), Rect::new(70., 0., 100., 10.));
+        assert_eq!(rect(&harness, tags[3]), Rect::new(0., 25., 30., 35.));
+        assert_eq!(rect(&harness, tags[4]), Rect::new(35., 25., 65., 35.));
+
+        harness.edit_root_widget(|mut flex| {
+            Flex::set_wrap(&mut flex, FlexWrap::WrapReverse);
+        });
+        assert_eq!(rect(&harness, tags[0]), Rect::new(0., 40., 30., 50.));
+        assert_eq!(rect(&harness, tags[1]), Rect::new(35., 40., 65., 60.));
+        assert_eq!(rect(&harness, tags[3]), Rect::new(0., 25., 30., 35.));
+
+        harness.edit_root_widget(|mut flex| {
+            Flex::set_wrap(&mut flex, FlexWrap::Wrap);
+            Flex::set_line_alignment(&mut flex, LineAlignment::SpaceBetween);
+        });
+        assert_eq!(rect(&harness, tags[0]), Rect::new(0., 0., 30., 10.));
+        assert_eq!(rect(&harness, tags[3]), Rect::new(0., 50., 30., 60.));
+    }
+
+    #[test]
+    fn wrap_flexible_children_per_line() {
+        let first = WidgetTag::unique();
+        let second = WidgetTag::unique();
+        let third = WidgetTag::unique();
+
+        let child = |tag| {
+            SizedBox::empty()
+                .size(40.px(), 10.px())
+                .prepare()
+                .with_tag(tag)
+        };
+        let widget = Flex::row()
+            .with_wrap(FlexWrap::Wrap)
+            .with(child(first), FlexParams::new(1.0, FlexBasis::Auto, None))
+            .with(child(second), FlexParams::new(1.0, FlexBasis::Auto, None))
+            .with(child(third), FlexParams::new(1.0, FlexBasis::Auto, None))
+            .prepare()
+            .with_props(Gap::new(0.px()));
+
+        let harness = TestHarness::create_with_size(test_property_set(), widget, (100, 20));
+
+        let rect = |tag: WidgetTag<SizedBox>| {
+            let widget = harness.get_widget(tag);
+            let ctx = widget.ctx();
+            ctx.window_transform().transform_rect_bbox(ctx.border_box())
+        };
+
+        // Each line distributes its own free space.
+        assert_eq!(rect(first).width(), 50.);
+        assert_eq!(rect(second).width(), 50.);
+        assert_eq!(rect(third).width(), 100.);
+    }
+
+    #[test]
+    fn wrap_measures_line_heights() {
+        let flex_tag = WidgetTag::unique();
+
+        let mut wrapped = Flex::row().with_wrap(FlexWrap::Wrap);
+        for _ in 0..5 {
+            wrapped = wrapped.with_fixed(SizedBox::empty().size(30.px(), 10.px()).prepare());
+        }
+        let wrapped = wrapped
+            .prepare()
+            .with_tag(flex_tag)
+            .with_props(Gap::new(5.px()));
+
+        let root = Flex::column()
+            .cross_axis_alignment(CrossAxisAlignment::Start)
+            .with_fixed(wrapped)
+            .prepare()
+            .with_props(Gap::new(0.px()));
+
+        let harness = TestHarness::create_with_size(test_property_set(), root, (100, 200));
+        let flex = harness.get_widget(flex_tag);
+        assert_eq!(flex.ctx().border_box().size(), Size::new(100., 25.));
+    }
+
     // TODO - Reduce copy-pasting?
     #[test]
     fn flex_row_cross_axis_snapshots() {
@@ -0,0 +1,291 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ArcStr, ChildrenIds, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
+    PropertiesMut, PropertiesRef, RegisterCtx, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
+    WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::{LayoutSize, LenDef, LenReq, Length, SizeDef};
+use crate::theme;
+use crate::widgets::Label;
+
+/// The class of the label showing the error message of a [`FormField`].
+///
+/// The default theme uses it to show the message in [`ERROR_COLOR`](theme::ERROR_COLOR).
+pub const FORM_ERROR_CLASS: &str = "#form_error";
+
+/// A form input with an error message shown under it.
+///
+/// While there is an error, the input is marked [invalid], so themes can style it
+/// with [`Selector::with_invalid`], and assistive technologies read the error message
+/// when describing it.
+/// Without an error, the message takes no space.
+///
+/// [invalid]: masonry_core::doc::masonry_concepts#invalid
+/// [`Selector::with_invalid`]: crate::core::Selector::with_invalid
+pub struct FormField {
+    input: WidgetPod<dyn Widget>,
+    error_label: WidgetPod<Label>,
+    error: Option<ArcStr>,
+}
+
+// --- MARK: BUILDERS
+impl FormField {
+    /// Creates a form field around `input`, with no error.
+    pub fn new(input: NewWidget<impl Widget + ?Sized>) -> Self {
+        let error_label = NewWidget::new(Label::new("")).with_class(FORM_ERROR_CLASS);
+        Self {
+            input: input.erased().to_pod(),
+            error_label: error_label.to_pod(),
+            error: None,
+        }
+    }
+
+    /// Builder-style method to set the error message.
+    ///
+    /// `None` means the input is valid.
+    pub fn with_error(mut self, error: Option<impl Into<ArcStr>>) -> Self {
+        self.error = error.map(Into::into);
+        self
+    }
+
+    /// Returns the current error message, if the input is invalid.
+    pub fn error(&self) -> Option<&ArcStr> {
+        self.error.as_ref()
+    }
+
+    /// Returns the id of the label showing the error message.
+    pub fn error_label_id(&self) -> WidgetId {
+        self.error_label.id()
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl FormField {
+    /// Sets the error message.
+    ///
+    /// `None` means the input is valid.
+    pub fn set_error(this: &mut WidgetMut<'_, Self>, error: Option<impl Into<ArcStr>>) {
+        let error = error.map(Into::into);
+        if this.widget.error == error {
+            return;
+        }
+        this.widget.error = error;
+        Self::sync_error(this);
+    }
+
+    /// Returns a mutable reference to the input widget.
+    pub fn input_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.input)
+    }
+
+    /// Replaces the input widget with a new one.
+    pub fn set_input(this: &mut WidgetMut<'_, Self>, input: NewWidget<impl Widget + ?Sized>) {
+        this.ctx.remove_child(std::mem::replace(
+            &mut this.widget.input,
+            input.erased().to_pod(),
+        ));
+        let error = this.widget.error.is_some();
+        let label_id = this.widget.error_label.id();
+        this.ctx
+            .mutate_child_later(&mut this.widget.input, move |mut input| {
+                Self::mark_input(&mut input, error, label_id);
+            });
+    }
+
+    /// Applies the current error to the label and the input.
+    fn sync_error(this: &mut WidgetMut<'_, Self>) {
+        let text = this.widget.error.clone().unwrap_or_default();
+        Label::set_text(&mut this.ctx.get_mut(&mut this.widget.error_label), text);
+        let error = this.widget.error.is_some();
+        let label_id = this.widget.error_label.id();
+        Self::mark_input(&mut Self::input_mut(this), error, label_id);
+        this.ctx.request_layout();
+    }
+
+    fn mark_input(input: &mut WidgetMut<'_, dyn Widget>, error: bool, label_id: WidgetId) {
+        input.ctx.set_invalid(error);
+        input.ctx.set_error_message(error.then_some(label_id));
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for FormField {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.input);
+        ctx.register_child(&mut self.error_label);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if matches!(event, Update::WidgetAdded) && self.error.is_some() {
+            ctx.mutate_self_later(|mut this| {
+                Self::sync_error(&mut this.downcast());
+            });
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        let space: LenDef = len_req.into();
+        // We know our width, and it's also the width of both children,
+        // but we don't know how to split our height between them.
+        let child_cross_length = match axis.cross() {
+            Axis::Horizontal => cross_length,
+            Axis::Vertical => None,
+        };
+        let context_size = LayoutSize::maybe(axis.cross(), child_cross_length);
+
+        let input_length = ctx.compute_length(
+            &mut self.input,
+            space,
+            context_size,
+            axis,
+            child_cross_length,
+        );
+        if self.error.is_none() {
+            return input_length;
+        }
+
+        let error_space = match axis {
+            Axis::Horizontal => space,
+            Axis::Vertical => space.reduce(input_length.saturating_add(theme::FORM_ERROR_GAP)),
+        };
+        let error_length = ctx.compute_length(
+            &mut self.error_label,
+            error_space,
+            context_size,
+            axis,
+            child_cross_length,
+        );
+        match axis {
+            Axis::Horizontal => input_length.max(error_length),
+            Axis::Vertical => input_length
+                .saturating_add(theme::FORM_ERROR_GAP)
+                .saturating_add(error_length),
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.set_stashed(&mut self.error_label, self.error.is_none());
+
+        let input_size = ctx.compute_size(&mut self.input, SizeDef::fit(size), size.into());
+        ctx.run_layout(&mut self.input, input_size);
+        ctx.place_child(&mut self.input, Point::ORIGIN);
+        ctx.derive_baselines(&self.input);
+
+        if self.error.is_some() {
+            let error_y = input_size.height + theme::FORM_ERROR_GAP.get();
+            let error_space = Size::new(size.width, (size.height - error_y).max(0.));
+            let error_size = ctx.compute_size(
+                &mut self.error_label,
+                SizeDef::fit(error_space),
+                error_space.into(),
+            );
+            ctx.run_layout(&mut self.error_label, error_size);
+            ctx.place_child(&mut self.error_label, Point::new(0., error_y));
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.input.id(), self.error_label.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("FormField", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::properties::{BorderColor, ContentColor};
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::TextInput;
+
+    #[test]
+    fn error_marks_input_invalid() {
+        let input = NewWidget::new(TextInput::new("bob"));
+        let input_id = input.id();
+        let field = FormField::new(input).with_error(Some("Must contain an @"));
+        let label_id = field.error_label_id();
+        let mut harness = TestHarness::create(test_property_set(), NewWidget::new(field));
+
+        let input = harness.get_widget_with_id(input_id);
+        assert!(input.ctx().is_invalid());
+        assert_eq!(input.get_prop::<BorderColor>().color, theme::ERROR_COLOR);
+        let node = harness.access_node(input_id).unwrap();
+        assert_eq!(node.data().invalid(), Some(accesskit::Invalid::True));
+        assert_eq!(
+            node.data().described_by(),
+            &[accesskit::NodeId::from(label_id)]
+        );
+        let label = harness.get_widget_with_id(label_id);
+        assert!(!label.ctx().is_stashed());
+        assert_eq!(label.get_prop::<ContentColor>().color, theme::ERROR_COLOR);
+
+        harness.edit_root_widget(|mut field| {
+            FormField::set_error(&mut field.downcast(), None::<ArcStr>);
+        });
+        let _ = harness.redraw();
+        let input = harness.get_widget_with_id(input_id);
+        assert!(!input.ctx().is_invalid());
+        assert_ne!(input.get_prop::<BorderColor>().color, theme::ERROR_COLOR);
+        let node = harness.access_node(input_id).unwrap();
+        assert_eq!(node.data().invalid(), None);
+        assert!(node.data().described_by().is_empty());
+        assert!(harness.get_widget_with_id(label_id).ctx().is_stashed());
+    }
+
+    #[test]
+    fn invalid_state_is_inherited() {
+        let input = NewWidget::new(TextInput::new(""));
+        let input_id = input.id();
+        let area_id = input.widget.area_pod().id();
+        let mut harness = TestHarness::create(test_property_set(), input);
+        assert!(!harness.get_widget_with_id(area_id).ctx().is_invalid());
+
+        harness.edit_root_widget(|mut input| input.ctx.set_invalid(true));
+        let _ = harness.redraw();
+        assert!(harness.get_widget_with_id(input_id).ctx().is_invalid());
+        assert!(harness.get_widget_with_id(area_id).ctx().is_invalid());
+        let node = harness.access_node(area_id).unwrap();
+        assert_eq!(node.data().invalid(), Some(accesskit::Invalid::True));
+    }
+}
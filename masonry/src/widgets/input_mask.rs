@@ -0,0 +1,169 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+/// A pattern constraining what can be typed in a [`TextArea`](super::TextArea).
+///
+/// Each character of the pattern is a slot for one character of text:
+///
+/// - `9` accepts a digit.
+/// - `a` accepts a letter.
+/// - `h` accepts a hexadecimal digit.
+/// - `*` accepts a letter or a digit.
+/// - `\` makes the next character a literal, e.g. `\9` for a literal `9`.
+/// - Any other character is a literal separator.
+///
+/// Characters which don't fit the next slot are dropped, and separators are
+/// inserted automatically as the user types.
+///
+/// # Examples
+///
+/// ```
+/// use masonry::widgets::InputMask;
+///
+/// let phone = InputMask::new("(999) 999-9999");
+/// assert_eq!(phone.apply("5551234567"), "(555) 123-4567");
+///
+/// let date = InputMask::new("99/99/9999");
+/// assert_eq!(date.apply("1a2"), "12");
+/// assert_eq!(date.apply("123"), "12/3");
+///
+/// let color = InputMask::new("#hhhhhh");
+/// assert_eq!(color.apply("ff80zz00"), "#ff8000");
+/// assert!(color.is_complete("#ff8000"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputMask {
+    slots: Vec<MaskSlot>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MaskSlot {
+    Digit,
+    Letter,
+    HexDigit,
+    Alphanumeric,
+    Literal(char),
+}
+
+impl MaskSlot {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::HexDigit => c.is_ascii_hexdigit(),
+            Self::Alphanumeric => c.is_alphanumeric(),
+            Self::Literal(literal) => c == literal,
+        }
+    }
+}
+
+impl InputMask {
+    /// Creates a mask from a pattern.
+    ///
+    /// See the [type documentation](Self) for the syntax.
+    pub fn new(pattern: &str) -> Self {
+        let mut slots = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            slots.push(match c {
+                '9' => MaskSlot::Digit,
+                'a' => MaskSlot::Letter,
+                'h' => MaskSlot::HexDigit,
+                '*' => MaskSlot::Alphanumeric,
+                '\\' => MaskSlot::Literal(chars.next().unwrap_or('\\')),
+                c => MaskSlot::Literal(c),
+            });
+        }
+        Self { slots }
+    }
+
+    /// Formats `text` with this mask.
+    ///
+    /// Characters which don't fit are dropped, and missing separators are inserted.
+    /// Separators after the last character of `text` aren't added.
+    pub fn apply(&self, text: &str) -> String {
+        self.apply_with_cursor(text, 0).0
+    }
+
+    /// Returns whether `text` fills every slot of this mask.
+    pub fn is_complete(&self, text: &str) -> bool {
+        let masked = self.apply(text);
+        masked == text && masked.chars().count() == self.slots.len()
+    }
+
+    /// Formats `text` with this mask, and moves `cursor` to match.
+    ///
+    /// `cursor` is a char index into `text`, and the returned cursor is a char index into
+    /// the result.
+    pub(crate) fn apply_with_cursor(&self, text: &str, cursor: usize) -> (String, usize) {
+        let mut masked = String::new();
+        let mut masked_len = 0;
+        let mut new_cursor = 0;
+        let mut next_slot = 0;
+        for (i, c) in text.chars().enumerate() {
+            // Find the first slot `c` fits in, skipping separators.
+            let fit = self.slots[next_slot..]
+                .iter()
+                .position(|slot| !matches!(slot, MaskSlot::Literal(_)) || slot.accepts(c))
+                .map(|offset| next_slot + offset)
+                .filter(|&slot| self.slots[slot].accepts(c));
+            if let Some(slot) = fit {
+                for skipped in &self.slots[next_slot..slot] {
+                    if let MaskSlot::Literal(literal) = skipped {
+                        masked.push(*literal);
+                    }
+                }
+                masked.push(c);
+                masked_len += slot + 1 - next_slot;
+                next_slot = slot + 1;
+            }
+            if i + 1 == cursor {
+                new_cursor = masked_len;
+            }
+        }
+        (masked, new_cursor)
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn separators_are_inserted() {
+        let phone = InputMask::new("(999) 999-9999");
+        assert_eq!(phone.apply(""), "");
+        assert_eq!(phone.apply("5"), "(5");
+        assert_eq!(phone.apply("555"), "(555");
+        assert_eq!(phone.apply("5551"), "(555) 1");
+        assert_eq!(phone.apply("(555) 1"), "(555) 1");
+        assert_eq!(phone.apply("555 12345678910"), "(555) 123-4567");
+    }
+
+    #[test]
+    fn rejected_characters_are_dropped() {
+        let date = InputMask::new("99/99/9999");
+        assert_eq!(date.apply("x"), "");
+        assert_eq!(date.apply("1x2/"), "12/");
+        assert!(!date.is_complete("12/"));
+        assert!(date.is_complete("12/31/1999"));
+
+        let code = InputMask::new(r"aa-\9*");
+        assert_eq!(code.apply("ab1c"), "ab-91");
+        assert_eq!(code.apply("a1"), "a");
+    }
+
+    #[test]
+    fn cursor_follows_text() {
+        let phone = InputMask::new("(999) 999-9999");
+        // Typing "1" at the end of "(555".
+        assert_eq!(phone.apply_with_cursor("(5551", 5), ("(555) 1".into(), 7));
+        // Typing "1" after "(5".
+        assert_eq!(phone.apply_with_cursor("(515", 3), ("(515".into(), 3));
+        // Deleting the separator before "1" brings it back.
+        assert_eq!(phone.apply_with_cursor("(555)1", 5), ("(555) 1".into(), 5));
+        // Typing a rejected character leaves the cursor where it was.
+        assert_eq!(phone.apply_with_cursor("(5x55", 3), ("(555".into(), 2));
+    }
+}
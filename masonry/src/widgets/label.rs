@@ -3,6 +3,7 @@
 
 use std::any::TypeId;
 use std::mem::Discriminant;
+use std::ops::Range;
 
 use accesskit::{Node, Role};
 use include_doc_path::include_doc_path;
@@ -51,6 +52,8 @@ pub struct Label {
     text: ArcStr,
     styles: StyleSet,
     text_alignment: TextAlign,
+    /// A byte range of the text drawn underlined, such as the access key of a menu.
+    underline: Option<Range<usize>>,
 
     /// Whether to hint whilst drawing the text.
     ///
@@ -188,6 +191,7 @@ impl Label {
             text: text.into(),
             styles,
             text_alignment: TextAlign::Start,
+            underline: None,
             hint: true,
             accessibility_hidden: false,
             accessibility: LayoutAccessibility::default(),
@@ -250,6 +254,12 @@ impl Label {
         self
     }
 
+    /// Underlines the given byte range of the text, on top of the label's styles.
+    pub(crate) fn with_underline(mut self, range: Option<Range<usize>>) -> Self {
+        self.underline = range;
+        self
+    }
+
     /// Shared logic between `with_style` and `insert_style`
     fn insert_style_inner(&mut self, property: StyleProperty) -> Option<StyleProperty> {
         if let StyleProperty::Brush(idx @ BrushIndex(1..))
@@ -340,6 +350,15 @@ impl Label {
         this.ctx.request_layout();
     }
 
+    /// The runtime equivalent of [`with_underline`](Self::with_underline).
+    pub(crate) fn set_underline(this: &mut WidgetMut<'_, Self>, range: Option<Range<usize>>) {
+        if this.widget.underline != range {
+            this.widget.underline = range;
+            this.widget.clear_cache();
+            this.ctx.request_layout();
+        }
+    }
+
     /// The runtime equivalent of [`with_hint`](Self::with_hint).
     pub fn set_hint(this: &mut WidgetMut<'_, Self>, hint: bool) {
         this.widget.hint = hint;
@@ -425,6 +444,9 @@ impl Label {
         for prop in self.styles.inner().values() {
             builder.push_default(prop.to_owned());
         }
+        if let Some(range) = &self.underline {
+            builder.push(StyleProperty::Underline(true), range.clone());
+        }
         builder.build_into(&mut layout.layout, &self.text);
 
         layout.layout.break_all_lines(max_advance);
@@ -0,0 +1,1061 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::Range;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::{
+    AccessCtx, ChildrenIds, EventCtx, LayerType, LayoutCtx, MeasureCtx, NewWidget, PaintCtx,
+    PointerButton, PointerButtonEvent, PointerEvent, PointerUpdate, PropertiesMut, PropertiesRef,
+    RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Rect, Size, Vec2};
+use crate::layers::MenuPopup;
+use crate::layout::{Anchor, LayoutSize, LenDef, LenReq, Length, SizeDef};
+use crate::theme;
+use crate::widgets::Label;
+
+/// The horizontal padding around the title of a menu.
+const TITLE_PADDING_H: f64 = 8.;
+/// The vertical padding around the titles of the menus.
+const TITLE_PADDING_V: f64 = 4.;
+
+/// A top-level menu of a [`MenuBar`].
+///
+/// In titles and item labels, `&` marks the next character as the access key of the
+/// menu or item; write `&&` for a literal `&`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Menu {
+    /// The title shown in the bar, such as `&File`.
+    pub title: String,
+    /// The items shown when the menu is open.
+    pub items: Vec<MenuItem>,
+}
+
+/// An item of a [`Menu`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum MenuItem {
+    /// An item which can be activated.
+    Command(MenuCommand),
+    /// A line between two groups of items.
+    Separator,
+}
+
+/// An item of a [`Menu`] which reports a [`MenuItemActivated`] when activated.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MenuCommand {
+    /// The identifier reported when the item is activated.
+    pub id: String,
+    /// The text of the item, such as `&Save`.
+    pub label: String,
+    /// The keyboard shortcut shown next to the label, such as `Ctrl+S`.
+    ///
+    /// This is only a hint: handling the shortcut is up to the app.
+    pub shortcut: Option<String>,
+    /// Whether the item can be activated.
+    pub enabled: bool,
+}
+
+/// An item of a [`MenuBar`] was activated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MenuItemActivated {
+    /// The [`id`](MenuCommand::id) of the item.
+    pub id: String,
+}
+
+/// The access key of a menu title or item, parsed from its `&` marker.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct AccessKey {
+    /// The lowercase text of the key.
+    key: String,
+    /// The byte range of the key in the displayed text.
+    pub(crate) range: Range<usize>,
+}
+
+impl AccessKey {
+    /// Returns whether pressing `key` triggers this access key.
+    pub(crate) fn matches(&self, key: &Key) -> bool {
+        matches!(key, Key::Character(c) if c.to_lowercase() == self.key)
+    }
+}
+
+/// Removes the `&` markers from `text`, returning the text to display and its access key.
+pub(crate) fn parse_access_key(text: &str) -> (String, Option<AccessKey>) {
+    let mut display = String::with_capacity(text.len());
+    let mut access_key = None;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '&' {
+            display.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('&') => display.push('&'),
+            Some(c) => {
+                let start = display.len();
+                display.push(c);
+                access_key.get_or_insert_with(|| AccessKey {
+                    key: c.to_lowercase().collect(),
+                    range: start..display.len(),
+                });
+            }
+            None => {}
+        }
+    }
+    (display, access_key)
+}
+
+/// A bar of menus, shown above the content of a window.
+///
+/// Clicking a title opens its menu as a [layer], and while a menu is open, hovering
+/// another title switches to it. Pressing and releasing Alt highlights the first title,
+/// after which the arrow keys move between menus and items, Enter activates the
+/// highlighted item and Escape steps back. Holding Alt underlines the access keys:
+/// Alt and the access key of a title opens its menu, and once a menu is open, the
+/// access key of an item activates it.
+///
+/// The bar wraps the content so that it receives the keys pressed while the focus is
+/// in the content. While a menu is used, the bar takes the focus, and gives it back
+/// when the menu closes.
+///
+/// [layer]: masonry_core::doc::masonry_concepts#layers
+pub struct MenuBar {
+    menus: Vec<Menu>,
+    titles: Vec<WidgetPod<Label>>,
+    title_keys: Vec<Option<AccessKey>>,
+    content: WidgetPod<dyn Widget>,
+    /// The area of each title, in content-box coordinates.
+    title_rects: Vec<Rect>,
+    /// The highlighted title, while the bar is used.
+    active: Option<usize>,
+    hovered: Option<usize>,
+    /// The layer of the open menu, which belongs to the active title.
+    popup: Option<WidgetId>,
+    /// The highlighted item of the open menu.
+    highlighted: Option<usize>,
+    show_access_keys: bool,
+    /// Whether Alt was pressed without any other key, so that releasing it enters the bar.
+    alt_alone: bool,
+    /// The widget which had the focus before the bar was used.
+    restore_focus: Option<WidgetId>,
+}
+
+// --- MARK: BUILDERS
+impl Menu {
+    /// Creates a menu without items.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            items: Vec::new(),
+        }
+    }
+
+    /// Builder-style method to add an item.
+    pub fn with_item(mut self, item: impl Into<MenuItem>) -> Self {
+        self.items.push(item.into());
+        self
+    }
+
+    /// Builder-style method to add a separator.
+    pub fn with_separator(mut self) -> Self {
+        self.items.push(MenuItem::Separator);
+        self
+    }
+}
+
+impl MenuCommand {
+    /// Creates an enabled item without shortcut.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            shortcut: None,
+            enabled: true,
+        }
+    }
+
+    /// Builder-style method to show a keyboard shortcut next to the label.
+    pub fn with_shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Builder-style method to set whether the item can be activated.
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+impl From<MenuCommand> for MenuItem {
+    fn from(command: MenuCommand) -> Self {
+        Self::Command(command)
+    }
+}
+
+impl MenuItem {
+    /// Returns the command of this item, if it can be activated.
+    pub(crate) fn enabled_command(&self) -> Option<&MenuCommand> {
+        match self {
+            Self::Command(command) if command.enabled => Some(command),
+            _ => None,
+        }
+    }
+}
+
+impl MenuBar {
+    /// Creates a bar with the given menus, above `content`.
+    pub fn new(menus: Vec<Menu>, content: NewWidget<impl Widget + ?Sized>) -> Self {
+        let (titles, title_keys) = Self::build_titles(&menus);
+        Self {
+            menus,
+            titles,
+            title_keys,
+            content: content.erased().to_pod(),
+            title_rects: Vec::new(),
+            active: None,
+            hovered: None,
+            popup: None,
+            highlighted: None,
+            show_access_keys: false,
+            alt_alone: false,
+            restore_focus: None,
+        }
+    }
+
+    /// Returns the menus of the bar.
+    pub fn menus(&self) -> &[Menu] {
+        &self.menus
+    }
+
+    /// Returns the index of the open menu, if any.
+    pub fn open_menu(&self) -> Option<usize> {
+        self.active.filter(|_| self.popup.is_some())
+    }
+
+    /// Returns the index of the highlighted item of the open menu, if any.
+    pub fn highlighted_item(&self) -> Option<usize> {
+        self.highlighted
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl MenuBar {
+    /// Replaces the menus of the bar, closing the open menu.
+    pub fn set_menus(this: &mut WidgetMut<'_, Self>, menus: Vec<Menu>) {
+        Self::reset(this);
+        for title in this.widget.titles.drain(..) {
+            this.ctx.remove_child(title);
+        }
+        let (titles, title_keys) = Self::build_titles(&menus);
+        this.widget.menus = menus;
+        this.widget.titles = titles;
+        this.widget.title_keys = title_keys;
+        this.widget.title_rects.clear();
+        this.widget.hovered = None;
+        this.ctx.children_changed();
+    }
+
+    /// Returns a mutable reference to the content.
+    pub fn content_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.content)
+    }
+
+    /// Closes the menu after one of its items was clicked, and reports the item.
+    pub(crate) fn activate_from_popup(
+        this: &mut WidgetMut<'_, Self>,
+        popup: WidgetId,
+        index: usize,
+    ) {
+        if this.widget.popup != Some(popup) {
+            return;
+        }
+        let command = this
+            .widget
+            .active
+            .and_then(|menu| this.widget.menus[menu].items.get(index))
+            .and_then(MenuItem::enabled_command);
+        let Some(command) = command else {
+            return;
+        };
+        let id = command.id.clone();
+        Self::reset(this);
+        this.ctx
+            .submit_action::<MenuItemActivated>(MenuItemActivated { id });
+    }
+
+    /// Closes the menu after its popup was dismissed by clicking outside of it.
+    pub(crate) fn dismiss_popup(this: &mut WidgetMut<'_, Self>, popup: WidgetId) {
+        if this.widget.popup == Some(popup) {
+            Self::reset(this);
+        }
+    }
+
+    /// Follows the item hovered in the popup, so keyboard navigation starts from it.
+    pub(crate) fn set_hovered_item(
+        this: &mut WidgetMut<'_, Self>,
+        popup: WidgetId,
+        index: Option<usize>,
+    ) {
+        if this.widget.popup == Some(popup) {
+            this.widget.highlighted = index;
+        }
+    }
+
+    /// Closes the open menu and stops using the bar, leaving the focus alone.
+    fn reset(this: &mut WidgetMut<'_, Self>) {
+        if let Some(popup) = this.widget.popup.take() {
+            this.ctx.remove_layer(popup);
+        }
+        this.widget.active = None;
+        this.widget.highlighted = None;
+        this.widget.alt_alone = false;
+        this.widget.restore_focus = None;
+        if this.widget.show_access_keys {
+            this.widget.show_access_keys = false;
+            for title in &mut this.widget.titles {
+                Label::set_underline(&mut this.ctx.get_mut(title), None);
+            }
+        }
+        this.ctx.request_paint_only();
+    }
+}
+
+// --- MARK: INTERNALS
+impl MenuBar {
+    fn build_titles(menus: &[Menu]) -> (Vec<WidgetPod<Label>>, Vec<Option<AccessKey>>) {
+        menus
+            .iter()
+            .map(|menu| {
+                let (text, key) = parse_access_key(&menu.title);
+                (WidgetPod::new(Label::new(text)), key)
+            })
+            .unzip()
+    }
+
+    fn title_at(&self, pos: Point) -> Option<usize> {
+        self.title_rects.iter().position(|rect| rect.contains(pos))
+    }
+
+    fn title_for_key(&self, key: &Key) -> Option<usize> {
+        self.title_keys
+            .iter()
+            .position(|access_key| access_key.as_ref().is_some_and(|k| k.matches(key)))
+    }
+
+    fn item_for_key(&self, menu: usize, key: &Key) -> Option<usize> {
+        self.menus[menu].items.iter().position(|item| {
+            item.enabled_command()
+                .and_then(|command| parse_access_key(&command.label).1)
+                .is_some_and(|access_key| access_key.matches(key))
+        })
+    }
+
+    /// Returns the enabled item of the open menu next to `from`, wrapping around.
+    fn step_item(&self, from: Option<usize>, forward: bool) -> Option<usize> {
+        let items = &self.menus[self.active?].items;
+        let len = items.len();
+        (1..=len)
+            .map(|step| match from {
+                Some(from) if forward => (from + step) % len,
+                Some(from) => (from + len - step) % len,
+                None if forward => step - 1,
+                None => len - step,
+            })
+            .find(|&index| items[index].enabled_command().is_some())
+    }
+
+    fn set_show_access_keys(&mut self, ctx: &mut EventCtx<'_>, show: bool) {
+        if self.show_access_keys == show {
+            return;
+        }
+        self.show_access_keys = show;
+        for (title, access_key) in self.titles.iter_mut().zip(&self.title_keys) {
+            let range = access_key
+                .as_ref()
+                .filter(|_| show)
+                .map(|access_key| access_key.range.clone());
+            ctx.mutate_child_later(title, move |mut label| {
+                Label::set_underline(&mut label, range);
+            });
+        }
+    }
+
+    /// Highlights the title at `index`, taking the focus if the bar wasn't used yet.
+    fn activate_title(&mut self, ctx: &mut EventCtx<'_>, index: usize) {
+        if self.active.is_none() {
+            let id = ctx.widget_id();
+            self.restore_focus = ctx.focus_target_id().filter(|focused| *focused != id);
+        }
+        // This also overrides the focus given back by a popup dismissed by this click.
+        ctx.request_focus();
+        self.active = Some(index);
+        ctx.request_paint_only();
+    }
+
+    fn open_menu_at(&mut self, ctx: &mut EventCtx<'_>, index: usize, from_keyboard: bool) {
+        self.close_popup(ctx);
+        self.activate_title(ctx, index);
+        if self.menus[index].items.is_empty() {
+            return;
+        }
+        if from_keyboard {
+            self.highlighted = self.step_item(None, true);
+        }
+
+        let popup = MenuPopup::new(
+            ctx.widget_id(),
+            &self.menus[index].items,
+            self.show_access_keys,
+        )
+        .with_highlighted(self.highlighted)
+        .with_restore_focus(self.restore_focus);
+        let popup = NewWidget::new(popup);
+        self.popup = Some(popup.id());
+
+        let title_rect = self.title_rects.get(index).copied().unwrap_or_default();
+        let anchor =
+            Anchor::new(ctx.widget_id()).with_offset(Vec2::new(title_rect.x0, title_rect.y1));
+        ctx.create_anchored_layer(LayerType::Other, popup, anchor);
+    }
+
+    fn close_popup(&mut self, ctx: &mut EventCtx<'_>) {
+        if let Some(popup) = self.popup.take() {
+            ctx.remove_layer(popup);
+        }
+        self.highlighted = None;
+        ctx.request_paint_only();
+    }
+
+    /// Closes the open menu, stops using the bar and gives the focus back.
+    fn leave(&mut self, ctx: &mut EventCtx<'_>) {
+        self.close_popup(ctx);
+        self.active = None;
+        self.alt_alone = false;
+        self.set_show_access_keys(ctx, false);
+        if let Some(id) = self.restore_focus.take() {
+            ctx.set_focus(id);
+        } else if ctx.is_focus_target() {
+            ctx.resign_focus();
+        }
+    }
+
+    fn activate_item(&mut self, ctx: &mut EventCtx<'_>, index: usize) {
+        let command = self
+            .active
+            .and_then(|menu| self.menus[menu].items.get(index))
+            .and_then(MenuItem::enabled_command);
+        let Some(command) = command else {
+            return;
+        };
+        let id = command.id.clone();
+        self.leave(ctx);
+        ctx.submit_action::<MenuItemActivated>(MenuItemActivated { id });
+    }
+
+    fn set_highlighted(&mut self, ctx: &mut EventCtx<'_>, index: Option<usize>) {
+        self.highlighted = index;
+        if let Some(popup) = self.popup {
+            ctx.mutate_later(popup, move |mut popup| {
+                MenuPopup::set_highlighted(&mut popup.downcast(), index);
+            });
+        }
+    }
+
+    fn on_key_down(&mut self, ctx: &mut EventCtx<'_>, key: &Key, alt: bool) {
+        if *key == Key::Named(NamedKey::Alt) {
+            self.alt_alone = true;
+            self.set_show_access_keys(ctx, true);
+            return;
+        }
+        self.alt_alone = false;
+
+        if alt {
+            if let Some(index) = self.title_for_key(key) {
+                self.set_show_access_keys(ctx, true);
+                self.open_menu_at(ctx, index, true);
+                ctx.set_handled();
+            }
+            return;
+        }
+
+        let Some(active) = self.active else {
+            return;
+        };
+        let open = self.popup.is_some();
+        let len = self.menus.len();
+        let is_space = matches!(key, Key::Character(c) if c == " ");
+        match key {
+            Key::Named(NamedKey::ArrowLeft | NamedKey::ArrowRight) => {
+                let next = if *key == Key::Named(NamedKey::ArrowRight) {
+                    (active + 1) % len
+                } else {
+                    (active + len - 1) % len
+                };
+                if open {
+                    self.open_menu_at(ctx, next, true);
+                } else {
+                    self.active = Some(next);
+                    ctx.request_paint_only();
+                }
+            }
+            Key::Named(NamedKey::ArrowDown | NamedKey::Enter) if !open => {
+                self.open_menu_at(ctx, active, true);
+            }
+            _ if is_space && !open => self.open_menu_at(ctx, active, true),
+            Key::Named(NamedKey::ArrowDown) => {
+                let next = self.step_item(self.highlighted, true);
+                self.set_highlighted(ctx, next);
+            }
+            Key::Named(NamedKey::ArrowUp) if open => {
+                let previous = self.step_item(self.highlighted, false);
+                self.set_highlighted(ctx, previous);
+            }
+            Key::Named(NamedKey::Enter) => {
+                if let Some(index) = self.highlighted {
+                    self.activate_item(ctx, index);
+                }
+            }
+            _ if is_space => {
+                if let Some(index) = self.highlighted {
+                    self.activate_item(ctx, index);
+                }
+            }
+            Key::Named(NamedKey::Escape) if open => self.close_popup(ctx),
+            Key::Named(NamedKey::Escape) => self.leave(ctx),
+            _ if open => {
+                if let Some(index) = self.item_for_key(active, key) {
+                    self.activate_item(ctx, index);
+                }
+            }
+            _ => {
+                if let Some(index) = self.title_for_key(key) {
+                    self.open_menu_at(ctx, index, true);
+                }
+            }
+        }
+        // While the bar is used, it keeps all keys from the rest of the window.
+        ctx.set_handled();
+    }
+
+    fn on_key_up(&mut self, ctx: &mut EventCtx<'_>, key: &Key) {
+        if *key != Key::Named(NamedKey::Alt) {
+            return;
+        }
+        if std::mem::take(&mut self.alt_alone) {
+            if self.active.is_some() {
+                self.leave(ctx);
+            } else if !self.menus.is_empty() {
+                self.activate_title(ctx, 0);
+            }
+            ctx.set_handled();
+        } else if self.active.is_none() {
+            self.set_show_access_keys(ctx, false);
+        }
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for MenuBar {
+    type Action = MenuItemActivated;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: None | Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                let Some(index) = self.title_at(ctx.local_position(state.position)) else {
+                    return;
+                };
+                if self.open_menu() == Some(index) {
+                    self.leave(ctx);
+                } else {
+                    self.open_menu_at(ctx, index, false);
+                }
+                ctx.set_handled();
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) => {
+                let hovered = self.title_at(ctx.local_position(current.position));
+                if hovered != self.hovered {
+                    self.hovered = hovered;
+                    ctx.request_paint_only();
+                }
+                if let Some(index) = hovered
+                    && self.popup.is_some()
+                    && self.active != Some(index)
+                {
+                    self.open_menu_at(ctx, index, false);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        match event {
+            TextEvent::Keyboard(event) if event.state.is_down() => {
+                self.on_key_down(ctx, &event.key, event.modifiers.alt());
+            }
+            TextEvent::Keyboard(event) => self.on_key_up(ctx, &event.key),
+            TextEvent::WindowFocusChange(false) => {
+                self.alt_alone = false;
+                if self.active.is_some() {
+                    self.leave(ctx);
+                } else {
+                    self.set_show_access_keys(ctx, false);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::HoveredChanged(false) => {
+                self.hovered = None;
+                ctx.request_paint_only();
+            }
+            // The focus moved elsewhere while the bar was used, e.g. to a clicked widget.
+            Update::FocusChanged(false) if self.active.is_some() => {
+                if let Some(popup) = self.popup.take() {
+                    ctx.remove_layer(popup);
+                }
+                self.active = None;
+                self.highlighted = None;
+                self.restore_focus = None;
+                if std::mem::take(&mut self.show_access_keys) {
+                    for title in &mut self.titles {
+                        ctx.mutate_child_later(title, |mut label| {
+                            Label::set_underline(&mut label, None);
+                        });
+                    }
+                }
+                ctx.request_paint_only();
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for title in &mut self.titles {
+            ctx.register_child(title);
+        }
+        ctx.register_child(&mut self.content);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        let auto_length: LenDef = len_req.into();
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+
+        let mut bar_length = Length::ZERO;
+        for title in &mut self.titles {
+            let length = ctx.compute_length(title, LenDef::MaxContent, context_size, axis, None);
+            bar_length = match axis {
+                Axis::Horizontal => bar_length
+                    .saturating_add(length.saturating_add(Length::px(2. * TITLE_PADDING_H))),
+                Axis::Vertical => {
+                    bar_length.max(length.saturating_add(Length::px(2. * TITLE_PADDING_V)))
+                }
+            };
+        }
+
+        match axis {
+            Axis::Horizontal => {
+                let content_length = ctx.compute_length(
+                    &mut self.content,
+                    auto_length,
+                    context_size,
+                    axis,
+                    cross_length,
+                );
+                bar_length.max(content_length)
+            }
+            Axis::Vertical => {
+                let content_length = ctx.compute_length(
+                    &mut self.content,
+                    auto_length.reduce(bar_length),
+                    context_size,
+                    axis,
+                    cross_length,
+                );
+                bar_length.saturating_add(content_length)
+            }
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let mut title_sizes = Vec::with_capacity(self.titles.len());
+        for title in &mut self.titles {
+            let title_size = ctx.compute_size(title, SizeDef::MAX, size.into());
+            ctx.run_layout(title, title_size);
+            title_sizes.push(title_size);
+        }
+        let bar_height = title_sizes
+            .iter()
+            .map(|title_size| title_size.height + 2. * TITLE_PADDING_V)
+            .fold(0., f64::max);
+
+        self.title_rects.clear();
+        let mut x = 0.;
+        for (title, title_size) in self.titles.iter_mut().zip(title_sizes) {
+            let width = title_size.width + 2. * TITLE_PADDING_H;
+            let y = (bar_height - title_size.height) * 0.5;
+            ctx.place_child(title, Point::new(x + TITLE_PADDING_H, y));
+            self.title_rects
+                .push(Rect::new(x, 0., x + width, bar_height));
+            x += width;
+        }
+
+        let space = Size::new(size.width, (size.height - bar_height).max(0.));
+        let content_size = ctx.compute_size(&mut self.content, SizeDef::fit(space), space.into());
+        ctx.run_layout(&mut self.content, content_size);
+        ctx.place_child(&mut self.content, Point::new(0., bar_height));
+    }
+
+    fn paint(
+        &mut self,
+        ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        painter: &mut Painter<'_>,
+    ) {
+        let bar_height = self.title_rects.first().map_or(0., |rect| rect.y1);
+        let bar_rect = Rect::new(0., 0., ctx.content_box().width(), bar_height);
+        painter.fill(bar_rect, theme::ZYNC_900).draw();
+
+        let highlight = self.active.map(|index| (index, theme::ZYNC_700));
+        let hover = self.hovered.map(|index| (index, theme::ZYNC_800));
+        for (index, color) in hover.into_iter().chain(highlight) {
+            if let Some(rect) = self.title_rects.get(index) {
+                painter.fill(rect.to_rounded_rect(4.), color).draw();
+            }
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::MenuBar
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.titles
+            .iter()
+            .map(|title| title.id())
+            .chain([self.content.id()])
+            .collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("MenuBar", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::keyboard::{KeyState, KeyboardEvent, Modifiers};
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+    use crate::widgets::TextInput;
+
+    fn menus() -> Vec<Menu> {
+        vec![
+            Menu::new("&File")
+                .with_item(MenuCommand::new("open", "&Open").with_shortcut("Ctrl+O"))
+                .with_separator()
+                .with_item(MenuCommand::new("print", "&Print").with_enabled(false))
+                .with_item(MenuCommand::new("quit", "&Quit")),
+            Menu::new("&Edit")
+                .with_item(MenuCommand::new("copy", "&Copy"))
+                .with_item(MenuCommand::new("paste", "&Paste")),
+        ]
+    }
+
+    fn key(harness: &mut TestHarness<MenuBar>, key: Key, state: KeyState, alt: bool) {
+        let modifiers = if alt {
+            Modifiers::ALT
+        } else {
+            Modifiers::empty()
+        };
+        harness.process_text_event(TextEvent::Keyboard(KeyboardEvent {
+            state,
+            key,
+            modifiers,
+            ..KeyboardEvent::default()
+        }));
+    }
+
+    fn press(harness: &mut TestHarness<MenuBar>, named: NamedKey) {
+        key(harness, Key::Named(named), KeyState::Down, false);
+        key(harness, Key::Named(named), KeyState::Up, false);
+    }
+
+    fn title_center(harness: &TestHarness<MenuBar>, index: usize) -> Point {
+        let bar = harness.root_widget();
+        bar.ctx().to_window(bar.inner().title_rects[index].center())
+    }
+
+    /// Returns the center of the item of the open menu with the given text.
+    fn item_center(harness: &TestHarness<MenuBar>, text: &str) -> Point {
+        let popup = harness.root_widget().inner().popup.unwrap();
+        let popup = harness.get_widget_with_id(popup);
+        let label = popup
+            .children()
+            .into_iter()
+            .find(|child| {
+                child
+                    .downcast::<Label>()
+                    .is_some_and(|label| label.inner().text().as_ref() == text)
+            })
+            .unwrap();
+        label
+            .ctx()
+            .window_transform()
+            .transform_rect_bbox(label.ctx().border_box())
+            .center()
+    }
+
+    fn activated(harness: &mut TestHarness<MenuBar>) -> Option<String> {
+        harness
+            .pop_action::<MenuItemActivated>()
+            .map(|(action, _)| action.id)
+    }
+
+    #[test]
+    fn parse_access_keys() {
+        let (text, access_key) = parse_access_key("Save &As...");
+        assert_eq!(text, "Save As...");
+        let access_key = access_key.unwrap();
+        assert_eq!(access_key.range, 5..6);
+        assert!(access_key.matches(&Key::Character("a".into())));
+        assert!(access_key.matches(&Key::Character("A".into())));
+
+        assert_eq!(parse_access_key("R&&D"), ("R&D".into(), None));
+    }
+
+    #[test]
+    fn open_menu_with_highlighted_item() {
+        let bar = MenuBar::new(menus(), NewWidget::new(TextInput::new("")));
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(bar), (300, 200));
+
+        harness.mouse_move(title_center(&harness, 0));
+        harness.mouse_button_press(None);
+        harness.mouse_button_release(None);
+        harness.mouse_move(item_center(&harness, "Open"));
+
+        assert_render_snapshot!(harness, "menu_bar_open_menu_with_highlighted_item");
+    }
+
+    #[test]
+    fn click_opens_and_hover_switches() {
+        let input = NewWidget::new(TextInput::new(""));
+        let input_id = input.id();
+        let bar = NewWidget::new(MenuBar::new(menus(), input));
+        let mut harness = TestHarness::create(test_property_set(), bar);
+        harness.focus_on(Some(input_id));
+
+        harness.mouse_move(title_center(&harness, 0));
+        harness.mouse_button_press(None);
+        harness.mouse_button_release(None);
+        assert_eq!(harness.root_widget().inner().open_menu(), Some(0));
+        assert_eq!(harness.root_widget().inner().highlighted_item(), None);
+
+        // Hovering another title while a menu is open switches to it.
+        harness.mouse_move(title_center(&harness, 1));
+        assert_eq!(harness.root_widget().inner().open_menu(), Some(1));
+
+        harness.mouse_move(item_center(&harness, "Paste"));
+        assert_eq!(harness.root_widget().inner().highlighted_item(), Some(1));
+        harness.mouse_button_press(None);
+        harness.mouse_button_release(None);
+        assert_eq!(activated(&mut harness).as_deref(), Some("paste"));
+        assert_eq!(harness.root_widget().inner().open_menu(), None);
+        assert_eq!(harness.focused_widget_id(), Some(input_id));
+    }
+
+    #[test]
+    fn click_outside_closes_menu() {
+        let input = NewWidget::new(TextInput::new(""));
+        let input_id = input.id();
+        let bar = NewWidget::new(MenuBar::new(menus(), input));
+        let mut harness = TestHarness::create(test_property_set(), bar);
+        harness.focus_on(Some(input_id));
+
+        harness.mouse_move(title_center(&harness, 0));
+        harness.mouse_button_press(None);
+        harness.mouse_button_release(None);
+        assert_eq!(harness.root_widget().inner().open_menu(), Some(0));
+
+        // Clicking the title of the open menu closes it.
+        harness.mouse_button_press(None);
+        harness.mouse_button_release(None);
+        assert_eq!(harness.root_widget().inner().open_menu(), None);
+        assert_eq!(harness.focused_widget_id(), Some(input_id));
+
+        harness.mouse_button_press(None);
+        harness.mouse_button_release(None);
+        assert_eq!(harness.root_widget().inner().open_menu(), Some(0));
+        harness.mouse_move(
+            harness
+                .root_widget()
+                .ctx()
+                .to_window(Point::new(300., 300.)),
+        );
+        harness.mouse_button_press(None);
+        harness.mouse_button_release(None);
+        assert_eq!(harness.root_widget().inner().open_menu(), None);
+        assert_eq!(activated(&mut harness), None);
+    }
+
+    #[test]
+    fn alt_enters_keyboard_navigation() {
+        let input = NewWidget::new(TextInput::new(""));
+        let input_id = input.id();
+        let bar = NewWidget::new(MenuBar::new(menus(), input));
+        let mut harness = TestHarness::create(test_property_set(), bar);
+        harness.focus_on(Some(input_id));
+
+        key(
+            &mut harness,
+            Key::Named(NamedKey::Alt),
+            KeyState::Down,
+            false,
+        );
+        assert!(harness.root_widget().inner().show_access_keys);
+        key(&mut harness, Key::Named(NamedKey::Alt), KeyState::Up, true);
+        let bar_id = harness.root_widget().id();
+        assert_eq!(harness.root_widget().inner().active, Some(0));
+        assert_eq!(harness.focused_widget_id(), Some(bar_id));
+
+        // Moving between titles doesn't open menus until asked.
+        press(&mut harness, NamedKey::ArrowRight);
+        assert_eq!(harness.root_widget().inner().active, Some(1));
+        assert_eq!(harness.root_widget().inner().open_menu(), None);
+        press(&mut harness, NamedKey::ArrowRight);
+        press(&mut harness, NamedKey::ArrowDown);
+        assert_eq!(harness.root_widget().inner().open_menu(), Some(0));
+        assert_eq!(harness.root_widget().inner().highlighted_item(), Some(0));
+
+        // The separator and the disabled item are skipped.
+        press(&mut harness, NamedKey::ArrowDown);
+        assert_eq!(harness.root_widget().inner().highlighted_item(), Some(3));
+        press(&mut harness, NamedKey::ArrowDown);
+        assert_eq!(harness.root_widget().inner().highlighted_item(), Some(0));
+        press(&mut harness, NamedKey::ArrowUp);
+        assert_eq!(harness.root_widget().inner().highlighted_item(), Some(3));
+
+        // Escape closes the menu first, then leaves the bar.
+        press(&mut harness, NamedKey::Escape);
+        assert_eq!(harness.root_widget().inner().open_menu(), None);
+        assert_eq!(harness.root_widget().inner().active, Some(0));
+        press(&mut harness, NamedKey::Escape);
+        assert_eq!(harness.root_widget().inner().active, None);
+        assert!(!harness.root_widget().inner().show_access_keys);
+        assert_eq!(harness.focused_widget_id(), Some(input_id));
+
+        key(
+            &mut harness,
+            Key::Named(NamedKey::Alt),
+            KeyState::Down,
+            false,
+        );
+        key(&mut harness, Key::Named(NamedKey::Alt), KeyState::Up, true);
+        press(&mut harness, NamedKey::Enter);
+        press(&mut harness, NamedKey::ArrowDown);
+        press(&mut harness, NamedKey::Enter);
+        assert_eq!(activated(&mut harness).as_deref(), Some("quit"));
+        assert_eq!(harness.root_widget().inner().active, None);
+        assert_eq!(harness.focused_widget_id(), Some(input_id));
+    }
+
+    #[test]
+    fn access_keys_open_menus_and_activate_items() {
+        let input = NewWidget::new(TextInput::new(""));
+        let input_id = input.id();
+        let bar = NewWidget::new(MenuBar::new(menus(), input));
+        let mut harness = TestHarness::create(test_property_set(), bar);
+        harness.focus_on(Some(input_id));
+
+        key(
+            &mut harness,
+            Key::Named(NamedKey::Alt),
+            KeyState::Down,
+            false,
+        );
+        key(
+            &mut harness,
+            Key::Character("e".into()),
+            KeyState::Down,
+            true,
+        );
+        key(&mut harness, Key::Character("e".into()), KeyState::Up, true);
+        key(&mut harness, Key::Named(NamedKey::Alt), KeyState::Up, false);
+        assert_eq!(harness.root_widget().inner().open_menu(), Some(1));
+        assert_eq!(harness.root_widget().inner().highlighted_item(), Some(0));
+
+        // Releasing Alt after using an access key doesn't leave the bar.
+        assert!(harness.root_widget().inner().show_access_keys);
+        key(
+            &mut harness,
+            Key::Character("p".into()),
+            KeyState::Down,
+            false,
+        );
+        assert_eq!(activated(&mut harness).as_deref(), Some("paste"));
+        assert_eq!(harness.focused_widget_id(), Some(input_id));
+
+        // Disabled items can't be activated by their access key.
+        key(
+            &mut harness,
+            Key::Named(NamedKey::Alt),
+            KeyState::Down,
+            false,
+        );
+        key(
+            &mut harness,
+            Key::Character("f".into()),
+            KeyState::Down,
+            true,
+        );
+        key(&mut harness, Key::Named(NamedKey::Alt), KeyState::Up, false);
+        key(
+            &mut harness,
+            Key::Character("p".into()),
+            KeyState::Down,
+            false,
+        );
+        assert_eq!(activated(&mut harness), None);
+        assert_eq!(harness.root_widget().inner().open_menu(), Some(0));
+    }
+}
@@ -8,81 +8,125 @@
     reason = "Widgets are not expected to implement Debug"
 )]
 
+mod adaptive;
 mod align;
 mod badge;
 mod badged;
 mod button;
+mod calendar;
+mod calendar_day;
 mod canvas;
 mod checkbox;
 mod collapse_panel;
+mod color_area;
+mod color_picker;
+mod color_slider;
+mod color_swatch;
+mod date_picker;
 mod disclosure_button;
 mod divider;
+mod dock_area;
+mod dock_layout;
 mod flex;
+mod form_field;
 mod grid;
 mod image;
 mod indexed_stack;
+mod input_mask;
 mod label;
+mod menu_bar;
 mod pagination;
 mod passthrough;
 mod portal;
+mod positioned;
 mod progress_bar;
 mod prose;
 mod radio_button;
 mod radio_group;
+mod range_slider;
 mod resize_observer;
 mod scroll_bar;
 mod selector;
 mod selector_item;
 mod sized_box;
 mod slider;
+mod slider_thumb;
+mod slider_ticks;
 mod spinner;
 mod split;
 mod step_input;
 mod svg;
 mod switch;
+mod tab_bar;
+mod tab_header;
+mod tabs;
 mod text_area;
 mod text_input;
+mod time_picker;
 mod variable_label;
 mod virtual_scroll;
+mod zoom_portal;
 mod zstack;
 
 // TODO - Split off widgets and other exports?
 // (e.g. actions, param types)
 
+pub use self::adaptive::*;
 pub use self::align::*;
 pub use self::badge::*;
 pub use self::badged::*;
 pub use self::button::*;
+pub use self::calendar::*;
+pub use self::calendar_day::*;
 pub use self::canvas::*;
 pub use self::checkbox::*;
 pub use self::collapse_panel::*;
+pub use self::color_area::*;
+pub use self::color_picker::*;
+pub use self::color_slider::*;
+pub use self::color_swatch::*;
+pub use self::date_picker::*;
 pub use self::disclosure_button::*;
 pub use self::divider::*;
+pub use self::dock_area::*;
+pub use self::dock_layout::*;
 pub use self::flex::*;
+pub use self::form_field::*;
 pub use self::grid::*;
 pub use self::image::*;
 pub use self::indexed_stack::*;
+pub use self::input_mask::*;
 pub use self::label::*;
+pub use self::menu_bar::*;
 pub use self::pagination::*;
 pub use self::passthrough::*;
 pub use self::portal::*;
+pub use self::positioned::*;
 pub use self::progress_bar::*;
 pub use self::prose::*;
 pub use self::radio_button::*;
 pub use self::radio_group::*;
+pub use self::range_slider::*;
 pub use self::resize_observer::*;
 pub use self::scroll_bar::*;
 pub use self::selector::*;
 pub use self::selector_item::*;
 pub use self::sized_box::*;
 pub use self::slider::*;
+pub use self::slider_thumb::*;
+pub use self::slider_ticks::*;
 pub use self::spinner::*;
 pub use self::split::*;
 pub use self::step_input::*;
 pub use self::svg::*;
 pub use self::switch::*;
+pub use self::tab_bar::*;
+pub use self::tab_header::*;
+pub use self::tabs::*;
 pub use self::text_area::*;
 pub use self::text_input::*;
+pub use self::time_picker::*;
 pub use self::variable_label::*;
 pub use self::virtual_scroll::*;
+pub use self::zoom_portal::*;
 pub use self::zstack::*;
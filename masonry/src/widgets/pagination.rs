@@ -477,8 +477,10 @@ mod tests {
 
     #[test]
     fn button_pages() {
+        // The empty vectors need their type spelled out: masonry_core's `inspector` feature
+        // pulls in serde_json, whose `PartialEq<Value>` impls for integers make `vec![]` ambiguous.
         let pg = Pagination::new(0);
-        assert_eq!(pg.derive_button_pages(), vec![]);
+        assert_eq!(pg.derive_button_pages(), Vec::<usize>::new());
 
         let pg = Pagination::new(1);
         assert_eq!(pg.derive_button_pages(), vec![0]);
@@ -491,7 +493,7 @@ mod tests {
             .buttons_start(1)
             .buttons_end(1)
             .active_page(5);
-        assert_eq!(pg.derive_button_pages(), vec![]);
+        assert_eq!(pg.derive_button_pages(), Vec::<usize>::new());
 
         let pg = Pagination::new(10)
             .buttons_total(1)
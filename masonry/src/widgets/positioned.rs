@@ -0,0 +1,565 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ChildrenIds, CollectionWidget, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
+    PropertiesRef, RegisterCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::{Anchor, LayoutSize, LenDef, LenReq, Length, SizeDef};
+
+/// Where a child of a [`Positioned`] container is placed.
+///
+/// A child is either placed with offsets from the container's content-box edges,
+/// or anchored to another widget with an [`Anchor`].
+///
+/// On each axis, a child with only a start (left/top) or an end (right/bottom) offset
+/// keeps its preferred length and is placed at that distance from the matching edge.
+/// A child with both offsets is stretched to fill the space between them.
+/// A child with neither is placed at the start edge.
+///
+/// Anchored children ignore the offsets: they are sized to fit the container and
+/// then moved to follow their target, so they don't contribute to the container's size.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Position {
+    /// Distance between the container's left edge and the child's left edge.
+    pub left: Option<Length>,
+    /// Distance between the container's top edge and the child's top edge.
+    pub top: Option<Length>,
+    /// Distance between the child's right edge and the container's right edge.
+    pub right: Option<Length>,
+    /// Distance between the child's bottom edge and the container's bottom edge.
+    pub bottom: Option<Length>,
+    /// The widget this child follows, if any.
+    pub anchor: Option<Anchor>,
+}
+
+impl Position {
+    /// Creates a position which places the child at the container's top-left corner.
+    pub const fn new() -> Self {
+        Self {
+            left: None,
+            top: None,
+            right: None,
+            bottom: None,
+            anchor: None,
+        }
+    }
+
+    /// Creates a position which makes the child follow the given anchor.
+    pub const fn anchored(anchor: Anchor) -> Self {
+        let mut position = Self::new();
+        position.anchor = Some(anchor);
+        position
+    }
+
+    /// Sets the distance from the container's left edge.
+    pub const fn left(mut self, left: Length) -> Self {
+        self.left = Some(left);
+        self
+    }
+
+    /// Sets the distance from the container's top edge.
+    pub const fn top(mut self, top: Length) -> Self {
+        self.top = Some(top);
+        self
+    }
+
+    /// Sets the distance from the container's right edge.
+    pub const fn right(mut self, right: Length) -> Self {
+        self.right = Some(right);
+        self
+    }
+
+    /// Sets the distance from the container's bottom edge.
+    pub const fn bottom(mut self, bottom: Length) -> Self {
+        self.bottom = Some(bottom);
+        self
+    }
+
+    /// Sets all four offsets, stretching the child over the container minus `inset`.
+    pub const fn inset(self, inset: Length) -> Self {
+        self.left(inset).top(inset).right(inset).bottom(inset)
+    }
+
+    /// Returns the start and end offsets on the given axis.
+    fn offsets(&self, axis: Axis) -> (Option<Length>, Option<Length>) {
+        match axis {
+            Axis::Horizontal => (self.left, self.right),
+            Axis::Vertical => (self.top, self.bottom),
+        }
+    }
+}
+
+impl From<Anchor> for Position {
+    fn from(anchor: Anchor) -> Self {
+        Self::anchored(anchor)
+    }
+}
+
+struct Child {
+    widget: WidgetPod<dyn Widget>,
+    position: Position,
+}
+
+/// A container which places each child at explicit offsets from its edges,
+/// or anchors it to another widget.
+///
+/// This is useful for badges, popovers, and other decorations which need to be
+/// laid out independently of their siblings.
+/// Children are painted in order, so later children are drawn on top of earlier ones.
+///
+/// See [`Position`] for how children are sized and placed.
+pub struct Positioned {
+    children: Vec<Child>,
+}
+
+// --- MARK: DEFAULT
+impl Default for Positioned {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// --- MARK: BUILDERS
+impl Positioned {
+    /// Constructs a new empty `Positioned` container.
+    pub fn new() -> Self {
+        Self {
+            children: Vec::new(),
+        }
+    }
+
+    /// Appends a child widget with the given position.
+    pub fn with(
+        mut self,
+        child: NewWidget<impl Widget + ?Sized>,
+        position: impl Into<Position>,
+    ) -> Self {
+        self.children.push(Child {
+            widget: child.erased().to_pod(),
+            position: position.into(),
+        });
+        self
+    }
+}
+
+// --- MARK: COLLECTIONWIDGET
+impl CollectionWidget<Position> for Positioned {
+    /// Returns the number of children.
+    fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Returns `true` if there are no children.
+    fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Returns a mutable reference to the child widget at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn get_mut<'t>(this: &'t mut WidgetMut<'_, Self>, idx: usize) -> WidgetMut<'t, dyn Widget> {
+        let child = &mut this.widget.children[idx].widget;
+        this.ctx.get_mut(child)
+    }
+
+    /// Appends a child widget to the collection.
+    fn add(
+        this: &mut WidgetMut<'_, Self>,
+        child: NewWidget<impl Widget + ?Sized>,
+        params: impl Into<Position>,
+    ) {
+        this.widget.children.push(Child {
+            widget: child.erased().to_pod(),
+            position: params.into(),
+        });
+        this.ctx.children_changed();
+    }
+
+    /// Inserts a child widget at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the number of children.
+    fn insert(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        child: NewWidget<impl Widget + ?Sized>,
+        params: impl Into<Position>,
+    ) {
+        let child = Child {
+            widget: child.erased().to_pod(),
+            position: params.into(),
+        };
+        this.widget.children.insert(idx, child);
+        this.ctx.children_changed();
+    }
+
+    /// Replaces the child widget at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn set(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        child: NewWidget<impl Widget + ?Sized>,
+        params: impl Into<Position>,
+    ) {
+        let child = Child {
+            widget: child.erased().to_pod(),
+            position: params.into(),
+        };
+        let old_child = std::mem::replace(&mut this.widget.children[idx], child);
+        this.ctx.remove_child(old_child.widget);
+    }
+
+    /// Sets the position of the child at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn set_params(this: &mut WidgetMut<'_, Self>, idx: usize, params: impl Into<Position>) {
+        this.widget.children[idx].position = params.into();
+        this.ctx.request_layout();
+    }
+
+    /// Swaps the index of two children.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    fn swap(this: &mut WidgetMut<'_, Self>, a: usize, b: usize) {
+        this.widget.children.swap(a, b);
+        this.ctx.children_changed();
+    }
+
+    /// Removes the child at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn remove(this: &mut WidgetMut<'_, Self>, idx: usize) {
+        let child = this.widget.children.remove(idx);
+        this.ctx.remove_child(child.widget);
+    }
+
+    /// Removes all children.
+    fn clear(this: &mut WidgetMut<'_, Self>) {
+        for child in this.widget.children.drain(..) {
+            this.ctx.remove_child(child.widget);
+        }
+    }
+}
+
+// --- MARK: HELPERS
+/// Returns the fallback used for a child's length on one axis,
+/// given the container's length on that axis.
+fn child_len_def(position: &Position, axis: Axis, length: Length) -> LenDef {
+    let (start, end) = position.offsets(axis);
+    let offsets = start
+        .unwrap_or(Length::ZERO)
+        .saturating_add(end.unwrap_or(Length::ZERO));
+    let available = length.saturating_sub(offsets);
+    if start.is_some() && end.is_some() {
+        LenDef::Fixed(available)
+    } else {
+        LenDef::FitContent(available)
+    }
+}
+
+/// Returns the child's origin on one axis, given its length and the container's length.
+fn child_origin(position: &Position, axis: Axis, child_length: f64, length: f64) -> f64 {
+    match position.offsets(axis) {
+        (Some(start), _) => start.get(),
+        (None, Some(end)) => length - end.get() - child_length,
+        (None, None) => 0.,
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Positioned {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for child in &mut self.children {
+            ctx.register_child(&mut child.widget);
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        let cross = axis.cross();
+        let context_size = LayoutSize::maybe(cross, cross_length);
+
+        let mut length = Length::ZERO;
+        for child in &mut self.children {
+            // Anchored children are placed relative to their target, not to us.
+            if child.position.anchor.is_some() {
+                continue;
+            }
+
+            let (start, end) = child.position.offsets(axis);
+            let offsets = start
+                .unwrap_or(Length::ZERO)
+                .saturating_add(end.unwrap_or(Length::ZERO));
+
+            let child_cross = cross_length.map(|cross_length| {
+                let len_def = child_len_def(&child.position, cross, cross_length);
+                ctx.compute_length(&mut child.widget, len_def, context_size, cross, None)
+            });
+            let auto_length = len_req.reduce(offsets).into();
+            let child_length = ctx.compute_length(
+                &mut child.widget,
+                auto_length,
+                context_size,
+                axis,
+                child_cross,
+            );
+            length = length.max(child_length.saturating_add(offsets));
+        }
+
+        length
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let context_size = size.into();
+        let width = Length::px(size.width);
+        let height = Length::px(size.height);
+
+        for child in &mut self.children {
+            let position = &child.position;
+            let auto_size = if position.anchor.is_some() {
+                SizeDef::fit(size)
+            } else {
+                SizeDef::new(
+                    child_len_def(position, Axis::Horizontal, width),
+                    child_len_def(position, Axis::Vertical, height),
+                )
+            };
+            let child_size = ctx.compute_size(&mut child.widget, auto_size, context_size);
+            ctx.run_layout(&mut child.widget, child_size);
+
+            let origin = if position.anchor.is_some() {
+                Point::ORIGIN
+            } else {
+                Point::new(
+                    child_origin(position, Axis::Horizontal, child_size.width, size.width),
+                    child_origin(position, Axis::Vertical, child_size.height, size.height),
+                )
+            };
+            ctx.place_child(&mut child.widget, origin);
+            ctx.set_child_anchor(&mut child.widget, position.anchor);
+        }
+        ctx.clear_baselines();
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.children
+            .iter()
+            .map(|child| child.widget.id())
+            .collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Positioned", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::WidgetTag;
+    use crate::kurbo::{Rect, Vec2};
+    use crate::layout::{AsUnit, UnitPoint};
+    use crate::properties::Dimensions;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::{Flex, SizedBox};
+
+    fn boxed(width: f64, height: f64) -> NewWidget<SizedBox> {
+        SizedBox::empty()
+            .prepare()
+            .with_props(Dimensions::fixed(width.px(), height.px()))
+    }
+
+    #[test]
+    fn places_children_by_offsets() {
+        let top_left = WidgetTag::unique();
+        let bottom_right = WidgetTag::unique();
+        let stretched = WidgetTag::unique();
+
+        let widget = Positioned::new()
+            .with(
+                boxed(10., 10.).with_tag(top_left),
+                Position::new().left(5.px()).top(5.px()),
+            )
+            .with(
+                boxed(10., 10.).with_tag(bottom_right),
+                Position::new().right(5.px()).bottom(5.px()),
+            )
+            .with(
+                SizedBox::empty().prepare().with_tag(stretched),
+                Position::new().inset(20.px()),
+            );
+
+        let harness =
+            TestHarness::create_with_size(test_property_set(), widget.prepare(), (100., 100.));
+
+        let rect = |tag: WidgetTag<SizedBox>| {
+            let widget = harness.get_widget(tag);
+            let ctx = widget.ctx();
+            ctx.border_box() + ctx.window_transform().translation()
+        };
+        assert_eq!(rect(top_left), Rect::new(5., 5., 15., 15.));
+        assert_eq!(rect(bottom_right), Rect::new(85., 85., 95., 95.));
+        assert_eq!(rect(stretched), Rect::new(20., 20., 80., 80.));
+    }
+
+    #[test]
+    fn anchored_child_follows_target() {
+        let target = WidgetTag::unique();
+        let badge = WidgetTag::unique();
+
+        let spacer = WidgetTag::unique();
+        let target_widget = boxed(40., 20.).with_tag(target);
+        let anchor =
+            Anchor::new(target_widget.id()).with_points(UnitPoint::TOP_RIGHT, UnitPoint::CENTER);
+
+        let content = Flex::column()
+            .with_fixed(boxed(10., 30.).with_tag(spacer))
+            .with_fixed(target_widget);
+        let widget = Positioned::new()
+            .with(content.prepare(), Position::new())
+            .with(boxed(6., 6.).with_tag(badge), anchor);
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget.prepare(), (100., 100.));
+
+        fn origin(harness: &TestHarness<Positioned>, tag: WidgetTag<SizedBox>) -> Vec2 {
+            harness
+                .get_widget(tag)
+                .ctx()
+                .window_transform()
+                .translation()
+        }
+        let target_origin = origin(&harness, target);
+        assert_eq!(origin(&harness, badge), target_origin + Vec2::new(37., -3.));
+
+        // Moving and resizing the target moves the badge along with it.
+        harness.edit_widget(spacer, |mut spacer| {
+            spacer.insert_prop(Dimensions::fixed(10.px(), 50.px()));
+        });
+        harness.edit_widget(target, |mut target| {
+            target.insert_prop(Dimensions::fixed(60.px(), 20.px()));
+        });
+        let old_target_origin = target_origin;
+        let target_origin = origin(&harness, target);
+        assert_eq!(target_origin.y, old_target_origin.y + 20.);
+        assert_eq!(origin(&harness, badge), target_origin + Vec2::new(57., -3.));
+    }
+
+    #[test]
+    fn chained_anchors_follow_their_targets() {
+        let spacer = WidgetTag::unique();
+        let target_tag = WidgetTag::unique();
+        let target = boxed(20., 20.).with_tag(target_tag);
+        let mut anchor =
+            Anchor::new(target.id()).with_points(UnitPoint::TOP_RIGHT, UnitPoint::TOP_LEFT);
+
+        let content = Flex::column()
+            .with_fixed(boxed(10., 30.).with_tag(spacer))
+            .with_fixed(target);
+        let mut widget = Positioned::new().with(content.prepare(), Position::new());
+        // Each badge is anchored to the right of the previous one.
+        let mut badges = Vec::new();
+        for _ in 0..6 {
+            let badge = boxed(5., 5.);
+            let next_anchor = Anchor {
+                target: badge.id(),
+                ..anchor
+            };
+            badges.push(badge.id());
+            widget = widget.with(badge, anchor);
+            anchor = next_anchor;
+        }
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget.prepare(), (100., 100.));
+
+        let assert_chained = |harness: &TestHarness<Positioned>| {
+            let target_origin = harness
+                .get_widget(target_tag)
+                .ctx()
+                .window_transform()
+                .translation();
+            for (i, id) in badges.iter().enumerate() {
+                let origin = harness
+                    .get_widget_with_id(*id)
+                    .ctx()
+                    .window_transform()
+                    .translation();
+                let expected = target_origin + Vec2::new(20. + 5. * i as f64, 0.);
+                assert_eq!(origin, expected, "badge {i}");
+            }
+        };
+        assert_chained(&harness);
+
+        // Moving the target moves the whole chain in a single update.
+        harness.edit_widget(spacer, |mut spacer| {
+            spacer.insert_prop(Dimensions::fixed(10.px(), 50.px()));
+        });
+        assert_chained(&harness);
+    }
+
+    #[test]
+    fn measures_offset_children() {
+        let positioned = WidgetTag::unique();
+        let widget = Positioned::new()
+            .with(boxed(30., 20.), Position::new().left(10.px()).top(5.px()))
+            .with(boxed(60., 60.), Anchor::new(boxed(1., 1.).id()))
+            .prepare()
+            .with_tag(positioned);
+        let widget = Flex::column().with_fixed(widget);
+
+        let harness =
+            TestHarness::create_with_size(test_property_set(), widget.prepare(), (100., 100.));
+
+        let size = harness.get_widget(positioned).ctx().border_box().size();
+        assert_eq!(size.height, 25.);
+    }
+}
@@ -0,0 +1,917 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{ActionData, Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::pointer::PointerButton;
+use crate::core::{
+    AccessCtx, AccessEvent, ChildrenIds, EventCtx, LayerType, LayoutCtx, MeasureCtx, NewWidget,
+    PaintCtx, PointerButtonEvent, PointerEvent, PointerUpdate, PropertiesMut, PropertiesRef,
+    Property, RegisterCtx, TextEvent, Update, UpdateCtx, UsesProperty, Widget, WidgetId, WidgetMut,
+    WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Rect, Size, Vec2};
+use crate::layers::Tooltip;
+use crate::layout::{Anchor, LayoutSize, LenDef, LenReq, Length, SizeDef, UnitPoint};
+use crate::properties::{TrackColor, TrackThickness};
+use crate::theme;
+use crate::widgets::{
+    Label, SliderThumb, SliderTick, SliderTicks, TickLabels, format_value, paint_ticks,
+};
+
+/// The length of the track, when not constrained by its parent.
+const TRACK_LENGTH: f64 = 100.;
+/// The space between a thumb and the tooltip showing its value.
+const TOOLTIP_GAP: f64 = 4.;
+/// The accessibility labels of the thumbs.
+const START_LABEL: &str = "Minimum";
+const END_LABEL: &str = "Maximum";
+
+/// A widget that allows a user to select a range of values, with two thumbs.
+///
+/// The thumbs can't cross: the start of the range is never above its end.
+/// Each thumb can be focused, and moved with the arrow keys by a step,
+/// or ten steps while `Shift` is held. `Home` and `End` move it as far as possible.
+/// The value of the dragged thumb is shown in a [`Tooltip`].
+///
+/// The slider is horizontal by default, with values increasing to the right.
+/// When [vertical](Self::with_direction), values increase upwards.
+///
+/// There will be a [`RangeSliderMoved`] action when the user moves a thumb.
+pub struct RangeSlider {
+    min: f64,
+    max: f64,
+    start: f64,
+    end: f64,
+    step: Option<f64>,
+    direction: Axis,
+    tick_config: SliderTicks,
+    ticks: Vec<SliderTick>,
+    tick_labels: TickLabels,
+    start_thumb: WidgetPod<SliderThumb>,
+    end_thumb: WidgetPod<SliderThumb>,
+    dragged: Option<Thumb>,
+    /// The side of the thumbs, from the last layout.
+    thumb_size: f64,
+}
+
+/// The range of a [`RangeSlider`] was changed by the user.
+#[derive(PartialEq, Debug)]
+pub struct RangeSliderMoved {
+    /// The start of the range.
+    pub start: f64,
+    /// The end of the range.
+    pub end: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Thumb {
+    Start,
+    End,
+}
+
+/// The geometry of the track of a [`RangeSlider`], along its main axis.
+#[derive(Clone, Copy)]
+struct Track {
+    min: f64,
+    max: f64,
+    direction: Axis,
+    thumb_size: f64,
+    length: f64,
+}
+
+impl Track {
+    /// Returns the position of the center of a thumb at `value`.
+    fn position(self, value: f64) -> f64 {
+        let range = self.max - self.min;
+        let progress = if range > 0. {
+            (value - self.min) / range
+        } else {
+            0.
+        };
+        let offset = self.thumb_size / 2. + progress * (self.length - self.thumb_size).max(0.);
+        match self.direction {
+            Axis::Horizontal => offset,
+            Axis::Vertical => self.length - offset,
+        }
+    }
+
+    /// Returns the value of a thumb centered at `position`.
+    fn value_at(self, position: f64) -> f64 {
+        let offset = match self.direction {
+            Axis::Horizontal => position,
+            Axis::Vertical => self.length - position,
+        } - self.thumb_size / 2.;
+        let progress = (offset / (self.length - self.thumb_size).max(f64::EPSILON)).clamp(0., 1.);
+        self.min + progress * (self.max - self.min)
+    }
+}
+
+// --- MARK: BUILDERS
+impl RangeSlider {
+    /// Creates a horizontal slider from `min` to `max`, with the range from `start` to `end`.
+    pub fn new(min: f64, max: f64, start: f64, end: f64) -> Self {
+        let max = max.max(min);
+        let (start, end) = (
+            start.min(end).clamp(min, max),
+            end.max(start).clamp(min, max),
+        );
+        let direction = Axis::Horizontal;
+        Self {
+            min,
+            max,
+            start,
+            end,
+            step: None,
+            direction,
+            tick_config: SliderTicks::None,
+            ticks: Vec::new(),
+            tick_labels: TickLabels::default(),
+            start_thumb: WidgetPod::new(SliderThumb::new(
+                START_LABEL,
+                start,
+                (min, end),
+                None,
+                direction,
+            )),
+            end_thumb: WidgetPod::new(SliderThumb::new(
+                END_LABEL,
+                end,
+                (start, max),
+                None,
+                direction,
+            )),
+            dragged: None,
+            thumb_size: 0.,
+        }
+    }
+
+    /// Builder-style method to set the stepping interval of the slider.
+    pub fn with_step(mut self, step: f64) -> Self {
+        self.step = Some(step).filter(|step| *step > 0.);
+        self.clamp_values();
+        self.resolve_ticks();
+        self.new_thumbs();
+        self
+    }
+
+    /// Builder-style method to set the tick marks shown along the slider.
+    pub fn with_ticks(mut self, ticks: SliderTicks) -> Self {
+        self.tick_config = ticks;
+        self.resolve_ticks();
+        self
+    }
+
+    /// Builder-style method to set the direction of the slider.
+    pub fn with_direction(mut self, direction: Axis) -> Self {
+        self.direction = direction;
+        self.new_thumbs();
+        self
+    }
+
+    /// Returns the start of the range.
+    pub fn start(&self) -> f64 {
+        self.start
+    }
+
+    /// Returns the end of the range.
+    pub fn end(&self) -> f64 {
+        self.end
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl RangeSlider {
+    /// Sets the start and end of the range.
+    pub fn set_values(this: &mut WidgetMut<'_, Self>, start: f64, end: f64) {
+        let (prev_start, prev_end) = (this.widget.start, this.widget.end);
+        this.widget.start = start;
+        this.widget.end = end;
+        this.widget.clamp_values();
+        if (this.widget.start, this.widget.end) != (prev_start, prev_end) {
+            Self::sync_thumbs(this);
+            this.ctx.request_layout();
+        }
+    }
+
+    /// Sets the bounds of the slider.
+    pub fn set_bounds(this: &mut WidgetMut<'_, Self>, min: f64, max: f64) {
+        if (this.widget.min, this.widget.max) == (min, max) {
+            return;
+        }
+        this.widget.min = min;
+        this.widget.max = max;
+        this.widget.clamp_values();
+        Self::update_ticks(this);
+        Self::sync_thumbs(this);
+        this.ctx.request_layout();
+    }
+
+    /// Sets or removes the stepping interval of the slider.
+    pub fn set_step(this: &mut WidgetMut<'_, Self>, step: Option<f64>) {
+        let step = step.filter(|step| *step > 0.);
+        if this.widget.step == step {
+            return;
+        }
+        this.widget.step = step;
+        this.widget.clamp_values();
+        Self::update_ticks(this);
+        Self::sync_thumbs(this);
+        this.ctx.request_layout();
+    }
+
+    /// Sets the tick marks shown along the slider.
+    pub fn set_ticks(this: &mut WidgetMut<'_, Self>, ticks: SliderTicks) {
+        if this.widget.tick_config != ticks {
+            this.widget.tick_config = ticks;
+            Self::update_ticks(this);
+        }
+    }
+
+    /// Sets the direction of the slider.
+    pub fn set_direction(this: &mut WidgetMut<'_, Self>, direction: Axis) {
+        if this.widget.direction != direction {
+            this.widget.direction = direction;
+            Self::sync_thumbs(this);
+            this.ctx.request_layout();
+        }
+    }
+
+    fn update_ticks(this: &mut WidgetMut<'_, Self>) {
+        let ticks =
+            this.widget
+                .tick_config
+                .resolve(this.widget.min, this.widget.max, this.widget.step);
+        if this.widget.ticks == ticks {
+            return;
+        }
+        this.widget.tick_labels.replace(&mut this.ctx, &ticks);
+        this.widget.ticks = ticks;
+        this.ctx.request_layout();
+    }
+
+    fn sync_thumbs(this: &mut WidgetMut<'_, Self>) {
+        for thumb in [Thumb::Start, Thumb::End] {
+            let (value, bounds) = this.widget.thumb_value(thumb);
+            let (step, direction) = (this.widget.step, this.widget.direction);
+            let mut thumb = this.ctx.get_mut(this.widget.thumb_pod(thumb));
+            SliderThumb::set_value(&mut thumb, value, bounds, step, direction);
+        }
+    }
+}
+
+// --- MARK: INTERNALS
+impl RangeSlider {
+    /// Snaps a value to the step of the slider, within its bounds.
+    fn snap(&self, value: f64) -> f64 {
+        let value = value.clamp(self.min, self.max);
+        match self.step {
+            Some(step) => ((value / step).round() * step).clamp(self.min, self.max),
+            None => value,
+        }
+    }
+
+    fn clamp_values(&mut self) {
+        if self.max < self.min {
+            self.max = self.min;
+        }
+        if self.end < self.start {
+            std::mem::swap(&mut self.start, &mut self.end);
+        }
+        self.start = self.snap(self.start);
+        self.end = self.snap(self.end).max(self.start);
+    }
+
+    /// Recreates the thumbs with the current state, before they're added to the tree.
+    fn new_thumbs(&mut self) {
+        self.start_thumb = WidgetPod::new(self.new_thumb(Thumb::Start));
+        self.end_thumb = WidgetPod::new(self.new_thumb(Thumb::End));
+    }
+
+    fn new_thumb(&self, thumb: Thumb) -> SliderThumb {
+        let label = match thumb {
+            Thumb::Start => START_LABEL,
+            Thumb::End => END_LABEL,
+        };
+        let (value, bounds) = self.thumb_value(thumb);
+        SliderThumb::new(label, value, bounds, self.step, self.direction)
+    }
+
+    fn resolve_ticks(&mut self) {
+        self.ticks = self.tick_config.resolve(self.min, self.max, self.step);
+        self.tick_labels = TickLabels::new(&self.ticks);
+    }
+
+    fn thumb_pod(&mut self, thumb: Thumb) -> &mut WidgetPod<SliderThumb> {
+        match thumb {
+            Thumb::Start => &mut self.start_thumb,
+            Thumb::End => &mut self.end_thumb,
+        }
+    }
+
+    /// Returns the value of `thumb`, and the bounds within which it can move.
+    fn thumb_value(&self, thumb: Thumb) -> (f64, (f64, f64)) {
+        match thumb {
+            Thumb::Start => (self.start, (self.min, self.end)),
+            Thumb::End => (self.end, (self.start, self.max)),
+        }
+    }
+
+    fn thumb_for_target(&self, target: WidgetId) -> Option<Thumb> {
+        if target == self.start_thumb.id() {
+            Some(Thumb::Start)
+        } else if target == self.end_thumb.id() {
+            Some(Thumb::End)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the thumb which is moved by a press at `value`.
+    ///
+    /// Returns `None` for a press on both thumbs, which are at the same value:
+    /// the moved thumb then depends on the direction of the drag.
+    fn thumb_near(&self, value: f64) -> Option<Thumb> {
+        let value = self.snap(value);
+        if value < self.start {
+            Some(Thumb::Start)
+        } else if value > self.end {
+            Some(Thumb::End)
+        } else if self.start == self.end {
+            None
+        } else if value - self.start < self.end - value {
+            Some(Thumb::Start)
+        } else {
+            Some(Thumb::End)
+        }
+    }
+
+    /// The step used by the arrow keys and accessibility actions.
+    fn key_step(&self) -> f64 {
+        self.step
+            .unwrap_or((self.max - self.min) / 100.0)
+            .max(f64::EPSILON)
+    }
+
+    /// Returns the geometry of the track, given its length along the main axis.
+    fn track(&self, length: f64) -> Track {
+        Track {
+            min: self.min,
+            max: self.max,
+            direction: self.direction,
+            thumb_size: self.thumb_size,
+            length,
+        }
+    }
+
+    /// Returns the length of the band of the track and thumbs, on the cross axis.
+    fn band(&self) -> f64 {
+        self.thumb_size + theme::WIDGET_CONTROL_COMPONENT_PADDING.get()
+    }
+
+    /// Moves `thumb` to `value`, without crossing the other thumb.
+    fn move_thumb(&mut self, ctx: &mut EventCtx<'_>, thumb: Thumb, value: f64) {
+        let (prev, (low, high)) = self.thumb_value(thumb);
+        let value = self.snap(value).clamp(low, high);
+        if (value - prev).abs() <= f64::EPSILON {
+            return;
+        }
+        match thumb {
+            Thumb::Start => self.start = value,
+            Thumb::End => self.end = value,
+        }
+        ctx.submit_action::<RangeSliderMoved>(RangeSliderMoved {
+            start: self.start,
+            end: self.end,
+        });
+        self.sync_thumbs_later(ctx);
+        ctx.request_layout();
+        ctx.request_paint_only();
+
+        if let Some(layer) = ctx.get_attached_layer::<Tooltip>() {
+            let text = format_value(value);
+            ctx.mutate_later(layer, move |mut tooltip| {
+                let mut tooltip = tooltip.downcast::<Tooltip>();
+                Label::set_text(&mut Tooltip::child_mut(&mut tooltip).downcast(), text);
+            });
+        }
+    }
+
+    fn sync_thumbs_later(&mut self, ctx: &mut EventCtx<'_>) {
+        for thumb in [Thumb::Start, Thumb::End] {
+            let (value, bounds) = self.thumb_value(thumb);
+            let (step, direction) = (self.step, self.direction);
+            ctx.mutate_child_later(self.thumb_pod(thumb), move |mut thumb| {
+                SliderThumb::set_value(&mut thumb, value, bounds, step, direction);
+            });
+        }
+    }
+
+    fn start_drag(&mut self, ctx: &mut EventCtx<'_>, thumb: Thumb) {
+        self.dragged = Some(thumb);
+        let pod = self.thumb_pod(thumb);
+        let thumb_id = pod.id();
+        ctx.mutate_child_later(pod, |mut thumb| SliderThumb::set_dragged(&mut thumb, true));
+        ctx.set_focus(thumb_id);
+
+        // The text changes while dragging, so the tooltip is always our own widget.
+        let (value, _) = self.thumb_value(thumb);
+        let tooltip = NewWidget::new(
+            Tooltip::new(NewWidget::new(Label::new(format_value(value))))
+                .with_dismiss_on_pointer(false),
+        );
+        let layer_id = tooltip.id();
+        let anchor = match self.direction {
+            Axis::Horizontal => Anchor::new(thumb_id)
+                .with_points(UnitPoint::TOP, UnitPoint::BOTTOM)
+                .with_offset(Vec2::new(0., -TOOLTIP_GAP)),
+            Axis::Vertical => Anchor::new(thumb_id)
+                .with_points(UnitPoint::LEFT, UnitPoint::RIGHT)
+                .with_offset(Vec2::new(-TOOLTIP_GAP, 0.)),
+        };
+        ctx.create_attached_layer(LayerType::Other, tooltip, Point::ORIGIN);
+        ctx.anchor_layer(layer_id, Some(anchor));
+    }
+
+    fn end_drag(&mut self, ctx: &mut EventCtx<'_>) {
+        let Some(thumb) = self.dragged.take() else {
+            return;
+        };
+        ctx.mutate_child_later(self.thumb_pod(thumb), |mut thumb| {
+            SliderThumb::set_dragged(&mut thumb, false);
+        });
+        if let Some(layer) = ctx.get_attached_layer::<Tooltip>() {
+            ctx.remove_layer(layer);
+        }
+    }
+
+    fn pointer_value(&self, ctx: &EventCtx<'_>, position: Point) -> f64 {
+        let length = ctx.content_box().size().get_coord(self.direction);
+        self.track(length)
+            .value_at(position.get_coord(self.direction))
+    }
+}
+
+impl UsesProperty<TrackThickness> for RangeSlider {}
+impl UsesProperty<TrackColor> for RangeSlider {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for RangeSlider {
+    type Action = RangeSliderMoved;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if ctx.is_disabled() {
+            return;
+        }
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: Some(PointerButton::Primary) | None,
+                state,
+                ..
+            }) => {
+                ctx.capture_pointer();
+                let value = self.pointer_value(ctx, ctx.local_position(state.position));
+                if let Some(thumb) = self.thumb_near(value) {
+                    self.start_drag(ctx, thumb);
+                    self.move_thumb(ctx, thumb, value);
+                }
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) if ctx.is_active() => {
+                let value = self.pointer_value(ctx, ctx.local_position(current.position));
+                let thumb = match self.dragged {
+                    Some(thumb) => thumb,
+                    // The press was on both thumbs: pick one once the pointer moves away.
+                    None => {
+                        let Some(thumb) = self.thumb_near(value) else {
+                            return;
+                        };
+                        self.start_drag(ctx, thumb);
+                        thumb
+                    }
+                };
+                self.move_thumb(ctx, thumb, value);
+            }
+            PointerEvent::Up(..) | PointerEvent::Cancel(..) => self.end_drag(ctx),
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if ctx.is_disabled() {
+            return;
+        }
+        let Some(thumb) = self.thumb_for_target(ctx.target()) else {
+            return;
+        };
+        let TextEvent::Keyboard(event) = event else {
+            return;
+        };
+        if event.state.is_up() {
+            return;
+        }
+        let (value, _) = self.thumb_value(thumb);
+        let step = self.key_step() * if event.modifiers.shift() { 10. } else { 1. };
+        let value = match &event.key {
+            Key::Named(NamedKey::ArrowLeft | NamedKey::ArrowDown) => value - step,
+            Key::Named(NamedKey::ArrowRight | NamedKey::ArrowUp) => value + step,
+            Key::Named(NamedKey::Home) => self.min,
+            Key::Named(NamedKey::End) => self.max,
+            _ => return,
+        };
+        self.move_thumb(ctx, thumb, value);
+        ctx.set_handled();
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if ctx.is_disabled() {
+            return;
+        }
+        let Some(thumb) = self.thumb_for_target(ctx.target()) else {
+            return;
+        };
+        let (value, _) = self.thumb_value(thumb);
+        let value = match (event.action, &event.data) {
+            (accesskit::Action::Increment, _) => value + self.key_step(),
+            (accesskit::Action::Decrement, _) => value - self.key_step(),
+            (accesskit::Action::SetValue, Some(ActionData::NumericValue(value))) => *value,
+            (accesskit::Action::SetValue, Some(ActionData::Value(text))) => {
+                let Ok(value) = text.parse() else {
+                    return;
+                };
+                value
+            }
+            _ => return,
+        };
+        self.move_thumb(ctx, thumb, value);
+        ctx.set_handled();
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::ChildFocusChanged(_) | Update::HoveredChanged(_) => {
+                ctx.request_paint_only();
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.start_thumb);
+        ctx.register_child(&mut self.end_thumb);
+        for label in self.tick_labels.pods_mut() {
+            ctx.register_child(label);
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        TrackThickness::prop_changed(ctx, property_type);
+        if TrackColor::matches(property_type) {
+            ctx.request_paint_only();
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        _cross_length: Option<Length>,
+    ) -> Length {
+        if axis == self.direction {
+            return match len_req {
+                LenReq::MinContent | LenReq::MaxContent => Length::const_px(TRACK_LENGTH),
+                LenReq::FitContent(space) => space,
+            };
+        }
+        let thumb = ctx.compute_length(
+            &mut self.start_thumb,
+            LenDef::MaxContent,
+            LayoutSize::NONE,
+            axis,
+            None,
+        );
+        let band = thumb.saturating_add(theme::WIDGET_CONTROL_COMPONENT_PADDING);
+        Length::px(band.get() + self.tick_labels.measure_extent(ctx, self.direction))
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let thumb_size = ctx.compute_size(&mut self.start_thumb, SizeDef::MAX, size.into());
+        self.thumb_size = thumb_size.width.max(thumb_size.height);
+        let track = self.track(size.get_coord(self.direction));
+        let band = self.band();
+        let side = self.thumb_size;
+
+        for thumb in [Thumb::Start, Thumb::End] {
+            let (value, _) = self.thumb_value(thumb);
+            let main = track.position(value) - side / 2.;
+            let origin = self.direction.pack_point(main, (band - side) / 2.);
+            let pod = self.thumb_pod(thumb);
+            ctx.run_layout(pod, Size::new(side, side));
+            ctx.place_child(pod, origin);
+        }
+
+        self.tick_labels
+            .layout(ctx, self.direction, size, band, |value| {
+                track.position(value)
+            });
+    }
+
+    fn paint(
+        &mut self,
+        ctx: &mut PaintCtx<'_>,
+        props: &PropertiesRef<'_>,
+        painter: &mut Painter<'_>,
+    ) {
+        let cache = ctx.property_cache();
+        let track_color = props.get::<TrackColor>(cache);
+        let thickness = props.get::<TrackThickness>(cache).0.get();
+
+        let length = ctx.content_box().size().get_coord(self.direction);
+        let track = self.track(length);
+        let center = self.band() / 2.;
+        let bar = |from: f64, to: f64| {
+            let p0 = self.direction.pack_point(from, center - thickness / 2.);
+            let p1 = self.direction.pack_point(to, center + thickness / 2.);
+            Rect::from_points(p0, p1).to_rounded_rect(thickness / 2.)
+        };
+        painter.fill(bar(0., length), track_color.inactive).draw();
+        let start = track.position(self.start);
+        let end = track.position(self.end);
+        painter.fill(bar(start, end), track_color.active).draw();
+
+        paint_ticks(
+            painter,
+            self.direction,
+            self.ticks.iter().map(|tick| track.position(tick.value)),
+            center + thickness / 2.,
+            (start, end),
+            (track_color.active, track_color.inactive),
+        );
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Group
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_value(format!(
+            "{} to {}",
+            format_value(self.start),
+            format_value(self.end)
+        ));
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        [self.start_thumb.id(), self.end_thumb.id()]
+            .into_iter()
+            .chain(self.tick_labels.pods().map(WidgetPod::id))
+            .collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("RangeSlider", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use accesskit::{ActionRequest, TreeId};
+
+    use super::*;
+    use crate::core::keyboard::Modifiers;
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+
+    fn thumb_ids(harness: &TestHarness<RangeSlider>) -> (WidgetId, WidgetId) {
+        let slider = harness.root_widget();
+        (
+            slider.inner().start_thumb.id(),
+            slider.inner().end_thumb.id(),
+        )
+    }
+
+    fn thumb_center(harness: &TestHarness<RangeSlider>, id: WidgetId) -> Point {
+        let thumb = harness.get_widget_with_id(id);
+        thumb
+            .ctx()
+            .window_transform()
+            .transform_rect_bbox(thumb.ctx().border_box())
+            .center()
+    }
+
+    fn press(harness: &mut TestHarness<RangeSlider>, key: NamedKey, modifiers: Modifiers) {
+        let mut event = TextEvent::key_down(Key::Named(key));
+        if let TextEvent::Keyboard(event) = &mut event {
+            event.modifiers = modifiers;
+        }
+        harness.process_text_event(event);
+        harness.process_text_event(TextEvent::key_up(Key::Named(key)));
+    }
+
+    fn moved(harness: &mut TestHarness<RangeSlider>) -> Option<(f64, f64)> {
+        let (action, _) = harness.pop_action::<RangeSliderMoved>()?;
+        Some((action.start, action.end))
+    }
+
+    fn tooltip(harness: &mut TestHarness<RangeSlider>) -> Option<WidgetId> {
+        harness.edit_root_widget(|slider| slider.ctx.get_attached_layer::<Tooltip>())
+    }
+
+    #[test]
+    fn range_with_labeled_ticks() {
+        let slider = RangeSlider::new(0., 100., 20., 60.)
+            .with_step(20.)
+            .with_ticks(SliderTicks::Steps { labeled: true });
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(slider), (200, 48));
+
+        assert_render_snapshot!(harness, "range_slider_with_labeled_ticks");
+    }
+
+    #[test]
+    fn keyboard_thumbs_cannot_cross() {
+        let slider = RangeSlider::new(0., 100., 20., 30.).with_step(10.);
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(slider), (200, 40));
+        let (start_id, end_id) = thumb_ids(&harness);
+
+        harness.focus_on(Some(start_id));
+        press(&mut harness, NamedKey::ArrowRight, Modifiers::empty());
+        assert_eq!(moved(&mut harness), Some((30., 30.)));
+        press(&mut harness, NamedKey::ArrowRight, Modifiers::empty());
+        assert_eq!(moved(&mut harness), None);
+        press(&mut harness, NamedKey::ArrowLeft, Modifiers::SHIFT);
+        assert_eq!(moved(&mut harness), Some((0., 30.)));
+
+        harness.focus_on(Some(end_id));
+        press(&mut harness, NamedKey::Home, Modifiers::empty());
+        assert_eq!(moved(&mut harness), Some((0., 0.)));
+        press(&mut harness, NamedKey::ArrowDown, Modifiers::empty());
+        assert_eq!(moved(&mut harness), None);
+        press(&mut harness, NamedKey::End, Modifiers::empty());
+        assert_eq!(moved(&mut harness), Some((0., 100.)));
+        assert_eq!(harness.root_widget().inner().start(), 0.);
+        assert_eq!(harness.root_widget().inner().end(), 100.);
+    }
+
+    #[test]
+    fn drag_nearest_thumb() {
+        let slider = RangeSlider::new(0., 100., 20., 80.).with_step(1.);
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(slider), (200, 40));
+        let (start_id, end_id) = thumb_ids(&harness);
+        let start_center = thumb_center(&harness, start_id);
+        let end_center = thumb_center(&harness, end_id);
+
+        // Pressing a thumb doesn't move it, but shows its value.
+        harness.mouse_move(end_center);
+        harness.mouse_button_press(None);
+        assert_eq!(moved(&mut harness), None);
+        assert_eq!(harness.focused_widget_id(), Some(end_id));
+        assert!(tooltip(&mut harness).is_some());
+
+        // The end thumb stops at the start thumb.
+        harness.mouse_move(start_center - Vec2::new(20., 0.));
+        assert_eq!(moved(&mut harness), Some((20., 20.)));
+        harness.mouse_button_release(None);
+        assert!(tooltip(&mut harness).is_none());
+
+        // When the thumbs overlap, the direction of the drag picks the moved thumb.
+        harness.mouse_move(start_center);
+        harness.mouse_button_press(None);
+        assert_eq!(moved(&mut harness), None);
+        assert!(tooltip(&mut harness).is_none());
+        harness.mouse_move(end_center);
+        assert_eq!(moved(&mut harness), Some((20., 80.)));
+        assert_eq!(harness.focused_widget_id(), Some(end_id));
+        assert!(tooltip(&mut harness).is_some());
+        harness.mouse_button_release(None);
+
+        // Pressing the track moves the nearest thumb there.
+        harness.mouse_move(Point::new(start_center.x - 20., start_center.y));
+        harness.mouse_button_press(None);
+        let (start, end) = moved(&mut harness).unwrap();
+        assert!(start < 20.);
+        assert_eq!(end, 80.);
+        harness.mouse_button_release(None);
+    }
+
+    #[test]
+    fn thumb_accessibility() {
+        let slider = RangeSlider::new(0., 100., 20., 80.).with_step(5.);
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(slider), (200, 40));
+        let (start_id, end_id) = thumb_ids(&harness);
+
+        let end_node = harness.access_node(end_id).unwrap();
+        assert_eq!(end_node.numeric_value(), Some(80.));
+        assert_eq!(end_node.min_numeric_value(), Some(20.));
+        assert_eq!(end_node.max_numeric_value(), Some(100.));
+
+        harness.process_access_event(ActionRequest {
+            action: accesskit::Action::SetValue,
+            target_tree: TreeId::ROOT,
+            target_node: end_id.into(),
+            data: Some(ActionData::NumericValue(10.)),
+        });
+        assert_eq!(moved(&mut harness), Some((20., 20.)));
+        harness.process_access_event(ActionRequest {
+            action: accesskit::Action::Decrement,
+            target_tree: TreeId::ROOT,
+            target_node: start_id.into(),
+            data: None,
+        });
+        assert_eq!(moved(&mut harness), Some((15., 20.)));
+
+        harness.redraw();
+        let end_node = harness.access_node(end_id).unwrap();
+        assert_eq!(end_node.numeric_value(), Some(20.));
+        assert_eq!(end_node.min_numeric_value(), Some(15.));
+        let start_node = harness.access_node(start_id).unwrap();
+        assert_eq!(start_node.max_numeric_value(), Some(20.));
+    }
+
+    #[test]
+    fn vertical() {
+        let slider = RangeSlider::new(0., 10., 2., 8.)
+            .with_step(1.)
+            .with_direction(Axis::Vertical);
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(slider), (40, 200));
+        let (start_id, end_id) = thumb_ids(&harness);
+
+        // Values increase upwards.
+        let start_center = thumb_center(&harness, start_id);
+        let end_center = thumb_center(&harness, end_id);
+        assert!(start_center.y > end_center.y);
+
+        harness.focus_on(Some(start_id));
+        press(&mut harness, NamedKey::ArrowUp, Modifiers::empty());
+        assert_eq!(moved(&mut harness), Some((3., 8.)));
+
+        harness.mouse_move(end_center);
+        harness.mouse_button_press(None);
+        harness.mouse_move(Point::new(end_center.x, 0.));
+        assert_eq!(moved(&mut harness), Some((3., 10.)));
+        harness.mouse_button_release(None);
+    }
+
+    #[test]
+    fn tick_labels() {
+        let slider = RangeSlider::new(0., 100., 25., 75.)
+            .with_step(25.)
+            .with_ticks(SliderTicks::Steps { labeled: true });
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(slider), (200, 60));
+        assert_eq!(harness.root_widget().children().len(), 7);
+
+        harness.edit_root_widget(|mut slider| {
+            RangeSlider::set_step(&mut slider, Some(50.));
+        });
+        assert_eq!(harness.root_widget().children().len(), 5);
+
+        harness.edit_root_widget(|mut slider| {
+            RangeSlider::set_ticks(
+                &mut slider,
+                SliderTicks::Custom(vec![SliderTick::labeled(0., "Free"), SliderTick::new(50.)]),
+            );
+        });
+        assert_eq!(harness.root_widget().children().len(), 3);
+        let label = harness.root_widget().children()[2]
+            .downcast::<Label>()
+            .unwrap();
+        assert_eq!(label.inner().text().as_ref(), "Free");
+
+        harness.edit_root_widget(|mut slider| {
+            RangeSlider::set_ticks(&mut slider, SliderTicks::None);
+        });
+        assert_eq!(harness.root_widget().children().len(), 2);
+    }
+}
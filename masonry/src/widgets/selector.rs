@@ -11,9 +11,9 @@ use crate::core::{
     TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
 };
 use crate::imaging::Painter;
-use crate::kurbo::{Axis, Size, Vec2};
+use crate::kurbo::{Axis, Size};
 use crate::layers::SelectorMenu;
-use crate::layout::{AsUnit, LayoutSize, LenReq, Length, SizeDef};
+use crate::layout::{Anchor, AsUnit, LayoutSize, LenReq, Length, SizeDef};
 use crate::theme;
 use crate::util::debug_panic;
 use crate::widgets::{Label, SelectorItem};
@@ -140,12 +140,7 @@ impl Selector {
         let layer_widget = NewWidget::new(menu);
 
         // TODO: We should ideally create a layer with the same transform as this widget.
-        let border_box = ctx.border_box();
-        ctx.create_layer(
-            layer_type,
-            layer_widget,
-            ctx.to_window(border_box.origin()) + Vec2::new(0., border_box.size().height),
-        );
+        ctx.create_anchored_layer(layer_type, layer_widget, Anchor::below(ctx.widget_id()));
     }
 }
 
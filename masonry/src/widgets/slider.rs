@@ -13,7 +13,7 @@ use crate::core::{
     AccessCtx, AccessEvent, ChildrenIds, EventCtx, LayoutCtx, MeasureCtx, PaintCtx,
     PointerButtonEvent, PointerEvent, PointerUpdate, PrePaintProps, PropertiesMut, PropertiesRef,
     Property, RegisterCtx, TextEvent, Update, UpdateCtx, UsesProperty, Widget, WidgetId, WidgetMut,
-    paint_background, paint_box_shadow,
+    WidgetPod, paint_background, paint_box_shadow,
 };
 use crate::imaging::{Composite, GroupRef, Painter};
 use crate::kurbo::{Axis, Circle, Rect, Size, Stroke};
@@ -21,6 +21,7 @@ use crate::layout::{LenReq, Length};
 use crate::peniko;
 use crate::properties::{ThumbColor, ThumbRadius, TrackColor, TrackThickness};
 use crate::theme;
+use crate::widgets::{SliderTick, SliderTicks, TickLabels, paint_ticks};
 
 /// A widget that allows a user to select a value from a continuous range.
 ///
@@ -35,6 +36,9 @@ pub struct Slider {
     max: f64,
     value: f64,
     step: Option<f64>,
+    tick_config: SliderTicks,
+    ticks: Vec<SliderTick>,
+    tick_labels: TickLabels,
 }
 
 // --- MARK: BUILDERS
@@ -46,12 +50,25 @@ impl Slider {
             max,
             value: value.clamp(min, max),
             step: None,
+            tick_config: SliderTicks::None,
+            ticks: Vec::new(),
+            tick_labels: TickLabels::default(),
         }
     }
 
     /// Configures the stepping interval of the slider.
     pub fn with_step(mut self, step: f64) -> Self {
         self.set_step_internal(Some(step));
+        self.ticks = self.resolve_ticks();
+        self.tick_labels = TickLabels::new(&self.ticks);
+        self
+    }
+
+    /// Configures the tick marks shown along the slider.
+    pub fn with_ticks(mut self, ticks: SliderTicks) -> Self {
+        self.tick_config = ticks;
+        self.ticks = self.resolve_ticks();
+        self.tick_labels = TickLabels::new(&self.ticks);
         self
     }
 }
@@ -68,6 +85,16 @@ impl Slider {
         };
     }
 
+    fn resolve_ticks(&self) -> Vec<SliderTick> {
+        self.tick_config.resolve(self.min, self.max, self.step)
+    }
+
+    /// Returns the position of the center of the thumb at `value`, along a track of `width`.
+    fn thumb_position(&self, value: f64, width: f64, thumb_radius: f64) -> f64 {
+        let progress = (value - self.min) / (self.max - self.min).max(0.);
+        thumb_radius + progress * (width - thumb_radius * 2.).max(0.)
+    }
+
     fn update_value_from_position(&mut self, x: f64, width: f64) -> bool {
         let progress = (x / width).clamp(0.0, 1.0);
         let new_value = self.min + progress * (self.max - self.min);
@@ -107,16 +134,36 @@ impl Slider {
         let filtered_step = step.filter(|s| *s > 0.0);
         if this.widget.step != filtered_step {
             this.widget.set_step_internal(filtered_step);
+            Self::update_ticks(this);
             this.ctx.request_render();
         }
     }
 
+    /// Sets the tick marks shown along the slider.
+    pub fn set_ticks(this: &mut WidgetMut<'_, Self>, ticks: SliderTicks) {
+        if this.widget.tick_config != ticks {
+            this.widget.tick_config = ticks;
+            Self::update_ticks(this);
+            this.ctx.request_render();
+        }
+    }
+
+    fn update_ticks(this: &mut WidgetMut<'_, Self>) {
+        let ticks = this.widget.resolve_ticks();
+        if this.widget.ticks != ticks {
+            this.widget.tick_labels.replace(&mut this.ctx, &ticks);
+            this.widget.ticks = ticks;
+        }
+    }
+
     /// Sets the range (min and max) of the slider.
     pub fn set_range(this: &mut WidgetMut<'_, Self>, min: f64, max: f64) {
         if this.widget.min != min || this.widget.max != max {
             this.widget.min = min;
             this.widget.max = max;
             Self::set_value(this, this.widget.value);
+            Self::update_ticks(this);
+            this.ctx.request_render();
         }
     }
 }
@@ -291,7 +338,11 @@ impl Widget for Slider {
         }
     }
 
-    fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for label in self.tick_labels.pods_mut() {
+            ctx.register_child(label);
+        }
+    }
 
     fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
         TrackThickness::prop_changed(ctx, property_type);
@@ -325,14 +376,27 @@ impl Widget for Slider {
                 let track_length = track_thickness.0;
                 let padding_length = theme::WIDGET_CONTROL_COMPONENT_PADDING;
 
-                thumb_length
+                let band = thumb_length
                     .max(track_length)
-                    .saturating_add(padding_length)
+                    .saturating_add(padding_length);
+                Length::px(band.get() + self.tick_labels.measure_extent(ctx, Axis::Horizontal))
             }
         }
     }
 
-    fn layout(&mut self, _ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, _size: Size) {}
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, props: &PropertiesRef<'_>, size: Size) {
+        let cache = ctx.property_cache();
+        let thumb_radius = props.get::<ThumbRadius>(cache).0.get();
+        let track_thickness = props.get::<TrackThickness>(cache).0.get();
+        let band = (thumb_radius * 2.).max(track_thickness)
+            + theme::WIDGET_CONTROL_COMPONENT_PADDING.get();
+        let width = size.width;
+        self.tick_labels
+            .layout(ctx, Axis::Horizontal, size, band, |value| {
+                let progress = (value - self.min) / (self.max - self.min).max(0.);
+                thumb_radius + progress * (width - thumb_radius * 2.).max(0.)
+            });
+    }
 
     fn pre_paint(
         &mut self,
@@ -388,7 +452,8 @@ impl Widget for Slider {
 
         // Calculate geometry based on state
         let size = ctx.content_box().size();
-        let track_y = (size.height - track_thickness) / 2.0;
+        // The track is centered in the space above the labels of the ticks, if any.
+        let track_y = (size.height - self.tick_labels.extent() - track_thickness) / 2.0;
         let border_box = ctx.border_box();
 
         // TODO: replace with proper disabled colors
@@ -418,9 +483,20 @@ impl Widget for Slider {
         ]);
         painter.fill(track_rect, &gradient).draw();
 
+        let thumb_x = self.thumb_position(self.value, size.width, thumb_radius);
+        paint_ticks(
+            painter,
+            Axis::Horizontal,
+            self.ticks
+                .iter()
+                .map(|tick| self.thumb_position(tick.value, size.width, thumb_radius)),
+            track_y + track_thickness,
+            (0., thumb_x),
+            (track_color.active, track_color.inactive),
+        );
+
         // Paint thumb
-        let thumb_x = thumb_radius + progress * (size.width - thumb_radius * 2.).max(0.);
-        let thumb_y = size.height / 2.;
+        let thumb_y = track_y + track_thickness / 2.;
         let thumb_circle = Circle::new((thumb_x, thumb_y), thumb_radius - thumb_border_width / 2.);
 
         painter.fill(thumb_circle, thumb_color).draw();
@@ -463,7 +539,7 @@ impl Widget for Slider {
     }
 
     fn children_ids(&self) -> ChildrenIds {
-        ChildrenIds::new()
+        self.tick_labels.pods().map(WidgetPod::id).collect()
     }
 
     fn make_trace_span(&self, id: WidgetId) -> Span {
@@ -537,6 +613,23 @@ mod tests {
         assert_render_snapshot!(harness, "slider_keyboard_moved");
     }
 
+    #[test]
+    fn slider_ticks() {
+        let widget = Slider::new(0.0, 100.0, 50.0)
+            .with_step(25.0)
+            .with_ticks(SliderTicks::Steps { labeled: true })
+            .prepare();
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 48));
+        assert_eq!(harness.root_widget().children().len(), 5);
+        assert_render_snapshot!(harness, "slider_labeled_ticks");
+
+        harness.edit_root_widget(|mut slider| Slider::set_step(&mut slider, Some(50.0)));
+        assert_eq!(harness.root_widget().children().len(), 3);
+
+        harness.edit_root_widget(|mut slider| Slider::set_ticks(&mut slider, SliderTicks::None));
+        assert!(harness.root_widget().children().is_empty());
+    }
+
     #[test]
     fn slider_disabled_state() {
         let mut widget = Slider::new(0.0, 100.0, 50.0).prepare();
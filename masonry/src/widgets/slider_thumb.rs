@@ -0,0 +1,207 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Orientation, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ChildrenIds, LayoutCtx, MeasureCtx, NoAction, PaintCtx, PropertiesMut,
+    PropertiesRef, Property, RegisterCtx, Update, UpdateCtx, UsesProperty, Widget, WidgetId,
+    WidgetMut,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Circle, Size, Stroke};
+use crate::layout::{LenReq, Length};
+use crate::properties::{BorderColor, ThumbColor, ThumbRadius, TrackColor};
+use crate::widgets::format_value;
+
+/// One of the thumbs of a [`RangeSlider`].
+///
+/// Each thumb can be focused, and is exposed to accessibility as a slider of its own,
+/// whose bounds are the value of the other thumb.
+/// The pointer and keyboard input of the thumbs are handled by their range slider.
+///
+/// [`RangeSlider`]: super::RangeSlider
+pub struct SliderThumb {
+    label: &'static str,
+    direction: Axis,
+    value: f64,
+    min: f64,
+    max: f64,
+    step: Option<f64>,
+    dragged: bool,
+}
+
+// --- MARK: BUILDERS
+impl SliderThumb {
+    pub(crate) fn new(
+        label: &'static str,
+        value: f64,
+        (min, max): (f64, f64),
+        step: Option<f64>,
+        direction: Axis,
+    ) -> Self {
+        Self {
+            label,
+            direction,
+            value,
+            min,
+            max,
+            step,
+            dragged: false,
+        }
+    }
+
+    /// Returns the value of the thumb.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl SliderThumb {
+    /// Sets the value of the thumb and its bounds, as shown to accessibility.
+    pub(crate) fn set_value(
+        this: &mut WidgetMut<'_, Self>,
+        value: f64,
+        (min, max): (f64, f64),
+        step: Option<f64>,
+        direction: Axis,
+    ) {
+        let widget = &mut *this.widget;
+        if (
+            widget.value,
+            widget.min,
+            widget.max,
+            widget.step,
+            widget.direction,
+        ) != (value, min, max, step, direction)
+        {
+            widget.value = value;
+            widget.min = min;
+            widget.max = max;
+            widget.step = step;
+            widget.direction = direction;
+            this.ctx.request_accessibility_update();
+        }
+    }
+
+    /// Sets whether the thumb is being dragged, which thickens its outline.
+    pub(crate) fn set_dragged(this: &mut WidgetMut<'_, Self>, dragged: bool) {
+        if this.widget.dragged != dragged {
+            this.widget.dragged = dragged;
+            this.ctx.request_paint_only();
+        }
+    }
+}
+
+impl UsesProperty<ThumbRadius> for SliderThumb {}
+impl UsesProperty<ThumbColor> for SliderThumb {}
+impl UsesProperty<TrackColor> for SliderThumb {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for SliderThumb {
+    type Action = NoAction;
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn accepts_pointer_interaction(&self) -> bool {
+        false
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::FocusChanged(_) = event {
+            ctx.request_paint_only();
+        }
+    }
+
+    fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        ThumbRadius::prop_changed(ctx, property_type);
+        ThumbColor::prop_changed(ctx, property_type);
+        if TrackColor::matches(property_type) {
+            ctx.request_paint_only();
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        props: &PropertiesRef<'_>,
+        _axis: Axis,
+        _len_req: LenReq,
+        _cross_length: Option<Length>,
+    ) -> Length {
+        let radius = props.get::<ThumbRadius>(ctx.property_cache()).0;
+        radius.saturating_add(radius)
+    }
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, _size: Size) {}
+
+    fn paint(
+        &mut self,
+        ctx: &mut PaintCtx<'_>,
+        props: &PropertiesRef<'_>,
+        painter: &mut Painter<'_>,
+    ) {
+        let cache = ctx.property_cache();
+        let thumb_color = props.get::<ThumbColor>(cache).0;
+        let track_color = props.get::<TrackColor>(cache);
+        let focus_color = props.get::<BorderColor>(cache).color;
+
+        let size = ctx.content_box().size();
+        let radius = size.width.min(size.height) / 2.;
+        let border_width = if self.dragged { 2.5 } else { 2. };
+        let center = size.to_rect().center();
+        let circle = Circle::new(center, radius - border_width / 2.);
+        painter.fill(circle, thumb_color).draw();
+        painter
+            .stroke(circle, &Stroke::new(border_width), track_color.active)
+            .draw();
+
+        if ctx.is_focus_target() {
+            let ring = Circle::new(center, radius + 2.);
+            painter.stroke(ring, &Stroke::new(2.), focus_color).draw();
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Slider
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_label(self.label);
+        node.set_orientation(match self.direction {
+            Axis::Horizontal => Orientation::Horizontal,
+            Axis::Vertical => Orientation::Vertical,
+        });
+        node.set_value(format_value(self.value));
+        node.set_numeric_value(self.value);
+        node.set_min_numeric_value(self.min);
+        node.set_max_numeric_value(self.max);
+        if let Some(step) = self.step {
+            node.set_numeric_value_step(step);
+        }
+        node.add_action(accesskit::Action::SetValue);
+        node.add_action(accesskit::Action::Increment);
+        node.add_action(accesskit::Action::Decrement);
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::new()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("SliderThumb", id = id.trace())
+    }
+}
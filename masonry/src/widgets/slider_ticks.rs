@@ -0,0 +1,260 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::core::{ArcStr, LayoutCtx, MeasureCtx, MutateCtx, WidgetPod};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Line, Size, Stroke};
+use crate::layout::{LayoutSize, LenDef, SizeDef};
+use crate::peniko::Color;
+use crate::widgets::Label;
+
+/// The most ticks shown by [`SliderTicks::Steps`].
+///
+/// Sliders with smaller steps don't show ticks at their steps.
+const MAX_STEP_TICKS: usize = 100;
+/// The space between the track of a slider and the labels of its ticks.
+const LABEL_GAP: f64 = 4.;
+/// The length of tick marks, past the edge of the track.
+const TICK_LENGTH: f64 = 4.;
+
+/// A tick mark along a [`Slider`] or [`RangeSlider`], with an optional label.
+///
+/// [`Slider`]: super::Slider
+/// [`RangeSlider`]: super::RangeSlider
+#[derive(Clone, Debug, PartialEq)]
+pub struct SliderTick {
+    /// The value at which the tick is shown.
+    pub value: f64,
+    /// The text shown next to the tick, if any.
+    pub label: Option<ArcStr>,
+}
+
+impl SliderTick {
+    /// Creates a tick without a label.
+    pub fn new(value: f64) -> Self {
+        Self { value, label: None }
+    }
+
+    /// Creates a tick with a label.
+    pub fn labeled(value: f64, label: impl Into<ArcStr>) -> Self {
+        Self {
+            value,
+            label: Some(label.into()),
+        }
+    }
+}
+
+/// The tick marks shown along a [`Slider`] or [`RangeSlider`].
+///
+/// [`Slider`]: super::Slider
+/// [`RangeSlider`]: super::RangeSlider
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum SliderTicks {
+    /// No tick marks.
+    #[default]
+    None,
+    /// A tick at each step of the slider, labeled with its value if `labeled` is true.
+    ///
+    /// Nothing is shown if the slider has no step, or more than 100 steps.
+    Steps {
+        /// Whether the ticks are labeled with their value.
+        labeled: bool,
+    },
+    /// Ticks at the given values.
+    Custom(Vec<SliderTick>),
+}
+
+impl SliderTicks {
+    /// Returns the ticks shown for a slider with the given range and step.
+    pub(crate) fn resolve(&self, min: f64, max: f64, step: Option<f64>) -> Vec<SliderTick> {
+        match self {
+            Self::None => Vec::new(),
+            Self::Steps { labeled } => {
+                let Some(step) = step.filter(|step| *step > 0.) else {
+                    return Vec::new();
+                };
+                // The values of sliders are snapped to multiples of the step.
+                let first = (min / step).ceil() * step;
+                let mut ticks = Vec::new();
+                let mut index = 0.;
+                loop {
+                    let value = first + index * step;
+                    if value > max + step * 1e-9 {
+                        return ticks;
+                    }
+                    if ticks.len() == MAX_STEP_TICKS {
+                        return Vec::new();
+                    }
+                    ticks.push(if *labeled {
+                        SliderTick::labeled(value, format_value(value))
+                    } else {
+                        SliderTick::new(value)
+                    });
+                    index += 1.;
+                }
+            }
+            Self::Custom(ticks) => ticks
+                .iter()
+                .filter(|tick| (min..=max).contains(&tick.value))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// Formats a slider value without the noise of floating-point rounding.
+pub(crate) fn format_value(value: f64) -> String {
+    // Adding zero turns negative zero into zero, which isn't shown with a sign.
+    let rounded = (value * 1e6).round() / 1e6 + 0.;
+    rounded.to_string()
+}
+
+/// The labels of the ticks of a slider, as child widgets.
+#[derive(Default)]
+pub(crate) struct TickLabels {
+    labels: Vec<(f64, WidgetPod<Label>)>,
+    /// The length of the labels on the cross axis of the slider, from the last layout.
+    extent: f64,
+}
+
+impl TickLabels {
+    pub(crate) fn new(ticks: &[SliderTick]) -> Self {
+        let labels = ticks
+            .iter()
+            .filter_map(|tick| {
+                let label = tick.label.clone()?;
+                Some((tick.value, WidgetPod::new(Label::new(label))))
+            })
+            .collect();
+        Self { labels, extent: 0. }
+    }
+
+    pub(crate) fn pods_mut(&mut self) -> impl Iterator<Item = &mut WidgetPod<Label>> {
+        self.labels.iter_mut().map(|(_, pod)| pod)
+    }
+
+    /// Replaces the labels with those of `ticks`, removing the previous ones from the tree.
+    pub(crate) fn replace(&mut self, ctx: &mut MutateCtx<'_>, ticks: &[SliderTick]) {
+        for (_, label) in std::mem::replace(self, Self::new(ticks)).labels {
+            ctx.remove_child(label);
+        }
+        ctx.children_changed();
+    }
+
+    pub(crate) fn pods(&self) -> impl Iterator<Item = &WidgetPod<Label>> {
+        self.labels.iter().map(|(_, pod)| pod)
+    }
+
+    /// Returns the space taken by the labels on the cross axis of a slider along `axis`,
+    /// including the gap between them and the track.
+    pub(crate) fn measure_extent(&mut self, ctx: &mut MeasureCtx<'_>, axis: Axis) -> f64 {
+        if self.labels.is_empty() {
+            return 0.;
+        }
+        let cross = axis.cross();
+        let extent = self
+            .labels
+            .iter_mut()
+            .map(|(_, pod)| {
+                ctx.compute_length(pod, LenDef::MaxContent, LayoutSize::NONE, cross, None)
+                    .get()
+            })
+            .fold(0., f64::max);
+        LABEL_GAP + extent
+    }
+
+    /// Lays out the labels after the `band` of the track and thumbs on the cross axis,
+    /// centering each label on the position returned by `position` for its value.
+    pub(crate) fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx<'_>,
+        axis: Axis,
+        size: Size,
+        band: f64,
+        position: impl Fn(f64) -> f64,
+    ) {
+        let length = size.get_coord(axis);
+        let mut extent: f64 = 0.;
+        for (value, pod) in &mut self.labels {
+            let label_size = ctx.compute_size(pod, SizeDef::MAX, LayoutSize::NONE);
+            ctx.run_layout(pod, label_size);
+            let label_length = label_size.get_coord(axis);
+            let main = (position(*value) - label_length / 2.)
+                .min(length - label_length)
+                .max(0.);
+            ctx.place_child(pod, axis.pack_point(main, band + LABEL_GAP));
+            extent = extent.max(label_size.get_coord(axis.cross()));
+        }
+        self.extent = if self.labels.is_empty() {
+            0.
+        } else {
+            LABEL_GAP + extent
+        };
+    }
+
+    /// Returns the space taken by the labels on the cross axis, as of the last layout.
+    pub(crate) fn extent(&self) -> f64 {
+        self.extent
+    }
+}
+
+/// Paints tick marks across the edge of a track.
+///
+/// `track_end` is the position on the cross axis of the edge of the track facing the labels.
+/// Ticks between `active` positions are painted with `active_color`.
+pub(crate) fn paint_ticks(
+    painter: &mut Painter<'_>,
+    axis: Axis,
+    positions: impl Iterator<Item = f64>,
+    track_end: f64,
+    active: (f64, f64),
+    (active_color, inactive_color): (Color, Color),
+) {
+    let stroke = Stroke::new(1.);
+    for main in positions {
+        let line = Line::new(
+            axis.pack_point(main, track_end - TICK_LENGTH * 2.),
+            axis.pack_point(main, track_end + TICK_LENGTH),
+        );
+        let in_range = main >= active.0.min(active.1) && main <= active.0.max(active.1);
+        let color = if in_range {
+            active_color
+        } else {
+            inactive_color
+        };
+        painter.stroke(line, &stroke, color).draw();
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(ticks: &[SliderTick]) -> Vec<f64> {
+        ticks.iter().map(|tick| tick.value).collect()
+    }
+
+    #[test]
+    fn resolve_ticks() {
+        let steps = SliderTicks::Steps { labeled: true };
+        let ticks = steps.resolve(0., 1., Some(0.25));
+        assert_eq!(values(&ticks), [0., 0.25, 0.5, 0.75, 1.]);
+        assert_eq!(ticks[1].label.as_deref(), Some("0.25"));
+        assert_eq!(values(&steps.resolve(5., 22., Some(10.))), [10., 20.]);
+        assert!(steps.resolve(0., 1., None).is_empty());
+        assert!(steps.resolve(0., 1000., Some(1.)).is_empty());
+
+        let custom = SliderTicks::Custom(vec![
+            SliderTick::labeled(0., "Free"),
+            SliderTick::new(50.),
+            SliderTick::new(500.),
+        ]);
+        assert_eq!(values(&custom.resolve(0., 100., None)), [0., 50.]);
+        assert!(SliderTicks::None.resolve(0., 1., Some(0.1)).is_empty());
+
+        assert_eq!(format_value(0.1 + 0.2), "0.3");
+        assert_eq!(format_value(-0.), "0");
+        assert_eq!(format_value(1500.), "1500");
+    }
+}
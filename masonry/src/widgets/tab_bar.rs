@@ -0,0 +1,737 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Orientation, Role};
+
+use tracing::{Span, trace_span};
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::pointer::PointerButton;
+use crate::core::{
+    AccessCtx, AccessEvent, ActionCtx, ChildrenIds, ErasedAction, EventCtx, FromDynWidget,
+    LayoutCtx, MeasureCtx, MutateCtx, NewWidget, PaintCtx, PointerButtonEvent, PointerEvent,
+    PointerScrollEvent, PointerUpdate, PropertiesMut, PropertiesRef, RegisterCtx, TextEvent,
+    Update, UpdateCtx, UsesProperty, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::dpi::{LogicalPosition, PhysicalPosition};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Rect, Size};
+use crate::layout::{LayoutSize, LenDef, LenReq, Length, SizeDef};
+use crate::properties::Gap;
+use crate::widgets::{TabCloseRequested, TabHeader};
+
+/// How far the pointer must move before a pressed tab starts being dragged.
+const DRAG_THRESHOLD: f64 = 4.;
+
+/// A row of [`TabHeader`]s, exactly one of which is selected.
+///
+/// Clicking a tab selects it, and dragging it along the bar asks to move it.
+/// When the tabs don't fit, the bar can be scrolled with the mouse wheel, and always
+/// keeps the selected tab in view.
+///
+/// The bar is a single focus stop, following the [ARIA tablist pattern]:
+/// the arrow keys, Home and End select another tab, and Delete asks to close the selected
+/// tab if it's closable.
+///
+/// Selecting a tab is done by the bar itself.
+/// Closing and moving tabs are only requested through [`TabAction`]s: it's up to the
+/// owner of the bar to apply them with [`remove_tab`](Self::remove_tab) and
+/// [`move_tab`](Self::move_tab), as it likely needs to update its own data too.
+///
+/// See [`Tabs`](super::Tabs) for a tab bar which also shows the content of the selected tab.
+///
+/// [ARIA tablist pattern]: https://www.w3.org/WAI/ARIA/apg/patterns/tabs/
+pub struct TabBar {
+    tabs: Vec<WidgetPod<TabHeader>>,
+    selected: Option<usize>,
+    /// Whether the selected tab should be scrolled into view during the next layout.
+    reveal_selected: bool,
+    scroll_offset: f64,
+    /// The total width of the tabs and the gaps between them.
+    content_width: f64,
+    /// The width of each tab, from the last layout.
+    widths: Vec<f64>,
+    /// The area of each tab, in content-box coordinates, from the last layout.
+    rects: Vec<Rect>,
+    drag: Option<Drag>,
+}
+
+/// A tab being pressed, and maybe dragged.
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    index: usize,
+    start_x: f64,
+    offset: f64,
+    dragging: bool,
+}
+
+/// Something the user did with a [`TabBar`] or [`Tabs`](super::Tabs).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TabAction {
+    /// The tab at this index was selected.
+    Selected(usize),
+    /// The user asked to close the tab at this index.
+    CloseRequested(usize),
+    /// The user dragged the tab at index `from` so that it ends at index `to`.
+    Moved {
+        /// The index of the dragged tab.
+        from: usize,
+        /// The index the tab should have once moved.
+        to: usize,
+    },
+}
+
+// --- MARK: BUILDERS
+impl TabBar {
+    /// Creates an empty tab bar.
+    pub fn new() -> Self {
+        Self {
+            tabs: Vec::new(),
+            selected: None,
+            reveal_selected: false,
+            scroll_offset: 0.,
+            content_width: 0.,
+            widths: Vec::new(),
+            rects: Vec::new(),
+            drag: None,
+        }
+    }
+
+    /// Builder-style method to add a tab.
+    ///
+    /// The first tab added is selected.
+    pub fn with_tab(mut self, tab: NewWidget<TabHeader>) -> Self {
+        self.tabs.push(tab.to_pod());
+        self.selected.get_or_insert(0);
+        self
+    }
+
+    /// Builder-style method to select the tab at `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn with_selected(mut self, index: usize) -> Self {
+        assert!(index < self.tabs.len(), "tab index out of bounds");
+        self.selected = Some(index);
+        self
+    }
+
+    /// Returns the number of tabs.
+    pub fn len(&self) -> usize {
+        self.tabs.len()
+    }
+
+    /// Returns `true` if there are no tabs.
+    pub fn is_empty(&self) -> bool {
+        self.tabs.is_empty()
+    }
+
+    /// Returns the index of the selected tab, or `None` if there are no tabs.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Returns the id of the tab at `index`.
+    pub fn tab_id(&self, index: usize) -> Option<WidgetId> {
+        self.tabs.get(index).map(WidgetPod::id)
+    }
+
+    pub(crate) fn tab_index(&self, id: WidgetId) -> Option<usize> {
+        self.tabs.iter().position(|tab| tab.id() == id)
+    }
+}
+
+impl Default for TabBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl TabBar {
+    /// Adds a tab at the end.
+    ///
+    /// If the bar was empty, the new tab is selected.
+    pub fn add_tab(this: &mut WidgetMut<'_, Self>, tab: NewWidget<TabHeader>) {
+        let index = this.widget.tabs.len();
+        Self::insert_tab(this, index, tab);
+    }
+
+    /// Inserts a tab at `index`, shifting the following tabs.
+    ///
+    /// If the bar was empty, the new tab is selected.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is greater than the number of tabs.
+    pub fn insert_tab(this: &mut WidgetMut<'_, Self>, index: usize, mut tab: NewWidget<TabHeader>) {
+        this.widget.drag = None;
+        this.widget.selected = match this.widget.selected {
+            Some(selected) if selected >= index => Some(selected + 1),
+            Some(selected) => Some(selected),
+            None => {
+                this.widget.reveal_selected = true;
+                Some(index)
+            }
+        };
+        let selected = this.widget.selected == Some(index);
+        TabHeader::init_selected(&mut tab, selected, this.ctx.has_focus_target());
+        this.widget.tabs.insert(index, tab.to_pod());
+        this.ctx.children_changed();
+    }
+
+    /// Removes the tab at `index`.
+    ///
+    /// If it was selected, the next tab is selected instead, or the previous one if it
+    /// was the last.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn remove_tab(this: &mut WidgetMut<'_, Self>, index: usize) {
+        let tab = this.widget.tabs.remove(index);
+        this.ctx.remove_child(tab);
+        this.widget.drag = None;
+        let len = this.widget.tabs.len();
+        this.widget.selected = match this.widget.selected {
+            _ if len == 0 => None,
+            Some(selected) if selected > index => Some(selected - 1),
+            Some(selected) => Some(selected.min(len - 1)),
+            None => None,
+        };
+        this.widget.reveal_selected = true;
+        this.widget.sync_tabs(&mut this.ctx);
+    }
+
+    /// Moves the tab at `from` so that it ends at index `to`.
+    ///
+    /// The selected tab stays selected.
+    ///
+    /// # Panics
+    ///
+    /// If `from` or `to` is out of bounds.
+    pub fn move_tab(this: &mut WidgetMut<'_, Self>, from: usize, to: usize) {
+        let tab = this.widget.tabs.remove(from);
+        this.widget.tabs.insert(to, tab);
+        this.widget.drag = None;
+        this.widget.selected = this.widget.selected.map(|selected| {
+            if selected == from {
+                to
+            } else if from < selected && selected <= to {
+                selected - 1
+            } else if to <= selected && selected < from {
+                selected + 1
+            } else {
+                selected
+            }
+        });
+        this.widget.reveal_selected = true;
+        this.ctx.children_changed();
+    }
+
+    /// Selects the tab at `index`.
+    ///
+    /// This doesn't submit a [`TabAction::Selected`].
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn set_selected(this: &mut WidgetMut<'_, Self>, index: usize) {
+        assert!(index < this.widget.tabs.len(), "tab index out of bounds");
+        if this.widget.selected != Some(index) {
+            this.widget.selected = Some(index);
+            this.widget.reveal_selected = true;
+            this.widget.sync_tabs(&mut this.ctx);
+        }
+    }
+
+    /// Returns a mutable reference to the tab at `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn tab_mut<'t>(
+        this: &'t mut WidgetMut<'_, Self>,
+        index: usize,
+    ) -> WidgetMut<'t, TabHeader> {
+        this.ctx.get_mut(&mut this.widget.tabs[index])
+    }
+}
+
+// --- MARK: INTERNALS
+impl TabBar {
+    /// Selects the tab at `index`, and submits a [`TabAction::Selected`] if it changed.
+    fn select(&mut self, ctx: &mut EventCtx<'_>, index: usize) {
+        if self.selected == Some(index) {
+            return;
+        }
+        self.selected = Some(index);
+        self.reveal_selected = true;
+        self.sync_tabs(ctx);
+        ctx.submit_action::<<Self as Widget>::Action>(TabAction::Selected(index));
+    }
+
+    /// Applies the selection and focus state to the tabs.
+    fn sync_tabs(&mut self, ctx: &mut impl SyncCtx) {
+        let focused = ctx.has_focus_target();
+        for (index, tab) in self.tabs.iter_mut().enumerate() {
+            let selected = self.selected == Some(index);
+            ctx.mutate_child_later(tab, move |mut tab| {
+                TabHeader::set_selected(&mut tab, selected, focused);
+            });
+        }
+        ctx.request_layout();
+    }
+
+    /// Returns the index the dragged tab would be moved to if it was dropped now.
+    fn drop_index(&self, drag: &Drag, gap: f64) -> usize {
+        let mut x = 0.;
+        let mut centers = Vec::with_capacity(self.widths.len());
+        for width in &self.widths {
+            centers.push(x + width * 0.5);
+            x += width + gap;
+        }
+        let Some(&dragged_center) = centers.get(drag.index) else {
+            return drag.index;
+        };
+        let dragged_center = dragged_center + drag.offset;
+        centers
+            .iter()
+            .enumerate()
+            .filter(|&(index, &center)| index != drag.index && center < dragged_center)
+            .count()
+    }
+
+    /// Scrolls the tabs by `delta`, and returns whether the offset changed.
+    fn scroll_by(&mut self, delta: f64, visible_width: f64) -> bool {
+        let max_offset = (self.content_width - visible_width).max(0.);
+        let offset = (self.scroll_offset + delta).clamp(0., max_offset);
+        if offset == self.scroll_offset {
+            return false;
+        }
+        self.scroll_offset = offset;
+        true
+    }
+}
+
+impl UsesProperty<Gap> for TabBar {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for TabBar {
+    type Action = TabAction;
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if ctx.is_disabled() {
+            return;
+        }
+        match *event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: Some(PointerButton::Primary) | None,
+                ref state,
+                ..
+            }) => {
+                let position = ctx.local_position(state.position);
+                let Some(index) = self.rects.iter().position(|rect| rect.contains(position)) else {
+                    return;
+                };
+                ctx.request_focus();
+                ctx.capture_pointer();
+                self.select(ctx, index);
+                self.drag = Some(Drag {
+                    index,
+                    start_x: position.x,
+                    offset: 0.,
+                    dragging: false,
+                });
+                ctx.set_handled();
+            }
+            PointerEvent::Move(PointerUpdate { ref current, .. }) => {
+                if let Some(drag) = &mut self.drag {
+                    drag.offset = ctx.local_position(current.position).x - drag.start_x;
+                    if drag.dragging || drag.offset.abs() > DRAG_THRESHOLD {
+                        drag.dragging = true;
+                        ctx.request_layout();
+                    }
+                }
+            }
+            PointerEvent::Up(PointerButtonEvent { .. }) => {
+                if let Some(drag) = self.drag.take()
+                    && drag.dragging
+                {
+                    let cache = ctx.property_cache();
+                    let gap = props.get::<Gap>(cache).gap.get();
+                    let to = self.drop_index(&drag, gap);
+                    if to != drag.index {
+                        ctx.submit_action::<Self::Action>(TabAction::Moved {
+                            from: drag.index,
+                            to,
+                        });
+                    }
+                    ctx.request_layout();
+                }
+            }
+            PointerEvent::Cancel(_) if self.drag.take().is_some() => {
+                ctx.request_layout();
+            }
+            PointerEvent::Scroll(PointerScrollEvent { delta, .. }) => {
+                let width = ctx.content_box().width();
+                let scale_factor = ctx.scale_factor();
+                let line_px = PhysicalPosition {
+                    x: 120.0 * scale_factor,
+                    y: 120.0 * scale_factor,
+                };
+                let page_px = PhysicalPosition {
+                    x: width * scale_factor,
+                    y: width * scale_factor,
+                };
+                let delta_px = delta.to_pixel_delta(line_px, page_px);
+                let LogicalPosition { x, y } = delta_px.to_logical::<f64>(scale_factor);
+                // Mouse wheels only scroll vertically, so we also use that direction.
+                let delta = if x != 0. { -x } else { -y };
+                if self.scroll_by(delta, width) {
+                    ctx.request_layout();
+                    ctx.set_handled();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if ctx.is_disabled() || !ctx.is_focus_target() {
+            return;
+        }
+        let TextEvent::Keyboard(key_event) = event else {
+            return;
+        };
+        if key_event.state.is_up() {
+            return;
+        }
+        let (Some(selected), len) = (self.selected, self.tabs.len()) else {
+            return;
+        };
+        let index = match &key_event.key {
+            Key::Named(NamedKey::ArrowLeft) => (selected + len - 1) % len,
+            Key::Named(NamedKey::ArrowRight) => (selected + 1) % len,
+            Key::Named(NamedKey::Home) => 0,
+            Key::Named(NamedKey::End) => len - 1,
+            Key::Named(NamedKey::Delete) => {
+                if ctx.get_raw(&mut self.tabs[selected]).0.is_closable() {
+                    ctx.submit_action::<Self::Action>(TabAction::CloseRequested(selected));
+                }
+                ctx.set_handled();
+                return;
+            }
+            _ => return,
+        };
+        self.select(ctx, index);
+        ctx.set_handled();
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if event.action == accesskit::Action::Click
+            && let Some(index) = self.tab_index(ctx.target())
+        {
+            self.select(ctx, index);
+            ctx.set_handled();
+        }
+    }
+
+    fn on_action(
+        &mut self,
+        ctx: &mut ActionCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        action: &ErasedAction,
+        source: WidgetId,
+    ) {
+        if action.is::<TabCloseRequested>()
+            && let Some(index) = self.tab_index(source)
+        {
+            ctx.submit_action::<Self::Action>(TabAction::CloseRequested(index));
+            ctx.set_handled();
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::FocusChanged(_) => {
+                self.sync_tabs(ctx);
+            }
+            Update::WidgetAdded => {
+                self.reveal_selected = true;
+                self.sync_tabs(ctx);
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for tab in &mut self.tabs {
+            ctx.register_child(tab);
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        Gap::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        let cache = ctx.property_cache();
+        let gap = props.get::<Gap>(cache).gap;
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+
+        match axis {
+            Axis::Horizontal => {
+                // The bar can be scrolled, so it only needs to show one tab.
+                let auto_length = match len_req {
+                    LenReq::MinContent => LenDef::MinContent,
+                    LenReq::MaxContent | LenReq::FitContent(_) => LenDef::MaxContent,
+                };
+                let mut widest = Length::ZERO;
+                let mut total = Length::ZERO;
+                for (index, tab) in self.tabs.iter_mut().enumerate() {
+                    let length =
+                        ctx.compute_length(tab, auto_length, context_size, axis, cross_length);
+                    widest = widest.max(length);
+                    if index > 0 {
+                        total = total.saturating_add(gap);
+                    }
+                    total = total.saturating_add(length);
+                }
+                match len_req {
+                    LenReq::MinContent => widest,
+                    LenReq::MaxContent => total,
+                    LenReq::FitContent(space) => total.min(space),
+                }
+            }
+            Axis::Vertical => self.tabs.iter_mut().fold(Length::ZERO, |tallest, tab| {
+                let length =
+                    ctx.compute_length(tab, len_req.into(), context_size, axis, cross_length);
+                tallest.max(length)
+            }),
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, props: &PropertiesRef<'_>, size: Size) {
+        let cache = ctx.property_cache();
+        let gap = props.get::<Gap>(cache).gap.get();
+
+        self.widths.clear();
+        for tab in &mut self.tabs {
+            let auto_size =
+                SizeDef::new(LenDef::MaxContent, LenDef::Fixed(Length::px(size.height)));
+            let tab_size = ctx.compute_size(tab, auto_size, size.into());
+            ctx.run_layout(tab, Size::new(tab_size.width, size.height));
+            self.widths.push(tab_size.width);
+        }
+        self.content_width =
+            self.widths.iter().sum::<f64>() + gap * self.widths.len().saturating_sub(1) as f64;
+
+        let mut order: Vec<usize> = (0..self.tabs.len()).collect();
+        let drag = self.drag.filter(|drag| drag.dragging);
+        if let Some(drag) = &drag {
+            let to = self.drop_index(drag, gap);
+            let index = order.remove(drag.index);
+            order.insert(to, index);
+        }
+        let mut xs = vec![0.; self.tabs.len()];
+        let mut x = 0.;
+        for &index in &order {
+            xs[index] = x;
+            x += self.widths[index] + gap;
+        }
+        if let Some(drag) = &drag {
+            let start = self.widths[..drag.index].iter().sum::<f64>() + gap * drag.index as f64;
+            let max_x = (self.content_width - self.widths[drag.index]).max(0.);
+            xs[drag.index] = (start + drag.offset).clamp(0., max_x);
+        }
+
+        if self.reveal_selected
+            && let Some(selected) = self.selected
+            && let Some(&start) = xs.get(selected)
+        {
+            let end = start + self.widths[selected];
+            if start < self.scroll_offset {
+                self.scroll_offset = start;
+            } else if end > self.scroll_offset + size.width {
+                self.scroll_offset = end - size.width;
+            }
+        }
+        self.reveal_selected = false;
+        self.scroll_offset = self
+            .scroll_offset
+            .clamp(0., (self.content_width - size.width).max(0.));
+
+        self.rects.clear();
+        for (index, tab) in self.tabs.iter_mut().enumerate() {
+            let origin = Point::new(xs[index] - self.scroll_offset, 0.);
+            ctx.place_child(tab, origin);
+            self.rects.push(Rect::from_origin_size(
+                origin,
+                Size::new(self.widths[index], size.height),
+            ));
+        }
+        if let Some(first) = self.tabs.first() {
+            ctx.derive_baselines(first);
+        }
+
+        if self.content_width > size.width {
+            ctx.set_clip_path(size.to_rect());
+        } else {
+            ctx.clear_clip_path();
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::TabList
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_orientation(Orientation::Horizontal);
+        if let Some(selected) = self.selected {
+            node.set_active_descendant(self.tabs[selected].id().into());
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.tabs.iter().map(WidgetPod::id).collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("TabBar", id = id.trace())
+    }
+}
+
+/// Context methods needed to sync tab headers with the widget holding them.
+pub(crate) trait SyncCtx {
+    fn has_focus_target(&self) -> bool;
+    fn request_layout(&mut self);
+    fn mutate_child_later<W: Widget + FromDynWidget + ?Sized>(
+        &mut self,
+        child: &mut WidgetPod<W>,
+        f: impl FnOnce(WidgetMut<'_, W>) + Send + 'static,
+    );
+}
+
+macro_rules! impl_sync_ctx {
+    ($($ctx:ty),*) => {$(
+        impl SyncCtx for $ctx {
+            fn has_focus_target(&self) -> bool {
+                self.has_focus_target()
+            }
+
+            fn request_layout(&mut self) {
+                self.request_layout();
+            }
+
+            fn mutate_child_later<W: Widget + FromDynWidget + ?Sized>(
+                &mut self,
+                child: &mut WidgetPod<W>,
+                f: impl FnOnce(WidgetMut<'_, W>) + Send + 'static,
+            ) {
+                self.mutate_child_later(child, f);
+            }
+        }
+    )*};
+}
+
+impl_sync_ctx!(MutateCtx<'_>, EventCtx<'_>, UpdateCtx<'_>);
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::AsUnit;
+    use crate::properties::Dimensions;
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+
+    #[test]
+    fn tab_bar_with_closable_tab() {
+        let bar = TabBar::new()
+            .with_tab(NewWidget::new(TabHeader::new("Files")))
+            .with_tab(NewWidget::new(TabHeader::new("Search").with_closable(true)))
+            .with_tab(NewWidget::new(TabHeader::new("Settings")))
+            .with_selected(1);
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(bar), (300, 40));
+
+        assert_render_snapshot!(harness, "tab_bar_with_closable_tab");
+    }
+
+    #[test]
+    fn selected_tab_is_scrolled_into_view() {
+        let mut bar = TabBar::new();
+        for index in 0..20 {
+            bar = bar.with_tab(NewWidget::new(TabHeader::new(format!("Tab {index}"))));
+        }
+        let bar = NewWidget::new(bar).with_props(Dimensions::width(200.px()));
+        let mut harness = TestHarness::create(test_property_set(), bar);
+        let last_id = harness.root_widget().inner().tab_id(19).unwrap();
+        let visible = |harness: &TestHarness<TabBar>, id| {
+            let bar = harness.root_widget();
+            let bar = bar
+                .ctx()
+                .window_transform()
+                .transform_rect_bbox(bar.ctx().border_box());
+            let tab = harness.get_widget_with_id(id);
+            let tab = tab
+                .ctx()
+                .window_transform()
+                .transform_rect_bbox(tab.ctx().border_box());
+            bar.contains(tab.origin()) && bar.contains(Point::new(tab.x1 - 1., tab.y1 - 1.))
+        };
+        assert!(!visible(&harness, last_id));
+
+        harness.edit_root_widget(|mut bar| TabBar::set_selected(&mut bar, 19));
+        assert!(visible(&harness, last_id));
+
+        // The bar can also be scrolled with the mouse wheel.
+        let first_id = harness.root_widget().inner().tab_id(0).unwrap();
+        harness.mouse_move((10., 10.));
+        harness.mouse_wheel((0., 10_000.).into());
+        assert!(visible(&harness, first_id));
+    }
+}
@@ -0,0 +1,355 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ArcStr, ChildrenIds, EventCtx, LayoutCtx, MeasureCtx, NewWidget, PaintCtx,
+    PointerButtonEvent, PointerEvent, PointerUpdate, PropertiesMut, PropertiesRef, RegisterCtx,
+    Update, UpdateCtx, UsesProperty, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, BezPath, Cap, Circle, Point, Rect, Size, Stroke};
+use crate::layout::{LayoutSize, LenReq, Length, SizeDef};
+use crate::properties::ContentColor;
+use crate::widgets::Label;
+
+/// The side of the square holding the close button of a [`TabHeader`].
+const CLOSE_SIZE: f64 = 16.;
+/// The space between the icon, the label and the close button of a [`TabHeader`].
+const GAP: f64 = 6.;
+
+/// A tab in a [`TabBar`](super::TabBar), with an optional leading icon and close button.
+///
+/// The selected tab has the `#selected` [class], and also the `#focused` class while
+/// its tab bar has focus.
+///
+/// [class]: masonry_core::doc::masonry_concepts#classes
+pub struct TabHeader {
+    icon: Option<WidgetPod<dyn Widget>>,
+    label: WidgetPod<Label>,
+    closable: bool,
+    selected: bool,
+    focused: bool,
+    close_hovered: bool,
+    close_pressed: bool,
+    /// The area of the close button, in content-box coordinates.
+    close_rect: Rect,
+}
+
+/// Sent by a [`TabHeader`] when its close button is clicked.
+///
+/// [`TabBar`](super::TabBar) turns it into a [`TabAction::CloseRequested`](super::TabAction::CloseRequested).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TabCloseRequested;
+
+// --- MARK: BUILDERS
+impl TabHeader {
+    /// Creates a tab showing `text`, without icon and close button.
+    pub fn new(text: impl Into<ArcStr>) -> Self {
+        Self {
+            icon: None,
+            label: WidgetPod::new(Label::new(text)),
+            closable: false,
+            selected: false,
+            focused: false,
+            close_hovered: false,
+            close_pressed: false,
+            close_rect: Rect::ZERO,
+        }
+    }
+
+    /// Builder-style method to show a widget before the text, usually an image or a glyph.
+    pub fn with_icon(mut self, icon: NewWidget<impl Widget + ?Sized>) -> Self {
+        self.icon = Some(icon.erased().to_pod());
+        self
+    }
+
+    /// Builder-style method to show a close button after the text.
+    pub fn with_closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Returns whether the tab has a close button.
+    pub fn is_closable(&self) -> bool {
+        self.closable
+    }
+
+    /// Returns whether the tab is the selected tab of its tab bar.
+    pub fn is_selected(&self) -> bool {
+        self.selected
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl TabHeader {
+    /// Sets the text of the tab.
+    pub fn set_text(this: &mut WidgetMut<'_, Self>, text: impl Into<ArcStr>) {
+        Label::set_text(&mut Self::label_mut(this), text);
+    }
+
+    /// Sets the widget shown before the text.
+    pub fn set_icon(this: &mut WidgetMut<'_, Self>, icon: NewWidget<impl Widget + ?Sized>) {
+        Self::remove_icon(this);
+        this.widget.icon = Some(icon.erased().to_pod());
+        this.ctx.children_changed();
+    }
+
+    /// Removes the widget shown before the text.
+    pub fn remove_icon(this: &mut WidgetMut<'_, Self>) {
+        if let Some(icon) = this.widget.icon.take() {
+            this.ctx.remove_child(icon);
+        }
+    }
+
+    /// Sets whether the tab has a close button.
+    pub fn set_closable(this: &mut WidgetMut<'_, Self>, closable: bool) {
+        if this.widget.closable != closable {
+            this.widget.closable = closable;
+            this.widget.close_hovered = false;
+            this.widget.close_pressed = false;
+            this.ctx.request_layout();
+        }
+    }
+
+    /// Returns a mutable reference to the label.
+    pub fn label_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, Label> {
+        this.ctx.get_mut(&mut this.widget.label)
+    }
+
+    /// Returns a mutable reference to the icon, if any.
+    pub fn icon_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> Option<WidgetMut<'t, dyn Widget>> {
+        this.widget.icon.as_mut().map(|icon| this.ctx.get_mut(icon))
+    }
+
+    /// Like [`set_selected`](Self::set_selected), for a tab which isn't in the tree yet.
+    pub(crate) fn init_selected(tab: &mut NewWidget<Self>, selected: bool, focused: bool) {
+        tab.widget.selected = selected;
+        tab.widget.focused = selected && focused;
+        if selected {
+            tab.classes.insert("#selected".into());
+        }
+        if tab.widget.focused {
+            tab.classes.insert("#focused".into());
+        }
+    }
+
+    /// Sets whether this is the selected tab, and whether its tab bar has focus.
+    pub(crate) fn set_selected(this: &mut WidgetMut<'_, Self>, selected: bool, focused: bool) {
+        let focused = selected && focused;
+        if this.widget.selected != selected {
+            this.widget.selected = selected;
+            if selected {
+                this.ctx.add_class("#selected");
+            } else {
+                this.ctx.remove_class("#selected");
+            }
+            this.ctx.request_accessibility_update();
+        }
+        if this.widget.focused != focused {
+            this.widget.focused = focused;
+            if focused {
+                this.ctx.add_class("#focused");
+            } else {
+                this.ctx.remove_class("#focused");
+            }
+        }
+    }
+}
+
+impl UsesProperty<ContentColor> for TabHeader {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for TabHeader {
+    type Action = TabCloseRequested;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if !self.closable || ctx.is_disabled() {
+            return;
+        }
+        match event {
+            PointerEvent::Down(PointerButtonEvent { state, .. })
+                if self.close_rect.contains(ctx.local_position(state.position)) =>
+            {
+                self.close_pressed = true;
+                ctx.capture_pointer();
+                ctx.request_paint_only();
+                // The tab bar would select the tab or start dragging it otherwise.
+                ctx.set_handled();
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) => {
+                let hovered = self
+                    .close_rect
+                    .contains(ctx.local_position(current.position));
+                if hovered != self.close_hovered {
+                    self.close_hovered = hovered;
+                    ctx.request_paint_only();
+                }
+                if self.close_pressed {
+                    ctx.set_handled();
+                }
+            }
+            PointerEvent::Up(PointerButtonEvent { state, .. }) if self.close_pressed => {
+                self.close_pressed = false;
+                if self.close_rect.contains(ctx.local_position(state.position)) {
+                    ctx.submit_action::<Self::Action>(TabCloseRequested);
+                }
+                ctx.request_paint_only();
+                ctx.set_handled();
+            }
+            PointerEvent::Leave(_) | PointerEvent::Cancel(_) => {
+                self.close_hovered = false;
+                self.close_pressed = false;
+                ctx.request_paint_only();
+            }
+            _ => {}
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::HoveredChanged(false) = event {
+            self.close_hovered = false;
+            ctx.request_paint_only();
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        if let Some(icon) = &mut self.icon {
+            ctx.register_child(icon);
+        }
+        ctx.register_child(&mut self.label);
+    }
+
+    fn property_changed(&mut self, _ctx: &mut UpdateCtx<'_>, _property_type: TypeId) {}
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        let auto_length = len_req.into();
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+
+        let mut lengths = Vec::with_capacity(3);
+        if let Some(icon) = &mut self.icon {
+            lengths.push(ctx.compute_length(icon, auto_length, context_size, axis, None));
+        }
+        lengths.push(ctx.compute_length(&mut self.label, auto_length, context_size, axis, None));
+        if self.closable {
+            lengths.push(Length::px(CLOSE_SIZE));
+        }
+
+        match axis {
+            Axis::Horizontal => {
+                let gaps = Length::px(GAP * (lengths.len() - 1) as f64);
+                lengths
+                    .into_iter()
+                    .fold(gaps, |total, length| total.saturating_add(length))
+            }
+            Axis::Vertical => lengths.into_iter().fold(Length::ZERO, Length::max),
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let mut x = 0.;
+        if let Some(icon) = &mut self.icon {
+            let icon_size = ctx.compute_size(icon, SizeDef::fit(size), size.into());
+            ctx.run_layout(icon, icon_size);
+            ctx.place_child(icon, Point::new(x, (size.height - icon_size.height) * 0.5));
+            x += icon_size.width + GAP;
+        }
+
+        let close_width = if self.closable { CLOSE_SIZE + GAP } else { 0. };
+        let label_space = Size::new((size.width - x - close_width).max(0.), size.height);
+        let label_size = ctx.compute_size(
+            &mut self.label,
+            SizeDef::fit(label_space),
+            label_space.into(),
+        );
+        ctx.run_layout(&mut self.label, label_size);
+        ctx.place_child(
+            &mut self.label,
+            Point::new(x, (size.height - label_size.height) * 0.5),
+        );
+        ctx.derive_baselines(&self.label);
+
+        self.close_rect = if self.closable {
+            Rect::from_origin_size(
+                Point::new(size.width - CLOSE_SIZE, (size.height - CLOSE_SIZE) * 0.5),
+                Size::new(CLOSE_SIZE, CLOSE_SIZE),
+            )
+        } else {
+            Rect::ZERO
+        };
+    }
+
+    fn paint(
+        &mut self,
+        ctx: &mut PaintCtx<'_>,
+        props: &PropertiesRef<'_>,
+        painter: &mut Painter<'_>,
+    ) {
+        if !self.closable {
+            return;
+        }
+        let cache = ctx.property_cache();
+        let color = props.get::<ContentColor>(cache).color;
+
+        let center = self.close_rect.center();
+        if self.close_hovered {
+            let alpha = if self.close_pressed { 0.35 } else { 0.2 };
+            painter
+                .fill(
+                    Circle::new(center, CLOSE_SIZE * 0.5),
+                    color.multiply_alpha(alpha),
+                )
+                .draw();
+        }
+
+        let arm = CLOSE_SIZE * 0.2;
+        let mut cross = BezPath::new();
+        cross.move_to(center + (-arm, -arm));
+        cross.line_to(center + (arm, arm));
+        cross.move_to(center + (arm, -arm));
+        cross.line_to(center + (-arm, arm));
+        let stroke = Stroke::new(1.5).with_caps(Cap::Round);
+        painter.stroke(cross, &stroke, color).draw();
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Tab
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_selected(self.selected);
+        node.add_action(accesskit::Action::Click);
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        match &self.icon {
+            Some(icon) => ChildrenIds::from_slice(&[icon.id(), self.label.id()]),
+            None => ChildrenIds::from_slice(&[self.label.id()]),
+        }
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("TabHeader", id = id.trace())
+    }
+}
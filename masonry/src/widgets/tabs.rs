@@ -0,0 +1,689 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ActionCtx, ChildrenIds, ErasedAction, LayoutCtx, MeasureCtx, NewWidget, PaintCtx,
+    PropertiesMut, PropertiesRef, RegisterCtx, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::{LayoutSize, LenDef, LenReq, Length, SizeDef};
+use crate::widgets::{TabAction, TabBar, TabHeader};
+
+/// Builds the content of a tab, the first time it's selected.
+pub type TabContentFn = Box<dyn FnOnce() -> NewWidget<dyn Widget>>;
+
+/// A [`TabBar`] showing the content of the selected tab under it.
+///
+/// The content of the other tabs is kept but [stashed], so it keeps its state.
+/// Content can also be created lazily: either with a function called when the tab is
+/// first selected, or by leaving the tab empty and calling
+/// [`set_content`](Self::set_content) when a [`TabAction::Selected`] is received.
+///
+/// Like [`TabBar`], this only requests closing and moving tabs: apply the
+/// [`TabAction`]s with [`remove_tab`](Self::remove_tab) and [`move_tab`](Self::move_tab).
+///
+/// [stashed]: masonry_core::doc::masonry_concepts#stashed
+pub struct Tabs {
+    bar: Bar,
+    contents: Vec<TabContent>,
+    /// The selected tab, kept in sync with the bar.
+    selected: Option<usize>,
+}
+
+/// The tab bar, which stays a [`NewWidget`] until the widget is added to the tree
+/// so that the builder methods can add tabs to it.
+enum Bar {
+    New(NewWidget<TabBar>),
+    Pod(WidgetPod<TabBar>),
+}
+
+impl Bar {
+    fn id(&self) -> WidgetId {
+        match self {
+            Self::New(bar) => bar.id(),
+            Self::Pod(bar) => bar.id(),
+        }
+    }
+
+    fn pod_mut(&mut self) -> &mut WidgetPod<TabBar> {
+        match self {
+            Self::Pod(bar) => bar,
+            Self::New(_) => unreachable!("the tab bar is registered when the widget is added"),
+        }
+    }
+}
+
+enum TabContent {
+    Empty,
+    Lazy(TabContentFn),
+    Built(WidgetPod<dyn Widget>),
+}
+
+impl TabContent {
+    fn pod_mut(&mut self) -> Option<&mut WidgetPod<dyn Widget>> {
+        match self {
+            Self::Built(pod) => Some(pod),
+            Self::Empty | Self::Lazy(_) => None,
+        }
+    }
+}
+
+// --- MARK: BUILDERS
+impl Tabs {
+    /// Creates a tab view with no tabs.
+    pub fn new() -> Self {
+        Self {
+            bar: Bar::New(NewWidget::new(TabBar::new())),
+            contents: Vec::new(),
+            selected: None,
+        }
+    }
+
+    /// Builder-style method to add a tab.
+    ///
+    /// The first tab added is selected.
+    pub fn with_tab(
+        self,
+        header: NewWidget<TabHeader>,
+        content: NewWidget<impl Widget + ?Sized>,
+    ) -> Self {
+        self.with_content(header, TabContent::Built(content.erased().to_pod()))
+    }
+
+    /// Builder-style method to add a tab whose content is built by `build`
+    /// the first time the tab is selected.
+    pub fn with_lazy_tab(
+        self,
+        header: NewWidget<TabHeader>,
+        build: impl FnOnce() -> NewWidget<dyn Widget> + 'static,
+    ) -> Self {
+        self.with_content(header, TabContent::Lazy(Box::new(build)))
+    }
+
+    /// Builder-style method to add a tab without content.
+    ///
+    /// The content can be added later with [`set_content`](Self::set_content).
+    pub fn with_empty_tab(self, header: NewWidget<TabHeader>) -> Self {
+        self.with_content(header, TabContent::Empty)
+    }
+
+    /// Builder-style method to select the tab at `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn with_selected(mut self, index: usize) -> Self {
+        assert!(index < self.contents.len(), "tab index out of bounds");
+        let Bar::New(bar) = &mut self.bar else {
+            unreachable!()
+        };
+        *bar.widget = std::mem::take(&mut *bar.widget).with_selected(index);
+        self.selected = Some(index);
+        self.build_selected();
+        self
+    }
+
+    fn with_content(mut self, header: NewWidget<TabHeader>, content: TabContent) -> Self {
+        let Bar::New(bar) = &mut self.bar else {
+            unreachable!()
+        };
+        *bar.widget = std::mem::take(&mut *bar.widget).with_tab(header);
+        self.contents.push(content);
+        self.selected.get_or_insert(0);
+        self.build_selected();
+        self
+    }
+
+    /// Returns the number of tabs.
+    pub fn len(&self) -> usize {
+        self.contents.len()
+    }
+
+    /// Returns `true` if there are no tabs.
+    pub fn is_empty(&self) -> bool {
+        self.contents.is_empty()
+    }
+
+    /// Returns the index of the selected tab, or `None` if there are no tabs.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Returns `true` if the content of the tab at `index` exists.
+    ///
+    /// This is `false` for tabs without content and for lazy tabs which weren't
+    /// selected yet.
+    pub fn has_content(&self, index: usize) -> bool {
+        matches!(self.contents.get(index), Some(TabContent::Built(_)))
+    }
+
+    /// Returns the id of the tab bar.
+    pub fn bar_id(&self) -> WidgetId {
+        self.bar.id()
+    }
+}
+
+impl Default for Tabs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Tabs {
+    /// Adds a tab at the end.
+    ///
+    /// If there were no tabs, the new tab is selected.
+    pub fn add_tab(
+        this: &mut WidgetMut<'_, Self>,
+        header: NewWidget<TabHeader>,
+        content: NewWidget<impl Widget + ?Sized>,
+    ) {
+        let index = this.widget.contents.len();
+        Self::insert_tab(this, index, header, content);
+    }
+
+    /// Inserts a tab at `index`, shifting the following tabs.
+    ///
+    /// If there were no tabs, the new tab is selected.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is greater than the number of tabs.
+    pub fn insert_tab(
+        this: &mut WidgetMut<'_, Self>,
+        index: usize,
+        header: NewWidget<TabHeader>,
+        content: NewWidget<impl Widget + ?Sized>,
+    ) {
+        let content = TabContent::Built(content.erased().to_pod());
+        Self::insert_content(this, index, header, content);
+    }
+
+    /// Inserts a tab at `index` whose content is built by `build` the first time
+    /// the tab is selected.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is greater than the number of tabs.
+    pub fn insert_lazy_tab(
+        this: &mut WidgetMut<'_, Self>,
+        index: usize,
+        header: NewWidget<TabHeader>,
+        build: impl FnOnce() -> NewWidget<dyn Widget> + 'static,
+    ) {
+        Self::insert_content(this, index, header, TabContent::Lazy(Box::new(build)));
+    }
+
+    /// Inserts a tab without content at `index`.
+    ///
+    /// The content can be added later with [`set_content`](Self::set_content).
+    ///
+    /// # Panics
+    ///
+    /// If `index` is greater than the number of tabs.
+    pub fn insert_empty_tab(
+        this: &mut WidgetMut<'_, Self>,
+        index: usize,
+        header: NewWidget<TabHeader>,
+    ) {
+        Self::insert_content(this, index, header, TabContent::Empty);
+    }
+
+    fn insert_content(
+        this: &mut WidgetMut<'_, Self>,
+        index: usize,
+        header: NewWidget<TabHeader>,
+        content: TabContent,
+    ) {
+        TabBar::insert_tab(
+            &mut this.ctx.get_mut(this.widget.bar.pod_mut()),
+            index,
+            header,
+        );
+        this.widget.contents.insert(index, content);
+        Self::sync_selected(this);
+    }
+
+    /// Removes the tab at `index`, and its content.
+    ///
+    /// If it was selected, the next tab is selected instead, or the previous one if it
+    /// was the last.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn remove_tab(this: &mut WidgetMut<'_, Self>, index: usize) {
+        TabBar::remove_tab(&mut this.ctx.get_mut(this.widget.bar.pod_mut()), index);
+        if let TabContent::Built(pod) = this.widget.contents.remove(index) {
+            this.ctx.remove_child(pod);
+        }
+        Self::sync_selected(this);
+    }
+
+    /// Moves the tab at `from`, and its content, so that it ends at index `to`.
+    ///
+    /// # Panics
+    ///
+    /// If `from` or `to` is out of bounds.
+    pub fn move_tab(this: &mut WidgetMut<'_, Self>, from: usize, to: usize) {
+        TabBar::move_tab(&mut this.ctx.get_mut(this.widget.bar.pod_mut()), from, to);
+        let content = this.widget.contents.remove(from);
+        this.widget.contents.insert(to, content);
+        let selected = Self::bar_mut(this).widget.selected();
+        this.widget.selected = selected;
+        this.ctx.children_changed();
+    }
+
+    /// Selects the tab at `index`.
+    ///
+    /// This doesn't submit a [`TabAction::Selected`].
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn set_selected(this: &mut WidgetMut<'_, Self>, index: usize) {
+        TabBar::set_selected(&mut this.ctx.get_mut(this.widget.bar.pod_mut()), index);
+        Self::sync_selected(this);
+    }
+
+    /// Replaces the content of the tab at `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn set_content(
+        this: &mut WidgetMut<'_, Self>,
+        index: usize,
+        content: NewWidget<impl Widget + ?Sized>,
+    ) {
+        let content = TabContent::Built(content.erased().to_pod());
+        if let TabContent::Built(old) = std::mem::replace(&mut this.widget.contents[index], content)
+        {
+            this.ctx.remove_child(old);
+        }
+        this.ctx.children_changed();
+    }
+
+    /// Returns a mutable reference to the content of the tab at `index`, if it exists.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn content_mut<'t>(
+        this: &'t mut WidgetMut<'_, Self>,
+        index: usize,
+    ) -> Option<WidgetMut<'t, dyn Widget>> {
+        let pod = this.widget.contents[index].pod_mut()?;
+        Some(this.ctx.get_mut(pod))
+    }
+
+    /// Returns a mutable reference to the tab bar.
+    ///
+    /// Use [`TabBar::tab_mut`] to edit the tabs.
+    pub fn bar_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, TabBar> {
+        this.ctx.get_mut(this.widget.bar.pod_mut())
+    }
+
+    fn sync_selected(this: &mut WidgetMut<'_, Self>) {
+        let selected = Self::bar_mut(this).widget.selected();
+        this.widget.selected = selected;
+        if this.widget.build_selected() {
+            this.ctx.children_changed();
+        } else {
+            this.ctx.request_layout();
+        }
+    }
+}
+
+// --- MARK: INTERNALS
+impl Tabs {
+    /// Builds the content of the selected tab if it's lazy, and returns whether it was.
+    fn build_selected(&mut self) -> bool {
+        let Some(content) = self.selected.and_then(|index| self.contents.get_mut(index)) else {
+            return false;
+        };
+        if !matches!(content, TabContent::Lazy(_)) {
+            return false;
+        }
+        let TabContent::Lazy(build) = std::mem::replace(content, TabContent::Empty) else {
+            unreachable!()
+        };
+        *content = TabContent::Built(build().to_pod());
+        true
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Tabs {
+    type Action = TabAction;
+
+    fn on_action(
+        &mut self,
+        ctx: &mut ActionCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        action: &ErasedAction,
+        source: WidgetId,
+    ) {
+        if source != self.bar.id() {
+            return;
+        }
+        let Some(action) = action.downcast_ref::<TabAction>() else {
+            return;
+        };
+        if let TabAction::Selected(index) = *action {
+            self.selected = Some(index);
+            if self.build_selected() {
+                ctx.children_changed();
+            } else {
+                ctx.request_layout();
+            }
+        }
+        ctx.submit_action::<Self::Action>(*action);
+        ctx.set_handled();
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        if let Bar::New(bar) = &mut self.bar {
+            let bar = std::mem::replace(bar, NewWidget::new(TabBar::new()));
+            self.bar = Bar::Pod(bar.to_pod());
+        }
+        ctx.register_child(self.bar.pod_mut());
+        for content in &mut self.contents {
+            if let Some(pod) = content.pod_mut() {
+                ctx.register_child(pod);
+            }
+        }
+    }
+
+    fn property_changed(&mut self, _ctx: &mut UpdateCtx<'_>, _property_type: TypeId) {}
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        let auto_length: LenDef = len_req.into();
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        let bar = self.bar.pod_mut();
+        let bar_length = ctx.compute_length(bar, auto_length, context_size, axis, cross_length);
+
+        let Some(content) = self
+            .selected
+            .and_then(|index| self.contents.get_mut(index))
+            .and_then(TabContent::pod_mut)
+        else {
+            return bar_length;
+        };
+        match axis {
+            Axis::Horizontal => {
+                let content_length =
+                    ctx.compute_length(content, auto_length, context_size, axis, cross_length);
+                bar_length.max(content_length)
+            }
+            Axis::Vertical => {
+                let content_length = ctx.compute_length(
+                    content,
+                    auto_length.reduce(bar_length),
+                    context_size,
+                    axis,
+                    cross_length,
+                );
+                bar_length.saturating_add(content_length)
+            }
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let bar_auto = SizeDef::fit(size).with_width(LenDef::Fixed(Length::px(size.width)));
+        let bar = self.bar.pod_mut();
+        let bar_size = ctx.compute_size(bar, bar_auto, size.into());
+        let bar_size = Size::new(size.width, bar_size.height);
+        ctx.run_layout(bar, bar_size);
+        ctx.place_child(bar, Point::ORIGIN);
+        ctx.derive_baselines(bar);
+
+        let selected = self.selected;
+        // TODO: move set_stashed to a different layout pass when possible
+        for (index, content) in self.contents.iter_mut().enumerate() {
+            let Some(pod) = content.pod_mut() else {
+                continue;
+            };
+            ctx.set_stashed(pod, Some(index) != selected);
+            if Some(index) == selected {
+                let space = Size::new(size.width, (size.height - bar_size.height).max(0.));
+                let content_size = ctx.compute_size(pod, SizeDef::fit(space), space.into());
+                ctx.run_layout(pod, content_size);
+                ctx.place_child(pod, Point::new(0., bar_size.height));
+            }
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        let contents = self.contents.iter().filter_map(|content| match content {
+            TabContent::Built(pod) => Some(pod.id()),
+            TabContent::Empty | TabContent::Lazy(_) => None,
+        });
+        std::iter::once(self.bar.id()).chain(contents).collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Tabs", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use super::*;
+    use crate::core::TextEvent;
+    use crate::core::keyboard::{Key, NamedKey};
+    use crate::kurbo::Rect;
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+    use crate::widgets::Label;
+
+    fn tab_rect(harness: &TestHarness<Tabs>, index: usize) -> Rect {
+        let bar = harness.get_widget_with_id(harness.root_widget().inner().bar_id());
+        let tab = bar.children()[index];
+        tab.ctx()
+            .window_transform()
+            .transform_rect_bbox(tab.ctx().border_box())
+    }
+
+    fn tab_id(harness: &TestHarness<Tabs>, index: usize) -> WidgetId {
+        let bar = harness.get_widget_with_id(harness.root_widget().inner().bar_id());
+        bar.children()[index].id()
+    }
+
+    #[test]
+    fn tabs_with_selected_content() {
+        let widget = Tabs::new()
+            .with_tab(
+                NewWidget::new(TabHeader::new("First")),
+                NewWidget::new(Label::new("First content")),
+            )
+            .with_tab(
+                NewWidget::new(TabHeader::new("Second").with_closable(true)),
+                NewWidget::new(Label::new("Second content")),
+            )
+            .with_empty_tab(NewWidget::new(TabHeader::new("Third")))
+            .with_selected(1);
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(widget), (300, 120));
+
+        assert_render_snapshot!(harness, "tabs_with_selected_content");
+    }
+
+    #[test]
+    fn lazy_content_is_built_on_selection() {
+        let built = Arc::new(AtomicBool::new(false));
+        let built_in_fn = built.clone();
+        let first = NewWidget::new(Label::new("First content"));
+        let first_id = first.id();
+        let widget = Tabs::new()
+            .with_tab(NewWidget::new(TabHeader::new("First")), first)
+            .with_lazy_tab(NewWidget::new(TabHeader::new("Second")), move || {
+                built_in_fn.store(true, Ordering::Relaxed);
+                NewWidget::new(Label::new("Second content")).erased()
+            });
+        let mut harness = TestHarness::create(test_property_set(), NewWidget::new(widget));
+        assert_eq!(harness.root_widget().inner().selected(), Some(0));
+        assert!(!built.load(Ordering::Relaxed));
+        assert!(!harness.root_widget().inner().has_content(1));
+
+        harness.mouse_click_on(tab_id(&harness, 1), None);
+        assert_eq!(
+            harness.pop_action::<TabAction>(),
+            Some((TabAction::Selected(1), harness.root_id()))
+        );
+        assert!(built.load(Ordering::Relaxed));
+        assert!(harness.root_widget().inner().has_content(1));
+        assert!(harness.get_widget_with_id(first_id).ctx().is_stashed());
+
+        let _ = harness.redraw();
+        let bar_id = harness.root_widget().inner().bar_id();
+        let bar = harness.access_node(bar_id).unwrap();
+        assert_eq!(bar.role(), Role::TabList);
+        assert_eq!(
+            bar.data().active_descendant(),
+            Some(tab_id(&harness, 1).into())
+        );
+        let tab = harness.access_node(tab_id(&harness, 1)).unwrap();
+        assert_eq!(tab.role(), Role::Tab);
+        assert_eq!(tab.is_selected(), Some(true));
+    }
+
+    #[test]
+    fn keyboard_navigation() {
+        let mut widget = Tabs::new();
+        for text in ["A", "B", "C"] {
+            let header = TabHeader::new(text).with_closable(text != "B");
+            widget = widget.with_empty_tab(NewWidget::new(header));
+        }
+        let mut harness = TestHarness::create(test_property_set(), NewWidget::new(widget));
+        let bar_id = harness.root_widget().inner().bar_id();
+        harness.focus_on(Some(bar_id));
+
+        let press = |harness: &mut TestHarness<Tabs>, key: NamedKey| {
+            harness.process_text_event(TextEvent::key_down(Key::Named(key)));
+            harness.process_text_event(TextEvent::key_up(Key::Named(key)));
+            harness.pop_action::<TabAction>().map(|(action, _)| action)
+        };
+        assert_eq!(
+            press(&mut harness, NamedKey::ArrowRight),
+            Some(TabAction::Selected(1))
+        );
+        assert_eq!(press(&mut harness, NamedKey::Delete), None);
+        assert_eq!(
+            press(&mut harness, NamedKey::End),
+            Some(TabAction::Selected(2))
+        );
+        assert_eq!(
+            press(&mut harness, NamedKey::ArrowRight),
+            Some(TabAction::Selected(0))
+        );
+        assert_eq!(
+            press(&mut harness, NamedKey::ArrowLeft),
+            Some(TabAction::Selected(2))
+        );
+        assert_eq!(
+            press(&mut harness, NamedKey::Delete),
+            Some(TabAction::CloseRequested(2))
+        );
+
+        // Closing is up to the owner of the widget.
+        harness.edit_root_widget(|mut tabs| Tabs::remove_tab(&mut tabs, 2));
+        assert_eq!(harness.root_widget().inner().selected(), Some(1));
+        assert_eq!(
+            press(&mut harness, NamedKey::Home),
+            Some(TabAction::Selected(0))
+        );
+    }
+
+    #[test]
+    fn close_button_requests_close() {
+        let widget = Tabs::new()
+            .with_empty_tab(NewWidget::new(TabHeader::new("Kept")))
+            .with_empty_tab(NewWidget::new(TabHeader::new("Closed").with_closable(true)));
+        let mut harness = TestHarness::create(test_property_set(), NewWidget::new(widget));
+
+        // The close button is at the end of the content-box,
+        // inside the padding and the border.
+        let rect = tab_rect(&harness, 1);
+        harness.mouse_move((rect.x1 - 11. - 8., rect.center().y));
+        harness.mouse_button_press(None);
+        harness.mouse_button_release(None);
+        assert_eq!(
+            harness.pop_action::<TabAction>(),
+            Some((TabAction::CloseRequested(1), harness.root_id()))
+        );
+        // Clicking the close button doesn't select the tab.
+        assert_eq!(harness.pop_action::<TabAction>(), None);
+        assert_eq!(harness.root_widget().inner().selected(), Some(0));
+    }
+
+    #[test]
+    fn drag_to_reorder() {
+        let first = NewWidget::new(Label::new("First content"));
+        let first_id = first.id();
+        let widget = Tabs::new()
+            .with_tab(NewWidget::new(TabHeader::new("First")), first)
+            .with_empty_tab(NewWidget::new(TabHeader::new("Second")))
+            .with_empty_tab(NewWidget::new(TabHeader::new("Third")));
+        let mut harness = TestHarness::create(test_property_set(), NewWidget::new(widget));
+        let first_tab = tab_id(&harness, 0);
+
+        let start = tab_rect(&harness, 0).center();
+        let end = tab_rect(&harness, 1).x1 + 5.;
+        harness.mouse_move(start);
+        harness.mouse_button_press(None);
+        harness.mouse_move((end, start.y));
+        // The dragged tab follows the pointer.
+        assert!(tab_rect(&harness, 0).center().x > start.x);
+        harness.mouse_button_release(None);
+        assert_eq!(
+            harness.pop_action::<TabAction>(),
+            Some((TabAction::Moved { from: 0, to: 1 }, harness.root_id()))
+        );
+
+        // Until the move is applied, the tab goes back to its place.
+        assert_eq!(tab_rect(&harness, 0).center(), start);
+        harness.edit_root_widget(|mut tabs| Tabs::move_tab(&mut tabs, 0, 1));
+        assert_eq!(tab_id(&harness, 1), first_tab);
+        assert_eq!(harness.root_widget().inner().selected(), Some(1));
+        assert!(!harness.get_widget_with_id(first_id).ctx().is_stashed());
+    }
+}
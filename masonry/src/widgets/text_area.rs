@@ -3,6 +3,7 @@
 
 use std::any::TypeId;
 use std::mem::Discriminant;
+use std::ops::Range;
 
 use accesskit::{Node, Role};
 use tracing::{Span, trace_span};
@@ -17,14 +18,18 @@ use crate::core::{
 use crate::imaging::Painter;
 use crate::kurbo::{Affine, Axis, Point, Rect, Size};
 use crate::layout::{AsUnit, LenReq, Length};
-use crate::parley::PlainEditor;
 use crate::parley::editing::{Generation, SplitString};
+use crate::parley::{FontContext, LayoutContext, PlainEditor};
 use crate::properties::{CaretColor, ContentColor, SelectionColor};
 use crate::theme::default_text_styles;
 use crate::util::bounding_box_to_rect;
 use crate::util::debug_panic;
+use crate::widgets::InputMask;
 use crate::{TextAlign, theme};
 
+/// The character shown in place of each character of a password.
+const PASSWORD_BULLET: char = '•';
+
 /// `TextArea` implements the core of interactive text.
 ///
 /// It is used to implement [`TextInput`](super::TextInput) and [`Prose`](super::Prose).
@@ -83,6 +88,19 @@ pub struct TextArea<const USER_EDITABLE: bool> {
 
     /// Time elapsed (ms) to calculate the timeout of the cursor's blink animation.
     anim_elapsed: u64,
+
+    /// The text entered in password mode, if enabled.
+    ///
+    /// Unless the password is revealed, `editor` only holds bullets.
+    password: Option<Password>,
+    /// The pattern the text must follow, if any.
+    mask: Option<InputMask>,
+}
+
+/// The state of a text area in password mode.
+struct Password {
+    text: String,
+    revealed: bool,
 }
 
 // --- MARK: BUILDERS
@@ -128,6 +146,8 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
             anim_cursor_visible: true,
             anim_prev_interval: 0,
             anim_elapsed: 0,
+            password: None,
+            mask: None,
         }
     }
 
@@ -215,6 +235,30 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         self
     }
 
+    /// Hides the text behind bullets, for entering passwords.
+    ///
+    /// In password mode, the text can't be copied or cut, [`text`](Self::text) returns the
+    /// bullets, and [`password`](Self::password) returns the actual text.
+    /// Assistive technologies are told this is a password field, and are only given the bullets.
+    /// The input mask is ignored.
+    ///
+    /// To modify this on an active text area, use [`set_password`](Self::set_password).
+    pub fn with_password(mut self, password: bool) -> Self {
+        self.set_password_inner(password);
+        self
+    }
+
+    /// Constrains the text to follow the given mask.
+    ///
+    /// The current text is formatted with the mask.
+    /// Masks are ignored in password mode.
+    ///
+    /// To modify this on an active text area, use [`set_input_mask`](Self::set_input_mask).
+    pub fn with_input_mask(mut self, mask: InputMask) -> Self {
+        self.set_input_mask_inner(Some(mask));
+        self
+    }
+
     /// Shared logic between `with_style` and `insert_style`
     #[track_caller]
     fn insert_style_inner(&mut self, property: StyleProperty) -> Option<StyleProperty> {
@@ -240,6 +284,9 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
     /// To update the text of an active text area, use [`reset_text`](Self::reset_text).
     ///
     /// The return value is not just `&str` to handle IME preedits.
+    ///
+    /// In [password mode](Self::with_password), this returns the bullets shown in place of
+    /// the text, unless the password is revealed.
     pub fn text(&self) -> SplitString<'_> {
         self.editor.text()
     }
@@ -249,6 +296,119 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         self.editor.raw_text().is_empty()
     }
 
+    /// Returns the text entered in [password mode](Self::with_password).
+    ///
+    /// Returns `None` if this text area isn't in password mode.
+    pub fn password(&self) -> Option<&str> {
+        self.password
+            .as_ref()
+            .map(|password| password.text.as_str())
+    }
+
+    /// Returns whether the text is shown in clear in [password mode](Self::with_password).
+    ///
+    /// See [`set_password_revealed`](Self::set_password_revealed).
+    pub fn is_password_revealed(&self) -> bool {
+        self.password
+            .as_ref()
+            .is_some_and(|password| password.revealed)
+    }
+
+    /// Returns the text sent in [`TextAction`]s, which is the actual text in password mode.
+    fn value(&self) -> String {
+        match &self.password {
+            Some(password) => password.text.clone(),
+            None => self.text().into_iter().collect(),
+        }
+    }
+
+    /// Returns the selection, as a range of char indices.
+    fn char_selection(&self) -> Range<usize> {
+        let text = self.editor.raw_text();
+        let range = self.editor.raw_selection().text_range();
+        char_index(text, range.start)..char_index(text, range.end)
+    }
+
+    /// Replaces the text of the editor, keeping the cursor at the same char index.
+    fn replace_editor_text(
+        &mut self,
+        text: &str,
+        cursor: usize,
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+    ) {
+        self.editor.set_text(text);
+        self.editor
+            .driver(fctx, lctx)
+            .move_to_byte(byte_index(text, cursor));
+    }
+
+    fn set_password_inner(&mut self, password: bool) {
+        if password == self.password.is_some() {
+            return;
+        }
+        if password {
+            let text = self.editor.raw_text().to_string();
+            self.editor.set_text(&bullets(&text));
+            self.password = Some(Password {
+                text,
+                revealed: false,
+            });
+        } else if let Some(password) = self.password.take() {
+            self.editor.set_text(&password.text);
+        }
+    }
+
+    fn set_input_mask_inner(&mut self, mask: Option<InputMask>) {
+        if let Some(mask) = &mask
+            && self.password.is_none()
+        {
+            let text = mask.apply(self.editor.raw_text());
+            self.editor.set_text(&text);
+        }
+        self.mask = mask;
+    }
+
+    /// Applies password mode and the input mask to an edit made by the user.
+    ///
+    /// `before` is the selection before the edit, in chars.
+    fn filter_edit(
+        &mut self,
+        before: Range<usize>,
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+    ) {
+        let text = self.editor.raw_text().to_string();
+        let cursor = char_index(&text, self.editor.raw_selection().focus().index());
+        if let Some(password) = &mut self.password {
+            if password.revealed {
+                password.text = text;
+                return;
+            }
+            // Edits replace a range starting at the cursor or at the previous selection
+            // with the text before the new cursor.
+            let start = before.start.min(cursor);
+            let (old_len, new_len) = (password.text.chars().count(), text.chars().count());
+            let removed = (old_len + cursor - start).saturating_sub(new_len);
+            let inserted: String = text.chars().skip(start).take(cursor - start).collect();
+            let old = std::mem::take(&mut password.text);
+            password.text = old.chars().take(start).collect();
+            password.text.push_str(&inserted);
+            password.text.extend(old.chars().skip(start + removed));
+
+            if !inserted.is_empty() {
+                let mut drv = self.editor.driver(fctx, lctx);
+                drv.select_byte_range(byte_index(&text, start), byte_index(&text, cursor));
+                drv.insert_or_replace_selection(&bullets(&inserted));
+            }
+        } else if let Some(mask) = &self.mask {
+            let (masked, cursor) = mask.apply_with_cursor(&text, cursor);
+            if masked != text {
+                self.replace_editor_text(&masked, cursor, fctx, lctx);
+            }
+        }
+    }
+
     /// Returns the IME area from the editor, accounting for padding.
     ///
     /// This should only be called when the editor layout is available.
@@ -336,7 +496,19 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
             let (fctx, lctx) = this.ctx.text_contexts();
             this.widget.editor.driver(fctx, lctx).clear_compose();
         }
-        this.widget.editor.set_text(new_text);
+        let widget = &mut *this.widget;
+        if let Some(password) = &mut widget.password {
+            password.text = new_text.to_string();
+            if password.revealed {
+                widget.editor.set_text(new_text);
+            } else {
+                widget.editor.set_text(&bullets(new_text));
+            }
+        } else if let Some(mask) = &widget.mask {
+            widget.editor.set_text(&mask.apply(new_text));
+        } else {
+            widget.editor.set_text(new_text);
+        }
 
         let (fctx, lctx) = this.ctx.text_contexts();
         this.widget.editor.driver(fctx, lctx).move_to_text_end();
@@ -344,6 +516,71 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         this.ctx.request_layout();
     }
 
+    /// Enables or disables password mode.
+    ///
+    /// The runtime equivalent of [`with_password`](Self::with_password).
+    /// For full documentation, see that method.
+    pub fn set_password(this: &mut WidgetMut<'_, Self>, password: bool) {
+        if this.widget.editor.is_composing() {
+            let (fctx, lctx) = this.ctx.text_contexts();
+            this.widget.editor.driver(fctx, lctx).clear_compose();
+        }
+        this.widget.set_password_inner(password);
+        let (fctx, lctx) = this.ctx.text_contexts();
+        this.widget.editor.driver(fctx, lctx).move_to_text_end();
+
+        this.ctx.request_layout();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Shows or hides the text in [password mode](Self::with_password).
+    ///
+    /// The text can still not be copied while it's shown.
+    /// This does nothing if the text area isn't in password mode.
+    pub fn set_password_revealed(this: &mut WidgetMut<'_, Self>, revealed: bool) {
+        let Some(password) = &mut this.widget.password else {
+            return;
+        };
+        if password.revealed == revealed {
+            return;
+        }
+        password.revealed = revealed;
+        let text = if revealed {
+            password.text.clone()
+        } else {
+            bullets(&password.text)
+        };
+
+        let cursor = this.widget.char_selection().end;
+        let (fctx, lctx) = this.ctx.text_contexts();
+        this.widget.replace_editor_text(&text, cursor, fctx, lctx);
+        this.ctx.request_layout();
+    }
+
+    /// Sets the pattern the text must follow, or removes it.
+    ///
+    /// The runtime equivalent of [`with_input_mask`](Self::with_input_mask).
+    /// For full documentation, see that method.
+    pub fn set_input_mask(this: &mut WidgetMut<'_, Self>, mask: Option<InputMask>) {
+        if this.widget.editor.is_composing() {
+            let (fctx, lctx) = this.ctx.text_contexts();
+            this.widget.editor.driver(fctx, lctx).clear_compose();
+        }
+        let cursor = this.widget.char_selection().end;
+        let masked = mask
+            .as_ref()
+            .map(|mask| mask.apply_with_cursor(this.widget.editor.raw_text(), cursor));
+        this.widget.mask = mask;
+        if let Some((text, cursor)) = masked
+            && this.widget.password.is_none()
+        {
+            let (fctx, lctx) = this.ctx.text_contexts();
+            this.widget.replace_editor_text(&text, cursor, fctx, lctx);
+        }
+
+        this.ctx.request_layout();
+    }
+
     /// Sets [word wrapping](https://en.wikipedia.org/wiki/Line_wrap_and_word_wrap) for the text area.
     ///
     /// When enabled, the text will be laid out to fit within the available width.
@@ -552,6 +789,8 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
         self.anim_elapsed = 0;
         ctx.request_anim_frame();
 
+        let selection_before = self.char_selection();
+
         match event {
             TextEvent::Keyboard(key_event) => {
                 if key_event.state != KeyState::Down || self.editor.is_composing() {
@@ -574,7 +813,8 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                     Key::Character(x)
                         if EDITABLE && action_mod && x.as_str().eq_ignore_ascii_case("x") =>
                     {
-                        if let Some(text) = self.editor.selected_text()
+                        if self.password.is_none()
+                            && let Some(text) = self.editor.selected_text()
                             && !text.is_empty()
                         {
                             let text = text.to_string();
@@ -586,7 +826,8 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                     // Copy
                     #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
                     Key::Character(c) if action_mod && c.as_str().eq_ignore_ascii_case("c") => {
-                        if let Some(text) = self.editor.selected_text()
+                        if self.password.is_none()
+                            && let Some(text) = self.editor.selected_text()
                             && !text.is_empty()
                         {
                             ctx.set_clipboard(text.to_string());
@@ -712,9 +953,7 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                                 .insert_or_replace_selection("\n");
                             edited = true;
                         } else {
-                            ctx.submit_action::<Self::Action>(TextAction::Entered(
-                                self.text().to_string(),
-                            ));
+                            ctx.submit_action::<Self::Action>(TextAction::Entered(self.value()));
                         }
                     }
 
@@ -738,12 +977,14 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                     }
                 }
                 ctx.set_handled();
+                if edited {
+                    let (fctx, lctx) = ctx.text_contexts();
+                    self.filter_edit(selection_before, fctx, lctx);
+                }
                 let new_generation = self.editor.generation();
                 if new_generation != self.rendered_generation {
                     if edited {
-                        ctx.submit_action::<Self::Action>(TextAction::Changed(
-                            self.text().into_iter().collect(),
-                        ));
+                        ctx.submit_action::<Self::Action>(TextAction::Changed(self.value()));
                         ctx.request_layout();
                     } else {
                         ctx.request_render();
@@ -770,7 +1011,9 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                         self.editor.driver(fctx, lctx).clear_compose();
                     }
                     Ime::Preedit(text, cursor) => {
-                        if text.is_empty() {
+                        // Text being composed would be shown in clear, so we only show the
+                        // committed text in password mode.
+                        if text.is_empty() || self.password.is_some() {
                             self.editor.driver(fctx, lctx).clear_compose();
                         } else {
                             self.editor.driver(fctx, lctx).set_compose(text, *cursor);
@@ -781,6 +1024,7 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                         self.editor
                             .driver(fctx, lctx)
                             .insert_or_replace_selection(text);
+                        self.filter_edit(selection_before, fctx, lctx);
                         edited = true;
                     }
                     Ime::Enabled => {}
@@ -788,8 +1032,7 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
 
                 ctx.set_handled();
                 if edited {
-                    let text = self.text().into_iter().collect();
-                    ctx.submit_action::<Self::Action>(TextAction::Changed(text));
+                    ctx.submit_action::<Self::Action>(TextAction::Changed(self.value()));
                 }
 
                 let new_generation = self.editor.generation();
@@ -805,13 +1048,12 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                     self.editor
                         .driver(fctx, lctx)
                         .insert_or_replace_selection(text);
+                    self.filter_edit(selection_before, fctx, lctx);
 
                     // TODO - Factor out with other branches
                     let new_generation = self.editor.generation();
                     if new_generation != self.rendered_generation {
-                        ctx.submit_action::<Self::Action>(TextAction::Changed(
-                            self.text().into_iter().collect(),
-                        ));
+                        ctx.submit_action::<Self::Action>(TextAction::Changed(self.value()));
                         ctx.request_layout();
                         self.rendered_generation = new_generation;
                     }
@@ -1051,7 +1293,9 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
     }
 
     fn accessibility_role(&self) -> Role {
-        if EDITABLE {
+        if EDITABLE && self.password.is_some() {
+            Role::PasswordInput
+        } else if EDITABLE {
             match self.insert_newline {
                 InsertNewline::OnShiftEnter | InsertNewline::OnEnter => Role::MultilineTextInput,
                 _ => Role::TextInput,
@@ -1107,6 +1351,23 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
     }
 }
 
+/// Returns the index of the char starting at byte `index` of `text`.
+fn char_index(text: &str, index: usize) -> usize {
+    text[..index].chars().count()
+}
+
+/// Returns the byte index of the char at char index `index` of `text`.
+fn byte_index(text: &str, index: usize) -> usize {
+    text.char_indices()
+        .nth(index)
+        .map_or(text.len(), |(i, _)| i)
+}
+
+/// Returns one bullet for each char of `text`.
+fn bullets(text: &str) -> String {
+    text.chars().map(|_| PASSWORD_BULLET).collect()
+}
+
 /// When to insert a newline in a text area.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum InsertNewline {
@@ -1329,4 +1590,95 @@ mod tests {
         assert!(harness.pop_action_erased().is_none());
         assert_eq!(text, "hello world");
     }
+
+    fn press_key(harness: &mut TestHarness<impl Widget>, key: Key, modifiers: Modifiers) {
+        harness.process_text_event(TextEvent::Keyboard(KeyboardEvent {
+            key,
+            modifiers,
+            ..Default::default()
+        }));
+    }
+
+    #[test]
+    fn password_mode_hides_text() {
+        let area = NewWidget::new(TextArea::new_editable("").with_password(true));
+
+        let mut harness = TestHarness::create(test_property_set(), area);
+        let text_id = harness.root_id();
+        harness.focus_on(Some(text_id));
+        harness.keyboard_type_chars("pass");
+        press_key(
+            &mut harness,
+            Key::Named(NamedKey::ArrowLeft),
+            Modifiers::empty(),
+        );
+        press_key(
+            &mut harness,
+            Key::Named(NamedKey::Backspace),
+            Modifiers::empty(),
+        );
+        harness.keyboard_type_chars("XY");
+
+        let area = harness.root_widget();
+        assert_eq!(area.text().to_string(), "•••••");
+        assert_eq!(area.password(), Some("paXYs"));
+        let mut last_action = None;
+        while let Some((action, _)) = harness.pop_action::<TextAction>() {
+            last_action = Some(action);
+        }
+        assert_eq!(last_action, Some(TextAction::Changed("paXYs".into())));
+
+        let node = harness.access_node(text_id).unwrap();
+        assert_eq!(node.role(), Role::PasswordInput);
+        assert!(!node.value().unwrap_or_default().contains("paXYs"));
+
+        // The password can't be copied.
+        let action_mod = if cfg!(target_os = "macos") {
+            Modifiers::META
+        } else {
+            Modifiers::CONTROL
+        };
+        press_key(&mut harness, Key::Character("a".into()), action_mod);
+        press_key(&mut harness, Key::Character("c".into()), action_mod);
+        assert_eq!(harness.clipboard_contents(), "");
+
+        harness.edit_root_widget(|mut area| {
+            TextArea::set_password_revealed(&mut area, true);
+        });
+        assert_eq!(harness.root_widget().text().to_string(), "paXYs");
+
+        harness.edit_root_widget(|mut area| {
+            TextArea::set_password(&mut area, false);
+        });
+        let area = harness.root_widget();
+        assert_eq!(area.text().to_string(), "paXYs");
+        assert_eq!(area.password(), None);
+    }
+
+    #[test]
+    fn input_mask_formats_typed_text() {
+        let mask = InputMask::new("(999) 999-9999");
+        let area = NewWidget::new(TextArea::new_editable("").with_input_mask(mask));
+
+        let mut harness = TestHarness::create(test_property_set(), area);
+        let text_id = harness.root_id();
+        harness.focus_on(Some(text_id));
+        harness.keyboard_type_chars("555x12");
+        assert_eq!(harness.root_widget().text().to_string(), "(555) 12");
+
+        // Typing in the middle keeps the cursor after the typed character.
+        press_key(
+            &mut harness,
+            Key::Named(NamedKey::ArrowLeft),
+            Modifiers::empty(),
+        );
+        harness.keyboard_type_chars("3");
+        harness.keyboard_type_chars("4");
+        assert_eq!(harness.root_widget().text().to_string(), "(555) 134-2");
+
+        harness.edit_root_widget(|mut area| {
+            TextArea::reset_text(&mut area, "12345678901234");
+        });
+        assert_eq!(harness.root_widget().text().to_string(), "(123) 456-7890");
+    }
 }
@@ -8,16 +8,16 @@ use tracing::{Span, trace_span};
 
 use crate::TextAlign;
 use crate::core::{
-    AccessCtx, ArcStr, ChildrenIds, EventCtx, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
-    PointerButton, PointerButtonEvent, PointerEvent, PrePaintProps, PropertiesMut, PropertiesRef,
-    RegisterCtx, TextEvent, Update, UpdateCtx, UsesProperty, Widget, WidgetId, WidgetMut,
-    WidgetPod, paint_background, paint_border, paint_box_shadow,
+    AccessCtx, ActionCtx, ArcStr, ChildrenIds, ErasedAction, EventCtx, LayoutCtx, MeasureCtx,
+    NewWidget, NoAction, PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PrePaintProps,
+    PropertiesMut, PropertiesRef, RegisterCtx, TextEvent, Update, UpdateCtx, UsesProperty, Widget,
+    WidgetId, WidgetMut, WidgetPod, paint_background, paint_border, paint_box_shadow,
 };
 use crate::imaging::Painter;
 use crate::kurbo::{Axis, Point, Size};
-use crate::layout::{LayoutSize, LenReq, Length};
+use crate::layout::{LayoutSize, LenReq, Length, SizeDef};
 use crate::properties::{CaretColor, ContentColor, LineBreaking, PlaceholderColor, SelectionColor};
-use crate::widgets::{Label, TextArea};
+use crate::widgets::{Button, ButtonPress, Label, TextArea};
 
 /// The text input widget displays text which can be edited by the user,
 /// inside a surrounding box.
@@ -29,6 +29,9 @@ use crate::widgets::{Label, TextArea};
 /// However, the child widget will do so, as it is user editable.
 /// The ID of the child can be accessed using [`area_pod`](Self::area_pod).
 ///
+/// For entering passwords, use [`new_password`](Self::new_password), optionally with
+/// a [button to show the password](Self::with_password_toggle).
+///
 /// At runtime, most properties of the text will be set using [`text_mut`](Self::text_mut).
 /// This is because `TextInput` largely serves as a wrapper around a [`TextArea`].
 ///
@@ -50,8 +53,22 @@ pub struct TextInput {
 
     /// Whether to clip the contained text.
     clip: bool,
+
+    /// The button showing or hiding the password, if any.
+    password_toggle: Option<WidgetPod<Button>>,
+    /// The text of the password toggle button while the password is hidden, then shown.
+    ///
+    /// This is also how the button is described to assistive technologies.
+    password_toggle_text: [ArcStr; 2],
+    /// Whether the password is shown by the text area.
+    password_revealed: bool,
 }
 
+/// The default text of the password toggle button while the password is hidden.
+const SHOW_PASSWORD_TEXT: &str = "Show";
+/// The default text of the password toggle button while the password is shown.
+const HIDE_PASSWORD_TEXT: &str = "Hide";
+
 // --- MARK: BUILDERS
 impl TextInput {
     /// Creates a new `TextInput` with the given text.
@@ -73,9 +90,17 @@ impl TextInput {
             placeholder_text: "".into(),
             text_alignment: TextAlign::default(),
             clip: false,
+            password_toggle: None,
+            password_toggle_text: [SHOW_PASSWORD_TEXT.into(), HIDE_PASSWORD_TEXT.into()],
+            password_revealed: false,
         }
     }
 
+    /// Creates a new `TextInput` in [password mode](TextArea::with_password).
+    pub fn new_password(text: &str) -> Self {
+        Self::from_text_area(TextArea::new_editable(text).with_password(true).prepare())
+    }
+
     /// Sets the text alignment for both the input text and placeholder.
     pub fn with_text_alignment(mut self, text_alignment: TextAlign) -> Self {
         self.text_alignment = text_alignment;
@@ -107,6 +132,39 @@ impl TextInput {
         self.clip = clip;
         self
     }
+
+    /// Whether to show a button which shows or hides the password.
+    ///
+    /// This is only useful if the text area is in [password mode](TextArea::with_password).
+    ///
+    /// To modify this on active text input, use [`set_password_toggle`](Self::set_password_toggle).
+    pub fn with_password_toggle(mut self, toggle: bool) -> Self {
+        self.password_toggle = toggle.then(|| {
+            Button::with_text(self.current_password_toggle_text())
+                .prepare()
+                .to_pod()
+        });
+        self
+    }
+
+    /// The text of the password toggle button while the password is hidden, and while it's shown.
+    ///
+    /// The button is described to assistive technologies by this text, which defaults
+    /// to "Show" and "Hide".
+    ///
+    /// To modify this on active text input, use
+    /// [`set_password_toggle_text`](Self::set_password_toggle_text).
+    pub fn with_password_toggle_text(
+        mut self,
+        show: impl Into<ArcStr>,
+        hide: impl Into<ArcStr>,
+    ) -> Self {
+        self.password_toggle_text = [show.into(), hide.into()];
+        if self.password_toggle.is_some() {
+            self = self.with_password_toggle(true);
+        }
+        self
+    }
 }
 
 // --- MARK: METHODS
@@ -117,6 +175,10 @@ impl TextInput {
     pub fn area_pod(&self) -> &WidgetPod<TextArea<true>> {
         &self.text
     }
+
+    fn current_password_toggle_text(&self) -> ArcStr {
+        self.password_toggle_text[usize::from(self.password_revealed)].clone()
+    }
 }
 
 // --- MARK: WIDGETMUT
@@ -154,6 +216,51 @@ impl TextInput {
         this.ctx.request_layout();
     }
 
+    /// Whether to show a button which shows or hides the password.
+    ///
+    /// The runtime equivalent of [`with_password_toggle`](Self::with_password_toggle).
+    pub fn set_password_toggle(this: &mut WidgetMut<'_, Self>, toggle: bool) {
+        if toggle == this.widget.password_toggle.is_some() {
+            return;
+        }
+        if toggle {
+            let button = Button::with_text(this.widget.current_password_toggle_text()).prepare();
+            this.widget.password_toggle = Some(button.to_pod());
+        } else if let Some(button) = this.widget.password_toggle.take() {
+            this.ctx.remove_child(button);
+        }
+        this.ctx.children_changed();
+    }
+
+    /// Shows or hides the password.
+    ///
+    /// See [`TextArea::set_password_revealed`].
+    pub fn set_password_revealed(this: &mut WidgetMut<'_, Self>, revealed: bool) {
+        this.widget.password_revealed = revealed;
+        TextArea::set_password_revealed(&mut Self::text_mut(this), revealed);
+        Self::update_password_toggle_text(this);
+    }
+
+    /// The text of the password toggle button while the password is hidden, and while it's shown.
+    ///
+    /// The runtime equivalent of [`with_password_toggle_text`](Self::with_password_toggle_text).
+    pub fn set_password_toggle_text(
+        this: &mut WidgetMut<'_, Self>,
+        show: impl Into<ArcStr>,
+        hide: impl Into<ArcStr>,
+    ) {
+        this.widget.password_toggle_text = [show.into(), hide.into()];
+        Self::update_password_toggle_text(this);
+    }
+
+    fn update_password_toggle_text(this: &mut WidgetMut<'_, Self>) {
+        let text = this.widget.current_password_toggle_text();
+        if let Some(button) = &mut this.widget.password_toggle {
+            let mut button = this.ctx.get_mut(button);
+            Label::set_text(&mut Button::child_mut(&mut button).downcast(), text);
+        }
+    }
+
     /// Sets the text alignment for both the input text and placeholder.
     pub fn set_text_alignment(this: &mut WidgetMut<'_, Self>, text_alignment: TextAlign) {
         this.widget.text_alignment = text_alignment;
@@ -207,9 +314,32 @@ impl Widget for TextInput {
         }
     }
 
+    fn on_action(
+        &mut self,
+        ctx: &mut ActionCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        action: &ErasedAction,
+        source: WidgetId,
+    ) {
+        if action.is::<ButtonPress>()
+            && let Some(button) = &self.password_toggle
+            && button.id() == source
+        {
+            ctx.mutate_self_later(|mut input| {
+                let mut input = input.downcast::<Self>();
+                let revealed = !input.widget.password_revealed;
+                Self::set_password_revealed(&mut input, revealed);
+            });
+            ctx.set_handled();
+        }
+    }
+
     fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
         ctx.register_child(&mut self.text);
         ctx.register_child(&mut self.placeholder);
+        if let Some(button) = &mut self.password_toggle {
+            ctx.register_child(button);
+        }
     }
 
     fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
@@ -288,13 +418,22 @@ impl Widget for TextInput {
                 let auto_length = len_req.into();
                 let context_size = LayoutSize::maybe(axis.cross(), cross_length);
 
-                ctx.compute_length(
+                let text_length = ctx.compute_length(
                     &mut self.text,
                     auto_length,
                     context_size,
                     axis,
                     cross_length,
-                )
+                );
+                let Some(button) = &mut self.password_toggle else {
+                    return text_length;
+                };
+                let button_length =
+                    ctx.compute_length(button, auto_length, context_size, axis, cross_length);
+                match axis {
+                    Axis::Horizontal => text_length.saturating_add(button_length),
+                    Axis::Vertical => text_length.max(button_length),
+                }
             }
             // We always want to use all the offered space,
             // even on the block axis as we have multi-line display.
@@ -303,7 +442,22 @@ impl Widget for TextInput {
     }
 
     fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
-        ctx.run_layout(&mut self.text, size);
+        // The password toggle is placed at the end of the input, vertically centered.
+        let button_width = if let Some(button) = &mut self.password_toggle {
+            let button_size = ctx.compute_size(button, SizeDef::fit(size), size.into());
+            ctx.run_layout(button, button_size);
+            let button_origin = Point::new(
+                size.width - button_size.width,
+                (size.height - button_size.height) / 2.,
+            );
+            ctx.place_child(button, button_origin);
+            button_size.width
+        } else {
+            0.
+        };
+        let text_size = Size::new((size.width - button_width).max(0.), size.height);
+
+        ctx.run_layout(&mut self.text, text_size);
 
         let child_origin = Point::ORIGIN;
         ctx.place_child(&mut self.text, child_origin);
@@ -313,7 +467,7 @@ impl Widget for TextInput {
         let text_is_empty = ctx.get_raw(&mut self.text).0.is_empty();
         ctx.set_stashed(&mut self.placeholder, !text_is_empty);
         if text_is_empty {
-            ctx.run_layout(&mut self.placeholder, size);
+            ctx.run_layout(&mut self.placeholder, text_size);
             ctx.place_child(&mut self.placeholder, child_origin);
         }
 
@@ -366,7 +520,11 @@ impl Widget for TextInput {
     }
 
     fn children_ids(&self) -> ChildrenIds {
-        ChildrenIds::from_slice(&[self.text.id(), self.placeholder.id()])
+        let mut ids = ChildrenIds::from_slice(&[self.text.id(), self.placeholder.id()]);
+        if let Some(button) = &self.password_toggle {
+            ids.push(button.id());
+        }
+        ids
     }
 
     fn make_trace_span(&self, id: WidgetId) -> Span {
@@ -487,4 +645,69 @@ mod tests {
 
         assert_render_snapshot!(harness, "text_input_clip");
     }
+
+    #[test]
+    fn password_toggle_reveals_text() {
+        let text_input =
+            NewWidget::new(TextInput::new_password("hunter2").with_password_toggle(true));
+        let text_area_id = text_input.widget.area_pod().id();
+        let button_id = text_input.widget.password_toggle.as_ref().unwrap().id();
+        let mut harness = TestHarness::create_with(test_property_set(), text_input, HARNESS_PARAMS);
+
+        let text_area = harness.get_widget_with_id(text_area_id);
+        assert_eq!(
+            text_area.downcast::<TextArea<true>>().unwrap().text(),
+            "•••••••"
+        );
+
+        harness.mouse_click_on(button_id, None);
+        let text_area = harness.get_widget_with_id(text_area_id);
+        let text_area = text_area.downcast::<TextArea<true>>().unwrap();
+        assert_eq!(text_area.text(), "hunter2");
+        assert_eq!(text_area.password(), Some("hunter2"));
+
+        harness.mouse_click_on(button_id, None);
+        let text_area = harness.get_widget_with_id(text_area_id);
+        assert_eq!(
+            text_area.downcast::<TextArea<true>>().unwrap().text(),
+            "•••••••"
+        );
+
+        // The text area shrinks to make room for the button.
+        let input_width = harness.root_widget().ctx().border_box().width();
+        let area_width = harness
+            .get_widget_with_id(text_area_id)
+            .ctx()
+            .border_box()
+            .width();
+        assert!(area_width < input_width);
+    }
+
+    #[test]
+    fn password_toggle_text_is_configurable() {
+        let text_input = NewWidget::new(
+            TextInput::new_password("hunter2")
+                .with_password_toggle(true)
+                .with_password_toggle_text("Reveal", "Conceal"),
+        );
+        let button_id = text_input.widget.password_toggle.as_ref().unwrap().id();
+        let mut harness = TestHarness::create_with(test_property_set(), text_input, HARNESS_PARAMS);
+        let button_text = |harness: &TestHarness<TextInput>| {
+            let button = harness.get_widget_with_id(button_id);
+            button.children()[0]
+                .downcast::<Label>()
+                .unwrap()
+                .text()
+                .to_string()
+        };
+
+        assert_eq!(button_text(&harness), "Reveal");
+        harness.mouse_click_on(button_id, None);
+        assert_eq!(button_text(&harness), "Conceal");
+
+        harness.edit_root_widget(|mut text_input| {
+            TextInput::set_password_toggle_text(&mut text_input, "Afficher", "Masquer");
+        });
+        assert_eq!(button_text(&harness), "Masquer");
+    }
 }
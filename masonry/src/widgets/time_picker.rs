@@ -0,0 +1,589 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use time::Time;
+use tracing::{Span, trace_span};
+
+use crate::core::keyboard::Key;
+use crate::core::{
+    AccessCtx, ActionCtx, ChildrenIds, ErasedAction, EventCtx, LayoutCtx, MeasureCtx, PaintCtx,
+    PropertiesMut, PropertiesRef, RegisterCtx, TextEvent, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::{LayoutSize, LenDef, LenReq, Length, SizeDef};
+use crate::widgets::{Label, Step, StepInput};
+
+/// The space around the separators between the segments.
+const SEPARATOR_GAP: f64 = 2.;
+/// The space before the AM/PM segment.
+const PERIOD_GAP: f64 = 6.;
+
+/// An input for a time of the day, made of a [`StepInput`] for each of its parts.
+///
+/// The hours, minutes, and optionally [seconds](Self::with_seconds) and the
+/// [AM/PM period](Self::with_twelve_hour) are separate segments, which are stepped
+/// through like any `StepInput` and wrap around.
+/// Digits can also be typed into the focused segment, which moves the focus to the
+/// next segment once complete. The period segment is set by typing `A` or `P`.
+///
+/// There will be a [`TimeChanged`] action when the user changes the time.
+pub struct TimePicker {
+    time: Time,
+    twelve_hour: bool,
+    show_seconds: bool,
+    segments: Vec<Segment>,
+    /// The colons between the hours, minutes and seconds.
+    separators: Vec<WidgetPod<Label>>,
+    /// The first digit typed into a segment, waiting for a second one.
+    typed: Option<(WidgetId, u8)>,
+    /// Whether the segments were rebuilt and aren't in the widget tree yet.
+    segments_pending: bool,
+}
+
+/// A part of the time in a [`TimePicker`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SegmentKind {
+    Hour,
+    Minute,
+    Second,
+    Period,
+}
+
+struct Segment {
+    kind: SegmentKind,
+    input: WidgetPod<StepInput<u8>>,
+}
+
+/// The time of a [`TimePicker`] was changed by the user.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimeChanged(pub Time);
+
+// --- MARK: BUILDERS
+impl TimePicker {
+    /// Creates a 24-hour picker showing the hours and minutes of `time`.
+    pub fn new(time: Time) -> Self {
+        let mut picker = Self {
+            time,
+            twelve_hour: false,
+            show_seconds: false,
+            segments: Vec::new(),
+            separators: Vec::new(),
+            typed: None,
+            segments_pending: false,
+        };
+        picker.build_segments();
+        picker
+    }
+
+    /// Builder-style method to show the hours from 1 to 12, with an AM/PM segment.
+    pub fn with_twelve_hour(mut self, twelve_hour: bool) -> Self {
+        self.twelve_hour = twelve_hour;
+        self.build_segments();
+        self
+    }
+
+    /// Builder-style method to show the seconds.
+    pub fn with_seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+        self.build_segments();
+        self
+    }
+
+    /// Returns the time of the picker.
+    pub fn time(&self) -> Time {
+        self.time
+    }
+
+    /// Returns whether the hours are shown from 1 to 12, with an AM/PM segment.
+    pub fn is_twelve_hour(&self) -> bool {
+        self.twelve_hour
+    }
+
+    /// Returns whether the seconds are shown.
+    pub fn shows_seconds(&self) -> bool {
+        self.show_seconds
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl TimePicker {
+    /// Sets the time.
+    pub fn set_time(this: &mut WidgetMut<'_, Self>, time: Time) {
+        if this.widget.time == time {
+            return;
+        }
+        this.widget.time = time;
+        this.widget.typed = None;
+        this.ctx.request_accessibility_update();
+        if this.widget.segments_pending {
+            // The new segments can't be mutated yet, so they are built again with the time.
+            this.widget.build_segments();
+            return;
+        }
+        for segment in &mut this.widget.segments {
+            let value = segment_value(segment.kind, time, this.widget.twelve_hour);
+            StepInput::set_base(&mut this.ctx.get_mut(&mut segment.input), value);
+        }
+    }
+
+    /// Sets whether the hours are shown from 1 to 12, with an AM/PM segment.
+    pub fn set_twelve_hour(this: &mut WidgetMut<'_, Self>, twelve_hour: bool) {
+        if this.widget.twelve_hour != twelve_hour {
+            this.widget.twelve_hour = twelve_hour;
+            Self::rebuild_segments(this);
+        }
+    }
+
+    /// Sets whether the seconds are shown.
+    pub fn set_show_seconds(this: &mut WidgetMut<'_, Self>, show_seconds: bool) {
+        if this.widget.show_seconds != show_seconds {
+            this.widget.show_seconds = show_seconds;
+            Self::rebuild_segments(this);
+        }
+    }
+
+    fn rebuild_segments(this: &mut WidgetMut<'_, Self>) {
+        for segment in this.widget.segments.drain(..) {
+            this.ctx.remove_child(segment.input);
+        }
+        for separator in this.widget.separators.drain(..) {
+            this.ctx.remove_child(separator);
+        }
+        this.widget.build_segments();
+        this.widget.segments_pending = true;
+        this.ctx.children_changed();
+    }
+}
+
+// --- MARK: INTERNALS
+impl TimePicker {
+    fn build_segments(&mut self) {
+        let mut kinds = vec![SegmentKind::Hour, SegmentKind::Minute];
+        if self.show_seconds {
+            kinds.push(SegmentKind::Second);
+        }
+        if self.twelve_hour {
+            kinds.push(SegmentKind::Period);
+        }
+        self.separators = (0..if self.show_seconds { 2 } else { 1 })
+            .map(|_| WidgetPod::new(Label::new(":")))
+            .collect();
+        self.segments = kinds
+            .into_iter()
+            .map(|kind| {
+                let (min, max) = segment_bounds(kind, self.twelve_hour);
+                let value = segment_value(kind, self.time, self.twelve_hour);
+                let input = StepInput::new(value, 1, min, max)
+                    .with_wrap(true)
+                    .with_display(move |state| match kind {
+                        SegmentKind::Period if state.value == 0 => "AM".into(),
+                        SegmentKind::Period => "PM".into(),
+                        _ => format!("{:02}", state.value),
+                    });
+                Segment {
+                    kind,
+                    input: WidgetPod::new(input),
+                }
+            })
+            .collect();
+        self.typed = None;
+    }
+
+    /// Sets the value of a segment after it was changed by the user.
+    fn change_segment(&mut self, ctx: &mut EventCtx<'_>, index: usize, value: u8) {
+        ctx.mutate_child_later(&mut self.segments[index].input, move |mut input| {
+            StepInput::set_base(&mut input, value);
+        });
+        let kind = self.segments[index].kind;
+        let time = with_segment_value(self.time, kind, value, self.twelve_hour);
+        if time != self.time {
+            self.time = time;
+            ctx.submit_action::<TimeChanged>(TimeChanged(time));
+            ctx.request_accessibility_update();
+        }
+    }
+}
+
+fn segment_bounds(kind: SegmentKind, twelve_hour: bool) -> (u8, u8) {
+    match kind {
+        SegmentKind::Hour if twelve_hour => (1, 12),
+        SegmentKind::Hour => (0, 23),
+        SegmentKind::Minute | SegmentKind::Second => (0, 59),
+        SegmentKind::Period => (0, 1),
+    }
+}
+
+fn segment_value(kind: SegmentKind, time: Time, twelve_hour: bool) -> u8 {
+    match kind {
+        SegmentKind::Hour if twelve_hour => match time.hour() % 12 {
+            0 => 12,
+            hour => hour,
+        },
+        SegmentKind::Hour => time.hour(),
+        SegmentKind::Minute => time.minute(),
+        SegmentKind::Second => time.second(),
+        SegmentKind::Period => u8::from(time.hour() >= 12),
+    }
+}
+
+/// Returns `time` with the segment of the given `kind` set to `value`.
+fn with_segment_value(time: Time, kind: SegmentKind, value: u8, twelve_hour: bool) -> Time {
+    let pm = if time.hour() >= 12 { 12 } else { 0 };
+    let result = match kind {
+        SegmentKind::Hour if twelve_hour => time.replace_hour(value % 12 + pm),
+        SegmentKind::Hour => time.replace_hour(value),
+        SegmentKind::Minute => time.replace_minute(value),
+        SegmentKind::Second => time.replace_second(value),
+        SegmentKind::Period => time.replace_hour(time.hour() % 12 + 12 * value),
+    };
+    result.unwrap_or(time)
+}
+
+fn format_time(time: Time, show_seconds: bool) -> String {
+    if show_seconds {
+        format!(
+            "{:02}:{:02}:{:02}",
+            time.hour(),
+            time.minute(),
+            time.second()
+        )
+    } else {
+        format!("{:02}:{:02}", time.hour(), time.minute())
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for TimePicker {
+    type Action = TimeChanged;
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let TextEvent::Keyboard(event) = event else {
+            return;
+        };
+        let Key::Character(c) = &event.key else {
+            return;
+        };
+        if !event.state.is_down() {
+            return;
+        }
+        let target = ctx.target();
+        let Some(index) = self
+            .segments
+            .iter()
+            .position(|segment| segment.input.id() == target)
+        else {
+            return;
+        };
+        let kind = self.segments[index].kind;
+
+        if kind == SegmentKind::Period {
+            let value = match c.to_ascii_lowercase().as_str() {
+                "a" => 0,
+                "p" => 1,
+                _ => return,
+            };
+            self.change_segment(ctx, index, value);
+            ctx.set_handled();
+            return;
+        }
+
+        let Some(digit) = c.parse::<u8>().ok().filter(|digit| *digit < 10) else {
+            return;
+        };
+        let (min, max) = segment_bounds(kind, self.twelve_hour);
+        let (value, complete) = match self.typed.take() {
+            Some((id, first)) if id == target && first * 10 + digit <= max => {
+                (first * 10 + digit, true)
+            }
+            // The digit can't be followed by another one.
+            _ if digit * 10 > max => (digit, true),
+            _ => {
+                self.typed = Some((target, digit));
+                (digit, false)
+            }
+        };
+        if value >= min {
+            self.change_segment(ctx, index, value);
+        }
+        if complete && let Some(next) = self.segments.get(index + 1) {
+            ctx.set_focus(next.input.id());
+        }
+        ctx.set_handled();
+    }
+
+    fn on_action(
+        &mut self,
+        ctx: &mut ActionCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        action: &ErasedAction,
+        source: WidgetId,
+    ) {
+        let Some(step) = action.downcast_ref::<Step<u8>>() else {
+            return;
+        };
+        let Some(segment) = self
+            .segments
+            .iter()
+            .find(|segment| segment.input.id() == source)
+        else {
+            return;
+        };
+        self.typed = None;
+        let time = with_segment_value(self.time, segment.kind, step.value, self.twelve_hour);
+        if time != self.time {
+            self.time = time;
+            ctx.submit_action::<Self::Action>(TimeChanged(time));
+            ctx.request_accessibility_update();
+        }
+        ctx.set_handled();
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        self.segments_pending = false;
+        for segment in &mut self.segments {
+            ctx.register_child(&mut segment.input);
+        }
+        for separator in &mut self.separators {
+            ctx.register_child(separator);
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        _cross_length: Option<Length>,
+    ) -> Length {
+        let context_size = LayoutSize::NONE;
+        let lengths = self
+            .segments
+            .iter_mut()
+            .map(|segment| &mut segment.input)
+            .map(|input| ctx.compute_length(input, LenDef::MaxContent, context_size, axis, None))
+            .collect::<Vec<_>>();
+        let separators = self
+            .separators
+            .iter_mut()
+            .map(|separator| {
+                ctx.compute_length(separator, LenDef::MaxContent, context_size, axis, None)
+            })
+            .collect::<Vec<_>>();
+        match axis {
+            Axis::Horizontal => {
+                let mut total = lengths
+                    .into_iter()
+                    .chain(separators)
+                    .fold(Length::ZERO, Length::saturating_add);
+                total = total.saturating_add(Length::px(
+                    2. * SEPARATOR_GAP * self.separators.len() as f64,
+                ));
+                if self.twelve_hour {
+                    total = total.saturating_add(Length::px(PERIOD_GAP));
+                }
+                total
+            }
+            Axis::Vertical => lengths
+                .into_iter()
+                .chain(separators)
+                .fold(Length::ZERO, Length::max),
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let mut x = 0.;
+        let mut separators = self.separators.iter_mut();
+        for segment in &mut self.segments {
+            if segment.kind == SegmentKind::Period {
+                x += PERIOD_GAP;
+            } else if segment.kind != SegmentKind::Hour
+                && let Some(separator) = separators.next()
+            {
+                let separator_size = ctx.compute_size(separator, SizeDef::MAX, size.into());
+                ctx.run_layout(separator, separator_size);
+                let y = (size.height - separator_size.height) * 0.5;
+                ctx.place_child(separator, Point::new(x + SEPARATOR_GAP, y));
+                x += separator_size.width + 2. * SEPARATOR_GAP;
+            }
+            let input = &mut segment.input;
+            let input_size = ctx.compute_size(input, SizeDef::MAX, size.into());
+            ctx.run_layout(input, input_size);
+            ctx.place_child(
+                input,
+                Point::new(x, (size.height - input_size.height) * 0.5),
+            );
+            x += input_size.width;
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::TimeInput
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_value(format_time(self.time, self.show_seconds));
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.segments
+            .iter()
+            .map(|segment| segment.input.id())
+            .chain(self.separators.iter().map(WidgetPod::id))
+            .collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("TimePicker", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::NewWidget;
+    use crate::core::keyboard::NamedKey;
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+
+    fn time(hour: u8, minute: u8, second: u8) -> Time {
+        Time::from_hms(hour, minute, second).unwrap()
+    }
+
+    fn segment_id(harness: &TestHarness<TimePicker>, kind: SegmentKind) -> WidgetId {
+        let picker = harness.root_widget();
+        let segment = picker.inner().segments.iter().find(|s| s.kind == kind);
+        segment.unwrap().input.id()
+    }
+
+    fn type_key(harness: &mut TestHarness<TimePicker>, key: Key) {
+        harness.process_text_event(TextEvent::key_down(key.clone()));
+        harness.process_text_event(TextEvent::key_up(key));
+    }
+
+    fn changed(harness: &mut TestHarness<TimePicker>) -> Option<Time> {
+        harness
+            .pop_action::<TimeChanged>()
+            .map(|(action, _)| action.0)
+    }
+
+    #[test]
+    fn twelve_hour_with_seconds() {
+        let picker = TimePicker::new(time(13, 5, 9))
+            .with_twelve_hour(true)
+            .with_seconds(true);
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(picker), (520, 40));
+
+        assert_render_snapshot!(harness, "time_picker_twelve_hour_with_seconds");
+    }
+
+    #[test]
+    fn twelve_hour_conversion() {
+        assert_eq!(segment_value(SegmentKind::Hour, time(0, 30, 0), true), 12);
+        assert_eq!(segment_value(SegmentKind::Hour, time(13, 30, 0), true), 1);
+        assert_eq!(segment_value(SegmentKind::Period, time(13, 30, 0), true), 1);
+        assert_eq!(
+            with_segment_value(time(13, 30, 0), SegmentKind::Hour, 12, true),
+            time(12, 30, 0)
+        );
+        assert_eq!(
+            with_segment_value(time(9, 30, 0), SegmentKind::Hour, 12, true),
+            time(0, 30, 0)
+        );
+        assert_eq!(
+            with_segment_value(time(9, 30, 0), SegmentKind::Period, 1, true),
+            time(21, 30, 0)
+        );
+    }
+
+    #[test]
+    fn step_segments() {
+        let picker = TimePicker::new(time(23, 59, 0));
+        let mut harness = TestHarness::create(test_property_set(), NewWidget::new(picker));
+
+        let hour = segment_id(&harness, SegmentKind::Hour);
+        harness.focus_on(Some(hour));
+        type_key(&mut harness, Key::Named(NamedKey::ArrowUp));
+        assert_eq!(changed(&mut harness), Some(time(0, 59, 0)));
+
+        let minute = segment_id(&harness, SegmentKind::Minute);
+        harness.focus_on(Some(minute));
+        type_key(&mut harness, Key::Named(NamedKey::ArrowDown));
+        assert_eq!(changed(&mut harness), Some(time(0, 58, 0)));
+        assert_eq!(harness.root_widget().inner().time(), time(0, 58, 0));
+    }
+
+    #[test]
+    fn typed_digits() {
+        let picker = TimePicker::new(time(12, 0, 0)).with_seconds(true);
+        let mut harness = TestHarness::create(test_property_set(), NewWidget::new(picker));
+
+        let hour = segment_id(&harness, SegmentKind::Hour);
+        harness.focus_on(Some(hour));
+        type_key(&mut harness, Key::Character("0".into()));
+        assert_eq!(changed(&mut harness), Some(time(0, 0, 0)));
+        assert_eq!(harness.focused_widget_id(), Some(hour));
+        type_key(&mut harness, Key::Character("9".into()));
+        assert_eq!(changed(&mut harness), Some(time(9, 0, 0)));
+
+        // Two digits move the focus to the next segment.
+        let minute = segment_id(&harness, SegmentKind::Minute);
+        assert_eq!(harness.focused_widget_id(), Some(minute));
+        // A digit which can't start a two-digit value is enough.
+        type_key(&mut harness, Key::Character("7".into()));
+        assert_eq!(changed(&mut harness), Some(time(9, 7, 0)));
+        let second = segment_id(&harness, SegmentKind::Second);
+        assert_eq!(harness.focused_widget_id(), Some(second));
+        type_key(&mut harness, Key::Character("4".into()));
+        type_key(&mut harness, Key::Character("5".into()));
+        assert_eq!(harness.root_widget().inner().time(), time(9, 7, 45));
+    }
+
+    #[test]
+    fn twelve_hour_mode() {
+        let picker = TimePicker::new(time(13, 5, 0)).with_twelve_hour(true);
+        let mut harness = TestHarness::create(test_property_set(), NewWidget::new(picker));
+
+        let period = segment_id(&harness, SegmentKind::Period);
+        harness.focus_on(Some(period));
+        type_key(&mut harness, Key::Character("a".into()));
+        assert_eq!(changed(&mut harness), Some(time(1, 5, 0)));
+
+        let hour = segment_id(&harness, SegmentKind::Hour);
+        harness.focus_on(Some(hour));
+        type_key(&mut harness, Key::Character("1".into()));
+        type_key(&mut harness, Key::Character("2".into()));
+        assert_eq!(harness.root_widget().inner().time(), time(0, 5, 0));
+
+        // Switching to 24 hours removes the period segment.
+        harness.edit_root_widget(|mut picker| {
+            TimePicker::set_twelve_hour(&mut picker, false);
+            TimePicker::set_time(&mut picker, time(18, 30, 0));
+        });
+        let picker = harness.root_widget();
+        assert_eq!(picker.inner().segments.len(), 2);
+        assert_eq!(picker.inner().time(), time(18, 30, 0));
+    }
+}
@@ -0,0 +1,616 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ChildrenIds, ComposeCtx, EventCtx, FromDynWidget, LayoutCtx, MeasureCtx, NewWidget,
+    PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PointerGesture, PointerGestureEvent,
+    PointerScrollEvent, PointerUpdate, PropertiesMut, PropertiesRef, RegisterCtx, UpdateCtx,
+    Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::dpi::{LogicalPosition, PhysicalPosition};
+use crate::imaging::Painter;
+use crate::kurbo::{Affine, Axis, Point, Size, Vec2};
+use crate::layout::{LayoutSize, LenReq, Length, SizeDef};
+
+/// How much the zoom changes for each logical pixel scrolled.
+const ZOOM_PER_PIXEL: f64 = 0.0015;
+/// How fast momentum panning slows down, as a fraction of its speed per second.
+const MOMENTUM_DECAY: f64 = 4.;
+/// The speed, in logical pixels per second, below which momentum panning stops.
+const MIN_MOMENTUM_SPEED: f64 = 10.;
+
+/// A container which lets the user pan and zoom around its child.
+///
+/// The child is laid out at its max-content size, and shown scaled by the current zoom,
+/// with the `offset` point of the child at the top left of the viewport.
+/// The portal scales its child with the child's [transform](crate::core::MutateCtx::set_transform),
+/// so the child shouldn't be transformed in other ways.
+/// Pointer events are mapped through this transform, so the child's widgets can be used as usual.
+///
+/// The user can:
+/// - Drag the child around with the primary button, which keeps panning with some momentum
+///   when released. Widgets of the child which capture the pointer, like buttons,
+///   aren't dragged.
+/// - Zoom around the cursor with the mouse wheel, or by pinching a touchpad.
+///   When [wheel zoom](Self::with_wheel_zoom) is disabled, the mouse wheel pans the child,
+///   and zooms only with <kbd>Ctrl</kbd> held.
+///
+/// The zoom is kept in a range, from a tenth to ten times the size of the child by default.
+/// When the child is smaller than the viewport, it is centered in the viewport.
+///
+/// Emits [`ViewportChanged`] when the user pans or zooms.
+pub struct ZoomPortal<W: Widget + ?Sized> {
+    child: WidgetPod<W>,
+    content_size: Size,
+    viewport_size: Size,
+    zoom: f64,
+    min_zoom: f64,
+    max_zoom: f64,
+    /// The point of the child shown at the top left of the viewport, in the child's coordinates.
+    offset: Point,
+    wheel_zoom: bool,
+    /// Whether the zoom and offset should be reset to fit the child at the next layout.
+    fit_pending: bool,
+    /// The zoom applied to the child's transform.
+    applied_zoom: f64,
+    /// The last pointer position of the current drag, if any.
+    drag_position: Option<Point>,
+    /// How much the child was dragged since the last animation frame.
+    drag_delta: Vec2,
+    /// The speed of the drag or of momentum panning, in logical pixels per second.
+    velocity: Vec2,
+}
+
+/// The user panned or zoomed a [`ZoomPortal`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewportChanged {
+    /// The new zoom.
+    pub zoom: f64,
+    /// The new point of the child shown at the top left of the viewport.
+    pub offset: Point,
+}
+
+// --- MARK: BUILDERS
+impl<W: Widget + ?Sized> ZoomPortal<W> {
+    /// Creates a pan and zoom container for the given child widget.
+    pub fn new(child: NewWidget<W>) -> Self {
+        Self {
+            child: child.to_pod(),
+            content_size: Size::ZERO,
+            viewport_size: Size::ZERO,
+            zoom: 1.,
+            min_zoom: 0.1,
+            max_zoom: 10.,
+            offset: Point::ORIGIN,
+            wheel_zoom: true,
+            fit_pending: false,
+            applied_zoom: 1.,
+            drag_position: None,
+            drag_delta: Vec2::ZERO,
+            velocity: Vec2::ZERO,
+        }
+    }
+
+    /// Builder-style method to set the initial zoom.
+    pub fn with_zoom(mut self, zoom: f64) -> Self {
+        self.zoom = zoom.clamp(self.min_zoom, self.max_zoom);
+        self
+    }
+
+    /// Builder-style method to set the point of the child initially shown at the top left.
+    pub fn with_offset(mut self, offset: Point) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Builder-style method to set the range of the zoom.
+    ///
+    /// # Panics
+    ///
+    /// If `min` isn't positive, or is greater than `max`.
+    pub fn with_zoom_range(mut self, min: f64, max: f64) -> Self {
+        assert!(0. < min && min <= max, "invalid zoom range: {min} to {max}");
+        self.min_zoom = min;
+        self.max_zoom = max;
+        self.zoom = self.zoom.clamp(min, max);
+        self
+    }
+
+    /// Builder-style method to set whether the mouse wheel zooms, `true` by default.
+    ///
+    /// When `false`, the mouse wheel pans the child, and zooms only with <kbd>Ctrl</kbd> held.
+    pub fn with_wheel_zoom(mut self, wheel_zoom: bool) -> Self {
+        self.wheel_zoom = wheel_zoom;
+        self
+    }
+
+    /// Builder-style method to initially zoom so the whole child fits in the viewport.
+    pub fn with_fit_to_content(mut self) -> Self {
+        self.fit_pending = true;
+        self
+    }
+
+    /// Returns the current zoom.
+    pub fn zoom(&self) -> f64 {
+        self.zoom
+    }
+
+    /// Returns the point of the child shown at the top left of the viewport.
+    pub fn offset(&self) -> Point {
+        self.offset
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl<W: Widget + FromDynWidget + ?Sized> ZoomPortal<W> {
+    /// Replaces the child widget with a new one.
+    pub fn set_child(this: &mut WidgetMut<'_, Self>, child: NewWidget<W>) {
+        this.ctx
+            .remove_child(std::mem::replace(&mut this.widget.child, child.to_pod()));
+        // The new child doesn't have the zoom transform yet.
+        this.widget.applied_zoom = 1.;
+    }
+
+    /// Returns a mutable reference to the child widget.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Sets the zoom, keeping the same point of the child at the top left of the viewport.
+    pub fn set_zoom(this: &mut WidgetMut<'_, Self>, zoom: f64) {
+        Self::zoom_around(this, zoom, Point::ORIGIN);
+    }
+
+    /// Sets the zoom, keeping the same point of the child under `anchor`.
+    ///
+    /// `anchor` is in the portal's content-box coordinate space.
+    pub fn zoom_around(this: &mut WidgetMut<'_, Self>, zoom: f64, anchor: Point) {
+        if this.widget.zoom_around_raw(zoom, anchor) {
+            this.ctx.request_compose();
+        }
+    }
+
+    /// Sets the point of the child shown at the top left of the viewport.
+    pub fn set_offset(this: &mut WidgetMut<'_, Self>, offset: Point) {
+        let previous = this.widget.offset;
+        this.widget.offset = offset;
+        this.widget.clamp_offset();
+        if this.widget.offset != previous {
+            this.ctx.request_compose();
+        }
+    }
+
+    /// Sets the range of the zoom.
+    ///
+    /// # Panics
+    ///
+    /// If `min` isn't positive, or is greater than `max`.
+    pub fn set_zoom_range(this: &mut WidgetMut<'_, Self>, min: f64, max: f64) {
+        assert!(0. < min && min <= max, "invalid zoom range: {min} to {max}");
+        this.widget.min_zoom = min;
+        this.widget.max_zoom = max;
+        let zoom = this.widget.zoom;
+        Self::set_zoom(this, zoom);
+    }
+
+    /// Sets whether the mouse wheel zooms.
+    ///
+    /// See [`ZoomPortal::with_wheel_zoom`] for more details.
+    pub fn set_wheel_zoom(this: &mut WidgetMut<'_, Self>, wheel_zoom: bool) {
+        this.widget.wheel_zoom = wheel_zoom;
+    }
+
+    /// Zooms so the whole child fits in the viewport, and centers it.
+    ///
+    /// This happens at the next layout, once the size of the child is known.
+    pub fn fit_to_content(this: &mut WidgetMut<'_, Self>) {
+        this.widget.fit_pending = true;
+        this.widget.velocity = Vec2::ZERO;
+        this.ctx.request_layout();
+    }
+}
+
+// --- MARK: INTERNALS
+impl<W: Widget + ?Sized> ZoomPortal<W> {
+    /// Keeps the child in view, centering it on the axes where it's smaller than the viewport.
+    fn clamp_offset(&mut self) {
+        // Before the first layout, the offset is kept as is.
+        if self.viewport_size.is_zero_area() {
+            return;
+        }
+        let visible = self.viewport_size / self.zoom;
+        let clamp = |offset: f64, content: f64, visible: f64| {
+            if content <= visible {
+                (content - visible) / 2.
+            } else {
+                offset.clamp(0., content - visible)
+            }
+        };
+        self.offset = Point::new(
+            clamp(self.offset.x, self.content_size.width, visible.width),
+            clamp(self.offset.y, self.content_size.height, visible.height),
+        );
+    }
+
+    fn zoom_around_raw(&mut self, zoom: f64, anchor: Point) -> bool {
+        let previous = (self.zoom, self.offset);
+        let zoom = zoom.clamp(self.min_zoom, self.max_zoom);
+        let anchored = self.offset + anchor.to_vec2() / self.zoom;
+        self.zoom = zoom;
+        self.offset = anchored - anchor.to_vec2() / zoom;
+        self.clamp_offset();
+        (self.zoom, self.offset) != previous
+    }
+
+    /// Pans by `delta` logical pixels, with positive values moving the child to the bottom right.
+    fn pan_by_raw(&mut self, delta: Vec2) -> bool {
+        let previous = self.offset;
+        self.offset -= delta / self.zoom;
+        self.clamp_offset();
+        self.offset != previous
+    }
+
+    fn fit(&mut self) {
+        if self.content_size.is_zero_area() || self.viewport_size.is_zero_area() {
+            return;
+        }
+        let zoom = (self.viewport_size.width / self.content_size.width)
+            .min(self.viewport_size.height / self.content_size.height);
+        self.zoom = zoom.clamp(self.min_zoom, self.max_zoom);
+        // Centers the child, even if it's still larger than the viewport at the minimum zoom.
+        let visible = self.viewport_size / self.zoom;
+        self.offset = Point::new(
+            (self.content_size.width - visible.width) / 2.,
+            (self.content_size.height - visible.height) / 2.,
+        );
+        self.fit_pending = false;
+    }
+
+    fn viewport_changed(&self, ctx: &mut EventCtx<'_>) {
+        ctx.request_compose();
+        ctx.submit_action::<ViewportChanged>(ViewportChanged {
+            zoom: self.zoom,
+            offset: self.offset,
+        });
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl<W: Widget + FromDynWidget + ?Sized> Widget for ZoomPortal<W> {
+    type Action = ViewportChanged;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: None | Some(PointerButton::Primary),
+                state,
+                ..
+            }) if !ctx.is_handled() && !ctx.is_pointer_captured() => {
+                ctx.capture_pointer();
+                self.drag_position = Some(ctx.local_position(state.position));
+                self.drag_delta = Vec2::ZERO;
+                self.velocity = Vec2::ZERO;
+                ctx.request_anim_frame();
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) => {
+                let Some(last) = self.drag_position else {
+                    return;
+                };
+                let position = ctx.local_position(current.position);
+                let delta = position - last;
+                self.drag_position = Some(position);
+                self.drag_delta += delta;
+                if self.pan_by_raw(delta) {
+                    self.viewport_changed(ctx);
+                }
+                ctx.set_handled();
+            }
+            PointerEvent::Up(..) | PointerEvent::Cancel(..) if self.drag_position.is_some() => {
+                self.drag_position = None;
+                ctx.release_pointer();
+                if matches!(event, PointerEvent::Cancel(..)) {
+                    self.velocity = Vec2::ZERO;
+                }
+            }
+            PointerEvent::Scroll(PointerScrollEvent { delta, state, .. }) => {
+                let scale_factor = ctx.scale_factor();
+                let size = ctx.content_box().size();
+                let line_px = PhysicalPosition {
+                    x: 120.0 * scale_factor,
+                    y: 120.0 * scale_factor,
+                };
+                let page_px = PhysicalPosition {
+                    x: size.width * scale_factor,
+                    y: size.height * scale_factor,
+                };
+                let delta_px = delta.to_pixel_delta(line_px, page_px);
+                let LogicalPosition { x, y } = delta_px.to_logical::<f64>(scale_factor);
+
+                let changed = if self.wheel_zoom || state.modifiers.ctrl() {
+                    let anchor = ctx.local_position(state.position);
+                    let zoom = self.zoom * (y * ZOOM_PER_PIXEL).exp();
+                    self.zoom_around_raw(zoom, anchor)
+                } else {
+                    self.pan_by_raw(Vec2 { x, y })
+                };
+                if changed {
+                    self.velocity = Vec2::ZERO;
+                    self.viewport_changed(ctx);
+                    ctx.set_handled();
+                }
+            }
+            PointerEvent::Gesture(PointerGestureEvent {
+                gesture: PointerGesture::Pinch(delta),
+                state,
+                ..
+            }) => {
+                let anchor = ctx.local_position(state.position);
+                let zoom = self.zoom * (1. + f64::from(*delta));
+                if self.zoom_around_raw(zoom, anchor) {
+                    self.velocity = Vec2::ZERO;
+                    self.viewport_changed(ctx);
+                    ctx.set_handled();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        let seconds = interval as f64 * 1e-9;
+        if self.drag_position.is_some() {
+            // Samples the speed of the drag, to keep panning at that speed when it's released.
+            if seconds > 0. {
+                self.velocity = self.drag_delta / seconds;
+            }
+            self.drag_delta = Vec2::ZERO;
+            ctx.request_anim_frame();
+            return;
+        }
+        if self.velocity.hypot() < MIN_MOMENTUM_SPEED {
+            self.velocity = Vec2::ZERO;
+            return;
+        }
+        if self.pan_by_raw(self.velocity * seconds) {
+            ctx.request_compose();
+            ctx.submit_action::<ViewportChanged>(ViewportChanged {
+                zoom: self.zoom,
+                offset: self.offset,
+            });
+            self.velocity *= (-MOMENTUM_DECAY * seconds).exp();
+            ctx.request_anim_frame();
+        } else {
+            // The child reached the edge of the viewport.
+            self.velocity = Vec2::ZERO;
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        match len_req {
+            LenReq::MinContent => Length::ZERO,
+            LenReq::MaxContent => ctx.compute_length(
+                &mut self.child,
+                len_req.into(),
+                LayoutSize::maybe(axis.cross(), cross_length),
+                axis,
+                None,
+            ),
+            LenReq::FitContent(space) => space,
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let content_size = ctx.compute_size(&mut self.child, SizeDef::MAX, LayoutSize::NONE);
+        ctx.run_layout(&mut self.child, content_size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        ctx.set_clip_path(size.to_rect());
+
+        self.content_size = content_size;
+        self.viewport_size = size;
+        if self.fit_pending {
+            self.fit();
+        } else {
+            self.clamp_offset();
+        }
+    }
+
+    fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {
+        // The scroll translation is applied after the child's transform, in the portal's space.
+        ctx.set_child_scroll_translation(&mut self.child, -self.offset.to_vec2() * self.zoom);
+        if self.applied_zoom != self.zoom {
+            self.applied_zoom = self.zoom;
+            let transform = Affine::scale(self.zoom);
+            ctx.mutate_child_later(&mut self.child, move |mut child| {
+                child.ctx.set_transform(transform);
+            });
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::ScrollView
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_clips_children();
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("ZoomPortal", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::AsUnit;
+    use crate::properties::Dimensions;
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+    use crate::widgets::{Button, ButtonPress, Label};
+
+    fn label(width: f64, height: f64) -> NewWidget<Label> {
+        NewWidget::new(Label::new("Content")).with_props(Dimensions::fixed(width.px(), height.px()))
+    }
+
+    fn viewport<W: Widget + FromDynWidget + ?Sized>(
+        harness: &TestHarness<ZoomPortal<W>>,
+    ) -> (f64, Point) {
+        let portal = harness.root_widget();
+        (portal.inner().zoom(), portal.inner().offset())
+    }
+
+    #[test]
+    fn zoomed_and_offset_content() {
+        let portal = ZoomPortal::new(
+            NewWidget::new(Button::with_text("Zoomed"))
+                .with_props(Dimensions::fixed(80.px(), 40.px())),
+        )
+        .with_zoom(2.)
+        .with_offset(Point::new(20., 10.));
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(portal), (200, 200));
+
+        assert_render_snapshot!(harness, "zoom_portal_zoomed_and_offset_content");
+    }
+
+    #[test]
+    fn wheel_zooms_around_cursor() {
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            NewWidget::new(ZoomPortal::new(label(400., 300.))),
+            (200, 200),
+        );
+        harness.mouse_move((100., 60.));
+        harness.mouse_wheel(Vec2::new(0., 200.));
+
+        let (zoom, offset) = viewport(&harness);
+        assert!(zoom > 1.);
+        // The point under the cursor stays in place.
+        let anchored = offset + Vec2::new(100., 60.) / zoom;
+        assert!((anchored - Point::new(100., 60.)).hypot() < 1e-9);
+        let (action, _) = harness.pop_action::<ViewportChanged>().unwrap();
+        assert_eq!((action.zoom, action.offset), (zoom, offset));
+    }
+
+    #[test]
+    fn pinch_zooms_within_range() {
+        let portal = ZoomPortal::new(label(400., 300.)).with_zoom_range(0.5, 3.);
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(portal), (200, 200));
+        harness.mouse_move((0., 0.));
+
+        harness.pinch(1.);
+        assert_eq!(viewport(&harness), (2., Point::ORIGIN));
+        harness.pinch(1.);
+        assert_eq!(viewport(&harness).0, 3.);
+        harness.pinch(-0.9);
+        assert_eq!(viewport(&harness).0, 0.5);
+    }
+
+    #[test]
+    fn hit_testing_follows_zoom() {
+        let button = NewWidget::new(Button::with_text("Zoomed"))
+            .with_props(Dimensions::fixed(100.px(), 40.px()));
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            NewWidget::new(ZoomPortal::new(button).with_zoom(2.)),
+            (200, 200),
+        );
+        // The button is 200 by 80 pixels at this zoom, and centered vertically.
+        let offset = viewport(&harness).1;
+        assert_eq!(offset, Point::new(0., -30.));
+
+        harness.mouse_move((150., 130.));
+        harness.mouse_button_press(Some(PointerButton::Primary));
+        harness.mouse_button_release(Some(PointerButton::Primary));
+        assert!(harness.pop_action::<ButtonPress>().is_some());
+
+        harness.mouse_move((150., 50.));
+        harness.mouse_button_press(Some(PointerButton::Primary));
+        harness.mouse_button_release(Some(PointerButton::Primary));
+        assert!(harness.pop_action::<ButtonPress>().is_none());
+    }
+
+    #[test]
+    fn drag_pans_with_momentum() {
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            NewWidget::new(ZoomPortal::new(label(400., 300.))),
+            (200, 200),
+        );
+        harness.mouse_move((100., 100.));
+        harness.mouse_button_press(Some(PointerButton::Primary));
+        harness.mouse_move((80., 90.));
+        assert_eq!(viewport(&harness).1, Point::new(20., 10.));
+
+        harness.animate_ms(16);
+        harness.mouse_button_release(Some(PointerButton::Primary));
+        harness.animate_ms(16);
+        let offset = viewport(&harness).1;
+        assert!(offset.x > 20. && offset.y > 10.);
+
+        // Momentum stops at the edges of the child.
+        for _ in 0..100 {
+            harness.animate_ms(16);
+        }
+        assert_eq!(viewport(&harness).1.x, 200.);
+    }
+
+    #[test]
+    fn fit_to_content() {
+        let portal = ZoomPortal::new(label(400., 300.)).with_fit_to_content();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), NewWidget::new(portal), (200, 200));
+        assert_eq!(viewport(&harness), (0.5, Point::new(0., -50.)));
+
+        harness.edit_root_widget(|mut portal| {
+            ZoomPortal::set_zoom(&mut portal, 4.);
+            ZoomPortal::set_offset(&mut portal, Point::new(100., 100.));
+        });
+        assert_eq!(viewport(&harness), (4., Point::new(100., 100.)));
+        harness.edit_root_widget(|mut portal| ZoomPortal::fit_to_content(&mut portal));
+        assert_eq!(viewport(&harness), (0.5, Point::new(0., -50.)));
+    }
+}
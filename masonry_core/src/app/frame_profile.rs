@@ -0,0 +1,249 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::app::PassTiming;
+use crate::core::WidgetId;
+use crate::util::{Duration, Instant};
+
+/// Detailed timings of a frame, collected when frame profiling is enabled.
+///
+/// See [`RenderRoot::set_frame_profiling`](crate::app::RenderRoot::set_frame_profiling).
+#[derive(Clone, Debug, Default)]
+pub struct FrameProfile {
+    /// The time spent in each pass, in the order they first ran.
+    pub passes: Vec<PassTiming>,
+    /// The total time spent in passes during the frame.
+    pub total: Duration,
+    /// The time spent in each widget, from the most to the least expensive.
+    ///
+    /// Widgets have one entry for each pass which visited them.
+    pub widgets: Vec<WidgetTiming>,
+    events: Vec<TraceEvent>,
+}
+
+/// The time spent in a widget during a pass.
+///
+/// See [`FrameProfile::widgets`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WidgetTiming {
+    /// The id of the widget.
+    pub id: WidgetId,
+    /// The short type name of the widget.
+    pub name: &'static str,
+    /// The name of the pass, e.g. `"layout"`.
+    pub pass: &'static str,
+    /// How many times the pass visited the widget.
+    pub visits: usize,
+    /// The time spent in the widget itself, excluding the time spent in its children.
+    pub duration: Duration,
+}
+
+/// A span of time in a Chrome trace.
+#[derive(Clone, Debug)]
+struct TraceEvent {
+    name: &'static str,
+    category: &'static str,
+    widget: Option<WidgetId>,
+    /// Start time, relative to the start of the frame.
+    start: Duration,
+    duration: Duration,
+}
+
+impl FrameProfile {
+    /// Returns the timing of the pass with the given name, if it ran during the frame.
+    pub fn pass(&self, name: &str) -> Option<&PassTiming> {
+        self.passes.iter().find(|pass| pass.name == name)
+    }
+
+    /// Returns the `n` widget timings with the longest durations.
+    pub fn most_expensive_widgets(&self, n: usize) -> &[WidgetTiming] {
+        &self.widgets[..n.min(self.widgets.len())]
+    }
+
+    /// Returns the frame in the Chrome trace event format.
+    ///
+    /// The result can be loaded in `chrome://tracing` or [Perfetto](https://ui.perfetto.dev/),
+    /// and shows each pass run with the widgets it visited nested inside it.
+    pub fn to_chrome_trace(&self) -> String {
+        let mut json = String::from("{\"traceEvents\":[");
+        for (i, event) in self.events.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            let _ = write!(
+                json,
+                "\n{{\"name\":\"{}\",\"cat\":\"{}\",\"ph\":\"X\",\"ts\":{:.3},\"dur\":{:.3},\"pid\":1,\"tid\":1",
+                escape_json(event.name),
+                escape_json(event.category),
+                event.start.as_secs_f64() * 1_000_000.,
+                event.duration.as_secs_f64() * 1_000_000.,
+            );
+            if let Some(id) = event.widget {
+                let _ = write!(json, ",\"args\":{{\"widget_id\":{}}}", id.to_raw());
+            }
+            json.push('}');
+        }
+        json.push_str("\n],\"displayTimeUnit\":\"ms\"}\n");
+        json
+    }
+}
+
+fn escape_json(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", u32::from(c));
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// --- MARK: COLLECTION
+
+thread_local! {
+    /// The profiler of the pass currently running on this thread, if profiling is enabled.
+    ///
+    /// Passes don't have access to the `RenderRoot` they run in while visiting widgets,
+    /// so the `RenderRoot` lends its profiler here for the duration of each pass.
+    static ACTIVE_PROFILER: RefCell<Option<FrameProfiler>> = const { RefCell::new(None) };
+}
+
+/// The number of passes running with a profiler, on any thread.
+///
+/// This lets widget visits skip the lookup of [`ACTIVE_PROFILER`] when no pass is profiled,
+/// which is the common case.
+static PROFILED_PASSES: AtomicUsize = AtomicUsize::new(0);
+
+/// Collects a [`FrameProfile`].
+#[derive(Debug, Default)]
+pub(crate) struct FrameProfiler {
+    frame_start: Option<Instant>,
+    pass: &'static str,
+    pass_visits: usize,
+    /// The widget visits which haven't ended yet, innermost last.
+    open_visits: Vec<OpenVisit>,
+    widgets: HashMap<(WidgetId, &'static str), WidgetTiming>,
+    events: Vec<TraceEvent>,
+}
+
+#[derive(Debug)]
+struct OpenVisit {
+    start: Instant,
+    /// The time spent in the children visited so far.
+    children: Duration,
+}
+
+impl FrameProfiler {
+    /// Lends `profiler` to the widget visits of the pass `name`, which starts at `start`.
+    ///
+    /// If `profiler` is `None`, widget visits aren't profiled.
+    pub(crate) fn start_pass(profiler: Option<Self>, name: &'static str, start: Instant) {
+        let Some(mut profiler) = profiler else {
+            return;
+        };
+        profiler.frame_start.get_or_insert(start);
+        profiler.pass = name;
+        profiler.pass_visits = 0;
+        ACTIVE_PROFILER.with(|active| *active.borrow_mut() = Some(profiler));
+        PROFILED_PASSES.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Takes back the profiler lent by [`start_pass`](Self::start_pass).
+    ///
+    /// Returns the profiler and the number of widget visits during the pass.
+    pub(crate) fn end_pass(start: Instant) -> Option<(Self, usize)> {
+        let mut profiler = ACTIVE_PROFILER.with(|active| active.borrow_mut().take())?;
+        PROFILED_PASSES.fetch_sub(1, Ordering::Relaxed);
+        let frame_start = profiler.frame_start.unwrap_or(start);
+        profiler.events.push(TraceEvent {
+            name: profiler.pass,
+            category: "pass",
+            widget: None,
+            start: start.saturating_duration_since(frame_start),
+            duration: start.elapsed(),
+        });
+        profiler.open_visits.clear();
+        let visits = profiler.pass_visits;
+        Some((profiler, visits))
+    }
+
+    /// Ends the current frame, and returns its profile.
+    pub(crate) fn finish_frame(&mut self, passes: &[PassTiming]) -> FrameProfile {
+        let mut widgets: Vec<_> = self.widgets.drain().map(|(_, timing)| timing).collect();
+        widgets.sort_by_key(|widget| Reverse(widget.duration));
+        self.frame_start = None;
+        FrameProfile {
+            passes: passes.to_vec(),
+            total: passes.iter().map(|pass| pass.duration).sum(),
+            widgets,
+            events: std::mem::take(&mut self.events),
+        }
+    }
+}
+
+/// Starts timing a widget visit, if profiling is enabled.
+///
+/// Returns whether [`end_widget_visit`] must be called when the visit ends.
+pub(crate) fn start_widget_visit() -> bool {
+    if PROFILED_PASSES.load(Ordering::Relaxed) == 0 {
+        return false;
+    }
+    ACTIVE_PROFILER.with(|profiler| {
+        let Some(profiler) = &mut *profiler.borrow_mut() else {
+            return false;
+        };
+        profiler.pass_visits += 1;
+        profiler.open_visits.push(OpenVisit {
+            start: Instant::now(),
+            children: Duration::ZERO,
+        });
+        true
+    })
+}
+
+/// Ends the innermost widget visit started with [`start_widget_visit`].
+pub(crate) fn end_widget_visit(id: WidgetId, name: &'static str) {
+    ACTIVE_PROFILER.with(|profiler| {
+        let Some(profiler) = &mut *profiler.borrow_mut() else {
+            return;
+        };
+        let Some(visit) = profiler.open_visits.pop() else {
+            return;
+        };
+        let duration = visit.start.elapsed();
+        if let Some(parent) = profiler.open_visits.last_mut() {
+            parent.children += duration;
+        }
+
+        let pass = profiler.pass;
+        let timing = profiler.widgets.entry((id, pass)).or_insert(WidgetTiming {
+            id,
+            name,
+            pass,
+            visits: 0,
+            duration: Duration::ZERO,
+        });
+        timing.visits += 1;
+        timing.duration += duration.saturating_sub(visit.children);
+
+        let frame_start = profiler.frame_start.unwrap_or(visit.start);
+        profiler.events.push(TraceEvent {
+            name,
+            category: pass,
+            widget: Some(id),
+            start: visit.start.saturating_duration_since(frame_start),
+            duration,
+        });
+    });
+}
@@ -0,0 +1,559 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A protocol to inspect and edit a widget tree from another process.
+//!
+//! The protocol is made of line-delimited JSON messages: each line sent by the client
+//! is an [`InspectorRequest`], and the app answers each request with exactly one line
+//! containing an [`InspectorResponse`].
+//!
+//! Requests are applied with [`RenderRoot::handle_inspector_request`].
+//! On native platforms, [`InspectorServer`] reads requests from a TCP socket or from stdin
+//! and writes responses back; the app is expected to call [`InspectorServer::poll`]
+//! from its event loop whenever the server wakes it up.
+//!
+//! # Security
+//!
+//! Clients aren't authenticated: anyone who can connect to the server can read the widget tree,
+//! including the text it displays, and change it.
+//! The TCP server only listens on the loopback interface, so only processes running on
+//! the same machine can connect, but it should still only be enabled while debugging.
+//!
+//! Examples of requests:
+//!
+//! ```json
+//! {"command":"get_tree"}
+//! {"command":"get_timings"}
+//! {"command":"highlight","widget":12}
+//! {"command":"set_disabled","widget":12,"disabled":true}
+//! {"command":"set_property","widget":12,"property":"Padding","value":8.0}
+//! {"command":"set_property","widget":12,"property":"Background","value":"#ff000080"}
+//! ```
+
+use std::collections::HashMap;
+use std::num::NonZeroU64;
+
+use accesskit::{Action, Node};
+use serde::{Deserialize, Serialize};
+
+use crate::app::{RenderRoot, RenderRootSignal};
+use crate::core::{Widget, WidgetId, WidgetRef};
+use crate::kurbo::Rect;
+use crate::layout::Length;
+use crate::passes::accessibility::run_accessibility_pass;
+use crate::peniko::color::{AlphaColor, Srgb, parse_color};
+use crate::properties::{
+    Background, BorderColor, BorderWidth, BoxShadow, CornerRadius, Dimensions, Padding,
+};
+
+/// A command sent by an inspector client.
+///
+/// Widgets are referred to by the raw value of their [`WidgetId`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum InspectorRequest {
+    /// Returns the whole widget tree, including all layers.
+    GetTree,
+    /// Returns the time spent in each pass during the last frame.
+    GetTimings,
+    /// Highlights the given widget, or removes the highlight if `None`.
+    Highlight {
+        /// The widget to highlight.
+        widget: Option<u64>,
+    },
+    /// Disables or re-enables the given widget.
+    SetDisabled {
+        /// The widget to change.
+        widget: u64,
+        /// Whether the widget should be disabled.
+        disabled: bool,
+    },
+    /// Sets a core property on the given widget.
+    ///
+    /// `property` is the type name of the property, as listed in [`WidgetNode::properties`].
+    /// The accepted values are:
+    /// - `Padding`: a number for all sides, or an object with `left`, `right`, `top` and `bottom`.
+    /// - `BorderWidth` and `CornerRadius`: a number.
+    /// - `Dimensions`: a number for both axes, or a `[width, height]` array.
+    /// - `Background` and `BorderColor`: a CSS color string.
+    SetProperty {
+        /// The widget to change.
+        widget: u64,
+        /// The type name of the property.
+        property: String,
+        /// The new value of the property.
+        value: serde_json::Value,
+    },
+}
+
+/// The answer to an [`InspectorRequest`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "response", rename_all = "snake_case")]
+pub enum InspectorResponse {
+    /// The answer to [`InspectorRequest::GetTree`].
+    Tree {
+        /// The root of the widget tree.
+        root: WidgetNode,
+    },
+    /// The answer to [`InspectorRequest::GetTimings`].
+    Timings {
+        /// The passes which ran during the last frame, in the order they first ran.
+        passes: Vec<PassReport>,
+    },
+    /// The request was applied.
+    Done,
+    /// The request couldn't be applied.
+    Error {
+        /// A description of the error.
+        message: String,
+    },
+}
+
+/// A serialized widget and its descendants.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WidgetNode {
+    /// The raw value of the widget's [`WidgetId`].
+    pub id: u64,
+    /// The short type name of the widget.
+    pub name: String,
+    /// The widget's border-box, as a `[x0, y0, x1, y1]` bounding box in window coordinates.
+    pub border_box: [f64; 4],
+    /// The widget's content-box, as a `[x0, y0, x1, y1]` bounding box in window coordinates.
+    pub content_box: [f64; 4],
+    /// Whether the widget is stashed.
+    pub stashed: bool,
+    /// Whether the widget is disabled, either explicitly or because of an ancestor.
+    pub disabled: bool,
+    /// The classes set on the widget, sorted alphabetically.
+    pub classes: Vec<String>,
+    /// The values of the core properties, as `(type name, debug representation)` pairs.
+    pub properties: Vec<(String, String)>,
+    /// The widget's accessibility node, if it's in the accessibility tree.
+    pub accessibility: Option<AccessibilityNode>,
+    /// The widget's debug text, if any.
+    pub debug_text: Option<String>,
+    /// The widget's children.
+    pub children: Vec<Self>,
+}
+
+/// The accessibility node of a widget, as built by [`Widget::accessibility`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AccessibilityNode {
+    /// The accessibility role, e.g. `"Button"`.
+    pub role: String,
+    /// The label read by assistive technologies.
+    pub label: Option<String>,
+    /// The value of the widget, e.g. the text of a text input.
+    pub value: Option<String>,
+    /// The longer description of the widget.
+    pub description: Option<String>,
+    /// The states set on the node, e.g. `"disabled"`, `"focusable"` or `"expanded"`.
+    pub states: Vec<String>,
+}
+
+/// The time spent in a pass, see [`PassTiming`](crate::app::PassTiming).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PassReport {
+    /// The name of the pass.
+    pub name: String,
+    /// The time spent in the pass, in microseconds.
+    pub micros: f64,
+}
+
+// --- MARK: REQUESTS
+
+impl RenderRoot {
+    /// Applies a request from an out-of-process inspector and returns the response.
+    ///
+    /// See the [`inspector`](crate::app::inspector) module for the protocol.
+    ///
+    /// This enables [frame timing](Self::set_frame_timing), so that the frames painted after
+    /// the first request can be reported by [`InspectorRequest::GetTimings`].
+    pub fn handle_inspector_request(&mut self, request: InspectorRequest) -> InspectorResponse {
+        self.set_frame_timing(true);
+        self.apply_inspector_request(request)
+            .unwrap_or_else(|message| InspectorResponse::Error { message })
+    }
+
+    /// Builds the accessibility node of every widget, keyed by the raw widget id.
+    ///
+    /// The accessibility pass only rebuilds the nodes of widgets which changed, so this
+    /// rebuilds all of them, then requests a full rebuild for the next frame so that
+    /// the platform's accessibility tree doesn't miss any update.
+    fn build_access_nodes(&mut self) -> HashMap<u64, Node> {
+        self.request_access_all();
+        let update = run_accessibility_pass(self, self.global_state.scale_factor);
+        self.request_access_all();
+        update
+            .nodes
+            .into_iter()
+            .map(|(id, node)| (id.0, node))
+            .collect()
+    }
+
+    fn apply_inspector_request(
+        &mut self,
+        request: InspectorRequest,
+    ) -> Result<InspectorResponse, String> {
+        match request {
+            InspectorRequest::GetTree => {
+                let access_nodes = self.build_access_nodes();
+                let root = self
+                    .get_widget(self.root_id())
+                    .expect("root widget not in widget tree");
+                return Ok(InspectorResponse::Tree {
+                    root: WidgetNode::new(root, &access_nodes),
+                });
+            }
+            InspectorRequest::GetTimings => {
+                let passes = self
+                    .last_frame_timings()
+                    .iter()
+                    .map(|timing| PassReport {
+                        name: timing.name.to_string(),
+                        micros: timing.duration.as_secs_f64() * 1_000_000.,
+                    })
+                    .collect();
+                return Ok(InspectorResponse::Timings { passes });
+            }
+            InspectorRequest::Highlight { widget } => {
+                let id = widget
+                    .map(|raw| self.find_inspected_widget(raw))
+                    .transpose()?;
+                self.global_state.inspector_state.hovered_widget = id;
+                self.root_state_mut().needs_paint = true;
+                self.global_state
+                    .emit_signal(RenderRootSignal::RequestRedraw);
+            }
+            InspectorRequest::SetDisabled { widget, disabled } => {
+                let id = self.find_inspected_widget(widget)?;
+                self.edit_widget(id, |mut widget| widget.ctx.set_disabled(disabled));
+            }
+            InspectorRequest::SetProperty {
+                widget,
+                property,
+                value,
+            } => {
+                let id = self.find_inspected_widget(widget)?;
+                self.set_inspected_property(id, &property, &value)?;
+            }
+        }
+        Ok(InspectorResponse::Done)
+    }
+
+    fn find_inspected_widget(&self, raw: u64) -> Result<WidgetId, String> {
+        NonZeroU64::new(raw)
+            .map(WidgetId)
+            .filter(|id| self.widget_arena.has(*id))
+            .ok_or_else(|| format!("no widget with id {raw}"))
+    }
+
+    fn set_inspected_property(
+        &mut self,
+        id: WidgetId,
+        property: &str,
+        value: &serde_json::Value,
+    ) -> Result<(), String> {
+        match property {
+            "Padding" => {
+                let padding = if let Some(object) = value.as_object() {
+                    let side = |name: &str| match object.get(name) {
+                        Some(value) => parse_length(value),
+                        None => Ok(Length::ZERO),
+                    };
+                    Padding {
+                        left: side("left")?,
+                        right: side("right")?,
+                        top: side("top")?,
+                        bottom: side("bottom")?,
+                    }
+                } else {
+                    Padding::all(parse_length(value)?)
+                };
+                self.edit_widget(id, |mut widget| widget.insert_prop(padding));
+            }
+            "BorderWidth" => {
+                let border_width = BorderWidth::all(parse_length(value)?);
+                self.edit_widget(id, |mut widget| widget.insert_prop(border_width));
+            }
+            "CornerRadius" => {
+                let corner_radius = CornerRadius::all(parse_length(value)?);
+                self.edit_widget(id, |mut widget| widget.insert_prop(corner_radius));
+            }
+            "Dimensions" => {
+                let dimensions = match value.as_array().map(Vec::as_slice) {
+                    Some([width, height]) => {
+                        Dimensions::fixed(parse_length(width)?, parse_length(height)?)
+                    }
+                    Some(_) => return Err("expected a [width, height] array".into()),
+                    None => {
+                        let length = parse_length(value)?;
+                        Dimensions::fixed(length, length)
+                    }
+                };
+                self.edit_widget(id, |mut widget| widget.insert_prop(dimensions));
+            }
+            "Background" => {
+                let background = Background::Color(parse_css_color(value)?);
+                self.edit_widget(id, |mut widget| widget.insert_prop(background));
+            }
+            "BorderColor" => {
+                let border_color = BorderColor::new(parse_css_color(value)?);
+                self.edit_widget(id, |mut widget| widget.insert_prop(border_color));
+            }
+            _ => return Err(format!("property '{property}' can't be edited")),
+        }
+        Ok(())
+    }
+}
+
+fn parse_length(value: &serde_json::Value) -> Result<Length, String> {
+    match value.as_f64() {
+        Some(value) if value >= 0. && value.is_finite() => Ok(Length::px(value)),
+        _ => Err(format!("expected a non-negative length, got {value}")),
+    }
+}
+
+fn parse_css_color(value: &serde_json::Value) -> Result<AlphaColor<Srgb>, String> {
+    let text = value
+        .as_str()
+        .ok_or_else(|| format!("expected a color string, got {value}"))?;
+    parse_color(text)
+        .map(|color| color.to_alpha_color())
+        .map_err(|err| format!("invalid color '{text}': {err}"))
+}
+
+// --- MARK: TREE
+
+impl WidgetNode {
+    fn new(widget: WidgetRef<'_, dyn Widget>, access_nodes: &HashMap<u64, Node>) -> Self {
+        let state = widget.ctx().widget_state;
+        let props = &widget.ctx().properties;
+        let cache = &state.property_cache;
+        let to_window = |rect: Rect| {
+            let rect = state.window_transform.transform_rect_bbox(rect);
+            [rect.x0, rect.y0, rect.x1, rect.y1]
+        };
+
+        let mut classes: Vec<_> = props.class_set.classes.iter().cloned().collect();
+        classes.sort();
+
+        let properties = vec![
+            debug_prop(props.get_without_saving::<Dimensions>(cache)),
+            debug_prop(props.get_without_saving::<Padding>(cache)),
+            debug_prop(props.get_without_saving::<BorderWidth>(cache)),
+            debug_prop(props.get_without_saving::<BorderColor>(cache)),
+            debug_prop(props.get_without_saving::<CornerRadius>(cache)),
+            debug_prop(props.get_without_saving::<Background>(cache)),
+            debug_prop(props.get_without_saving::<BoxShadow>(cache)),
+        ];
+
+        Self {
+            id: widget.id().to_raw(),
+            name: state.widget_name.to_string(),
+            border_box: to_window(state.border_box()),
+            content_box: to_window(state.content_box()),
+            stashed: state.is_stashed,
+            disabled: state.is_disabled,
+            classes,
+            properties,
+            accessibility: access_nodes
+                .get(&widget.id().to_raw())
+                .map(AccessibilityNode::new),
+            debug_text: widget.get_debug_text(),
+            children: widget
+                .children()
+                .into_iter()
+                .map(|child| Self::new(child, access_nodes))
+                .collect(),
+        }
+    }
+}
+
+impl AccessibilityNode {
+    fn new(node: &Node) -> Self {
+        let flags = [
+            ("disabled", node.is_disabled()),
+            ("hidden", node.is_hidden()),
+            ("read_only", node.is_read_only()),
+            ("required", node.is_required()),
+            ("busy", node.is_busy()),
+            ("modal", node.is_modal()),
+            ("focusable", node.supports_action(Action::Focus)),
+            ("expanded", node.is_expanded() == Some(true)),
+            ("collapsed", node.is_expanded() == Some(false)),
+            ("selected", node.is_selected() == Some(true)),
+            ("invalid", node.invalid().is_some()),
+        ];
+        let mut states: Vec<_> = flags
+            .into_iter()
+            .filter(|(_, set)| *set)
+            .map(|(state, _)| state.to_string())
+            .collect();
+        if let Some(toggled) = node.toggled() {
+            states.push(format!("toggled_{toggled:?}").to_lowercase());
+        }
+
+        Self {
+            role: format!("{:?}", node.role()),
+            label: node.label().map(str::to_string),
+            value: node.value().map(str::to_string),
+            description: node.description().map(str::to_string),
+            states,
+        }
+    }
+}
+
+fn debug_prop<P: std::fmt::Debug>(value: &P) -> (String, String) {
+    let name = std::any::type_name::<P>();
+    let name = name.rsplit("::").next().unwrap_or(name);
+    (name.to_string(), format!("{value:?}"))
+}
+
+// --- MARK: SERVER
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use server::InspectorServer;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod server {
+    use std::io::{self, BufRead, BufReader, Write};
+    use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+    use std::sync::mpsc::{Receiver, Sender, channel};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    use tracing::warn;
+
+    use super::InspectorResponse;
+    use crate::app::RenderRoot;
+
+    type Reply = Arc<Mutex<dyn Write + Send>>;
+
+    /// Receives [`InspectorRequest`](super::InspectorRequest)s from another process and sends back the responses.
+    ///
+    /// Requests are read on background threads.
+    /// Each time a request arrives, the `wake` callback given on creation is called;
+    /// the event loop should then call [`poll`](Self::poll) with the inspected [`RenderRoot`].
+    pub struct InspectorServer {
+        requests: Receiver<(String, Reply)>,
+        local_addr: Option<SocketAddr>,
+    }
+
+    impl std::fmt::Debug for InspectorServer {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("InspectorServer")
+                .field("local_addr", &self.local_addr)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl InspectorServer {
+        /// Listens for inspector clients on the given TCP port of the loopback interface.
+        ///
+        /// Any number of clients can be connected at the same time.
+        /// Clients aren't authenticated, so the server never listens on other interfaces;
+        /// see the [module documentation](super#security).
+        /// With a `port` of 0, the OS picks a free port, which [`local_addr`](Self::local_addr) returns.
+        pub fn bind_tcp(port: u16, wake: impl Fn() + Send + Sync + 'static) -> io::Result<Self> {
+            let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+            let local_addr = listener.local_addr()?;
+            let (sender, requests) = channel();
+            let wake = Arc::new(wake);
+
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let stream = match stream.and_then(|stream| Ok((stream.try_clone()?, stream))) {
+                        Ok(streams) => streams,
+                        Err(err) => {
+                            warn!("failed to accept inspector client: {err}");
+                            continue;
+                        }
+                    };
+                    let (reader, writer): (TcpStream, TcpStream) = stream;
+                    let sender = sender.clone();
+                    let wake = wake.clone();
+                    thread::spawn(move || {
+                        read_requests(reader, Arc::new(Mutex::new(writer)), &sender, &*wake);
+                    });
+                }
+            });
+
+            Ok(Self {
+                requests,
+                local_addr: Some(local_addr),
+            })
+        }
+
+        /// Reads requests from the process's stdin and writes responses to its stdout.
+        ///
+        /// This is meant for apps started by the inspector client itself.
+        pub fn stdio(wake: impl Fn() + Send + Sync + 'static) -> Self {
+            let (sender, requests) = channel();
+            thread::spawn(move || {
+                read_requests(
+                    io::stdin(),
+                    Arc::new(Mutex::new(io::stdout())),
+                    &sender,
+                    &wake,
+                );
+            });
+            Self {
+                requests,
+                local_addr: None,
+            }
+        }
+
+        /// Returns the address the server listens on, if it uses TCP.
+        pub fn local_addr(&self) -> Option<SocketAddr> {
+            self.local_addr
+        }
+
+        /// Applies all the pending requests to `root` and sends back their responses.
+        ///
+        /// Returns `true` if any request was handled.
+        pub fn poll(&self, root: &mut RenderRoot) -> bool {
+            let mut handled = false;
+            while let Ok((line, reply)) = self.requests.try_recv() {
+                handled = true;
+                let response = match serde_json::from_str(&line) {
+                    Ok(request) => root.handle_inspector_request(request),
+                    Err(err) => InspectorResponse::Error {
+                        message: format!("invalid request: {err}"),
+                    },
+                };
+                let mut response =
+                    serde_json::to_string(&response).expect("inspector responses are serializable");
+                response.push('\n');
+                let mut reply = reply.lock().unwrap();
+                if let Err(err) = reply
+                    .write_all(response.as_bytes())
+                    .and_then(|()| reply.flush())
+                {
+                    warn!("failed to send inspector response: {err}");
+                }
+            }
+            handled
+        }
+    }
+
+    fn read_requests(
+        reader: impl io::Read,
+        reply: Reply,
+        sender: &Sender<(String, Reply)>,
+        wake: &(dyn Fn() + Send + Sync),
+    ) {
+        for line in BufReader::new(reader).lines() {
+            let Ok(line) = line else {
+                return;
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            if sender.send((line, reply.clone())).is_err() {
+                return;
+            }
+            wake();
+        }
+    }
+}
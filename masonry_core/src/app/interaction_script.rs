@@ -0,0 +1,424 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Scripts of user input, recorded from a running app and replayed in tests.
+//!
+//! An [`InteractionScript`] is a list of input events, each with the time at which it happened,
+//! stored as JSON so that it can be written and edited without writing Rust:
+//!
+//! ```json
+//! {"steps":[
+//!   {"time_ms":0,"event":"pointer_move","x":20.0,"y":15.0},
+//!   {"time_ms":120,"event":"pointer_down","button":"primary"},
+//!   {"time_ms":180,"event":"pointer_up","button":"primary"},
+//!   {"time_ms":400,"event":"text","text":"hello"},
+//!   {"time_ms":450,"event":"key_down","key":"Enter","modifiers":["shift"]},
+//!   {"time_ms":900,"event":"snapshot","name":"after_typing"}
+//! ]}
+//! ```
+//!
+//! Scripts are recorded with a [`ScriptRecorder`]; Masonry Winit records one when the
+//! `MASONRY_RECORD_SCRIPT` environment variable is set.
+//! Masonry Testing replays them in its test harness, advancing animations by the time between
+//! steps and checking a screenshot at each [`ScriptEvent::Snapshot`] step.
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::keyboard::{Key, KeyState, Modifiers};
+use crate::core::{
+    Ime, KeyboardEvent, PointerButton, PointerButtonEvent, PointerEvent, PointerScrollEvent,
+    ScrollDelta, TextEvent,
+};
+use crate::dpi::PhysicalSize;
+use crate::util::Instant;
+
+/// A list of input events with timestamps.
+///
+/// See the [module documentation](self) for the JSON format.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct InteractionScript {
+    /// The steps of the script, in the order they happened.
+    pub steps: Vec<ScriptStep>,
+}
+
+/// An event in an [`InteractionScript`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScriptStep {
+    /// The time of the event, in milliseconds since the start of the script.
+    pub time_ms: u64,
+    /// The event.
+    #[serde(flatten)]
+    pub event: ScriptEvent,
+}
+
+/// An input event, or a point at which to take a snapshot.
+///
+/// Positions and sizes are in physical pixels.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ScriptEvent {
+    /// The mouse moved.
+    PointerMove {
+        /// The horizontal position of the pointer.
+        x: f64,
+        /// The vertical position of the pointer.
+        y: f64,
+    },
+    /// A mouse button was pressed.
+    PointerDown {
+        /// The button, if any.
+        button: Option<ScriptButton>,
+    },
+    /// A mouse button was released.
+    PointerUp {
+        /// The button, if any.
+        button: Option<ScriptButton>,
+    },
+    /// The mouse left the window.
+    PointerLeave,
+    /// The mouse wheel or touchpad scrolled.
+    Scroll {
+        /// The horizontal scroll amount.
+        x: f64,
+        /// The vertical scroll amount.
+        y: f64,
+        /// The unit of `x` and `y`.
+        unit: ScrollUnit,
+    },
+    /// A key was pressed.
+    KeyDown {
+        /// The key, as named in the [W3C UI Events] specification, e.g. `"a"` or `"Enter"`.
+        ///
+        /// [W3C UI Events]: https://w3c.github.io/uievents-key/
+        key: String,
+        /// The modifiers held at the time.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        modifiers: Vec<ScriptModifier>,
+    },
+    /// A key was released.
+    KeyUp {
+        /// The key, as named in the [W3C UI Events] specification, e.g. `"a"` or `"Enter"`.
+        ///
+        /// [W3C UI Events]: https://w3c.github.io/uievents-key/
+        key: String,
+        /// The modifiers held at the time.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        modifiers: Vec<ScriptModifier>,
+    },
+    /// Text was committed by the input method.
+    Text {
+        /// The committed text.
+        text: String,
+    },
+    /// Text was pasted from the clipboard.
+    Paste {
+        /// The pasted text.
+        text: String,
+    },
+    /// The window was resized.
+    Resize {
+        /// The new width of the window.
+        width: u32,
+        /// The new height of the window.
+        height: u32,
+    },
+    /// The window took or lost focus.
+    WindowFocus {
+        /// Whether the window has focus.
+        focused: bool,
+    },
+    /// A point at which the replay should check a screenshot of the window.
+    Snapshot {
+        /// The name of the screenshot.
+        name: String,
+    },
+}
+
+/// A mouse button in an [`InteractionScript`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScriptButton {
+    /// The primary button, usually the left button.
+    Primary,
+    /// The secondary button, usually the right button.
+    Secondary,
+    /// The auxiliary button, usually the wheel button.
+    Auxiliary,
+    /// The "back" button.
+    Back,
+    /// The "forward" button.
+    Forward,
+}
+
+/// The unit of a [`ScriptEvent::Scroll`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScrollUnit {
+    /// Physical pixels.
+    Pixels,
+    /// Lines of text.
+    Lines,
+    /// Pages.
+    Pages,
+}
+
+/// A keyboard modifier in an [`InteractionScript`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScriptModifier {
+    /// The Shift key.
+    Shift,
+    /// The Control key.
+    Control,
+    /// The Alt key, or Option on macOS.
+    Alt,
+    /// The Meta key, i.e. Command on macOS and the Windows key on Windows.
+    Meta,
+}
+
+// --- MARK: CONVERSIONS
+
+impl From<ScriptButton> for PointerButton {
+    fn from(button: ScriptButton) -> Self {
+        match button {
+            ScriptButton::Primary => Self::Primary,
+            ScriptButton::Secondary => Self::Secondary,
+            ScriptButton::Auxiliary => Self::Auxiliary,
+            ScriptButton::Back => Self::X1,
+            ScriptButton::Forward => Self::X2,
+        }
+    }
+}
+
+impl ScriptButton {
+    /// Returns the script button for `button`, if there is one.
+    pub fn from_pointer_button(button: PointerButton) -> Option<Self> {
+        match button {
+            PointerButton::Primary => Some(Self::Primary),
+            PointerButton::Secondary => Some(Self::Secondary),
+            PointerButton::Auxiliary => Some(Self::Auxiliary),
+            PointerButton::X1 => Some(Self::Back),
+            PointerButton::X2 => Some(Self::Forward),
+            _ => None,
+        }
+    }
+}
+
+impl ScrollUnit {
+    /// Returns the scroll delta of `x` and `y` in this unit.
+    pub fn delta(self, x: f64, y: f64) -> ScrollDelta {
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "line and page deltas are stored as f32"
+        )]
+        let (x32, y32) = (x as f32, y as f32);
+        match self {
+            Self::Pixels => ScrollDelta::PixelDelta((x, y).into()),
+            Self::Lines => ScrollDelta::LineDelta(x32, y32),
+            Self::Pages => ScrollDelta::PageDelta(x32, y32),
+        }
+    }
+}
+
+fn script_modifiers(modifiers: Modifiers) -> Vec<ScriptModifier> {
+    [
+        (Modifiers::SHIFT, ScriptModifier::Shift),
+        (Modifiers::CONTROL, ScriptModifier::Control),
+        (Modifiers::ALT, ScriptModifier::Alt),
+        (Modifiers::META, ScriptModifier::Meta),
+    ]
+    .into_iter()
+    .filter(|(modifier, _)| modifiers.contains(*modifier))
+    .map(|(_, modifier)| modifier)
+    .collect()
+}
+
+fn keyboard_modifiers(modifiers: &[ScriptModifier]) -> Modifiers {
+    modifiers
+        .iter()
+        .map(|modifier| match modifier {
+            ScriptModifier::Shift => Modifiers::SHIFT,
+            ScriptModifier::Control => Modifiers::CONTROL,
+            ScriptModifier::Alt => Modifiers::ALT,
+            ScriptModifier::Meta => Modifiers::META,
+        })
+        .fold(Modifiers::empty(), |all, modifier| all | modifier)
+}
+
+impl ScriptEvent {
+    /// Returns the script event for `event`, if it can be recorded.
+    ///
+    /// Only the events of the primary pointer are recorded.
+    /// Gestures and pointer cancellations aren't recorded.
+    pub fn from_pointer_event(event: &PointerEvent) -> Option<Self> {
+        if !event.is_primary_pointer() {
+            return None;
+        }
+        let button = |button: Option<PointerButton>| match button {
+            Some(button) => ScriptButton::from_pointer_button(button).map(Some),
+            None => Some(None),
+        };
+        match event {
+            PointerEvent::Move(update) => Some(Self::PointerMove {
+                x: update.current.position.x,
+                y: update.current.position.y,
+            }),
+            PointerEvent::Down(PointerButtonEvent { button: b, .. }) => Some(Self::PointerDown {
+                button: button(*b)?,
+            }),
+            PointerEvent::Up(PointerButtonEvent { button: b, .. }) => Some(Self::PointerUp {
+                button: button(*b)?,
+            }),
+            PointerEvent::Leave(_) => Some(Self::PointerLeave),
+            PointerEvent::Scroll(PointerScrollEvent { delta, .. }) => {
+                let (x, y, unit) = match *delta {
+                    ScrollDelta::PixelDelta(delta) => (delta.x, delta.y, ScrollUnit::Pixels),
+                    ScrollDelta::LineDelta(x, y) => (x.into(), y.into(), ScrollUnit::Lines),
+                    ScrollDelta::PageDelta(x, y) => (x.into(), y.into(), ScrollUnit::Pages),
+                };
+                Some(Self::Scroll { x, y, unit })
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the script event for `event`, if it can be recorded.
+    ///
+    /// Only committed IME text is recorded, not intermediate preedit states.
+    pub fn from_text_event(event: &TextEvent) -> Option<Self> {
+        match event {
+            TextEvent::Keyboard(event) => {
+                let key = event.key.to_string();
+                let modifiers = script_modifiers(event.modifiers);
+                Some(match event.state {
+                    KeyState::Down => Self::KeyDown { key, modifiers },
+                    KeyState::Up => Self::KeyUp { key, modifiers },
+                })
+            }
+            TextEvent::Ime(Ime::Commit(text)) => Some(Self::Text { text: text.clone() }),
+            TextEvent::Ime(_) => None,
+            TextEvent::WindowFocusChange(focused) => Some(Self::WindowFocus { focused: *focused }),
+            TextEvent::ClipboardPaste(text) => Some(Self::Paste { text: text.clone() }),
+        }
+    }
+
+    /// Returns the text event this script event stands for, if any.
+    ///
+    /// Returns `None` for pointer, window and snapshot events, and for keys whose name isn't
+    /// recognized.
+    pub fn to_text_event(&self) -> Option<TextEvent> {
+        let keyboard_event = |key: &str, modifiers: &[ScriptModifier], state| {
+            let key = key.parse::<Key>().ok()?;
+            Some(TextEvent::Keyboard(KeyboardEvent {
+                state,
+                key,
+                modifiers: keyboard_modifiers(modifiers),
+                ..KeyboardEvent::default()
+            }))
+        };
+        match self {
+            Self::KeyDown { key, modifiers } => keyboard_event(key, modifiers, KeyState::Down),
+            Self::KeyUp { key, modifiers } => keyboard_event(key, modifiers, KeyState::Up),
+            Self::Text { text } => Some(TextEvent::Ime(Ime::Commit(text.clone()))),
+            Self::Paste { text } => Some(TextEvent::ClipboardPaste(text.clone())),
+            Self::WindowFocus { focused } => Some(TextEvent::WindowFocusChange(*focused)),
+            _ => None,
+        }
+    }
+}
+
+impl InteractionScript {
+    /// Parses a script from its JSON representation.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns the JSON representation of the script, with one step per line.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\"steps\":[");
+        for (i, step) in self.steps.iter().enumerate() {
+            json.push_str(if i == 0 { "\n  " } else { ",\n  " });
+            json.push_str(&serde_json::to_string(step).expect("script steps always serialize"));
+        }
+        json.push_str("\n]}\n");
+        json
+    }
+}
+
+// --- MARK: RECORDER
+
+/// Records input events into an [`InteractionScript`].
+///
+/// Each event is stamped with the time elapsed since the recorder was created.
+#[derive(Debug)]
+pub struct ScriptRecorder {
+    start: Instant,
+    script: InteractionScript,
+    snapshot_count: usize,
+}
+
+impl ScriptRecorder {
+    /// Creates a recorder, starting its clock.
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            script: InteractionScript::default(),
+            snapshot_count: 0,
+        }
+    }
+
+    /// Adds `event` to the script.
+    pub fn record(&mut self, event: ScriptEvent) {
+        let time_ms = u64::try_from(self.start.elapsed().as_millis()).unwrap_or(u64::MAX);
+        self.script.steps.push(ScriptStep { time_ms, event });
+    }
+
+    /// Adds `event` to the script, if it can be recorded.
+    ///
+    /// See [`ScriptEvent::from_pointer_event`].
+    pub fn record_pointer_event(&mut self, event: &PointerEvent) {
+        if let Some(event) = ScriptEvent::from_pointer_event(event) {
+            self.record(event);
+        }
+    }
+
+    /// Adds `event` to the script, if it can be recorded.
+    ///
+    /// See [`ScriptEvent::from_text_event`].
+    pub fn record_text_event(&mut self, event: &TextEvent) {
+        if let Some(event) = ScriptEvent::from_text_event(event) {
+            self.record(event);
+        }
+    }
+
+    /// Records that the window was resized to `size`.
+    pub fn record_resize(&mut self, size: PhysicalSize<u32>) {
+        self.record(ScriptEvent::Resize {
+            width: size.width,
+            height: size.height,
+        });
+    }
+
+    /// Adds a [`ScriptEvent::Snapshot`] step, named `snapshot_1`, `snapshot_2`, and so on.
+    pub fn mark_snapshot(&mut self) {
+        self.snapshot_count += 1;
+        let name = format!("snapshot_{}", self.snapshot_count);
+        self.record(ScriptEvent::Snapshot { name });
+    }
+
+    /// Returns the script recorded so far.
+    pub fn script(&self) -> &InteractionScript {
+        &self.script
+    }
+
+    /// Stops recording and returns the script.
+    pub fn finish(self) -> InteractionScript {
+        self.script
+    }
+}
+
+impl Default for ScriptRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -12,7 +12,7 @@ use crate::core::{
     RegisterCtx, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
 };
 use crate::imaging::Painter;
-use crate::layout::{LenReq, Length, SizeDef};
+use crate::layout::{Anchor, LenReq, Length, SizeDef};
 
 /// A widget representing the top-level stack of visible layers owned by [`RenderRoot`](crate::app::RenderRoot).
 ///
@@ -41,6 +41,8 @@ struct LayerEntry {
     root: WidgetPod<dyn Widget>,
     /// Position of this layer entry in the `LayerStack`'s content-box coordinates.
     position: Point,
+    /// Anchor overriding `position` while its target is in the tree.
+    anchor: Option<Anchor>,
 }
 
 // --- MARK: IMPL LAYER_STACK
@@ -50,6 +52,7 @@ impl LayerStack {
         let layer = LayerEntry {
             root: root.erased().to_pod(),
             position: Point::ZERO,
+            anchor: None,
         };
         Self {
             layers: vec![layer],
@@ -86,6 +89,7 @@ impl LayerStack {
         let layer = LayerEntry {
             root: root.erased().to_pod(),
             position,
+            anchor: None,
         };
         this.widget.layers.push(layer);
         this.ctx.children_changed();
@@ -160,6 +164,37 @@ impl LayerStack {
             }
         }
     }
+
+    /// Anchors the layer with the given widget as root to another widget.
+    ///
+    /// While the anchor's target is in the tree, the layer follows it
+    /// and its position is ignored. Passing `None` removes the anchor.
+    ///
+    /// The base layer cannot be anchored.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug mode if the intended layer is the base layer or
+    /// is not found.
+    pub(crate) fn anchor_layer(
+        this: &mut WidgetMut<'_, Self>,
+        root_id: WidgetId,
+        anchor: Option<Anchor>,
+    ) {
+        match this
+            .widget
+            .layers
+            .iter()
+            .position(|layer| layer.root.id() == root_id)
+        {
+            Some(0) => debug_panic!("Cannot anchor initial layer"),
+            None => debug_panic!("layer with root widget {root_id:?} not found"),
+            Some(idx) => {
+                this.widget.layers[idx].anchor = anchor;
+                this.ctx.request_layout();
+            }
+        }
+    }
 }
 
 // --- MARK: IMPL WIDGET
@@ -215,6 +250,7 @@ impl Widget for LayerStack {
             let layer_size = ctx.compute_size(&mut layer.root, SizeDef::MAX, size.into());
             ctx.run_layout(&mut layer.root, layer_size);
             ctx.place_child(&mut layer.root, layer.position);
+            ctx.set_child_anchor(&mut layer.root, layer.anchor);
         }
     }
 
@@ -3,12 +3,20 @@
 
 //! Types needed for running a Masonry app.
 
+pub(crate) mod frame_profile;
+#[cfg(feature = "inspector")]
+pub mod inspector;
+#[cfg(feature = "interaction_script")]
+pub mod interaction_script;
 mod layer_stack;
 mod render_root;
 mod tracing_backend;
 mod visual_layers;
 
-pub use render_root::{RenderRoot, RenderRootOptions, RenderRootSignal, WindowSizePolicy};
+pub use frame_profile::{FrameProfile, WidgetTiming};
+pub use render_root::{
+    PaintCacheStats, PassTiming, RenderRoot, RenderRootOptions, RenderRootSignal, WindowSizePolicy,
+};
 pub use tracing_backend::{
     TracingSubscriberHasBeenSetError, default_tracing_subscriber, try_init_test_tracing,
     try_init_tracing,
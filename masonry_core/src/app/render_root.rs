@@ -2,12 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::any::{Any, TypeId};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use accesskit::{ActionRequest, NodeId, TreeId, TreeUpdate};
 use dpi::{LogicalPosition, LogicalSize, PhysicalSize};
-use kurbo::{Point, Rect, Size};
+use kurbo::{Affine, Point, Rect, Size};
 use parley::fontique::{
     Blob, Collection, CollectionOptions, FallbackKey, FamilyId, FontInfo, Script, SourceCache,
 };
@@ -16,6 +16,7 @@ use tracing::{debug, info_span, warn};
 use tree_arena::{ArenaMut, TreeArena};
 
 use crate::app::VisualLayerPlan;
+use crate::app::frame_profile::{FrameProfile, FrameProfiler};
 use crate::app::layer_stack::LayerStack;
 use crate::core::{
     AccessCtx, AccessEvent, BrushIndex, CursorIcon, DefaultProperties, ErasedAction, FromDynWidget,
@@ -23,7 +24,10 @@ use crate::core::{
     ResizeDirection, TextEvent, Widget, WidgetArena, WidgetArenaNode, WidgetId, WidgetMut,
     WidgetPod, WidgetRef, WidgetState, WidgetTag, WidgetTagInner, WindowEvent,
 };
+use crate::imaging::RgbaImage;
 use crate::imaging::record::Scene;
+use crate::imaging::render::{ImageRenderer, ImageRendererError};
+use crate::layout::Anchor;
 use crate::passes::accessibility::run_accessibility_pass;
 use crate::passes::action::run_action_pass;
 use crate::passes::anim::run_update_anim_pass;
@@ -33,14 +37,16 @@ use crate::passes::event::{
 };
 use crate::passes::layout::run_layout_pass;
 use crate::passes::mutate::{mutate_widget, run_mutate_pass};
-use crate::passes::paint::run_paint_pass;
+use crate::passes::paint::{CachedSubtree, paint_subtree_scene, run_paint_pass};
 use crate::passes::update::{
-    run_update_disabled_pass, run_update_focus_pass, run_update_focusable_pass,
-    run_update_fonts_pass, run_update_pointer_pass, run_update_props_pass, run_update_scroll_pass,
-    run_update_stashed_pass, run_update_widget_tree_pass,
+    collect_focus_chain, run_update_disabled_pass, run_update_focus_pass,
+    run_update_focusable_pass, run_update_fonts_pass, run_update_pointer_pass,
+    run_update_props_pass, run_update_scroll_pass, run_update_stashed_pass,
+    run_update_widget_tree_pass,
 };
 use crate::passes::{PassTracing, recurse_on_children};
 use crate::properties::Dimensions;
+use crate::util::{Duration, Instant};
 
 /// We ensure that any valid initial IME area is sent to the platform by storing an invalid initial
 /// IME area as the `last_sent_ime_area`.
@@ -115,6 +121,11 @@ pub(crate) struct RenderRootState {
     /// in its border-box coordinate space.
     pub(crate) scroll_request_targets: Vec<(WidgetId, Rect)>,
 
+    /// Widgets whose parent gave them an [`Anchor`](crate::layout::Anchor).
+    ///
+    /// Their positions are resolved after each compose pass.
+    pub(crate) anchored_widgets: HashSet<WidgetId>,
+
     /// List of ancestors of the currently hovered widget.
     pub(crate) hovered_path: Vec<WidgetId>,
 
@@ -149,6 +160,29 @@ pub(crate) struct RenderRootState {
     /// Scene cache for the widget tree.
     pub(crate) scene_cache: HashMap<WidgetId, (Scene, Scene, Scene)>,
 
+    /// Paint output of widget subtrees which didn't change, spliced in by the paint pass.
+    pub(crate) paint_subtree_cache: HashMap<WidgetId, CachedSubtree>,
+
+    /// Subtree cache usage during the last paint pass.
+    pub(crate) paint_cache_stats: PaintCacheStats,
+
+    /// Window area, in window coordinates, which changed since the last paint pass.
+    pub(crate) damage: Option<Rect>,
+
+    /// Whether the whole window changed since the last paint pass.
+    pub(crate) damage_all: bool,
+
+    /// Whether debug visuals (debug paint, the layout debugger, or the inspector highlight)
+    /// were shown by the last paint pass.
+    pub(crate) painted_debug_visuals: bool,
+
+    /// Number of paint passes run so far, used as the generation of changed layers.
+    pub(crate) paint_generation: u64,
+
+    /// The generation of each layer of the last paint pass, keyed like
+    /// [`VisualLayerPlan::keyed_layers`].
+    pub(crate) layer_generations: HashMap<(WidgetId, u32), u64>,
+
     pub(crate) widget_tags: HashMap<WidgetTagInner, WidgetId>,
 
     /// Map of layers attached to widgets, keyed by the attached widget id, then the type of the layer root.
@@ -163,6 +197,24 @@ pub(crate) struct RenderRootState {
     /// Internal state of the widget inspector.
     pub(crate) inspector_state: InspectorState,
 
+    /// Whether the time spent in each pass is measured, see [`RenderRoot::set_frame_timing`].
+    pub(crate) frame_timing: bool,
+
+    /// Time spent in each pass since the last frame was painted.
+    pub(crate) frame_timings: Vec<PassTiming>,
+
+    /// Time spent in each pass during the last painted frame.
+    pub(crate) last_frame_timings: Vec<PassTiming>,
+
+    /// Collector of the current frame's profile, if frame profiling is enabled.
+    pub(crate) profiler: Option<FrameProfiler>,
+
+    /// Profile of the last painted frame, if frame profiling was enabled.
+    pub(crate) last_frame_profile: Option<FrameProfile>,
+
+    /// Time frames should take at most, above which a warning is logged.
+    pub(crate) frame_budget: Option<Duration>,
+
     /// Whether the next accessibility pass tree should be updated during `render()`.
     pub(crate) access_tree_active: bool,
 
@@ -180,6 +232,37 @@ pub(crate) struct MutateCallback {
     pub(crate) callback: Box<dyn FnOnce(WidgetMut<'_, dyn Widget>)>,
 }
 
+/// The time spent running a pass during a frame.
+///
+/// See [`RenderRoot::last_frame_timings`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PassTiming {
+    /// The name of the pass, e.g. `"layout"`.
+    pub name: &'static str,
+    /// The total time spent in the pass.
+    ///
+    /// If the pass ran several times during the frame, this is the sum of all runs.
+    pub duration: Duration,
+    /// The number of widgets visited by the pass.
+    ///
+    /// Widgets visited several times are counted once per visit.
+    /// This is only counted while [frame profiling](RenderRoot::set_frame_profiling)
+    /// is enabled, and is zero otherwise.
+    pub widgets_visited: usize,
+}
+
+/// How often the paint pass reused the output of unchanged widget subtrees.
+///
+/// See [`RenderRoot::last_paint_cache_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PaintCacheStats {
+    /// Number of subtrees whose cached scene was reused.
+    pub hits: usize,
+    /// Number of subtrees which had to be painted again even though they didn't change,
+    /// because they weren't cached or their transform changed.
+    pub misses: usize,
+}
+
 /// Defines how a window's size is determined.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub enum WindowSizePolicy {
@@ -223,6 +306,11 @@ pub struct RenderRootOptions {
     /// We expect to develop a much more fully-featured font API in the future, but
     /// this is necessary for our testing of Masonry.
     pub test_font: Option<Blob<u8>>,
+
+    /// If true, the layout debugger overlay is shown from the first frame.
+    ///
+    /// See [`RenderRoot::set_layout_debugger`] for details.
+    pub layout_debugger: bool,
 }
 
 /// Objects emitted by the [`RenderRoot`] to signal that something has changed or require external actions.
@@ -291,6 +379,9 @@ pub enum RenderRootSignal {
     ///
     /// The given [`Point`] must be in the window's coordinate space.
     RepositionLayer(WidgetId, Point),
+    /// The layer with the given widget as root should follow the given [`Anchor`],
+    /// or stop following its anchor if `None`.
+    AnchorLayer(WidgetId, Option<Anchor>),
 }
 
 /// State of the widget inspector. Useful for debugging.
@@ -299,6 +390,10 @@ pub enum RenderRootSignal {
 pub(crate) struct InspectorState {
     pub(crate) is_picking_widget: bool,
     pub(crate) hovered_widget: Option<WidgetId>,
+    /// Whether the layout debugger overlay is painted.
+    pub(crate) layout_debugger: bool,
+    /// The widget under the pointer, described by the layout debugger overlay.
+    pub(crate) layout_debugger_target: Option<WidgetId>,
 }
 
 impl RenderRoot {
@@ -324,6 +419,7 @@ impl RenderRoot {
             size,
             scale_factor,
             test_font,
+            layout_debugger,
         } = options;
         let debug_paint = std::env::var("MASONRY_DEBUG_PAINT").is_ok_and(|it| !it.is_empty());
 
@@ -349,6 +445,7 @@ impl RenderRoot {
                 focus_fallback: None,
                 window_focused: true,
                 scroll_request_targets: Vec::new(),
+                anchored_widgets: HashSet::new(),
                 hovered_path: Vec::new(),
                 active_path: Vec::new(),
                 pointer_capture_target: None,
@@ -366,6 +463,13 @@ impl RenderRoot {
                 is_ime_active: false,
                 last_sent_ime_area: INVALID_IME_AREA,
                 scene_cache: HashMap::new(),
+                paint_subtree_cache: HashMap::new(),
+                paint_cache_stats: PaintCacheStats::default(),
+                damage: None,
+                damage_all: true,
+                painted_debug_visuals: false,
+                paint_generation: 0,
+                layer_generations: HashMap::new(),
                 widget_tags: HashMap::new(),
                 attached_layers: HashMap::new(),
                 needs_pointer_pass: false,
@@ -373,7 +477,15 @@ impl RenderRoot {
                 inspector_state: InspectorState {
                     is_picking_widget: false,
                     hovered_widget: None,
+                    layout_debugger,
+                    layout_debugger_target: None,
                 },
+                frame_timing: false,
+                frame_timings: Vec::new(),
+                last_frame_timings: Vec::new(),
+                profiler: None,
+                last_frame_profile: None,
+                frame_budget: None,
                 access_tree_active: false,
                 scale_factor,
                 debug_paint,
@@ -516,13 +628,16 @@ impl RenderRoot {
             }
             WindowEvent::Resize(size) => {
                 self.global_state.size = size;
+                self.global_state.damage_all = true;
                 self.root_state_mut().request_layout = true;
                 self.root_state_mut().set_needs_layout(true);
                 self.run_rewrite_passes();
                 Handled::Yes
             }
             WindowEvent::AnimFrame(duration) => {
-                run_update_anim_pass(self, duration.as_nanos() as u64);
+                self.run_timed_pass("anim", |root| {
+                    run_update_anim_pass(root, duration.as_nanos() as u64);
+                });
                 self.run_rewrite_passes();
 
                 Handled::Yes
@@ -547,7 +662,7 @@ impl RenderRoot {
     /// Handles a pointer event.
     pub fn handle_pointer_event(&mut self, event: PointerEvent) -> Handled {
         let _span = info_span!("pointer_event");
-        let handled = run_on_pointer_event_pass(self, &event);
+        let handled = self.run_timed_pass("event", |root| run_on_pointer_event_pass(root, &event));
         run_update_pointer_pass(self);
         self.run_rewrite_passes();
 
@@ -557,7 +672,7 @@ impl RenderRoot {
     /// Handles a text event.
     pub fn handle_text_event(&mut self, event: TextEvent) -> Handled {
         let _span = info_span!("text_event");
-        let handled = run_on_text_event_pass(self, &event);
+        let handled = self.run_timed_pass("event", |root| run_on_text_event_pass(root, &event));
         run_update_focus_pass(self);
 
         if matches!(event, TextEvent::Ime(Ime::Enabled)) {
@@ -589,7 +704,9 @@ impl RenderRoot {
             data: event.data,
         };
 
-        run_on_access_event_pass(self, &event, WidgetId(id));
+        self.run_timed_pass("event", |root| {
+            run_on_access_event_pass(root, &event, WidgetId(id));
+        });
         self.run_rewrite_passes();
     }
 
@@ -610,18 +727,133 @@ impl RenderRoot {
     /// Redraws the window.
     ///
     /// Returns the current visual-layer plan and, if accessibility is active, a tree update.
+    /// The plan's [`damage`](VisualLayerPlan::damage) is the area which changed since the
+    /// previous call.
     pub fn redraw(&mut self) -> (VisualLayerPlan, Option<TreeUpdate>) {
         self.run_rewrite_passes();
 
         let access_tree_active = self.global_state.access_tree_active;
 
         // TODO - Handle invalidation regions
-        let visual_layers = run_paint_pass(self);
-        let tree_update = access_tree_active
-            .then(|| run_accessibility_pass(self, self.global_state.scale_factor));
+        let visual_layers = self.run_timed_pass("paint", run_paint_pass);
+        let tree_update = access_tree_active.then(|| {
+            self.run_timed_pass("accessibility", |root| {
+                run_accessibility_pass(root, root.global_state.scale_factor)
+            })
+        });
+        self.finish_frame();
         (visual_layers, tree_update)
     }
 
+    /// Paints the widget with the given id and its descendants into a new scene.
+    ///
+    /// The scene is in the widget's border-box coordinates, in logical pixels,
+    /// and doesn't include debug visuals or the content of external layers.
+    /// It can be exported to other formats, e.g. with `masonry_imaging`'s `export` module.
+    ///
+    /// # Panics
+    ///
+    /// If the widget isn't in the tree.
+    pub fn widget_scene(&mut self, id: WidgetId) -> Scene {
+        self.run_rewrite_passes();
+        if self.root_state().needs_paint {
+            let _ = self.run_timed_pass("paint", run_paint_pass);
+            // The damage of this paint pass isn't reported, so the next frame is redrawn in full.
+            self.global_state.damage_all = true;
+        }
+        paint_subtree_scene(self, id)
+            .unwrap_or_else(|| panic!("Could not find widget {id} in tree."))
+    }
+
+    /// Renders the widget with the given id and its descendants to an image.
+    ///
+    /// The image covers the widget's border-box on a transparent background,
+    /// with `scale` pixels per logical pixel.
+    /// Any of `masonry_imaging`'s headless renderers can be used as `renderer`.
+    ///
+    /// # Panics
+    ///
+    /// If the widget isn't in the tree.
+    pub fn render_widget_to_image(
+        &mut self,
+        id: WidgetId,
+        scale: f64,
+        renderer: &mut (impl ImageRenderer + ?Sized),
+    ) -> Result<RgbaImage, ImageRendererError> {
+        let scene = self.widget_scene(id);
+        let size = self.widget_arena.get_state(id).border_box().size() * scale;
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "Image sizes are far below u32::MAX"
+        )]
+        let (width, height) = (size.width.ceil() as u32, size.height.ceil() as u32);
+
+        let mut scaled = Scene::new();
+        scaled.append_transformed(&scene, Affine::scale(scale));
+        renderer.render_source(&mut &scaled, width.max(1), height.max(1))
+    }
+
+    /// Returns the time spent in each pass during the last call to [`redraw`](Self::redraw).
+    ///
+    /// This includes the rewrite passes run since the previous frame, in the order they first ran.
+    ///
+    /// Passes are only timed if [frame timing](Self::set_frame_timing) or
+    /// [frame profiling](Self::set_frame_profiling) is enabled, or a
+    /// [frame budget](Self::set_frame_budget) is set; otherwise this is empty.
+    pub fn last_frame_timings(&self) -> &[PassTiming] {
+        &self.global_state.last_frame_timings
+    }
+
+    /// Enables or disables measuring the time spent in each pass.
+    ///
+    /// The results are available from [`last_frame_timings`](Self::last_frame_timings).
+    /// Timing is disabled by default.
+    pub fn set_frame_timing(&mut self, enabled: bool) {
+        self.global_state.frame_timing = enabled;
+    }
+
+    /// Enables or disables frame profiling.
+    ///
+    /// While enabled, the time spent in each widget during each pass is measured, and the
+    /// widgets visited by each pass are counted.
+    /// The results are available from [`last_frame_profile`](Self::last_frame_profile).
+    ///
+    /// This adds some overhead to every widget visit, so it's disabled by default.
+    pub fn set_frame_profiling(&mut self, enabled: bool) {
+        if enabled != self.global_state.profiler.is_some() {
+            self.global_state.profiler = enabled.then(FrameProfiler::default);
+            self.global_state.last_frame_profile = None;
+        }
+    }
+
+    /// Returns the profile of the last call to [`redraw`](Self::redraw).
+    ///
+    /// Returns `None` if [frame profiling](Self::set_frame_profiling) is disabled,
+    /// or no frame was painted since it was enabled.
+    pub fn last_frame_profile(&self) -> Option<&FrameProfile> {
+        self.global_state.last_frame_profile.as_ref()
+    }
+
+    /// Sets the time frames should take at most.
+    ///
+    /// When the passes run for a frame take longer than `budget` in total,
+    /// a warning naming the slowest pass is logged.
+    /// If [frame profiling](Self::set_frame_profiling) is enabled, the warning also names the
+    /// most expensive widgets.
+    ///
+    /// There is no budget by default.
+    pub fn set_frame_budget(&mut self, budget: Option<Duration>) {
+        self.global_state.frame_budget = budget;
+    }
+
+    /// Returns how often the last paint pass reused the output of unchanged widget subtrees.
+    ///
+    /// The same counts are recorded as the `cache_hits` and `cache_misses` fields
+    /// of the `paint` tracing span.
+    pub fn last_paint_cache_stats(&self) -> PaintCacheStats {
+        self.global_state.paint_cache_stats
+    }
+
     /// Returns the current icon that the mouse should display.
     pub fn cursor_icon(&self) -> CursorIcon {
         self.global_state.cursor_icon
@@ -676,6 +908,16 @@ impl RenderRoot {
         Some(widget_ref)
     }
 
+    /// Returns a [`WidgetRef`] to the lowest layer whose root widget is a `W`, if any.
+    ///
+    /// This lets widgets which manage a layer of their own, like a layer of notifications,
+    /// find it again without keeping track of its id.
+    pub fn find_layer<W: Widget + FromDynWidget + ?Sized>(&self) -> Option<WidgetRef<'_, W>> {
+        self.layer_root_ids()
+            .into_iter()
+            .find_map(|id| self.get_widget(id)?.downcast())
+    }
+
     /// Checks if a widget with the given id is in the tree.
     pub fn has_widget(&self, id: WidgetId) -> bool {
         self.widget_arena.has(id)
@@ -806,6 +1048,64 @@ impl RenderRoot {
         self.run_rewrite_passes();
     }
 
+    /// Anchors the layer with the given widget as root to another widget.
+    ///
+    /// While the anchor's target is in the tree, the layer follows it across layout changes,
+    /// and the position given to [`Self::add_layer`] or [`Self::reposition_layer`] is ignored.
+    /// Passing `None` removes the anchor.
+    ///
+    /// The base layer cannot be anchored.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug mode if the intended layer is the base layer or
+    /// is not found.
+    pub fn anchor_layer(&mut self, root_id: WidgetId, anchor: Option<Anchor>) {
+        mutate_widget(self, self.root_id(), |mut layer_stack| {
+            let mut layer_stack = layer_stack.downcast::<LayerStack>();
+            LayerStack::anchor_layer(&mut layer_stack, root_id, anchor);
+        });
+
+        self.run_rewrite_passes();
+    }
+
+    /// Shows or hides the layout debugger overlay.
+    ///
+    /// The overlay outlines every widget's border-box, its padding-box and content-box,
+    /// its baselines, and its clip rect.
+    /// The widget under the pointer is highlighted, and a panel next to it describes
+    /// its type, [`WidgetId`], resolved geometry, and resolved layout properties.
+    ///
+    /// The overlay can also be toggled with <kbd>Shift</kbd>+<kbd>F12</kbd>,
+    /// or enabled from the start with [`RenderRootOptions::layout_debugger`].
+    pub fn set_layout_debugger(&mut self, enabled: bool) {
+        if self.global_state.inspector_state.layout_debugger == enabled {
+            return;
+        }
+        self.global_state.inspector_state.layout_debugger = enabled;
+        self.global_state.inspector_state.layout_debugger_target = None;
+        self.global_state.needs_pointer_pass = true;
+        self.root_state_mut().needs_paint = true;
+        self.run_rewrite_passes();
+    }
+
+    /// Returns `true` if the layout debugger overlay is shown.
+    ///
+    /// See [`Self::set_layout_debugger`] for details.
+    pub fn layout_debugger_enabled(&self) -> bool {
+        self.global_state.inspector_state.layout_debugger
+    }
+
+    /// Returns the widget under the pointer, which the layout debugger panel describes.
+    ///
+    /// Returns `None` if the layout debugger is disabled.
+    pub fn layout_debugger_target(&self) -> Option<WidgetId> {
+        self.global_state
+            .inspector_state
+            .layout_debugger_target
+            .filter(|_| self.global_state.inspector_state.layout_debugger)
+    }
+
     /// Returns the current size of the window.
     pub fn size(&self) -> PhysicalSize<u32> {
         self.global_state.size
@@ -819,6 +1119,86 @@ impl RenderRoot {
         Size::new(size.width, size.height)
     }
 
+    /// Runs `pass` and adds the time it took to the current frame's timings.
+    ///
+    /// The pass isn't timed unless timing, profiling or a frame budget is enabled.
+    fn run_timed_pass<R>(&mut self, name: &'static str, pass: impl FnOnce(&mut Self) -> R) -> R {
+        let state = &self.global_state;
+        if !state.frame_timing && state.profiler.is_none() && state.frame_budget.is_none() {
+            return pass(self);
+        }
+        let start = Instant::now();
+        FrameProfiler::start_pass(self.global_state.profiler.take(), name, start);
+        let res = pass(self);
+        let duration = start.elapsed();
+        let widgets_visited = FrameProfiler::end_pass(start).map_or(0, |(profiler, visits)| {
+            self.global_state.profiler = Some(profiler);
+            visits
+        });
+
+        let timings = &mut self.global_state.frame_timings;
+        if let Some(timing) = timings.iter_mut().find(|timing| timing.name == name) {
+            timing.duration += duration;
+            timing.widgets_visited += widgets_visited;
+        } else {
+            timings.push(PassTiming {
+                name,
+                duration,
+                widgets_visited,
+            });
+        }
+        res
+    }
+
+    /// Ends the current frame's timings, and warns if the frame went over budget.
+    fn finish_frame(&mut self) {
+        let state = &mut self.global_state;
+        state.last_frame_timings = std::mem::take(&mut state.frame_timings);
+        state.last_frame_profile = state
+            .profiler
+            .as_mut()
+            .map(|profiler| profiler.finish_frame(&state.last_frame_timings));
+
+        let Some(budget) = state.frame_budget else {
+            return;
+        };
+        let total: Duration = state
+            .last_frame_timings
+            .iter()
+            .map(|pass| pass.duration)
+            .sum();
+        if total <= budget {
+            return;
+        }
+        let slowest = state
+            .last_frame_timings
+            .iter()
+            .max_by_key(|pass| pass.duration)
+            .map_or("none", |pass| pass.name);
+        match &state.last_frame_profile {
+            Some(profile) => {
+                let widgets = profile
+                    .most_expensive_widgets(3)
+                    .iter()
+                    .map(|widget| {
+                        format!(
+                            "{} {} in {} ({:?})",
+                            widget.name, widget.id, widget.pass, widget.duration
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                warn!(
+                    "Frame took {total:?}, over its budget of {budget:?}. \
+                    Slowest pass: {slowest}. Most expensive widgets: {widgets}"
+                );
+            }
+            None => warn!(
+                "Frame took {total:?}, over its budget of {budget:?}. Slowest pass: {slowest}"
+            ),
+        }
+    }
+
     // --- MARK: REWRITE PASSES
     /// Runs all rewrite passes on widget tree.
     ///
@@ -834,18 +1214,18 @@ impl RenderRoot {
             // expected to have its own early exits.
             // Calling a run_xxx_pass should always be very fast if the pass doesn't need to do anything.
 
-            run_mutate_pass(self);
-            run_action_pass(self);
-            run_update_widget_tree_pass(self);
-            run_update_disabled_pass(self);
-            run_update_stashed_pass(self);
-            run_update_focusable_pass(self);
-            run_update_focus_pass(self);
-            run_layout_pass(self);
-            run_update_scroll_pass(self);
-            run_compose_pass(self);
-            run_update_pointer_pass(self);
-            run_update_props_pass(self);
+            self.run_timed_pass("mutate", run_mutate_pass);
+            self.run_timed_pass("action", run_action_pass);
+            self.run_timed_pass("update_widget_tree", run_update_widget_tree_pass);
+            self.run_timed_pass("update_disabled", run_update_disabled_pass);
+            self.run_timed_pass("update_stashed", run_update_stashed_pass);
+            self.run_timed_pass("update_focusable", run_update_focusable_pass);
+            self.run_timed_pass("update_focus", run_update_focus_pass);
+            self.run_timed_pass("layout", run_layout_pass);
+            self.run_timed_pass("update_scroll", run_update_scroll_pass);
+            self.run_timed_pass("compose", run_compose_pass);
+            self.run_timed_pass("update_pointer", run_update_pointer_pass);
+            self.run_timed_pass("update_props", run_update_props_pass);
 
             if !self.needs_rewrite_passes() {
                 break;
@@ -937,6 +1317,7 @@ impl RenderRoot {
 
         let root_node = self.widget_arena.get_node_mut(self.root_id());
         request_render_all_in(root_node);
+        self.global_state.damage_all = true;
         self.global_state
             .emit_signal(RenderRootSignal::RequestRedraw);
     }
@@ -958,6 +1339,15 @@ impl RenderRoot {
         self.global_state.focused_widget
     }
 
+    /// Returns the widgets which pressing <kbd>Tab</kbd> moves the [focus](crate::doc::masonry_concepts#text-focus) to, in order.
+    ///
+    /// Pressing <kbd>Shift+Tab</kbd> goes through the same widgets in reverse order.
+    /// This doesn't account for widgets which handle <kbd>Tab</kbd> themselves,
+    /// e.g. to insert tab characters.
+    pub fn focus_chain(&self) -> Vec<WidgetId> {
+        collect_focus_chain(self)
+    }
+
     /// Returns the [`WidgetId`] of the widget which [captures pointer events](crate::doc::masonry_concepts#pointer-capture).
     pub fn pointer_capture_target(&self) -> Option<WidgetId> {
         self.global_state.pointer_capture_target
@@ -1054,6 +1444,14 @@ impl RenderRootState {
             || !self.mutate_callbacks.is_empty()
             || !self.actions.is_empty()
     }
+
+    /// Marks an area of the window, in window coordinates, as changed since the last paint pass.
+    pub(crate) fn add_damage(&mut self, rect: Rect) {
+        self.damage = Some(match self.damage {
+            Some(damage) => damage.union(rect),
+            None => rect,
+        });
+    }
 }
 
 impl RenderRootSignal {
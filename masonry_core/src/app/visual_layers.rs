@@ -1,6 +1,8 @@
 // Copyright 2026 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+
 use kurbo::{Affine, Rect};
 
 use crate::core::WidgetId;
@@ -9,12 +11,20 @@ use crate::imaging::record::{Scene, replay_transformed};
 
 /// Snapshot of Masonry's current visual layers in painter order.
 ///
-/// This is the semantic paint output of Masonry. Current hosts still consume it through
-/// compatibility helpers that flatten or reinterpret it, but the plan is the source of truth.
+/// This is the semantic paint output of Masonry.
+/// Hosts may flatten it with [`replay_into`](Self::replay_into), or composite it:
+/// `masonry_winit` caches [isolated](VisualLayer::isolated) layers as textures and fills
+/// [external](VisualLayerKind::External) layers with textures supplied by the app.
 #[derive(Debug)]
 pub struct VisualLayerPlan {
     /// Layers in painter order, back to front.
     pub layers: Vec<VisualLayer>,
+    /// The area of the window which changed since the previous plan, in window coordinates.
+    ///
+    /// Hosts which keep the previous frame can restrict rendering to this area.
+    /// This is `None` if the whole window must be redrawn, for instance after a resize,
+    /// and an empty rect if nothing changed.
+    pub damage: Option<Rect>,
 }
 
 impl VisualLayerPlan {
@@ -43,6 +53,30 @@ impl VisualLayerPlan {
             .find(|layer| matches!(layer.kind, VisualLayerKind::Scene(_)))
     }
 
+    /// All layers in painter order, each paired with a number which is unique among the
+    /// layers owned by the same widget.
+    ///
+    /// A widget can own several layers, for instance when an isolated scene layer
+    /// is split around a nested one. The `(widget_id, part)` pair identifies a layer
+    /// across frames, which lets hosts cache what they realized for it.
+    pub fn keyed_layers(&self) -> impl Iterator<Item = (&VisualLayer, u32)> {
+        let mut parts = HashMap::<WidgetId, u32>::new();
+        self.layers.iter().map(move |layer| {
+            let part = parts.entry(layer.widget_id).or_default();
+            let key = *part;
+            *part += 1;
+            (layer, key)
+        })
+    }
+
+    /// Whether any layer needs more than flattening to be presented faithfully,
+    /// i.e. whether the plan has isolated or external layers.
+    pub fn has_composited_layers(&self) -> bool {
+        self.layers
+            .iter()
+            .any(|layer| layer.isolated || matches!(layer.kind, VisualLayerKind::External { .. }))
+    }
+
     /// All scene layers after the first one, in painter order.
     ///
     /// In the current compatibility model, these are replayed after the root layer
@@ -72,6 +106,22 @@ pub struct VisualLayer {
     pub transform: Affine,
     /// The widget that requested this layer boundary.
     pub widget_id: WidgetId,
+    /// Whether this scene layer was recorded for a widget using
+    /// [`PaintLayerMode::IsolatedScene`](crate::core::PaintLayerMode::IsolatedScene).
+    ///
+    /// Hosts may render isolated layers separately and reuse the result while the
+    /// layer's [`generation`](Self::generation) and transform stay the same.
+    pub isolated: bool,
+    /// The area covered by the layer's content, in layer-local coordinates.
+    ///
+    /// This is the bounding box of the widgets painted into the layer, so content
+    /// painted outside of their paint-box may be cut off by hosts which use it.
+    pub bounds: Rect,
+    /// A number which changes whenever the content of the layer may have changed.
+    ///
+    /// It is compared between layers with the same [key](VisualLayerPlan::keyed_layers),
+    /// and stays the same across paint passes which don't repaint any widget of the layer.
+    pub generation: u64,
 }
 
 /// The content represented by a visual layer.
@@ -116,6 +166,9 @@ mod tests {
                     kind: VisualLayerKind::Scene(root_scene.clone()),
                     transform: Affine::IDENTITY,
                     widget_id: WidgetId::next(),
+                    isolated: false,
+                    bounds: Rect::ZERO,
+                    generation: 0,
                 },
                 VisualLayer {
                     kind: VisualLayerKind::External {
@@ -123,13 +176,20 @@ mod tests {
                     },
                     transform: Affine::IDENTITY,
                     widget_id: WidgetId::next(),
+                    isolated: false,
+                    bounds: Rect::ZERO,
+                    generation: 0,
                 },
                 VisualLayer {
                     kind: VisualLayerKind::Scene(overlay_scene.clone()),
                     transform: Affine::translate((20.0, 5.0)),
                     widget_id: WidgetId::next(),
+                    isolated: false,
+                    bounds: Rect::ZERO,
+                    generation: 0,
                 },
             ],
+            damage: None,
         };
 
         let mut actual = Scene::new();
@@ -158,6 +218,9 @@ mod tests {
                     kind: VisualLayerKind::Scene(root_scene),
                     transform: Affine::IDENTITY,
                     widget_id: WidgetId::next(),
+                    isolated: false,
+                    bounds: Rect::ZERO,
+                    generation: 0,
                 },
                 VisualLayer {
                     kind: VisualLayerKind::External {
@@ -165,13 +228,20 @@ mod tests {
                     },
                     transform: Affine::IDENTITY,
                     widget_id: WidgetId::next(),
+                    isolated: false,
+                    bounds: Rect::ZERO,
+                    generation: 0,
                 },
                 VisualLayer {
                     kind: VisualLayerKind::Scene(overlay_scene),
                     transform: Affine::translate((20.0, 5.0)),
                     widget_id: WidgetId::next(),
+                    isolated: false,
+                    bounds: Rect::ZERO,
+                    generation: 0,
                 },
             ],
+            damage: None,
         };
 
         assert!(matches!(
@@ -11,6 +11,7 @@ pub(crate) struct ClassSet {
     pub(crate) is_active: bool,
     pub(crate) is_disabled: bool,
     pub(crate) has_focus_target: bool,
+    pub(crate) is_invalid: bool,
 }
 
 /// A series of changes that need to be applied to a [`ClassSet`] during the next
@@ -25,6 +26,7 @@ pub(crate) struct ClassSetDiff {
     pub(crate) is_active: Option<bool>,
     pub(crate) is_disabled: Option<bool>,
     pub(crate) has_focus_target: Option<bool>,
+    pub(crate) is_invalid: Option<bool>,
 }
 
 // ---
@@ -53,6 +55,9 @@ impl ClassSet {
         if let Some(v) = diff.has_focus_target {
             self.has_focus_target = v;
         }
+        if let Some(v) = diff.is_invalid {
+            self.is_invalid = v;
+        }
     }
 }
 
@@ -26,7 +26,7 @@ use crate::core::{
     WidgetState,
 };
 use crate::kurbo::{Affine, Axis, Insets, Point, Rect, Size, Vec2};
-use crate::layout::{LayoutSize, LenDef, Length, SizeDef};
+use crate::layout::{Anchor, LayoutSize, LenDef, Length, SizeDef};
 use crate::passes::layout::{place_widget, resolve_length, resolve_size, run_layout_on};
 use crate::peniko::Color;
 use crate::util::{ParentLinkedList, get_debug_color};
@@ -308,7 +308,10 @@ impl MutateCtx<'_> {
             widget_state: &mut node_mut.item.state,
             properties: PropertiesMut {
                 local: &mut node_mut.item.properties,
-                default_map: self.properties.default_map,
+                default_map: self
+                    .property_arena
+                    .default_properties
+                    .for_widget(child_type_id),
                 stack: child_stack,
                 class_set: &node_mut.item.class_set,
             },
@@ -374,7 +377,10 @@ impl<'w> QueryCtx<'w> {
             widget_state: &child_node.item.state,
             properties: PropertiesRef {
                 local: &child_node.item.properties,
-                default_map: self.properties.default_map,
+                default_map: self
+                    .property_arena
+                    .default_properties
+                    .for_widget(child_type_id),
                 stack: child_stack,
                 class_set: &child_node.item.class_set,
             },
@@ -482,6 +488,17 @@ impl EventCtx<'_> {
         self.global_state.needs_pointer_pass = true;
     }
 
+    /// Whether a pointer is [captured] by any widget.
+    ///
+    /// While a [`Down`] event bubbles up, this tells whether a descendant already captured
+    /// the pointer, which containers that can be dragged around should respect.
+    ///
+    /// [captured]: crate::doc::masonry_concepts#pointer-capture
+    /// [`Down`]: ui_events::pointer::PointerEvent::Down
+    pub fn is_pointer_captured(&self) -> bool {
+        self.global_state.pointer_capture_target.is_some()
+    }
+
     /// The widget originally targeted by the event.
     ///
     /// This will be different from [`widget_id`](Self::widget_id) during event bubbling.
@@ -893,6 +910,42 @@ impl LayoutCtx<'_> {
         place_widget(child_state, child_origin);
     }
 
+    /// Anchors the child to another widget's border-box.
+    ///
+    /// After layout, the child is translated so that its position matches `anchor`,
+    /// which lets it follow its target across layout changes.
+    /// The position given to [`place_child`](Self::place_child) is used as a fallback
+    /// whenever the target isn't in the tree or is stashed.
+    ///
+    /// Passing `None` removes the anchor.
+    ///
+    /// The anchor translation is resolved in window coordinates after the compose pass,
+    /// so the anchored child doesn't contribute to this widget's measured size.
+    pub fn set_child_anchor(
+        &mut self,
+        child: &mut WidgetPod<impl Widget + ?Sized>,
+        anchor: Option<Anchor>,
+    ) {
+        let child_id = child.id();
+        let child_state = self.get_child_state_mut(child);
+        if child_state.anchor == anchor {
+            return;
+        }
+        child_state.anchor = anchor;
+        if anchor.is_none() {
+            child_state.anchor_translation = Vec2::ZERO;
+        }
+        child_state.transform_changed = true;
+        child_state.needs_compose = true;
+        self.widget_state.needs_compose = true;
+
+        if anchor.is_some() {
+            self.global_state.anchored_widgets.insert(child_id);
+        } else {
+            self.global_state.anchored_widgets.remove(&child_id);
+        }
+    }
+
     /// Sets explicit paint [`Insets`] for this widget.
     ///
     /// The argument is an [`Insets`] struct that indicates where your widget will overpaint,
@@ -1452,6 +1505,17 @@ impl_context_method!(
             self.widget_state.is_disabled
         }
 
+        /// Whether this widget is [invalid].
+        ///
+        /// Returns `true` if this widget or any of its ancestors is explicitly invalid.
+        /// To make this widget explicitly invalid use [`set_invalid`].
+        ///
+        /// [invalid]: crate::doc::masonry_concepts#invalid
+        /// [`set_invalid`]: EventCtx::set_invalid
+        pub fn is_invalid(&self) -> bool {
+            self.widget_state.is_invalid
+        }
+
         /// Whether this widget is [stashed].
         ///
         /// [stashed]: crate::doc::masonry_concepts#stashed
@@ -1624,6 +1688,10 @@ impl_context_method!(
                 }
 
                 global_state.scene_cache.remove(&state.id);
+                global_state.paint_subtree_cache.remove(&state.id);
+                if let Some(rect) = state.painted_rect {
+                    global_state.add_damage(rect);
+                }
 
                 if let Some(layers) = global_state.attached_layers.remove(&state.id) {
                     for (_, layer_id) in layers {
@@ -1655,6 +1723,31 @@ impl_context_method!(
             self.widget_state.is_explicitly_disabled = disabled;
         }
 
+        /// Sets the invalid state for this widget.
+        ///
+        /// Invalid widgets match selectors built with [`Selector::with_invalid`], and
+        /// are reported as invalid to assistive technologies.
+        /// Like the disabled state, this is inherited by descendants.
+        /// See [`is_invalid`] for more information.
+        ///
+        /// [`is_invalid`]: EventCtx::is_invalid
+        /// [`Selector::with_invalid`]: crate::core::Selector::with_invalid
+        pub fn set_invalid(&mut self, invalid: bool) {
+            self.widget_state.needs_update_disabled = true;
+            self.widget_state.is_explicitly_invalid = invalid;
+        }
+
+        /// Sets the widget explaining why this widget is [invalid], usually a label.
+        ///
+        /// Assistive technologies read this widget when describing this widget and its
+        /// descendants while they are invalid.
+        ///
+        /// [invalid]: crate::doc::masonry_concepts#invalid
+        pub fn set_error_message(&mut self, message: Option<WidgetId>) {
+            self.widget_state.needs_update_disabled = true;
+            self.widget_state.explicit_error_message = message;
+        }
+
         /// Sets the local transform for this widget.
         ///
         /// This maps this widget's border-box coordinate space
@@ -1975,6 +2068,30 @@ impl_context_method!(
             ));
         }
 
+        /// Creates a new [layer] which follows the given [`Anchor`].
+        ///
+        /// Unlike [`Self::create_layer`], the layer's position doesn't need to be computed
+        /// by the caller: the layer is kept attached to the anchor's target across
+        /// layout changes. If the target leaves the tree, the layer is placed at the
+        /// window's origin.
+        ///
+        /// # Panics
+        ///
+        /// If [`W::as_layer()`](Widget::as_layer) returns `None`.
+        ///
+        /// [layer]: crate::doc::masonry_concepts#layers
+        pub fn create_anchored_layer<W: Widget + ?Sized>(
+            &mut self,
+            layer_type: LayerType,
+            fallback_widget: NewWidget<W>,
+            anchor: Anchor,
+        ) {
+            let layer_id = fallback_widget.id;
+            self.create_layer(layer_type, fallback_widget, Point::ORIGIN);
+            self.global_state
+                .emit_signal(RenderRootSignal::AnchorLayer(layer_id, Some(anchor)));
+        }
+
         /// Creates a new [layer] at a specified `position`, and ties it to the current widget.
         ///
         /// The layer will be removed when the current widget is removed from the tree.
@@ -2061,6 +2178,19 @@ impl_context_method!(
             self.global_state
                 .emit_signal(RenderRootSignal::RepositionLayer(root_widget_id, position));
         }
+
+        /// Anchors the layer with the specified widget as root to another widget.
+        ///
+        /// While the anchor's target is in the tree, the layer follows it across
+        /// layout changes, and its position is ignored.
+        /// Passing `None` removes the anchor.
+        ///
+        /// See [`Anchor`] for details.
+        pub fn anchor_layer(&mut self, root_widget_id: WidgetId, anchor: Option<Anchor>) {
+            trace!("anchor_layer");
+            self.global_state
+                .emit_signal(RenderRootSignal::AnchorLayer(root_widget_id, anchor));
+        }
     }
 );
 
@@ -2153,9 +2283,10 @@ impl Drop for RawCtx<'_> {
 impl PaintCtx<'_> {
     /// Controls how this widget subtree is recorded in the current paint pass.
     ///
-    /// This is reset to [`PaintLayerMode::Inline`] at the start of each paint
-    /// pass for the widget. Widgets that want isolated scene layers should set
-    /// this during `pre_paint`, `paint`, or `post_paint` each time they paint.
+    /// This is reset to [`PaintLayerMode::Inline`] each time the widget is repainted,
+    /// and kept as-is in paint passes which don't repaint the widget.
+    /// Widgets that want their own layer should set this during `pre_paint`,
+    /// `paint`, or `post_paint` each time they paint.
     pub fn set_paint_layer_mode(&mut self, mode: PaintLayerMode) {
         self.widget_state.paint_layer_mode = mode;
     }
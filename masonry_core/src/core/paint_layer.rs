@@ -4,9 +4,8 @@
 /// How the current widget subtree should be represented in the current paint pass.
 ///
 /// This controls how Masonry records the widget subtree into the current
-/// [`VisualLayerPlan`](crate::app::VisualLayerPlan). Current hosts still flatten
-/// these scene layers back together, so changing this does not yet change runtime
-/// presentation behavior.
+/// [`VisualLayerPlan`](crate::app::VisualLayerPlan).
+/// Hosts which only flatten the plan render all modes the same way.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum PaintLayerMode {
     /// Paint into the current scene layer.
@@ -16,12 +15,17 @@ pub enum PaintLayerMode {
     ///
     /// The subtree still paints in normal painter order. If nested isolated scene
     /// layers occur, Masonry will split the surrounding scene as needed to preserve
-    /// that order in the flattened visual-layer plan.
+    /// that order in the visual-layer plan.
+    ///
+    /// Compositing hosts render the layer separately and only re-render it when its
+    /// content or transform changes, which makes this worthwhile for expensive subtrees
+    /// that rarely change.
     IsolatedScene,
     /// Record this widget subtree as an external placeholder layer.
     ///
-    /// Current hosts do not realize these placeholders yet; compatibility consumers
-    /// simply skip them while flattening scene content. This mode exists so the core
-    /// paint model can represent external boundaries before host integration lands.
+    /// The widget's own paint output is skipped, and its border-box is left for the host
+    /// to fill, e.g. with a 3D viewport or a video frame.
+    /// In `masonry_winit`, the content is supplied by the app as a texture keyed by the widget id.
+    /// Hosts which only flatten the plan leave the area empty.
     External,
 }
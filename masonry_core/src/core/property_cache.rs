@@ -18,6 +18,7 @@ pub struct PropertyCache {
     pub(crate) relevant_is_active: bool,
     pub(crate) relevant_is_disabled: bool,
     pub(crate) relevant_has_focus_target: bool,
+    pub(crate) relevant_is_invalid: bool,
     /// Whether the widget's property stack has changed.
     pub(crate) invalidated: bool,
 }
@@ -31,6 +32,7 @@ impl PropertyCache {
         self.relevant_is_active |= selector.is_active.is_some();
         self.relevant_is_disabled |= selector.is_disabled.is_some();
         self.relevant_has_focus_target |= selector.has_focus_target.is_some();
+        self.relevant_is_invalid |= selector.is_invalid.is_some();
     }
 
     // Returns:
@@ -16,6 +16,7 @@ pub struct Selector {
     pub(crate) is_active: Option<bool>,
     pub(crate) is_disabled: Option<bool>,
     pub(crate) has_focus_target: Option<bool>,
+    pub(crate) is_invalid: Option<bool>,
 }
 
 impl Selector {
@@ -57,6 +58,14 @@ impl Selector {
         self
     }
 
+    /// Builder method for a selector that matches widgets with a specific "invalid" status.
+    ///
+    /// See [`EventCtx::set_invalid`](crate::core::EventCtx::set_invalid).
+    pub fn with_invalid(mut self, value: bool) -> Self {
+        self.is_invalid = Some(value);
+        self
+    }
+
     /// Checks whether this selector matches a given `ClassSet`.
     pub(crate) fn matches(&self, class_set: &ClassSet) -> bool {
         self.classes.is_subset(&class_set.classes)
@@ -66,5 +75,6 @@ impl Selector {
             && self
                 .has_focus_target
                 .is_none_or(|v| class_set.has_focus_target == v)
+            && self.is_invalid.is_none_or(|v| class_set.is_invalid == v)
     }
 }
@@ -139,7 +139,11 @@ impl<'w, W: Widget + ?Sized> WidgetRef<'w, W> {
                     widget_state: state,
                     properties: PropertiesRef {
                         local: properties,
-                        default_map: self.ctx.properties.default_map,
+                        default_map: self
+                            .ctx
+                            .property_arena
+                            .default_properties
+                            .for_widget(widget.type_id()),
                         stack,
                         class_set,
                     },
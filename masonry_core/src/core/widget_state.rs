@@ -9,7 +9,7 @@ use tracing::Span;
 use crate::core::{
     ClassSetDiff, PaintLayerMode, PropertyCache, PropertyStackId, WidgetId, WidgetOptions,
 };
-use crate::layout::MeasurementCache;
+use crate::layout::{Anchor, MeasurementCache};
 
 // TODO - Reduce WidgetState size.
 // See https://github.com/linebender/xilem/issues/706
@@ -151,8 +151,14 @@ pub(crate) struct WidgetState {
     pub(crate) window_transform: Affine,
     /// Translation applied by scrolling, applied after applying `transform` to this widget.
     pub(crate) scroll_translation: Vec2,
-    /// The effective compose transform has changed because `transform`, `scroll_translation`, or
-    /// `origin` has changed.
+    /// Anchor set by the parent, see [`LayoutCtx::set_child_anchor`](crate::core::LayoutCtx::set_child_anchor).
+    pub(crate) anchor: Option<Anchor>,
+    /// Translation moving this widget to the position described by `anchor`.
+    ///
+    /// Resolved after the compose pass, and applied on top of `scroll_translation`.
+    pub(crate) anchor_translation: Vec2,
+    /// The effective compose transform has changed because `transform`, `scroll_translation`,
+    /// `anchor_translation`, or `origin` has changed.
     pub(crate) transform_changed: bool,
 
     // --- INTERACTIONS ---
@@ -205,6 +211,9 @@ pub(crate) struct WidgetState {
     pub(crate) needs_paint: bool,
     /// How this widget subtree should be represented in the current paint pass.
     pub(crate) paint_layer_mode: PaintLayerMode,
+    /// The paint-box in window coordinates at the time of the last paint pass,
+    /// or `None` if the widget wasn't painted (e.g. because it was stashed).
+    pub(crate) painted_rect: Option<Rect>,
 
     /// The `accessibility` method must be called on this widget
     pub(crate) request_accessibility: bool,
@@ -216,7 +225,8 @@ pub(crate) struct WidgetState {
     /// An animation must run on this widget or a descendant
     pub(crate) needs_anim: bool,
 
-    /// This widget or a descendant changed its `is_explicitly_disabled` value
+    /// This widget or a descendant changed its `is_explicitly_disabled` value,
+    /// its `is_explicitly_invalid` value or its `explicit_error_message`.
     pub(crate) needs_update_disabled: bool,
     /// This widget or a descendant changed its `is_explicitly_stashed` value
     pub(crate) needs_update_stashed: bool,
@@ -239,6 +249,15 @@ pub(crate) struct WidgetState {
     /// This widget or an ancestor has been disabled.
     pub(crate) is_disabled: bool,
 
+    /// This widget has been marked invalid.
+    pub(crate) is_explicitly_invalid: bool,
+    /// This widget or an ancestor has been marked invalid.
+    pub(crate) is_invalid: bool,
+    /// The widget describing why this widget is invalid, as set on this widget.
+    pub(crate) explicit_error_message: Option<WidgetId>,
+    /// The widget describing why this widget or its closest ancestor with one is invalid.
+    pub(crate) error_message: Option<WidgetId>,
+
     /// This widget has been stashed.
     pub(crate) is_explicitly_stashed: bool,
     /// This widget or an ancestor has been stashed.
@@ -305,6 +324,8 @@ impl WidgetState {
             transform: options.transform,
             window_transform: Affine::IDENTITY,
             scroll_translation: Vec2::ZERO,
+            anchor: None,
+            anchor_translation: Vec2::ZERO,
             transform_changed: false,
 
             action_type,
@@ -326,6 +347,7 @@ impl WidgetState {
             request_post_paint: true,
             needs_paint: true,
             paint_layer_mode: PaintLayerMode::Inline,
+            painted_rect: None,
             request_accessibility: true,
             needs_accessibility: true,
             request_anim: true,
@@ -340,6 +362,10 @@ impl WidgetState {
 
             is_explicitly_disabled: options.disabled,
             is_disabled: false,
+            is_explicitly_invalid: false,
+            is_invalid: false,
+            explicit_error_message: None,
+            error_message: None,
             is_explicitly_stashed: false,
             is_stashed: false,
             has_hovered: false,
@@ -433,11 +459,12 @@ impl WidgetState {
     ///
     /// This maps from this widget's border-box coordinate space into its
     /// parent's border-box coordinate space. It includes the widget's local
-    /// transform, scroll translation, and origin.
+    /// transform, scroll translation, anchor translation, and origin.
     pub(crate) fn compose_local_transform(&self) -> Affine {
         // The translation needs to be applied after the local transform so scrolling
         // and layout origin are in the transformed coordinate space, similar to CSS.
-        let local_translation = self.scroll_translation + self.origin.to_vec2();
+        let local_translation =
+            self.scroll_translation + self.anchor_translation + self.origin.to_vec2();
         self.transform.then_translate(local_translation)
     }
 
@@ -0,0 +1,71 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::core::WidgetId;
+use crate::kurbo::{Point, Rect, Size, Vec2};
+use crate::layout::UnitPoint;
+
+/// Describes how a widget is positioned relative to another widget's border-box.
+///
+/// An anchored widget is moved after layout so that its `self_point` lands on the
+/// `target_point` of the target widget, plus `offset`.
+/// Both points are resolved in window coordinates, so the anchored widget follows
+/// its target across layout changes, scrolling, and transforms of either widget's ancestors.
+///
+/// If the target isn't in the widget tree or is stashed, the anchored widget
+/// stays at the position given by its parent.
+///
+/// See [`LayoutCtx::set_child_anchor`](crate::core::LayoutCtx::set_child_anchor).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Anchor {
+    /// The widget to anchor to.
+    pub target: WidgetId,
+    /// The point of the target's border-box the anchored widget is attached to.
+    pub target_point: UnitPoint,
+    /// The point of the anchored widget's border-box which is attached to the target.
+    pub self_point: UnitPoint,
+    /// Additional offset applied after aligning the two points, in window coordinates.
+    pub offset: Vec2,
+}
+
+impl Anchor {
+    /// Creates an anchor which aligns the top-left corners of the anchored widget and `target`.
+    pub const fn new(target: WidgetId) -> Self {
+        Self {
+            target,
+            target_point: UnitPoint::TOP_LEFT,
+            self_point: UnitPoint::TOP_LEFT,
+            offset: Vec2::ZERO,
+        }
+    }
+
+    /// Creates an anchor which places the anchored widget right below `target`,
+    /// with their left edges aligned.
+    ///
+    /// This is the usual placement of dropdown menus.
+    pub const fn below(target: WidgetId) -> Self {
+        Self::new(target).with_points(UnitPoint::BOTTOM_LEFT, UnitPoint::TOP_LEFT)
+    }
+
+    /// Sets the points of the target's border-box and of the anchored widget's
+    /// border-box which are aligned with each other.
+    pub const fn with_points(mut self, target_point: UnitPoint, self_point: UnitPoint) -> Self {
+        self.target_point = target_point;
+        self.self_point = self_point;
+        self
+    }
+
+    /// Sets the offset applied after aligning the two points.
+    pub const fn with_offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Returns the origin of the anchored widget's border-box, given the target's rect
+    /// and the anchored widget's size, in the coordinate space of `target_rect`.
+    pub fn resolve(&self, target_rect: Rect, size: Size) -> Point {
+        let attach_point = self.target_point.resolve(target_rect);
+        let self_offset = self.self_point.resolve(size.to_rect()).to_vec2();
+        attach_point - self_offset + self.offset
+    }
+}
@@ -3,6 +3,7 @@
 
 //! Core layout types and traits Masonry is built on.
 
+mod anchor;
 mod as_unit;
 mod dim;
 mod layout_size;
@@ -13,6 +14,7 @@ mod measurement_cache;
 mod size_def;
 mod unit_point;
 
+pub use anchor::*;
 pub use as_unit::*;
 pub use dim::*;
 pub use layout_size::*;
@@ -49,6 +49,8 @@
 //!
 //! - `tracy`: Enables creating output for the [Tracy](https://github.com/wolfpld/tracy) profiler using [`tracing-tracy`][tracing_tracy].
 //!   This can be used by installing Tracy and connecting to a Masonry with this feature enabled.
+//! - `interaction_script`: Enables recording input events into serializable scripts and replaying them,
+//!   in the `app::interaction_script` module.
 //!
 //! [Masonry's documentation]: https://docs.rs/masonry/latest/
 //! [Masonry Winit]: https://docs.rs/masonry_winit/latest/
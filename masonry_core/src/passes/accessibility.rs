@@ -128,6 +128,13 @@ fn build_access_node(
     if ctx.is_disabled() {
         node.set_disabled();
     }
+    if ctx.is_invalid() {
+        node.set_invalid(accesskit::Invalid::True);
+        if let Some(message) = ctx.widget_state.error_message {
+            node.push_described_by(message.into());
+            node.set_error_message(message.into());
+        }
+    }
     if ctx.widget_state.clip_path.is_some() {
         node.set_clips_children();
     }
@@ -1,13 +1,13 @@
 // Copyright 2024 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use kurbo::Affine;
-use tracing::info_span;
+use kurbo::{Affine, Point, Vec2};
+use tracing::{info_span, warn};
 use tree_arena::ArenaMut;
 
 use crate::app::{RenderRoot, RenderRootState};
-use crate::core::{ComposeCtx, PropertyArena, WidgetArenaNode};
-use crate::passes::{enter_span_if, recurse_on_children};
+use crate::core::{ComposeCtx, PropertyArena, WidgetArenaNode, WidgetId};
+use crate::passes::{enter_span_if, merge_state_up, recurse_on_children};
 
 // --- MARK: RECURSE
 fn compose_widget(
@@ -74,6 +74,72 @@ fn compose_widget(
     });
 }
 
+// --- MARK: ANCHORS
+/// Updates the anchor translation of every anchored widget to match its target's
+/// current window rect.
+///
+/// Returns `true` if any widget moved, in which case compose must run again.
+fn resolve_anchors(root: &mut RenderRoot) -> bool {
+    let arena = &mut root.widget_arena;
+    let mut moved = false;
+
+    let anchored: Vec<WidgetId> = root.global_state.anchored_widgets.iter().copied().collect();
+    for id in anchored {
+        let anchor = if arena.has(id) {
+            arena.get_state(id).anchor
+        } else {
+            None
+        };
+        let Some(anchor) = anchor else {
+            root.global_state.anchored_widgets.remove(&id);
+            continue;
+        };
+
+        let target_rect = if arena.has(anchor.target) {
+            let target = arena.get_state(anchor.target);
+            (!target.is_stashed).then(|| {
+                target
+                    .window_transform
+                    .transform_rect_bbox(target.border_box())
+            })
+        } else {
+            None
+        };
+
+        let state = arena.get_state_mut(id);
+        let translation = match target_rect {
+            Some(target_rect) if !state.is_stashed => {
+                // Map the desired window position into the parent's border-box space,
+                // and compare it to where the widget would be without an anchor.
+                let parent_transform =
+                    state.window_transform * state.compose_local_transform().inverse();
+                let anchored_origin = anchor.resolve(target_rect, state.border_box().size());
+                let unanchored_origin = state
+                    .transform
+                    .then_translate(state.scroll_translation + state.origin.to_vec2())
+                    * Point::ORIGIN;
+                (parent_transform.inverse() * anchored_origin - unanchored_origin).round()
+            }
+            _ => Vec2::ZERO,
+        };
+        if translation == state.anchor_translation {
+            continue;
+        }
+        state.anchor_translation = translation;
+        state.transform_changed = true;
+        state.needs_compose = true;
+        moved = true;
+
+        let mut current_id = id;
+        while let Some(parent_id) = arena.parent_of(current_id) {
+            merge_state_up(arena, current_id);
+            current_id = parent_id;
+        }
+    }
+
+    moved
+}
+
 // --- MARK: ROOT
 /// See the [passes documentation](crate::doc::pass_system#compose-pass).
 pub(crate) fn run_compose_pass(root: &mut RenderRoot) {
@@ -93,4 +159,29 @@ pub(crate) fn run_compose_pass(root: &mut RenderRoot) {
         false,
         Affine::IDENTITY,
     );
+
+    // Anchored widgets depend on the window transforms computed above,
+    // so they can only be moved once the rest of the tree has been composed.
+    // Anchors can target (or be inside of) other anchored widgets, whose transforms are only
+    // up to date after the next compose, so we repeat until no widget moves.
+    // Each iteration settles at least one more link in every chain of anchors, so only anchors
+    // which depend on each other in a cycle can still be moving after the last iteration.
+    let max_iterations = root.global_state.anchored_widgets.len() + 1;
+    for _ in 0..max_iterations {
+        if !resolve_anchors(root) {
+            return;
+        }
+        root.global_state.needs_pointer_pass = true;
+        let root_node = root.widget_arena.get_node_mut(root.root_id());
+        compose_widget(
+            &mut root.global_state,
+            &root.property_arena,
+            root_node,
+            false,
+            Affine::IDENTITY,
+        );
+    }
+    warn!(
+        "Anchored widgets were still moving after {max_iterations} iterations, anchors may form a cycle"
+    );
 }
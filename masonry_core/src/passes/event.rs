@@ -338,6 +338,19 @@ pub(crate) fn run_on_text_event_pass(root: &mut RenderRoot, event: &TextEvent) -
             handled = Handled::Yes;
         }
 
+        if key.key == Key::Named(NamedKey::F12)
+            && key.modifiers.shift()
+            && key.state == KeyState::Down
+            && handled == Handled::No
+        {
+            let inspector_state = &mut root.global_state.inspector_state;
+            inspector_state.layout_debugger = !inspector_state.layout_debugger;
+            inspector_state.layout_debugger_target = None;
+            root.global_state.needs_pointer_pass = true;
+            root.root_state_mut().needs_paint = true;
+            handled = Handled::Yes;
+        }
+
         if key.key == Key::Named(NamedKey::F12)
             && key.state == KeyState::Down
             && handled == Handled::No
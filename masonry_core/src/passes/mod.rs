@@ -10,6 +10,7 @@
 use tracing::span::EnteredSpan;
 use tree_arena::{ArenaMut, ArenaMutList};
 
+use crate::app::frame_profile::{end_widget_visit, start_widget_visit};
 use crate::core::{Widget, WidgetArena, WidgetArenaNode, WidgetId, WidgetState};
 
 pub(crate) mod accessibility;
@@ -22,14 +23,35 @@ pub(crate) mod mutate;
 pub(crate) mod paint;
 pub(crate) mod update;
 
+/// The visit of a pass to a widget.
+///
+/// Closes the widget's tracing span, if it was entered, and reports the visit
+/// to the frame profiler when dropped.
+pub(crate) struct WidgetSpan {
+    /// Only held so the span stays entered until the visit ends.
+    _span: Option<EnteredSpan>,
+    profiled: Option<(WidgetId, &'static str)>,
+}
+
+impl Drop for WidgetSpan {
+    fn drop(&mut self) {
+        if let Some((id, name)) = self.profiled {
+            end_widget_visit(id, name);
+        }
+    }
+}
+
 #[must_use = "Span will be immediately closed if dropped"]
-pub(crate) fn enter_span_if(enabled: bool, state: &WidgetState) -> Option<EnteredSpan> {
-    enabled.then(|| enter_span(state))
+pub(crate) fn enter_span_if(enabled: bool, state: &WidgetState) -> WidgetSpan {
+    WidgetSpan {
+        _span: enabled.then(|| state.trace_span.clone().entered()),
+        profiled: start_widget_visit().then_some((state.id, state.widget_name)),
+    }
 }
 
 #[must_use = "Span will be immediately closed if dropped"]
-pub(crate) fn enter_span(state: &WidgetState) -> EnteredSpan {
-    state.trace_span.clone().entered()
+pub(crate) fn enter_span(state: &WidgetState) -> WidgetSpan {
+    enter_span_if(true, state)
 }
 
 pub(crate) fn recurse_on_children(
@@ -3,35 +3,62 @@
 
 use std::collections::HashMap;
 
-use kurbo::{Affine, Line, Stroke};
+use kurbo::{Affine, Line, Point, Rect, RoundedRect, Size, Stroke, Vec2};
+use parley::StyleProperty;
 use peniko::{Color, Fill};
 use tracing::{info_span, trace};
-use tree_arena::ArenaMut;
+use tree_arena::{ArenaMut, ArenaRef};
 
-use crate::app::{RenderRoot, RenderRootState, VisualLayer, VisualLayerKind, VisualLayerPlan};
+use crate::app::{
+    PaintCacheStats, RenderRoot, RenderRootState, VisualLayer, VisualLayerKind, VisualLayerPlan,
+};
 use crate::core::{
     DefaultProperties, PaintCtx, PaintLayerMode, PropertiesRef, PropertyArena, WidgetArenaNode,
-    WidgetId,
+    WidgetId, WidgetState, render_text,
 };
 use crate::imaging::record::{Clip, Geometry, Scene};
 use crate::imaging::{PaintSink, Painter};
 use crate::passes::{enter_span_if, recurse_on_children};
+use crate::properties::{BorderWidth, Dimensions, Padding};
 use crate::util::get_debug_color;
 
 struct LayerCollector {
     current_scene: Scene,
-    layers: Vec<VisualLayer>,
+    /// The finished layers, each paired with whether a widget painted into it changed.
+    layers: Vec<(VisualLayer, bool)>,
     current_owner_id: WidgetId,
+    /// Whether the current scene belongs to a widget with [`PaintLayerMode::IsolatedScene`].
+    current_isolated: bool,
+    /// The bounding box of the current owner, in layer coordinates.
+    current_bounds: Rect,
+    /// Whether a widget painted into the current scene changed since the last paint pass.
+    current_changed: bool,
     transform: Affine,
+    /// Whether a widget is recording its subtree for the [paint cache](CachedSubtree).
+    is_recording: bool,
+    /// The scene painted before the subtree being recorded, if no layer boundary
+    /// interrupted the recording yet.
+    recording_outer: Option<Scene>,
+    /// Layout debugger outlines, in window coordinates.
+    debug_overlay: Scene,
+    /// The widget described by the layout debugger panel, if it's in this layer.
+    inspected_widget: Option<InspectedWidget>,
 }
 
 impl LayerCollector {
-    fn new(root_id: WidgetId, transform: Affine) -> Self {
+    fn new(root_id: WidgetId, transform: Affine, bounds: Rect) -> Self {
         Self {
             current_scene: Scene::new(),
             layers: Vec::new(),
             current_owner_id: root_id,
+            current_isolated: false,
+            current_bounds: bounds,
+            current_changed: false,
             transform,
+            is_recording: false,
+            recording_outer: None,
+            debug_overlay: Scene::new(),
+            inspected_widget: None,
         }
     }
 
@@ -40,33 +67,68 @@ impl LayerCollector {
     }
 
     fn finish_current_layer(&mut self, allow_empty: bool) {
+        // A layer boundary splits the recorded subtree across layers, so the recording
+        // is abandoned and what was painted before it is put back in place.
+        if let Some(mut outer) = self.recording_outer.take() {
+            outer.append_transformed(&self.current_scene, Affine::IDENTITY);
+            self.current_scene = outer;
+        }
+
+        let changed = std::mem::take(&mut self.current_changed);
         let empty_scene = Scene::new();
         if !allow_empty && self.current_scene == empty_scene {
             return;
         }
 
         let scene = std::mem::replace(&mut self.current_scene, empty_scene);
-        self.layers.push(VisualLayer {
-            kind: VisualLayerKind::Scene(scene),
-            transform: self.transform,
-            widget_id: self.current_owner_id,
-        });
+        self.layers.push((
+            VisualLayer {
+                kind: VisualLayerKind::Scene(scene),
+                transform: self.transform,
+                widget_id: self.current_owner_id,
+                isolated: self.current_isolated,
+                bounds: self.current_bounds,
+                // Set by `run_paint_pass` once all layers are collected.
+                generation: 0,
+            },
+            changed,
+        ));
     }
 
-    fn push_external_layer(&mut self, widget_id: WidgetId, bounds: kurbo::Rect) {
-        self.layers.push(VisualLayer {
-            kind: VisualLayerKind::External { bounds },
-            transform: self.transform,
-            widget_id,
-        });
+    fn push_external_layer(&mut self, widget_id: WidgetId, bounds: Rect, changed: bool) {
+        self.layers.push((
+            VisualLayer {
+                kind: VisualLayerKind::External { bounds },
+                transform: self.transform,
+                widget_id,
+                isolated: false,
+                bounds,
+                generation: 0,
+            },
+            changed,
+        ));
     }
 
-    fn into_layers(mut self) -> Vec<VisualLayer> {
+    fn into_layers(mut self) -> Vec<(VisualLayer, bool)> {
         self.finish_current_layer(self.layers.is_empty());
         self.layers
     }
 }
 
+/// The paint output of a widget subtree which didn't need to be repainted.
+///
+/// When a subtree has no paint invalidation and its transform didn't change, the cached
+/// scene is spliced into the layer instead of visiting the subtree again.
+/// Only the outermost clean subtrees are cached, so that the scenes aren't duplicated.
+#[derive(Debug)]
+pub(crate) struct CachedSubtree {
+    /// Transform from the widget's border-box to its layer.
+    transform: Affine,
+    /// The subtree's scene in layer coordinates, or `None` if the subtree creates
+    /// layer boundaries and can't be spliced as a single scene.
+    scene: Option<Scene>,
+}
+
 // --- MARK: PAINT WIDGET
 fn paint_widget(
     global_state: &mut RenderRootState,
@@ -91,16 +153,18 @@ fn paint_widget(
     // but we deliberately avoid doing that to avoid creating zombie flags.
     // (See WidgetState doc.)
     let is_stashed = state.is_stashed;
+    let subtree_is_clean = !state.needs_paint;
+    let is_repainted = state.request_pre_paint || state.request_paint || state.request_post_paint;
 
-    // TODO - Handle damage regions
-    // https://github.com/linebender/xilem/issues/789
-
-    state.paint_layer_mode = PaintLayerMode::Inline;
-    if (state.request_pre_paint || state.request_paint || state.request_post_paint) && !is_stashed {
+    if is_repainted && !is_stashed {
         if trace {
             trace!("Painting widget '{}' {}", widget.short_type_name(), id);
         }
 
+        // The widget sets its mode again while painting.
+        // If it isn't repainted, the mode from its last paint is kept.
+        state.paint_layer_mode = PaintLayerMode::Inline;
+
         let stack = property_arena.get(state.property_stack_id, widget.type_id());
         let mut ctx = PaintCtx {
             global_state,
@@ -156,10 +220,22 @@ fn paint_widget(
     }
 
     let previous_owner_id = layer_collector.current_owner_id;
+    let previous_isolated = layer_collector.current_isolated;
+    let previous_bounds = layer_collector.current_bounds;
     layer_collector.current_owner_id = match paint_layer_mode {
         PaintLayerMode::Inline => previous_owner_id,
         PaintLayerMode::IsolatedScene | PaintLayerMode::External => id,
     };
+    layer_collector.current_isolated = match paint_layer_mode {
+        PaintLayerMode::Inline => previous_isolated,
+        PaintLayerMode::IsolatedScene => true,
+        PaintLayerMode::External => false,
+    };
+    if paint_layer_mode != PaintLayerMode::Inline {
+        layer_collector.current_bounds =
+            window_to_layer_transform.transform_rect_bbox(state.bounding_box);
+    }
+    layer_collector.current_changed |= !subtree_is_clean;
 
     let border_box_to_layer_transform = *window_to_layer_transform * state.window_transform;
     let content_box_to_layer_transform =
@@ -167,6 +243,60 @@ fn paint_widget(
     let has_clip = state.clip_path.is_some();
     let paint_as_external = paint_layer_mode == PaintLayerMode::External;
 
+    // --- MARK: DAMAGE
+    if !subtree_is_clean {
+        let painted_rect = (!is_stashed).then(|| {
+            state
+                .window_transform
+                .transform_rect_bbox(state.paint_box())
+        });
+        if is_repainted || painted_rect != state.painted_rect {
+            for rect in [state.painted_rect, painted_rect].into_iter().flatten() {
+                global_state.add_damage(rect);
+            }
+        }
+        state.painted_rect = painted_rect;
+    }
+
+    // --- MARK: SUBTREE CACHE
+    if !subtree_is_clean {
+        global_state.paint_subtree_cache.remove(&id);
+    }
+    let can_use_cache = subtree_is_clean
+        && paint_layer_mode == PaintLayerMode::Inline
+        && !is_stashed
+        && !global_state.debug_paint
+        && !global_state.inspector_state.layout_debugger
+        && global_state.inspector_state.hovered_widget.is_none()
+        && !widget.children_ids().is_empty();
+    let mut is_recording = false;
+    if can_use_cache {
+        match global_state.paint_subtree_cache.get(&id) {
+            Some(cached) if cached.transform == border_box_to_layer_transform => {
+                // Otherwise, the subtree has layer boundaries and is painted as usual.
+                if let Some(scene) = &cached.scene {
+                    layer_collector
+                        .scene_mut()
+                        .append_transformed(scene, Affine::IDENTITY);
+                    global_state.paint_cache_stats.hits += 1;
+                    if layer_collector.is_recording {
+                        // The ancestor being recorded caches this subtree from now on.
+                        global_state.paint_subtree_cache.remove(&id);
+                    }
+                    return;
+                }
+            }
+            _ if !layer_collector.is_recording => {
+                global_state.paint_cache_stats.misses += 1;
+                is_recording = true;
+                layer_collector.is_recording = true;
+                layer_collector.recording_outer =
+                    Some(std::mem::take(&mut layer_collector.current_scene));
+            }
+            _ => {}
+        }
+    }
+
     if !is_stashed && !paint_as_external {
         let Some((pre_scene, scene, _)) = &mut scene_cache.get(&id) else {
             debug_panic!(
@@ -211,6 +341,8 @@ fn paint_widget(
         );
         parent_state.merge_up(&mut node.item.state);
     });
+    // A child with its own layer may have finished the scene this widget started painting into.
+    layer_collector.current_changed |= !subtree_is_clean;
 
     if !is_stashed && !paint_as_external {
         if global_state.debug_paint {
@@ -265,8 +397,42 @@ fn paint_widget(
         }
     }
 
+    if global_state.inspector_state.layout_debugger && !is_stashed {
+        let props = PropertiesRef {
+            local: properties,
+            default_map: default_properties.for_widget(widget.type_id()),
+            stack: property_arena.get(state.property_stack_id, widget.type_id()),
+            class_set,
+        };
+        let is_target = global_state.inspector_state.layout_debugger_target == Some(id);
+        paint_layout_debug_outlines(&mut layer_collector.debug_overlay, state, &props, is_target);
+        if is_target {
+            layer_collector.inspected_widget = Some(InspectedWidget::new(state, &props));
+        }
+    }
+
+    if is_recording {
+        layer_collector.is_recording = false;
+        let scene = layer_collector.recording_outer.take().map(|mut outer| {
+            std::mem::swap(&mut outer, &mut layer_collector.current_scene);
+            layer_collector
+                .current_scene
+                .append_transformed(&outer, Affine::IDENTITY);
+            outer
+        });
+        global_state.paint_subtree_cache.insert(
+            id,
+            CachedSubtree {
+                transform: border_box_to_layer_transform,
+                scene,
+            },
+        );
+    }
+
     if paint_as_external {
-        layer_collector.push_external_layer(id, state.border_box());
+        // The bounds are stored in layer-local coordinates.
+        let bounds = border_box_to_layer_transform.transform_rect_bbox(state.border_box());
+        layer_collector.push_external_layer(id, bounds, !subtree_is_clean);
     }
 
     if matches!(
@@ -277,12 +443,31 @@ fn paint_widget(
     }
 
     layer_collector.current_owner_id = previous_owner_id;
+    layer_collector.current_isolated = previous_isolated;
+    layer_collector.current_bounds = previous_bounds;
 }
 
 // --- MARK: ROOT
 /// See the [passes documentation](crate::doc::pass_system#render-passes).
 pub(crate) fn run_paint_pass(root: &mut RenderRoot) -> VisualLayerPlan {
-    let _span = info_span!("paint").entered();
+    let span = info_span!(
+        "paint",
+        cache_hits = tracing::field::Empty,
+        cache_misses = tracing::field::Empty
+    )
+    .entered();
+    root.global_state.paint_cache_stats = PaintCacheStats::default();
+
+    // Debug visuals aren't tracked per widget, so they damage the whole window
+    // while they're shown, and once more when they're hidden.
+    let inspector_state = &root.global_state.inspector_state;
+    let shows_debug_visuals = root.global_state.debug_paint
+        || inspector_state.layout_debugger
+        || inspector_state.hovered_widget.is_some();
+    if shows_debug_visuals || root.global_state.painted_debug_visuals {
+        root.global_state.damage_all = true;
+    }
+    root.global_state.painted_debug_visuals = shows_debug_visuals;
 
     // TODO - This is a bit of a hack until we refactor widget tree mutation.
     // This should be removed once remove_child is exclusive to MutateCtx.
@@ -292,17 +477,22 @@ pub(crate) fn run_paint_pass(root: &mut RenderRoot) -> VisualLayerPlan {
     let layer_root_ids = root.layer_root_ids();
 
     let mut layers = Vec::new();
+    let mut debug_overlay = Scene::new();
+    let mut inspected_widget = None;
 
     for (idx, &layer_widget_id) in layer_root_ids.iter().enumerate() {
+        let layer_state = root.widget_arena.get_state(layer_widget_id);
         let transform = if idx == 0 {
             Affine::IDENTITY
         } else {
-            root.widget_arena
-                .get_state(layer_widget_id)
-                .window_transform
+            layer_state.window_transform
         };
+        let bounds = layer_state
+            .window_transform
+            .inverse()
+            .transform_rect_bbox(layer_state.bounding_box);
 
-        let mut collector = LayerCollector::new(layer_widget_id, transform);
+        let mut collector = LayerCollector::new(layer_widget_id, transform, bounds);
         paint_layer(
             root,
             &mut collector,
@@ -310,12 +500,68 @@ pub(crate) fn run_paint_pass(root: &mut RenderRoot) -> VisualLayerPlan {
             root_id,
             layer_widget_id,
         );
+        debug_overlay.append_transformed(&collector.debug_overlay, Affine::IDENTITY);
+        inspected_widget = collector.inspected_widget.take().or(inspected_widget);
         layers.extend(collector.into_layers());
     }
 
     root.global_state.scene_cache = scene_cache;
 
-    VisualLayerPlan { layers }
+    let stats = root.global_state.paint_cache_stats;
+    span.record("cache_hits", stats.hits);
+    span.record("cache_misses", stats.misses);
+
+    // The layout debugger is drawn above every layer, so that outlines of
+    // the base layer aren't hidden by menus or tooltips.
+    if root.global_state.inspector_state.layout_debugger {
+        if let Some(inspected_widget) = inspected_widget {
+            let window_size = root.get_kurbo_size();
+            inspected_widget.paint_panel(&mut root.global_state, &mut debug_overlay, window_size);
+        }
+        layers.push((
+            VisualLayer {
+                kind: VisualLayerKind::Scene(debug_overlay),
+                transform: Affine::IDENTITY,
+                widget_id: root_id,
+                isolated: false,
+                bounds: root.get_kurbo_size().to_rect(),
+                generation: 0,
+            },
+            true,
+        ));
+    }
+
+    // Layers which didn't change keep the generation they had in the previous paint pass.
+    root.global_state.paint_generation += 1;
+    let generation = root.global_state.paint_generation;
+    let changed_all = root.global_state.damage_all;
+    let previous_generations = std::mem::take(&mut root.global_state.layer_generations);
+    let mut parts = HashMap::<WidgetId, u32>::new();
+    let layers = layers
+        .into_iter()
+        .map(|(mut layer, changed)| {
+            let part = parts.entry(layer.widget_id).or_default();
+            let key = (layer.widget_id, *part);
+            *part += 1;
+            layer.generation = match previous_generations.get(&key) {
+                Some(&previous) if !changed && !changed_all => previous,
+                _ => generation,
+            };
+            root.global_state
+                .layer_generations
+                .insert(key, layer.generation);
+            layer
+        })
+        .collect();
+
+    let damage = root.global_state.damage.take();
+    let damage = if std::mem::take(&mut root.global_state.damage_all) {
+        None
+    } else {
+        Some(damage.unwrap_or(Rect::ZERO))
+    };
+
+    VisualLayerPlan { layers, damage }
 }
 
 /// Paint a single layer's widget subtree into `target_scene`.
@@ -361,3 +607,253 @@ fn paint_layer(
         layer_node,
     );
 }
+
+// --- MARK: SUBTREE SCENE
+/// Returns the paint output of the widget with the given id and its descendants,
+/// in the widget's border-box coordinates.
+///
+/// This replays the scenes cached by the last paint pass, without debug visuals.
+pub(crate) fn paint_subtree_scene(root: &RenderRoot, id: WidgetId) -> Option<Scene> {
+    let node = root.widget_arena.nodes.find(id)?;
+    let window_to_scene_transform = node.item.state.window_transform.inverse();
+    let mut scene = Scene::new();
+    append_subtree_scene(
+        &mut scene,
+        &root.global_state.scene_cache,
+        &window_to_scene_transform,
+        node,
+    );
+    Some(scene)
+}
+
+fn append_subtree_scene(
+    target: &mut Scene,
+    scene_cache: &HashMap<WidgetId, (Scene, Scene, Scene)>,
+    window_to_scene_transform: &Affine,
+    node: ArenaRef<'_, WidgetArenaNode>,
+) {
+    let widget = &*node.item.widget;
+    let state = &node.item.state;
+    if state.is_stashed {
+        return;
+    }
+
+    let border_box_to_scene_transform = *window_to_scene_transform * state.window_transform;
+    let content_box_to_scene_transform =
+        border_box_to_scene_transform.pre_translate(state.border_box_translation());
+    // External layers have no content of their own.
+    let scenes = scene_cache
+        .get(&state.id)
+        .filter(|_| state.paint_layer_mode != PaintLayerMode::External);
+
+    if let Some((pre_scene, scene, _)) = scenes {
+        target.append_transformed(pre_scene, content_box_to_scene_transform);
+        if let Some(clip) = state.clip_path {
+            target.push_clip(Clip::Fill {
+                transform: border_box_to_scene_transform,
+                shape: Geometry::Rect(clip),
+                fill_rule: Fill::NonZero,
+            });
+        }
+        target.append_transformed(scene, content_box_to_scene_transform);
+    }
+
+    for &child_id in widget.children_ids().iter() {
+        if let Some(child) = node.children.item(child_id) {
+            append_subtree_scene(target, scene_cache, window_to_scene_transform, child);
+        }
+    }
+
+    if let Some((_, _, post_scene)) = scenes {
+        if state.clip_path.is_some() {
+            target.pop_clip();
+        }
+        target.append_transformed(post_scene, content_box_to_scene_transform);
+    }
+}
+
+// --- MARK: LAYOUT DEBUGGER
+const DEBUG_BORDER_FILL: Color = Color::from_rgba8(250, 180, 80, 90);
+const DEBUG_PADDING_FILL: Color = Color::from_rgba8(150, 220, 120, 90);
+const DEBUG_CONTENT_FILL: Color = Color::from_rgba8(100, 160, 230, 90);
+const DEBUG_CLIP_COLOR: Color = Color::from_rgb8(230, 60, 60);
+const DEBUG_PANEL_FILL: Color = Color::from_rgba8(20, 20, 30, 230);
+const DEBUG_PANEL_TEXT: Color = Color::from_rgb8(240, 240, 240);
+
+/// Paints the layout debugger outlines of one widget into `scene`, in window coordinates.
+///
+/// All widgets get their border-box, padding-box, content-box, baselines, and clip outlined.
+/// The widget under the pointer additionally gets its boxes filled, the same way browser
+/// developer tools highlight an element.
+fn paint_layout_debug_outlines(
+    scene: &mut Scene,
+    state: &WidgetState,
+    props: &PropertiesRef<'_>,
+    is_target: bool,
+) {
+    let color = get_debug_color(state.id.to_raw());
+    let transform = state.window_transform;
+    let border_width = props
+        .get_without_saving::<BorderWidth>(&state.property_cache)
+        .width
+        .get();
+
+    let border_box = state.border_box();
+    let padding_box = border_box.inset(-border_width);
+    let content_box = state.content_box();
+
+    let mut painter = Painter::new(scene);
+    if is_target {
+        painter
+            .fill(border_box, DEBUG_BORDER_FILL)
+            .transform(transform)
+            .draw();
+        painter
+            .fill(padding_box, DEBUG_PADDING_FILL)
+            .transform(transform)
+            .draw();
+        painter
+            .fill(content_box, DEBUG_CONTENT_FILL)
+            .transform(transform)
+            .draw();
+    }
+
+    let solid = Stroke::new(1.0);
+    let dashed = Stroke::new(1.0).with_dashes(0., [2.0, 2.0]);
+    painter
+        .stroke(border_box.inset(-0.5), &solid, color)
+        .transform(transform)
+        .draw();
+    if padding_box != border_box {
+        painter
+            .stroke(padding_box.inset(-0.5), &dashed, color)
+            .transform(transform)
+            .draw();
+    }
+    if content_box != padding_box {
+        painter
+            .stroke(content_box.inset(-0.5), &dashed, color)
+            .transform(transform)
+            .draw();
+    }
+
+    let baseline_style = Stroke::new(1.0).with_dashes(0., [4.0, 4.0]);
+    for baseline in [state.first_baseline, state.last_baseline] {
+        if baseline.is_nan() {
+            continue;
+        }
+        let line = Line::new((border_box.x0, baseline), (border_box.x1, baseline));
+        painter
+            .stroke(line, &baseline_style, color)
+            .transform(transform)
+            .draw();
+    }
+
+    if let Some(clip) = state.clip_path {
+        painter
+            .stroke(clip.inset(-0.5), &dashed, DEBUG_CLIP_COLOR)
+            .transform(transform)
+            .draw();
+    }
+}
+
+/// Description of the widget under the pointer, shown by the layout debugger panel.
+struct InspectedWidget {
+    /// The widget's bounding box in window coordinates.
+    bounding_box: Rect,
+    lines: Vec<String>,
+}
+
+impl InspectedWidget {
+    fn new(state: &WidgetState, props: &PropertiesRef<'_>) -> Self {
+        let cache = &state.property_cache;
+        let origin = state.window_transform * Point::ORIGIN;
+        let border_box = state.border_box();
+        let content_box = state.content_box();
+        let format_baseline = |baseline: f64| {
+            if baseline.is_nan() {
+                "none".to_string()
+            } else {
+                format!("{baseline:.1}")
+            }
+        };
+
+        let mut lines = vec![
+            format!("{} {}", state.widget_name, state.id),
+            format!(
+                "border-box: {:.1} x {:.1} at ({:.1}, {:.1})",
+                border_box.width(),
+                border_box.height(),
+                origin.x,
+                origin.y,
+            ),
+            format!(
+                "content-box: {:.1} x {:.1}",
+                content_box.width(),
+                content_box.height(),
+            ),
+            format!(
+                "baselines: {} / {}",
+                format_baseline(state.first_baseline),
+                format_baseline(state.last_baseline),
+            ),
+            format!("{:?}", props.get_without_saving::<Dimensions>(cache)),
+            format!("{:?}", props.get_without_saving::<Padding>(cache)),
+            format!("{:?}", props.get_without_saving::<BorderWidth>(cache)),
+        ];
+        if let Some(clip) = state.clip_path {
+            lines.push(format!(
+                "clip: {:.1} x {:.1} at ({:.1}, {:.1})",
+                clip.width(),
+                clip.height(),
+                clip.x0,
+                clip.y0,
+            ));
+        }
+
+        Self {
+            bounding_box: state.window_transform.transform_rect_bbox(border_box),
+            lines,
+        }
+    }
+
+    /// Paints the description next to the widget, kept inside the window.
+    fn paint_panel(&self, global_state: &mut RenderRootState, scene: &mut Scene, window: Size) {
+        const PADDING: f64 = 4.0;
+        const GAP: f64 = 4.0;
+
+        let text = self.lines.join("\n");
+        let font_ctx = &mut global_state.font_context;
+        let layout_ctx = &mut global_state.text_layout_context;
+        let mut builder = layout_ctx.ranged_builder(font_ctx, &text, 1.0, true);
+        builder.push_default(StyleProperty::FontSize(12.0));
+        let mut layout = builder.build(&text);
+        layout.break_all_lines(None);
+
+        let size = Size::new(
+            layout.width() as f64 + 2. * PADDING,
+            layout.height() as f64 + 2. * PADDING,
+        );
+        // Prefer placing the panel below the widget, and flip above if it doesn't fit.
+        let below = self.bounding_box.y1 + GAP;
+        let above = self.bounding_box.y0 - GAP - size.height;
+        let y = if below + size.height <= window.height || above < 0. {
+            below
+        } else {
+            above
+        };
+        let x = self.bounding_box.x0.min(window.width - size.width).max(0.);
+        let origin = Point::new(x, y.min(window.height - size.height).max(0.));
+
+        let mut painter = Painter::new(scene);
+        let panel = RoundedRect::from_rect(Rect::from_origin_size(origin, size), 3.0);
+        painter.fill(panel, DEBUG_PANEL_FILL).draw();
+        render_text(
+            &mut painter,
+            Affine::translate(origin.to_vec2() + Vec2::new(PADDING, PADDING)),
+            &layout,
+            &[DEBUG_PANEL_TEXT.into()],
+            true,
+        );
+    }
+}
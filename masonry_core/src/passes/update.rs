@@ -3,6 +3,7 @@
 
 use std::collections::{HashMap, HashSet};
 
+use kurbo::Point;
 use tracing::{info_span, trace};
 use tree_arena::{ArenaMut, ArenaMutList};
 use ui_events::pointer::PointerType;
@@ -11,7 +12,7 @@ use crate::app::{RenderRoot, RenderRootSignal, RenderRootState};
 use crate::core::{
     ClassSetDiff, CursorIcon, DefaultProperties, Ime, PointerEvent, PointerInfo, PropertiesMut,
     PropertiesRef, PropertyArena, PropertyCache, QueryCtx, RegisterCtx, TextEvent, Update,
-    UpdateCtx, Widget, WidgetArenaNode, WidgetId, WidgetState,
+    UpdateCtx, Widget, WidgetArenaNode, WidgetId, WidgetRef, WidgetState,
 };
 use crate::passes::event::{run_on_pointer_event_pass, run_on_text_event_pass};
 use crate::passes::{enter_span, enter_span_if, merge_state_up, recurse_on_children};
@@ -305,6 +306,8 @@ fn update_disabled_for_widget(
     property_arena: &PropertyArena,
     node: ArenaMut<'_, WidgetArenaNode>,
     parent_disabled: bool,
+    parent_invalid: bool,
+    parent_error_message: Option<WidgetId>,
 ) {
     let mut children = node.children;
     let widget = &mut *node.item.widget;
@@ -316,7 +319,13 @@ fn update_disabled_for_widget(
     let _span = enter_span(state);
 
     let disabled = state.is_explicitly_disabled || parent_disabled;
-    if !state.needs_update_disabled && disabled == state.is_disabled {
+    let invalid = state.is_explicitly_invalid || parent_invalid;
+    let error_message = state.explicit_error_message.or(parent_error_message);
+    if !state.needs_update_disabled
+        && disabled == state.is_disabled
+        && invalid == state.is_invalid
+        && error_message == state.error_message
+    {
         return;
     }
 
@@ -345,6 +354,20 @@ fn update_disabled_for_widget(
         state.needs_accessibility = true;
     }
 
+    if invalid != state.is_invalid {
+        state.is_invalid = invalid;
+        state.class_diff.is_invalid = Some(invalid);
+        state.request_update_props = true;
+        state.needs_update_props = true;
+        state.request_accessibility = true;
+        state.needs_accessibility = true;
+    }
+    if error_message != state.error_message {
+        state.error_message = error_message;
+        state.request_accessibility = true;
+        state.needs_accessibility = true;
+    }
+
     state.needs_update_disabled = false;
 
     let parent_state = state;
@@ -355,6 +378,8 @@ fn update_disabled_for_widget(
             property_arena,
             node.reborrow_mut(),
             disabled,
+            invalid,
+            error_message,
         );
         parent_state.merge_up(&mut node.item.state);
     });
@@ -375,6 +400,8 @@ pub(crate) fn run_update_disabled_pass(root: &mut RenderRoot) {
         &root.property_arena,
         root_node,
         false,
+        false,
+        None,
     );
 }
 
@@ -551,6 +578,29 @@ pub(crate) fn find_next_focusable(root: &mut RenderRoot, forward: bool) -> Optio
     find_first_focusable(root, &[], root.root_id(), forward)
 }
 
+/// Returns the widgets which pressing Tab moves the focus to, in order.
+///
+/// This is the order [`find_next_focusable`] follows, computed without moving the focus.
+pub(crate) fn collect_focus_chain(root: &RenderRoot) -> Vec<WidgetId> {
+    fn collect(root: &RenderRoot, id: WidgetId, chain: &mut Vec<WidgetId>) {
+        let node = root.widget_arena.nodes.find(id).unwrap();
+        let state = &node.item.state;
+        if !state.descendant_is_focusable {
+            return;
+        }
+        if state.accepts_focus {
+            chain.push(id);
+        }
+        for child in node.item.widget.children_ids() {
+            collect(root, child, chain);
+        }
+    }
+
+    let mut chain = Vec::new();
+    collect(root, root.root_id(), &mut chain);
+    chain
+}
+
 fn find_first_focusable(
     root: &mut RenderRoot,
     anchor_path: &[WidgetId],
@@ -821,6 +871,31 @@ pub(crate) fn run_update_scroll_pass(root: &mut RenderRoot) {
 // ----------------
 
 // --- MARK: POINTER
+/// Returns the topmost widget whose border-box contains `pos`.
+///
+/// Unlike [`Widget::find_widget_under_pointer`], this ignores whether widgets
+/// accept pointer interaction, so that the layout debugger can describe any widget.
+///
+/// [`Widget::find_widget_under_pointer`]: crate::core::Widget::find_widget_under_pointer
+fn find_widget_at(widget: WidgetRef<'_, dyn Widget>, pos: Point) -> Option<WidgetId> {
+    let ctx = widget.ctx();
+    if ctx.is_stashed() || !ctx.bounding_box().contains(pos) {
+        return None;
+    }
+    let local_pos = ctx.to_local(pos);
+    if let Some(clip) = ctx.clip_path()
+        && !clip.contains(local_pos)
+    {
+        return None;
+    }
+    for child in widget.children().into_iter().rev() {
+        if let Some(id) = find_widget_at(child, pos) {
+            return Some(id);
+        }
+    }
+    ctx.border_box().contains(local_pos).then(|| widget.id())
+}
+
 /// See the [passes documentation](crate::doc::pass_system#update-passes).
 pub(crate) fn run_update_pointer_pass(root: &mut RenderRoot) {
     if !root.global_state.needs_pointer_pass {
@@ -847,6 +922,19 @@ pub(crate) fn run_update_pointer_pass(root: &mut RenderRoot) {
         return;
     }
 
+    if root.global_state.inspector_state.layout_debugger {
+        let target = pointer_pos.and_then(|pos| {
+            let root_widget = root
+                .get_widget(root.root_id())
+                .expect("root widget not in widget tree");
+            find_widget_at(root_widget, pos)
+        });
+        if root.global_state.inspector_state.layout_debugger_target != target {
+            root.global_state.inspector_state.layout_debugger_target = target;
+            root.root_state_mut().needs_paint = true;
+        }
+    }
+
     // Release pointer capture if target can no longer hold it.
     if let Some(id) = root.global_state.pointer_capture_target
         && !root.is_still_interactive(id)
@@ -1176,6 +1264,7 @@ fn cached_props_changed(diff: &ClassSetDiff, cache: &PropertyCache) -> bool {
         || (diff.is_active.is_some() && cache.relevant_is_active)
         || (diff.is_disabled.is_some() && cache.relevant_is_disabled)
         || (diff.has_focus_target.is_some() && cache.relevant_has_focus_target)
+        || (diff.is_invalid.is_some() && cache.relevant_is_invalid)
 }
 
 pub(crate) fn run_update_props_pass(root: &mut RenderRoot) {
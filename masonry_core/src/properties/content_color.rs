@@ -8,19 +8,10 @@ use crate::peniko::color::{AlphaColor, Srgb};
 
 /// The color of a widget's content, often text and text decorations.
 ///
-/// **IMPORTANT:** This property is defined for [`Label`] and [`TextArea`], *not*
-/// for widgets embedding them such as [`Button`], [`Checkbox`], [`TextInput`], [`Prose`], etc.
+/// **IMPORTANT:** In Masonry's widget set, this property is defined for `Label` and `TextArea`,
+/// *not* for widgets embedding them such as `Button`, `Checkbox`, `TextInput`, `Prose`, etc.
 ///
-/// This property is also defined for [`Spinner`] and [`StepInput`].
-///
-/// [`Label`]: crate::widgets::Label
-/// [`TextArea`]: crate::widgets::TextArea
-/// [`Button`]: crate::widgets::Button
-/// [`Checkbox`]: crate::widgets::Checkbox
-/// [`TextInput`]: crate::widgets::TextInput
-/// [`Spinner`]: crate::widgets::Spinner
-/// [`StepInput`]: crate::widgets::StepInput
-/// [`Prose`]: crate::widgets::Prose
+/// This property is also defined for `Spinner` and `StepInput`.
 #[expect(missing_docs, reason = "field names are self-descriptive")]
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct ContentColor {
@@ -7,6 +7,7 @@ mod background;
 mod border_color;
 mod border_width;
 mod box_shadow;
+mod content_color;
 mod corner_radius;
 mod dimensions;
 mod padding;
@@ -19,6 +20,7 @@ pub use background::*;
 pub use border_color::*;
 pub use border_width::*;
 pub use box_shadow::*;
+pub use content_color::*;
 pub use corner_radius::*;
 pub use dimensions::*;
 pub use padding::*;
@@ -0,0 +1,501 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Compositing of layered Masonry frames.
+//!
+//! Unlike [`PreparedFrame`], a [`CompositeFrame`] keeps the layer structure of Masonry's
+//! paint output:
+//!
+//! - Consecutive [`CompositeLayer::Scene`] layers are flattened and rendered each frame.
+//! - [`CompositeLayer::Isolated`] layers are rendered on their own, into an image
+//!   covering their bounds, and cached; they are only re-rendered when their generation,
+//!   transform, or the area they cover in the frame changes.
+//! - [`CompositeLayer::External`] layers are filled with content supplied by the caller,
+//!   for example a 3D viewport or a video frame.
+//!
+//! The results are blended together in painter order.
+//! [`ImageCompositor`] does this on the CPU for any [`ImageRenderer`], and
+//! `texture_render::Renderer::render_composited` does it on the GPU.
+
+use std::collections::HashMap;
+
+use imaging::RgbaImage;
+use imaging::record::Scene;
+use imaging::render::{ImageRenderer, ImageRendererError};
+use kurbo::{Affine, Rect};
+use peniko::Color;
+
+use crate::{Layer, PreparedFrame};
+
+/// Identifies a layer across frames.
+///
+/// A widget can own several layers (for instance when an isolated layer is split around
+/// a nested one), so the widget id is paired with the index of the layer among the
+/// layers owned by that widget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LayerKey {
+    /// The raw id of the widget owning the layer.
+    pub id: u64,
+    /// The index of the layer among the layers owned by the same widget.
+    pub part: u32,
+}
+
+/// A layer of a [`CompositeFrame`].
+#[derive(Clone, Copy, Debug)]
+pub enum CompositeLayer<'a> {
+    /// Scene content rendered each frame, together with neighbouring scene layers.
+    Scene(Layer<'a>),
+    /// Scene content which is cached between frames.
+    Isolated {
+        /// The key used to find the cached rendering of the layer.
+        key: LayerKey,
+        /// A number which changes whenever the content of the layer changes.
+        ///
+        /// The cached rendering is reused while it stays the same.
+        generation: u64,
+        /// The content of the layer.
+        layer: Layer<'a>,
+        /// The area covered by the content, in layer-local coordinates.
+        ///
+        /// Content outside of it is cut off.
+        bounds: Rect,
+    },
+    /// An area filled with content supplied by the caller.
+    External {
+        /// The raw id of the widget owning the layer, used to find its content.
+        id: u64,
+        /// The area to fill, in layer-local coordinates.
+        bounds: Rect,
+        /// Transform from layer-local coordinates into window coordinates.
+        transform: Affine,
+    },
+}
+
+/// A layered Masonry frame ready to be composited into a render target.
+#[derive(Clone, Copy, Debug)]
+pub struct CompositeFrame<'a> {
+    /// Frame width in physical pixels.
+    pub width: u32,
+    /// Frame height in physical pixels.
+    pub height: u32,
+    /// Window scale factor.
+    pub scale_factor: f64,
+    /// Background color to paint below all layers.
+    pub background_color: Color,
+    /// Layers in painter order.
+    pub layers: &'a [CompositeLayer<'a>],
+}
+
+/// Counts of the isolated layers handled while compositing a frame.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompositeStats {
+    /// Isolated layers which were rendered because they changed or weren't cached.
+    pub rendered_layers: usize,
+    /// Isolated layers whose cached rendering was reused.
+    pub reused_layers: usize,
+}
+
+impl<'a> CompositeFrame<'a> {
+    /// Create a layered Masonry frame.
+    pub fn new(
+        width: u32,
+        height: u32,
+        scale_factor: f64,
+        background_color: Color,
+        layers: &'a [CompositeLayer<'a>],
+    ) -> Self {
+        Self {
+            width,
+            height,
+            scale_factor,
+            background_color,
+            layers,
+        }
+    }
+
+    /// Splits the layers into the units which are rendered separately.
+    pub(crate) fn segments(&self) -> Vec<Segment<'a>> {
+        let mut segments = Vec::new();
+        for layer in self.layers {
+            match *layer {
+                CompositeLayer::Scene(layer) => {
+                    if let Some(Segment::Scenes(layers)) = segments.last_mut() {
+                        layers.push(layer);
+                    } else {
+                        segments.push(Segment::Scenes(vec![layer]));
+                    }
+                }
+                CompositeLayer::Isolated {
+                    key,
+                    generation,
+                    layer,
+                    bounds,
+                } => {
+                    // Antialiasing can bleed slightly outside of the painted shapes.
+                    let rect = (Affine::scale(self.scale_factor) * layer.transform)
+                        .transform_rect_bbox(bounds)
+                        .inflate(1., 1.)
+                        .expand();
+                    if let Some(rect) = PixelRect::clamped(rect, self.width, self.height) {
+                        segments.push(Segment::Isolated {
+                            key,
+                            generation,
+                            layer,
+                            rect,
+                        });
+                    }
+                }
+                CompositeLayer::External {
+                    id,
+                    bounds,
+                    transform,
+                } => {
+                    if let Some(rect) = self.pixel_rect(transform, bounds) {
+                        segments.push(Segment::External(id, rect));
+                    }
+                }
+            }
+        }
+        segments
+    }
+
+    /// Returns a frame which only renders the given scene layers.
+    pub(crate) fn prepared<'b>(
+        &self,
+        empty: &'b Scene,
+        layers: &'b [Layer<'b>],
+        background_color: Color,
+    ) -> PreparedFrame<'b> {
+        PreparedFrame::new(
+            self.width,
+            self.height,
+            self.scale_factor,
+            background_color,
+            empty,
+            layers,
+        )
+    }
+
+    /// Returns a transparent frame of the size of `rect` which renders the given scene layers.
+    ///
+    /// The layers should already be [cropped](PixelRect::crop) to `rect`.
+    pub(crate) fn cropped<'b>(
+        &self,
+        empty: &'b Scene,
+        layers: &'b [Layer<'b>],
+        rect: PixelRect,
+    ) -> PreparedFrame<'b> {
+        PreparedFrame::new(
+            rect.width,
+            rect.height,
+            self.scale_factor,
+            Color::TRANSPARENT,
+            empty,
+            layers,
+        )
+    }
+
+    /// Returns the area covered by `bounds` in physical pixels, clamped to the frame.
+    fn pixel_rect(&self, transform: Affine, bounds: Rect) -> Option<PixelRect> {
+        let rect = (Affine::scale(self.scale_factor) * transform)
+            .transform_rect_bbox(bounds)
+            .round();
+        PixelRect::clamped(rect, self.width, self.height)
+    }
+}
+
+/// A part of a [`CompositeFrame`] which is rendered separately.
+pub(crate) enum Segment<'a> {
+    Scenes(Vec<Layer<'a>>),
+    Isolated {
+        key: LayerKey,
+        generation: u64,
+        layer: Layer<'a>,
+        /// The area of the frame covered by the layer.
+        rect: PixelRect,
+    },
+    External(u64, PixelRect),
+}
+
+/// An area of a frame, in physical pixels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct PixelRect {
+    pub(crate) x: u32,
+    pub(crate) y: u32,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+}
+
+impl PixelRect {
+    /// Returns the part of `rect` inside a frame of the given size, or `None` if it's empty.
+    ///
+    /// `rect` should already be rounded to whole pixels.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "values are clamped to the frame size first"
+    )]
+    pub(crate) fn clamped(rect: Rect, width: u32, height: u32) -> Option<Self> {
+        let clamp = |value: f64, max: u32| value.clamp(0., f64::from(max)) as u32;
+        let x0 = clamp(rect.x0, width);
+        let y0 = clamp(rect.y0, height);
+        let x1 = clamp(rect.x1, width);
+        let y1 = clamp(rect.y1, height);
+        (x1 > x0 && y1 > y0).then_some(Self {
+            x: x0,
+            y: y0,
+            width: x1 - x0,
+            height: y1 - y0,
+        })
+    }
+
+    /// Returns `layer` moved so that this area starts at the origin.
+    pub(crate) fn crop<'a>(self, layer: Layer<'a>, scale_factor: f64) -> Layer<'a> {
+        let offset = Affine::translate((
+            -f64::from(self.x) / scale_factor,
+            -f64::from(self.y) / scale_factor,
+        ));
+        Layer {
+            scene: layer.scene,
+            transform: offset * layer.transform,
+        }
+    }
+}
+
+// --- MARK: CACHE
+
+/// Cached renderings of isolated layers.
+#[derive(Debug)]
+pub(crate) struct LayerCache<T> {
+    entries: HashMap<LayerKey, CachedLayer<T>>,
+}
+
+#[derive(Debug)]
+struct CachedLayer<T> {
+    generation: u64,
+    transform: Affine,
+    rect: PixelRect,
+    scale_factor: f64,
+    content: T,
+}
+
+impl<T> LayerCache<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Returns the cached rendering of an isolated layer covering `rect`,
+    /// calling `render` first if it's outdated.
+    pub(crate) fn get_or_render<E>(
+        &mut self,
+        key: LayerKey,
+        generation: u64,
+        layer: Layer<'_>,
+        rect: PixelRect,
+        frame: &CompositeFrame<'_>,
+        stats: &mut CompositeStats,
+        render: impl FnOnce() -> Result<T, E>,
+    ) -> Result<&T, E> {
+        let is_valid = self.entries.get(&key).is_some_and(|cached| {
+            cached.generation == generation
+                && cached.transform == layer.transform
+                && cached.rect == rect
+                && cached.scale_factor == frame.scale_factor
+        });
+        if is_valid {
+            stats.reused_layers += 1;
+        } else {
+            stats.rendered_layers += 1;
+            let content = render()?;
+            self.entries.insert(
+                key,
+                CachedLayer {
+                    generation,
+                    transform: layer.transform,
+                    rect,
+                    scale_factor: frame.scale_factor,
+                    content,
+                },
+            );
+        }
+        Ok(&self.entries[&key].content)
+    }
+
+    /// Drops the cached layers which aren't part of `frame`.
+    pub(crate) fn retain_frame_layers(&mut self, frame: &CompositeFrame<'_>) {
+        self.entries.retain(|key, _| {
+            frame
+                .layers
+                .iter()
+                .any(|layer| matches!(layer, CompositeLayer::Isolated { key: k, .. } if k == key))
+        });
+    }
+}
+
+// --- MARK: IMAGE COMPOSITOR
+
+/// Composites [`CompositeFrame`]s into RGBA images on the CPU.
+///
+/// Isolated layers are cached as images between calls to [`render`](Self::render).
+#[derive(Debug)]
+pub struct ImageCompositor {
+    layers: LayerCache<RgbaImage>,
+    external_images: HashMap<u64, RgbaImage>,
+}
+
+impl ImageCompositor {
+    /// Create a compositor with an empty cache.
+    pub fn new() -> Self {
+        Self {
+            layers: LayerCache::new(),
+            external_images: HashMap::new(),
+        }
+    }
+
+    /// Sets the content of the external layers owned by the widget with the given raw id.
+    ///
+    /// The image is stretched to cover the layer's bounds.
+    pub fn set_external_image(&mut self, id: u64, image: RgbaImage) {
+        self.external_images.insert(id, image);
+    }
+
+    /// Removes the content of the external layers owned by the widget with the given raw id.
+    ///
+    /// External layers without content are left empty.
+    pub fn remove_external_image(&mut self, id: u64) -> Option<RgbaImage> {
+        self.external_images.remove(&id)
+    }
+
+    /// Composites `frame` into a new image using `renderer` to render scene content.
+    pub fn render(
+        &mut self,
+        renderer: &mut (impl ImageRenderer + ?Sized),
+        frame: CompositeFrame<'_>,
+    ) -> Result<(RgbaImage, CompositeStats), ImageRendererError> {
+        let empty = Scene::new();
+        let mut stats = CompositeStats::default();
+        let mut segments = frame.segments().into_iter().peekable();
+
+        // The first scene layers are rendered directly above the background.
+        let base_layers = match segments.peek() {
+            Some(Segment::Scenes(_)) => {
+                let Some(Segment::Scenes(layers)) = segments.next() else {
+                    unreachable!()
+                };
+                layers
+            }
+            _ => Vec::new(),
+        };
+        let mut base = frame.prepared(&empty, &base_layers, frame.background_color);
+        let mut image = renderer.render_source(&mut base, frame.width, frame.height)?;
+
+        for segment in segments {
+            match segment {
+                Segment::Scenes(layers) => {
+                    let mut source = frame.prepared(&empty, &layers, Color::TRANSPARENT);
+                    let layer_image =
+                        renderer.render_source(&mut source, frame.width, frame.height)?;
+                    blend_image(&mut image, &layer_image);
+                }
+                Segment::Isolated {
+                    key,
+                    generation,
+                    layer,
+                    rect,
+                } => {
+                    let layer_image = self.layers.get_or_render(
+                        key,
+                        generation,
+                        layer,
+                        rect,
+                        &frame,
+                        &mut stats,
+                        || {
+                            let layers = [rect.crop(layer, frame.scale_factor)];
+                            let mut source = frame.cropped(&empty, &layers, rect);
+                            renderer.render_source(&mut source, rect.width, rect.height)
+                        },
+                    )?;
+                    blend_image_into_rect(&mut image, layer_image, rect);
+                }
+                Segment::External(id, rect) => {
+                    if let Some(external) = self.external_images.get(&id) {
+                        blend_image_into_rect(&mut image, external, rect);
+                    }
+                }
+            }
+        }
+        self.layers.retain_frame_layers(&frame);
+
+        Ok((image, stats))
+    }
+}
+
+impl Default for ImageCompositor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Blends `src` over `dst`. Both images must have the same size.
+fn blend_image(dst: &mut RgbaImage, src: &RgbaImage) {
+    debug_assert_eq!(
+        (dst.width, dst.height),
+        (src.width, src.height),
+        "blended images must have the same size"
+    );
+    for (dst, src) in dst.data.chunks_exact_mut(4).zip(src.data.chunks_exact(4)) {
+        blend_pixel(dst, src);
+    }
+}
+
+/// Blends `src` over the `rect` area of `dst`, stretching it with nearest-neighbor sampling.
+///
+/// If `src` has the size of `rect`, it is copied pixel for pixel.
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "source coordinates are smaller than the source size"
+)]
+fn blend_image_into_rect(dst: &mut RgbaImage, src: &RgbaImage, rect: PixelRect) {
+    if src.width == 0 || src.height == 0 {
+        return;
+    }
+    let pixel_index = |x: u32, y: u32, width: u32| (y as usize * width as usize + x as usize) * 4;
+    for y in 0..rect.height {
+        let src_y = (u64::from(y) * u64::from(src.height) / u64::from(rect.height)) as u32;
+        for x in 0..rect.width {
+            let src_x = (u64::from(x) * u64::from(src.width) / u64::from(rect.width)) as u32;
+            let src_idx = pixel_index(src_x, src_y, src.width);
+            let dst_idx = pixel_index(rect.x + x, rect.y + y, dst.width);
+            blend_pixel(
+                &mut dst.data[dst_idx..dst_idx + 4],
+                &src.data[src_idx..src_idx + 4],
+            );
+        }
+    }
+}
+
+/// Blends an unpremultiplied `src` pixel over an unpremultiplied `dst` pixel.
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "blended values stay within 0..=255"
+)]
+fn blend_pixel(dst: &mut [u8], src: &[u8]) {
+    match src[3] {
+        0 => {}
+        255 => dst.copy_from_slice(src),
+        _ => {
+            let src_alpha = f32::from(src[3]) / 255.;
+            let dst_alpha = f32::from(dst[3]) / 255. * (1. - src_alpha);
+            let alpha = src_alpha + dst_alpha;
+            for channel in 0..3 {
+                let value = (f32::from(src[channel]) * src_alpha
+                    + f32::from(dst[channel]) * dst_alpha)
+                    / alpha;
+                dst[channel] = value.round() as u8;
+            }
+            dst[3] = (alpha * 255.).round() as u8;
+        }
+    }
+}
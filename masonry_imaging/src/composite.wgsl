@@ -0,0 +1,30 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+// Blends a texture over the viewport of the render target.
+// The viewport is set to the area the texture should cover.
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) tex_coords: vec2<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vi: u32) -> VertexOutput {
+    var out: VertexOutput;
+    // A single triangle covering the whole viewport.
+    out.tex_coords = vec2<f32>(f32((vi << 1u) & 2u), f32(vi & 2u));
+    out.position = vec4<f32>(out.tex_coords * 2.0 - 1.0, 0.0, 1.0);
+    out.tex_coords.y = 1.0 - out.tex_coords.y;
+    return out;
+}
+
+@group(0) @binding(0)
+var layer: texture_2d<f32>;
+@group(0) @binding(1)
+var layer_sampler: sampler;
+
+@fragment
+fn fs_main(vs: VertexOutput) -> @location(0) vec4<f32> {
+    return textureSample(layer, layer_sampler, vs.tex_coords);
+}
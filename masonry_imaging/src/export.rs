@@ -0,0 +1,1127 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Vector export of retained scenes to SVG and PDF.
+//!
+//! Scenes are exported with one scene unit per CSS pixel, i.e. 1/96 inch.
+//! Text is exported as glyph outlines, so the output doesn't depend on installed fonts.
+//!
+//! Not everything a scene can hold has a vector equivalent. The export is approximate where:
+//!
+//! - sweep gradients are drawn with the color of their first stop,
+//! - gradients are interpolated in sRGB, whatever their interpolation color space,
+//! - images are clipped to their bounds, rather than extended with their extend mode,
+//! - blurred rounded rects (i.e. shadows), masks and filters are skipped,
+//! - the opacity of a group is applied to each draw in the group individually,
+//! - blend modes are ignored.
+//!
+//! PDF export has further limitations:
+//!
+//! - gradients are padded, whatever their extend mode,
+//! - gradients are drawn with the opacity of their first stop, rather than per-stop opacities.
+
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+
+use base64::Engine as _;
+use imaging::record::{Glyph, Scene};
+use imaging::{
+    BlurredRoundedRect, ClipRef, FillRef, GeometryRef, GlyphRunRef, GroupRef, PaintSink, StrokeRef,
+};
+use kurbo::{Affine, BezPath, Cap, Join, PathEl, Shape, Size, Stroke, StrokeOpts};
+use peniko::color::Srgb;
+use peniko::{
+    BrushRef, Color, Extend, Fill, Gradient, GradientKind, ImageAlphaType, ImageData, ImageFormat,
+    Style,
+};
+use skrifa::instance::{LocationRef, Size as FontSize};
+use skrifa::outline::{DrawSettings, OutlinePen};
+use skrifa::raw::types::F2Dot14;
+use skrifa::{FontRef, GlyphId, MetadataProvider};
+
+/// The tolerance used to flatten shapes into paths, in scene units.
+const TOLERANCE: f64 = 0.1;
+
+/// The number of PDF points per scene unit.
+const POINTS_PER_UNIT: f64 = 72. / 96.;
+
+/// Exports `scene` as an SVG document of the given size.
+///
+/// Images are embedded as PNG data URLs.
+pub fn scene_to_svg(scene: &Scene, size: Size) -> String {
+    let commands = VectorSink::record(scene);
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+        w = size.width,
+        h = size.height,
+    );
+    let mut clip_count = 0;
+    let mut gradient_count = 0;
+    for command in &commands {
+        match command {
+            Command::PushClip { path, fill_rule } => {
+                write_svg_clip(&mut svg, clip_count, path, *fill_rule);
+                let _ = writeln!(svg, r#"<g clip-path="url(#clip{clip_count})">"#);
+                clip_count += 1;
+            }
+            Command::PopClip => svg.push_str("</g>\n"),
+            Command::Draw {
+                transform,
+                path,
+                paint,
+                brush:
+                    ExportBrush::Image {
+                        image,
+                        transform: image_transform,
+                    },
+            } => {
+                // Images are drawn clipped to the painted area.
+                let (outline, fill_rule) = paint_outline(path, paint);
+                write_svg_clip(&mut svg, clip_count, &(*transform * outline), fill_rule);
+                let _ = writeln!(
+                    svg,
+                    r#"<image clip-path="url(#clip{clip_count})" transform="matrix({})" width="{}" height="{}" href="data:image/png;base64,{}"/>"#,
+                    svg_matrix(*transform * *image_transform),
+                    image.width,
+                    image.height,
+                    base64::engine::general_purpose::STANDARD.encode(image.to_png()),
+                );
+                clip_count += 1;
+            }
+            Command::Draw {
+                transform,
+                path,
+                paint,
+                brush,
+            } => {
+                let (server, opacity) = match brush {
+                    ExportBrush::Solid(color) => {
+                        let [r, g, b, a] = color.to_rgba8().to_u8_array();
+                        (format!("#{r:02x}{g:02x}{b:02x}"), f32::from(a) / 255.)
+                    }
+                    ExportBrush::Gradient {
+                        gradient,
+                        transform,
+                    } => {
+                        write_svg_gradient(&mut svg, gradient_count, gradient, *transform);
+                        let server = format!("url(#gradient{gradient_count})");
+                        gradient_count += 1;
+                        // The opacity is part of the gradient stops.
+                        (server, 1.)
+                    }
+                    ExportBrush::Image { .. } => unreachable!("images are drawn above"),
+                };
+                let _ = write!(
+                    svg,
+                    r#"<path d="{}" transform="matrix({})" "#,
+                    path.to_svg(),
+                    svg_matrix(*transform),
+                );
+                match paint {
+                    Paint::Fill(fill_rule) => {
+                        let _ = write!(
+                            svg,
+                            r#"fill="{server}" fill-opacity="{opacity}" fill-rule="{}""#,
+                            svg_fill_rule(*fill_rule),
+                        );
+                    }
+                    Paint::Stroke(stroke) => {
+                        let _ = write!(
+                            svg,
+                            r#"fill="none" stroke="{server}" stroke-opacity="{opacity}" stroke-width="{}" stroke-linecap="{}" stroke-linejoin="{}" stroke-miterlimit="{}""#,
+                            stroke.width,
+                            match stroke.start_cap {
+                                Cap::Butt => "butt",
+                                Cap::Square => "square",
+                                Cap::Round => "round",
+                            },
+                            match stroke.join {
+                                Join::Bevel => "bevel",
+                                Join::Miter => "miter",
+                                Join::Round => "round",
+                            },
+                            stroke.miter_limit,
+                        );
+                        if !stroke.dash_pattern.is_empty() {
+                            let dashes: Vec<_> =
+                                stroke.dash_pattern.iter().map(f64::to_string).collect();
+                            let _ = write!(
+                                svg,
+                                r#" stroke-dasharray="{}" stroke-dashoffset="{}""#,
+                                dashes.join(" "),
+                                stroke.dash_offset,
+                            );
+                        }
+                    }
+                }
+                svg.push_str("/>\n");
+            }
+        }
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Exports `scene` as a single-page PDF document of the given size.
+pub fn scene_to_pdf(scene: &Scene, size: Size) -> Vec<u8> {
+    use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref};
+
+    let commands = VectorSink::record(scene);
+
+    let catalog_id = Ref::new(1);
+    let page_tree_id = Ref::new(2);
+    let page_id = Ref::new(3);
+    let content_id = Ref::new(4);
+    let mut next_id = Ref::new(5);
+
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "PDF coordinates are single precision"
+    )]
+    let (width, height) = (
+        (size.width * POINTS_PER_UNIT) as f32,
+        (size.height * POINTS_PER_UNIT) as f32,
+    );
+
+    // Each distinct opacity needs its own graphics state,
+    // and each gradient and image its own resource.
+    let mut alphas = BTreeSet::new();
+    let mut gradients = Vec::new();
+    let mut images = Vec::new();
+    let alpha_name = |alpha: u8| format!("A{alpha}");
+    let mut set_alpha = |content: &mut Content, alpha: u8| {
+        if alpha != u8::MAX {
+            alphas.insert(alpha);
+            content.set_parameters(Name(alpha_name(alpha).as_bytes()));
+        }
+    };
+
+    let mut content = Content::new();
+    // PDF's y axis points up, and its unit is the point.
+    content.transform(pdf_matrix(
+        Affine::new([1., 0., 0., -1., 0., size.height]).then_scale(POINTS_PER_UNIT),
+    ));
+    for command in &commands {
+        match command {
+            Command::PushClip { path, fill_rule } => {
+                content.save_state();
+                pdf_clip(&mut content, path, *fill_rule);
+            }
+            Command::PopClip => {
+                content.restore_state();
+            }
+            Command::Draw {
+                transform,
+                path,
+                paint,
+                brush: ExportBrush::Solid(color),
+            } => {
+                let [r, g, b, a] = color.to_rgba8().to_u8_array();
+                let [r, g, b] = [r, g, b].map(|c| f32::from(c) / 255.);
+                content.save_state();
+                content.transform(pdf_matrix(*transform));
+                set_alpha(&mut content, a);
+                pdf_path(&mut content, path);
+                match paint {
+                    Paint::Fill(fill_rule) => {
+                        content.set_fill_rgb(r, g, b);
+                        match fill_rule {
+                            Fill::NonZero => content.fill_nonzero(),
+                            Fill::EvenOdd => content.fill_even_odd(),
+                        };
+                    }
+                    Paint::Stroke(stroke) => {
+                        content.set_stroke_rgb(r, g, b);
+                        set_pdf_stroke(&mut content, stroke);
+                        content.stroke();
+                    }
+                }
+                content.restore_state();
+            }
+            Command::Draw {
+                transform,
+                path,
+                paint,
+                brush,
+            } => {
+                // Gradients and images fill the painted area, which is clipped first.
+                let (outline, fill_rule) = paint_outline(path, paint);
+                content.save_state();
+                content.transform(pdf_matrix(*transform));
+                pdf_clip(&mut content, &outline, fill_rule);
+                match brush {
+                    ExportBrush::Gradient {
+                        gradient,
+                        transform,
+                    } => {
+                        let alpha = gradient.stops.first().map_or(u8::MAX, |stop| {
+                            stop.color.to_alpha_color::<Srgb>().to_rgba8().a
+                        });
+                        set_alpha(&mut content, alpha);
+                        content.transform(pdf_matrix(*transform));
+                        content.shading(Name(format!("Sh{}", gradients.len()).as_bytes()));
+                        gradients.push(gradient);
+                    }
+                    ExportBrush::Image { image, transform } => {
+                        // Image space is the unit square, with its first row at the top.
+                        let (width, height) = (f64::from(image.width), f64::from(image.height));
+                        content.transform(pdf_matrix(
+                            *transform * Affine::new([width, 0., 0., -height, 0., height]),
+                        ));
+                        content.x_object(Name(format!("Im{}", images.len()).as_bytes()));
+                        images.push(image);
+                    }
+                    ExportBrush::Solid(_) => unreachable!("solid colors are drawn above"),
+                }
+                content.restore_state();
+            }
+        }
+    }
+
+    let alpha_ids: Vec<_> = alphas
+        .iter()
+        .map(|&alpha| (alpha, next_id.bump()))
+        .collect();
+    let gradient_ids: Vec<_> = gradients.iter().map(|_| next_id.bump()).collect();
+    let image_ids: Vec<_> = images.iter().map(|_| next_id.bump()).collect();
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id).kids([page_id]).count(1);
+    let mut page = pdf.page(page_id);
+    page.media_box(Rect::new(0., 0., width, height));
+    page.parent(page_tree_id);
+    page.contents(content_id);
+    {
+        let mut resources = page.resources();
+        let mut states = resources.ext_g_states();
+        for &(alpha, id) in &alpha_ids {
+            states.pair(Name(alpha_name(alpha).as_bytes()), id);
+        }
+        states.finish();
+        let mut shadings = resources.shadings();
+        for (index, &id) in gradient_ids.iter().enumerate() {
+            shadings.pair(Name(format!("Sh{index}").as_bytes()), id);
+        }
+        shadings.finish();
+        let mut x_objects = resources.x_objects();
+        for (index, &id) in image_ids.iter().enumerate() {
+            x_objects.pair(Name(format!("Im{index}").as_bytes()), id);
+        }
+    }
+    page.finish();
+    pdf.stream(content_id, &content.finish());
+
+    for (alpha, id) in alpha_ids {
+        let alpha = f32::from(alpha) / 255.;
+        pdf.ext_graphics(id)
+            .stroking_alpha(alpha)
+            .non_stroking_alpha(alpha);
+    }
+    for (gradient, id) in gradients.into_iter().zip(gradient_ids) {
+        write_pdf_gradient(&mut pdf, id, &mut next_id, gradient);
+    }
+    for (image, id) in images.into_iter().zip(image_ids) {
+        write_pdf_image(&mut pdf, id, &mut next_id, image);
+    }
+    pdf.finish()
+}
+
+// --- MARK: RECORDING
+
+/// A simplified drawing command, which maps directly to SVG and PDF.
+#[derive(Debug)]
+enum Command {
+    /// Clips the following commands. The path is in scene coordinates.
+    PushClip {
+        path: BezPath,
+        fill_rule: Fill,
+    },
+    PopClip,
+    /// Draws a path, which is transformed by `transform` along with the stroke and the brush.
+    Draw {
+        transform: Affine,
+        path: BezPath,
+        paint: Paint,
+        brush: ExportBrush,
+    },
+}
+
+/// A brush with the opacity of its draw applied.
+#[derive(Debug)]
+enum ExportBrush {
+    Solid(Color),
+    /// A linear or radial gradient, placed in path coordinates by `transform`.
+    Gradient {
+        gradient: Box<Gradient>,
+        transform: Affine,
+    },
+    /// An image, placed in path coordinates by `transform`.
+    Image {
+        image: ExportImage,
+        transform: Affine,
+    },
+}
+
+/// An image with unpremultiplied RGBA pixels.
+struct ExportImage {
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+impl ExportImage {
+    /// Converts `image`, multiplying its opacity by `alpha`.
+    ///
+    /// Returns `None` if the image is empty or its data doesn't match its size.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "channel values stay within 0..=255"
+    )]
+    fn new(image: &ImageData, alpha: f32) -> Option<Self> {
+        let len = image.format.size_in_bytes(image.width, image.height)?;
+        let bytes = image.data.data();
+        if len == 0 || bytes.len() < len {
+            return None;
+        }
+        let data = bytes[..len]
+            .chunks_exact(4)
+            .flat_map(|pixel| {
+                let [mut r, g, mut b, a] = [pixel[0], pixel[1], pixel[2], pixel[3]];
+                if image.format == ImageFormat::Bgra8 {
+                    std::mem::swap(&mut r, &mut b);
+                }
+                let mut rgb = [r, g, b];
+                if image.alpha_type == ImageAlphaType::AlphaPremultiplied && a != 0 {
+                    rgb = rgb.map(|c| (u16::from(c) * 255 / u16::from(a)).min(255) as u8);
+                }
+                let a = (f32::from(a) * alpha).round().clamp(0., 255.) as u8;
+                [rgb[0], rgb[1], rgb[2], a]
+            })
+            .collect();
+        Some(Self {
+            width: image.width,
+            height: image.height,
+            data,
+        })
+    }
+
+    fn to_png(&self) -> Vec<u8> {
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder
+            .write_header()
+            .expect("writing a PNG header into memory can't fail");
+        writer
+            .write_image_data(&self.data)
+            .expect("image data should match the image size");
+        writer
+            .finish()
+            .expect("writing a PNG into memory can't fail");
+        png
+    }
+}
+
+impl std::fmt::Debug for ExportImage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExportImage")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug)]
+enum Paint {
+    Fill(Fill),
+    Stroke(Stroke),
+}
+
+/// What a group pushed onto the sink changed, to be undone when it's popped.
+#[derive(Clone, Copy, Debug)]
+struct GroupState {
+    has_clip: bool,
+    previous_alpha: f32,
+}
+
+/// A paint sink which records a scene as [`Command`]s.
+#[derive(Debug, Default)]
+struct VectorSink {
+    commands: Vec<Command>,
+    groups: Vec<GroupState>,
+    /// The product of the opacities of the groups being recorded.
+    alpha: f32,
+}
+
+impl VectorSink {
+    fn record(scene: &Scene) -> Vec<Command> {
+        let mut sink = Self {
+            alpha: 1.,
+            ..Self::default()
+        };
+        imaging::record::replay(scene, &mut sink);
+        sink.commands
+    }
+
+    fn push_draw(
+        &mut self,
+        transform: Affine,
+        path: BezPath,
+        paint: Paint,
+        brush: BrushRef<'_>,
+        brush_transform: Option<Affine>,
+        alpha: f32,
+    ) {
+        let alpha = alpha * self.alpha;
+        let brush_transform = brush_transform.unwrap_or(Affine::IDENTITY);
+        let brush = match brush {
+            BrushRef::Solid(color) => ExportBrush::Solid(color.multiply_alpha(alpha)),
+            BrushRef::Gradient(gradient) => {
+                let Some(first) = gradient.stops.first() else {
+                    return;
+                };
+                if let GradientKind::Sweep(_) = gradient.kind {
+                    ExportBrush::Solid(first.color.to_alpha_color().multiply_alpha(alpha))
+                } else {
+                    let mut gradient = Box::new(gradient.clone());
+                    for stop in gradient.stops.iter_mut() {
+                        stop.color = stop.color.multiply_alpha(alpha);
+                    }
+                    ExportBrush::Gradient {
+                        gradient,
+                        transform: brush_transform,
+                    }
+                }
+            }
+            BrushRef::Image(brush) => {
+                let Some(image) = ExportImage::new(brush.image, brush.sampler.alpha * alpha) else {
+                    return;
+                };
+                ExportBrush::Image {
+                    image,
+                    transform: brush_transform,
+                }
+            }
+        };
+        self.commands.push(Command::Draw {
+            transform,
+            path,
+            paint,
+            brush,
+        });
+    }
+}
+
+impl PaintSink for VectorSink {
+    fn push_clip(&mut self, clip: ClipRef<'_>) {
+        let (path, fill_rule) = clip_path(clip);
+        self.commands.push(Command::PushClip { path, fill_rule });
+    }
+
+    fn pop_clip(&mut self) {
+        self.commands.push(Command::PopClip);
+    }
+
+    fn push_group(&mut self, group: GroupRef<'_>) {
+        let has_clip = if let Some(clip) = group.clip {
+            self.push_clip(clip);
+            true
+        } else {
+            false
+        };
+        self.groups.push(GroupState {
+            has_clip,
+            previous_alpha: self.alpha,
+        });
+        self.alpha *= group.composite.alpha;
+    }
+
+    fn pop_group(&mut self) {
+        let Some(group) = self.groups.pop() else {
+            return;
+        };
+        self.alpha = group.previous_alpha;
+        if group.has_clip {
+            self.pop_clip();
+        }
+    }
+
+    fn fill(&mut self, draw: FillRef<'_>) {
+        let path = geometry_path(draw.shape);
+        self.push_draw(
+            draw.transform,
+            path,
+            Paint::Fill(draw.fill_rule),
+            draw.brush,
+            draw.brush_transform,
+            draw.composite.alpha,
+        );
+    }
+
+    fn stroke(&mut self, draw: StrokeRef<'_>) {
+        let path = geometry_path(draw.shape);
+        self.push_draw(
+            draw.transform,
+            path,
+            Paint::Stroke(draw.stroke.clone()),
+            draw.brush,
+            draw.brush_transform,
+            draw.composite.alpha,
+        );
+    }
+
+    fn glyph_run(&mut self, draw: GlyphRunRef<'_>, glyphs: &mut dyn Iterator<Item = Glyph>) {
+        let Ok(font) = FontRef::from_index(draw.font.data.data(), draw.font.index) else {
+            return;
+        };
+        let outlines = font.outline_glyphs();
+        let coords: Vec<_> = draw
+            .normalized_coords
+            .iter()
+            .map(|&coord| F2Dot14::from_bits(coord))
+            .collect();
+        let location = LocationRef::new(&coords);
+        let glyph_transform = draw.glyph_transform.unwrap_or(Affine::IDENTITY);
+
+        for glyph in glyphs {
+            let Some(outline) = outlines.get(GlyphId::new(glyph.id)) else {
+                continue;
+            };
+            let mut pen = PathPen(BezPath::new());
+            let settings = DrawSettings::unhinted(FontSize::new(draw.font_size), location);
+            if outline.draw(settings, &mut pen).is_err() {
+                continue;
+            }
+            // Font outlines have their y axis pointing up.
+            let glyph_to_run = Affine::translate((f64::from(glyph.x), f64::from(glyph.y)))
+                * glyph_transform
+                * Affine::FLIP_Y;
+            // The brush is placed in run coordinates.
+            let brush_transform = glyph_to_run.inverse() * draw.brush_transform.unwrap_or_default();
+            let paint = match draw.style {
+                Style::Fill(fill_rule) => Paint::Fill(*fill_rule),
+                Style::Stroke(stroke) => Paint::Stroke(stroke.clone()),
+            };
+            self.push_draw(
+                draw.transform * glyph_to_run,
+                pen.0,
+                paint,
+                draw.brush,
+                Some(brush_transform),
+                draw.composite.alpha,
+            );
+        }
+    }
+
+    fn blurred_rounded_rect(&mut self, _draw: BlurredRoundedRect) {}
+}
+
+/// Builds a path from glyph outlines.
+struct PathPen(BezPath);
+
+impl OutlinePen for PathPen {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.0.move_to((x, y));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.0.line_to((x, y));
+    }
+
+    fn quad_to(&mut self, cx0: f32, cy0: f32, x: f32, y: f32) {
+        self.0.quad_to((cx0, cy0), (x, y));
+    }
+
+    fn curve_to(&mut self, cx0: f32, cy0: f32, cx1: f32, cy1: f32, x: f32, y: f32) {
+        self.0.curve_to((cx0, cy0), (cx1, cy1), (x, y));
+    }
+
+    fn close(&mut self) {
+        self.0.close_path();
+    }
+}
+
+fn geometry_path(shape: GeometryRef<'_>) -> BezPath {
+    match shape {
+        GeometryRef::Path(path) => path.clone(),
+        GeometryRef::OwnedPath(path) => path,
+        GeometryRef::Rect(rect) => rect.to_path(TOLERANCE),
+        GeometryRef::RoundedRect(rect) => rect.to_path(TOLERANCE),
+    }
+}
+
+/// Returns the area covered by `clip` in scene coordinates.
+fn clip_path(clip: ClipRef<'_>) -> (BezPath, Fill) {
+    match clip {
+        ClipRef::Fill {
+            transform,
+            shape,
+            fill_rule,
+        } => (transform * geometry_path(shape), fill_rule),
+        ClipRef::Stroke {
+            transform,
+            shape,
+            stroke,
+        } => {
+            let outline = kurbo::stroke(
+                geometry_path(shape),
+                stroke,
+                &StrokeOpts::default(),
+                TOLERANCE,
+            );
+            (transform * outline, Fill::NonZero)
+        }
+    }
+}
+
+/// Returns the area painted by drawing `path` with `paint`, in path coordinates.
+fn paint_outline(path: &BezPath, paint: &Paint) -> (BezPath, Fill) {
+    match paint {
+        Paint::Fill(fill_rule) => (path.clone(), *fill_rule),
+        Paint::Stroke(stroke) => (
+            kurbo::stroke(path.iter(), stroke, &StrokeOpts::default(), TOLERANCE),
+            Fill::NonZero,
+        ),
+    }
+}
+
+// --- MARK: SERIALIZATION
+
+fn svg_fill_rule(fill_rule: Fill) -> &'static str {
+    match fill_rule {
+        Fill::NonZero => "nonzero",
+        Fill::EvenOdd => "evenodd",
+    }
+}
+
+fn svg_spread_method(extend: Extend) -> &'static str {
+    match extend {
+        Extend::Pad => "pad",
+        Extend::Repeat => "repeat",
+        Extend::Reflect => "reflect",
+    }
+}
+
+fn write_svg_clip(svg: &mut String, id: usize, path: &BezPath, fill_rule: Fill) {
+    let _ = writeln!(
+        svg,
+        r#"<clipPath id="clip{id}"><path d="{}" clip-rule="{}"/></clipPath>"#,
+        path.to_svg(),
+        svg_fill_rule(fill_rule),
+    );
+}
+
+/// Writes `gradient` as a paint server, to be used by the following path.
+fn write_svg_gradient(svg: &mut String, id: usize, gradient: &Gradient, transform: Affine) {
+    let element = match gradient.kind {
+        GradientKind::Linear(line) => {
+            let _ = write!(
+                svg,
+                r#"<linearGradient id="gradient{id}" x1="{}" y1="{}" x2="{}" y2="{}" "#,
+                line.start.x, line.start.y, line.end.x, line.end.y,
+            );
+            "linearGradient"
+        }
+        GradientKind::Radial(circles) => {
+            let _ = write!(
+                svg,
+                r#"<radialGradient id="gradient{id}" cx="{}" cy="{}" r="{}" fx="{}" fy="{}" fr="{}" "#,
+                circles.end_center.x,
+                circles.end_center.y,
+                circles.end_radius,
+                circles.start_center.x,
+                circles.start_center.y,
+                circles.start_radius,
+            );
+            "radialGradient"
+        }
+        GradientKind::Sweep(_) => unreachable!("sweep gradients are exported as solid colors"),
+    };
+    let _ = writeln!(
+        svg,
+        r#"gradientUnits="userSpaceOnUse" gradientTransform="matrix({})" spreadMethod="{}">"#,
+        svg_matrix(transform),
+        svg_spread_method(gradient.extend),
+    );
+    for stop in gradient.stops.iter() {
+        let [r, g, b, a] = stop.color.to_alpha_color::<Srgb>().to_rgba8().to_u8_array();
+        let _ = writeln!(
+            svg,
+            r##"<stop offset="{}" stop-color="#{r:02x}{g:02x}{b:02x}" stop-opacity="{}"/>"##,
+            stop.offset,
+            f32::from(a) / 255.,
+        );
+    }
+    let _ = writeln!(svg, "</{element}>");
+}
+
+fn svg_matrix(transform: Affine) -> String {
+    let [a, b, c, d, e, f] = transform.as_coeffs();
+    format!("{a} {b} {c} {d} {e} {f}")
+}
+
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "PDF coordinates are single precision"
+)]
+fn pdf_matrix(transform: Affine) -> [f32; 6] {
+    transform.as_coeffs().map(|coeff| coeff as f32)
+}
+
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "PDF coordinates are single precision"
+)]
+fn pdf_path(content: &mut pdf_writer::Content, path: &BezPath) {
+    let mut last = kurbo::Point::ZERO;
+    for element in path.elements() {
+        match *element {
+            PathEl::MoveTo(p) => {
+                content.move_to(p.x as f32, p.y as f32);
+                last = p;
+            }
+            PathEl::LineTo(p) => {
+                content.line_to(p.x as f32, p.y as f32);
+                last = p;
+            }
+            PathEl::QuadTo(p1, p2) => {
+                // PDF has no quadratic curves, so they're raised to cubic ones.
+                let c1 = last + (p1 - last) * (2. / 3.);
+                let c2 = p2 + (p1 - p2) * (2. / 3.);
+                content.cubic_to(
+                    c1.x as f32,
+                    c1.y as f32,
+                    c2.x as f32,
+                    c2.y as f32,
+                    p2.x as f32,
+                    p2.y as f32,
+                );
+                last = p2;
+            }
+            PathEl::CurveTo(p1, p2, p3) => {
+                content.cubic_to(
+                    p1.x as f32,
+                    p1.y as f32,
+                    p2.x as f32,
+                    p2.y as f32,
+                    p3.x as f32,
+                    p3.y as f32,
+                );
+                last = p3;
+            }
+            PathEl::ClosePath => {
+                content.close_path();
+            }
+        }
+    }
+}
+
+fn pdf_clip(content: &mut pdf_writer::Content, path: &BezPath, fill_rule: Fill) {
+    pdf_path(content, path);
+    match fill_rule {
+        Fill::NonZero => content.clip_nonzero(),
+        Fill::EvenOdd => content.clip_even_odd(),
+    };
+    content.end_path();
+}
+
+/// Writes `gradient` as a shading, with a function for each pair of neighbouring stops.
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "PDF coordinates are single precision"
+)]
+fn write_pdf_gradient(
+    pdf: &mut pdf_writer::Pdf,
+    id: pdf_writer::Ref,
+    next_id: &mut pdf_writer::Ref,
+    gradient: &Gradient,
+) {
+    use pdf_writer::types::FunctionShadingType;
+
+    let mut stops: Vec<_> = gradient
+        .stops
+        .iter()
+        .map(|stop| {
+            let [r, g, b, _] = stop.color.to_alpha_color::<Srgb>().components;
+            (stop.offset.clamp(0., 1.), [r, g, b])
+        })
+        .collect();
+    // Colors are padded to the ends of the function's domain.
+    if let Some(&(offset, color)) = stops.first()
+        && offset > 0.
+    {
+        stops.insert(0, (0., color));
+    }
+    if let Some(&(offset, color)) = stops.last()
+        && offset < 1.
+    {
+        stops.push((1., color));
+    }
+    let function_ids: Vec<_> = stops.windows(2).map(|_| next_id.bump()).collect();
+    for (pair, &function_id) in stops.windows(2).zip(&function_ids) {
+        pdf.exponential_function(function_id)
+            .domain([0., 1.])
+            .c0(pair[0].1)
+            .c1(pair[1].1)
+            .n(1.);
+    }
+    let stitching_id = next_id.bump();
+    let inner_offsets = stops[1..stops.len() - 1].iter().map(|&(offset, _)| offset);
+    pdf.stitching_function(stitching_id)
+        .domain([0., 1.])
+        .functions(function_ids.iter().copied())
+        .bounds(inner_offsets)
+        .encode(function_ids.iter().flat_map(|_| [0., 1.]));
+
+    let mut shading = pdf.function_shading(id);
+    match gradient.kind {
+        GradientKind::Linear(line) => {
+            shading.shading_type(FunctionShadingType::Axial);
+            shading.coords([line.start.x, line.start.y, line.end.x, line.end.y].map(|c| c as f32));
+        }
+        GradientKind::Radial(circles) => {
+            shading.shading_type(FunctionShadingType::Radial);
+            shading.coords([
+                circles.start_center.x as f32,
+                circles.start_center.y as f32,
+                circles.start_radius,
+                circles.end_center.x as f32,
+                circles.end_center.y as f32,
+                circles.end_radius,
+            ]);
+        }
+        GradientKind::Sweep(_) => unreachable!("sweep gradients are exported as solid colors"),
+    }
+    shading.color_space().device_rgb();
+    shading.function(stitching_id);
+    shading.extend([true, true]);
+}
+
+/// Writes `image` as a compressed image, with a soft mask if it isn't opaque.
+fn write_pdf_image(
+    pdf: &mut pdf_writer::Pdf,
+    id: pdf_writer::Ref,
+    next_id: &mut pdf_writer::Ref,
+    image: &ExportImage,
+) {
+    use pdf_writer::{Filter, Finish};
+
+    let width = i32::try_from(image.width).unwrap_or(i32::MAX);
+    let height = i32::try_from(image.height).unwrap_or(i32::MAX);
+    let (rgb, alpha): (Vec<_>, Vec<_>) = image
+        .data
+        .chunks_exact(4)
+        .map(|pixel| ([pixel[0], pixel[1], pixel[2]], pixel[3]))
+        .unzip();
+    let mask_id = alpha
+        .iter()
+        .any(|&alpha| alpha != u8::MAX)
+        .then(|| next_id.bump());
+
+    let rgb = miniz_oxide::deflate::compress_to_vec_zlib(rgb.as_flattened(), 6);
+    let mut x_object = pdf.image_xobject(id, &rgb);
+    x_object.filter(Filter::FlateDecode);
+    x_object.width(width);
+    x_object.height(height);
+    x_object.color_space().device_rgb();
+    x_object.bits_per_component(8);
+    if let Some(mask_id) = mask_id {
+        x_object.s_mask(mask_id);
+    }
+    x_object.finish();
+
+    if let Some(mask_id) = mask_id {
+        let alpha = miniz_oxide::deflate::compress_to_vec_zlib(&alpha, 6);
+        let mut mask = pdf.image_xobject(mask_id, &alpha);
+        mask.filter(Filter::FlateDecode);
+        mask.width(width);
+        mask.height(height);
+        mask.color_space().device_gray();
+        mask.bits_per_component(8);
+    }
+}
+
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "PDF coordinates are single precision"
+)]
+fn set_pdf_stroke(content: &mut pdf_writer::Content, stroke: &Stroke) {
+    use pdf_writer::types::{LineCapStyle, LineJoinStyle};
+
+    content.set_line_width(stroke.width as f32);
+    content.set_line_cap(match stroke.start_cap {
+        Cap::Butt => LineCapStyle::ButtCap,
+        Cap::Square => LineCapStyle::ProjectingSquareCap,
+        Cap::Round => LineCapStyle::RoundCap,
+    });
+    content.set_line_join(match stroke.join {
+        Join::Bevel => LineJoinStyle::BevelJoin,
+        Join::Miter => LineJoinStyle::MiterJoin,
+        Join::Round => LineJoinStyle::RoundJoin,
+    });
+    content.set_miter_limit(stroke.miter_limit as f32);
+    if !stroke.dash_pattern.is_empty() {
+        content.set_dash_pattern(
+            stroke.dash_pattern.iter().map(|&dash| dash as f32),
+            stroke.dash_offset as f32,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use imaging::Painter;
+    use kurbo::{Circle, Rect};
+    use peniko::{Blob, ImageBrush};
+
+    use super::*;
+
+    fn test_scene() -> Scene {
+        let mut scene = Scene::new();
+        let mut painter = Painter::new(&mut scene);
+        painter
+            .fill(Rect::new(0., 0., 10., 20.), Color::from_rgb8(255, 0, 0))
+            .draw();
+        painter
+            .stroke(
+                Circle::new((5., 5.), 4.),
+                &Stroke::new(2.),
+                Color::from_rgba8(0, 0, 255, 128),
+            )
+            .transform(Affine::translate((10., 0.)))
+            .draw();
+        scene
+    }
+
+    #[test]
+    fn svg_export_contains_the_scene_draws() {
+        let svg = scene_to_svg(&test_scene(), Size::new(30., 20.));
+        assert!(svg.starts_with("<svg"), "not an SVG document: {svg}");
+        assert!(svg.contains(r#"viewBox="0 0 30 20""#), "{svg}");
+        assert!(
+            svg.contains(r##"fill="#ff0000" fill-opacity="1""##),
+            "{svg}"
+        );
+        assert!(svg.contains(r##"stroke="#0000ff""##), "{svg}");
+        assert!(svg.contains(r#"stroke-width="2""#), "{svg}");
+        assert!(svg.contains(r#"transform="matrix(1 0 0 1 10 0)""#), "{svg}");
+    }
+
+    #[test]
+    fn pdf_export_is_a_pdf_document() {
+        let pdf = scene_to_pdf(&test_scene(), Size::new(96., 48.));
+        assert!(pdf.starts_with(b"%PDF-"), "not a PDF document");
+        let text = String::from_utf8_lossy(&pdf);
+        // One inch wide and half an inch tall.
+        assert!(text.contains("/MediaBox [0 0 72 36]"), "{text}");
+        // The semi-transparent stroke has its own graphics state.
+        assert!(text.contains("/A128"), "{text}");
+    }
+
+    fn brush_scene() -> Scene {
+        let mut scene = Scene::new();
+        let mut painter = Painter::new(&mut scene);
+        let linear = Gradient::new_linear((0., 0.), (10., 0.))
+            .with_stops([Color::from_rgb8(255, 0, 0), Color::from_rgba8(0, 0, 255, 0)])
+            .with_extend(Extend::Reflect);
+        painter.fill(Rect::new(0., 0., 10., 10.), &linear).draw();
+        let radial = Gradient::new_radial((5., 5.), 5.)
+            .with_stops([Color::from_rgb8(0, 255, 0), Color::from_rgb8(0, 0, 0)]);
+        painter
+            .stroke(Circle::new((5., 5.), 4.), &Stroke::new(2.), &radial)
+            .draw();
+        // A 2x1 image with a transparent pixel.
+        let image = ImageBrush::new(ImageData {
+            data: Blob::from(vec![255, 0, 0, 255, 0, 0, 0, 0]),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 2,
+            height: 1,
+        });
+        painter
+            .fill(Rect::new(0., 0., 2., 1.), &image)
+            .brush_transform(Some(Affine::translate((10., 0.))))
+            .draw();
+        scene
+    }
+
+    #[test]
+    fn svg_export_contains_gradients_and_images() {
+        let svg = scene_to_svg(&brush_scene(), Size::new(30., 20.));
+        assert!(
+            svg.contains(r#"<linearGradient id="gradient0" x1="0" y1="0" x2="10" y2="0""#),
+            "{svg}"
+        );
+        assert!(svg.contains(r#"spreadMethod="reflect""#), "{svg}");
+        assert!(
+            svg.contains(r##"<stop offset="1" stop-color="#0000ff" stop-opacity="0"/>"##),
+            "{svg}"
+        );
+        assert!(svg.contains(r#"fill="url(#gradient0)""#), "{svg}");
+        assert!(
+            svg.contains(r#"<radialGradient id="gradient1" cx="5" cy="5" r="5""#),
+            "{svg}"
+        );
+        assert!(svg.contains(r#"stroke="url(#gradient1)""#), "{svg}");
+        assert!(
+            svg.contains(r#"transform="matrix(1 0 0 1 10 0)" width="2" height="1" href="data:image/png;base64,iVBORw0KGgo"#),
+            "{svg}"
+        );
+    }
+
+    #[test]
+    fn pdf_export_contains_gradients_and_images() {
+        let pdf = scene_to_pdf(&brush_scene(), Size::new(30., 20.));
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(text.contains("/ShadingType 2"), "{text}");
+        assert!(text.contains("/ShadingType 3"), "{text}");
+        assert!(text.contains("/Sh0 sh"), "{text}");
+        assert!(text.contains("/Subtype /Image"), "{text}");
+        // The transparent pixel needs a soft mask.
+        assert!(text.contains("/SMask"), "{text}");
+        assert!(text.contains("/Im0 Do"), "{text}");
+    }
+
+    #[test]
+    fn sweep_gradients_use_their_first_stop() {
+        let mut scene = Scene::new();
+        {
+            let mut painter = Painter::new(&mut scene);
+            let sweep = Gradient::new_sweep((5., 5.), 0., 1.)
+                .with_stops([Color::from_rgb8(0, 255, 0), Color::from_rgb8(0, 0, 0)]);
+            painter.fill(Rect::new(0., 0., 10., 10.), &sweep).draw();
+        }
+        let svg = scene_to_svg(&scene, Size::new(10., 10.));
+        assert!(svg.contains(r##"fill="#00ff00""##), "{svg}");
+    }
+
+    #[test]
+    fn clips_and_groups_are_balanced() {
+        let mut scene = Scene::new();
+        {
+            let mut painter = Painter::new(&mut scene);
+            painter.with_clip(ClipRef::fill(Rect::new(0., 0., 5., 5.)), |painter| {
+                painter
+                    .fill(Rect::new(0., 0., 10., 10.), Color::BLACK)
+                    .draw();
+            });
+        }
+        let commands = VectorSink::record(&scene);
+        assert!(
+            matches!(
+                commands.as_slice(),
+                [
+                    Command::PushClip { .. },
+                    Command::Draw { .. },
+                    Command::PopClip
+                ]
+            ),
+            "unexpected commands {commands:?}"
+        );
+    }
+}
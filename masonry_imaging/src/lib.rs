@@ -14,6 +14,8 @@
 //!   `imaging_vello_hybrid`, `imaging_vello_cpu`, and `imaging_skia`
 //! - exposing host-neutral texture rendering helpers for writing into caller-provided WGPU
 //!   targets
+//! - compositing layered frames, with cached isolated layers and caller-supplied external
+//!   content, see the [`composite`] module
 //!
 //! This crate does not own window integration, surfaces, or compositor policy.
 //!
@@ -22,8 +24,9 @@
 //! - `default`: Enables the `vello` module.
 //! - `imaging_vello`: Enables the `vello` module and texture rendering support.
 //! - `imaging_vello_hybrid`: Enables the `vello_hybrid` module and texture rendering support.
-//! - `imaging_vello_cpu`: Enables the `vello_cpu` module for headless image rendering only.
+//! - `imaging_vello_cpu`: Enables the `vello_cpu` module for image rendering on the CPU.
 //! - `imaging_skia`: Enables the `skia` module and texture rendering support on non-wasm targets.
+//! - `export`: Enables the `export` module, which exports scenes to SVG and PDF.
 
 // LINEBENDER LINT SET - lib.rs - v3
 // See https://linebender.org/wiki/canonical-lints/
@@ -34,17 +37,23 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 use imaging::record::{Scene, ValidateError, replay_transformed};
-use imaging::render::RenderSource;
+use imaging::render::{ImageRendererError, RenderSource};
 use imaging::{PaintSink, Painter};
 use kurbo::{Affine, Rect};
 use peniko::Color;
 
+use crate::composite::PixelRect;
+
 #[cfg(all(
     not(target_arch = "wasm32"),
     any(feature = "imaging_vello", feature = "imaging_vello_hybrid")
 ))]
 mod headless_wgpu;
 
+pub mod composite;
+#[cfg(feature = "export")]
+pub mod export;
+
 /// Masonry helpers for rendering retained scenes with `imaging_skia`.
 #[cfg(all(feature = "imaging_skia", not(target_arch = "wasm32")))]
 pub mod skia;
@@ -61,7 +70,8 @@ pub mod vello_cpu;
 #[cfg(all(not(target_arch = "wasm32"), feature = "imaging_vello_hybrid"))]
 pub mod vello_hybrid;
 
-pub use imaging::render::ImageRenderer;
+pub use imaging::RgbaImage;
+pub use imaging::render::{ImageBufferTarget, ImageRenderer};
 #[cfg(not(target_arch = "wasm32"))]
 pub use imaging_wgpu::TextureRenderer;
 
@@ -170,7 +180,7 @@ impl core::fmt::Debug for Layer<'_> {
 /// A flattened Masonry frame ready to be adapted to a concrete render target.
 ///
 /// This is intentionally a single-target convenience type for Masonry's current rendering paths.
-/// Future compositor-oriented work is expected to preserve more layer structure above this level.
+/// See [`composite::CompositeFrame`] for a frame which preserves the layer structure.
 #[derive(Clone, Copy, Debug)]
 pub struct PreparedFrame<'a> {
     /// Frame width in physical pixels.
@@ -206,6 +216,75 @@ impl<'a> PreparedFrame<'a> {
             overlays,
         }
     }
+
+    /// Returns the pixels covered by `damage`, or `None` if there are none.
+    ///
+    /// `damage` is in window coordinates, like the damage reported by Masonry's
+    /// `VisualLayerPlan`, and is scaled by the frame's scale factor and rounded out to
+    /// whole pixels.
+    pub(crate) fn damage_pixel_rect(&self, damage: Rect) -> Option<PixelRect> {
+        let rect = Affine::scale(self.scale_factor)
+            .transform_rect_bbox(damage)
+            // Antialiasing can bleed slightly outside of the painted shapes.
+            .inflate(1., 1.)
+            .expand();
+        PixelRect::clamped(rect, self.width, self.height)
+    }
+
+    /// Returns the layers of the frame, moved so that `rect` starts at the origin.
+    pub(crate) fn layers_cropped_to(&self, rect: PixelRect) -> Vec<Layer<'a>> {
+        std::iter::once(Layer {
+            scene: self.base,
+            transform: Affine::IDENTITY,
+        })
+        .chain(self.overlays.iter().copied())
+        .map(|layer| rect.crop(layer, self.scale_factor))
+        .collect()
+    }
+}
+
+/// Render the part of the given Masonry paint output covered by `damage` into `image`,
+/// leaving the rest of the image untouched.
+///
+/// This is the CPU counterpart of `texture_render::Renderer::render_damage_to_texture`,
+/// and `image` should hold the previous frame.
+/// If `image` doesn't have the size of the frame, it is resized and the whole frame is
+/// rendered.
+pub fn render_damage_into_image(
+    renderer: &mut (impl ImageRenderer + ?Sized),
+    image: &mut RgbaImage,
+    mut frame: PreparedFrame<'_>,
+    damage: Rect,
+) -> Result<(), ImageRendererError> {
+    if image.width != frame.width || image.height != frame.height {
+        *image = RgbaImage::new(frame.width, frame.height);
+        return renderer.render_source_into(&mut frame, ImageBufferTarget::from_rgba_image(image));
+    }
+    let Some(rect) = frame.damage_pixel_rect(damage) else {
+        return Ok(());
+    };
+    if rect.width == frame.width && rect.height == frame.height {
+        return renderer.render_source_into(&mut frame, ImageBufferTarget::from_rgba_image(image));
+    }
+
+    // Render the damaged area on its own, then copy it into place.
+    let layers = frame.layers_cropped_to(rect);
+    let empty = Scene::new();
+    let mut damage_frame = PreparedFrame::new(
+        rect.width,
+        rect.height,
+        frame.scale_factor,
+        frame.background_color,
+        &empty,
+        &layers,
+    );
+    let damaged = renderer.render_source(&mut damage_frame, rect.width, rect.height)?;
+    let row_len = rect.width as usize * 4;
+    for (y, row) in damaged.data.chunks_exact(row_len).enumerate() {
+        let start = ((rect.y as usize + y) * image.width as usize + rect.x as usize) * 4;
+        image.data[start..start + row_len].copy_from_slice(row);
+    }
+    Ok(())
 }
 
 impl RenderSource for PreparedFrame<'_> {
@@ -6,9 +6,14 @@
 //! This module owns backend state for rendering Masonry paint output into a caller-provided WGPU
 //! texture target. It does not own window surfaces or presentation.
 
+use std::collections::HashMap;
+
+use imaging::record::Scene;
+use kurbo::Rect;
 use wgpu;
 
 use crate::PreparedFrame;
+use crate::composite::{CompositeFrame, CompositeStats, LayerCache, PixelRect, Segment};
 
 /// GPU target that Masonry content should be rendered into.
 #[derive(Clone, Copy, Debug)]
@@ -39,7 +44,14 @@ impl std::error::Error for Error {}
 
 /// Backend-selected texture renderer for Masonry paint output.
 #[derive(Debug)]
-pub struct Renderer(imp::Renderer);
+pub struct Renderer {
+    backend: imp::Renderer,
+    layers: LayerCache<wgpu::Texture>,
+    scratch: Option<wgpu::Texture>,
+    damage_scratch: Option<wgpu::Texture>,
+    external_textures: HashMap<u64, wgpu::TextureView>,
+    blend: Option<BlendPipeline>,
+}
 
 impl Renderer {
     /// Stable backend name for diagnostics.
@@ -47,7 +59,14 @@ impl Renderer {
 
     /// Create an empty renderer state.
     pub fn new() -> Self {
-        Self(imp::Renderer::new())
+        Self {
+            backend: imp::Renderer::new(),
+            layers: LayerCache::new(),
+            scratch: None,
+            damage_scratch: None,
+            external_textures: HashMap::new(),
+            blend: None,
+        }
     }
 
     /// Render the given Masonry paint output into the provided target texture.
@@ -56,7 +75,397 @@ impl Renderer {
         target: RenderTarget<'_>,
         frame: PreparedFrame<'_>,
     ) -> Result<(), Error> {
-        self.0.render_to_texture(target, frame).map_err(Error)
+        self.backend.render_to_texture(target, frame).map_err(Error)
+    }
+
+    /// Render the part of the given Masonry paint output covered by `damage` into the
+    /// provided target texture, leaving the rest of the texture untouched.
+    ///
+    /// `damage` is in window coordinates, like the damage reported by Masonry's
+    /// `VisualLayerPlan`, and is scaled by the frame's scale factor and rounded out to
+    /// whole pixels.
+    /// Only the damaged area is rendered, into an intermediate texture which is then copied
+    /// into the target, so the target texture must support
+    /// [`COPY_DST`](wgpu::TextureUsages::COPY_DST) and hold the previous frame.
+    pub fn render_damage_to_texture(
+        &mut self,
+        target: RenderTarget<'_>,
+        frame: PreparedFrame<'_>,
+        damage: Rect,
+    ) -> Result<(), Error> {
+        let Some(rect) = frame.damage_pixel_rect(damage) else {
+            return Ok(());
+        };
+        let PixelRect {
+            x: x0,
+            y: y0,
+            width,
+            height,
+        } = rect;
+        if width == frame.width && height == frame.height {
+            return self.render_to_texture(target, frame);
+        }
+
+        // Render the damaged area at the origin of a texture of its size.
+        let layers = frame.layers_cropped_to(rect);
+        let empty = Scene::new();
+        let damage_frame = PreparedFrame::new(
+            width,
+            height,
+            frame.scale_factor,
+            frame.background_color,
+            &empty,
+            &layers,
+        );
+
+        if self
+            .damage_scratch
+            .as_ref()
+            .is_none_or(|scratch| scratch.width() != width || scratch.height() != height)
+        {
+            self.damage_scratch = Some(target.device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("masonry_imaging::texture_render::damage"),
+                size: wgpu::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: target.texture.format(),
+                usage: target.texture.usage() | wgpu::TextureUsages::COPY_SRC,
+                view_formats: &[],
+            }));
+        }
+        let scratch = self.damage_scratch.as_ref().unwrap();
+        let view = scratch.create_view(&wgpu::TextureViewDescriptor::default());
+        self.backend
+            .render_to_texture(layer_target(target, scratch, &view), damage_frame)
+            .map_err(Error)?;
+
+        let mut encoder = target
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("masonry_imaging::texture_render::copy_damage"),
+            });
+        encoder.copy_texture_to_texture(
+            scratch.as_image_copy(),
+            wgpu::TexelCopyTextureInfo {
+                origin: wgpu::Origin3d { x: x0, y: y0, z: 0 },
+                ..target.texture.as_image_copy()
+            },
+            wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        );
+        target.queue.submit([encoder.finish()]);
+        Ok(())
+    }
+
+    /// Sets the content of the external layers owned by the widget with the given raw id.
+    ///
+    /// The texture is stretched to cover the layer's bounds, and is expected to hold
+    /// unpremultiplied colors in a filterable float format.
+    /// It must have been created on the device used for rendering.
+    pub fn set_external_texture(&mut self, id: u64, view: wgpu::TextureView) {
+        self.external_textures.insert(id, view);
+    }
+
+    /// Removes the content of the external layers owned by the widget with the given raw id.
+    ///
+    /// External layers without content are left empty.
+    pub fn remove_external_texture(&mut self, id: u64) -> Option<wgpu::TextureView> {
+        self.external_textures.remove(&id)
+    }
+
+    /// Composite the given layered Masonry paint output into the provided target texture.
+    ///
+    /// Isolated layers are kept in textures covering their bounds between calls,
+    /// and are only re-rendered when they change.
+    /// The target texture must support [`RENDER_ATTACHMENT`](wgpu::TextureUsages::RENDER_ATTACHMENT).
+    pub fn render_composited(
+        &mut self,
+        target: RenderTarget<'_>,
+        frame: CompositeFrame<'_>,
+    ) -> Result<CompositeStats, Error> {
+        let empty = Scene::new();
+        let mut stats = CompositeStats::default();
+        let mut segments = frame.segments().into_iter().peekable();
+
+        // The first scene layers are rendered directly above the background.
+        let base_layers = match segments.peek() {
+            Some(Segment::Scenes(_)) => {
+                let Some(Segment::Scenes(layers)) = segments.next() else {
+                    unreachable!()
+                };
+                layers
+            }
+            _ => Vec::new(),
+        };
+        self.backend
+            .render_to_texture(
+                target,
+                frame.prepared(&empty, &base_layers, frame.background_color),
+            )
+            .map_err(Error)?;
+
+        let full_rect = PixelRect {
+            x: 0,
+            y: 0,
+            width: frame.width,
+            height: frame.height,
+        };
+        let blend = BlendPipeline::get_or_create(&mut self.blend, target);
+        for segment in segments {
+            match segment {
+                Segment::Scenes(layers) => {
+                    if self.scratch.as_ref().is_none_or(|scratch| {
+                        scratch.width() != frame.width || scratch.height() != frame.height
+                    }) {
+                        self.scratch =
+                            Some(create_layer_texture(target, frame.width, frame.height));
+                    }
+                    let scratch = self.scratch.as_ref().unwrap();
+                    let view = scratch.create_view(&wgpu::TextureViewDescriptor::default());
+                    self.backend
+                        .render_to_texture(
+                            layer_target(target, scratch, &view),
+                            frame.prepared(&empty, &layers, peniko::Color::TRANSPARENT),
+                        )
+                        .map_err(Error)?;
+                    blend.blend(target, &view, full_rect);
+                }
+                Segment::Isolated {
+                    key,
+                    generation,
+                    layer,
+                    rect,
+                } => {
+                    let backend = &mut self.backend;
+                    let texture = self.layers.get_or_render(
+                        key,
+                        generation,
+                        layer,
+                        rect,
+                        &frame,
+                        &mut stats,
+                        || {
+                            let texture = create_layer_texture(target, rect.width, rect.height);
+                            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+                            let layers = [rect.crop(layer, frame.scale_factor)];
+                            backend
+                                .render_to_texture(
+                                    layer_target(target, &texture, &view),
+                                    frame.cropped(&empty, &layers, rect),
+                                )
+                                .map_err(Error)?;
+                            Ok(texture)
+                        },
+                    )?;
+                    blend.blend(
+                        target,
+                        &texture.create_view(&wgpu::TextureViewDescriptor::default()),
+                        rect,
+                    );
+                }
+                Segment::External(id, rect) => {
+                    if let Some(view) = self.external_textures.get(&id) {
+                        blend.blend(target, view, rect);
+                    }
+                }
+            }
+        }
+        self.layers.retain_frame_layers(&frame);
+
+        Ok(stats)
+    }
+}
+
+/// Creates a texture which can be rendered into like `target`, and sampled from.
+fn create_layer_texture(target: RenderTarget<'_>, width: u32, height: u32) -> wgpu::Texture {
+    target.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("masonry_imaging::texture_render::layer"),
+        size: wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: target.texture.format(),
+        usage: target.texture.usage() | wgpu::TextureUsages::TEXTURE_BINDING,
+        view_formats: &[],
+    })
+}
+
+fn layer_target<'a>(
+    target: RenderTarget<'a>,
+    texture: &'a wgpu::Texture,
+    view: &'a wgpu::TextureView,
+) -> RenderTarget<'a> {
+    RenderTarget {
+        texture,
+        view,
+        ..target
+    }
+}
+
+/// Blends textures over a render target.
+#[derive(Debug)]
+struct BlendPipeline {
+    /// The device and target format the pipeline was built for.
+    key: (wgpu::Device, wgpu::TextureFormat),
+    pipeline: wgpu::RenderPipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+}
+
+impl BlendPipeline {
+    fn get_or_create<'a>(cache: &'a mut Option<Self>, target: RenderTarget<'_>) -> &'a Self {
+        let key = (target.device.clone(), target.texture.format());
+        if cache.as_ref().is_none_or(|blend| blend.key != key) {
+            *cache = Some(Self::new(key));
+        }
+        cache.as_ref().unwrap()
+    }
+
+    fn new(key: (wgpu::Device, wgpu::TextureFormat)) -> Self {
+        let device = &key.0;
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("masonry_imaging::texture_render::blend_sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("masonry_imaging::texture_render::blend_bind_group_layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("masonry_imaging::texture_render::blend_pipeline_layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            immediate_size: 0,
+        });
+        let shader = device.create_shader_module(wgpu::include_wgsl!("composite.wgsl"));
+        // Layers hold unpremultiplied colors.
+        let blend = wgpu::BlendState {
+            color: wgpu::BlendComponent {
+                src_factor: wgpu::BlendFactor::SrcAlpha,
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                operation: wgpu::BlendOperation::Add,
+            },
+            alpha: wgpu::BlendComponent {
+                src_factor: wgpu::BlendFactor::One,
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                operation: wgpu::BlendOperation::Add,
+            },
+        };
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("masonry_imaging::texture_render::blend_pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+                buffers: &[],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: key.1,
+                    blend: Some(blend),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            multiview_mask: None,
+            cache: None,
+        });
+
+        Self {
+            key,
+            pipeline,
+            bind_group_layout,
+            sampler,
+        }
+    }
+
+    /// Blends `source` over the `rect` area of `target`, and submits the work immediately.
+    fn blend(&self, target: RenderTarget<'_>, source: &wgpu::TextureView, rect: PixelRect) {
+        let bind_group = target.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("masonry_imaging::texture_render::blend_bind_group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(source),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&self.sampler),
+                },
+            ],
+        });
+        let mut encoder = target
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("masonry_imaging::texture_render::blend"),
+            });
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("masonry_imaging::texture_render::blend_pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: target.view,
+                    depth_slice: None,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+                multiview_mask: None,
+            });
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.set_viewport(
+                rect.x as f32,
+                rect.y as f32,
+                rect.width as f32,
+                rect.height as f32,
+                0.,
+                1.,
+            );
+            pass.draw(0..3, 0..1);
+        }
+        target.queue.submit([encoder.finish()]);
     }
 }
 
@@ -78,13 +487,17 @@ mod shared_texture_renderer {
 
     #[derive(Debug)]
     pub(super) struct RendererCache<R, K> {
+        /// The wgpu handles the renderer was created for.
+        ///
+        /// wgpu handles compare by resource identity, so a new device that happens
+        /// to reuse the address of a dropped one still invalidates the cache.
         key: Option<K>,
         inner: Option<R>,
     }
 
     impl<R, K> RendererCache<R, K>
     where
-        K: Copy + Eq,
+        K: Eq,
     {
         pub(super) fn new() -> Self {
             Self {
@@ -98,7 +511,7 @@ mod shared_texture_renderer {
             key: K,
             create: impl FnOnce() -> Result<R, E>,
         ) -> Result<&mut R, E> {
-            if self.key != Some(key) {
+            if self.key.as_ref() != Some(&key) {
                 self.inner = Some(create()?);
                 self.key = Some(key);
             }
@@ -112,11 +525,8 @@ mod shared_texture_renderer {
 
     #[cfg(any(feature = "imaging_vello", feature = "imaging_vello_hybrid"))]
     #[inline]
-    pub(super) fn device_queue_key(target: super::RenderTarget<'_>) -> (usize, usize) {
-        (
-            target.device as *const _ as usize,
-            target.queue as *const _ as usize,
-        )
+    pub(super) fn device_queue_key(target: super::RenderTarget<'_>) -> (wgpu::Device, wgpu::Queue) {
+        (target.device.clone(), target.queue.clone())
     }
 
     pub(super) fn render_window_source_to_texture<R>(
@@ -207,7 +617,7 @@ mod imp {
     /// Runtime renderer state for the Skia backend.
     #[derive(Debug)]
     pub(super) struct Renderer {
-        inner: RendererCache<TargetRenderer, (usize, usize, usize)>,
+        inner: RendererCache<TargetRenderer, (wgpu::Adapter, wgpu::Device, wgpu::Queue)>,
     }
 
     impl Renderer {
@@ -229,9 +639,9 @@ mod imp {
         ) -> Result<(), Error> {
             let renderer = self.inner.get_or_try_init(
                 (
-                    target.adapter as *const _ as usize,
-                    target.device as *const _ as usize,
-                    target.queue as *const _ as usize,
+                    target.adapter.clone(),
+                    target.device.clone(),
+                    target.queue.clone(),
                 ),
                 || {
                     new_target_renderer(
@@ -283,7 +693,7 @@ mod imp {
     /// Runtime renderer state for the Vello backend.
     #[derive(Debug)]
     pub(super) struct Renderer {
-        inner: RendererCache<TargetRenderer, (usize, usize)>,
+        inner: RendererCache<TargetRenderer, (wgpu::Device, wgpu::Queue)>,
     }
 
     impl Renderer {
@@ -353,7 +763,7 @@ mod imp {
     /// Runtime renderer state for the Vello Hybrid backend.
     #[derive(Debug)]
     pub(super) struct Renderer {
-        inner: RendererCache<TargetRenderer, (usize, usize)>,
+        inner: RendererCache<TargetRenderer, (wgpu::Device, wgpu::Queue)>,
     }
 
     impl Renderer {
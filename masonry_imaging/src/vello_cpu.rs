@@ -26,7 +26,9 @@ pub const BACKEND_NAME: &str = "imaging_vello_cpu";
 /// Masonry alias for the selected Vello CPU renderer type.
 pub type Renderer = imaging_vello_cpu::VelloCpuRenderer;
 
-/// Create a reusable headless Vello CPU renderer.
+/// Create a reusable Vello CPU renderer.
+///
+/// It renders into caller-owned image buffers, which hosts can present in software.
 pub fn new_headless_renderer() -> Result<Renderer, Error> {
     Ok(imaging_vello_cpu::VelloCpuRenderer::new(1, 1))
 }
@@ -0,0 +1,276 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Automated checks for common accessibility problems.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use masonry_core::accesskit::{Action, Role};
+use masonry_core::core::{Widget, WidgetId, WidgetRef};
+use masonry_core::kurbo::Rect;
+use masonry_core::peniko::color::{AlphaColor, LinearSrgb, Srgb};
+use masonry_core::properties::{Background, ContentColor};
+
+use crate::TestHarness;
+
+/// The smallest contrast ratio between text and its background accepted by
+/// [`TestHarness::accessibility_audit`].
+///
+/// This is the minimum the Web Content Accessibility Guidelines require for normal text
+/// at level AA.
+pub const MIN_CONTRAST_RATIO: f64 = 4.5;
+
+/// A problem found by [`TestHarness::accessibility_audit`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum AccessibilityIssue {
+    /// An interactive widget has no label, so screen readers can't say what it does.
+    MissingLabel {
+        /// The unlabelled widget.
+        widget: WidgetId,
+    },
+    /// An image has no alt text, and isn't marked as decorative.
+    MissingAltText {
+        /// The image widget.
+        widget: WidgetId,
+    },
+    /// A widget accepts focus, but can't be reached by pressing Tab.
+    NotReachableByTab {
+        /// The unreachable widget.
+        widget: WidgetId,
+    },
+    /// Text doesn't stand out enough from its background.
+    ///
+    /// See [`MIN_CONTRAST_RATIO`].
+    LowContrast {
+        /// The text widget.
+        widget: WidgetId,
+        /// The contrast ratio between the text color and the background color, from 1 to 21.
+        ratio: f64,
+    },
+    /// The hit targets of two interactive widgets overlap, so clicks may reach the wrong one.
+    OverlappingHitTargets {
+        /// The first widget, in tree order.
+        widget: WidgetId,
+        /// The second widget, in tree order.
+        other: WidgetId,
+    },
+}
+
+impl AccessibilityIssue {
+    /// Returns the widget the issue is about.
+    pub fn widget(&self) -> WidgetId {
+        match self {
+            Self::MissingLabel { widget }
+            | Self::MissingAltText { widget }
+            | Self::NotReachableByTab { widget }
+            | Self::LowContrast { widget, .. }
+            | Self::OverlappingHitTargets { widget, .. } => *widget,
+        }
+    }
+}
+
+impl fmt::Display for AccessibilityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingLabel { widget } => {
+                write!(f, "interactive widget {widget} has no label")
+            }
+            Self::MissingAltText { widget } => write!(
+                f,
+                "image {widget} has no alt text and isn't marked as decorative"
+            ),
+            Self::NotReachableByTab { widget } => {
+                write!(f, "focusable widget {widget} can't be reached with Tab")
+            }
+            Self::LowContrast { widget, ratio } => write!(
+                f,
+                "text of widget {widget} has a contrast ratio of {ratio:.2} with its background \
+                (at least {MIN_CONTRAST_RATIO} needed)"
+            ),
+            Self::OverlappingHitTargets { widget, other } => {
+                write!(f, "hit targets of widgets {widget} and {other} overlap")
+            }
+        }
+    }
+}
+
+/// What the audit needs to know about a widget.
+struct AuditedWidget {
+    id: WidgetId,
+    /// The widget and its ancestors.
+    path: Vec<WidgetId>,
+    /// The widget's border-box, in window coordinates.
+    bounds: Rect,
+    /// The contrast ratio of the widget's text with its background, if it shows text.
+    contrast: Option<f64>,
+}
+
+impl<W: Widget> TestHarness<W> {
+    /// Checks the widget tree for common accessibility problems.
+    ///
+    /// This looks for:
+    /// - Interactive widgets without a label.
+    /// - Images without alt text which aren't marked as decorative.
+    /// - Focusable widgets which can't be reached by pressing Tab.
+    /// - Text whose [`ContentColor`] has a contrast ratio with the [`Background`] behind it
+    ///   below [`MIN_CONTRAST_RATIO`].
+    /// - Interactive widgets whose hit targets overlap.
+    ///
+    /// Which widgets are reachable is read from the [focus chain](Self::focus_chain),
+    /// so the audit doesn't send any event to the widgets.
+    ///
+    /// Only the widgets of the base layer are checked.
+    pub fn accessibility_audit(&mut self) -> Vec<AccessibilityIssue> {
+        let _ = self.redraw();
+
+        let mut widgets = Vec::new();
+        collect_widgets(
+            self.get_widget_with_id(self.root_id()),
+            &mut Vec::new(),
+            Some(self.background_color()),
+            &mut widgets,
+        );
+
+        let mut issues = Vec::new();
+        let mut focusable = Vec::new();
+        let mut hit_targets = Vec::new();
+        for widget in &widgets {
+            let Some(node) = self.access_node(widget.id) else {
+                continue;
+            };
+            let data = node.data();
+            if data.supports_action(Action::Focus) {
+                focusable.push(widget.id);
+            }
+            if data.supports_action(Action::Click) && !node.is_disabled() {
+                hit_targets.push(widget);
+            }
+            if node.is_hidden() {
+                continue;
+            }
+            let has_text = |text: Option<String>| text.is_some_and(|text| !text.trim().is_empty());
+            if node.role() == Role::Image {
+                if !has_text(node.label()) && !has_text(node.description()) {
+                    issues.push(AccessibilityIssue::MissingAltText { widget: widget.id });
+                }
+            } else if (data.supports_action(Action::Click) || data.supports_action(Action::Focus))
+                && !has_text(node.label())
+                && !has_text(node.placeholder().map(str::to_string))
+            {
+                issues.push(AccessibilityIssue::MissingLabel { widget: widget.id });
+            }
+        }
+
+        let reachable: HashSet<_> = self.focus_chain().into_iter().collect();
+        issues.extend(
+            focusable
+                .iter()
+                .filter(|id| !reachable.contains(id))
+                .map(|&widget| AccessibilityIssue::NotReachableByTab { widget }),
+        );
+
+        issues.extend(widgets.iter().filter_map(|widget| {
+            let ratio = widget.contrast?;
+            (ratio < MIN_CONTRAST_RATIO).then_some(AccessibilityIssue::LowContrast {
+                widget: widget.id,
+                ratio,
+            })
+        }));
+
+        for (i, widget) in hit_targets.iter().enumerate() {
+            for other in &hit_targets[i + 1..] {
+                let related = widget.path.contains(&other.id) || other.path.contains(&widget.id);
+                let overlap = widget.bounds.intersect(other.bounds);
+                // Touching edges and rounding errors aren't overlaps.
+                if !related && overlap.width() > 0.5 && overlap.height() > 0.5 {
+                    issues.push(AccessibilityIssue::OverlappingHitTargets {
+                        widget: widget.id,
+                        other: other.id,
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+
+    /// Asserts that [`accessibility_audit`](Self::accessibility_audit) finds no problems.
+    ///
+    /// # Panics
+    ///
+    /// If any problem is found, listing all of them.
+    #[track_caller]
+    pub fn assert_accessible(&mut self) {
+        let issues = self.accessibility_audit();
+        if issues.is_empty() {
+            return;
+        }
+        let mut message = format!("Found {} accessibility issues:", issues.len());
+        for issue in &issues {
+            let name = self.get_widget_with_id(issue.widget()).short_type_name();
+            message.push_str(&format!("\n- {issue} ({name})"));
+        }
+        panic!("{message}");
+    }
+}
+
+/// Collects the non-stashed widgets under `widget`, in tree order.
+///
+/// `background` is the opaque color behind `widget`, if it's a plain color.
+fn collect_widgets(
+    widget: WidgetRef<'_, dyn Widget>,
+    path: &mut Vec<WidgetId>,
+    background: Option<AlphaColor<Srgb>>,
+    widgets: &mut Vec<AuditedWidget>,
+) {
+    let ctx = widget.ctx();
+    if ctx.is_stashed() {
+        return;
+    }
+    let background = match (background, widget.get_prop::<Background>()) {
+        (Some(below), Background::Color(color)) => Some(composite(*color, below)),
+        // We can't tell the contrast of text over a gradient.
+        (_, Background::Gradient(_)) | (None, _) => None,
+    };
+    let shows_text = matches!(
+        widget.inner().accessibility_role(),
+        Role::Label | Role::TextInput | Role::MultilineTextInput | Role::PasswordInput
+    );
+    let contrast = if shows_text && !ctx.is_disabled() {
+        background.map(|background| {
+            let text = composite(widget.get_prop::<ContentColor>().color, background);
+            contrast_ratio(text, background)
+        })
+    } else {
+        None
+    };
+
+    path.push(widget.id());
+    widgets.push(AuditedWidget {
+        id: widget.id(),
+        path: path.clone(),
+        bounds: ctx.window_transform().transform_rect_bbox(ctx.border_box()),
+        contrast,
+    });
+    for child in widget.children() {
+        collect_widgets(child, path, background, widgets);
+    }
+    path.pop();
+}
+
+/// Paints `color` over the opaque color `below`.
+fn composite(color: AlphaColor<Srgb>, below: AlphaColor<Srgb>) -> AlphaColor<Srgb> {
+    below.lerp_rect(color.with_alpha(1.), color.components[3])
+}
+
+/// Returns the contrast ratio between two opaque colors, as defined by WCAG 2.
+fn contrast_ratio(a: AlphaColor<Srgb>, b: AlphaColor<Srgb>) -> f64 {
+    let luminance = |color: AlphaColor<Srgb>| {
+        let [r, g, b, _] = color.convert::<LinearSrgb>().components;
+        f64::from(0.2126 * r + 0.7152 * g + 0.0722 * b)
+    };
+    let (a, b) = (luminance(a), luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
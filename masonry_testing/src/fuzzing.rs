@@ -0,0 +1,774 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Property-based testing of widget trees.
+//!
+//! See [`WidgetFuzzer`].
+
+#![expect(
+    missing_debug_implementations,
+    reason = "Generators hold closures which can't be debug-printed"
+)]
+
+use std::cell::Cell;
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use masonry_core::accesskit::{Action, ActionRequest, TreeId};
+use masonry_core::core::keyboard::{Key, KeyState, NamedKey};
+use masonry_core::core::{
+    DefaultProperties, FromDynWidget, KeyboardEvent, NewWidget, PointerButton, TextEvent, Widget,
+    WidgetId, WidgetMut, WidgetPod, WidgetRef, WindowEvent,
+};
+use masonry_core::dpi::PhysicalSize;
+use masonry_core::kurbo::Vec2;
+use masonry_core::layout::Length;
+
+use crate::{ModularWidget, Record, Recorder, Recording, TestHarness};
+
+/// The environment variable which overrides the seed of [`WidgetFuzzer::run`].
+const SEED_ENV_VAR: &str = "MASONRY_FUZZ_SEED";
+
+/// The environment variable which overrides the number of cases of [`WidgetFuzzer::run`].
+const CASES_ENV_VAR: &str = "MASONRY_FUZZ_CASES";
+
+/// How many failing cases the shrinker tries before giving up on finding a smaller one.
+const MAX_SHRINK_ATTEMPTS: u32 = 2_000;
+
+/// The source of random choices for a [`WidgetFuzzer`] case.
+///
+/// Every random decision made while building a case goes through this type.
+/// When a case fails, the fuzzer replays simplified versions of the recorded choices
+/// to find a smaller case which still fails.
+/// Because of this, generators should derive their decisions from the values returned
+/// here rather than from another source of randomness, and smaller values should lead
+/// to simpler widgets.
+pub struct FuzzInput {
+    choices: Vec<u64>,
+    position: usize,
+    /// The state of the random number generator, or `None` when replaying choices.
+    rng: Option<u64>,
+}
+
+impl FuzzInput {
+    fn random(seed: u64) -> Self {
+        Self {
+            choices: Vec::new(),
+            position: 0,
+            rng: Some(seed),
+        }
+    }
+
+    fn replay(choices: Vec<u64>) -> Self {
+        Self {
+            choices,
+            position: 0,
+            rng: None,
+        }
+    }
+
+    /// The choices made so far.
+    fn consumed(&self) -> Vec<u64> {
+        self.choices[..self.position.min(self.choices.len())].to_vec()
+    }
+
+    fn draw(&mut self) -> u64 {
+        let value = if let Some(&value) = self.choices.get(self.position) {
+            value
+        } else if let Some(state) = &mut self.rng {
+            let value = split_mix(state);
+            self.choices.push(value);
+            value
+        } else {
+            // Replayed choices ran out, so we pick the simplest option.
+            0
+        };
+        self.position += 1;
+        value
+    }
+
+    /// Returns a number in `0..n`, or 0 if `n` is 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        if n == 0 {
+            return 0;
+        }
+        usize::try_from(self.draw() % n as u64).unwrap()
+    }
+
+    /// Returns a random boolean, shrinking towards `false`.
+    pub fn bool(&mut self) -> bool {
+        self.below(2) == 1
+    }
+
+    /// Returns a number between `min` and `max`, shrinking towards `min`.
+    pub fn f64_between(&mut self, min: f64, max: f64) -> f64 {
+        let t = f64::from(u32::try_from(self.draw() % 1001).unwrap()) / 1000.;
+        min + (max - min) * t
+    }
+
+    /// Returns one of the given items, shrinking towards the first one.
+    ///
+    /// # Panics
+    ///
+    /// If `items` is empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        assert!(!items.is_empty(), "FuzzInput::pick: no items to pick from");
+        &items[self.below(items.len())]
+    }
+}
+
+/// One step of the `SplitMix64` generator.
+fn split_mix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+type BuildFn = dyn Fn(&mut FuzzInput, Vec<NewWidget<dyn Widget>>) -> NewWidget<dyn Widget>;
+type AppliesFn = dyn Fn(&WidgetRef<'_, dyn Widget>) -> bool;
+type EditFn = dyn Fn(&mut FuzzInput, WidgetMut<'_, dyn Widget>);
+
+struct WidgetKind {
+    name: &'static str,
+    max_children: usize,
+    build: Box<BuildFn>,
+}
+
+struct WidgetEdit {
+    name: &'static str,
+    applies: Box<AppliesFn>,
+    edit: Box<EditFn>,
+}
+
+/// An instrumented widget inserted in generated trees, which checks the order in which
+/// the passes call its methods.
+type Probe<S> = Recorder<ModularWidget<S>>;
+
+/// What a probe has seen so far.
+struct ProbeState {
+    recording: Recording,
+    registered: bool,
+    measured: bool,
+    laid_out: bool,
+}
+
+impl ProbeState {
+    fn new(recording: Recording) -> Self {
+        Self {
+            recording,
+            registered: false,
+            measured: false,
+            laid_out: false,
+        }
+    }
+
+    fn check(&mut self) -> Result<(), String> {
+        for record in self.recording.drain() {
+            match record {
+                Record::RegisterChildren => self.registered = true,
+                record if !self.registered => {
+                    return Err(format!(
+                        "Probe got {record:?} before its children were registered"
+                    ));
+                }
+                Record::Measure(_) => self.measured = true,
+                Record::Layout(size) => {
+                    if !self.measured {
+                        return Err("Probe was laid out before being measured".into());
+                    }
+                    if !size.is_finite() || size.width < 0. || size.height < 0. {
+                        return Err(format!("Probe was laid out with invalid size {size}"));
+                    }
+                    self.laid_out = true;
+                }
+                Record::PrePaint | Record::Paint | Record::PostPaint if !self.laid_out => {
+                    return Err("Probe was painted before being laid out".into());
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A fuzzer which checks the invariants of Masonry's passes on random widget trees.
+///
+/// The fuzzer builds random trees from the widgets registered with [`with_leaf`](Self::with_leaf)
+/// and [`with_container`](Self::with_container), then runs random sequences of pointer, text
+/// and accessibility events, window resizes, animation frames and widget edits on them.
+/// Instrumented probe widgets built on [`ModularWidget`] and [`Recorder`] are mixed into the tree.
+///
+/// After each step, the fuzzer checks that:
+/// - No pass panicked, including the debug assertions of Masonry's passes.
+/// - Each widget has a single parent, and all ids returned by [`Widget::children_ids`] are in the tree.
+/// - Children of stashed or disabled widgets are stashed or disabled.
+/// - Laid out widgets have finite bounds.
+/// - Probes had their children registered before any other method was called,
+///   were measured before being laid out, and laid out before being painted.
+///
+/// When a case fails, the fuzzer shrinks it to a smaller case which still fails, then
+/// panics with a description of the widget tree and of the steps which reproduce the failure.
+///
+/// Runs are deterministic.
+/// The seed and the number of cases can be overridden with the `MASONRY_FUZZ_SEED` and
+/// `MASONRY_FUZZ_CASES` environment variables, for longer fuzzing sessions.
+pub struct WidgetFuzzer {
+    kinds: Vec<WidgetKind>,
+    edits: Vec<WidgetEdit>,
+    seed: u64,
+    cases: u32,
+    max_steps: usize,
+    max_depth: usize,
+    window_size: PhysicalSize<u32>,
+}
+
+impl Default for WidgetFuzzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WidgetFuzzer {
+    /// Creates a fuzzer with no widgets besides its probes.
+    pub fn new() -> Self {
+        Self {
+            kinds: Vec::new(),
+            edits: Vec::new(),
+            seed: 0,
+            cases: 64,
+            max_steps: 24,
+            max_depth: 4,
+            window_size: PhysicalSize::new(400, 400),
+        }
+    }
+
+    /// Builder method to add a widget without children to the generated trees.
+    pub fn with_leaf<W: Widget>(
+        mut self,
+        name: &'static str,
+        build: impl Fn(&mut FuzzInput) -> NewWidget<W> + 'static,
+    ) -> Self {
+        self.kinds.push(WidgetKind {
+            name,
+            max_children: 0,
+            build: Box::new(move |input, _| build(input).erased()),
+        });
+        self
+    }
+
+    /// Builder method to add a widget with up to `max_children` children to the generated trees.
+    ///
+    /// `build` is given the generated children, between zero and `max_children` of them.
+    pub fn with_container<W: Widget>(
+        mut self,
+        name: &'static str,
+        max_children: usize,
+        build: impl Fn(&mut FuzzInput, Vec<NewWidget<dyn Widget>>) -> NewWidget<W> + 'static,
+    ) -> Self {
+        self.kinds.push(WidgetKind {
+            name,
+            max_children,
+            build: Box::new(move |input, children| build(input, children).erased()),
+        });
+        self
+    }
+
+    /// Builder method to add an edit which can be applied to widgets of type `W`.
+    pub fn with_edit<W: Widget + FromDynWidget + ?Sized>(
+        mut self,
+        name: &'static str,
+        edit: impl Fn(&mut FuzzInput, WidgetMut<'_, W>) + 'static,
+    ) -> Self {
+        self.edits.push(WidgetEdit {
+            name,
+            applies: Box::new(|widget| widget.downcast::<W>().is_some()),
+            edit: Box::new(move |input, mut widget| edit(input, widget.downcast::<W>())),
+        });
+        self
+    }
+
+    /// Builder method to set the seed the cases are generated from.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Builder method to set the number of cases to run.
+    pub fn with_cases(mut self, cases: u32) -> Self {
+        self.cases = cases;
+        self
+    }
+
+    /// Builder method to set the maximum number of steps run on each tree.
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Builder method to set the maximum depth of the generated trees.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Builder method to set the size of the simulated window.
+    pub fn with_window_size(mut self, width: u32, height: u32) -> Self {
+        self.window_size = PhysicalSize::new(width, height);
+        self
+    }
+
+    /// Runs the fuzzer, creating each test harness with the properties returned by `default_props`.
+    ///
+    /// # Panics
+    ///
+    /// If a case fails, with a description of the smallest failing case found.
+    #[track_caller]
+    pub fn run(&self, default_props: impl Fn() -> DefaultProperties) {
+        let seed = env_var(SEED_ENV_VAR).unwrap_or(self.seed);
+        let cases = env_var(CASES_ENV_VAR).unwrap_or(self.cases);
+
+        for case in 0..cases {
+            let case_seed = split_mix(&mut seed.wrapping_add(u64::from(case)));
+            let mut input = FuzzInput::random(case_seed);
+            if self.run_case(&mut input, &default_props).is_ok() {
+                continue;
+            }
+
+            let choices = self.shrink(input.consumed(), &default_props);
+            let mut input = FuzzInput::replay(choices);
+            let failure = self
+                .run_case(&mut input, &default_props)
+                .expect_err("shrunk case should fail");
+            panic!(
+                "Fuzzing found a failing case (seed {seed}, case {case}).\n\
+                Shrunk case:\n{}\nError: {}",
+                failure.description, failure.error
+            );
+        }
+    }
+
+    /// Looks for a simpler list of choices which still makes a case fail.
+    fn shrink(
+        &self,
+        mut choices: Vec<u64>,
+        default_props: &dyn Fn() -> DefaultProperties,
+    ) -> Vec<u64> {
+        let attempts = Cell::new(0);
+        let still_fails = |candidate: Vec<u64>| {
+            attempts.set(attempts.get() + 1);
+            let mut input = FuzzInput::replay(candidate);
+            self.run_case(&mut input, default_props)
+                .is_err()
+                .then(|| input.consumed())
+        };
+
+        loop {
+            let mut shrunk = false;
+
+            // Removing choices removes widgets and steps.
+            for chunk in [8, 4, 2, 1] {
+                let mut start = 0;
+                while start + chunk <= choices.len() {
+                    let mut candidate = choices.clone();
+                    candidate.drain(start..start + chunk);
+                    if let Some(smaller) = still_fails(candidate) {
+                        choices = smaller;
+                        shrunk = true;
+                    } else {
+                        start += 1;
+                    }
+                    if attempts.get() >= MAX_SHRINK_ATTEMPTS {
+                        return choices;
+                    }
+                }
+            }
+
+            // Smaller choices pick simpler options.
+            for i in 0..choices.len() {
+                let value = choices[i];
+                for smaller in [
+                    0,
+                    1,
+                    value % 2,
+                    value % 4,
+                    value % 16,
+                    value % 256,
+                    value / 2,
+                ] {
+                    if smaller >= value || i >= choices.len() {
+                        continue;
+                    }
+                    let mut candidate = choices.clone();
+                    candidate[i] = smaller;
+                    if let Some(simpler) = still_fails(candidate) {
+                        choices = simpler;
+                        shrunk = true;
+                        break;
+                    }
+                    if attempts.get() >= MAX_SHRINK_ATTEMPTS {
+                        return choices;
+                    }
+                }
+            }
+
+            if !shrunk {
+                return choices;
+            }
+        }
+    }
+
+    /// Runs a single case, catching panics.
+    fn run_case(
+        &self,
+        input: &mut FuzzInput,
+        default_props: &dyn Fn() -> DefaultProperties,
+    ) -> Result<(), CaseFailure> {
+        let mut description = String::new();
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            self.run_case_inner(input, default_props, &mut description)
+        }));
+        let error = match result {
+            Ok(Ok(())) => return Ok(()),
+            Ok(Err(error)) => error,
+            Err(payload) => {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|message| (*message).to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "unknown panic payload".into());
+                format!("panicked: {message}")
+            }
+        };
+        Err(CaseFailure { description, error })
+    }
+
+    fn run_case_inner(
+        &self,
+        input: &mut FuzzInput,
+        default_props: &dyn Fn() -> DefaultProperties,
+        description: &mut String,
+    ) -> Result<(), String> {
+        let mut probes = Vec::new();
+        description.push_str("Probe\n");
+        let tree = self.generate_widget(input, 1, &mut probes, description);
+        let root_recording = Recording::default();
+        // The root widget is given the window size without being measured.
+        probes.push(ProbeState {
+            measured: true,
+            ..ProbeState::new(root_recording.clone())
+        });
+        let root = Recorder::new(ModularWidget::new_parent(tree), &root_recording);
+
+        let mut harness = TestHarness::create_with_size(
+            default_props(),
+            NewWidget::new(root),
+            (
+                f64::from(self.window_size.width),
+                f64::from(self.window_size.height),
+            ),
+        );
+        let _ = harness.redraw();
+        check_invariants(&harness, &mut probes)?;
+
+        description.push_str("Steps:\n");
+        let steps = input.below(self.max_steps + 1);
+        for step in 1..=steps {
+            let _ = write!(description, "{step:>3}. ");
+            self.run_step(input, &mut harness, description);
+            description.push('\n');
+            let _ = harness.redraw();
+            check_invariants(&harness, &mut probes)?;
+        }
+        Ok(())
+    }
+
+    /// Generates a random widget, and writes its tree at the end of `description`.
+    fn generate_widget(
+        &self,
+        input: &mut FuzzInput,
+        depth: usize,
+        probes: &mut Vec<ProbeState>,
+        description: &mut String,
+    ) -> NewWidget<dyn Widget> {
+        let indent = "  ".repeat(depth);
+        let kinds: Vec<_> = self
+            .kinds
+            .iter()
+            .filter(|kind| kind.max_children == 0 || depth < self.max_depth)
+            .collect();
+
+        // The first choice is a probe without children, the simplest possible widget.
+        let choice = input.below(kinds.len() + 2);
+        if choice == 0 || kinds.is_empty() {
+            let _ = writeln!(description, "{indent}Probe");
+            let recording = Recording::default();
+            probes.push(ProbeState::new(recording.clone()));
+            let probe = ModularWidget::new(()).measure_fn(|_, _, _, _, _, _| Length::const_px(20.));
+            return NewWidget::new(Recorder::new(probe, &recording)).erased();
+        }
+        if choice == 1 && depth < self.max_depth {
+            let _ = writeln!(description, "{indent}Probe");
+            let child = self.generate_widget(input, depth + 1, probes, description);
+            let recording = Recording::default();
+            probes.push(ProbeState::new(recording.clone()));
+            let probe: Probe<WidgetPod<dyn Widget>> =
+                Recorder::new(ModularWidget::new_parent(child), &recording);
+            return NewWidget::new(probe).erased();
+        }
+
+        let kind = kinds[choice.saturating_sub(2).min(kinds.len() - 1)];
+        let _ = writeln!(description, "{indent}{}", kind.name);
+        let child_count = input.below(kind.max_children + 1);
+        let children = (0..child_count)
+            .map(|_| self.generate_widget(input, depth + 1, probes, description))
+            .collect();
+        (kind.build)(input, children)
+    }
+
+    /// Runs a random step, and writes what it did at the end of `description`.
+    fn run_step<W: Widget>(
+        &self,
+        input: &mut FuzzInput,
+        harness: &mut TestHarness<W>,
+        description: &mut String,
+    ) {
+        let widgets = widget_ids(harness);
+        let index = input.below(widgets.len());
+        let id = widgets[index];
+        let (name, stashed, disabled, accepts_focus) = {
+            let widget = harness.get_widget_with_id(id);
+            let ctx = widget.ctx();
+            (
+                widget.short_type_name(),
+                ctx.is_stashed(),
+                ctx.is_disabled(),
+                ctx.accepts_focus(),
+            )
+        };
+        let target = format!("widget {index} ({name})");
+
+        match input.below(16) {
+            0 => {
+                let x = input.f64_between(0., f64::from(self.window_size.width));
+                let y = input.f64_between(0., f64::from(self.window_size.height));
+                let _ = write!(description, "Move pointer to ({x:.1}, {y:.1})");
+                harness.mouse_move((x, y));
+            }
+            1 => {
+                description.push_str("Press primary button");
+                harness.mouse_button_press(Some(PointerButton::Primary));
+            }
+            2 => {
+                description.push_str("Release primary button");
+                harness.mouse_button_release(Some(PointerButton::Primary));
+            }
+            3 => {
+                let _ = write!(description, "Click {target}");
+                if !stashed {
+                    harness.mouse_move_to_unchecked(id);
+                    harness.mouse_button_press(Some(PointerButton::Primary));
+                    harness.mouse_button_release(Some(PointerButton::Primary));
+                }
+            }
+            4 => {
+                let delta = Vec2::new(
+                    input.f64_between(-200., 200.),
+                    input.f64_between(-200., 200.),
+                );
+                let _ = write!(description, "Scroll by ({:.1}, {:.1})", delta.x, delta.y);
+                harness.mouse_wheel(delta);
+            }
+            5 => {
+                let text = *input.pick(&["a", "Hello world", " ", "é\u{301}", "\n"]);
+                let _ = write!(description, "Type {text:?}");
+                harness.keyboard_type_chars(text);
+            }
+            6 => {
+                let key = *input.pick(&[
+                    NamedKey::Enter,
+                    NamedKey::Backspace,
+                    NamedKey::Delete,
+                    NamedKey::ArrowLeft,
+                    NamedKey::ArrowRight,
+                    NamedKey::ArrowUp,
+                    NamedKey::ArrowDown,
+                    NamedKey::Home,
+                    NamedKey::End,
+                    NamedKey::PageDown,
+                    NamedKey::Escape,
+                ]);
+                let _ = write!(description, "Press {key:?}");
+                for state in [KeyState::Down, KeyState::Up] {
+                    harness.process_text_event(TextEvent::Keyboard(KeyboardEvent {
+                        state,
+                        key: Key::Named(key),
+                        ..KeyboardEvent::default()
+                    }));
+                }
+            }
+            7 => {
+                let shift = input.bool();
+                let _ = write!(description, "Press Tab (shift: {shift})");
+                harness.press_tab_key(shift);
+            }
+            8 => {
+                let action = *input.pick(&[
+                    Action::Click,
+                    Action::Focus,
+                    Action::Increment,
+                    Action::Decrement,
+                    Action::ScrollIntoView,
+                ]);
+                let _ = write!(description, "Send {action:?} action to {target}");
+                harness.process_access_event(ActionRequest {
+                    action,
+                    target_tree: TreeId::ROOT,
+                    target_node: id.to_raw().into(),
+                    data: None,
+                });
+            }
+            9 => {
+                let _ = write!(description, "Focus {target}");
+                if accepts_focus && !stashed && !disabled {
+                    harness.focus_on(Some(id));
+                }
+            }
+            10 => {
+                let disabled = input.bool();
+                let _ = write!(description, "Set {target} disabled: {disabled}");
+                harness.edit_widget_with_id(id, |mut widget| {
+                    widget.ctx.set_disabled(disabled);
+                });
+            }
+            11 => {
+                let _ = write!(description, "Request layout of {target}");
+                harness.edit_widget_with_id(id, |mut widget| {
+                    widget.ctx.request_layout();
+                });
+            }
+            12 | 13 => {
+                let edits: Vec<_> = {
+                    let widget = harness.get_widget_with_id(id);
+                    self.edits
+                        .iter()
+                        .filter(|edit| (edit.applies)(&widget))
+                        .collect()
+                };
+                if edits.is_empty() {
+                    let _ = write!(description, "Request paint of {target}");
+                    harness.edit_widget_with_id(id, |mut widget| {
+                        widget.ctx.request_paint_only();
+                    });
+                } else {
+                    let edit = *input.pick(&edits);
+                    let _ = write!(description, "Edit {target}: {}", edit.name);
+                    harness.edit_widget_with_id(id, |widget| (edit.edit)(input, widget));
+                }
+            }
+            14 => {
+                let width = 1 + input.below(800);
+                let height = 1 + input.below(800);
+                let _ = write!(description, "Resize window to {width}x{height}");
+                harness.process_window_event(WindowEvent::Resize(PhysicalSize::new(
+                    u32::try_from(width).unwrap(),
+                    u32::try_from(height).unwrap(),
+                )));
+            }
+            _ => {
+                let ms = 1 + input.below(500) as u64;
+                let _ = write!(description, "Advance time by {ms}ms");
+                harness.animate_ms(ms);
+            }
+        }
+    }
+}
+
+/// A failing case.
+struct CaseFailure {
+    /// The widget tree and steps of the case.
+    description: String,
+    error: String,
+}
+
+fn env_var<T: std::str::FromStr>(name: &str) -> Option<T> {
+    let value = std::env::var(name).ok()?;
+    match value.parse() {
+        Ok(value) => Some(value),
+        Err(_) => panic!("Invalid value {value:?} for environment variable {name}"),
+    }
+}
+
+/// Returns the ids of the widgets of the base layer, in tree order.
+fn widget_ids<W: Widget>(harness: &TestHarness<W>) -> Vec<WidgetId> {
+    fn visit(widget: WidgetRef<'_, dyn Widget>, ids: &mut Vec<WidgetId>) {
+        ids.push(widget.id());
+        for child in widget.children() {
+            visit(child, ids);
+        }
+    }
+    let mut ids = Vec::new();
+    visit(harness.root_widget().as_dyn(), &mut ids);
+    ids
+}
+
+fn check_invariants<W: Widget>(
+    harness: &TestHarness<W>,
+    probes: &mut [ProbeState],
+) -> Result<(), String> {
+    fn visit<W: Widget>(
+        harness: &TestHarness<W>,
+        widget: WidgetRef<'_, dyn Widget>,
+        parent_stashed: bool,
+        parent_disabled: bool,
+        seen: &mut HashSet<WidgetId>,
+    ) -> Result<(), String> {
+        let id = widget.id();
+        let name = widget.short_type_name();
+        let ctx = widget.ctx();
+        if !seen.insert(id) {
+            return Err(format!("'{name}' {id} has more than one parent"));
+        }
+        if parent_stashed && !ctx.is_stashed() {
+            return Err(format!(
+                "'{name}' {id} has a stashed parent but isn't stashed"
+            ));
+        }
+        if parent_disabled && !ctx.is_disabled() {
+            return Err(format!(
+                "'{name}' {id} has a disabled parent but isn't disabled"
+            ));
+        }
+        if !ctx.is_stashed()
+            && !ctx
+                .window_transform()
+                .transform_rect_bbox(ctx.border_box())
+                .is_finite()
+        {
+            return Err(format!("'{name}' {id} has non-finite bounds"));
+        }
+        for child_id in widget.inner().children_ids() {
+            if harness.try_get_widget(child_id).is_none() {
+                return Err(format!(
+                    "'{name}' {id} returns child {child_id} from children_ids, \
+                    but it isn't in the tree"
+                ));
+            }
+        }
+        for child in widget.children() {
+            visit(harness, child, ctx.is_stashed(), ctx.is_disabled(), seen)?;
+        }
+        Ok(())
+    }
+
+    visit(
+        harness,
+        harness.root_widget().as_dyn(),
+        false,
+        false,
+        &mut HashSet::new(),
+    )?;
+    probes.iter_mut().try_for_each(ProbeState::check)
+}
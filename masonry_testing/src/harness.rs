@@ -30,6 +30,10 @@ use image::{DynamicImage, ImageFormat, ImageReader, Rgba, RgbaImage};
 use imaging_vello_cpu::VelloCpuRenderer;
 use masonry_core::imaging::record::{Scene, replay_transformed};
 use masonry_core::imaging::{ImageRenderer as _, Painter};
+use masonry_imaging::Layer as ImagingLayer;
+use masonry_imaging::composite::{
+    CompositeFrame, CompositeLayer, CompositeStats, ImageCompositor, LayerKey,
+};
 use oxipng::{Options, optimize_from_memory};
 use tracing::debug;
 
@@ -42,16 +46,22 @@ use masonry_core::app::{
 use masonry_core::core::keyboard::{Code, Key, KeyState, NamedKey};
 use masonry_core::core::{
     CursorIcon, DefaultProperties, ErasedAction, FromDynWidget, Handled, Ime, KeyboardEvent,
-    Modifiers, NewWidget, PointerButton, PointerButtonEvent, PointerEvent, PointerId, PointerInfo,
-    PointerScrollEvent, PointerState, PointerType, PointerUpdate, ScrollDelta, TextEvent, Widget,
-    WidgetId, WidgetMut, WidgetRef, WidgetTag, WindowEvent,
+    Modifiers, NewWidget, PointerButton, PointerButtonEvent, PointerEvent, PointerGesture,
+    PointerGestureEvent, PointerId, PointerInfo, PointerScrollEvent, PointerState, PointerType,
+    PointerUpdate, ScrollDelta, TextEvent, Widget, WidgetId, WidgetMut, WidgetRef, WidgetTag,
+    WindowEvent,
 };
 use masonry_core::dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize};
 use masonry_core::kurbo::{Affine, Point, Rect, Vec2};
 use masonry_core::peniko::{Blob, Color};
 use masonry_core::util::Duration;
 
-use crate::screenshots::get_image_diff;
+#[cfg(feature = "interaction_script")]
+use masonry_core::app::interaction_script::{InteractionScript, ScriptEvent};
+
+use crate::screenshots::{
+    ImageTolerance, PixelMetric, SCREENSHOT_BACKEND, get_image_diff, html_report, reference_path,
+};
 use crate::{Record, Recorder};
 
 /// A [`PointerInfo`] for a primary mouse, for testing.
@@ -148,12 +158,13 @@ pub struct TestHarness<W: Widget> {
     render_root: RenderRoot,
     access_tree: accesskit_consumer::Tree,
     renderer: Option<VelloCpuRenderer>,
+    compositor: ImageCompositor,
     mouse_state: PointerState,
     window_size: PhysicalSize<u32>,
     root_padding: u32,
     background_color: Color,
     panic_on_rewrite_saturation: bool,
-    screenshot_tolerance: u32,
+    screenshot_tolerance: ImageTolerance,
     max_screenshot_size: u32,
     action_queue: VecDeque<(ErasedAction, WidgetId)>,
     has_ime_session: bool,
@@ -186,9 +197,20 @@ pub struct TestHarnessParams {
     ///
     /// [`assert_render_snapshot`]: crate::assert_render_snapshot
     pub root_padding: u32,
-    /// The maximum difference between two pixel channels before the harness will fail a screenshot test.
+    /// The largest difference a pixel of a screenshot can have with its reference before it counts as differing.
     /// Defaults to [`TestHarnessParams::DEFAULT_SCREENSHOT_TOLERANCE`].
+    ///
+    /// The difference is measured with [`TestHarnessParams::screenshot_metric`].
     pub screenshot_tolerance: u32,
+    /// How the difference between two pixels is measured in screenshot tests.
+    /// Defaults to [`PixelMetric::Channel`].
+    pub screenshot_metric: PixelMetric,
+    /// How many pixels of a screenshot can differ from its reference before the harness will fail a screenshot test.
+    /// Defaults to zero.
+    ///
+    /// This lets tests accept a handful of anti-aliasing differences without raising
+    /// [`TestHarnessParams::screenshot_tolerance`] for the whole image.
+    pub max_differing_pixels: u32,
     /// The scale factor widgets are rendered at.
     /// Defaults to 1.0.
     pub scale_factor: f64,
@@ -214,8 +236,14 @@ pub struct TestHarnessParams {
 /// as documented in [`TestHarnessParams::root_padding`].
 ///
 /// If a screenshot already exists, the rendered value is compared against this screenshot.
-/// The assert passes if both are equal; otherwise, a diff file is created.
-/// If the test is run again and the new rendered value matches the old screenshot, the diff file is deleted.
+/// The assert passes if both are equal, within the tolerances set in [`TestHarnessParams`];
+/// otherwise, a diff file is created, along with a `<TEST-NAME>.report.html` page showing the
+/// reference, the new screenshot and the diff side by side.
+/// If the test is run again and the new rendered value matches the old screenshot, these files are deleted.
+///
+/// If a backend renders a screenshot differently from the others, it can be given its own reference,
+/// stored as `<CRATE-ROOT>/screenshots/<TEST-NAME>.<BACKEND>.png` (e.g. `button.vello_cpu.png`).
+/// This file is used instead of the shared reference when it exists.
 ///
 /// If a screenshot doesn't exist, the assert will fail; the new screenshot is stored as
 /// `<CRATE-ROOT>/screenshots/<TEST-NAME>.new.png`, and must be renamed before the assert will pass.
@@ -248,6 +276,24 @@ macro_rules! assert_failing_render_snapshot {
     };
 }
 
+/// Replay an [interaction script](masonry_core::app::interaction_script), asserting a snapshot
+/// at each of its snapshot steps.
+///
+/// This macro takes a test harness and a script, and replays the script with
+/// [`TestHarness::replay_script`]. Each snapshot step is checked like [`assert_render_snapshot`],
+/// using the name of the step as the name of the snapshot.
+///
+/// [`assert_render_snapshot`]: crate::assert_render_snapshot
+#[cfg(feature = "interaction_script")]
+#[macro_export]
+macro_rules! assert_script_replay {
+    ($test_harness:expr, $script:expr) => {
+        $test_harness.replay_script($script, |harness, name| {
+            harness.check_render_snapshot(env!("CARGO_MANIFEST_DIR"), name, false);
+        })
+    };
+}
+
 impl TestHarnessParams {
     /// Default test param values.
     pub const DEFAULT: Self = Self {
@@ -255,6 +301,8 @@ impl TestHarnessParams {
         background_color: Self::DEFAULT_BACKGROUND_COLOR,
         root_padding: 0,
         screenshot_tolerance: Self::DEFAULT_SCREENSHOT_TOLERANCE,
+        screenshot_metric: PixelMetric::Channel,
+        max_differing_pixels: 0,
         scale_factor: 1.0,
         panic_on_rewrite_saturation: true,
         max_screenshot_size: 8 * Self::KIBIBYTE,
@@ -326,6 +374,23 @@ impl TestHarnessParams {
         }
     }
 
+    /// Builder method to set `screenshot_tolerance` and `screenshot_metric`.
+    pub const fn with_screenshot_tolerance(self, metric: PixelMetric, tolerance: u32) -> Self {
+        Self {
+            screenshot_metric: metric,
+            screenshot_tolerance: tolerance,
+            ..self
+        }
+    }
+
+    /// Builder method to set `max_differing_pixels`.
+    pub const fn with_max_differing_pixels(self, max_differing_pixels: u32) -> Self {
+        Self {
+            max_differing_pixels,
+            ..self
+        }
+    }
+
     /// Builder method to set `max_screenshot_size`.
     pub const fn with_max_screenshot_size(self, max_screenshot_size: u32) -> Self {
         Self {
@@ -406,15 +471,21 @@ impl<W: Widget> TestHarness<W> {
                     size: window_size,
                     scale_factor: params.scale_factor,
                     test_font: Some(data),
+                    layout_debugger: false,
                 },
             ),
             access_tree: accesskit_consumer::Tree::new(dummy_tree_update, false),
             renderer: None,
+            compositor: ImageCompositor::new(),
             mouse_state,
             window_size,
             background_color: params.background_color,
             root_padding: params.root_padding,
-            screenshot_tolerance: params.screenshot_tolerance,
+            screenshot_tolerance: ImageTolerance {
+                metric: params.screenshot_metric,
+                pixel_tolerance: params.screenshot_tolerance,
+                max_differing_pixels: params.max_differing_pixels,
+            },
             panic_on_rewrite_saturation: params.panic_on_rewrite_saturation,
             max_screenshot_size: params.max_screenshot_size,
             action_queue: VecDeque::new(),
@@ -525,6 +596,9 @@ impl<W: Widget> TestHarness<W> {
                 RenderRootSignal::RepositionLayer(root_id, new_pos) => {
                     self.render_root.reposition_layer(root_id, new_pos);
                 }
+                RenderRootSignal::AnchorLayer(root_id, anchor) => {
+                    self.render_root.anchor_layer(root_id, anchor);
+                }
             }
         }
     }
@@ -579,6 +653,89 @@ impl<W: Widget> TestHarness<W> {
         RgbaImage::from_vec(image.width, image.height, image.data).expect("failed to create image")
     }
 
+    /// Renders the window into an image the way a compositing host would.
+    ///
+    /// Unlike [`render`](Self::render), [isolated](masonry_core::core::PaintLayerMode::IsolatedScene)
+    /// layers are rendered separately and cached between calls, and
+    /// [external](masonry_core::core::PaintLayerMode::External) layers are filled with the images
+    /// passed to [`set_external_image`](Self::set_external_image).
+    ///
+    /// Also returns how many isolated layers were re-rendered or reused.
+    pub fn render_composited(&mut self) -> (RgbaImage, CompositeStats) {
+        let (visual_layers, _tree_update) = self.redraw();
+
+        let width = self.window_size.width.max(1) + self.root_padding * 2;
+        let height = self.window_size.height.max(1) + self.root_padding * 2;
+        let padding_transform =
+            Affine::translate((f64::from(self.root_padding), f64::from(self.root_padding)));
+
+        let layers: Vec<_> = visual_layers
+            .keyed_layers()
+            .map(|(layer, part)| {
+                let transform = padding_transform * layer.transform;
+                match &layer.kind {
+                    VisualLayerKind::Scene(scene) if layer.isolated => CompositeLayer::Isolated {
+                        key: LayerKey {
+                            id: layer.widget_id.to_raw(),
+                            part,
+                        },
+                        generation: layer.generation,
+                        layer: ImagingLayer { scene, transform },
+                        bounds: layer.bounds,
+                    },
+                    VisualLayerKind::Scene(scene) => {
+                        CompositeLayer::Scene(ImagingLayer { scene, transform })
+                    }
+                    VisualLayerKind::External { bounds } => CompositeLayer::External {
+                        id: layer.widget_id.to_raw(),
+                        bounds: *bounds,
+                        transform,
+                    },
+                }
+            })
+            .collect();
+        let frame = CompositeFrame::new(width, height, 1., self.background_color, &layers);
+
+        let renderer = self
+            .renderer
+            .get_or_insert_with(|| VelloCpuRenderer::new(1, 1));
+        let (image, stats) = self.compositor.render(renderer, frame).unwrap();
+        let image = RgbaImage::from_vec(image.width, image.height, image.data)
+            .expect("failed to create image");
+        (image, stats)
+    }
+
+    /// Renders the widget with the given id and its descendants into an image,
+    /// with `scale` pixels per logical pixel.
+    ///
+    /// See [`RenderRoot::render_widget_to_image`] for details.
+    pub fn render_widget(&mut self, id: WidgetId, scale: f64) -> RgbaImage {
+        let renderer = self
+            .renderer
+            .get_or_insert_with(|| VelloCpuRenderer::new(1, 1));
+        let image = self
+            .render_root
+            .render_widget_to_image(id, scale, renderer)
+            .unwrap();
+        RgbaImage::from_vec(image.width, image.height, image.data).expect("failed to create image")
+    }
+
+    /// Sets the image shown in the external layer of the given widget by
+    /// [`render_composited`](Self::render_composited).
+    ///
+    /// The image is stretched to cover the widget's border-box.
+    pub fn set_external_image(&mut self, widget_id: WidgetId, image: RgbaImage) {
+        let (width, height) = image.dimensions();
+        self.compositor.set_external_image(
+            widget_id.to_raw(),
+            masonry_core::imaging::RgbaImage {
+                width,
+                height,
+                data: image.into_raw(),
+            },
+        );
+    }
+
     /// Redraws the window.
     ///
     /// If you want to get a bitmap image of the contents, use [`Self::render`] instead.
@@ -665,6 +822,18 @@ impl<W: Widget> TestHarness<W> {
         }));
     }
 
+    /// Sends a pinch [`Gesture`](PointerEvent::Gesture) event to the window, at the mouse position.
+    ///
+    /// `delta` is the change of scale, as a fraction of the current scale:
+    /// `0.1` means "zoom in by 10%".
+    pub fn pinch(&mut self, delta: f32) {
+        self.process_pointer_event(PointerEvent::Gesture(PointerGestureEvent {
+            pointer: PRIMARY_MOUSE,
+            gesture: PointerGesture::Pinch(delta),
+            state: self.mouse_state.clone(),
+        }));
+    }
+
     /// Sends events that lead to a given widget being clicked.
     ///
     /// Combines [`mouse_move`](Self::mouse_move), [`mouse_button_press`](Self::mouse_button_press), and [`mouse_button_release`](Self::mouse_button_release).
@@ -849,6 +1018,48 @@ impl<W: Widget> TestHarness<W> {
         let _ = self.render_root.set_focus_fallback(id);
     }
 
+    /// Shows or hides the layout debugger overlay.
+    ///
+    /// See [`RenderRoot::set_layout_debugger`] for details.
+    pub fn set_layout_debugger(&mut self, enabled: bool) {
+        self.render_root.set_layout_debugger(enabled);
+        self.process_signals();
+    }
+
+    /// Returns the widget described by the layout debugger panel.
+    ///
+    /// See [`RenderRoot::layout_debugger_target`] for details.
+    pub fn layout_debugger_target(&self) -> Option<WidgetId> {
+        self.render_root.layout_debugger_target()
+    }
+
+    /// Applies a request from an out-of-process inspector and returns the response.
+    ///
+    /// See [`RenderRoot::handle_inspector_request`] for details.
+    #[cfg(feature = "inspector")]
+    pub fn inspector_request(
+        &mut self,
+        request: masonry_core::app::inspector::InspectorRequest,
+    ) -> masonry_core::app::inspector::InspectorResponse {
+        let response = self.render_root.handle_inspector_request(request);
+        self.process_signals();
+        response
+    }
+
+    /// Applies the requests received by `server` to the widget tree.
+    ///
+    /// Returns `true` if any request was handled.
+    /// See [`InspectorServer::poll`](masonry_core::app::inspector::InspectorServer::poll) for details.
+    #[cfg(all(feature = "inspector", not(target_arch = "wasm32")))]
+    pub fn poll_inspector_server(
+        &mut self,
+        server: &masonry_core::app::inspector::InspectorServer,
+    ) -> bool {
+        let handled = server.poll(&mut self.render_root);
+        self.process_signals();
+        handled
+    }
+
     /// Runs an animation pass on the widget tree.
     pub fn animate_ms(&mut self, ms: u64) {
         self.render_root
@@ -856,6 +1067,64 @@ impl<W: Widget> TestHarness<W> {
         self.process_signals();
     }
 
+    /// Replays the steps of an [interaction script](masonry_core::app::interaction_script).
+    ///
+    /// Before each step, animations are advanced by the time elapsed since the previous step,
+    /// using [`animate_ms`](Self::animate_ms).
+    /// `on_snapshot` is called with the name of each [`Snapshot`] step; the
+    /// [`assert_script_replay`] macro uses it to check a screenshot at each of these steps.
+    ///
+    /// Steps with keys whose name isn't recognized are skipped.
+    ///
+    /// [`Snapshot`]: ScriptEvent::Snapshot
+    /// [`assert_script_replay`]: crate::assert_script_replay
+    #[cfg(feature = "interaction_script")]
+    #[track_caller]
+    pub fn replay_script(
+        &mut self,
+        script: &InteractionScript,
+        mut on_snapshot: impl FnMut(&mut Self, &str),
+    ) {
+        let mut time_ms = 0;
+        for step in &script.steps {
+            if step.time_ms > time_ms {
+                self.animate_ms(step.time_ms - time_ms);
+                time_ms = step.time_ms;
+            }
+            match &step.event {
+                ScriptEvent::PointerMove { x, y } => self.mouse_move((*x, *y)),
+                ScriptEvent::PointerDown { button } => {
+                    self.mouse_button_press(button.map(Into::into));
+                }
+                ScriptEvent::PointerUp { button } => {
+                    self.mouse_button_release(button.map(Into::into));
+                }
+                ScriptEvent::PointerLeave => {
+                    self.process_pointer_event(PointerEvent::Leave(PRIMARY_MOUSE));
+                }
+                ScriptEvent::Scroll { x, y, unit } => {
+                    self.process_pointer_event(PointerEvent::Scroll(PointerScrollEvent {
+                        pointer: PRIMARY_MOUSE,
+                        delta: unit.delta(*x, *y),
+                        state: self.mouse_state.clone(),
+                    }));
+                }
+                ScriptEvent::Resize { width, height } => {
+                    self.window_size = PhysicalSize::new(*width, *height);
+                    self.process_window_event(WindowEvent::Resize(self.window_size));
+                }
+                ScriptEvent::Snapshot { name } => on_snapshot(self, name),
+                event => {
+                    if let Some(event) = event.to_text_event() {
+                        self.process_text_event(event);
+                    } else {
+                        debug!("Skipping script step {event:?}");
+                    }
+                }
+            }
+        }
+    }
+
     /// Helper method to directly enable/disable a widget.
     pub fn set_disabled(&mut self, widget: WidgetTag<impl Widget>, disabled: bool) {
         self.edit_widget(widget, |mut target| {
@@ -946,6 +1215,13 @@ impl<W: Widget> TestHarness<W> {
         self.render_root.focused_widget()
     }
 
+    /// Returns the widgets which pressing <kbd>Tab</kbd> moves the focus to, in order.
+    ///
+    /// See [`RenderRoot::focus_chain`] for details.
+    pub fn focus_chain(&self) -> Vec<WidgetId> {
+        self.render_root.focus_chain()
+    }
+
     /// Returns a [`WidgetRef`] to the widget which [captures pointer events](masonry_core::doc::masonry_concepts#pointer-capture).
     pub fn pointer_capture_target(&self) -> Option<WidgetRef<'_, dyn Widget>> {
         self.render_root
@@ -1069,6 +1345,11 @@ impl<W: Widget> TestHarness<W> {
         self.window_size
     }
 
+    /// Returns the color painted behind the widget tree.
+    pub(crate) fn background_color(&self) -> Color {
+        self.background_color
+    }
+
     /// Returns the title of the simulated window.
     pub fn title(&self) -> String {
         self.title.clone()
@@ -1149,16 +1430,21 @@ impl<W: Widget> TestHarness<W> {
         let screenshots_folder = PathBuf::from(manifest_dir).join("screenshots");
         std::fs::create_dir_all(&screenshots_folder).unwrap();
 
-        let reference_path = screenshots_folder.join(format!("{test_name}.png"));
+        let reference_path = reference_path(&screenshots_folder, test_name, SCREENSHOT_BACKEND);
         let new_path = screenshots_folder.join(format!("{test_name}.new.png"));
         let diff_path = screenshots_folder.join(format!("{test_name}.diff.png"));
+        let report_path = screenshots_folder.join(format!("{test_name}.report.html"));
+        let remove_vestigial_files = || {
+            let _ = std::fs::remove_file(&new_path);
+            let _ = std::fs::remove_file(&diff_path);
+            let _ = std::fs::remove_file(&report_path);
+        };
 
         let bless_test = std::env::var_os("MASONRY_TEST_BLESS").is_some_and(|it| !it.is_empty());
 
         let Ok(reference_file) = File::open(&reference_path) else {
             if bless_test && !expect_failure {
-                let _ = std::fs::remove_file(&new_path);
-                let _ = std::fs::remove_file(&diff_path);
+                remove_vestigial_files();
                 save_image(&new_image, &reference_path, max_size);
                 return;
             }
@@ -1175,9 +1461,11 @@ impl<W: Widget> TestHarness<W> {
 
         let ref_image = reference_file.decode().unwrap().to_rgb8();
 
+        let image_diff =
+            get_image_diff(&ref_image, &new_image.to_rgb8(), &self.screenshot_tolerance);
+
         if expect_failure {
-            if get_image_diff(&ref_image, &new_image.to_rgb8(), self.screenshot_tolerance).is_some()
-            {
+            if image_diff.is_some() {
                 return;
             } else {
                 panic!(
@@ -1186,23 +1474,32 @@ impl<W: Widget> TestHarness<W> {
             }
         }
 
-        if let Some(diff_image) =
-            get_image_diff(&ref_image, &new_image.to_rgb8(), self.screenshot_tolerance)
-        {
+        if let Some(image_diff) = image_diff {
             if bless_test {
-                let _ = std::fs::remove_file(&new_path);
-                let _ = std::fs::remove_file(&diff_path);
+                remove_vestigial_files();
                 save_image(&new_image, &reference_path, max_size);
             } else {
+                let summary = image_diff.summary(&self.screenshot_tolerance);
                 save_image(&new_image, &new_path, max_size);
                 // Don't fail if the diff file is too big!
-                save_image(&diff_image.into(), &diff_path, None);
-                panic!("Snapshot test '{test_name}' failed: Images are different");
+                save_image(&image_diff.image.into(), &diff_path, None);
+                let file_name =
+                    |path: &PathBuf| path.file_name().unwrap().to_string_lossy().into_owned();
+                let report = html_report(
+                    test_name,
+                    &summary,
+                    &file_name(&reference_path),
+                    &file_name(&new_path),
+                    &file_name(&diff_path),
+                );
+                std::fs::write(&report_path, report).unwrap();
+                panic!(
+                    "Snapshot test '{test_name}' failed: {summary}.\nSee {} for a comparison.",
+                    report_path.display()
+                );
             }
         } else {
-            // Remove the vestigial new and diff images
-            let _ = std::fs::remove_file(&new_path);
-            let _ = std::fs::remove_file(&diff_path);
+            remove_vestigial_files();
             if reference_size > u64::from(self.max_screenshot_size) {
                 panic!(
                     "Existing file ({reference_size}) was larger than the supported file size ({}).\
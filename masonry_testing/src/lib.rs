@@ -17,6 +17,9 @@
 //!
 //! - Simulate any external event which Masonry handles, including mouse movement, key presses, text input, accessibility events.
 //! - Control the flow of time to the application (i.e. for testing animations).
+//! - Replay scripts of input events recorded from a running app (with the `interaction_script` feature).
+//! - Audit the widget tree for common accessibility problems, such as unlabelled buttons or low-contrast text.
+//! - Fuzz random widget trees with random events and edits, checking the invariants of Masonry's passes (see [`WidgetFuzzer`]).
 //! - Take screenshots of the application, save these to a file, and ensure that these are up-to-date.
 //!   See [Screenshots](#screenshots) for more details.
 //!
@@ -33,6 +36,11 @@
 //! This renders the application being tested, then compares it against the png file with the given name
 //! from the `screenshots` folder (in the package being tested, i.e. adjacent to its `Cargo.toml` file).
 //!
+//! Screenshots match their reference if few enough pixels differ by more than a tolerance,
+//! measured either per color channel or perceptually; see [`TestHarnessParams`] for the settings.
+//! When a screenshot doesn't match, Masonry Testing writes the new screenshot, an image of the
+//! differing pixels, and an HTML report showing them side by side next to the reference.
+//!
 //! Masonry Testing will update the reference file when the `MASONRY_TEST_BLESS` environment variable has a value of `1`.
 //! This can be used if the file doesn't exist, or there's an expected difference.
 //! The screenshots are losslessly compressed (using [Oxipng][oxipng]) and limited to a small maximum file size (this
@@ -61,21 +69,26 @@
 //! - To test applications: see the tests in Masonry's examples.
 //! - To test widgets: see the `tests` module in each widget in Masonry.
 
+mod accessibility_audit;
 mod assert_any;
 mod assert_debug_panics;
 mod debug_name;
+mod fuzzing;
 mod harness;
 mod modular_widget;
 mod recorder_widget;
 mod screenshots;
 mod wrapper_widget;
 
+pub use accessibility_audit::{AccessibilityIssue, MIN_CONTRAST_RATIO};
 pub use assert_any::{assert_all, assert_any, assert_none};
 pub use assert_debug_panics::assert_debug_panics_inner;
 pub use debug_name::DebugName;
+pub use fuzzing::{FuzzInput, WidgetFuzzer};
 pub use harness::{PRIMARY_MOUSE, ROBOTO, TestHarness, TestHarnessParams};
 pub use modular_widget::ModularWidget;
 pub use recorder_widget::{Record, Recorder, Recording};
+pub use screenshots::PixelMetric;
 pub use wrapper_widget::WrapperWidget;
 
 use masonry_core::core::Widget;
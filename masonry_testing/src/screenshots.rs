@@ -3,7 +3,59 @@
 
 //! Helper functions for writing snapshot tests and comparing images.
 
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
 use image::{GenericImageView as _, Pixel as _, Rgb, RgbImage};
+use masonry_core::peniko::color::{Oklab, OpaqueColor, Srgb};
+
+/// The name of the renderer the test harness takes screenshots with, as used in the
+/// file names of backend-specific reference screenshots.
+pub(crate) const SCREENSHOT_BACKEND: &str = "vello_cpu";
+
+/// How the difference between a pixel of a screenshot and the same pixel of its
+/// reference is measured.
+///
+/// Pixels whose difference is above [`TestHarnessParams::screenshot_tolerance`] count as differing.
+///
+/// [`TestHarnessParams::screenshot_tolerance`]: crate::TestHarnessParams::screenshot_tolerance
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PixelMetric {
+    /// The largest difference between two color channels, from 0 to 255.
+    #[default]
+    Channel,
+    /// The Euclidean distance between both colors in the Oklab color space, multiplied by 100.
+    ///
+    /// This follows how different colors look rather than how they are stored: the
+    /// same channel difference counts for more in dark colors than in light ones.
+    /// Differences below 2 are hard to notice.
+    Perceptual,
+}
+
+impl PixelMetric {
+    fn distance(self, left: Rgb<u8>, right: Rgb<u8>) -> u32 {
+        match self {
+            Self::Channel => {
+                let (diff_min, diff_max) = pixel_min_max_distance(left, right);
+                u32::from(diff_min.max(diff_max))
+            }
+            Self::Perceptual => {
+                let oklab = |pixel: Rgb<u8>| {
+                    let [r, g, b] = pixel.0;
+                    OpaqueColor::<Srgb>::from_rgb8(r, g, b).convert::<Oklab>()
+                };
+                let distance = oklab(left).difference(oklab(right)) * 100.;
+                #[expect(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_sign_loss,
+                    reason = "Oklab distances between sRGB colors are small and positive"
+                )]
+                let distance = distance.ceil() as u32;
+                distance
+            }
+        }
+    }
+}
 
 // Copy-pasted from kompari
 fn pixel_min_max_distance(left: Rgb<u8>, right: Rgb<u8>) -> (u8, u8) {
@@ -19,49 +71,274 @@ fn pixel_min_max_distance(left: Rgb<u8>, right: Rgb<u8>) -> (u8, u8) {
         })
 }
 
-pub(crate) fn get_image_diff(
-    ref_image: &RgbImage,
-    new_image: &RgbImage,
-    max_screenshot_tolerance: u32,
-) -> Option<RgbImage> {
-    let ref_size = (ref_image.width(), ref_image.height());
-    let new_size = (new_image.width(), new_image.height());
-
-    let mut max_distance: u32 = 0;
-    for (p1, p2) in ref_image.pixels().zip(new_image.pixels()) {
-        let (diff_min, diff_max) = pixel_min_max_distance(*p1, *p2);
-        let new_max = std::cmp::max(diff_max, diff_min) as u32;
-        max_distance = std::cmp::max(max_distance, new_max);
-    }
+/// The criteria a screenshot must meet to match its reference.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ImageTolerance {
+    pub(crate) metric: PixelMetric,
+    /// The largest difference a pixel can have before it counts as differing.
+    pub(crate) pixel_tolerance: u32,
+    /// How many pixels can differ before the images count as different.
+    pub(crate) max_differing_pixels: u32,
+}
 
-    if max_distance <= max_screenshot_tolerance && ref_size == new_size {
-        return None;
+/// The differences between a screenshot and its reference.
+pub(crate) struct ImageDiff {
+    /// The pixels which differ, with all other pixels black.
+    pub(crate) image: RgbImage,
+    /// The number of pixels which differ.
+    ///
+    /// If the images have different sizes, pixels covered by only one of them count as differing.
+    pub(crate) differing_pixels: u64,
+    /// The largest difference between two pixels, in units of the [`PixelMetric`] used.
+    pub(crate) max_difference: u32,
+    /// Whether both images have the same size.
+    pub(crate) same_size: bool,
+}
+
+impl ImageDiff {
+    /// A short description of the differences, for error messages.
+    pub(crate) fn summary(&self, tolerance: &ImageTolerance) -> String {
+        if !self.same_size {
+            return "Images have different sizes".into();
+        }
+        format!(
+            "{} pixels differ (at most {} allowed), with a largest difference of {} ({:?} metric, tolerance {})",
+            self.differing_pixels,
+            tolerance.max_differing_pixels,
+            self.max_difference,
+            tolerance.metric,
+            tolerance.pixel_tolerance,
+        )
     }
+}
 
+/// Compares `new_image` against `ref_image`.
+///
+/// Returns `None` if the images match within `tolerance`.
+pub(crate) fn get_image_diff(
+    ref_image: &RgbImage,
+    new_image: &RgbImage,
+    tolerance: &ImageTolerance,
+) -> Option<ImageDiff> {
+    let same_size = ref_image.dimensions() == new_image.dimensions();
     let width = std::cmp::max(ref_image.width(), new_image.width());
     let height = std::cmp::max(ref_image.height(), new_image.height());
 
+    let mut differing_pixels = 0;
+    let mut max_difference = 0;
     let diff_image = RgbImage::from_fn(width, height, |x, y| {
-        let ref_pixel = if ref_image.in_bounds(x, y) {
-            *ref_image.get_pixel(x, y)
-        } else {
-            [0, 0, 0].into()
-        };
-        let new_pixel = if new_image.in_bounds(x, y) {
-            *new_image.get_pixel(x, y)
-        } else {
-            [255, 255, 255].into()
+        let (ref_pixel, new_pixel) = match (ref_image.in_bounds(x, y), new_image.in_bounds(x, y)) {
+            (true, true) => (*ref_image.get_pixel(x, y), *new_image.get_pixel(x, y)),
+            (true, false) => {
+                differing_pixels += 1;
+                return [0, 0, 0].into();
+            }
+            (false, true) => {
+                differing_pixels += 1;
+                return *new_image.get_pixel(x, y);
+            }
+            (false, false) => return [0, 0, 0].into(),
         };
 
-        let (diff_min, diff_max) = pixel_min_max_distance(ref_pixel, new_pixel);
-        let diff_abs = std::cmp::max(diff_min, diff_max);
-
-        if diff_abs as u32 > max_screenshot_tolerance {
+        let difference = tolerance.metric.distance(ref_pixel, new_pixel);
+        max_difference = max_difference.max(difference);
+        if difference > tolerance.pixel_tolerance {
+            differing_pixels += 1;
             new_pixel
         } else {
             [0, 0, 0].into()
         }
     });
 
-    Some(diff_image)
+    if same_size && differing_pixels <= u64::from(tolerance.max_differing_pixels) {
+        return None;
+    }
+
+    Some(ImageDiff {
+        image: diff_image,
+        differing_pixels,
+        max_difference,
+        same_size,
+    })
+}
+
+/// Returns the path of the reference screenshot for `test_name` in `folder`.
+///
+/// A reference specific to `backend`, named `<test_name>.<backend>.png`, takes precedence over
+/// the shared `<test_name>.png`, for the few cases where backends disagree on how to render
+/// something.
+pub(crate) fn reference_path(folder: &Path, test_name: &str, backend: &str) -> PathBuf {
+    let backend_path = folder.join(format!("{test_name}.{backend}.png"));
+    if backend_path.exists() {
+        backend_path
+    } else {
+        folder.join(format!("{test_name}.png"))
+    }
+}
+
+/// Returns an HTML page showing the reference, the new screenshot and their diff side by side.
+///
+/// The images are referenced by file name, so the page must be saved next to them.
+pub(crate) fn html_report(
+    test_name: &str,
+    summary: &str,
+    reference_file: &str,
+    new_file: &str,
+    diff_file: &str,
+) -> String {
+    let mut html = String::new();
+    let _ = write!(
+        html,
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Snapshot test '{test_name}'</title>
+<style>
+body {{ font-family: sans-serif; background: #202020; color: #e0e0e0; }}
+.images {{ display: flex; gap: 16px; align-items: flex-start; }}
+figure {{ margin: 0; }}
+img {{ image-rendering: pixelated; border: 1px solid #808080; }}
+</style>
+</head>
+<body>
+<h1>Snapshot test '{test_name}' failed</h1>
+<p>{summary}</p>
+<div class="images">
+"#,
+        test_name = escape_html(test_name),
+        summary = escape_html(summary),
+    );
+    for (caption, file) in [
+        ("Reference", reference_file),
+        ("New", new_file),
+        ("Diff", diff_file),
+    ] {
+        let _ = writeln!(
+            html,
+            r#"<figure><figcaption>{caption}</figcaption><a href="{file}"><img src="{file}"></a></figure>"#,
+            file = escape_html(file),
+        );
+    }
+    html.push_str("</div>\n</body>\n</html>\n");
+    html
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHANNEL: ImageTolerance = ImageTolerance {
+        metric: PixelMetric::Channel,
+        pixel_tolerance: 16,
+        max_differing_pixels: 0,
+    };
+
+    fn image_with_spots(spots: &[(u32, u32, [u8; 3])]) -> RgbImage {
+        let mut image = RgbImage::from_pixel(10, 10, [40, 40, 40].into());
+        for &(x, y, color) in spots {
+            image.put_pixel(x, y, color.into());
+        }
+        image
+    }
+
+    #[test]
+    fn small_differences_are_tolerated() {
+        let reference = image_with_spots(&[]);
+        let new = image_with_spots(&[(1, 1, [50, 40, 40])]);
+        assert!(get_image_diff(&reference, &new, &CHANNEL).is_none());
+
+        let new = image_with_spots(&[(1, 1, [90, 40, 40])]);
+        let diff = get_image_diff(&reference, &new, &CHANNEL).unwrap();
+        assert_eq!(diff.differing_pixels, 1);
+        assert_eq!(diff.max_difference, 50);
+        assert_eq!(*diff.image.get_pixel(1, 1), Rgb([90, 40, 40]));
+        assert_eq!(*diff.image.get_pixel(0, 0), Rgb([0, 0, 0]));
+    }
+
+    #[test]
+    fn differing_pixels_can_be_capped() {
+        let reference = image_with_spots(&[]);
+        let new = image_with_spots(&[(1, 1, [255, 0, 0]), (2, 2, [255, 0, 0])]);
+        let tolerance = ImageTolerance {
+            max_differing_pixels: 2,
+            ..CHANNEL
+        };
+        assert!(get_image_diff(&reference, &new, &tolerance).is_none());
+
+        let tolerance = ImageTolerance {
+            max_differing_pixels: 1,
+            ..CHANNEL
+        };
+        let diff = get_image_diff(&reference, &new, &tolerance).unwrap();
+        assert_eq!(diff.differing_pixels, 2);
+    }
+
+    #[test]
+    fn size_changes_always_fail() {
+        let reference = image_with_spots(&[]);
+        let new = RgbImage::from_pixel(10, 12, [40, 40, 40].into());
+        let tolerance = ImageTolerance {
+            max_differing_pixels: 1000,
+            ..CHANNEL
+        };
+        let diff = get_image_diff(&reference, &new, &tolerance).unwrap();
+        assert!(!diff.same_size);
+        assert_eq!(diff.differing_pixels, 20);
+    }
+
+    #[test]
+    fn perceptual_metric_weighs_dark_colors_more() {
+        let perceptual =
+            |a: [u8; 3], b: [u8; 3]| PixelMetric::Perceptual.distance(a.into(), b.into());
+        assert_eq!(perceptual([40, 40, 40], [40, 40, 40]), 0);
+        assert_eq!(perceptual([0, 0, 0], [255, 255, 255]), 100);
+        assert!(
+            perceptual([10, 10, 10], [30, 30, 30]) > perceptual([220, 220, 220], [240, 240, 240])
+        );
+    }
+
+    #[test]
+    fn backend_references_take_precedence() {
+        let folder =
+            std::env::temp_dir().join(format!("masonry-screenshots-{}", std::process::id()));
+        std::fs::create_dir_all(&folder).unwrap();
+        assert_eq!(
+            reference_path(&folder, "widget", "skia"),
+            folder.join("widget.png")
+        );
+        std::fs::write(folder.join("widget.skia.png"), []).unwrap();
+        assert_eq!(
+            reference_path(&folder, "widget", "skia"),
+            folder.join("widget.skia.png")
+        );
+        assert_eq!(
+            reference_path(&folder, "widget", SCREENSHOT_BACKEND),
+            folder.join("widget.png")
+        );
+        let _ = std::fs::remove_dir_all(&folder);
+    }
+
+    #[test]
+    fn report_shows_all_images() {
+        let report = html_report(
+            "<test>",
+            "2 pixels differ",
+            "test.png",
+            "test.new.png",
+            "test.diff.png",
+        );
+        assert!(report.contains("&lt;test&gt;"));
+        assert!(report.contains("2 pixels differ"));
+        for file in ["test.png", "test.new.png", "test.diff.png"] {
+            assert!(report.contains(&format!(r#"src="{file}""#)));
+        }
+    }
 }
@@ -172,4 +172,16 @@ impl DriverCtx<'_, '_> {
     pub fn exit(&mut self) {
         self.state.exit = true;
     }
+
+    /// Sets the texture shown in the external layer of the given widget.
+    ///
+    /// See [`MasonryState::set_external_texture`] for details.
+    pub fn set_external_texture(&mut self, widget_id: WidgetId, view: wgpu::TextureView) {
+        self.state.set_external_texture(widget_id, view);
+    }
+
+    /// Removes the texture shown in the external layer of the given widget.
+    pub fn remove_external_texture(&mut self, widget_id: WidgetId) {
+        self.state.remove_external_texture(widget_id);
+    }
 }
@@ -10,14 +10,17 @@ use accesskit_winit::Adapter;
 use copypasta::nop_clipboard::NopClipboardContext;
 use copypasta::{ClipboardContext, ClipboardProvider};
 use masonry_core::app::{
-    RenderRoot, RenderRootOptions, RenderRootSignal, VisualLayerKind, WindowSizePolicy,
+    RenderRoot, RenderRootOptions, RenderRootSignal, VisualLayerKind, VisualLayerPlan,
+    WindowSizePolicy,
 };
 use masonry_core::core::keyboard::{Key, KeyState};
 use masonry_core::core::{
-    DefaultProperties, ErasedAction, NewWidget, TextEvent, Widget, WindowEvent,
+    DefaultProperties, ErasedAction, NewWidget, TextEvent, Widget, WidgetId, WindowEvent,
 };
+use masonry_core::kurbo::Rect;
 use masonry_core::peniko::Color;
 use masonry_core::util::Instant;
+use masonry_imaging::composite::{CompositeFrame, CompositeLayer, LayerKey};
 use masonry_imaging::texture_render::{
     RenderTarget as ImagingRenderTarget, Renderer as ImagingRenderer,
 };
@@ -36,13 +39,20 @@ use crate::app::{
     winit_ime_to_masonry,
 };
 use crate::app_driver::WindowId;
-use crate::vello_util::{RenderContext, RenderSurface};
+#[cfg(feature = "interaction_script")]
+use crate::script_recording::ScriptRecording;
+#[cfg(feature = "software")]
+use crate::software::{SoftwareRenderer, SoftwareSurface};
+use crate::vello_util::{RenderContext, RenderSurface, RenderSurfaceError};
 
 /// The custom event type that we inject into winit's [`EventLoop`](winit::event_loop::EventLoop).
 ///
 /// This represents the types that can be emitted during the event loop, but aren't emitted
 /// by winit.
+///
+/// This enum is non-exhaustive, as some variants only exist with some features enabled.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum MasonryUserEvent {
     /// An accessibility API emitted an event.
     ///
@@ -52,6 +62,11 @@ pub enum MasonryUserEvent {
     ///
     /// Higher-level GUI frameworks may send these to winit from background threads to wake up the event loop.
     AsyncAction(WindowId, ErasedAction),
+    /// An out-of-process inspector sent requests.
+    ///
+    /// See [`masonry_core::app::inspector`].
+    #[cfg(feature = "inspector")]
+    Inspector,
 }
 
 impl From<accesskit_winit::Event> for MasonryUserEvent {
@@ -164,6 +179,7 @@ impl Window {
                     size,
                     scale_factor,
                     test_font: None,
+                    layout_debugger: false,
                 },
             ),
             base_color,
@@ -218,7 +234,16 @@ pub struct MasonryState<'a> {
 
     window_id_to_handle_id: HashMap<WindowId, HandleId>,
 
-    surfaces: HashMap<HandleId, RenderSurface<'a>>,
+    surfaces: HashMap<HandleId, WindowSurface<'a>>,
+    /// Renders windows on the CPU.
+    #[cfg(feature = "software")]
+    software_renderer: SoftwareRenderer,
+    /// Whether new windows are rendered on the CPU.
+    ///
+    /// This is set if the `MASONRY_RENDERER` environment variable is `software`,
+    /// or once no compatible GPU could be found.
+    #[cfg(feature = "software")]
+    use_software: bool,
     windows: HashMap<HandleId, Window>,
     /// On Metal, we need to track the state of resize requests to avoid jitter.
     #[cfg(target_os = "macos")]
@@ -236,6 +261,12 @@ pub struct MasonryState<'a> {
     /// Windows that are scheduled to be created in the next resumed event.
     new_windows: Vec<NewWindow>,
     need_first_frame: Vec<HandleId>,
+    /// Server for out-of-process inspectors, enabled by the `MASONRY_INSPECTOR` environment variable.
+    #[cfg(feature = "inspector")]
+    inspector: Option<masonry_core::app::inspector::InspectorServer>,
+    /// Script of input events, recorded if the `MASONRY_RECORD_SCRIPT` environment variable is set.
+    #[cfg(feature = "interaction_script")]
+    script_recording: Option<ScriptRecording>,
 }
 
 // TODO - Merge into MasonryState?
@@ -256,6 +287,46 @@ pub type EventLoopBuilder = winit::event_loop::EventLoopBuilder<MasonryUserEvent
 /// A proxy used to send events to the event loop
 pub type EventLoopProxy = winit::event_loop::EventLoopProxy<MasonryUserEvent>;
 
+/// Starts the inspector server described by the `MASONRY_INSPECTOR` environment variable.
+///
+/// The variable is either `stdio`, or the TCP port to listen on, e.g. `9339`.
+/// The server only accepts connections from the same machine.
+#[cfg(feature = "inspector")]
+fn start_inspector_server(
+    event_loop_proxy: EventLoopProxy,
+) -> Option<masonry_core::app::inspector::InspectorServer> {
+    use masonry_core::app::inspector::InspectorServer;
+
+    let addr = std::env::var("MASONRY_INSPECTOR")
+        .ok()
+        .filter(|addr| !addr.is_empty())?;
+    let proxy = std::sync::Mutex::new(event_loop_proxy);
+    let wake = move || {
+        // The event loop may have exited already, in which case there's nothing to inspect.
+        let _ = proxy
+            .lock()
+            .unwrap()
+            .send_event(MasonryUserEvent::Inspector);
+    };
+    if addr == "stdio" {
+        return Some(InspectorServer::stdio(wake));
+    }
+    let Ok(port) = addr.parse() else {
+        tracing::error!("MASONRY_INSPECTOR should be `stdio` or a port number, got {addr:?}");
+        return None;
+    };
+    match InspectorServer::bind_tcp(port, wake) {
+        Ok(server) => {
+            info!("inspector listening on 127.0.0.1:{port}");
+            Some(server)
+        }
+        Err(err) => {
+            tracing::error!("failed to start inspector on port {port}: {err}");
+            None
+        }
+    }
+}
+
 // --- MARK: RUN
 
 /// Runs the app to completion.
@@ -381,7 +452,7 @@ impl<'a> Debug for MasonryState<'a> {
     }
 }
 
-impl MasonryState<'_> {
+impl<'a> MasonryState<'a> {
     /// Creates the Masonry application's composition root.
     ///
     /// - `event_loop_proxy`: a queue provided by [`EventLoop::create_proxy`](winit::event_loop::EventLoop::create_proxy) to send custom events (mostly accessibility) to your event loop.
@@ -392,10 +463,17 @@ impl MasonryState<'_> {
         new_windows: Vec<NewWindow>,
         default_properties: DefaultProperties,
     ) -> Self {
-        tracing::debug!(
-            backend = ImagingRenderer::BACKEND_NAME,
-            "selected Masonry Winit render backend"
-        );
+        #[cfg(feature = "software")]
+        let use_software = crate::software::requested_by_env();
+        #[cfg(feature = "software")]
+        let backend = if use_software {
+            SoftwareRenderer::BACKEND_NAME
+        } else {
+            ImagingRenderer::BACKEND_NAME
+        };
+        #[cfg(not(feature = "software"))]
+        let backend = ImagingRenderer::BACKEND_NAME;
+        tracing::debug!(backend, "selected Masonry Winit render backend");
 
         let render_cx = RenderContext::new();
 
@@ -411,6 +489,9 @@ impl MasonryState<'_> {
             clipboard_cx.unwrap()
         };
 
+        #[cfg(feature = "inspector")]
+        let inspector = start_inspector_server(event_loop_proxy.clone());
+
         MasonryState {
             is_suspended: true,
             render_cx,
@@ -424,6 +505,10 @@ impl MasonryState<'_> {
             window_id_to_handle_id: HashMap::new(),
             windows: HashMap::new(),
             surfaces: HashMap::new(),
+            #[cfg(feature = "software")]
+            software_renderer: SoftwareRenderer::new(),
+            #[cfg(feature = "software")]
+            use_software,
             #[cfg(target_os = "macos")]
             resized_window: None,
 
@@ -434,6 +519,10 @@ impl MasonryState<'_> {
             exit: false,
             new_windows,
             need_first_frame: Vec::new(),
+            #[cfg(feature = "inspector")]
+            inspector,
+            #[cfg(feature = "interaction_script")]
+            script_recording: ScriptRecording::from_env(),
         }
     }
 
@@ -583,6 +672,12 @@ impl MasonryState<'_> {
         tracing::debug!(window_id = window.id.trace(), handle=?handle_id, "creating window");
         self.window_id_to_handle_id.insert(window.id, handle_id);
         self.windows.insert(handle_id, window);
+        // Inspector clients can ask for the timings of the last frame at any time.
+        #[cfg(feature = "inspector")]
+        if self.inspector.is_some() {
+            let window = self.windows.get_mut(&handle_id).unwrap();
+            window.render_root.set_frame_timing(true);
+        }
     }
 
     pub(crate) fn close_window(&mut self, window_id: WindowId) {
@@ -613,38 +708,27 @@ impl MasonryState<'_> {
         }
 
         // Get the existing surface or create a new one
-        let surface = if let Some(surface) = self.surfaces.get_mut(&handle_id) {
-            #[cfg(target_os = "macos")]
-            if self.resized_window == Some(handle_id) {
-                self.render_cx.on_window_resize_state_change(surface, true);
-            }
+        if let Some(surface) = self.surfaces.get_mut(&handle_id) {
+            if let Some(surface) = surface.gpu_mut() {
+                #[cfg(target_os = "macos")]
+                if self.resized_window == Some(handle_id) {
+                    self.render_cx.on_window_resize_state_change(surface, true);
+                }
 
-            // The window might have been resized, make sure the surface dimensions match.
-            if surface.config.width != size.width || surface.config.height != size.height {
-                self.render_cx
-                    .resize_surface(surface, size.width, size.height);
+                // The window might have been resized, make sure the surface dimensions match.
+                if surface.config.width != size.width || surface.config.height != size.height {
+                    self.render_cx
+                        .resize_surface(surface, size.width, size.height);
+                }
             }
-            surface
         } else {
-            let devices_before = self.render_cx.devices.len();
-            let surface = create_surface(&mut self.render_cx, window.handle.clone(), size);
-            let dev_id = surface.dev_id;
+            let Some(surface) = self.create_surface(handle_id, size, app_driver) else {
+                return;
+            };
             self.surfaces.insert(handle_id, surface);
-            let surface = self.surfaces.get_mut(&handle_id).unwrap();
-
-            if self.render_cx.devices.len() != devices_before {
-                let device_handle = &self.render_cx.devices[dev_id];
-                let wgpu = WgpuContext {
-                    instance: &self.render_cx.instance,
-                    adapter: &device_handle.adapter,
-                    device: &device_handle.device,
-                    queue: &device_handle.queue,
-                };
-                app_driver.on_wgpu_ready(&wgpu);
-            }
-
-            surface
-        };
+        }
+        let window = self.windows.get_mut(&handle_id).unwrap();
+        let surface = self.surfaces.get_mut(&handle_id).unwrap();
 
         let now = Instant::now();
         // TODO: this calculation uses wall-clock time of the paint call, which
@@ -664,39 +748,64 @@ impl MasonryState<'_> {
         self.last_anim = animation_continues.then_some(now);
 
         #[cfg(target_os = "macos")]
-        if self.resized_window == Some(handle_id) {
+        if self.resized_window == Some(handle_id)
+            && let Some(surface) = surface.gpu_mut()
+        {
             self.render_cx.on_window_resize_state_change(surface, true);
         }
 
         let (visual_layers, tree_update) = window.render_root.redraw();
-        let overlays: Vec<_> = visual_layers
-            .overlay_layers()
-            .map(|layer| {
-                let VisualLayerKind::Scene(scene) = &layer.kind else {
-                    unreachable!("overlay_layers only returns scene layers");
-                };
-                ImagingLayer {
-                    scene,
-                    transform: layer.transform,
-                }
-            })
-            .collect();
         let size = window.render_root.size();
-        let root_layer = visual_layers
-            .root_layer()
-            .expect("paint should always produce a root layer");
-        let VisualLayerKind::Scene(root_scene) = &root_layer.kind else {
-            unreachable!("root_layer always returns a scene layer");
+        let scale_factor = window.handle.scale_factor();
+        let overlays: Vec<_>;
+        let composite_layers: Vec<_>;
+        let frame = if visual_layers.has_composited_layers() {
+            composite_layers = composite_layers_of(&visual_layers);
+            RenderFrame::Composited(CompositeFrame::new(
+                size.width,
+                size.height,
+                scale_factor,
+                window.base_color,
+                &composite_layers,
+            ))
+        } else {
+            overlays = visual_layers
+                .overlay_layers()
+                .map(|layer| {
+                    let VisualLayerKind::Scene(scene) = &layer.kind else {
+                        unreachable!("overlay_layers only returns scene layers");
+                    };
+                    ImagingLayer {
+                        scene,
+                        transform: layer.transform,
+                    }
+                })
+                .collect();
+            let root_layer = visual_layers
+                .root_layer()
+                .expect("paint should always produce a root layer");
+            let VisualLayerKind::Scene(root_scene) = &root_layer.kind else {
+                unreachable!("root_layer always returns a scene layer");
+            };
+            RenderFrame::Flattened(
+                PreparedFrame::new(
+                    size.width,
+                    size.height,
+                    scale_factor,
+                    window.base_color,
+                    root_scene,
+                    &overlays,
+                ),
+                visual_layers.damage,
+            )
         };
-        let frame = PreparedFrame::new(
-            size.width,
-            size.height,
-            window.handle.scale_factor(),
-            window.base_color,
-            root_scene,
-            &overlays,
-        );
-        Self::render(surface, window, frame, &self.render_cx, &mut self.renderer);
+        match surface {
+            WindowSurface::Gpu(surface) => {
+                Self::render(surface, window, frame, &self.render_cx, &mut self.renderer);
+            }
+            #[cfg(feature = "software")]
+            WindowSurface::Software(surface) => self.software_renderer.render(surface, frame),
+        }
         #[cfg(feature = "tracy")]
         drop(self.frame.take());
         if let Some(tree_update) = tree_update {
@@ -704,11 +813,64 @@ impl MasonryState<'_> {
         }
     }
 
+    /// Creates the surface of a window, rendering on the CPU if no compatible GPU is found.
+    ///
+    /// Returns `None` and requests an exit if the window can't be presented at all.
+    fn create_surface(
+        &mut self,
+        handle_id: HandleId,
+        size: PhysicalSize<u32>,
+        app_driver: &mut dyn AppDriver,
+    ) -> Option<WindowSurface<'a>> {
+        let handle = self.windows[&handle_id].handle.clone();
+        #[cfg(feature = "software")]
+        if self.use_software {
+            return self.create_software_surface(handle);
+        }
+
+        let devices_before = self.render_cx.devices.len();
+        let surface = match create_gpu_surface(&mut self.render_cx, handle.clone(), size) {
+            Ok(surface) => surface,
+            #[cfg(feature = "software")]
+            Err(err) => {
+                tracing::warn!("falling back to software rendering: {err}");
+                self.use_software = true;
+                return self.create_software_surface(handle);
+            }
+            #[cfg(not(feature = "software"))]
+            Err(err) => panic!("failed to create a surface: {err}"),
+        };
+
+        if self.render_cx.devices.len() != devices_before {
+            let device_handle = &self.render_cx.devices[surface.dev_id];
+            let wgpu = WgpuContext {
+                instance: &self.render_cx.instance,
+                adapter: &device_handle.adapter,
+                device: &device_handle.device,
+                queue: &device_handle.queue,
+            };
+            app_driver.on_wgpu_ready(&wgpu);
+        }
+        Some(WindowSurface::Gpu(surface))
+    }
+
+    #[cfg(feature = "software")]
+    fn create_software_surface(&mut self, handle: Arc<WindowHandle>) -> Option<WindowSurface<'a>> {
+        match SoftwareSurface::new(handle) {
+            Ok(surface) => Some(WindowSurface::Software(surface)),
+            Err(err) => {
+                tracing::error!("Couldn't create a software surface, exiting: {err}");
+                self.exit = true;
+                None
+            }
+        }
+    }
+
     // --- MARK: RENDER
     fn render(
         surface: &mut RenderSurface<'_>,
         window: &mut Window,
-        frame: PreparedFrame<'_>,
+        frame: RenderFrame<'_>,
         render_cx: &RenderContext,
         renderer: &mut ImagingRenderer,
     ) {
@@ -725,16 +887,32 @@ impl MasonryState<'_> {
             backend = ImagingRenderer::BACKEND_NAME
         )
         .entered();
-        if let Err(err) = renderer.render_to_texture(
-            ImagingRenderTarget {
-                adapter: &render_cx.devices[dev_id].adapter,
-                device,
-                queue,
-                texture: &surface.target_texture,
-                view: &surface.target_view,
-            },
-            frame,
-        ) {
+        let target = ImagingRenderTarget {
+            adapter: &render_cx.devices[dev_id].adapter,
+            device,
+            queue,
+            texture: &surface.target_texture,
+            view: &surface.target_view,
+        };
+        let (background_color, result) = match frame {
+            // If the target still holds the previous frame, only the damaged area is rendered.
+            RenderFrame::Flattened(frame, Some(damage))
+                if surface.rendered_base_color == Some(frame.background_color) =>
+            {
+                let result = renderer.render_damage_to_texture(target, frame, damage);
+                (frame.background_color, result)
+            }
+            RenderFrame::Flattened(frame, _) => (
+                frame.background_color,
+                renderer.render_to_texture(target, frame),
+            ),
+            RenderFrame::Composited(frame) => (
+                frame.background_color,
+                renderer.render_composited(target, frame).map(|_| ()),
+            ),
+        };
+        surface.rendered_base_color = result.is_ok().then_some(background_color);
+        if let Err(err) = result {
             tracing::error!(
                 backend = ImagingRenderer::BACKEND_NAME,
                 "Couldn't render Masonry content into target texture: {err}"
@@ -861,16 +1039,29 @@ impl MasonryState<'_> {
                         && action_mod
                         && k.state == KeyState::Down
                     {
-                        window
-                            .render_root
-                            .handle_text_event(TextEvent::ClipboardPaste(
-                                self.clipboard_cx.get_contents().unwrap(),
-                            ));
+                        let event =
+                            TextEvent::ClipboardPaste(self.clipboard_cx.get_contents().unwrap());
+                        #[cfg(feature = "interaction_script")]
+                        if let Some(recording) = &mut self.script_recording {
+                            recording.record_text_event(&event);
+                        }
+                        window.render_root.handle_text_event(event);
                     } else {
+                        #[cfg(feature = "interaction_script")]
+                        if let Some(recording) = &mut self.script_recording
+                            && !recording.record_key(&k)
+                        {
+                            // The key marked a snapshot point, and isn't meant for the app.
+                            return;
+                        }
                         window.render_root.handle_text_event(TextEvent::Keyboard(k));
                     }
                 }
                 WindowEventTranslation::Pointer(p) => {
+                    #[cfg(feature = "interaction_script")]
+                    if let Some(recording) = &mut self.script_recording {
+                        recording.record_pointer_event(&p);
+                    }
                     window.render_root.handle_pointer_event(p);
                 }
             }
@@ -888,6 +1079,7 @@ impl MasonryState<'_> {
                     .resized_window
                     .take()
                     .and_then(|id| self.surfaces.get_mut(&id))
+                    .and_then(WindowSurface::gpu_mut)
                 {
                     self.render_cx.on_window_resize_state_change(surface, false);
                 }
@@ -910,23 +1102,38 @@ impl MasonryState<'_> {
                 #[cfg(target_os = "macos")]
                 {
                     self.resized_window = Some(handle_id);
-                    if let Some(surface) = self.surfaces.get_mut(&handle_id) {
+                    if let Some(surface) = self
+                        .surfaces
+                        .get_mut(&handle_id)
+                        .and_then(WindowSurface::gpu_mut)
+                    {
                         self.render_cx.on_window_resize_state_change(surface, true);
                     }
                 }
 
+                #[cfg(feature = "interaction_script")]
+                if let Some(recording) = &mut self.script_recording {
+                    recording.record_resize(size);
+                }
                 window
                     .render_root
                     .handle_window_event(WindowEvent::Resize(size));
             }
             WinitWindowEvent::Ime(ime) => {
-                let ime = winit_ime_to_masonry(ime);
-                window.render_root.handle_text_event(TextEvent::Ime(ime));
+                let event = TextEvent::Ime(winit_ime_to_masonry(ime));
+                #[cfg(feature = "interaction_script")]
+                if let Some(recording) = &mut self.script_recording {
+                    recording.record_text_event(&event);
+                }
+                window.render_root.handle_text_event(event);
             }
             WinitWindowEvent::Focused(new_focus) => {
-                window
-                    .render_root
-                    .handle_text_event(TextEvent::WindowFocusChange(new_focus));
+                let event = TextEvent::WindowFocusChange(new_focus);
+                #[cfg(feature = "interaction_script")]
+                if let Some(recording) = &mut self.script_recording {
+                    recording.record_text_event(&event);
+                }
+                window.render_root.handle_text_event(event);
             }
             _ => (),
         }
@@ -956,6 +1163,17 @@ impl MasonryState<'_> {
         event: MasonryUserEvent,
         app_driver: &mut dyn AppDriver,
     ) {
+        #[cfg(feature = "inspector")]
+        if let MasonryUserEvent::Inspector = event {
+            // The inspector is attached to the oldest open window.
+            let window = self.windows.values_mut().min_by_key(|window| window.id.0);
+            if let (Some(inspector), Some(window)) = (&self.inspector, window) {
+                inspector.poll(&mut window.render_root);
+            }
+            self.handle_signals(event_loop, app_driver);
+            return;
+        }
+
         let window = match &event {
             MasonryUserEvent::AccessKit(handle_id, ..) => {
                 let Some(state) = self.windows.get_mut(handle_id) else {
@@ -971,6 +1189,8 @@ impl MasonryState<'_> {
                 };
                 self.windows.get_mut(window_id).unwrap()
             }
+            #[cfg(feature = "inspector")]
+            MasonryUserEvent::Inspector => unreachable!(),
         };
         match event {
             MasonryUserEvent::AccessKit(_, event) => {
@@ -999,6 +1219,8 @@ impl MasonryState<'_> {
                     action,
                 );
             }
+            #[cfg(feature = "inspector")]
+            MasonryUserEvent::Inspector => unreachable!(),
         }
 
         self.handle_signals(event_loop, app_driver);
@@ -1012,7 +1234,12 @@ impl MasonryState<'_> {
     pub fn handle_new_events(&mut self, _: &ActiveEventLoop, _: winit::event::StartCause) {}
 
     /// Delegate method for [`ApplicationHandler::exiting()`].
-    pub fn handle_exiting(&mut self, _: &ActiveEventLoop) {}
+    pub fn handle_exiting(&mut self, _: &ActiveEventLoop) {
+        #[cfg(feature = "interaction_script")]
+        if let Some(recording) = &self.script_recording {
+            recording.save();
+        }
+    }
 
     /// Delegate method for [`ApplicationHandler::memory_warning()`].
     pub fn handle_memory_warning(&mut self, _: &ActiveEventLoop) {}
@@ -1126,6 +1353,9 @@ impl MasonryState<'_> {
                 RenderRootSignal::RepositionLayer(root_id, new_pos) => {
                     window.render_root.reposition_layer(root_id, new_pos);
                 }
+                RenderRootSignal::AnchorLayer(root_id, anchor) => {
+                    window.render_root.anchor_layer(root_id, anchor);
+                }
             }
         }
 
@@ -1180,18 +1410,115 @@ impl MasonryState<'_> {
     /// Sets how frames are presented to the user.
     ///
     /// This affects what users commonly know as "VSync".
+    /// It has no effect on windows rendered in software.
     pub fn set_present_mode(&mut self, window_id: WindowId, present_mode: wgpu::PresentMode) {
         let handle_id = self.handle_id(window_id);
-        let surface = self.surfaces.get_mut(&handle_id).unwrap();
-        self.render_cx.set_present_mode(surface, present_mode);
+        if let Some(surface) = self.surfaces.get_mut(&handle_id).unwrap().gpu_mut() {
+            self.render_cx.set_present_mode(surface, present_mode);
+        }
+    }
+
+    /// Sets the texture shown in the [external](masonry_core::core::PaintLayerMode::External)
+    /// layer of the given widget, and redraws all windows.
+    ///
+    /// The texture is stretched to cover the widget's border-box, and is expected to hold
+    /// unpremultiplied colors in a filterable float format.
+    /// It must be created with the device passed to [`AppDriver::on_wgpu_ready`].
+    ///
+    /// Call this again after writing new content into the texture to present it.
+    /// Windows rendered in software leave external layers empty.
+    pub fn set_external_texture(&mut self, widget_id: WidgetId, view: wgpu::TextureView) {
+        self.renderer.set_external_texture(widget_id.to_raw(), view);
+        self.request_redraw_all();
+    }
+
+    /// Removes the texture shown in the external layer of the given widget,
+    /// and redraws all windows.
+    pub fn remove_external_texture(&mut self, widget_id: WidgetId) {
+        self.renderer.remove_external_texture(widget_id.to_raw());
+        self.request_redraw_all();
+    }
+
+    fn request_redraw_all(&self) {
+        for window in self.windows.values() {
+            window.handle.request_redraw();
+        }
+    }
+}
+
+/// A frame of a window, in the form its renderer needs.
+pub(crate) enum RenderFrame<'a> {
+    /// A frame without isolated or external layers, and the area which changed since
+    /// the previous frame.
+    Flattened(PreparedFrame<'a>, Option<Rect>),
+    /// A frame which needs its layers composited.
+    Composited(CompositeFrame<'a>),
+}
+
+/// Converts the layers of `plan` for compositing.
+///
+/// Layers are keyed by the raw id of the widget which owns them.
+fn composite_layers_of(plan: &VisualLayerPlan) -> Vec<CompositeLayer<'_>> {
+    plan.keyed_layers()
+        .map(|(layer, part)| match &layer.kind {
+            VisualLayerKind::Scene(scene) => {
+                let layer_content = ImagingLayer {
+                    scene,
+                    transform: layer.transform,
+                };
+                if layer.isolated {
+                    CompositeLayer::Isolated {
+                        key: LayerKey {
+                            id: layer.widget_id.to_raw(),
+                            part,
+                        },
+                        generation: layer.generation,
+                        layer: layer_content,
+                        bounds: layer.bounds,
+                    }
+                } else {
+                    CompositeLayer::Scene(layer_content)
+                }
+            }
+            VisualLayerKind::External { bounds } => CompositeLayer::External {
+                id: layer.widget_id.to_raw(),
+                bounds: *bounds,
+                transform: layer.transform,
+            },
+        })
+        .collect()
+}
+
+/// The surface a window is presented with.
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "software",
+    expect(
+        clippy::large_enum_variant,
+        reason = "There is only one surface per window"
+    )
+)]
+enum WindowSurface<'s> {
+    Gpu(RenderSurface<'s>),
+    #[cfg(feature = "software")]
+    Software(SoftwareSurface),
+}
+
+impl<'s> WindowSurface<'s> {
+    fn gpu_mut(&mut self) -> Option<&mut RenderSurface<'s>> {
+        match self {
+            Self::Gpu(surface) => Some(surface),
+            #[cfg(feature = "software")]
+            Self::Software(_) => None,
+        }
     }
 }
 
-fn create_surface<'s>(
+fn create_gpu_surface<'s>(
     render_cx: &mut RenderContext,
     handle: Arc<WindowHandle>,
     size: PhysicalSize<u32>,
-) -> RenderSurface<'s> {
+) -> Result<RenderSurface<'s>, RenderSurfaceError> {
     assert!(
         size.width != 0 && size.height != 0,
         "cannot create a surface with a width or height of zero"
@@ -1202,5 +1529,4 @@ fn create_surface<'s>(
         size.height,
         wgpu::PresentMode::AutoVsync,
     ))
-    .unwrap()
 }
@@ -91,6 +91,10 @@ use vello as _;
 mod app_driver;
 mod convert_winit_event;
 mod event_loop_runner;
+#[cfg(feature = "interaction_script")]
+mod script_recording;
+#[cfg(feature = "software")]
+mod software;
 mod vello_util;
 
 pub use winit;
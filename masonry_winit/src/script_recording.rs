@@ -0,0 +1,85 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Recording of input events into an [interaction script](masonry_core::app::interaction_script).
+//!
+//! Recording is enabled by setting the `MASONRY_RECORD_SCRIPT` environment variable to the path
+//! of the file to write the script to. The script is written when the app exits, and each time
+//! a snapshot point is marked with the F9 key.
+//!
+//! Events from all windows are recorded into the same script, so recordings are meant for
+//! apps with a single window.
+
+use std::path::PathBuf;
+
+use masonry_core::app::interaction_script::ScriptRecorder;
+use masonry_core::core::keyboard::{Key, KeyState, NamedKey};
+use masonry_core::core::{KeyboardEvent, PointerEvent, TextEvent};
+use winit::dpi::PhysicalSize;
+
+/// The environment variable holding the path of the recorded script.
+const RECORD_ENV_VAR: &str = "MASONRY_RECORD_SCRIPT";
+
+/// The key which marks a snapshot point in the script.
+const SNAPSHOT_KEY: Key = Key::Named(NamedKey::F9);
+
+/// An interaction script being recorded to a file.
+#[derive(Debug)]
+pub(crate) struct ScriptRecording {
+    recorder: ScriptRecorder,
+    path: PathBuf,
+}
+
+impl ScriptRecording {
+    /// Starts recording if requested through [`RECORD_ENV_VAR`].
+    pub(crate) fn from_env() -> Option<Self> {
+        let path = std::env::var_os(RECORD_ENV_VAR).filter(|path| !path.is_empty())?;
+        let path = PathBuf::from(path);
+        tracing::info!(
+            "Recording interaction script to {}, press F9 to mark a snapshot",
+            path.display()
+        );
+        Some(Self {
+            recorder: ScriptRecorder::new(),
+            path,
+        })
+    }
+
+    /// Records a keyboard event, or marks a snapshot if it's a press of the snapshot key.
+    ///
+    /// Returns `false` if the event was used to mark a snapshot, and shouldn't reach the app.
+    pub(crate) fn record_key(&mut self, event: &KeyboardEvent) -> bool {
+        if event.key != SNAPSHOT_KEY {
+            self.recorder
+                .record_text_event(&TextEvent::Keyboard(event.clone()));
+            return true;
+        }
+        if event.state == KeyState::Down && !event.repeat {
+            self.recorder.mark_snapshot();
+            self.save();
+        }
+        false
+    }
+
+    pub(crate) fn record_pointer_event(&mut self, event: &PointerEvent) {
+        self.recorder.record_pointer_event(event);
+    }
+
+    pub(crate) fn record_text_event(&mut self, event: &TextEvent) {
+        self.recorder.record_text_event(event);
+    }
+
+    pub(crate) fn record_resize(&mut self, size: PhysicalSize<u32>) {
+        self.recorder.record_resize(size);
+    }
+
+    /// Writes the script recorded so far to its file.
+    pub(crate) fn save(&self) {
+        if let Err(err) = std::fs::write(&self.path, self.recorder.script().to_json()) {
+            tracing::error!(
+                "Couldn't write interaction script to {}: {err}",
+                self.path.display()
+            );
+        }
+    }
+}
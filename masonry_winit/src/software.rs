@@ -0,0 +1,154 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Rendering on the CPU, for systems without a usable GPU.
+//!
+//! Frames are rendered with `vello_cpu` into an image buffer, which is then presented to the
+//! window through [`softbuffer`].
+
+use std::num::NonZeroU32;
+use std::sync::Arc;
+
+use masonry_core::peniko::Color;
+use masonry_imaging::composite::ImageCompositor;
+use masonry_imaging::vello_cpu::{self, Renderer};
+use masonry_imaging::{ImageBufferTarget, ImageRenderer, RgbaImage, render_damage_into_image};
+use winit::window::Window as WindowHandle;
+
+use crate::event_loop_runner::RenderFrame;
+
+/// The environment variable which selects the renderer at runtime.
+///
+/// Setting it to `software` makes Masonry render on the CPU even if a GPU is available.
+pub(crate) const RENDERER_ENV_VAR: &str = "MASONRY_RENDERER";
+
+/// Returns whether software rendering was requested through [`RENDERER_ENV_VAR`].
+pub(crate) fn requested_by_env() -> bool {
+    std::env::var(RENDERER_ENV_VAR).is_ok_and(|value| value.eq_ignore_ascii_case("software"))
+}
+
+/// Renders frames on the CPU, shared by all windows.
+pub(crate) struct SoftwareRenderer {
+    renderer: Renderer,
+    compositor: ImageCompositor,
+}
+
+impl SoftwareRenderer {
+    pub(crate) const BACKEND_NAME: &str = vello_cpu::BACKEND_NAME;
+
+    pub(crate) fn new() -> Self {
+        Self {
+            renderer: vello_cpu::new_headless_renderer()
+                .expect("creating a Vello CPU renderer can't fail"),
+            compositor: ImageCompositor::new(),
+        }
+    }
+
+    /// Renders `frame` and presents it to the window of `surface`.
+    pub(crate) fn render(&mut self, surface: &mut SoftwareSurface, frame: RenderFrame<'_>) {
+        let _render_span =
+            tracing::info_span!("Rendering Masonry window", backend = Self::BACKEND_NAME).entered();
+        let (background_color, result) = match frame {
+            // The surface keeps the previous frame, so only the damaged area is rendered.
+            // The whole image is still copied on present, as the buffer handed out by
+            // softbuffer may not hold the previous frame.
+            RenderFrame::Flattened(frame, Some(damage))
+                if surface.rendered_base_color == Some(frame.background_color) =>
+            {
+                let result =
+                    render_damage_into_image(&mut self.renderer, &mut surface.image, frame, damage);
+                (frame.background_color, result)
+            }
+            RenderFrame::Flattened(mut frame, _) => {
+                resize_image(&mut surface.image, frame.width, frame.height);
+                let result = self.renderer.render_source_into(
+                    &mut frame,
+                    ImageBufferTarget::from_rgba_image(&mut surface.image),
+                );
+                (frame.background_color, result)
+            }
+            RenderFrame::Composited(frame) => (
+                frame.background_color,
+                self.compositor
+                    .render(&mut self.renderer, frame)
+                    .map(|(image, _)| surface.image = image),
+            ),
+        };
+        surface.rendered_base_color = result.is_ok().then_some(background_color);
+        if let Err(err) = result {
+            tracing::error!(
+                backend = Self::BACKEND_NAME,
+                "Couldn't render Masonry content into image: {err}"
+            );
+            return;
+        }
+        if let Err(err) = surface.present() {
+            tracing::error!("Couldn't present software-rendered frame: {err}");
+        }
+    }
+}
+
+impl std::fmt::Debug for SoftwareRenderer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SoftwareRenderer")
+            .field("renderer", &"(Not Debug)")
+            .field("compositor", &self.compositor)
+            .finish()
+    }
+}
+
+/// A window presented with [`softbuffer`].
+pub(crate) struct SoftwareSurface {
+    surface: softbuffer::Surface<Arc<WindowHandle>, Arc<WindowHandle>>,
+    /// The most recently rendered frame.
+    image: RgbaImage,
+    /// The background color of the frame held by [`image`](Self::image),
+    /// or `None` if it doesn't hold a complete frame.
+    rendered_base_color: Option<Color>,
+}
+
+impl SoftwareSurface {
+    pub(crate) fn new(window: Arc<WindowHandle>) -> Result<Self, softbuffer::SoftBufferError> {
+        let context = softbuffer::Context::new(window.clone())?;
+        let surface = softbuffer::Surface::new(&context, window)?;
+        Ok(Self {
+            surface,
+            image: RgbaImage::new(0, 0),
+            rendered_base_color: None,
+        })
+    }
+
+    fn present(&mut self) -> Result<(), softbuffer::SoftBufferError> {
+        let (Some(width), Some(height)) = (
+            NonZeroU32::new(self.image.width),
+            NonZeroU32::new(self.image.height),
+        ) else {
+            return Ok(());
+        };
+        self.surface.resize(width, height)?;
+        self.surface.window().pre_present_notify();
+        let mut buffer = self.surface.buffer_mut()?;
+        // softbuffer expects `0RGB` pixels. Windows are treated as opaque.
+        for (pixel, rgba) in buffer.iter_mut().zip(self.image.data.chunks_exact(4)) {
+            *pixel = u32::from_be_bytes([0, rgba[0], rgba[1], rgba[2]]);
+        }
+        buffer.present()
+    }
+}
+
+impl std::fmt::Debug for SoftwareSurface {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SoftwareSurface")
+            .field("surface", &"(Not Debug)")
+            .field("width", &self.image.width)
+            .field("height", &self.image.height)
+            .field("rendered_base_color", &self.rendered_base_color)
+            .finish()
+    }
+}
+
+fn resize_image(image: &mut RgbaImage, width: u32, height: u32) {
+    if image.width != width || image.height != height {
+        *image = RgbaImage::new(width, height);
+    }
+}
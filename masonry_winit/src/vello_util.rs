@@ -6,6 +6,7 @@
 //! This module is based on [`vello::util`] module with modifications
 //! for transparent surfaces.
 
+use masonry_core::peniko::Color;
 use wgpu::util::{TextureBlitter, TextureBlitterBuilder};
 use wgpu::{
     self, BlendComponent, BlendFactor, BlendState, CompositeAlphaMode, Device, Instance,
@@ -211,6 +212,7 @@ impl RenderContext {
             target_view,
             blitter,
             resizing: false,
+            rendered_base_color: None,
         };
         self.configure_surface(&surface);
         Ok(surface)
@@ -223,6 +225,7 @@ impl RenderContext {
         // especially important on metal.
         surface.target_texture = texture;
         surface.target_view = view;
+        surface.rendered_base_color = None;
         surface.config.width = width;
         surface.config.height = height;
         self.configure_surface(surface);
@@ -372,6 +375,11 @@ pub(crate) struct RenderSurface<'s> {
     pub target_view: TextureView,
     pub blitter: TextureBlitter,
     pub resizing: bool,
+    /// The background color of the frame held by the target texture,
+    /// or `None` if it doesn't hold a complete frame.
+    ///
+    /// Only the damaged area of the next frame needs to be rendered when this matches.
+    pub rendered_base_color: Option<Color>,
 }
 
 impl std::fmt::Debug for RenderSurface<'_> {
@@ -385,6 +393,7 @@ impl std::fmt::Debug for RenderSurface<'_> {
             .field("target_view", &self.target_view)
             .field("blitter", &"(Not Debug)")
             .field("resizing", &self.resizing)
+            .field("rendered_base_color", &self.rendered_base_color)
             .finish()
     }
 }
@@ -36,6 +36,7 @@ pub use masonry;
 pub use xilem_core as core;
 
 pub mod style;
+pub mod validation;
 pub mod view;
 
 mod any_view;
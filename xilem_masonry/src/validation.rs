@@ -0,0 +1,248 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Validation of form inputs.
+//!
+//! A [`Validator`] checks the value of an input and explains why it's rejected.
+//! A [`Form`] runs the validators of every field while building the view, shows their
+//! errors with [`form_field`], and tells whether the form can be submitted.
+//!
+//! # Example
+//! ```
+//! # use xilem_masonry as xilem;
+//! use xilem::validation::{Form, Validator, in_range, required};
+//! use xilem::view::{flex_col, text_button, text_input};
+//! # use xilem::WidgetView;
+//!
+//! struct Signup {
+//!     name: String,
+//!     age: String,
+//!     submitted: bool,
+//! }
+//!
+//! fn signup_form(state: &mut Signup) -> impl WidgetView<Signup> + use<> {
+//!     let mut form = Form::new();
+//!     let name = form.field(
+//!         text_input(state.name.clone(), |state: &mut Signup, name| state.name = name),
+//!         &state.name,
+//!         required("Enter your name"),
+//!     );
+//!     let age_validator = |age: &String| match age.parse::<u32>() {
+//!         Ok(age) => in_range(18..=150, "You must be an adult").validate(&age),
+//!         Err(_) => Err("Enter a number".into()),
+//!     };
+//!     let age = form.field(
+//!         text_input(state.age.clone(), |state: &mut Signup, age| state.age = age),
+//!         &state.age,
+//!         age_validator,
+//!     );
+//!     let submit = text_button("Sign up", |state: &mut Signup| state.submitted = true)
+//!         .disabled(!form.is_valid());
+//!     flex_col((name, age, submit))
+//! }
+//! ```
+
+use std::ops::RangeBounds;
+
+use masonry::widgets::InputMask;
+
+use crate::WidgetView;
+use crate::view::{FormField, form_field};
+
+/// A check that a form value is acceptable.
+///
+/// Closures taking `&T` and returning `Result<(), String>` are validators, where the
+/// error is the message shown to the user.
+pub trait Validator<T: ?Sized> {
+    /// Returns `Err` with a message for the user if `value` is invalid.
+    fn validate(&self, value: &T) -> Result<(), String>;
+
+    /// Combines this validator with `other`.
+    ///
+    /// `other` only runs if this validator accepts the value, so the user sees
+    /// the first problem only.
+    fn and<V: Validator<T>>(self, other: V) -> And<Self, V>
+    where
+        Self: Sized,
+    {
+        And(self, other)
+    }
+}
+
+impl<T: ?Sized, F: Fn(&T) -> Result<(), String>> Validator<T> for F {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        self(value)
+    }
+}
+
+/// The validator created by [`Validator::and`].
+#[derive(Clone, Debug)]
+pub struct And<A, B>(A, B);
+
+impl<T: ?Sized, A: Validator<T>, B: Validator<T>> Validator<T> for And<A, B> {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        self.0.validate(value)?;
+        self.1.validate(value)
+    }
+}
+
+/// Rejects text made only of whitespace.
+pub fn required<T: AsRef<str> + ?Sized>(message: impl Into<String>) -> impl Validator<T> {
+    let message = message.into();
+    move |value: &T| {
+        if value.as_ref().trim().is_empty() {
+            Err(message.clone())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Rejects text with fewer than `min` characters.
+pub fn min_length<T: AsRef<str> + ?Sized>(
+    min: usize,
+    message: impl Into<String>,
+) -> impl Validator<T> {
+    let message = message.into();
+    move |value: &T| {
+        if value.as_ref().chars().count() < min {
+            Err(message.clone())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Rejects text with more than `max` characters.
+pub fn max_length<T: AsRef<str> + ?Sized>(
+    max: usize,
+    message: impl Into<String>,
+) -> impl Validator<T> {
+    let message = message.into();
+    move |value: &T| {
+        if value.as_ref().chars().count() > max {
+            Err(message.clone())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Rejects text which doesn't fill every slot of `mask`.
+///
+/// This is meant for inputs using the same mask,
+/// see [`TextInput::input_mask`](crate::view::TextInput::input_mask).
+pub fn mask_complete<T: AsRef<str> + ?Sized>(
+    mask: InputMask,
+    message: impl Into<String>,
+) -> impl Validator<T> {
+    let message = message.into();
+    move |value: &T| {
+        if mask.is_complete(value.as_ref()) {
+            Ok(())
+        } else {
+            Err(message.clone())
+        }
+    }
+}
+
+/// Rejects values outside of `range`.
+pub fn in_range<T: PartialOrd, R: RangeBounds<T>>(
+    range: R,
+    message: impl Into<String>,
+) -> impl Validator<T> {
+    let message = message.into();
+    move |value: &T| {
+        if range.contains(value) {
+            Ok(())
+        } else {
+            Err(message.clone())
+        }
+    }
+}
+
+/// Collects the validation results of the fields of a form.
+///
+/// A new `Form` is created each time the view is built, then each field is checked with
+/// [`field`](Self::field) or [`check`](Self::check).
+/// Once every field is checked, [`is_valid`](Self::is_valid) tells whether the form
+/// can be submitted, usually to disable its submit button.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Clone, Debug)]
+pub struct Form {
+    fields: usize,
+    invalid_fields: usize,
+    show_errors: bool,
+}
+
+impl Default for Form {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Form {
+    /// Creates a form with no fields.
+    pub fn new() -> Self {
+        Self {
+            fields: 0,
+            invalid_fields: 0,
+            show_errors: true,
+        }
+    }
+
+    /// Builder-style method to choose whether errors are shown to the user.
+    ///
+    /// Hidden errors still make the form invalid.
+    /// This is useful to avoid showing errors in a blank form until the user first
+    /// tries to submit it.
+    pub fn show_errors(mut self, show_errors: bool) -> Self {
+        self.show_errors = show_errors;
+        self
+    }
+
+    /// Validates a field of the form.
+    ///
+    /// Returns the error to show to the user, if any.
+    pub fn check<T: ?Sized>(&mut self, value: &T, validator: impl Validator<T>) -> Option<String> {
+        self.fields += 1;
+        let error = validator.validate(value).err();
+        if error.is_some() {
+            self.invalid_fields += 1;
+        }
+        error.filter(|_| self.show_errors)
+    }
+
+    /// Validates a field of the form, and wraps its `input` in a [`form_field`] showing
+    /// the error.
+    pub fn field<State, Action, V, T>(
+        &mut self,
+        input: V,
+        value: &T,
+        validator: impl Validator<T>,
+    ) -> FormField<V, State, Action>
+    where
+        State: 'static,
+        Action: 'static,
+        V: WidgetView<State, Action>,
+        T: ?Sized,
+    {
+        form_field(input, self.check(value, validator))
+    }
+
+    /// Returns whether every field checked so far is valid.
+    pub fn is_valid(&self) -> bool {
+        self.invalid_fields == 0
+    }
+
+    /// Returns how many fields were checked.
+    pub fn fields(&self) -> usize {
+        self.fields
+    }
+
+    /// Returns how many fields were invalid.
+    pub fn invalid_fields(&self) -> usize {
+        self.invalid_fields
+    }
+}
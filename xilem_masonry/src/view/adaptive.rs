@@ -0,0 +1,442 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::core::{CollectionWidget, FromDynWidget, Widget, WidgetMut};
+use masonry::kurbo::Axis;
+use masonry::layout::Length;
+use masonry::widgets;
+
+use crate::core::{
+    AppendVec, ElementSplice, MessageCtx, MessageResult, Mut, SuperElement, View, ViewElement,
+    ViewMarker, ViewSequence,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A layout which displays one of its children, chosen by the space available to it.
+///
+/// Every child has a breakpoint, set with [`AdaptiveExt::breakpoint`], which is the minimum
+/// length on the given `axis` that the child needs to be displayed. Children without a breakpoint
+/// have a breakpoint of zero. The child with the largest breakpoint that fits is displayed.
+///
+/// The choice is made during layout, so the displayed child changes in the same frame
+/// as the available space, without a round-trip through app state.
+/// Children that aren't displayed keep their widget state.
+///
+/// See [`masonry::widgets::Adaptive`] for details on how this layout is measured.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::masonry::kurbo::Axis;
+/// use xilem::masonry::layout::AsUnit;
+/// use xilem::view::{AdaptiveExt, adaptive, flex_col, flex_row, label};
+/// # use xilem::WidgetView;
+///
+/// # fn view<State: 'static>() -> impl WidgetView<State> {
+/// adaptive(
+///     Axis::Horizontal,
+///     (
+///         // Narrow windows get a bottom tab bar.
+///         flex_col((label("Content"), label("Tab bar"))),
+///         // Wide windows get a sidebar.
+///         flex_row((label("Sidebar"), label("Content"))).breakpoint(600.px()),
+///     ),
+/// )
+/// # }
+/// ```
+pub fn adaptive<State: 'static, Action, Seq: AdaptiveSequence<State, Action>>(
+    axis: Axis,
+    sequence: Seq,
+) -> Adaptive<Seq, State, Action> {
+    Adaptive {
+        sequence,
+        axis,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`adaptive`] from a sequence.
+///
+/// See `adaptive` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Adaptive<Seq, State, Action = ()> {
+    sequence: Seq,
+    axis: Axis,
+
+    /// Used to associate the State and Action in the call to `.adaptive()` with the State and Action
+    /// used in the View implementation, to allow inference to flow backwards, allowing State and
+    /// Action to be inferred properly.
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<Seq, State, Action> Adaptive<Seq, State, Action> {
+    /// Set the axis whose available length decides which child is displayed.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+}
+
+mod hidden {
+    use super::AdaptiveElement;
+    use crate::core::AppendVec;
+
+    #[doc(hidden)]
+    #[expect(
+        unnameable_types,
+        reason = "Implementation detail, public because of trait visibility rules"
+    )]
+    pub struct AdaptiveState<SeqState> {
+        pub(crate) seq_state: SeqState,
+        pub(crate) scratch: AppendVec<AdaptiveElement>,
+    }
+}
+
+use hidden::AdaptiveState;
+
+impl<Seq, State, Action> ViewMarker for Adaptive<Seq, State, Action> {}
+
+impl<State, Action, Seq> View<State, Action, ViewCtx> for Adaptive<Seq, State, Action>
+where
+    State: 'static,
+    Action: 'static,
+    Seq: AdaptiveSequence<State, Action>,
+{
+    type Element = Pod<widgets::Adaptive>;
+
+    type ViewState = AdaptiveState<Seq::SeqState>;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let mut elements = AppendVec::default();
+        let mut widget = widgets::Adaptive::new(self.axis);
+        let seq_state = self.sequence.seq_build(ctx, &mut elements, app_state);
+        for element in elements.drain() {
+            widget = widget.with(element.child.new_widget, element.breakpoint);
+        }
+        let pod = ctx.create_pod(widget);
+        (
+            pod,
+            AdaptiveState {
+                seq_state,
+                scratch: elements,
+            },
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        AdaptiveState { seq_state, scratch }: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        if prev.axis != self.axis {
+            widgets::Adaptive::set_axis(&mut element, self.axis);
+        }
+
+        let mut splice = AdaptiveSplice::new(element, scratch);
+        self.sequence
+            .seq_rebuild(&prev.sequence, seq_state, ctx, &mut splice, app_state);
+        debug_assert!(scratch.is_empty());
+    }
+
+    fn teardown(
+        &self,
+        AdaptiveState { seq_state, scratch }: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        let mut splice = AdaptiveSplice::new(element, scratch);
+        self.sequence.seq_teardown(seq_state, ctx, &mut splice);
+        debug_assert!(scratch.is_empty());
+    }
+
+    fn message(
+        &self,
+        AdaptiveState { seq_state, scratch }: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        let mut splice = AdaptiveSplice::new(element, scratch);
+        let result = self
+            .sequence
+            .seq_message(seq_state, message, &mut splice, app_state);
+        debug_assert!(scratch.is_empty());
+        result
+    }
+}
+
+// Used to become a reference form for editing. It's provided to rebuild and teardown.
+impl ViewElement for AdaptiveElement {
+    type Mut<'w> = AdaptiveElementMut<'w>;
+}
+
+// Used to allow the item to be used as a generic item in ViewSequence.
+impl SuperElement<Self, ViewCtx> for AdaptiveElement {
+    fn upcast(_ctx: &mut ViewCtx, child: Self) -> Self {
+        child
+    }
+
+    fn with_downcast_val<R>(
+        mut this: Mut<'_, Self>,
+        f: impl FnOnce(Mut<'_, Self>) -> R,
+    ) -> (Self::Mut<'_>, R) {
+        let r = {
+            let parent = this.parent.reborrow_mut();
+            let reborrow = AdaptiveElementMut {
+                idx: this.idx,
+                parent,
+            };
+            f(reborrow)
+        };
+        (this, r)
+    }
+}
+
+impl<W: Widget + FromDynWidget + ?Sized> SuperElement<Pod<W>, ViewCtx> for AdaptiveElement {
+    fn upcast(_: &mut ViewCtx, child: Pod<W>) -> Self {
+        // Getting here means that the widget didn't use .breakpoint.
+        Self {
+            child: child.erased(),
+            breakpoint: Length::ZERO,
+        }
+    }
+
+    fn with_downcast_val<R>(
+        mut this: Mut<'_, Self>,
+        f: impl FnOnce(Mut<'_, Pod<W>>) -> R,
+    ) -> (Mut<'_, Self>, R) {
+        let ret = {
+            let mut child = widgets::Adaptive::get_mut(&mut this.parent, this.idx);
+            let downcast = child.downcast();
+            f(downcast)
+        };
+
+        (this, ret)
+    }
+}
+
+// Used for building and rebuilding the ViewSequence
+impl ElementSplice<AdaptiveElement> for AdaptiveSplice<'_, '_> {
+    fn with_scratch<R>(&mut self, f: impl FnOnce(&mut AppendVec<AdaptiveElement>) -> R) -> R {
+        let ret = f(self.scratch);
+        for element in self.scratch.drain() {
+            widgets::Adaptive::insert(
+                &mut self.element,
+                self.idx,
+                element.child.new_widget,
+                element.breakpoint,
+            );
+            self.idx += 1;
+        }
+        ret
+    }
+
+    fn insert(&mut self, element: AdaptiveElement) {
+        widgets::Adaptive::insert(
+            &mut self.element,
+            self.idx,
+            element.child.new_widget,
+            element.breakpoint,
+        );
+        self.idx += 1;
+    }
+
+    fn mutate<R>(&mut self, f: impl FnOnce(Mut<'_, AdaptiveElement>) -> R) -> R {
+        let child = AdaptiveElementMut {
+            parent: self.element.reborrow_mut(),
+            idx: self.idx,
+        };
+        let ret = f(child);
+        self.idx += 1;
+        ret
+    }
+
+    fn skip(&mut self, n: usize) {
+        self.idx += n;
+    }
+
+    fn index(&self) -> usize {
+        self.idx
+    }
+
+    fn delete<R>(&mut self, f: impl FnOnce(Mut<'_, AdaptiveElement>) -> R) -> R {
+        let ret = {
+            let child = AdaptiveElementMut {
+                parent: self.element.reborrow_mut(),
+                idx: self.idx,
+            };
+            f(child)
+        };
+        widgets::Adaptive::remove(&mut self.element, self.idx);
+        ret
+    }
+}
+
+/// `AdaptiveSequence` is what allows an input to the adaptive layout that contains all the children.
+pub trait AdaptiveSequence<State: 'static, Action = ()>:
+    ViewSequence<State, Action, ViewCtx, AdaptiveElement>
+{
+}
+
+impl<Seq, State, Action> AdaptiveSequence<State, Action> for Seq
+where
+    Seq: ViewSequence<State, Action, ViewCtx, AdaptiveElement>,
+    State: 'static,
+{
+}
+
+/// A trait which extends a [`WidgetView`] with a method to set its breakpoint
+/// within an [`Adaptive`] [`View`].
+pub trait AdaptiveExt<State: 'static, Action>: WidgetView<State, Action> {
+    /// Sets the minimum length the [`Adaptive`] parent needs on its axis to display this view.
+    ///
+    /// # Examples
+    /// ```
+    /// # use xilem_masonry as xilem;
+    /// use xilem::masonry::kurbo::Axis;
+    /// use xilem::masonry::layout::AsUnit;
+    /// use xilem::view::{AdaptiveExt, adaptive, label};
+    /// # use xilem::WidgetView;
+    ///
+    /// # fn view<State: 'static>() -> impl WidgetView<State> {
+    /// adaptive(Axis::Vertical, (
+    ///     label("Short"),
+    ///     label("Tall").breakpoint(400.px()),
+    /// ))
+    /// # }
+    /// ```
+    fn breakpoint(self, breakpoint: Length) -> AdaptiveItem<Self, State, Action>
+    where
+        Action: 'static,
+        Self: Sized,
+    {
+        adaptive_item(self, breakpoint)
+    }
+}
+
+impl<State: 'static, Action, V: WidgetView<State, Action>> AdaptiveExt<State, Action> for V {}
+
+/// A child widget within an [`Adaptive`] view.
+pub struct AdaptiveElement {
+    /// The child widget.
+    child: Pod<dyn Widget>,
+    /// The breakpoint of the child widget.
+    breakpoint: Length,
+}
+
+/// A mutable reference to an [`AdaptiveElement`], used internally by Xilem traits.
+pub struct AdaptiveElementMut<'w> {
+    parent: WidgetMut<'w, widgets::Adaptive>,
+    idx: usize,
+}
+
+// Used for manipulating the ViewSequence.
+struct AdaptiveSplice<'w, 's> {
+    idx: usize,
+    element: WidgetMut<'w, widgets::Adaptive>,
+    scratch: &'s mut AppendVec<AdaptiveElement>,
+}
+
+impl<'w, 's> AdaptiveSplice<'w, 's> {
+    fn new(
+        element: WidgetMut<'w, widgets::Adaptive>,
+        scratch: &'s mut AppendVec<AdaptiveElement>,
+    ) -> Self {
+        Self {
+            idx: 0,
+            element,
+            scratch,
+        }
+    }
+}
+
+/// A `WidgetView` that can be used within an [`Adaptive`] [`View`].
+pub struct AdaptiveItem<V, State, Action> {
+    view: V,
+    breakpoint: Length,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+/// Creates an [`AdaptiveItem`] from a view and its breakpoint.
+pub fn adaptive_item<V, State, Action>(
+    view: V,
+    breakpoint: Length,
+) -> AdaptiveItem<V, State, Action>
+where
+    State: 'static,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    AdaptiveItem {
+        view,
+        breakpoint,
+        phantom: PhantomData,
+    }
+}
+
+impl<V, State, Action> ViewMarker for AdaptiveItem<V, State, Action> {}
+
+impl<State, Action, V> View<State, Action, ViewCtx> for AdaptiveItem<V, State, Action>
+where
+    State: 'static,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    type Element = AdaptiveElement;
+
+    type ViewState = V::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let (pod, state) = self.view.build(ctx, app_state);
+        (
+            AdaptiveElement {
+                child: pod.erased(),
+                breakpoint: self.breakpoint,
+            },
+            state,
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        if self.breakpoint != prev.breakpoint {
+            widgets::Adaptive::set_params(&mut element.parent, element.idx, self.breakpoint);
+        }
+        let mut child = widgets::Adaptive::get_mut(&mut element.parent, element.idx);
+        self.view
+            .rebuild(&prev.view, view_state, ctx, child.downcast(), app_state);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        let mut child = widgets::Adaptive::get_mut(&mut element.parent, element.idx);
+        self.view.teardown(view_state, ctx, child.downcast());
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        let mut child = widgets::Adaptive::get_mut(&mut element.parent, element.idx);
+        self.view
+            .message(view_state, message, child.downcast(), app_state)
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::time::{Date, Weekday};
+use masonry::widgets::{self, CalendarAction, CalendarMode};
+
+use crate::core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
+use crate::{Pod, ViewCtx};
+
+/// A month grid in which the user picks a date, or a range of dates.
+///
+/// The calendar shows the month of `date`, and shows it again whenever `date` changes.
+/// `on_select` is called when a date or a range is picked.
+/// See [`Calendar`](widgets::Calendar) for how the calendar is used with the keyboard.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::masonry::time::{Date, Month, Weekday};
+/// use xilem::masonry::widgets::{CalendarAction, CalendarMode};
+/// use xilem::view::calendar;
+/// # use xilem::WidgetView;
+///
+/// struct Booking {
+///     shown: Date,
+///     nights: Option<(Date, Date)>,
+/// }
+///
+/// fn app(booking: &mut Booking) -> impl WidgetView<Booking> + use<> {
+///     calendar(booking.shown, |booking: &mut Booking, action| {
+///         if let CalendarAction::RangeSelected { start, end } = action {
+///             booking.nights = Some((start, end));
+///         }
+///     })
+///     .mode(CalendarMode::Range)
+///     .selection(booking.nights)
+///     .first_day_of_week(Weekday::Sunday)
+/// }
+/// ```
+pub fn calendar<State, Action, F>(date: Date, on_select: F) -> Calendar<State, Action, F>
+where
+    State: 'static,
+    Action: 'static,
+    F: Fn(&mut State, CalendarAction) -> Action + Send + Sync + 'static,
+{
+    Calendar {
+        date,
+        mode: CalendarMode::Single,
+        selection: None,
+        first_day_of_week: Weekday::Monday,
+        today: None,
+        on_select,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`calendar`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Calendar<State, Action, F> {
+    date: Date,
+    mode: CalendarMode,
+    selection: Option<(Date, Date)>,
+    first_day_of_week: Weekday,
+    today: Option<Date>,
+    on_select: F,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+impl<State, Action, F> Calendar<State, Action, F> {
+    /// Sets whether single dates or ranges are picked.
+    pub fn mode(mut self, mode: CalendarMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the selection, from its first to its last day.
+    ///
+    /// A single selected date is both the first and last day.
+    pub fn selection(mut self, selection: Option<(Date, Date)>) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Sets the day on which weeks start, Monday by default.
+    pub fn first_day_of_week(mut self, weekday: Weekday) -> Self {
+        self.first_day_of_week = weekday;
+        self
+    }
+
+    /// Sets the current date, which is highlighted.
+    pub fn today(mut self, today: Option<Date>) -> Self {
+        self.today = today;
+        self
+    }
+}
+
+impl<State, Action, F> ViewMarker for Calendar<State, Action, F> {}
+impl<State, Action, F> View<State, Action, ViewCtx> for Calendar<State, Action, F>
+where
+    State: 'static,
+    Action: 'static,
+    F: Fn(&mut State, CalendarAction) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::Calendar>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: &mut State) -> (Self::Element, Self::ViewState) {
+        let widget = widgets::Calendar::new(self.date)
+            .with_mode(self.mode)
+            .with_selected_range(self.selection)
+            .with_first_day_of_week(self.first_day_of_week)
+            .with_today(self.today);
+        let pod = ctx.with_action_widget(|ctx| ctx.create_pod(widget));
+        (pod, ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: &mut State,
+    ) {
+        if self.mode != prev.mode {
+            widgets::Calendar::set_mode(&mut element, self.mode);
+        }
+        if self.selection != prev.selection {
+            widgets::Calendar::set_selected_range(&mut element, self.selection);
+        }
+        if self.first_day_of_week != prev.first_day_of_week {
+            widgets::Calendar::set_first_day_of_week(&mut element, self.first_day_of_week);
+        }
+        if self.today != prev.today {
+            widgets::Calendar::set_today(&mut element, self.today);
+        }
+        if self.date != prev.date {
+            widgets::Calendar::show_date(&mut element, self.date);
+        }
+    }
+
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        if message.take_first().is_some() {
+            tracing::warn!("Got unexpected id path in Calendar::message");
+            return MessageResult::Stale;
+        }
+        match message.take_message::<CalendarAction>() {
+            Some(action) => MessageResult::Action((self.on_select)(app_state, *action)),
+            None => {
+                tracing::error!(
+                    "Wrong message type in Calendar::message: {message:?}, expected {}",
+                    std::any::type_name::<CalendarAction>(),
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::peniko::Color;
+use masonry::widgets::{self, ColorChanged, ColorFormat};
+
+use crate::core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
+use crate::{Pod, ViewCtx};
+
+/// A widget to pick a color, with its hue, saturation, value and opacity, or as text.
+///
+/// `on_change` is called when the user picks a color in the picker, types a valid color,
+/// or presses one of the [swatches](ColorPicker::swatches).
+/// See [`ColorPicker`](widgets::ColorPicker) for the parts of the picker.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::masonry::peniko::Color;
+/// use xilem::masonry::widgets::ColorFormat;
+/// use xilem::view::color_picker;
+/// # use xilem::WidgetView;
+///
+/// struct Brush {
+///     color: Color,
+/// }
+///
+/// fn app(brush: &mut Brush) -> impl WidgetView<Brush> + use<> {
+///     color_picker(brush.color, |brush: &mut Brush, color| brush.color = color)
+///         .format(ColorFormat::Oklch)
+///         .swatches(vec![Color::BLACK, Color::WHITE])
+/// }
+/// ```
+pub fn color_picker<State, Action, F>(color: Color, on_change: F) -> ColorPicker<State, Action, F>
+where
+    State: 'static,
+    Action: 'static,
+    F: Fn(&mut State, Color) -> Action + Send + Sync + 'static,
+{
+    ColorPicker {
+        color,
+        format: ColorFormat::default(),
+        swatches: Vec::new(),
+        on_change,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`color_picker`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct ColorPicker<State, Action, F> {
+    color: Color,
+    format: ColorFormat,
+    swatches: Vec<Color>,
+    on_change: F,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+impl<State, Action, F> ColorPicker<State, Action, F> {
+    /// Sets the format of the text field, hexadecimal by default.
+    ///
+    /// The user can still switch to other formats with the button next to the field.
+    pub fn format(mut self, format: ColorFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the colors of the palette shown below the picker.
+    pub fn swatches(mut self, swatches: Vec<Color>) -> Self {
+        self.swatches = swatches;
+        self
+    }
+}
+
+impl<State, Action, F> ViewMarker for ColorPicker<State, Action, F> {}
+impl<State, Action, F> View<State, Action, ViewCtx> for ColorPicker<State, Action, F>
+where
+    State: 'static,
+    Action: 'static,
+    F: Fn(&mut State, Color) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::ColorPicker>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: &mut State) -> (Self::Element, Self::ViewState) {
+        let widget = widgets::ColorPicker::new(self.color)
+            .with_format(self.format)
+            .with_swatches(self.swatches.clone());
+        let pod = ctx.with_action_widget(|ctx| ctx.create_pod(widget));
+        (pod, ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: &mut State,
+    ) {
+        if self.color != prev.color {
+            widgets::ColorPicker::set_color(&mut element, self.color);
+        }
+        if self.format != prev.format {
+            widgets::ColorPicker::set_format(&mut element, self.format);
+        }
+        if self.swatches != prev.swatches {
+            widgets::ColorPicker::set_swatches(&mut element, self.swatches.clone());
+        }
+    }
+
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        if message.take_first().is_some() {
+            tracing::warn!("Got unexpected id path in ColorPicker::message");
+            return MessageResult::Stale;
+        }
+        match message.take_message::<ColorChanged>() {
+            Some(changed) => MessageResult::Action((self.on_change)(app_state, changed.0)),
+            None => {
+                tracing::error!(
+                    "Wrong message type in ColorPicker::message: {message:?}, expected {}",
+                    std::any::type_name::<ColorChanged>(),
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
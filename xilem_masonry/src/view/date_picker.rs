@@ -0,0 +1,144 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::time::{Date, Weekday};
+use masonry::widgets::{self, DateChanged};
+
+use crate::core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
+use crate::{Pod, ViewCtx};
+
+/// A text field for a date, with a button showing a calendar in a popup.
+///
+/// `on_change` is called when the user types a valid date, clears the field,
+/// or picks a date in the calendar.
+/// See [`DatePicker`](widgets::DatePicker) for the format of typed dates.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::masonry::time::{Date, Weekday};
+/// use xilem::view::date_picker;
+/// # use xilem::WidgetView;
+///
+/// struct Task {
+///     due: Option<Date>,
+/// }
+///
+/// fn app(task: &mut Task) -> impl WidgetView<Task> + use<> {
+///     date_picker(task.due, |task: &mut Task, due| task.due = due)
+///         .first_day_of_week(Weekday::Sunday)
+/// }
+/// ```
+pub fn date_picker<State, Action, F>(
+    date: Option<Date>,
+    on_change: F,
+) -> DatePicker<State, Action, F>
+where
+    State: 'static,
+    Action: 'static,
+    F: Fn(&mut State, Option<Date>) -> Action + Send + Sync + 'static,
+{
+    DatePicker {
+        date,
+        first_day_of_week: Weekday::Monday,
+        today: None,
+        on_change,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`date_picker`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct DatePicker<State, Action, F> {
+    date: Option<Date>,
+    first_day_of_week: Weekday,
+    today: Option<Date>,
+    on_change: F,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+impl<State, Action, F> DatePicker<State, Action, F> {
+    /// Sets the day on which the weeks of the calendar start, Monday by default.
+    pub fn first_day_of_week(mut self, weekday: Weekday) -> Self {
+        self.first_day_of_week = weekday;
+        self
+    }
+
+    /// Sets the current date, which is highlighted in the calendar.
+    pub fn today(mut self, today: Option<Date>) -> Self {
+        self.today = today;
+        self
+    }
+}
+
+impl<State, Action, F> ViewMarker for DatePicker<State, Action, F> {}
+impl<State, Action, F> View<State, Action, ViewCtx> for DatePicker<State, Action, F>
+where
+    State: 'static,
+    Action: 'static,
+    F: Fn(&mut State, Option<Date>) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::DatePicker>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: &mut State) -> (Self::Element, Self::ViewState) {
+        let widget = widgets::DatePicker::new(self.date)
+            .with_first_day_of_week(self.first_day_of_week)
+            .with_today(self.today);
+        let pod = ctx.with_action_widget(|ctx| ctx.create_pod(widget));
+        (pod, ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: &mut State,
+    ) {
+        if self.date != prev.date {
+            widgets::DatePicker::set_date(&mut element, self.date);
+        }
+        if self.first_day_of_week != prev.first_day_of_week {
+            widgets::DatePicker::set_first_day_of_week(&mut element, self.first_day_of_week);
+        }
+        if self.today != prev.today {
+            widgets::DatePicker::set_today(&mut element, self.today);
+        }
+    }
+
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        if message.take_first().is_some() {
+            tracing::warn!("Got unexpected id path in DatePicker::message");
+            return MessageResult::Stale;
+        }
+        match message.take_message::<DateChanged>() {
+            Some(changed) => MessageResult::Action((self.on_change)(app_state, changed.0)),
+            None => {
+                tracing::error!(
+                    "Wrong message type in DatePicker::message: {message:?}, expected {}",
+                    std::any::type_name::<DateChanged>(),
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
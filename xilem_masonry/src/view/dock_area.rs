@@ -0,0 +1,324 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::core::{ArcStr, NewWidget, WidgetMut};
+use masonry::widgets::{self, DockAction, DockLayout, TabHeader};
+
+use crate::core::{MessageCtx, MessageResult, Mut, View, ViewId, ViewMarker, ViewPathTracker};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// An IDE-style area of panels, which the user can rearrange by dragging their tabs.
+///
+/// Panels are identified by their `key`, which the `layout` uses to arrange them.
+/// Panels missing from the layout are added to its first group.
+///
+/// Changes made by the user and requests to close panels are reported to `on_event`.
+/// The layout always reflects what the view was last built with, so `on_event` should
+/// store the new layout in the app state; with the `serde` feature, it can also be
+/// saved to restore the arrangement on the next launch.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::masonry::kurbo::Axis;
+/// use xilem::masonry::widgets::{DockAction, DockLayout, DockNode, DockSide};
+/// use xilem::view::{dock_area, dock_panel, label};
+/// # use xilem::WidgetView;
+///
+/// struct Ide {
+///     layout: DockLayout,
+///     show_terminal: bool,
+/// }
+///
+/// impl Ide {
+///     fn new() -> Self {
+///         let center = DockNode::split(
+///             Axis::Horizontal,
+///             0.75,
+///             DockNode::group(["editor"]),
+///             DockNode::group(["outline"]),
+///         );
+///         Self {
+///             layout: DockLayout::new(center).with_side(DockSide::Bottom, ["terminal"], 200.),
+///             show_terminal: true,
+///         }
+///     }
+/// }
+///
+/// fn workbench(ide: &mut Ide) -> impl WidgetView<Ide> + use<> {
+///     let mut panels = vec![
+///         dock_panel("editor", "Editor", label("fn main() {}")),
+///         dock_panel("outline", "Outline", label("main")),
+///     ];
+///     if ide.show_terminal {
+///         panels.push(dock_panel("terminal", "Terminal", label("$")).closable(true));
+///     }
+///     dock_area(ide.layout.clone(), panels, |ide: &mut Ide, event| match event {
+///         DockAction::LayoutChanged(layout) => ide.layout = layout,
+///         DockAction::CloseRequested(key) => {
+///             if key == "terminal" {
+///                 ide.show_terminal = false;
+///             }
+///         }
+///     })
+/// }
+/// ```
+pub fn dock_area<State, Action, V, F>(
+    layout: DockLayout,
+    panels: Vec<DockPanel<V>>,
+    on_event: F,
+) -> DockArea<State, Action, V, F>
+where
+    State: 'static,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+    F: Fn(&mut State, DockAction) -> Action + Send + Sync + 'static,
+{
+    DockArea {
+        layout,
+        panels,
+        on_event,
+        phantom: PhantomData,
+    }
+}
+
+/// A panel of a [`dock_area`] view.
+///
+/// `key` must be unique among the panels of the view.
+pub fn dock_panel<V>(key: impl Into<String>, title: impl Into<ArcStr>, content: V) -> DockPanel<V> {
+    DockPanel {
+        key: key.into(),
+        title: title.into(),
+        closable: false,
+        content,
+    }
+}
+
+/// A panel of a [`dock_area`] view, created by [`dock_panel`].
+pub struct DockPanel<V> {
+    key: String,
+    title: ArcStr,
+    closable: bool,
+    content: V,
+}
+
+impl<V> DockPanel<V> {
+    /// Sets whether the tab of the panel has a close button.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    fn header(&self) -> NewWidget<TabHeader> {
+        NewWidget::new(TabHeader::new(self.title.clone()).with_closable(self.closable))
+    }
+
+    fn rebuild_header(&self, prev: &Self, mut header: WidgetMut<'_, TabHeader>) {
+        if self.title != prev.title {
+            TabHeader::set_text(&mut header, self.title.clone());
+        }
+        if self.closable != prev.closable {
+            TabHeader::set_closable(&mut header, self.closable);
+        }
+    }
+}
+
+/// The [`View`] created by [`dock_area`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct DockArea<State, Action, V, F> {
+    layout: DockLayout,
+    panels: Vec<DockPanel<V>>,
+    on_event: F,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+/// The state of a [`DockArea`] view.
+pub struct DockAreaState<S> {
+    panels: Vec<PanelState<S>>,
+    next_generation: u64,
+}
+
+struct PanelState<S> {
+    key: String,
+    /// Identifies the content in view paths, so messages can't reach another panel.
+    generation: u64,
+    content: S,
+}
+
+impl<State, Action, V, F> ViewMarker for DockArea<State, Action, V, F> {}
+impl<State, Action, V, F> View<State, Action, ViewCtx> for DockArea<State, Action, V, F>
+where
+    State: 'static,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+    F: Fn(&mut State, DockAction) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::DockArea>;
+    type ViewState = DockAreaState<V::ViewState>;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let mut widget = widgets::DockArea::new().with_layout(self.layout.clone());
+        let mut states = Vec::with_capacity(self.panels.len());
+        for (panel, generation) in self.panels.iter().zip(0..) {
+            let (content, state) = ctx.with_id(ViewId::new(generation), |ctx| {
+                panel.content.build(ctx, app_state)
+            });
+            widget = widget.with_panel(panel.key.clone(), panel.header(), content.new_widget);
+            states.push(PanelState {
+                key: panel.key.clone(),
+                generation,
+                content: state,
+            });
+        }
+        let pod = ctx.with_action_widget(|ctx| ctx.create_pod(widget));
+        let state = DockAreaState {
+            next_generation: states.len() as u64,
+            panels: states,
+        };
+        (pod, state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        for prev_panel in &prev.panels {
+            if self.panels.iter().any(|panel| panel.key == prev_panel.key) {
+                continue;
+            }
+            let index = view_state
+                .panels
+                .iter()
+                .position(|state| state.key == prev_panel.key)
+                .unwrap();
+            let mut state = view_state.panels.remove(index);
+            if let Some(mut content) = widgets::DockArea::content_mut(&mut element, &state.key) {
+                ctx.with_id(ViewId::new(state.generation), |ctx| {
+                    prev_panel
+                        .content
+                        .teardown(&mut state.content, ctx, content.downcast());
+                });
+            }
+            widgets::DockArea::remove_panel(&mut element, &state.key);
+        }
+
+        let mut states = Vec::with_capacity(self.panels.len());
+        for panel in &self.panels {
+            let existing = view_state
+                .panels
+                .iter()
+                .position(|state| state.key == panel.key);
+            let prev_panel = prev.panels.iter().find(|prev| prev.key == panel.key);
+            let (Some(index), Some(prev_panel)) = (existing, prev_panel) else {
+                let generation = view_state.next_generation;
+                view_state.next_generation += 1;
+                let (content, state) = ctx.with_id(ViewId::new(generation), |ctx| {
+                    panel.content.build(ctx, app_state)
+                });
+                widgets::DockArea::add_panel(
+                    &mut element,
+                    panel.key.clone(),
+                    panel.header(),
+                    content.new_widget,
+                );
+                states.push(PanelState {
+                    key: panel.key.clone(),
+                    generation,
+                    content: state,
+                });
+                continue;
+            };
+            let mut state = view_state.panels.swap_remove(index);
+            if let Some(header) = widgets::DockArea::header_mut(&mut element, &panel.key) {
+                panel.rebuild_header(prev_panel, header);
+            }
+            if let Some(mut content) = widgets::DockArea::content_mut(&mut element, &panel.key) {
+                ctx.with_id(ViewId::new(state.generation), |ctx| {
+                    panel.content.rebuild(
+                        &prev_panel.content,
+                        &mut state.content,
+                        ctx,
+                        content.downcast(),
+                        app_state,
+                    );
+                });
+            }
+            states.push(state);
+        }
+        view_state.panels = states;
+
+        // Adding and removing panels changes the layout of the widget, so it's reset to
+        // put re-added panels back to their place.
+        let panels_changed = self.panels.len() != prev.panels.len()
+            || self
+                .panels
+                .iter()
+                .zip(&prev.panels)
+                .any(|(a, b)| a.key != b.key);
+        if panels_changed || self.layout != prev.layout {
+            widgets::DockArea::set_layout(&mut element, self.layout.clone());
+        }
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        for (panel, state) in self.panels.iter().zip(&mut view_state.panels) {
+            if let Some(mut content) = widgets::DockArea::content_mut(&mut element, &state.key) {
+                ctx.with_id(ViewId::new(state.generation), |ctx| {
+                    panel
+                        .content
+                        .teardown(&mut state.content, ctx, content.downcast());
+                });
+            }
+        }
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        if let Some(id) = message.take_first() {
+            let Some((panel, state)) = self
+                .panels
+                .iter()
+                .zip(&mut view_state.panels)
+                .find(|(_, state)| ViewId::new(state.generation) == id)
+            else {
+                tracing::warn!("Got message for a removed panel in DockArea::message");
+                return MessageResult::Stale;
+            };
+            let Some(mut content) = widgets::DockArea::content_mut(&mut element, &state.key) else {
+                return MessageResult::Stale;
+            };
+            return panel.content.message(
+                &mut state.content,
+                message,
+                content.downcast(),
+                app_state,
+            );
+        }
+
+        let Some(action) = message.take_message::<DockAction>() else {
+            tracing::error!(
+                "Wrong message type in DockArea::message: {message:?}, expected {}",
+                std::any::type_name::<DockAction>(),
+            );
+            return MessageResult::Stale;
+        };
+        MessageResult::Action((self.on_event)(app_state, *action))
+    }
+}
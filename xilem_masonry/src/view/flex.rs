@@ -6,9 +6,9 @@ use std::marker::PhantomData;
 use masonry::core::{CollectionWidget, FromDynWidget, Widget, WidgetMut};
 use masonry::kurbo::Axis;
 use masonry::layout::Length;
-pub use masonry::properties::types::{CrossAxisAlignment, MainAxisAlignment};
-pub use masonry::widgets::FlexParams;
+pub use masonry::properties::types::{CrossAxisAlignment, LineAlignment, MainAxisAlignment};
 use masonry::widgets::{self};
+pub use masonry::widgets::{FlexParams, FlexWrap};
 
 use crate::core::{
     AppendVec, ElementSplice, MessageCtx, MessageResult, Mut, SuperElement, View, ViewElement,
@@ -40,6 +40,9 @@ use crate::{AnyWidgetView, Pod, ViewCtx, WidgetView};
 /// There is currently no fine-grained support for flex grow or flex shrink.
 /// Instead every child gets their size decided in one shot, as described above.
 ///
+/// By default all children are laid out on a single line.
+/// Use [`wrap`](Flex::wrap) to break them into multiple lines instead.
+///
 /// # Example
 /// ```rust,no_run
 /// # use xilem_masonry as xilem;
@@ -89,6 +92,8 @@ pub fn flex<State: 'static, Action, Seq: FlexSequence<State, Action>>(
         sequence,
         cross_axis_alignment: CrossAxisAlignment::Center,
         main_axis_alignment: MainAxisAlignment::Start,
+        wrap: FlexWrap::NoWrap,
+        line_alignment: LineAlignment::Start,
         phantom: PhantomData,
     }
 }
@@ -126,6 +131,8 @@ pub struct Flex<Seq, State, Action = ()> {
     axis: Axis,
     cross_axis_alignment: CrossAxisAlignment,
     main_axis_alignment: MainAxisAlignment,
+    wrap: FlexWrap,
+    line_alignment: LineAlignment,
     phantom: PhantomData<fn() -> (State, Action)>,
 }
 
@@ -147,6 +154,18 @@ impl<Seq, State, Action> Flex<Seq, State, Action> {
         self.main_axis_alignment = axis;
         self
     }
+
+    /// Set whether the children wrap onto multiple lines (see [`FlexWrap`]).
+    pub fn wrap(mut self, wrap: FlexWrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Set the [`LineAlignment`] of wrapped lines.
+    pub fn line_alignment(mut self, alignment: LineAlignment) -> Self {
+        self.line_alignment = alignment;
+        self
+    }
 }
 
 mod hidden {
@@ -206,7 +225,9 @@ where
         let mut elements = AppendVec::default();
         let mut widget = widgets::Flex::for_axis(self.axis)
             .cross_axis_alignment(self.cross_axis_alignment)
-            .main_axis_alignment(self.main_axis_alignment);
+            .main_axis_alignment(self.main_axis_alignment)
+            .with_wrap(self.wrap)
+            .line_alignment(self.line_alignment);
         let seq_state = self.sequence.seq_build(ctx, &mut elements, app_state);
         for child in elements.drain() {
             widget = match child {
@@ -241,6 +262,12 @@ where
         if prev.main_axis_alignment != self.main_axis_alignment {
             widgets::Flex::set_main_axis_alignment(&mut element, self.main_axis_alignment);
         }
+        if prev.wrap != self.wrap {
+            widgets::Flex::set_wrap(&mut element, self.wrap);
+        }
+        if prev.line_alignment != self.line_alignment {
+            widgets::Flex::set_line_alignment(&mut element, self.line_alignment);
+        }
         let mut splice = FlexSplice::new(element, scratch);
         self.sequence
             .seq_rebuild(&prev.sequence, seq_state, ctx, &mut splice, app_state);
@@ -0,0 +1,115 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::core::ArcStr;
+use masonry::widgets;
+
+use crate::core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A form input with an error message shown under it.
+///
+/// While `error` is `Some`, the input is marked invalid: the default theme gives it a red
+/// border, and assistive technologies read the error message when describing it.
+///
+/// See [`Form`](crate::validation::Form) to compute the errors of several fields
+/// with [validators](crate::validation::Validator).
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::view::{form_field, text_input};
+/// # use xilem::WidgetView;
+///
+/// struct State {
+///     email: String,
+/// }
+///
+/// # fn view(state: &mut State) -> impl WidgetView<State> + use<> {
+/// let error = (!state.email.contains('@')).then_some("Enter an email address");
+/// form_field(
+///     text_input(state.email.clone(), |state: &mut State, email| state.email = email),
+///     error,
+/// )
+/// # }
+/// ```
+pub fn form_field<State, Action, V>(
+    input: V,
+    error: Option<impl Into<ArcStr>>,
+) -> FormField<V, State, Action>
+where
+    State: 'static,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    FormField {
+        input,
+        error: error.map(Into::into),
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`form_field`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct FormField<V, State, Action = ()> {
+    input: V,
+    error: Option<ArcStr>,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> ViewMarker for FormField<V, State, Action> {}
+impl<V, State, Action> View<State, Action, ViewCtx> for FormField<V, State, Action>
+where
+    State: 'static,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    type Element = Pod<widgets::FormField>;
+    type ViewState = V::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let (input, input_state) = self.input.build(ctx, app_state);
+        let widget = widgets::FormField::new(input.new_widget).with_error(self.error.clone());
+        (ctx.create_pod(widget), input_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        if self.error != prev.error {
+            widgets::FormField::set_error(&mut element, self.error.clone());
+        }
+        let mut input = widgets::FormField::input_mut(&mut element);
+        self.input
+            .rebuild(&prev.input, view_state, ctx, input.downcast(), app_state);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        let mut input = widgets::FormField::input_mut(&mut element);
+        self.input.teardown(view_state, ctx, input.downcast());
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        let mut input = widgets::FormField::input_mut(&mut element);
+        self.input
+            .message(view_state, message, input.downcast(), app_state)
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::widgets::{self, Menu, MenuItemActivated};
+
+use crate::core::{MessageCtx, MessageResult, Mut, View, ViewId, ViewMarker, ViewPathTracker};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A bar of menus shown above `content`, as found at the top of desktop app windows.
+///
+/// `on_activate` is called with the [`id`](widgets::MenuCommand::id) of the activated item.
+/// See [`MenuBar`](widgets::MenuBar) for how menus are used with the mouse and keyboard.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::masonry::widgets::{Menu, MenuCommand};
+/// use xilem::view::{label, menu_bar};
+/// # use xilem::WidgetView;
+///
+/// struct Editor {
+///     text: String,
+///     saved: bool,
+/// }
+///
+/// fn app(editor: &mut Editor) -> impl WidgetView<Editor> + use<> {
+///     let menus = vec![
+///         Menu::new("&File")
+///             .with_item(MenuCommand::new("save", "&Save").with_shortcut("Ctrl+S"))
+///             .with_separator()
+///             .with_item(MenuCommand::new("quit", "&Quit")),
+///         Menu::new("&Edit").with_item(
+///             MenuCommand::new("clear", "C&lear").with_enabled(!editor.text.is_empty()),
+///         ),
+///     ];
+///     menu_bar(menus, label(editor.text.clone()), |editor: &mut Editor, id| {
+///         match id.as_str() {
+///             "save" => editor.saved = true,
+///             "clear" => editor.text.clear(),
+///             _ => {}
+///         }
+///     })
+/// }
+/// ```
+pub fn menu_bar<State, Action, V, F>(
+    menus: Vec<Menu>,
+    content: V,
+    on_activate: F,
+) -> MenuBar<State, Action, V, F>
+where
+    State: 'static,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+    F: Fn(&mut State, String) -> Action + Send + Sync + 'static,
+{
+    MenuBar {
+        menus,
+        content,
+        on_activate,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`menu_bar`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct MenuBar<State, Action, V, F> {
+    menus: Vec<Menu>,
+    content: V,
+    on_activate: F,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+/// Use a distinctive number here, to be able to catch bugs.
+/// In case the generational-id view path in `View::Message` leads to the wrong view.
+/// This is a randomly generated 32 bit number - 2716839467 in decimal.
+const MENU_BAR_CONTENT_VIEW_ID: ViewId = ViewId::new(0xa1eeb42b);
+
+impl<State, Action, V, F> ViewMarker for MenuBar<State, Action, V, F> {}
+impl<State, Action, V, F> View<State, Action, ViewCtx> for MenuBar<State, Action, V, F>
+where
+    State: 'static,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+    F: Fn(&mut State, String) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::MenuBar>;
+    type ViewState = V::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let (content, content_state) = ctx.with_id(MENU_BAR_CONTENT_VIEW_ID, |ctx| {
+            self.content.build(ctx, app_state)
+        });
+        let widget = widgets::MenuBar::new(self.menus.clone(), content.new_widget);
+        let pod = ctx.with_action_widget(|ctx| ctx.create_pod(widget));
+        (pod, content_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        if self.menus != prev.menus {
+            widgets::MenuBar::set_menus(&mut element, self.menus.clone());
+        }
+        ctx.with_id(MENU_BAR_CONTENT_VIEW_ID, |ctx| {
+            self.content.rebuild(
+                &prev.content,
+                view_state,
+                ctx,
+                widgets::MenuBar::content_mut(&mut element).downcast(),
+                app_state,
+            );
+        });
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.with_id(MENU_BAR_CONTENT_VIEW_ID, |ctx| {
+            self.content.teardown(
+                view_state,
+                ctx,
+                widgets::MenuBar::content_mut(&mut element).downcast(),
+            );
+        });
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(MENU_BAR_CONTENT_VIEW_ID) => self.content.message(
+                view_state,
+                message,
+                widgets::MenuBar::content_mut(&mut element).downcast(),
+                app_state,
+            ),
+            None => match message.take_message::<MenuItemActivated>() {
+                Some(activated) => {
+                    MessageResult::Action((self.on_activate)(app_state, activated.id))
+                }
+                None => {
+                    tracing::error!(
+                        "Wrong message type in MenuBar::message: {message:?}, expected {}",
+                        std::any::type_name::<MenuItemActivated>(),
+                    );
+                    MessageResult::Stale
+                }
+            },
+            _ => {
+                tracing::warn!("Got unexpected id path in MenuBar::message");
+                MessageResult::Stale
+            }
+        }
+    }
+}
@@ -3,24 +3,33 @@
 
 //! Views for the widgets which are built-in to Masonry. These are the primitives your Xilem app's view tree will generally be constructed from.
 
+mod adaptive;
 mod badge;
 mod badged;
 mod button;
+mod calendar;
 mod canvas;
 mod checkbox;
 mod collapse_panel;
+mod color_picker;
+mod date_picker;
 mod divider;
+mod dock_area;
 mod flex;
+mod form_field;
 mod grid;
 mod image;
 mod indexed_stack;
 mod label;
+mod menu_bar;
 mod portal;
+mod positioned;
 mod progress_bar;
 mod prop;
 mod prose;
 mod radio_button;
 mod radio_group;
+mod range_slider;
 mod resize_observer;
 mod sized_box;
 mod slider;
@@ -28,32 +37,45 @@ mod spinner;
 mod split;
 mod svg;
 mod switch;
+mod tabs;
 mod task;
 mod text_input;
+mod time_picker;
+mod toast;
 mod transform;
 mod variable_label;
 mod virtual_scroll;
 mod worker;
+mod zoom_portal;
 mod zstack;
 
+pub use self::adaptive::*;
 pub use self::badge::*;
 pub use self::badged::*;
 pub use self::button::*;
+pub use self::calendar::*;
 pub use self::canvas::*;
 pub use self::checkbox::*;
 pub use self::collapse_panel::*;
+pub use self::color_picker::*;
+pub use self::date_picker::*;
 pub use self::divider::*;
+pub use self::dock_area::*;
 pub use self::flex::*;
+pub use self::form_field::*;
 pub use self::grid::*;
 pub use self::image::*;
 pub use self::indexed_stack::*;
 pub use self::label::*;
+pub use self::menu_bar::*;
 pub use self::portal::*;
+pub use self::positioned::*;
 pub use self::progress_bar::*;
 pub use self::prop::*;
 pub use self::prose::*;
 pub use self::radio_button::*;
 pub use self::radio_group::*;
+pub use self::range_slider::*;
 pub use self::resize_observer::*;
 pub use self::sized_box::*;
 pub use self::slider::*;
@@ -61,10 +83,14 @@ pub use self::spinner::*;
 pub use self::split::*;
 pub use self::svg::*;
 pub use self::switch::*;
+pub use self::tabs::*;
 pub use self::task::*;
 pub use self::text_input::*;
+pub use self::time_picker::*;
+pub use self::toast::*;
 pub use self::transform::*;
 pub use self::variable_label::*;
 pub use self::virtual_scroll::*;
 pub use self::worker::*;
+pub use self::zoom_portal::*;
 pub use self::zstack::*;
@@ -0,0 +1,415 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::core::{CollectionWidget, FromDynWidget, Widget, WidgetMut};
+use masonry::widgets;
+
+pub use masonry::widgets::Position;
+
+use crate::core::{
+    AppendVec, ElementSplice, MessageCtx, MessageResult, Mut, SuperElement, View, ViewElement,
+    ViewMarker, ViewSequence,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A layout which places each child at offsets from its edges, or anchors it to another widget.
+///
+/// Children without a [`PositionedExt::position`] are placed at the top-left corner.
+/// Later children are drawn on top of earlier ones.
+///
+/// See [`masonry::widgets::Position`] for how children are sized and placed.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::masonry::layout::AsUnit;
+/// use xilem::view::{Position, PositionedExt, label, positioned};
+/// # use xilem::WidgetView;
+///
+/// # fn view<State: 'static>() -> impl WidgetView<State> {
+/// positioned((
+///     label("Inbox").position(Position::new().inset(0.px())),
+///     // A badge in the top-right corner.
+///     label("3").position(Position::new().top(4.px()).right(4.px())),
+/// ))
+/// # }
+/// ```
+pub fn positioned<State: 'static, Action, Seq: PositionedSequence<State, Action>>(
+    sequence: Seq,
+) -> Positioned<Seq, State, Action> {
+    Positioned {
+        sequence,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`positioned`] from a sequence.
+///
+/// See `positioned` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Positioned<Seq, State, Action = ()> {
+    sequence: Seq,
+
+    /// Used to associate the State and Action in the call to `.positioned()` with the State and Action
+    /// used in the View implementation, to allow inference to flow backwards, allowing State and
+    /// Action to be inferred properly.
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+mod hidden {
+    use super::PositionedElement;
+    use crate::core::AppendVec;
+
+    #[doc(hidden)]
+    #[expect(
+        unnameable_types,
+        reason = "Implementation detail, public because of trait visibility rules"
+    )]
+    pub struct PositionedState<SeqState> {
+        pub(crate) seq_state: SeqState,
+        pub(crate) scratch: AppendVec<PositionedElement>,
+    }
+}
+
+use hidden::PositionedState;
+
+impl<Seq, State, Action> ViewMarker for Positioned<Seq, State, Action> {}
+
+impl<State, Action, Seq> View<State, Action, ViewCtx> for Positioned<Seq, State, Action>
+where
+    State: 'static,
+    Action: 'static,
+    Seq: PositionedSequence<State, Action>,
+{
+    type Element = Pod<widgets::Positioned>;
+
+    type ViewState = PositionedState<Seq::SeqState>;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let mut elements = AppendVec::default();
+        let mut widget = widgets::Positioned::new();
+        let seq_state = self.sequence.seq_build(ctx, &mut elements, app_state);
+        for element in elements.drain() {
+            widget = widget.with(element.child.new_widget, element.position);
+        }
+        let pod = ctx.create_pod(widget);
+        (
+            pod,
+            PositionedState {
+                seq_state,
+                scratch: elements,
+            },
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        PositionedState { seq_state, scratch }: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        let mut splice = PositionedSplice::new(element, scratch);
+        self.sequence
+            .seq_rebuild(&prev.sequence, seq_state, ctx, &mut splice, app_state);
+        debug_assert!(scratch.is_empty());
+    }
+
+    fn teardown(
+        &self,
+        PositionedState { seq_state, scratch }: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        let mut splice = PositionedSplice::new(element, scratch);
+        self.sequence.seq_teardown(seq_state, ctx, &mut splice);
+        debug_assert!(scratch.is_empty());
+    }
+
+    fn message(
+        &self,
+        PositionedState { seq_state, scratch }: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        let mut splice = PositionedSplice::new(element, scratch);
+        let result = self
+            .sequence
+            .seq_message(seq_state, message, &mut splice, app_state);
+        debug_assert!(scratch.is_empty());
+        result
+    }
+}
+
+// Used to become a reference form for editing. It's provided to rebuild and teardown.
+impl ViewElement for PositionedElement {
+    type Mut<'w> = PositionedElementMut<'w>;
+}
+
+// Used to allow the item to be used as a generic item in ViewSequence.
+impl SuperElement<Self, ViewCtx> for PositionedElement {
+    fn upcast(_ctx: &mut ViewCtx, child: Self) -> Self {
+        child
+    }
+
+    fn with_downcast_val<R>(
+        mut this: Mut<'_, Self>,
+        f: impl FnOnce(Mut<'_, Self>) -> R,
+    ) -> (Self::Mut<'_>, R) {
+        let r = {
+            let parent = this.parent.reborrow_mut();
+            let reborrow = PositionedElementMut {
+                idx: this.idx,
+                parent,
+            };
+            f(reborrow)
+        };
+        (this, r)
+    }
+}
+
+impl<W: Widget + FromDynWidget + ?Sized> SuperElement<Pod<W>, ViewCtx> for PositionedElement {
+    fn upcast(_: &mut ViewCtx, child: Pod<W>) -> Self {
+        // Getting here means that the widget didn't use .position.
+        Self {
+            child: child.erased(),
+            position: Position::new(),
+        }
+    }
+
+    fn with_downcast_val<R>(
+        mut this: Mut<'_, Self>,
+        f: impl FnOnce(Mut<'_, Pod<W>>) -> R,
+    ) -> (Mut<'_, Self>, R) {
+        let ret = {
+            let mut child = widgets::Positioned::get_mut(&mut this.parent, this.idx);
+            let downcast = child.downcast();
+            f(downcast)
+        };
+
+        (this, ret)
+    }
+}
+
+// Used for building and rebuilding the ViewSequence
+impl ElementSplice<PositionedElement> for PositionedSplice<'_, '_> {
+    fn with_scratch<R>(&mut self, f: impl FnOnce(&mut AppendVec<PositionedElement>) -> R) -> R {
+        let ret = f(self.scratch);
+        for element in self.scratch.drain() {
+            widgets::Positioned::insert(
+                &mut self.element,
+                self.idx,
+                element.child.new_widget,
+                element.position,
+            );
+            self.idx += 1;
+        }
+        ret
+    }
+
+    fn insert(&mut self, element: PositionedElement) {
+        widgets::Positioned::insert(
+            &mut self.element,
+            self.idx,
+            element.child.new_widget,
+            element.position,
+        );
+        self.idx += 1;
+    }
+
+    fn mutate<R>(&mut self, f: impl FnOnce(Mut<'_, PositionedElement>) -> R) -> R {
+        let child = PositionedElementMut {
+            parent: self.element.reborrow_mut(),
+            idx: self.idx,
+        };
+        let ret = f(child);
+        self.idx += 1;
+        ret
+    }
+
+    fn skip(&mut self, n: usize) {
+        self.idx += n;
+    }
+
+    fn index(&self) -> usize {
+        self.idx
+    }
+
+    fn delete<R>(&mut self, f: impl FnOnce(Mut<'_, PositionedElement>) -> R) -> R {
+        let ret = {
+            let child = PositionedElementMut {
+                parent: self.element.reborrow_mut(),
+                idx: self.idx,
+            };
+            f(child)
+        };
+        widgets::Positioned::remove(&mut self.element, self.idx);
+        ret
+    }
+}
+
+/// `PositionedSequence` is what allows an input to the positioned layout that contains all the children.
+pub trait PositionedSequence<State: 'static, Action = ()>:
+    ViewSequence<State, Action, ViewCtx, PositionedElement>
+{
+}
+
+impl<Seq, State, Action> PositionedSequence<State, Action> for Seq
+where
+    Seq: ViewSequence<State, Action, ViewCtx, PositionedElement>,
+    State: 'static,
+{
+}
+
+/// A trait which extends a [`WidgetView`] with a method to set its position
+/// within a [`Positioned`] [`View`].
+pub trait PositionedExt<State: 'static, Action>: WidgetView<State, Action> {
+    /// Sets where the [`Positioned`] parent places this view.
+    ///
+    /// # Examples
+    /// ```
+    /// # use xilem_masonry as xilem;
+    /// use xilem::masonry::layout::AsUnit;
+    /// use xilem::view::{Position, PositionedExt, label, positioned};
+    /// # use xilem::WidgetView;
+    ///
+    /// # fn view<State: 'static>() -> impl WidgetView<State> {
+    /// positioned((
+    ///     label("Bottom left").position(Position::new().left(8.px()).bottom(8.px())),
+    /// ))
+    /// # }
+    /// ```
+    fn position(self, position: impl Into<Position>) -> PositionedItem<Self, State, Action>
+    where
+        Action: 'static,
+        Self: Sized,
+    {
+        positioned_item(self, position.into())
+    }
+}
+
+impl<State: 'static, Action, V: WidgetView<State, Action>> PositionedExt<State, Action> for V {}
+
+/// A child widget within an [`Positioned`] view.
+pub struct PositionedElement {
+    /// The child widget.
+    child: Pod<dyn Widget>,
+    /// Where the child widget is placed.
+    position: Position,
+}
+
+/// A mutable reference to a [`PositionedElement`], used internally by Xilem traits.
+pub struct PositionedElementMut<'w> {
+    parent: WidgetMut<'w, widgets::Positioned>,
+    idx: usize,
+}
+
+// Used for manipulating the ViewSequence.
+struct PositionedSplice<'w, 's> {
+    idx: usize,
+    element: WidgetMut<'w, widgets::Positioned>,
+    scratch: &'s mut AppendVec<PositionedElement>,
+}
+
+impl<'w, 's> PositionedSplice<'w, 's> {
+    fn new(
+        element: WidgetMut<'w, widgets::Positioned>,
+        scratch: &'s mut AppendVec<PositionedElement>,
+    ) -> Self {
+        Self {
+            idx: 0,
+            element,
+            scratch,
+        }
+    }
+}
+
+/// A `WidgetView` that can be used within an [`Positioned`] [`View`].
+pub struct PositionedItem<V, State, Action> {
+    view: V,
+    position: Position,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+/// Creates a [`PositionedItem`] from a view and its position.
+pub fn positioned_item<V, State, Action>(
+    view: V,
+    position: Position,
+) -> PositionedItem<V, State, Action>
+where
+    State: 'static,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    PositionedItem {
+        view,
+        position,
+        phantom: PhantomData,
+    }
+}
+
+impl<V, State, Action> ViewMarker for PositionedItem<V, State, Action> {}
+
+impl<State, Action, V> View<State, Action, ViewCtx> for PositionedItem<V, State, Action>
+where
+    State: 'static,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    type Element = PositionedElement;
+
+    type ViewState = V::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let (pod, state) = self.view.build(ctx, app_state);
+        (
+            PositionedElement {
+                child: pod.erased(),
+                position: self.position,
+            },
+            state,
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        if self.position != prev.position {
+            widgets::Positioned::set_params(&mut element.parent, element.idx, self.position);
+        }
+        let mut child = widgets::Positioned::get_mut(&mut element.parent, element.idx);
+        self.view
+            .rebuild(&prev.view, view_state, ctx, child.downcast(), app_state);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        let mut child = widgets::Positioned::get_mut(&mut element.parent, element.idx);
+        self.view.teardown(view_state, ctx, child.downcast());
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        let mut child = widgets::Positioned::get_mut(&mut element.parent, element.idx);
+        self.view
+            .message(view_state, message, child.downcast(), app_state)
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::kurbo::Axis;
+use masonry::widgets::{self, RangeSliderMoved, SliderTicks};
+
+use crate::core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
+use crate::{Pod, ViewCtx};
+
+/// A slider with two thumbs, for selecting a range of values between `min` and `max`.
+///
+/// `on_change` is called with the start and end of the range when the user moves a thumb.
+/// The thumbs can't cross, so the start is never above the end.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::masonry::widgets::SliderTicks;
+/// use xilem::view::range_slider;
+/// # use xilem::WidgetView;
+///
+/// struct Filter {
+///     price: (f64, f64),
+/// }
+///
+/// fn app(filter: &mut Filter) -> impl WidgetView<Filter> + use<> {
+///     range_slider(0., 500., filter.price, |filter: &mut Filter, price| {
+///         filter.price = price;
+///     })
+///     .step(50.)
+///     .ticks(SliderTicks::Steps { labeled: true })
+/// }
+/// ```
+pub fn range_slider<State, Action, F>(
+    min: f64,
+    max: f64,
+    (start, end): (f64, f64),
+    on_change: F,
+) -> RangeSlider<State, Action, F>
+where
+    State: 'static,
+    Action: 'static,
+    F: Fn(&mut State, (f64, f64)) -> Action + Send + Sync + 'static,
+{
+    RangeSlider {
+        min,
+        max,
+        start,
+        end,
+        step: None,
+        ticks: SliderTicks::None,
+        direction: Axis::Horizontal,
+        disabled: false,
+        on_change,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`range_slider`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct RangeSlider<State, Action, F> {
+    min: f64,
+    max: f64,
+    start: f64,
+    end: f64,
+    step: Option<f64>,
+    ticks: SliderTicks,
+    direction: Axis,
+    disabled: bool,
+    on_change: F,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+impl<State, Action, F> RangeSlider<State, Action, F> {
+    /// Sets the stepping interval of the slider.
+    pub fn step(mut self, step: f64) -> Self {
+        if step > 0.0 {
+            self.step = Some(step);
+        }
+        self
+    }
+
+    /// Sets the tick marks shown along the slider.
+    pub fn ticks(mut self, ticks: SliderTicks) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Sets the direction of the slider, which is horizontal by default.
+    ///
+    /// Values of vertical sliders increase upwards.
+    pub fn direction(mut self, direction: Axis) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets whether the slider is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl<State, Action, F> ViewMarker for RangeSlider<State, Action, F> {}
+impl<State, Action, F> View<State, Action, ViewCtx> for RangeSlider<State, Action, F>
+where
+    State: 'static,
+    Action: 'static,
+    F: Fn(&mut State, (f64, f64)) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::RangeSlider>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: &mut State) -> (Self::Element, Self::ViewState) {
+        (
+            ctx.with_action_widget(|ctx| {
+                let mut widget =
+                    widgets::RangeSlider::new(self.min, self.max, self.start, self.end)
+                        .with_direction(self.direction);
+                if let Some(step) = self.step {
+                    widget = widget.with_step(step);
+                }
+                widget = widget.with_ticks(self.ticks.clone());
+                let mut pod = ctx.create_pod(widget);
+                pod.new_widget.options.disabled = self.disabled;
+                pod
+            }),
+            (),
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: &mut State,
+    ) {
+        if prev.disabled != self.disabled {
+            element.ctx.set_disabled(self.disabled);
+        }
+        if prev.min != self.min || prev.max != self.max {
+            widgets::RangeSlider::set_bounds(&mut element, self.min, self.max);
+        }
+        if prev.step != self.step {
+            widgets::RangeSlider::set_step(&mut element, self.step);
+        }
+        if prev.start != self.start || prev.end != self.end {
+            widgets::RangeSlider::set_values(&mut element, self.start, self.end);
+        }
+        if prev.ticks != self.ticks {
+            widgets::RangeSlider::set_ticks(&mut element, self.ticks.clone());
+        }
+        if prev.direction != self.direction {
+            widgets::RangeSlider::set_direction(&mut element, self.direction);
+        }
+    }
+
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        if message.take_first().is_some() {
+            tracing::warn!("Got unexpected id path in RangeSlider::message");
+            return MessageResult::Stale;
+        }
+        match message.take_message::<RangeSliderMoved>() {
+            Some(moved) => {
+                MessageResult::Action((self.on_change)(app_state, (moved.start, moved.end)))
+            }
+            None => {
+                tracing::error!(
+                    "Wrong message type in RangeSlider::message: {message:?}, expected {}",
+                    std::any::type_name::<RangeSliderMoved>(),
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
@@ -3,7 +3,7 @@
 
 use std::marker::PhantomData;
 
-use masonry::widgets::{self, SliderMoved};
+use masonry::widgets::{self, SliderMoved, SliderTicks};
 
 use crate::core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
 use crate::{Pod, ViewCtx, WidgetView};
@@ -15,6 +15,7 @@ pub struct Slider<State, Action, F> {
     value: f64,
     on_change: F,
     step: Option<f64>,
+    ticks: SliderTicks,
     disabled: bool,
     phantom: PhantomData<fn(State) -> Action>,
 }
@@ -35,6 +36,7 @@ where
         value,
         on_change,
         step: None,
+        ticks: SliderTicks::None,
         disabled: false,
         phantom: PhantomData,
     }
@@ -48,6 +50,11 @@ impl<State, Action, F> Slider<State, Action, F> {
         }
         self
     }
+    /// Sets the tick marks shown along the slider.
+    pub fn ticks(mut self, ticks: SliderTicks) -> Self {
+        self.ticks = ticks;
+        self
+    }
     /// Sets whether the slider is disabled.
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -72,6 +79,7 @@ where
                 if let Some(step) = self.step {
                     widget = widget.with_step(step);
                 }
+                widget = widget.with_ticks(self.ticks.clone());
                 let mut pod = ctx.create_pod(widget);
                 pod.new_widget.options.disabled = self.disabled;
                 pod
@@ -100,6 +108,9 @@ where
         if prev.step != self.step {
             widgets::Slider::set_step(&mut element, self.step);
         }
+        if prev.ticks != self.ticks {
+            widgets::Slider::set_ticks(&mut element, self.ticks.clone());
+        }
     }
 
     fn teardown(
@@ -0,0 +1,387 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::core::{ArcStr, NewWidget, WidgetMut};
+use masonry::widgets::{self, TabAction, TabHeader};
+
+use crate::core::{MessageCtx, MessageResult, Mut, View, ViewId, ViewMarker, ViewPathTracker};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A tab bar showing the content of the selected tab under it.
+///
+/// Tabs are identified by their `key`, so when the list changes, the content of each
+/// tab keeps its state, even if the tab moved.
+/// The content of a tab is only built once the tab is first selected.
+///
+/// Selecting, closing and moving tabs are reported to `on_event`, which should update
+/// the app state accordingly: the tab list and `selected` always reflect what
+/// the view was last built with.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::view::{TabEvent, label, tab, tabs};
+/// # use xilem::WidgetView;
+///
+/// struct Document {
+///     id: u32,
+///     name: String,
+///     text: String,
+/// }
+///
+/// struct Editor {
+///     documents: Vec<Document>,
+///     current: u32,
+/// }
+///
+/// fn document_tabs(editor: &mut Editor) -> impl WidgetView<Editor> + use<> {
+///     let tab_list = editor
+///         .documents
+///         .iter()
+///         .map(|doc| tab(doc.id, doc.name.clone(), label(doc.text.clone())).closable(true))
+///         .collect();
+///     tabs(tab_list, editor.current, |editor: &mut Editor, event| match event {
+///         TabEvent::Selected(id) => editor.current = id,
+///         TabEvent::Close(id) => editor.documents.retain(|doc| doc.id != id),
+///         TabEvent::Moved { key, to } => {
+///             let from = editor.documents.iter().position(|doc| doc.id == key).unwrap();
+///             let doc = editor.documents.remove(from);
+///             editor.documents.insert(to, doc);
+///         }
+///     })
+/// }
+/// ```
+pub fn tabs<State, Action, K, V, F>(
+    tabs: Vec<Tab<K, V>>,
+    selected: K,
+    on_event: F,
+) -> Tabs<State, Action, K, V, F>
+where
+    State: 'static,
+    Action: 'static,
+    K: PartialEq + Clone + Send + Sync + 'static,
+    V: WidgetView<State, Action>,
+    F: Fn(&mut State, TabEvent<K>) -> Action + Send + Sync + 'static,
+{
+    Tabs {
+        tabs,
+        selected,
+        on_event,
+        phantom: PhantomData,
+    }
+}
+
+/// A tab of a [`tabs`] view.
+///
+/// `key` must be unique among the tabs of the view.
+pub fn tab<K, V>(key: K, label: impl Into<ArcStr>, content: V) -> Tab<K, V> {
+    Tab {
+        key,
+        label: label.into(),
+        icon: None,
+        closable: false,
+        content,
+    }
+}
+
+/// A tab of a [`tabs`] view, created by [`tab`].
+pub struct Tab<K, V> {
+    key: K,
+    label: ArcStr,
+    icon: Option<ArcStr>,
+    closable: bool,
+    content: V,
+}
+
+impl<K, V> Tab<K, V> {
+    /// Sets whether the tab has a close button.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Sets a glyph shown before the label, such as an emoji.
+    pub fn icon(mut self, icon: impl Into<ArcStr>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    fn header(&self) -> NewWidget<TabHeader> {
+        let mut header = TabHeader::new(self.label.clone()).with_closable(self.closable);
+        if let Some(icon) = &self.icon {
+            header = header.with_icon(NewWidget::new(widgets::Label::new(icon.clone())));
+        }
+        NewWidget::new(header)
+    }
+
+    fn rebuild_header(&self, prev: &Self, mut header: WidgetMut<'_, TabHeader>) {
+        if self.label != prev.label {
+            TabHeader::set_text(&mut header, self.label.clone());
+        }
+        if self.icon != prev.icon {
+            match &self.icon {
+                Some(icon) => {
+                    TabHeader::set_icon(
+                        &mut header,
+                        NewWidget::new(widgets::Label::new(icon.clone())),
+                    );
+                }
+                None => TabHeader::remove_icon(&mut header),
+            }
+        }
+        if self.closable != prev.closable {
+            TabHeader::set_closable(&mut header, self.closable);
+        }
+    }
+}
+
+/// Something the user did with a [`tabs`] view.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TabEvent<K> {
+    /// The tab with this key was selected.
+    Selected(K),
+    /// The user asked to close the tab with this key.
+    Close(K),
+    /// The user dragged the tab with this key to a new place.
+    Moved {
+        /// The key of the dragged tab.
+        key: K,
+        /// The index the tab should have once moved.
+        to: usize,
+    },
+}
+
+/// The [`View`] created by [`tabs`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Tabs<State, Action, K, V, F> {
+    tabs: Vec<Tab<K, V>>,
+    selected: K,
+    on_event: F,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+/// The state of a [`Tabs`] view.
+pub struct TabsState<K, S> {
+    tabs: Vec<TabState<K, S>>,
+    next_generation: u64,
+}
+
+struct TabState<K, S> {
+    key: K,
+    /// Identifies the content in view paths, so messages can't reach another tab.
+    generation: u64,
+    /// The state of the content, once built.
+    content: Option<S>,
+}
+
+impl<State, Action, K, V, F> Tabs<State, Action, K, V, F>
+where
+    State: 'static,
+    Action: 'static,
+    K: PartialEq + Clone + Send + Sync + 'static,
+    V: WidgetView<State, Action>,
+{
+    fn selected_index(&self) -> Option<usize> {
+        self.tabs.iter().position(|tab| tab.key == self.selected)
+    }
+
+    fn find_tab(&self, key: &K) -> Option<&Tab<K, V>> {
+        self.tabs.iter().find(|tab| tab.key == *key)
+    }
+}
+
+impl<State, Action, K, V, F> ViewMarker for Tabs<State, Action, K, V, F> {}
+impl<State, Action, K, V, F> View<State, Action, ViewCtx> for Tabs<State, Action, K, V, F>
+where
+    State: 'static,
+    Action: 'static,
+    K: PartialEq + Clone + Send + Sync + 'static,
+    V: WidgetView<State, Action>,
+    F: Fn(&mut State, TabEvent<K>) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::Tabs>;
+    type ViewState = TabsState<K, V::ViewState>;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let selected = self.selected_index();
+        let mut widget = widgets::Tabs::new();
+        let mut states = Vec::with_capacity(self.tabs.len());
+        for (index, (tab, generation)) in self.tabs.iter().zip(0..).enumerate() {
+            let mut content_state = None;
+            if Some(index) == selected {
+                let (content, state) = ctx.with_id(ViewId::new(generation), |ctx| {
+                    tab.content.build(ctx, app_state)
+                });
+                widget = widget.with_tab(tab.header(), content.new_widget);
+                content_state = Some(state);
+            } else {
+                widget = widget.with_empty_tab(tab.header());
+            }
+            states.push(TabState {
+                key: tab.key.clone(),
+                generation,
+                content: content_state,
+            });
+        }
+        if let Some(selected) = selected {
+            widget = widget.with_selected(selected);
+        }
+        let pod = ctx.with_action_widget(|ctx| ctx.create_pod(widget));
+        let state = TabsState {
+            next_generation: states.len() as u64,
+            tabs: states,
+        };
+        (pod, state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        for (index, tab) in self.tabs.iter().enumerate() {
+            let existing = view_state.tabs[index..]
+                .iter()
+                .position(|state| state.key == tab.key)
+                .map(|offset| index + offset);
+            let prev_tab = existing.and_then(|_| prev.find_tab(&tab.key));
+            let Some(prev_tab) = prev_tab else {
+                let generation = view_state.next_generation;
+                view_state.next_generation += 1;
+                view_state.tabs.insert(
+                    index,
+                    TabState {
+                        key: tab.key.clone(),
+                        generation,
+                        content: None,
+                    },
+                );
+                widgets::Tabs::insert_empty_tab(&mut element, index, tab.header());
+                continue;
+            };
+            let existing = existing.unwrap();
+            if existing != index {
+                let state = view_state.tabs.remove(existing);
+                view_state.tabs.insert(index, state);
+                widgets::Tabs::move_tab(&mut element, existing, index);
+            }
+
+            {
+                let mut bar = widgets::Tabs::bar_mut(&mut element);
+                tab.rebuild_header(prev_tab, widgets::TabBar::tab_mut(&mut bar, index));
+            }
+            let state = &mut view_state.tabs[index];
+            if let Some(content_state) = &mut state.content {
+                let mut content = widgets::Tabs::content_mut(&mut element, index).unwrap();
+                ctx.with_id(ViewId::new(state.generation), |ctx| {
+                    tab.content.rebuild(
+                        &prev_tab.content,
+                        content_state,
+                        ctx,
+                        content.downcast(),
+                        app_state,
+                    );
+                });
+            }
+        }
+
+        for index in (self.tabs.len()..view_state.tabs.len()).rev() {
+            let state = view_state.tabs.remove(index);
+            if let Some(mut content_state) = state.content
+                && let Some(prev_tab) = prev.find_tab(&state.key)
+            {
+                let mut content = widgets::Tabs::content_mut(&mut element, index).unwrap();
+                ctx.with_id(ViewId::new(state.generation), |ctx| {
+                    prev_tab
+                        .content
+                        .teardown(&mut content_state, ctx, content.downcast());
+                });
+            }
+            widgets::Tabs::remove_tab(&mut element, index);
+        }
+
+        let Some(selected) = self.selected_index() else {
+            return;
+        };
+        if element.widget.selected() != Some(selected) {
+            widgets::Tabs::set_selected(&mut element, selected);
+        }
+        let state = &mut view_state.tabs[selected];
+        if state.content.is_none() {
+            let (content, content_state) = ctx.with_id(ViewId::new(state.generation), |ctx| {
+                self.tabs[selected].content.build(ctx, app_state)
+            });
+            widgets::Tabs::set_content(&mut element, selected, content.new_widget);
+            state.content = Some(content_state);
+        }
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        for (index, (tab, state)) in self.tabs.iter().zip(&mut view_state.tabs).enumerate() {
+            if let Some(content_state) = &mut state.content {
+                let mut content = widgets::Tabs::content_mut(&mut element, index).unwrap();
+                ctx.with_id(ViewId::new(state.generation), |ctx| {
+                    tab.content.teardown(content_state, ctx, content.downcast());
+                });
+            }
+        }
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        if let Some(id) = message.take_first() {
+            let Some(index) = view_state
+                .tabs
+                .iter()
+                .position(|state| ViewId::new(state.generation) == id)
+            else {
+                tracing::warn!("Got message for a removed tab in Tabs::message");
+                return MessageResult::Stale;
+            };
+            let (Some(content_state), Some(tab)) =
+                (&mut view_state.tabs[index].content, self.tabs.get(index))
+            else {
+                tracing::warn!("Got message for a tab without content in Tabs::message");
+                return MessageResult::Stale;
+            };
+            let mut content = widgets::Tabs::content_mut(&mut element, index).unwrap();
+            return tab
+                .content
+                .message(content_state, message, content.downcast(), app_state);
+        }
+
+        let Some(action) = message.take_message::<TabAction>() else {
+            tracing::error!(
+                "Wrong message type in Tabs::message: {message:?}, expected {}",
+                std::any::type_name::<TabAction>(),
+            );
+            return MessageResult::Stale;
+        };
+        let key = |index: usize| view_state.tabs.get(index).map(|state| state.key.clone());
+        let event = match *action {
+            TabAction::Selected(index) => key(index).map(TabEvent::Selected),
+            TabAction::CloseRequested(index) => key(index).map(TabEvent::Close),
+            TabAction::Moved { from, to } => key(from).map(|key| TabEvent::Moved { key, to }),
+        };
+        match event {
+            Some(event) => MessageResult::Action((self.on_event)(app_state, event)),
+            None => MessageResult::Stale,
+        }
+    }
+}
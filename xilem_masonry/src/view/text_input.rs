@@ -6,7 +6,7 @@ use masonry::parley::style::FontWeight;
 use masonry::parley::{FontFamily, FontFamilyName, GenericFamily, StyleProperty};
 use masonry::peniko::Color;
 use masonry::properties::{CaretColor, ContentColor, PlaceholderColor, SelectionColor};
-use masonry::widgets::{self, TextAction};
+use masonry::widgets::{self, InputMask, TextAction};
 
 use crate::core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
 use crate::view::Prop;
@@ -95,6 +95,9 @@ where
         // Since we don't support setting the word wrapping, we can default to
         // not clipping
         clip: true,
+        password: false,
+        password_toggle: false,
+        input_mask: None,
     }
 }
 
@@ -114,6 +117,9 @@ pub struct TextInput<State: 'static, Action> {
     insert_newline: InsertNewline,
     disabled: bool,
     clip: bool,
+    password: bool,
+    password_toggle: bool,
+    input_mask: Option<InputMask>,
     // TODO: add more attributes of `masonry::widgets::TextInput`
 }
 
@@ -230,6 +236,28 @@ impl<State: 'static, Action: 'static> TextInput<State, Action> {
         self.clip = clip;
         self
     }
+
+    /// Set whether the text is hidden behind bullets, for entering passwords.
+    ///
+    /// See [`TextArea::with_password`](widgets::TextArea::with_password) for details.
+    pub fn password(mut self, password: bool) -> Self {
+        self.password = password;
+        self
+    }
+
+    /// Set whether to show a button which shows or hides the password.
+    pub fn password_toggle(mut self, password_toggle: bool) -> Self {
+        self.password_toggle = password_toggle;
+        self
+    }
+
+    /// Constrain the text to follow a pattern, e.g. for phone numbers or dates.
+    ///
+    /// See [`InputMask`] for the pattern syntax.
+    pub fn input_mask(mut self, input_mask: InputMask) -> Self {
+        self.input_mask = Some(input_mask);
+        self
+    }
 }
 
 impl<State: 'static, Action> ViewMarker for TextInput<State, Action> {}
@@ -244,7 +272,12 @@ impl<State: 'static, Action: 'static> View<State, Action, ViewCtx> for TextInput
             .with_insert_newline(self.insert_newline)
             .with_style(StyleProperty::FontSize(self.text_size))
             .with_style(StyleProperty::FontWeight(self.weight))
-            .with_style(StyleProperty::FontFamily(self.font.clone()));
+            .with_style(StyleProperty::FontFamily(self.font.clone()))
+            .with_password(self.password);
+        let text_area = match &self.input_mask {
+            Some(mask) => text_area.with_input_mask(mask.clone()),
+            None => text_area,
+        };
 
         // TODO - Replace this with properties on the TextInput view
         // once we implement property inheritance or something like it.
@@ -257,7 +290,8 @@ impl<State: 'static, Action: 'static> View<State, Action, ViewCtx> for TextInput
             widgets::TextInput::from_text_area(NewWidget::new(text_area).with_props(props))
                 .with_text_alignment(self.text_alignment)
                 .with_clip(self.clip)
-                .with_placeholder(self.placeholder.clone());
+                .with_placeholder(self.placeholder.clone())
+                .with_password_toggle(self.password_toggle);
 
         // Ensure that the actions from the *inner* TextArea get routed correctly.
         let id = text_input.area_pod().id();
@@ -300,8 +334,19 @@ impl<State: 'static, Action: 'static> View<State, Action, ViewCtx> for TextInput
             widgets::TextInput::set_text_alignment(&mut element, self.text_alignment);
         }
 
+        if self.password_toggle != prev.password_toggle {
+            widgets::TextInput::set_password_toggle(&mut element, self.password_toggle);
+        }
+
         let mut text_area = widgets::TextInput::text_mut(&mut element);
 
+        if self.password != prev.password {
+            widgets::TextArea::set_password(&mut text_area, self.password);
+        }
+        if self.input_mask != prev.input_mask {
+            widgets::TextArea::set_input_mask(&mut text_area, self.input_mask.clone());
+        }
+
         // Unlike the other properties, we don't compare to the previous value;
         // instead, we compare directly to the element's text. This is to handle
         // cases like "Previous data says contents is 'fooba', user presses 'r',
@@ -309,7 +354,11 @@ impl<State: 'static, Action: 'static> View<State, Action, ViewCtx> for TextInput
         // without calling `set_text`.
 
         // This is probably not the right behaviour, but determining what is the right behaviour is hard
-        if text_area.widget.text() != &self.contents {
+        let text_changed = match text_area.widget.password() {
+            Some(password) => password != self.contents,
+            None => text_area.widget.text() != &self.contents,
+        };
+        if text_changed {
             widgets::TextArea::reset_text(&mut text_area, &self.contents);
         }
 
@@ -0,0 +1,139 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::time::Time;
+use masonry::widgets::{self, TimeChanged};
+
+use crate::core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
+use crate::{Pod, ViewCtx};
+
+/// An input for a time of the day, with a segment for the hours, minutes, and optionally seconds.
+///
+/// `on_change` is called when the user changes any of the segments.
+/// See [`TimePicker`](widgets::TimePicker) for how the segments are used.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::masonry::time::Time;
+/// use xilem::view::time_picker;
+/// # use xilem::WidgetView;
+///
+/// struct Alarm {
+///     time: Time,
+/// }
+///
+/// fn app(alarm: &mut Alarm) -> impl WidgetView<Alarm> + use<> {
+///     time_picker(alarm.time, |alarm: &mut Alarm, time| alarm.time = time).twelve_hour(true)
+/// }
+/// ```
+pub fn time_picker<State, Action, F>(time: Time, on_change: F) -> TimePicker<State, Action, F>
+where
+    State: 'static,
+    Action: 'static,
+    F: Fn(&mut State, Time) -> Action + Send + Sync + 'static,
+{
+    TimePicker {
+        time,
+        twelve_hour: false,
+        seconds: false,
+        on_change,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`time_picker`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct TimePicker<State, Action, F> {
+    time: Time,
+    twelve_hour: bool,
+    seconds: bool,
+    on_change: F,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+impl<State, Action, F> TimePicker<State, Action, F> {
+    /// Sets whether the hours are shown from 1 to 12, with an AM/PM segment.
+    pub fn twelve_hour(mut self, twelve_hour: bool) -> Self {
+        self.twelve_hour = twelve_hour;
+        self
+    }
+
+    /// Sets whether the seconds are shown.
+    pub fn seconds(mut self, seconds: bool) -> Self {
+        self.seconds = seconds;
+        self
+    }
+}
+
+impl<State, Action, F> ViewMarker for TimePicker<State, Action, F> {}
+impl<State, Action, F> View<State, Action, ViewCtx> for TimePicker<State, Action, F>
+where
+    State: 'static,
+    Action: 'static,
+    F: Fn(&mut State, Time) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::TimePicker>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: &mut State) -> (Self::Element, Self::ViewState) {
+        let widget = widgets::TimePicker::new(self.time)
+            .with_twelve_hour(self.twelve_hour)
+            .with_seconds(self.seconds);
+        let pod = ctx.with_action_widget(|ctx| ctx.create_pod(widget));
+        (pod, ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: &mut State,
+    ) {
+        if self.twelve_hour != prev.twelve_hour {
+            widgets::TimePicker::set_twelve_hour(&mut element, self.twelve_hour);
+        }
+        if self.seconds != prev.seconds {
+            widgets::TimePicker::set_show_seconds(&mut element, self.seconds);
+        }
+        if self.time != prev.time {
+            widgets::TimePicker::set_time(&mut element, self.time);
+        }
+    }
+
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        if message.take_first().is_some() {
+            tracing::warn!("Got unexpected id path in TimePicker::message");
+            return MessageResult::Stale;
+        }
+        match message.take_message::<TimeChanged>() {
+            Some(changed) => MessageResult::Action((self.on_change)(app_state, changed.0)),
+            None => {
+                tracing::error!(
+                    "Wrong message type in TimePicker::message: {message:?}, expected {}",
+                    std::any::type_name::<TimeChanged>(),
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
@@ -0,0 +1,401 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use masonry::accesskit::Live;
+use masonry::core::{ArcStr, FromDynWidget, LayerType, NewWidget, Widget, WidgetId};
+use masonry::kurbo::Point;
+use masonry::layers::{Toast, ToastActivated, ToastCorner, ToastLayer};
+
+use crate::core::{
+    MessageCtx, MessageProxy, MessageResult, Mut, View, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A notification to show with a [`ToastHandle`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToastRequest {
+    /// The message of the toast.
+    pub message: ArcStr,
+    /// The text of the action button of the toast, if any.
+    pub action: Option<ArcStr>,
+    /// How long the toast is shown, or `None` to show it until its action is pressed.
+    pub duration: Option<Duration>,
+    /// Whether assistive technology interrupts the user to announce the toast.
+    pub urgent: bool,
+}
+
+impl ToastRequest {
+    /// Creates a request for a toast showing `message` for four seconds.
+    pub fn new(message: impl Into<ArcStr>) -> Self {
+        Self {
+            message: message.into(),
+            action: None,
+            duration: Some(Duration::from_secs(4)),
+            urgent: false,
+        }
+    }
+
+    /// Builder-style method to add an action button with the given text.
+    ///
+    /// When it's pressed, the request is passed to the `on_action` callback of the [`toaster`].
+    pub fn with_action(mut self, text: impl Into<ArcStr>) -> Self {
+        self.action = Some(text.into());
+        self
+    }
+
+    /// Builder-style method to set how long the toast is shown.
+    pub fn with_duration(mut self, duration: Option<Duration>) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Builder-style method to make assistive technology interrupt the user to announce the toast.
+    ///
+    /// This should be kept for errors and other time-sensitive notifications.
+    pub fn urgent(mut self) -> Self {
+        self.urgent = true;
+        self
+    }
+
+    fn to_toast(&self) -> Toast {
+        let mut toast = Toast::new(self.message.clone()).with_duration(self.duration);
+        if let Some(action) = &self.action {
+            toast = toast.with_action(action.clone());
+        }
+        if self.urgent {
+            toast = toast.with_live(Live::Assertive);
+        }
+        toast
+    }
+}
+
+/// A handle to show toasts from anywhere in the app, including from other threads.
+///
+/// The handle is usually created with the app's state, and passed to a [`toaster`] view,
+/// which shows the toasts. Clones of the handle show their toasts in the same `toaster`.
+/// Toasts shown before the `toaster` is built are queued until then.
+///
+/// This can be used to show toasts when a [`worker`](crate::view::worker) responds,
+/// or from a task which the handle was moved to.
+#[derive(Clone, Debug, Default)]
+pub struct ToastHandle {
+    inner: Arc<Mutex<ToastQueue>>,
+}
+
+#[derive(Debug, Default)]
+struct ToastQueue {
+    proxy: Option<MessageProxy<ToastRequest>>,
+    pending: Vec<ToastRequest>,
+}
+
+impl ToastHandle {
+    /// Creates a handle which isn't used by a [`toaster`] yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Shows a toast in the [`toaster`] using this handle.
+    pub fn show(&self, request: ToastRequest) {
+        let mut queue = self.inner.lock().unwrap();
+        match &queue.proxy {
+            Some(proxy) => {
+                if let Err(err) = proxy.message(request) {
+                    tracing::warn!("Could not show toast: {err}");
+                }
+            }
+            None => queue.pending.push(request),
+        }
+    }
+
+    fn connect(&self, proxy: MessageProxy<ToastRequest>) {
+        let mut queue = self.inner.lock().unwrap();
+        for request in std::mem::take(&mut queue.pending) {
+            if let Err(err) = proxy.message(request) {
+                tracing::warn!("Could not show toast: {err}");
+            }
+        }
+        queue.proxy = Some(proxy);
+    }
+
+    fn disconnect(&self) {
+        self.inner.lock().unwrap().proxy = None;
+    }
+
+    fn same_as(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+/// A view which shows the toasts of `handle` in a layer above the window, next to `child`.
+///
+/// Toasts are stacked in a corner of the window, the [bottom right](ToastCorner::BottomRight)
+/// one by default. See [`ToastLayer`] for how they're shown and dismissed.
+/// `on_action` is called with the request of a toast when its action button is pressed.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::core::fork;
+/// use xilem::view::{ToastHandle, ToastRequest, text_button, toaster, worker};
+/// # use xilem::WidgetView;
+///
+/// struct Editor {
+///     toasts: ToastHandle,
+///     saves: Option<tokio::sync::mpsc::UnboundedSender<()>>,
+/// }
+///
+/// fn app(editor: &mut Editor) -> impl WidgetView<Editor> + use<> {
+///     let save = text_button("Save", |editor: &mut Editor| {
+///         if let Some(saves) = &editor.saves {
+///             drop(saves.send(()));
+///         }
+///     });
+///     let saver = worker(
+///         |proxy, mut saves| async move {
+///             while let Some(()) = saves.recv().await {
+///                 // Save the document...
+///                 drop(proxy.message(()));
+///             }
+///         },
+///         |editor: &mut Editor, saves| editor.saves = Some(saves),
+///         |editor: &mut Editor, ()| {
+///             editor.toasts.show(ToastRequest::new("Saved").with_action("Undo"));
+///         },
+///     );
+///     toaster(
+///         &editor.toasts,
+///         fork(save, saver),
+///         |_: &mut Editor, request: ToastRequest| {
+///             // The "Undo" button of the toast was pressed.
+///         },
+///     )
+/// }
+/// ```
+pub fn toaster<State, Action, V, F>(
+    handle: &ToastHandle,
+    child: V,
+    on_action: F,
+) -> Toaster<V, F, State, Action>
+where
+    State: 'static,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+    F: Fn(&mut State, ToastRequest) -> Action + Send + Sync + 'static,
+{
+    Toaster {
+        handle: handle.clone(),
+        child,
+        corner: ToastCorner::default(),
+        on_action,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`toaster`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Toaster<V, F, State, Action> {
+    handle: ToastHandle,
+    child: V,
+    corner: ToastCorner,
+    on_action: F,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+impl<V, F, State, Action> Toaster<V, F, State, Action> {
+    /// Sets the corner of the window in which toasts are stacked.
+    pub fn corner(mut self, corner: ToastCorner) -> Self {
+        self.corner = corner;
+        self
+    }
+}
+
+/// This is a randomly generated 32 bit number - 1361597683 in decimal.
+const TOASTER_CHILD_VIEW_ID: ViewId = ViewId::new(0x5128c6f3);
+
+mod hidden {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    use masonry::core::{NewWidget, WidgetId};
+    use masonry::layers::ToastLayer;
+
+    use super::ToastRequest;
+
+    #[doc(hidden)]
+    #[expect(
+        unnameable_types,
+        reason = "Implementation detail, public because of trait visibility rules"
+    )]
+    pub struct ToasterState<ChildState> {
+        pub(crate) child: ChildState,
+        /// The layer, until it's added to the window with the first toast.
+        pub(crate) pending_layer: Option<NewWidget<ToastLayer>>,
+        pub(crate) layer: WidgetId,
+        /// The requests of the toasts with an action button, by toast id.
+        pub(crate) requests: Arc<Mutex<HashMap<WidgetId, ToastRequest>>>,
+    }
+}
+
+use hidden::ToasterState;
+
+impl<V, F, State, Action> ViewMarker for Toaster<V, F, State, Action> {}
+impl<V, F, State, Action> View<State, Action, ViewCtx> for Toaster<V, F, State, Action>
+where
+    State: 'static,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+    F: Fn(&mut State, ToastRequest) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<V::Widget>;
+    type ViewState = ToasterState<V::ViewState>;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = ctx.with_id(TOASTER_CHILD_VIEW_ID, |ctx| {
+            self.child.build(ctx, app_state)
+        });
+        let layer = NewWidget::new(ToastLayer::new(self.corner));
+        ctx.record_action_source(layer.id());
+        self.handle
+            .connect(MessageProxy::new(ctx.proxy(), ctx.view_path().into()));
+        let state = ToasterState {
+            child: child_state,
+            layer: layer.id(),
+            pending_layer: Some(layer),
+            requests: Arc::default(),
+        };
+        (child, state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        ctx.with_id(TOASTER_CHILD_VIEW_ID, |ctx| {
+            self.child.rebuild(
+                &prev.child,
+                &mut view_state.child,
+                ctx,
+                element.reborrow_mut(),
+                app_state,
+            );
+        });
+        if !self.handle.same_as(&prev.handle) {
+            prev.handle.disconnect();
+            self.handle
+                .connect(MessageProxy::new(ctx.proxy(), ctx.view_path().into()));
+        }
+        if self.corner != prev.corner {
+            let corner = self.corner;
+            match &mut view_state.pending_layer {
+                Some(layer) => *layer.widget = ToastLayer::new(corner),
+                None => element
+                    .ctx
+                    .mutate_later(view_state.layer, move |mut layer| {
+                        ToastLayer::set_corner(&mut layer.downcast(), corner);
+                    }),
+            }
+        }
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        self.handle.disconnect();
+        ctx.teardown_action_source_id(view_state.layer);
+        ctx.with_id(TOASTER_CHILD_VIEW_ID, |ctx| {
+            self.child.teardown(&mut view_state.child, ctx, element);
+        });
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(TOASTER_CHILD_VIEW_ID) => {
+                self.child
+                    .message(&mut view_state.child, message, element, app_state)
+            }
+            None => {
+                if let Some(request) = message.take_message::<ToastRequest>() {
+                    show_toast(view_state, &mut element, *request);
+                    MessageResult::Nop
+                } else if let Some(activated) = message.take_message::<ToastActivated>() {
+                    let request = view_state.requests.lock().unwrap().remove(&activated.toast);
+                    match request {
+                        Some(request) => {
+                            MessageResult::Action((self.on_action)(app_state, request))
+                        }
+                        None => MessageResult::Stale,
+                    }
+                } else {
+                    tracing::error!(
+                        "Wrong message type in Toaster::message: {message:?}, expected {} or {}",
+                        std::any::type_name::<ToastRequest>(),
+                        std::any::type_name::<ToastActivated>(),
+                    );
+                    MessageResult::Stale
+                }
+            }
+            _ => {
+                tracing::warn!("Got unexpected id path in Toaster::message");
+                MessageResult::Stale
+            }
+        }
+    }
+}
+
+/// Adds the toast of `request` to the layer of the toaster, adding the layer if needed.
+fn show_toast<W: Widget + FromDynWidget + ?Sized>(
+    view_state: &mut ToasterState<impl Sized>,
+    element: &mut Mut<'_, Pod<W>>,
+    request: ToastRequest,
+) {
+    let has_action = request.action.is_some();
+    match view_state.pending_layer.take() {
+        Some(mut layer) => {
+            let toast = NewWidget::new(request.to_toast());
+            if has_action {
+                let mut requests = view_state.requests.lock().unwrap();
+                requests.insert(toast.id(), request);
+            }
+            *layer.widget = std::mem::take(&mut *layer.widget).with_toast(toast);
+            element
+                .ctx
+                .create_attached_layer(LayerType::Other, layer, Point::ORIGIN);
+        }
+        None => {
+            let requests = view_state.requests.clone();
+            element
+                .ctx
+                .mutate_later(view_state.layer, move |mut layer| {
+                    let mut layer = layer.downcast::<ToastLayer>();
+                    let toast = NewWidget::new(request.to_toast());
+                    let id = toast.id();
+                    ToastLayer::push(&mut layer, toast);
+
+                    let mut requests = requests.lock().unwrap();
+                    // Forget the toasts which were closed.
+                    let shown: Vec<WidgetId> = layer.widget.toast_ids().collect();
+                    requests.retain(|id, _| shown.contains(id));
+                    if has_action {
+                        requests.insert(id, request);
+                    }
+                });
+        }
+    }
+}
@@ -0,0 +1,247 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::kurbo::Point;
+use masonry::widgets::{self, ViewportChanged};
+
+use crate::core::{MessageCtx, MessageResult, Mut, View, ViewId, ViewMarker, ViewPathTracker};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A view which lets the user pan and zoom around `child`, like an image viewer or a map.
+///
+/// `on_change` is called with the new zoom and offset when the user pans or zooms.
+/// The offset is the point of the child shown at the top left of the viewport.
+/// See [`ZoomPortal`](widgets::ZoomPortal) for the supported gestures.
+///
+/// The zoom and offset can also be set by the app, with [`zoom`](ZoomPortal::zoom)
+/// and [`offset`](ZoomPortal::offset).
+/// They are only applied when they change, so the user can still pan and zoom
+/// if the app doesn't keep track of them.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::masonry::kurbo::Point;
+/// use xilem::view::{label, zoom_portal};
+/// # use xilem::WidgetView;
+///
+/// struct Viewer {
+///     zoom: f64,
+///     offset: Point,
+/// }
+///
+/// fn app(viewer: &mut Viewer) -> impl WidgetView<Viewer> + use<> {
+///     zoom_portal(
+///         label("A very large map"),
+///         |viewer: &mut Viewer, zoom, offset| {
+///             viewer.zoom = zoom;
+///             viewer.offset = offset;
+///         },
+///     )
+///     .zoom_range(0.25, 8.)
+///     .zoom(viewer.zoom)
+///     .offset(viewer.offset)
+/// }
+/// ```
+pub fn zoom_portal<Child, State, Action, F>(
+    child: Child,
+    on_change: F,
+) -> ZoomPortal<Child, F, State, Action>
+where
+    State: 'static,
+    Action: 'static,
+    Child: WidgetView<State, Action>,
+    F: Fn(&mut State, f64, Point) -> Action + Send + Sync + 'static,
+{
+    ZoomPortal {
+        child,
+        zoom: None,
+        offset: None,
+        zoom_range: (0.1, 10.),
+        wheel_zoom: true,
+        fit_to_content: false,
+        on_change,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`zoom_portal`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct ZoomPortal<V, F, State, Action> {
+    child: V,
+    zoom: Option<f64>,
+    offset: Option<Point>,
+    zoom_range: (f64, f64),
+    wheel_zoom: bool,
+    fit_to_content: bool,
+    on_change: F,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+impl<V, F, State, Action> ZoomPortal<V, F, State, Action> {
+    /// Sets the zoom, when it's different from the zoom of the previous view.
+    pub fn zoom(mut self, zoom: f64) -> Self {
+        self.zoom = Some(zoom);
+        self
+    }
+
+    /// Sets the point of the child shown at the top left of the viewport,
+    /// when it's different from the offset of the previous view.
+    pub fn offset(mut self, offset: Point) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the range of the zoom, from a tenth to ten by default.
+    ///
+    /// # Panics
+    ///
+    /// If `min` isn't positive, or is greater than `max`.
+    pub fn zoom_range(mut self, min: f64, max: f64) -> Self {
+        assert!(0. < min && min <= max, "invalid zoom range: {min} to {max}");
+        self.zoom_range = (min, max);
+        self
+    }
+
+    /// Sets whether the mouse wheel zooms, `true` by default.
+    ///
+    /// When `false`, the mouse wheel pans the child, and zooms only with <kbd>Ctrl</kbd> held.
+    pub fn wheel_zoom(mut self, wheel_zoom: bool) -> Self {
+        self.wheel_zoom = wheel_zoom;
+        self
+    }
+
+    /// Initially zooms so the whole child fits in the viewport.
+    ///
+    /// This takes precedence over the initial [`zoom`](Self::zoom) and [`offset`](Self::offset).
+    pub fn fit_to_content(mut self) -> Self {
+        self.fit_to_content = true;
+        self
+    }
+}
+
+/// This is a randomly generated 32 bit number - 3735447090 in decimal.
+const ZOOM_PORTAL_CHILD_VIEW_ID: ViewId = ViewId::new(0xdea6b632);
+
+impl<V, F, State, Action> ViewMarker for ZoomPortal<V, F, State, Action> {}
+impl<V, F, State, Action> View<State, Action, ViewCtx> for ZoomPortal<V, F, State, Action>
+where
+    State: 'static,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+    F: Fn(&mut State, f64, Point) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::ZoomPortal<V::Widget>>;
+    type ViewState = V::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = ctx.with_id(ZOOM_PORTAL_CHILD_VIEW_ID, |ctx| {
+            self.child.build(ctx, app_state)
+        });
+        let (min, max) = self.zoom_range;
+        let mut widget = widgets::ZoomPortal::new(child.new_widget)
+            .with_zoom_range(min, max)
+            .with_wheel_zoom(self.wheel_zoom);
+        if let Some(zoom) = self.zoom {
+            widget = widget.with_zoom(zoom);
+        }
+        if let Some(offset) = self.offset {
+            widget = widget.with_offset(offset);
+        }
+        if self.fit_to_content {
+            widget = widget.with_fit_to_content();
+        }
+        let pod = ctx.with_action_widget(|ctx| ctx.create_pod(widget));
+        (pod, child_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        if self.zoom_range != prev.zoom_range {
+            let (min, max) = self.zoom_range;
+            widgets::ZoomPortal::set_zoom_range(&mut element, min, max);
+        }
+        if self.wheel_zoom != prev.wheel_zoom {
+            widgets::ZoomPortal::set_wheel_zoom(&mut element, self.wheel_zoom);
+        }
+        if let Some(zoom) = self.zoom
+            && self.zoom != prev.zoom
+            && zoom != element.widget.zoom()
+        {
+            widgets::ZoomPortal::set_zoom(&mut element, zoom);
+        }
+        if let Some(offset) = self.offset
+            && self.offset != prev.offset
+            && offset != element.widget.offset()
+        {
+            widgets::ZoomPortal::set_offset(&mut element, offset);
+        }
+
+        ctx.with_id(ZOOM_PORTAL_CHILD_VIEW_ID, |ctx| {
+            self.child.rebuild(
+                &prev.child,
+                view_state,
+                ctx,
+                widgets::ZoomPortal::child_mut(&mut element),
+                app_state,
+            );
+        });
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.with_id(ZOOM_PORTAL_CHILD_VIEW_ID, |ctx| {
+            self.child.teardown(
+                view_state,
+                ctx,
+                widgets::ZoomPortal::child_mut(&mut element),
+            );
+        });
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(ZOOM_PORTAL_CHILD_VIEW_ID) => self.child.message(
+                view_state,
+                message,
+                widgets::ZoomPortal::child_mut(&mut element),
+                app_state,
+            ),
+            None => match message.take_message::<ViewportChanged>() {
+                Some(changed) => {
+                    MessageResult::Action((self.on_change)(app_state, changed.zoom, changed.offset))
+                }
+                None => {
+                    tracing::error!(
+                        "Wrong message type in ZoomPortal::message: {message:?}, expected {}",
+                        std::any::type_name::<ViewportChanged>(),
+                    );
+                    MessageResult::Stale
+                }
+            },
+            _ => {
+                tracing::warn!("Got unexpected id path in ZoomPortal::message");
+                MessageResult::Stale
+            }
+        }
+    }
+}
@@ -80,6 +80,14 @@ impl ViewCtx {
         self.widget_map.remove(&widget.ctx.widget_id());
     }
 
+    /// Removes the id path of the widget `id` from the routing map.
+    ///
+    /// Unlike [`Self::teardown_action_source`], this works for widgets which aren't
+    /// the element of a view, such as the root of a layer.
+    pub fn teardown_action_source_id(&mut self, id: WidgetId) {
+        self.widget_map.remove(&id);
+    }
+
     /// Returns a reference to the app's tokio runtime.
     pub fn runtime(&self) -> &tokio::runtime::Runtime {
         &self.runtime